@@ -0,0 +1,113 @@
+CREATE TABLE domain_events_queue
+(
+    `merchant_id` String,
+    `event_type` LowCardinality(String),
+    `refund_id` Nullable(String),
+    `payment_id` Nullable(String),
+    `mandate_id` Nullable(String),
+    `customer_id` Nullable(String),
+    `connector` LowCardinality(String),
+    `refund_amount` Nullable(UInt32),
+    `currency` LowCardinality(Nullable(String)),
+    `refund_status` LowCardinality(Nullable(String)),
+    `mandate_type` LowCardinality(Nullable(String)),
+    `mandate_status` LowCardinality(Nullable(String)),
+    `old_status` LowCardinality(Nullable(String)),
+    `new_status` LowCardinality(Nullable(String)),
+    `latency_in_secs` Nullable(Int64),
+    `lifetime_in_secs` Nullable(Int64),
+    `created_at` DateTime64(9)
+)
+ENGINE = Kafka
+SETTINGS kafka_broker_list = 'kafka0:29092', kafka_topic_list = 'hyperswitch-domain-events', kafka_group_name = 'hyper', kafka_format = 'JSONEachRow', kafka_handle_error_mode = 'stream';
+
+CREATE MATERIALIZED VIEW domain_events_parse_errors (
+    `topic` String,
+    `partition` Int64,
+    `offset` Int64,
+    `raw` String,
+    `error` String
+) ENGINE = MergeTree
+ORDER BY
+    (topic, partition, offset) SETTINGS index_granularity = 8192 AS
+SELECT
+    _topic AS topic,
+    _partition AS partition,
+    _offset AS offset,
+    _raw_message AS raw,
+    _error AS error
+FROM
+    domain_events_queue
+WHERE
+    length(_error) > 0;
+
+CREATE TABLE domain_events (
+    `merchant_id` LowCardinality(String),
+    `event_type` LowCardinality(String),
+    `refund_id` Nullable(String),
+    `payment_id` Nullable(String),
+    `mandate_id` Nullable(String),
+    `customer_id` Nullable(String),
+    `connector` LowCardinality(String),
+    `refund_amount` Nullable(UInt32),
+    `currency` LowCardinality(Nullable(String)),
+    `refund_status` LowCardinality(Nullable(String)),
+    `mandate_type` LowCardinality(Nullable(String)),
+    `mandate_status` LowCardinality(Nullable(String)),
+    `old_status` LowCardinality(Nullable(String)),
+    `new_status` LowCardinality(Nullable(String)),
+    `latency_in_secs` Nullable(Int64),
+    `lifetime_in_secs` Nullable(Int64),
+    `created_at` DateTime64(9),
+    `inserted_at` DateTime DEFAULT now() CODEC(T64, LZ4),
+    INDEX eventTypeIndex event_type TYPE bloom_filter GRANULARITY 1,
+    INDEX connectorIndex connector TYPE bloom_filter GRANULARITY 1
+) ENGINE = MergeTree
+PARTITION BY toStartOfDay(created_at)
+ORDER BY (created_at, merchant_id, event_type)
+TTL toDateTime(created_at) + toIntervalMonth(18)
+SETTINGS index_granularity = 8192;
+
+CREATE MATERIALIZED VIEW domain_events_mv TO domain_events (
+    `merchant_id` String,
+    `event_type` LowCardinality(String),
+    `refund_id` Nullable(String),
+    `payment_id` Nullable(String),
+    `mandate_id` Nullable(String),
+    `customer_id` Nullable(String),
+    `connector` LowCardinality(String),
+    `refund_amount` Nullable(UInt32),
+    `currency` LowCardinality(Nullable(String)),
+    `refund_status` LowCardinality(Nullable(String)),
+    `mandate_type` LowCardinality(Nullable(String)),
+    `mandate_status` LowCardinality(Nullable(String)),
+    `old_status` LowCardinality(Nullable(String)),
+    `new_status` LowCardinality(Nullable(String)),
+    `latency_in_secs` Nullable(Int64),
+    `lifetime_in_secs` Nullable(Int64),
+    `created_at` DateTime64(9),
+    `inserted_at` DateTime64(3)
+) AS
+SELECT
+    merchant_id,
+    event_type,
+    refund_id,
+    payment_id,
+    mandate_id,
+    customer_id,
+    connector,
+    refund_amount,
+    currency,
+    refund_status,
+    mandate_type,
+    mandate_status,
+    old_status,
+    new_status,
+    latency_in_secs,
+    lifetime_in_secs,
+    created_at,
+    now() AS inserted_at
+FROM
+    domain_events_queue
+WHERE
+    length(_error) = 0;
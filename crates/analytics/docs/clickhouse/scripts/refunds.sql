@@ -23,6 +23,7 @@ CREATE TABLE refund_queue (
     `modified_at` DateTime,
     `organization_id` String,
     `profile_id` String,
+    `merchant_connector_id` Nullable(String),
     `sign_flag` Int8
 ) ENGINE = Kafka SETTINGS kafka_broker_list = 'kafka0:29092',
 kafka_topic_list = 'hyperswitch-refund-events',
@@ -56,6 +57,7 @@ CREATE TABLE refunds (
     `inserted_at` DateTime DEFAULT now() CODEC(T64, LZ4),
     `organization_id` String,
     `profile_id` String,
+    `merchant_connector_id` Nullable(String),
     `sign_flag` Int8,
     INDEX connectorIndex connector TYPE bloom_filter GRANULARITY 1,
     INDEX refundTypeIndex refund_type TYPE bloom_filter GRANULARITY 1,
@@ -91,6 +93,7 @@ CREATE MATERIALIZED VIEW refund_mv TO refunds (
     `inserted_at` DateTime64(3),
     `organization_id` String,
     `profile_id` String,
+    `merchant_connector_id` Nullable(String),
     `sign_flag` Int8
 ) AS
 SELECT
@@ -119,6 +122,7 @@ SELECT
     now() AS inserted_at,
     organization_id,
     profile_id,
+    merchant_connector_id,
     sign_flag
 FROM
     refund_queue
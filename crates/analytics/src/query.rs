@@ -448,6 +448,12 @@ impl<T: AnalyticsDataSource> ToSql<T> for ProfileId {
     }
 }
 
+impl<T: AnalyticsDataSource> ToSql<T> for common_utils::id_type::MerchantConnectorAccountId {
+    fn to_sql(&self, _table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
+        Ok(self.get_string_repr().to_owned())
+    }
+}
+
 impl<T: AnalyticsDataSource> ToSql<T> for &common_utils::id_type::PaymentId {
     fn to_sql(&self, _table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
         Ok(self.get_string_repr().to_owned())
@@ -347,6 +347,7 @@ pub async fn get_filters(
             RefundDimensions::Connector => fil.connector,
             RefundDimensions::RefundType => fil.refund_type.map(|i| i.as_ref().to_string()),
             RefundDimensions::ProfileId => fil.profile_id,
+            RefundDimensions::MerchantConnectorId => fil.merchant_connector_id,
             RefundDimensions::RefundReason => fil.refund_reason,
             RefundDimensions::RefundErrorMessage => fil.refund_error_message,
         })
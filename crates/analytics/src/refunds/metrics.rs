@@ -31,6 +31,7 @@ pub struct RefundMetricRow {
     pub connector: Option<String>,
     pub refund_type: Option<DBEnumWrapper<RefundType>>,
     pub profile_id: Option<String>,
+    pub merchant_connector_id: Option<String>,
     pub refund_reason: Option<String>,
     pub refund_error_message: Option<String>,
     pub total: Option<bigdecimal::BigDecimal>,
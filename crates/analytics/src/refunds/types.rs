@@ -42,6 +42,15 @@ where
                 .attach_printable("Error adding profile id filter")?;
         }
 
+        if !self.merchant_connector_id.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    RefundDimensions::MerchantConnectorId,
+                    &self.merchant_connector_id,
+                )
+                .attach_printable("Error adding merchant connector id filter")?;
+        }
+
         if !self.refund_reason.is_empty() {
             builder
                 .add_filter_in_range_clause(RefundDimensions::RefundReason, &self.refund_reason)
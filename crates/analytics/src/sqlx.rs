@@ -580,6 +580,11 @@ impl<'a> FromRow<'a, PgRow> for super::refunds::metrics::RefundMetricRow {
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
         })?;
+        let merchant_connector_id: Option<String> =
+            row.try_get("merchant_connector_id").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
         let refund_reason: Option<String> = row.try_get("refund_reason").or_else(|e| match e {
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
@@ -610,6 +615,7 @@ impl<'a> FromRow<'a, PgRow> for super::refunds::metrics::RefundMetricRow {
             connector,
             refund_type,
             profile_id,
+            merchant_connector_id,
             refund_reason,
             refund_error_message,
             total,
@@ -1186,6 +1192,11 @@ impl<'a> FromRow<'a, PgRow> for super::refunds::filters::RefundFilterRow {
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
         })?;
+        let merchant_connector_id: Option<String> =
+            row.try_get("merchant_connector_id").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
         let refund_reason: Option<String> = row.try_get("refund_reason").or_else(|e| match e {
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
@@ -1201,6 +1212,7 @@ impl<'a> FromRow<'a, PgRow> for super::refunds::filters::RefundFilterRow {
             connector,
             refund_type,
             profile_id,
+            merchant_connector_id,
             refund_reason,
             refund_error_message,
         })
@@ -1232,6 +1244,11 @@ impl<'a> FromRow<'a, PgRow> for super::refunds::distribution::RefundDistribution
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
         })?;
+        let merchant_connector_id: Option<String> =
+            row.try_get("merchant_connector_id").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
         let total: Option<bigdecimal::BigDecimal> = row.try_get("total").or_else(|e| match e {
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
@@ -1262,6 +1279,7 @@ impl<'a> FromRow<'a, PgRow> for super::refunds::distribution::RefundDistribution
             connector,
             refund_type,
             profile_id,
+            merchant_connector_id,
             total,
             count,
             refund_reason,
@@ -715,6 +715,11 @@ pub struct WebhookDetails {
     #[cfg(feature = "payouts")]
     #[schema(value_type = Option<Vec<PayoutStatus>>, example = json!(["success", "failed"]))]
     pub payout_statuses_enabled: Option<Vec<api_enums::PayoutStatus>>,
+
+    /// The version of the payload of the outgoing webhooks sent to this profile, version 1 is used if not provided.
+    /// Version 2 adds the error category and recommended action to refund webhooks, version 3 adds the split refund summary
+    #[schema(minimum = 1, maximum = 3, example = 1)]
+    pub payload_version: Option<u8>,
 }
 
 impl WebhookDetails {
@@ -752,6 +757,18 @@ impl WebhookDetails {
             }
         }
 
+        if let Some(payload_version) = self.payload_version {
+            let supported_versions = crate::consts::DEFAULT_OUTGOING_WEBHOOK_PAYLOAD_VERSION
+                ..=crate::consts::LATEST_OUTGOING_WEBHOOK_PAYLOAD_VERSION;
+            if !supported_versions.contains(&payload_version) {
+                return Err(format!(
+                    "Invalid webhook payload version provided: {payload_version}, supported versions are {} to {}",
+                    supported_versions.start(),
+                    supported_versions.end()
+                ));
+            }
+        }
+
         Ok(())
     }
 }
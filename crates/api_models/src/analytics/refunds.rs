@@ -42,6 +42,8 @@ pub struct RefundFilters {
     #[serde(default)]
     pub profile_id: Vec<id_type::ProfileId>,
     #[serde(default)]
+    pub merchant_connector_id: Vec<id_type::MerchantConnectorAccountId>,
+    #[serde(default)]
     pub refund_reason: Vec<String>,
     #[serde(default)]
     pub refund_error_message: Vec<String>,
@@ -69,6 +71,7 @@ pub enum RefundDimensions {
     Connector,
     RefundType,
     ProfileId,
+    MerchantConnectorId,
     RefundReason,
     RefundErrorMessage,
 }
@@ -176,6 +179,7 @@ pub struct RefundMetricsBucketIdentifier {
     pub connector: Option<String>,
     pub refund_type: Option<String>,
     pub profile_id: Option<String>,
+    pub merchant_connector_id: Option<String>,
     pub refund_reason: Option<String>,
     pub refund_error_message: Option<String>,
     #[serde(rename = "time_range")]
@@ -192,6 +196,7 @@ impl Hash for RefundMetricsBucketIdentifier {
         self.connector.hash(state);
         self.refund_type.hash(state);
         self.profile_id.hash(state);
+        self.merchant_connector_id.hash(state);
         self.refund_reason.hash(state);
         self.refund_error_message.hash(state);
         self.time_bucket.hash(state);
@@ -215,6 +220,7 @@ impl RefundMetricsBucketIdentifier {
         connector: Option<String>,
         refund_type: Option<String>,
         profile_id: Option<String>,
+        merchant_connector_id: Option<String>,
         refund_reason: Option<String>,
         refund_error_message: Option<String>,
         normalized_time_range: TimeRange,
@@ -225,6 +231,7 @@ impl RefundMetricsBucketIdentifier {
             connector,
             refund_type,
             profile_id,
+            merchant_connector_id,
             refund_reason,
             refund_error_message,
             time_bucket: normalized_time_range,
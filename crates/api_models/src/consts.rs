@@ -3,3 +3,11 @@ pub const MAX_ORDER_FULFILLMENT_EXPIRY: i64 = 1800;
 
 /// Min payment intent fulfillment expiry
 pub const MIN_ORDER_FULFILLMENT_EXPIRY: i64 = 60;
+
+/// Version of the outgoing webhook payload sent to profiles which have not opted into a newer
+/// version, this is the payload sent before payloads were versioned
+pub const DEFAULT_OUTGOING_WEBHOOK_PAYLOAD_VERSION: u8 = 1;
+
+/// Latest version of the outgoing webhook payload. Version 2 adds the error category and
+/// recommended action to refund webhooks, version 3 adds the split refund summary
+pub const LATEST_OUTGOING_WEBHOOK_PAYLOAD_VERSION: u8 = 3;
@@ -77,6 +77,14 @@ impl_api_event_type!(
         MerchantConnectorId,
         MandateResponse,
         MandateRevokedResponse,
+        MandateRevokeRequest,
+        MandateUpdateRequest,
+        MandateRevocationEligibilityResponse,
+        CustomerMandatesRevokedResponse,
+        MandateConnectorReferenceBackfillRequest,
+        MandateConnectorReferenceBackfillResponse,
+        PendingMandateSweepRequest,
+        PendingMandateSweepResponse,
         RetrievePaymentLinkRequest,
         PaymentLinkListConstraints,
         MandateId,
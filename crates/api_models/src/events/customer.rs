@@ -3,6 +3,8 @@ use common_utils::events::{ApiEventMetric, ApiEventsType};
 use crate::customers::{
     CustomerDeleteResponse, CustomerRequest, CustomerResponse, CustomerUpdateRequestInternal,
 };
+#[cfg(feature = "v1")]
+use crate::mandates::CustomerMandateListRequestInternal;
 
 #[cfg(feature = "v1")]
 impl ApiEventMetric for CustomerDeleteResponse {
@@ -73,3 +75,12 @@ impl ApiEventMetric for CustomerUpdateRequestInternal {
         })
     }
 }
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for CustomerMandateListRequestInternal {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer_id.clone(),
+        })
+    }
+}
@@ -1,12 +1,18 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use crate::refunds::{
-    self, RefundAggregateResponse, RefundListFilters, RefundListMetaData, RefundListRequest,
-    RefundListResponse,
+    self, RefundAggregateResponse, RefundLimitsConfigRequest, RefundLimitsConfigResponse,
+    RefundListFilters, RefundListMetaData, RefundListRequest, RefundListResponse,
+    RefundStatusCountRequest, RefundStatusCountResponse, RefundsModifiedSinceRequest,
+    RefundsModifiedSinceResponse,
 };
 #[cfg(feature = "v1")]
 use crate::refunds::{
-    RefundManualUpdateRequest, RefundRequest, RefundUpdateRequest, RefundsRetrieveRequest,
+    RefundBatchCreateRequest, RefundBatchResponse, RefundBatchRetrieveRequest,
+    RefundCircuitBreakerResetRequest, RefundCircuitBreakerResetResponse, RefundManualUpdateRequest,
+    RefundMerchantConnectorIdBackfillRequest, RefundMerchantConnectorIdBackfillResponse,
+    RefundRequest, RefundUpdateRequest, RefundsRetrieveRequest, RefundsStaleSyncRequest,
+    RefundsStaleSyncResponse,
 };
 
 #[cfg(feature = "v1")]
@@ -82,6 +88,69 @@ impl ApiEventMetric for RefundManualUpdateRequest {
     }
 }
 
+#[cfg(feature = "v1")]
+impl ApiEventMetric for RefundMerchantConnectorIdBackfillRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for RefundMerchantConnectorIdBackfillResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for RefundsStaleSyncRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for RefundsStaleSyncResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for RefundCircuitBreakerResetRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for RefundCircuitBreakerResetResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for RefundBatchCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for RefundBatchRetrieveRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for RefundBatchResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
 impl ApiEventMetric for RefundListRequest {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::ResourceListAPI)
@@ -94,12 +163,63 @@ impl ApiEventMetric for RefundListResponse {
     }
 }
 
+#[cfg(feature = "v1")]
+impl ApiEventMetric for refunds::RefundExportRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for RefundsModifiedSinceRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for RefundsModifiedSinceResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for RefundLimitsConfigRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for RefundLimitsConfigResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::BusinessProfile {
+            profile_id: self.profile_id.clone(),
+        })
+    }
+}
+
 impl ApiEventMetric for RefundAggregateResponse {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::ResourceListAPI)
     }
 }
 
+impl ApiEventMetric for RefundStatusCountRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for RefundStatusCountResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for refunds::RefundReconciliationRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
 impl ApiEventMetric for RefundListMetaData {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::ResourceListAPI)
@@ -1,4 +1,5 @@
 use common_types::payments as common_payments_types;
+use common_utils::pii;
 use masking::Secret;
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
@@ -11,6 +12,17 @@ pub struct MandateId {
     pub mandate_id: String,
 }
 
+#[derive(Default, Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MandateRevokeRequest {
+    #[serde(skip)]
+    pub mandate_id: String,
+    /// Whether to revoke the mandate locally when the connector fails to revoke it, such as when
+    /// the connector is unreachable or does not know the mandate anymore
+    #[serde(default)]
+    pub force: bool,
+}
+
 #[derive(Default, Debug, Deserialize, Serialize, ToSchema)]
 pub struct MandateRevokedResponse {
     /// The identifier for mandate
@@ -24,15 +36,167 @@ pub struct MandateRevokedResponse {
     /// If there was an error while calling the connector the error message is received here
     #[schema(example = "Failed while verifying the card")]
     pub error_message: Option<String>,
+    /// Whether the mandate was only revoked locally as the connector failed to revoke it, which
+    /// only happens on forced revocations
+    #[serde(default)]
+    pub revoked_locally_only: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct MandateRevocationEligibilityResponse {
+    /// The identifier for mandate
+    pub mandate_id: String,
+    /// The status for mandates
+    #[schema(value_type = MandateStatus)]
+    pub status: api_enums::MandateStatus,
+    /// Whether the mandate can be revoked
+    pub verdict: MandateRevocationVerdict,
+    /// The reason the mandate can not be revoked, or can only be revoked locally
+    pub reason: Option<MandateRevocationReason>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MandateRevocationVerdict {
+    /// The mandate is revoked at the connector and marked as revoked
+    Eligible,
+    /// The mandate is only marked as revoked, without revoking it at the connector
+    EligibleLocalOnly,
+    /// The mandate can not be revoked
+    Ineligible,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MandateRevocationReason {
+    /// The mandate has already been revoked
+    AlreadyRevoked,
+    /// The connector does not support revoking mandates
+    ConnectorRevokeNotSupported,
+    /// The mandate has no reference of the mandate at the connector
+    MissingConnectorMandateReference,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct CustomerMandatesRevokedResponse {
+    /// The identifier for the customer whose mandates were revoked
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: common_utils::id_type::CustomerId,
+    /// The outcome of the revocation for each mandate of the customer
+    pub mandates: Vec<CustomerMandateRevocationResult>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct CustomerMandateRevocationResult {
+    /// The identifier for mandate
+    pub mandate_id: String,
+    /// The status of the mandate after the revocation
+    #[schema(value_type = MandateStatus)]
+    pub status: api_enums::MandateStatus,
+    /// The outcome of the revocation for the mandate
+    pub outcome: MandateRevocationOutcome,
+    /// The connector with which the mandate was set up
+    pub connector: String,
+    /// If the revocation failed the code of the error is received here
+    #[schema(example = "E0001")]
+    pub error_code: Option<String>,
+    /// If the revocation failed the message of the error is received here
+    #[schema(example = "Mandate does not exist at the connector")]
+    pub error_message: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MandateRevocationOutcome {
+    /// The mandate was revoked
+    Revoked,
+    /// The mandate had already been revoked and was left as it is
+    Skipped,
+    /// The mandate could not be revoked
+    Failed,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct MandateConnectorReferenceBackfillRequest {
+    /// Merchant ID whose mandates are to be backfilled
+    pub merchant_id: common_utils::id_type::MerchantId,
+    /// The mandate id after which the batch starts, pass the `next_starting_after` of the previous batch to resume the backfill
+    pub starting_after: Option<String>,
+    /// The number of mandates to be processed in the batch
+    pub batch_size: Option<u32>,
+    /// Whether to only report the outcome for each mandate, without updating them
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MandateConnectorReferenceBackfillResponse {
+    /// The number of active mandates without a connector mandate id which were processed in the batch
+    pub mandates_processed: usize,
+    /// The number of mandates which were updated with the connector mandate id of their original payment
+    pub mandates_updated: usize,
+    /// Whether the batch was processed without updating the mandates
+    pub dry_run: bool,
+    /// The outcome of the backfill for each processed mandate
+    pub outcomes: Vec<MandateConnectorReferenceBackfillOutcome>,
+    /// The mandate id to resume the backfill from, absent once all mandates have been processed
+    pub next_starting_after: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MandateConnectorReferenceBackfillOutcome {
+    /// The identifier for mandate
+    pub mandate_id: String,
+    /// The outcome of the backfill for the mandate
+    pub status: MandateConnectorReferenceBackfillStatus,
 }
 
-#[derive(Default, Debug, Deserialize, Serialize, ToSchema, Clone)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MandateConnectorReferenceBackfillStatus {
+    /// The mandate was updated with the connector mandate id
+    Updated,
+    /// The mandate would be updated with the connector mandate id, reported on dry runs
+    WouldUpdate,
+    /// The mandate has no original payment to resolve the connector mandate id from
+    OriginalPaymentMissing,
+    /// No payment attempt of the original payment set up the mandate
+    PaymentAttemptNotFound,
+    /// The payment attempt which set up the mandate has no connector mandate reference stored
+    MandateReferenceNotFound,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PendingMandateSweepRequest {
+    /// Merchant ID whose stale pending mandates are to be marked inactive
+    pub merchant_id: common_utils::id_type::MerchantId,
+    /// The number of mandates to be processed in the batch
+    pub batch_size: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PendingMandateSweepResponse {
+    /// The identifiers of the mandates which were marked inactive after remaining pending past the timeout
+    pub mandate_ids: Vec<String>,
+    /// Whether more stale pending mandates may remain, in which case the sweep is to be repeated
+    pub has_more: bool,
+}
+
+#[derive(Default, Debug, Deserialize, Serialize, ToSchema, Clone, PartialEq)]
 pub struct MandateResponse {
     /// The identifier for mandate
     pub mandate_id: String,
     /// The status for mandates
     #[schema(value_type = MandateStatus)]
     pub status: api_enums::MandateStatus,
+    /// The identifier for the customer who accepted the mandate
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: common_utils::id_type::CustomerId,
+    /// The connector with which the mandate was set up
+    #[schema(example = "stripe")]
+    pub connector: String,
     /// The identifier for payment method
     pub payment_method_id: String,
     /// The payment method
@@ -44,9 +208,24 @@ pub struct MandateResponse {
     /// Details about the customer’s acceptance
     #[schema(value_type = Option<CustomerAcceptance>)]
     pub customer_acceptance: Option<common_payments_types::CustomerAcceptance>,
+    /// Metadata set by the merchant on the mandate, such as their internal contract ids
+    #[schema(value_type = Option<Object>, example = r#"{ "contract_id": "CON_1234" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MandateUpdateRequest {
+    #[serde(skip)]
+    pub mandate_id: String,
+    /// Metadata to be merged into the metadata of the mandate. Keys with a `null` value are removed
+    /// from the metadata. You can specify up to 50 keys, with key names up to 40 characters long
+    /// and values up to 500 characters long.
+    #[schema(value_type = Object, example = r#"{ "contract_id": "CON_1234" }"#)]
+    pub metadata: pii::SecretSerdeValue,
 }
 
-#[derive(Default, Debug, Deserialize, Serialize, ToSchema, Clone)]
+#[derive(Default, Debug, Deserialize, Serialize, ToSchema, Clone, PartialEq)]
 pub struct MandateCardDetails {
     /// The last 4 digits of card
     pub last4_digits: Option<String>,
@@ -83,7 +262,7 @@ pub struct MandateCardDetails {
     pub nick_name: Option<Secret<String>>,
 }
 
-#[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, ToSchema, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct MandateListConstraints {
     /// limit on the number of objects to return
@@ -113,6 +292,35 @@ pub struct MandateListConstraints {
     #[schema(example = "2022-09-10T10:11:12Z")]
     #[serde(rename = "created_time.gte")]
     pub created_time_gte: Option<PrimitiveDateTime>,
+    /// Time less than or equals to the mandate modified time
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(rename = "modified_time.lte")]
+    pub modified_time_lte: Option<PrimitiveDateTime>,
+    /// Time greater than or equals to the mandate modified time
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(rename = "modified_time.gte")]
+    pub modified_time_gte: Option<PrimitiveDateTime>,
+    /// The field to sort the mandates by, defaults to `created_at`
+    pub sort_by: Option<MandateSortBy>,
+    /// The order in which to sort the mandates, defaults to descending
+    pub sort_order: Option<crate::payments::SortBy>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Serialize)]
+pub struct CustomerMandateListRequestInternal {
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub constraints: MandateListConstraints,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MandateSortBy {
+    /// Sort by the time at which the mandate was created
+    #[default]
+    CreatedAt,
+    /// Sort by the time at which the mandate was last modified
+    ModifiedAt,
 }
 
 /// Details required for recurring payment
@@ -1180,6 +1180,14 @@ pub struct PaymentsRequest {
 
     /// If enabled, provides whole connector response
     pub all_keys_required: Option<bool>,
+
+    /// Allows a payment against a mandate to be made before the interval given by the mandate frequency has elapsed since the mandate was last charged. To be used for legitimate off-cycle charges
+    #[schema(default = false, example = false)]
+    pub bypass_mandate_frequency_check: Option<bool>,
+
+    /// If enabled, provides the mandate created or used by the payment in the response
+    #[remove_in(PaymentsUpdateRequest)]
+    pub expand_mandate: Option<bool>,
 }
 
 #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -1865,6 +1873,11 @@ pub struct MandateData {
     pub customer_acceptance: Option<common_payments_types::CustomerAcceptance>,
     /// A way to select the type of mandate used
     pub mandate_type: Option<MandateType>,
+    /// Metadata to be set on the mandate, such as the merchant's internal contract ids. You can
+    /// specify up to 50 keys, with key names up to 40 characters long and values up to 500
+    /// characters long.
+    #[schema(value_type = Option<Object>, example = r#"{ "contract_id": "CON_1234" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
 }
 
 #[derive(Clone, Eq, PartialEq, Copy, Debug, Default, serde::Serialize, serde::Deserialize)]
@@ -4801,7 +4814,9 @@ pub struct ReceiverDetails {
 }
 
 #[cfg(feature = "v1")]
-#[derive(Clone, Debug, PartialEq, serde::Serialize, ToSchema, router_derive::PolymorphicSchema)]
+#[derive(
+    Clone, Debug, Default, PartialEq, serde::Serialize, ToSchema, router_derive::PolymorphicSchema,
+)]
 #[generate_schemas(PaymentsCreateResponseOpenApi)]
 pub struct PaymentsResponse {
     /// Unique identifier for the payment. This ensures idempotency for multiple payments
@@ -4899,6 +4914,15 @@ pub struct PaymentsResponse {
     #[schema(max_length = 255, example = "mandate_iwer89rnjef349dni3")]
     pub mandate_id: Option<String>,
 
+    /// The mandate created or used by this payment, provided when `expand_mandate` is enabled
+    #[schema(value_type = Option<MandateResponse>)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mandate: Option<Box<crate::mandates::MandateResponse>>,
+
+    /// Set when `expand_mandate` is enabled but the mandate is not yet available to be expanded, only `mandate_id` is provided in this case
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mandate_expansion_pending: Option<bool>,
+
     /// Provided mandate information for creating a mandate
     pub mandate_data: Option<MandateData>,
 
@@ -6492,6 +6516,8 @@ pub struct PaymentsRetrieveRequest {
     pub expand_attempts: Option<bool>,
     /// If enabled, provides whole connector response
     pub all_keys_required: Option<bool>,
+    /// If enabled, provides the mandate created or used by the payment
+    pub expand_mandate: Option<bool>,
 }
 
 #[derive(Debug, Default, PartialEq, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
@@ -7069,6 +7095,8 @@ pub enum SessionToken {
     Paze(Box<PazeSessionTokenResponse>),
     /// The sessions response structure for ClickToPay
     ClickToPay(Box<ClickToPaySessionResponse>),
+    /// The session response structure for Amazon Pay
+    AmazonPay(Box<AmazonPaySessionTokenResponse>),
     /// Whenever there is no session token response or an error in session response
     NoSessionTokenReceived,
 }
@@ -7252,6 +7280,18 @@ pub struct KlarnaSessionTokenResponse {
     pub session_id: String,
 }
 
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, ToSchema)]
+pub struct AmazonPaySessionTokenResponse {
+    /// The identifier of the checkout session created with Amazon Pay
+    pub checkout_session_id: String,
+    /// The payload the Amazon Pay button is rendered with
+    pub payload: String,
+    /// The signature of the payload
+    pub signature: String,
+    /// The identifier of the public key the payload is signed with
+    pub public_key_id: String,
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, ToSchema)]
 #[serde(rename_all = "lowercase")]
 pub struct PaypalSessionTokenResponse {
@@ -7512,6 +7552,8 @@ pub struct PaymentRetrieveBody {
     pub expand_attempts: Option<bool>,
     /// If enabled, provides whole connector response
     pub all_keys_required: Option<bool>,
+    /// If enabled, provides the mandate created or used by the payment
+    pub expand_mandate: Option<bool>,
 }
 
 #[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
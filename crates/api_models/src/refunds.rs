@@ -1,7 +1,10 @@
 use std::collections::HashMap;
 
 pub use common_utils::types::MinorUnit;
-use common_utils::{pii, types::TimeRange};
+use common_utils::{
+    pii,
+    types::{StringMajorUnit, TimeRange},
+};
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
@@ -59,6 +62,10 @@ pub struct RefundRequest {
     /// Charge specific fields for controlling the revert of funds from either platform or connected account
     #[schema(value_type = Option<SplitRefund>)]
     pub split_refunds: Option<common_types::refunds::SplitRefund>,
+
+    /// The merchant_connector_id of an enabled processor account of the same connector as the payment, through which the refund is to be processed instead of the payment's processor account
+    #[schema(value_type = Option<String>, example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
 }
 
 #[cfg(feature = "v2")]
@@ -112,6 +119,8 @@ pub struct RefundsCreateRequest {
 #[derive(Default, Debug, Clone, Deserialize)]
 pub struct RefundsRetrieveBody {
     pub force_sync: Option<bool>,
+    /// Number of seconds to wait for the refund to reach a terminal status before responding
+    pub wait_seconds: Option<u64>,
 }
 
 #[cfg(feature = "v2")]
@@ -178,7 +187,7 @@ pub struct RefundUpdateRequest {
     #[schema(max_length = 255, example = "Customer returned the product")]
     pub reason: Option<String>,
 
-    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object. The keys are merged into the metadata of the refund, a key set to `null` is deleted.
     #[schema(value_type  = Option<Object>, example = r#"{ "city": "NY", "unit": "245" }"#)]
     pub metadata: Option<pii::SecretSerdeValue>,
 }
@@ -191,7 +200,7 @@ pub struct RefundMetadataUpdateRequest {
     #[schema(max_length = 255, example = "Customer returned the product")]
     pub reason: Option<String>,
 
-    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object. The keys are merged into the metadata of the refund, a key set to `null` is deleted.
     #[schema(value_type  = Option<Object>, example = r#"{ "city": "NY", "unit": "245" }"#)]
     pub metadata: Option<pii::SecretSerdeValue>,
 }
@@ -212,6 +221,169 @@ pub struct RefundManualUpdateRequest {
     pub error_message: Option<String>,
 }
 
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RefundMerchantConnectorIdBackfillRequest {
+    /// Merchant ID whose refunds are to be backfilled
+    pub merchant_id: common_utils::id_type::MerchantId,
+    /// The refund id after which the batch starts, pass the `next_starting_after` of the previous batch to resume the backfill
+    pub starting_after: Option<String>,
+    /// The number of refunds to be processed in the batch
+    pub batch_size: Option<u32>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Serialize)]
+pub struct RefundMerchantConnectorIdBackfillResponse {
+    /// The number of refunds without a merchant connector id which were processed in the batch
+    pub refunds_processed: usize,
+    /// The number of refunds which were updated with the merchant connector id of their payment attempt
+    pub refunds_updated: usize,
+    /// The refunds for which no merchant connector id could be resolved
+    pub unresolved_refund_ids: Vec<String>,
+    /// The refund id to resume the backfill from, absent once all refunds have been processed
+    pub next_starting_after: Option<String>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RefundsStaleSyncRequest {
+    /// Merchant ID whose stale refunds are to be synced
+    pub merchant_id: common_utils::id_type::MerchantId,
+    /// The time in seconds since the last update after which a pending refund is considered stale
+    pub older_than_in_secs: Option<i64>,
+    /// The maximum number of refunds to be synced
+    pub limit: Option<u32>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct RefundsStaleSyncResponse {
+    /// The number of stale refunds which were synced with the connector
+    pub refunds_synced: usize,
+    /// The number of synced refunds whose status changed
+    pub refunds_updated: usize,
+    /// The number of stale refunds which could not be synced
+    pub refunds_failed: usize,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Serialize)]
+pub struct RefundCircuitBreakerResetRequest {
+    /// The merchant connector account whose refund circuit breaker is to be reset
+    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Serialize)]
+pub struct RefundCircuitBreakerResetResponse {
+    /// The merchant connector account whose refund circuit breaker was reset
+    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    /// Whether the refunds of the merchant connector account were suspended before the reset
+    pub was_suspended: bool,
+    /// The number of consecutive credential or configuration failures recorded before the reset
+    pub consecutive_failures: u32,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, ToSchema, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RefundBatchCreateRequest {
+    /// The refunds to be initiated as part of the batch
+    #[schema(min_items = 1)]
+    pub entries: Vec<RefundBatchEntryRequest>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, ToSchema, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RefundBatchEntryRequest {
+    /// The payment id against which refund is to be initiated
+    #[schema(
+        max_length = 30,
+        min_length = 30,
+        example = "pay_mbabizu24mvu3mela5njyhpit4",
+        value_type = String,
+    )]
+    pub payment_id: common_utils::id_type::PaymentId,
+
+    /// Amount to be refunded in the lowest denomination of the currency. If not provided, this will default to the full refundable amount of the payment
+    #[schema(value_type = Option<i64>, minimum = 100, example = 6540)]
+    pub amount: Option<MinorUnit>,
+
+    /// Reason for the refund
+    #[schema(max_length = 255, example = "Event cancelled")]
+    pub reason: Option<String>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RefundBatchRetrieveRequest {
+    /// The identifier of the refund batch
+    pub batch_id: String,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct RefundBatchResponse {
+    /// The identifier of the refund batch
+    pub batch_id: String,
+    /// The status of the batch
+    #[schema(value_type = RefundBatchStatus)]
+    pub status: enums::RefundBatchStatus,
+    /// Total number of entries in the batch
+    pub total_entries: i32,
+    /// Number of entries in each state
+    pub summary: RefundBatchSummary,
+    /// The state of every entry of the batch, in the order they were submitted
+    pub entries: Vec<RefundBatchEntryResponse>,
+    /// The timestamp at which the batch was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// The timestamp at which the batch was last updated
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub updated_at: PrimitiveDateTime,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Default, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct RefundBatchSummary {
+    /// Entries yet to be submitted
+    pub pending: u32,
+    /// Entries that failed the upfront eligibility checks
+    pub invalid: u32,
+    /// Entries for which a refund was created
+    pub submitted: u32,
+    /// Entries for which the refund creation failed
+    pub failed: u32,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct RefundBatchEntryResponse {
+    /// Position of the entry in the batch request
+    pub entry_index: i32,
+    /// The payment id against which refund was requested
+    #[schema(value_type = String)]
+    pub payment_id: common_utils::id_type::PaymentId,
+    /// The refund id reserved for this entry
+    pub refund_id: String,
+    /// The requested refund amount
+    #[schema(value_type = Option<i64>, example = 6540)]
+    pub amount: Option<MinorUnit>,
+    /// The status of the entry within the batch
+    #[schema(value_type = RefundBatchEntryStatus)]
+    pub status: enums::RefundBatchEntryStatus,
+    /// The status of the refund created for this entry, if any
+    pub refund_status: Option<RefundStatus>,
+    /// The code for the error, if the entry is invalid or failed
+    pub error_code: Option<String>,
+    /// The error message, if the entry is invalid or failed
+    pub error_message: Option<String>,
+}
+
 #[cfg(feature = "v1")]
 /// To indicate whether to refund needs to be instant or scheduled
 #[derive(
@@ -247,6 +419,12 @@ pub struct RefundResponse {
     /// The refund amount, which should be less than or equal to the total payment amount. Amount for the payment in lowest denomination of the currency. (i.e) in cents for USD denomination, in paisa for INR denomination etc
     #[schema(value_type = i64 , minimum = 100, example = 6540)]
     pub amount: MinorUnit,
+    /// The refund amount in the major unit of the currency, formatted the same way it is sent to connectors
+    #[schema(value_type = Option<String>, example = "65.40")]
+    pub refund_amount_formatted: Option<StringMajorUnit>,
+    /// The total amount of the payment in the major unit of the currency, formatted the same way it is sent to connectors
+    #[schema(value_type = Option<String>, example = "100.00")]
+    pub total_amount_formatted: Option<StringMajorUnit>,
     /// The three-letter ISO currency code
     pub currency: String,
     /// The status for refund
@@ -286,6 +464,17 @@ pub struct RefundResponse {
     pub issuer_error_code: Option<String>,
     /// Error message received from the issuer in case of failed refunds
     pub issuer_error_message: Option<String>,
+    /// The normalized reason for the failure, present only when the refund has failed or is under manual review
+    #[schema(value_type = Option<RefundErrorCategory>, example = "processor_declined")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_category: Option<enums::RefundErrorCategory>,
+    /// The action recommended to the merchant, present only when the refund has failed or is under manual review
+    #[schema(value_type = Option<RefundRecommendedAction>, example = "retry_later")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recommended_action: Option<enums::RefundRecommendedAction>,
+    /// The flow through which the refund was created
+    #[schema(value_type = RefundOrigin, example = "api")]
+    pub origin: enums::RefundOrigin,
 }
 
 #[cfg(feature = "v1")]
@@ -295,6 +484,65 @@ impl RefundResponse {
     }
 }
 
+/// The refund object of the refund outgoing webhooks, whose shape depends on the version of the
+/// outgoing webhook payload
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(untagged)]
+pub enum RefundWebhookResponse {
+    Refund(Box<RefundResponse>),
+    WithSplitRefundsSummary(Box<RefundWebhookResponseV3>),
+}
+
+#[cfg(feature = "v1")]
+impl RefundWebhookResponse {
+    /// Builds the refund object of the given version of the outgoing webhook payload, the latest
+    /// version is used for versions newer than the ones known here
+    pub fn new(refund: RefundResponse, payload_version: u8) -> Self {
+        match payload_version {
+            // The error category and recommended action were added in version 2
+            ..=1 => Self::Refund(Box::new(RefundResponse {
+                error_category: None,
+                recommended_action: None,
+                ..refund
+            })),
+            2 => Self::Refund(Box::new(refund)),
+            _ => Self::WithSplitRefundsSummary(Box::new(refund.into())),
+        }
+    }
+
+    /// The refund, which is sent as is in all the versions of the refund object
+    pub fn get_refund(&self) -> &RefundResponse {
+        match self {
+            Self::Refund(refund) => refund,
+            Self::WithSplitRefundsSummary(refund) => &refund.refund,
+        }
+    }
+}
+
+/// The refund object of version 3 of the outgoing webhook payload, which adds a summary of the
+/// split refund to the refund
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RefundWebhookResponseV3 {
+    #[serde(flatten)]
+    pub refund: RefundResponse,
+    /// A summary of how the funds of a split refund are reverted, present only for split refunds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub split_refunds_summary: Option<SplitRefundSummary>,
+}
+
+#[cfg(feature = "v1")]
+impl From<RefundResponse> for RefundWebhookResponseV3 {
+    fn from(refund: RefundResponse) -> Self {
+        let split_refunds_summary = refund.split_refunds.as_ref().map(SplitRefundSummary::from);
+        Self {
+            refund,
+            split_refunds_summary,
+        }
+    }
+}
+
 #[cfg(feature = "v2")]
 #[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
 pub struct RefundResponse {
@@ -315,6 +563,12 @@ pub struct RefundResponse {
     /// The refund amount
     #[schema(value_type = i64 , minimum = 100, example = 6540)]
     pub amount: MinorUnit,
+    /// The refund amount in the major unit of the currency, formatted the same way it is sent to connectors
+    #[schema(value_type = Option<String>, example = "65.40")]
+    pub refund_amount_formatted: Option<StringMajorUnit>,
+    /// The total amount of the payment in the major unit of the currency, formatted the same way it is sent to connectors
+    #[schema(value_type = Option<String>, example = "100.00")]
+    pub total_amount_formatted: Option<StringMajorUnit>,
     /// The three-letter ISO currency code
     #[schema(value_type = Currency)]
     pub currency: common_enums::Currency,
@@ -327,6 +581,10 @@ pub struct RefundResponse {
     pub metadata: Option<pii::SecretSerdeValue>,
     /// The error details for the refund
     pub error_details: Option<RefundErrorDetails>,
+    /// Error code unified across the connectors is received here if there was an error while calling connector
+    pub unified_code: Option<String>,
+    /// Error message unified across the connectors is received here if there was an error while calling connector
+    pub unified_message: Option<String>,
     /// The timestamp at which refund is created
     #[serde(with = "common_utils::custom_serde::iso8601")]
     pub created_at: PrimitiveDateTime,
@@ -344,6 +602,16 @@ pub struct RefundResponse {
     pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
     /// The reference id of the connector for the refund
     pub connector_refund_reference_id: Option<String>,
+    /// A summary of how the funds of a split refund are reverted, present only for split refunds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub split_refunds_summary: Option<SplitRefundSummary>,
+    /// The flow through which the refund was created
+    #[schema(value_type = RefundOrigin, example = "api")]
+    pub origin: enums::RefundOrigin,
+    /// Error code received from the issuer in case of failed refunds
+    pub issuer_error_code: Option<String>,
+    /// Error message received from the issuer in case of failed refunds
+    pub issuer_error_message: Option<String>,
 }
 
 #[cfg(feature = "v2")]
@@ -353,6 +621,71 @@ impl RefundResponse {
     }
 }
 
+/// The processor specific kind of a split refund
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SplitRefundType {
+    StripeSplitRefund,
+    AdyenSplitRefund,
+    XenditSplitRefund,
+}
+
+/// A summary of a split refund. The account, store and sub-merchant identifiers of the split are
+/// left out so that they are not sent along with refund webhooks
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct SplitRefundSummary {
+    /// The processor specific kind of the split refund
+    #[schema(example = "stripe_split_refund")]
+    pub split_type: SplitRefundType,
+    /// Whether the application fee collected for the payment is reverted, for Stripe split refunds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revert_platform_fee: Option<bool>,
+    /// Whether the transfer made during the charge is reverted, for Stripe split refunds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revert_transfer: Option<bool>,
+    /// The number of split items the refund is split into, for Adyen split refunds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub split_items_count: Option<usize>,
+    /// The total amount of the split items, for Adyen split refunds
+    #[schema(value_type = Option<i64>, example = 6540)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub split_items_amount: Option<MinorUnit>,
+}
+
+impl From<&common_types::refunds::SplitRefund> for SplitRefundSummary {
+    fn from(split_refund: &common_types::refunds::SplitRefund) -> Self {
+        match split_refund {
+            common_types::refunds::SplitRefund::StripeSplitRefund(split_refund) => Self {
+                split_type: SplitRefundType::StripeSplitRefund,
+                revert_platform_fee: split_refund.revert_platform_fee,
+                revert_transfer: split_refund.revert_transfer,
+                split_items_count: None,
+                split_items_amount: None,
+            },
+            common_types::refunds::SplitRefund::AdyenSplitRefund(split_data) => Self {
+                split_type: SplitRefundType::AdyenSplitRefund,
+                revert_platform_fee: None,
+                revert_transfer: None,
+                split_items_count: Some(split_data.split_items.len()),
+                split_items_amount: Some(
+                    split_data
+                        .split_items
+                        .iter()
+                        .filter_map(|split_item| split_item.amount)
+                        .fold(MinorUnit::zero(), |total, amount| total + amount),
+                ),
+            },
+            common_types::refunds::SplitRefund::XenditSplitRefund(_) => Self {
+                split_type: SplitRefundType::XenditSplitRefund,
+                revert_platform_fee: None,
+                revert_transfer: None,
+                split_items_count: None,
+                split_items_amount: None,
+            },
+        }
+    }
+}
+
 #[cfg(feature = "v2")]
 #[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
 pub struct RefundErrorDetails {
@@ -391,6 +724,17 @@ pub struct RefundListRequest {
     /// The list of refund statuses to filter refunds list
     #[schema(value_type = Option<Vec<RefundStatus>>)]
     pub refund_status: Option<Vec<enums::RefundStatus>>,
+    /// The list of refund origins to filter refunds list
+    #[schema(value_type = Option<Vec<RefundOrigin>>)]
+    pub origin: Option<Vec<enums::RefundOrigin>>,
+    /// The list of unified error codes to filter refunds list
+    pub unified_code: Option<Vec<String>>,
+    /// The list of error codes returned by the issuer to filter refunds list
+    pub issuer_error_code: Option<Vec<String>>,
+    /// Whether to list only the refunds which have, or only those which do not have, a unified error code
+    pub has_unified_error: Option<bool>,
+    /// The `next_cursor` of the previous response, to list the refunds following the last refund of that response. The offset is ignored when a cursor is provided
+    pub cursor: Option<String>,
 }
 #[cfg(feature = "v2")]
 #[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
@@ -421,6 +765,9 @@ pub struct RefundListRequest {
     /// The list of refund statuses to filter refunds list
     #[schema(value_type = Option<Vec<RefundStatus>>)]
     pub refund_status: Option<Vec<enums::RefundStatus>>,
+    /// The list of refund origins to filter refunds list
+    #[schema(value_type = Option<Vec<RefundOrigin>>)]
+    pub origin: Option<Vec<enums::RefundOrigin>>,
 }
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, ToSchema)]
 pub struct RefundListResponse {
@@ -430,6 +777,169 @@ pub struct RefundListResponse {
     pub total_count: i64,
     /// The List of refund response object
     pub data: Vec<RefundResponse>,
+    /// The cursor to list the refunds following this page with, present when the page is full
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct RefundExportRequest {
+    /// The filters selecting the refunds to export, the limit and offset apply as in the refunds list
+    #[serde(flatten)]
+    pub filters: RefundListRequest,
+    /// The format of the export
+    #[serde(default)]
+    pub format: RefundExportFormat,
+    /// The columns to include in the export, in the given order. All the exportable columns are included if not provided
+    #[schema(value_type = Option<Vec<RefundExportField>>, example = json!(["refund_id", "refund_arn", "status"]))]
+    pub fields: Option<Vec<String>>,
+}
+
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RefundExportFormat {
+    /// Comma separated values, with a header row naming the columns
+    #[default]
+    Csv,
+    /// One JSON object per line, keyed by the column names
+    Jsonl,
+}
+
+/// The columns which can be included in a refunds export, exported in this order by default
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Deserialize,
+    Serialize,
+    ToSchema,
+    strum::Display,
+    strum::EnumString,
+    strum::EnumIter,
+    strum::VariantNames,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RefundExportField {
+    RefundId,
+    PaymentId,
+    ProfileId,
+    Connector,
+    MerchantConnectorId,
+    ConnectorRefundId,
+    RefundArn,
+    Status,
+    Origin,
+    Currency,
+    Amount,
+    RefundAmountFormatted,
+    TotalAmount,
+    TotalAmountFormatted,
+    Reason,
+    ErrorCode,
+    ErrorMessage,
+    IssuerErrorCode,
+    IssuerErrorMessage,
+    UnifiedCode,
+    UnifiedMessage,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RefundsModifiedSinceRequest {
+    /// Refunds modified at or after this time are returned, all the refunds of the merchant are returned if neither this nor a cursor is provided. Ignored when a cursor is provided
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub since: Option<PrimitiveDateTime>,
+    /// Limit on the number of objects to return
+    pub limit: Option<i64>,
+    /// The `next_cursor` of the previous response, to fetch the refunds modified after the last refund of that response
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, ToSchema)]
+pub struct RefundsModifiedSinceResponse {
+    /// The number of refunds included in the list
+    pub count: usize,
+    /// The list of refund response objects, ordered by the time they were last modified
+    pub data: Vec<RefundResponse>,
+    /// Whether there are more refunds modified after the last refund in the list
+    pub has_more: bool,
+    /// The cursor to fetch the next refunds with. It is returned even when there are no more refunds, so that refunds modified later can be fetched from the same position
+    pub next_cursor: Option<String>,
+    /// The time the last refund in the list was modified, all refunds modified before this time have been returned by this or earlier responses
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub high_watermark: Option<PrimitiveDateTime>,
+}
+
+/// The refund limits of a profile. The amount limits are enforced on a best effort basis, refunds
+/// created concurrently for the profile can together exceed them
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RefundLimitsConfigRequest {
+    /// Maximum total amount, in the minor unit of `currency`, that can be refunded across the payments of the profile within a velocity window
+    #[schema(value_type = Option<i64>, example = 100000)]
+    pub max_refund_amount_per_window: Option<MinorUnit>,
+    /// The currency of the amount limits. Only refunds in this currency count towards the velocity limit, while refunds in any other currency are rejected when a per payment or daily limit is configured
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<enums::Currency>,
+    /// The size of the velocity window in seconds
+    #[schema(example = 3600)]
+    pub window_size_in_seconds: Option<i64>,
+    /// Maximum total amount, in the minor unit of `currency`, that can be refunded for a single payment
+    #[schema(value_type = Option<i64>, example = 5000)]
+    pub max_refund_amount_per_payment: Option<MinorUnit>,
+    /// Maximum total amount, in the minor unit of `currency`, that can be refunded across the payments of the profile within a calendar day in UTC
+    #[schema(value_type = Option<i64>, example = 100000)]
+    pub daily_refund_amount_limit: Option<MinorUnit>,
+    /// Maximum number of refunds that can be created against a single payment, the default limit applies if not provided
+    #[schema(example = 5)]
+    pub max_partial_refunds_per_payment: Option<u16>,
+    /// Number of days since the creation of a payment within which it can be refunded, the default refund window applies if not provided
+    #[schema(example = 180)]
+    pub refund_window_days: Option<i64>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct RefundLimitsConfigResponse {
+    /// The identifier for the profile the refund limits apply to
+    #[schema(value_type = String, max_length = 64, example = "pro_abcdefghijklmnopqrstuvwxyz")]
+    pub profile_id: common_utils::id_type::ProfileId,
+    /// Maximum total amount, in the minor unit of `currency`, that can be refunded across the payments of the profile within a velocity window
+    #[schema(value_type = Option<i64>, example = 100000)]
+    pub max_refund_amount_per_window: Option<MinorUnit>,
+    /// The currency of the amount limits
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<enums::Currency>,
+    /// The size of the velocity window in seconds
+    #[schema(example = 3600)]
+    pub window_size_in_seconds: Option<i64>,
+    /// Maximum total amount, in the minor unit of `currency`, that can be refunded for a single payment
+    #[schema(value_type = Option<i64>, example = 5000)]
+    pub max_refund_amount_per_payment: Option<MinorUnit>,
+    /// Maximum total amount, in the minor unit of `currency`, that can be refunded across the payments of the profile within a calendar day in UTC
+    #[schema(value_type = Option<i64>, example = 100000)]
+    pub daily_refund_amount_limit: Option<MinorUnit>,
+    /// Maximum number of refunds that can be created against a single payment
+    #[schema(example = 5)]
+    pub max_partial_refunds_per_payment: Option<u16>,
+    /// Number of days since the creation of a payment within which it can be refunded
+    #[schema(example = 180)]
+    pub refund_window_days: Option<i64>,
+    /// The time at which the refund limits were configured
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// The time at which the refund limits were last updated
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq, ToSchema)]
@@ -462,6 +972,113 @@ pub struct RefundAggregateResponse {
     pub status_with_count: HashMap<enums::RefundStatus, i64>,
 }
 
+/// The dimensions along which the refund status counts are grouped
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RefundStatusCountGroupBy {
+    Connector,
+    Currency,
+    ConnectorAndCurrency,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct RefundStatusCountRequest {
+    /// The time range of the refund creation time to count the refunds in
+    #[serde(flatten)]
+    pub time_range: TimeRange,
+    /// The dimensions to group the refund status counts by
+    pub group_by: RefundStatusCountGroupBy,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, ToSchema)]
+pub struct RefundStatusCount {
+    /// The connector of the refunds, present when grouping by connector
+    pub connector: Option<String>,
+    /// The currency of the refunds, present when grouping by currency
+    #[schema(value_type = Option<Currency>)]
+    pub currency: Option<enums::Currency>,
+    /// The status of the refunds
+    #[schema(value_type = RefundStatus)]
+    pub refund_status: enums::RefundStatus,
+    /// The number of refunds in the group
+    pub count: i64,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct RefundStatusCountResponse {
+    /// The refund counts per status in each group
+    pub status_counts: Vec<RefundStatusCount>,
+}
+
+/// The combinations of refund status and connector data that a refund is not expected to be in
+/// once it is older than the age threshold
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct RefundReconciliationRules {
+    /// The statuses in which a refund is expected to have been sent to the connector
+    #[schema(value_type = Vec<RefundStatus>)]
+    pub statuses_requiring_gateway: Vec<enums::RefundStatus>,
+    /// The statuses in which a refund is not expected to remain once the connector has returned a
+    /// refund id for it
+    #[schema(value_type = Vec<RefundStatus>)]
+    pub unresolved_statuses: Vec<enums::RefundStatus>,
+    /// The number of hours after the creation of a refund from which it is checked against the
+    /// rules
+    pub older_than_hours: u32,
+}
+
+impl Default for RefundReconciliationRules {
+    fn default() -> Self {
+        Self {
+            statuses_requiring_gateway: vec![enums::RefundStatus::Success],
+            unresolved_statuses: vec![enums::RefundStatus::Pending],
+            older_than_hours: 24,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+pub struct RefundReconciliationRequest {
+    /// The rules to check the refunds against, the defaults flag successful refunds never sent to
+    /// the connector and refunds pending for a day despite having a connector refund id
+    #[serde(default)]
+    pub rules: RefundReconciliationRules,
+    /// Whether to schedule a sync with the connector for every mismatched refund
+    #[serde(default)]
+    pub schedule_sync: bool,
+    /// The maximum number of mismatched refunds to export
+    pub limit: Option<i64>,
+}
+
+/// The rule of the reconciliation that a refund violates
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, ToSchema, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RefundMismatchRule {
+    /// The refund is in a status requiring it to have been sent to the connector, but never was
+    NotSentToGateway,
+    /// The connector has returned a refund id for the refund, but it is still unresolved
+    UnresolvedWithConnectorRefundId,
+}
+
+/// A refund whose status does not match its connector data, a row of the reconciliation export
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, ToSchema)]
+pub struct RefundMismatch {
+    /// The identifier of the refund
+    pub refund_id: String,
+    /// The identifier of the payment of the refund
+    #[schema(value_type = String)]
+    pub payment_id: common_utils::id_type::PaymentId,
+    /// The connector of the refund
+    pub connector: String,
+    /// The current status of the refund
+    #[schema(value_type = RefundStatus)]
+    pub refund_status: enums::RefundStatus,
+    /// The rule violated by the refund
+    pub violated_rule: RefundMismatchRule,
+    /// Whether a sync with the connector was scheduled for the refund
+    pub sync_scheduled: bool,
+}
+
 /// The status for refunds
 #[derive(
     Debug,
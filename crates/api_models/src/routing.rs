@@ -149,7 +149,6 @@ impl EuclidDirFilter for ConnectorSelection {
         DirKeyKind::BillingCountry,
         DirKeyKind::BusinessCountry,
         DirKeyKind::BusinessLabel,
-        DirKeyKind::MetaData,
         DirKeyKind::RewardType,
         DirKeyKind::VoucherType,
         DirKeyKind::CardRedirectType,
@@ -352,6 +351,14 @@ pub struct ProgramThreeDsDecisionRule {
     pub rules: Vec<ast::Rule<ThreeDSDecisionRule>>,
     #[schema(value_type = HashMap<String, serde_json::Value>)]
     pub metadata: std::collections::HashMap<String, serde_json::Value>,
+    /// Schema version the program was saved with. Programs saved before versioning was
+    /// introduced are treated as version 0
+    #[serde(default)]
+    pub version: u32,
+    /// Named value lists which the conditions of the rules can reference
+    #[serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    #[schema(value_type = HashMap<String, Segment>)]
+    pub segments: ast::Segments,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
@@ -1444,3 +1451,121 @@ pub enum RoutingResultSource {
     /// Inbuilt Hyperswitch Routing Engine
     HyperswitchRouting,
 }
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingImportValidationRequest {
+    /// The profile against whose connectors the routing program is validated
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    /// The routing program to be imported, serialized in the same format as exported by the
+    /// dashboard
+    pub program: String,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct RoutingImportValidationResponse {
+    /// Whether the routing program can be imported, which is the case when none of the
+    /// diagnostics is an error
+    pub is_valid: bool,
+    /// The findings of the validation, errors followed by warnings
+    pub diagnostics: Vec<AnalysisDiagnostic>,
+}
+
+impl RoutingImportValidationResponse {
+    pub fn new(diagnostics: Vec<AnalysisDiagnostic>) -> Self {
+        Self {
+            is_valid: diagnostics
+                .iter()
+                .all(|diagnostic| diagnostic.severity != DiagnosticSeverity::Error),
+            diagnostics,
+        }
+    }
+}
+
+/// A finding of the validation of a routing program
+#[derive(Clone, Debug, PartialEq, Serialize, ToSchema)]
+pub struct AnalysisDiagnostic {
+    /// Whether the finding prevents the program from being imported
+    pub severity: DiagnosticSeverity,
+    /// The kind of the finding
+    pub code: AnalysisDiagnosticCode,
+    /// Human readable description of the finding
+    pub message: String,
+    /// The name of the rule the finding relates to, absent for findings on the whole program
+    pub rule_name: Option<String>,
+    /// The position in the serialized program the finding relates to, only present for parse
+    /// errors
+    pub location: Option<DiagnosticLocation>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DiagnosticSeverity {
+    Error,
+    Warning,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AnalysisDiagnosticCode {
+    /// The program could not be deserialized
+    ParseError,
+    /// The program failed the analysis, for instance due to conflicting conditions
+    InvalidProgram,
+    /// The program routes to a connector which is not configured for the profile
+    ConnectorNotConfigured,
+    /// A rule requires a payment method type which none of the profile's connectors support
+    UnsupportedPaymentMethodType,
+    /// A rule conditions on a key without a condition on the key it depends on
+    MissingKeyDependency,
+    /// A rule never matches as an earlier rule matches all of its payments
+    ShadowedRule,
+}
+
+/// One based line and column of a position in the serialized program
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, ToSchema)]
+pub struct DiagnosticLocation {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl AnalysisDiagnostic {
+    pub fn error(code: AnalysisDiagnosticCode, message: String) -> Self {
+        Self {
+            severity: DiagnosticSeverity::Error,
+            code,
+            message,
+            rule_name: None,
+            location: None,
+        }
+    }
+}
+
+impl From<euclid::dssa::types::AnalysisWarning> for AnalysisDiagnostic {
+    fn from(warning: euclid::dssa::types::AnalysisWarning) -> Self {
+        use euclid::dssa::types::AnalysisWarning;
+
+        let message = warning.to_string();
+        let (code, rule_name) = match warning {
+            AnalysisWarning::UnsupportedPaymentMethodType { rule_name, .. } => (
+                AnalysisDiagnosticCode::UnsupportedPaymentMethodType,
+                rule_name,
+            ),
+            AnalysisWarning::MissingKeyDependency { rule_name, .. } => {
+                (AnalysisDiagnosticCode::MissingKeyDependency, rule_name)
+            }
+            AnalysisWarning::ShadowedRule { rule_name, .. } => {
+                (AnalysisDiagnosticCode::ShadowedRule, rule_name)
+            }
+        };
+
+        Self {
+            severity: DiagnosticSeverity::Warning,
+            code,
+            message,
+            rule_name: Some(rule_name),
+            location: None,
+        }
+    }
+}
@@ -351,6 +351,16 @@ pub struct OutgoingWebhook {
     /// The time at which webhook was sent
     #[serde(default, with = "custom_serde::iso8601")]
     pub timestamp: PrimitiveDateTime,
+
+    /// The version of the webhook payload, incremented whenever fields are added to the content.
+    /// Payloads of newer versions are a superset of older ones. Not sent for version 1, which is
+    /// the payload sent before payloads were versioned
+    #[serde(skip_serializing_if = "is_default_payload_version")]
+    pub payload_version: u8,
+}
+
+fn is_default_payload_version(payload_version: &u8) -> bool {
+    *payload_version == crate::consts::DEFAULT_OUTGOING_WEBHOOK_PAYLOAD_VERSION
 }
 
 #[derive(Debug, Clone, Serialize, ToSchema)]
@@ -359,8 +369,8 @@ pub struct OutgoingWebhook {
 pub enum OutgoingWebhookContent {
     #[schema(value_type = PaymentsResponse, title = "PaymentsResponse")]
     PaymentDetails(Box<payments::PaymentsResponse>),
-    #[schema(value_type = RefundResponse, title = "RefundResponse")]
-    RefundDetails(Box<refunds::RefundResponse>),
+    #[schema(value_type = RefundWebhookResponse, title = "RefundWebhookResponse")]
+    RefundDetails(Box<refunds::RefundWebhookResponse>),
     #[schema(value_type = DisputeResponse, title = "DisputeResponse")]
     DisputeDetails(Box<disputes::DisputeResponse>),
     #[schema(value_type = MandateResponse, title = "MandateResponse")]
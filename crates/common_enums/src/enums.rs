@@ -2212,6 +2212,169 @@ pub enum RefundStatus {
     TransactionFailure,
 }
 
+/// Normalized reason for which a refund has failed or is under review
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    serde::Serialize,
+    serde::Deserialize,
+    ToSchema,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum RefundErrorCategory {
+    /// The connector was unavailable or did not respond in time
+    ProcessorUnavailable,
+    /// The connector does not support refunds for the payment
+    RefundNotSupported,
+    /// The refund is held for review, either by the connector or because the connector response
+    /// did not match the refund
+    UnderReview,
+    /// The balance of the merchant at the connector is not sufficient to fund the refund
+    InsufficientFundsOnAcquirer,
+    /// The payment is past the period within which the connector allows refunding it
+    RefundWindowExpired,
+    /// The payment has already been refunded
+    Duplicate,
+    /// The connector declined the refund for a reason not covered by the other categories
+    ProcessorDeclined,
+}
+
+/// The action recommended to the merchant for a refund that has failed or is under review
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    serde::Serialize,
+    serde::Deserialize,
+    ToSchema,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum RefundRecommendedAction {
+    /// The failure is transient, the refund can be retried after some time
+    RetryLater,
+    /// The processor has to be contacted to resolve the failure
+    ContactProcessor,
+    /// The refund cannot be made through the processor and has to be made outside of it
+    RefundExternally,
+    /// No action is required from the merchant
+    None,
+}
+
+/// How a refund came to exist
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    strum::EnumIter,
+    serde::Serialize,
+    serde::Deserialize,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum RefundOrigin {
+    /// The refund was created before its origin was recorded
+    #[default]
+    Legacy,
+    /// The refund was created through the API with an API key
+    Api,
+    /// The refund was created from the dashboard
+    Dashboard,
+    /// The refund was initiated on the connector's end and created from its webhook
+    ConnectorWebhook,
+    /// The refund was created as an entry of a bulk refund batch
+    BulkJob,
+    /// The refund was created by a scheduled job
+    Scheduled,
+    /// The refund was created by a retry worker
+    RetryWorker,
+}
+
+/// The status of a bulk refund batch
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    serde::Serialize,
+    serde::Deserialize,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum RefundBatchStatus {
+    /// The batch has been accepted and is yet to be processed
+    #[default]
+    Pending,
+    /// The entries of the batch are being submitted
+    Processing,
+    /// A refund has been created for every entry of the batch
+    Completed,
+    /// Every entry of the batch has been processed, but some of them are invalid or could not be
+    /// refunded
+    PartiallyFailed,
+    /// No refund has been created for the batch, either because none of its entries could be
+    /// refunded or because the batch could not be scheduled
+    Failed,
+}
+
+/// The status of a single entry of a bulk refund batch
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    strum::EnumIter,
+    serde::Serialize,
+    serde::Deserialize,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum RefundBatchEntryStatus {
+    /// The entry is yet to be submitted
+    #[default]
+    Pending,
+    /// The entry failed the upfront eligibility checks and will not be submitted
+    Invalid,
+    /// A refund has been created for the entry
+    Submitted,
+    /// The refund creation failed for the entry
+    Failed,
+}
+
 #[derive(
     Clone,
     Copy,
@@ -2307,6 +2470,32 @@ pub enum MandateStatus {
     Revoked,
 }
 
+/// The interval at which a multi use mandate is expected to be charged
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumIter,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case", ascii_case_insensitive)]
+pub enum MandateFrequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Quarterly,
+    Yearly,
+}
+
 /// Indicates the card network.
 #[derive(
     Clone,
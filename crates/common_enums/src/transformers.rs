@@ -5,8 +5,8 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "payouts")]
 use crate::enums::PayoutStatus;
 use crate::enums::{
-    AttemptStatus, Country, CountryAlpha2, CountryAlpha3, DisputeStatus, EventType, IntentStatus,
-    MandateStatus, PaymentMethod, PaymentMethodType, RefundStatus,
+    AttemptStatus, Country, CountryAlpha2, CountryAlpha3, Currency, DisputeStatus, EventType,
+    IntentStatus, MandateStatus, PaymentMethod, PaymentMethodType, RefundStatus,
 };
 
 impl Display for NumericCountryCodeParseError {
@@ -269,6 +269,240 @@ impl CountryAlpha2 {
             Self::ZW => CountryAlpha3::ZWE,
         }
     }
+
+    /// Returns the currencies in use in the country, its primary currency first. Territories
+    /// without a currency of their own, like Antarctica, have none.
+    pub const fn get_currencies(self) -> &'static [Currency] {
+        match self {
+            Self::AE => &[Currency::AED],
+            Self::AF => &[Currency::AFN],
+            Self::AL => &[Currency::ALL],
+            Self::AM => &[Currency::AMD],
+            Self::CW | Self::SX => &[Currency::ANG],
+            Self::AO => &[Currency::AOA],
+            Self::AR => &[Currency::ARS],
+            Self::AU
+            | Self::CX
+            | Self::CC
+            | Self::HM
+            | Self::KI
+            | Self::NR
+            | Self::NF
+            | Self::TV => &[Currency::AUD],
+            Self::AW => &[Currency::AWG],
+            Self::AZ => &[Currency::AZN],
+            Self::BA => &[Currency::BAM],
+            Self::BB => &[Currency::BBD],
+            Self::BD => &[Currency::BDT],
+            Self::BH => &[Currency::BHD],
+            Self::BI => &[Currency::BIF],
+            Self::BM => &[Currency::BMD],
+            Self::BN => &[Currency::BND],
+            Self::BO => &[Currency::BOB],
+            Self::BR => &[Currency::BRL],
+            Self::BS => &[Currency::BSD],
+            Self::BT => &[Currency::BTN, Currency::INR],
+            Self::BW => &[Currency::BWP],
+            Self::BY => &[Currency::BYN],
+            Self::BZ => &[Currency::BZD],
+            Self::CA => &[Currency::CAD],
+            Self::CD => &[Currency::CDF],
+            Self::LI | Self::CH => &[Currency::CHF],
+            Self::CL => &[Currency::CLP, Currency::CLF],
+            Self::CN => &[Currency::CNY],
+            Self::CO => &[Currency::COP],
+            Self::CR => &[Currency::CRC],
+            Self::CU => &[Currency::CUP, Currency::CUC],
+            Self::CV => &[Currency::CVE],
+            Self::CZ => &[Currency::CZK],
+            Self::DJ => &[Currency::DJF],
+            Self::DK | Self::FO | Self::GL => &[Currency::DKK],
+            Self::DO => &[Currency::DOP],
+            Self::DZ => &[Currency::DZD],
+            Self::EG => &[Currency::EGP],
+            Self::ER => &[Currency::ERN],
+            Self::ET => &[Currency::ETB],
+            Self::AX
+            | Self::AD
+            | Self::AT
+            | Self::BE
+            | Self::BG
+            | Self::HR
+            | Self::CY
+            | Self::EE
+            | Self::FI
+            | Self::FR
+            | Self::GF
+            | Self::TF
+            | Self::DE
+            | Self::GR
+            | Self::GP
+            | Self::VA
+            | Self::IE
+            | Self::IT
+            | Self::LV
+            | Self::LT
+            | Self::LU
+            | Self::MT
+            | Self::MQ
+            | Self::YT
+            | Self::MC
+            | Self::ME
+            | Self::NL
+            | Self::PT
+            | Self::RE
+            | Self::BL
+            | Self::MF
+            | Self::PM
+            | Self::SM
+            | Self::SK
+            | Self::SI
+            | Self::ES => &[Currency::EUR],
+            Self::FJ => &[Currency::FJD],
+            Self::FK => &[Currency::FKP],
+            Self::GG | Self::IM | Self::JE | Self::GB => &[Currency::GBP],
+            Self::GE => &[Currency::GEL],
+            Self::GH => &[Currency::GHS],
+            Self::GI => &[Currency::GIP],
+            Self::GM => &[Currency::GMD],
+            Self::GN => &[Currency::GNF],
+            Self::GT => &[Currency::GTQ],
+            Self::GY => &[Currency::GYD],
+            Self::HK => &[Currency::HKD],
+            Self::HN => &[Currency::HNL],
+            Self::HT => &[Currency::HTG, Currency::USD],
+            Self::HU => &[Currency::HUF],
+            Self::ID => &[Currency::IDR],
+            Self::IL => &[Currency::ILS],
+            Self::PS => &[Currency::ILS, Currency::JOD],
+            Self::IN => &[Currency::INR],
+            Self::IQ => &[Currency::IQD],
+            Self::IR => &[Currency::IRR],
+            Self::IS => &[Currency::ISK],
+            Self::JM => &[Currency::JMD],
+            Self::JO => &[Currency::JOD],
+            Self::JP => &[Currency::JPY],
+            Self::KE => &[Currency::KES],
+            Self::KG => &[Currency::KGS],
+            Self::KH => &[Currency::KHR],
+            Self::KM => &[Currency::KMF],
+            Self::KP => &[Currency::KPW],
+            Self::KR => &[Currency::KRW],
+            Self::KW => &[Currency::KWD],
+            Self::KY => &[Currency::KYD],
+            Self::KZ => &[Currency::KZT],
+            Self::LA => &[Currency::LAK],
+            Self::LB => &[Currency::LBP],
+            Self::LK => &[Currency::LKR],
+            Self::LR => &[Currency::LRD],
+            Self::LS => &[Currency::LSL, Currency::ZAR],
+            Self::LY => &[Currency::LYD],
+            Self::MA | Self::EH => &[Currency::MAD],
+            Self::MD => &[Currency::MDL],
+            Self::MG => &[Currency::MGA],
+            Self::MK => &[Currency::MKD],
+            Self::MM => &[Currency::MMK],
+            Self::MN => &[Currency::MNT],
+            Self::MO => &[Currency::MOP],
+            Self::MR => &[Currency::MRU],
+            Self::MU => &[Currency::MUR],
+            Self::MV => &[Currency::MVR],
+            Self::MW => &[Currency::MWK],
+            Self::MX => &[Currency::MXN],
+            Self::MY => &[Currency::MYR],
+            Self::MZ => &[Currency::MZN],
+            Self::NA => &[Currency::NAD, Currency::ZAR],
+            Self::NG => &[Currency::NGN],
+            Self::NI => &[Currency::NIO],
+            Self::BV | Self::NO | Self::SJ => &[Currency::NOK],
+            Self::NP => &[Currency::NPR],
+            Self::CK | Self::NZ | Self::NU | Self::PN | Self::TK => &[Currency::NZD],
+            Self::OM => &[Currency::OMR],
+            Self::PA => &[Currency::PAB, Currency::USD],
+            Self::PE => &[Currency::PEN],
+            Self::PG => &[Currency::PGK],
+            Self::PH => &[Currency::PHP],
+            Self::PK => &[Currency::PKR],
+            Self::PL => &[Currency::PLN],
+            Self::PY => &[Currency::PYG],
+            Self::QA => &[Currency::QAR],
+            Self::RO => &[Currency::RON],
+            Self::RS => &[Currency::RSD],
+            Self::RU => &[Currency::RUB],
+            Self::RW => &[Currency::RWF],
+            Self::SA => &[Currency::SAR],
+            Self::SB => &[Currency::SBD],
+            Self::SC => &[Currency::SCR],
+            Self::SD => &[Currency::SDG],
+            Self::SE => &[Currency::SEK],
+            Self::SG => &[Currency::SGD],
+            Self::SH => &[Currency::SHP],
+            Self::SL => &[Currency::SLE, Currency::SLL],
+            Self::SO => &[Currency::SOS],
+            Self::SR => &[Currency::SRD],
+            Self::SS => &[Currency::SSP],
+            Self::ST => &[Currency::STN],
+            Self::SY => &[Currency::SYP],
+            Self::SZ => &[Currency::SZL],
+            Self::TH => &[Currency::THB],
+            Self::TJ => &[Currency::TJS],
+            Self::TM => &[Currency::TMT],
+            Self::TN => &[Currency::TND],
+            Self::TO => &[Currency::TOP],
+            Self::TR => &[Currency::TRY],
+            Self::TT => &[Currency::TTD],
+            Self::TW => &[Currency::TWD],
+            Self::TZ => &[Currency::TZS],
+            Self::UA => &[Currency::UAH],
+            Self::UG => &[Currency::UGX],
+            Self::AS
+            | Self::BQ
+            | Self::IO
+            | Self::EC
+            | Self::GU
+            | Self::MH
+            | Self::FM
+            | Self::MP
+            | Self::PW
+            | Self::PR
+            | Self::TL
+            | Self::TC
+            | Self::UM
+            | Self::VG
+            | Self::VI
+            | Self::US => &[Currency::USD],
+            Self::SV => &[Currency::USD, Currency::SVC],
+            Self::UY => &[Currency::UYU],
+            Self::UZ => &[Currency::UZS],
+            Self::VE => &[Currency::VES],
+            Self::VN => &[Currency::VND],
+            Self::VU => &[Currency::VUV],
+            Self::WS => &[Currency::WST],
+            Self::CM | Self::CF | Self::TD | Self::CG | Self::GQ | Self::GA => &[Currency::XAF],
+            Self::AI
+            | Self::AG
+            | Self::DM
+            | Self::GD
+            | Self::MS
+            | Self::KN
+            | Self::LC
+            | Self::VC => &[Currency::XCD],
+            Self::BJ
+            | Self::BF
+            | Self::CI
+            | Self::GW
+            | Self::ML
+            | Self::NE
+            | Self::SN
+            | Self::TG => &[Currency::XOF],
+            Self::PF | Self::NC | Self::WF => &[Currency::XPF],
+            Self::YE => &[Currency::YER],
+            Self::ZA => &[Currency::ZAR],
+            Self::ZM => &[Currency::ZMW],
+            Self::ZW => &[Currency::ZWL, Currency::USD],
+            Self::AQ | Self::GS => &[],
+        }
+    }
 }
 impl Country {
     pub const fn from_alpha2(code: CountryAlpha2) -> Self {
@@ -2387,4 +2621,22 @@ mod tests {
             serde_json::from_str::<HyperswitchRequestNumeric>(request_str);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_currencies_of_multi_currency_countries() {
+        assert_eq!(
+            CountryAlpha2::PA.get_currencies(),
+            &[Currency::PAB, Currency::USD]
+        );
+        assert_eq!(
+            CountryAlpha2::BT.get_currencies(),
+            &[Currency::BTN, Currency::INR]
+        );
+        assert_eq!(
+            CountryAlpha2::NA.get_currencies(),
+            &[Currency::NAD, Currency::ZAR]
+        );
+        assert_eq!(CountryAlpha2::DE.get_currencies(), &[Currency::EUR]);
+        assert!(CountryAlpha2::AQ.get_currencies().is_empty());
+    }
 }
@@ -76,4 +76,14 @@ impl EuclidDirFilter for ThreeDSDecisionRule {
         DirKeyKind::AcquirerCountry,
         DirKeyKind::AcquirerFraudRate,
     ];
+    const KEY_DEPENDENCIES: &'static [(DirKeyKind, DirKeyKind)] = &[
+        (
+            DirKeyKind::CustomerDeviceDisplaySize,
+            DirKeyKind::CustomerDeviceType,
+        ),
+        (
+            DirKeyKind::CustomerDevicePlatform,
+            DirKeyKind::CustomerDeviceType,
+        ),
+    ];
 }
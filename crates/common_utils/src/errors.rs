@@ -71,6 +71,15 @@ pub enum ValidationError {
     InvalidValue { message: String },
 }
 
+/// The original connector ID is missing for a hashed connector transaction or refund ID, leaving
+/// only its hash which the connector does not recognise.
+#[derive(Debug, thiserror::Error, Clone, PartialEq, Eq)]
+#[error("Processor data is missing for the hashed connector ID {hashed_id}")]
+pub struct MissingProcessorData {
+    /// The hashed connector ID
+    pub hashed_id: String,
+}
+
 /// Integrity check errors.
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct IntegrityCheckError {
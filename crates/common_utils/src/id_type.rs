@@ -37,7 +37,7 @@ pub use self::global_id::{
     payment_methods::{GlobalPaymentMethodId, GlobalPaymentMethodSessionId},
     refunds::GlobalRefundId,
     token::GlobalTokenId,
-    CellId,
+    CellId, GlobalIdParsingError,
 };
 pub use self::{
     api_key::ApiKeyId,
@@ -19,7 +19,7 @@ use crate::{
 /// This id will have information about the entity and cell in a distributed system architecture
 pub(crate) struct GlobalId(LengthId<MAX_GLOBAL_ID_LENGTH, MIN_GLOBAL_ID_LENGTH>);
 
-#[derive(Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 /// Entities that can be identified by a global id
 pub(crate) enum GlobalEntity {
     Customer,
@@ -43,6 +43,37 @@ impl GlobalEntity {
             Self::Token => "tok",
         }
     }
+
+    fn from_prefix(prefix: &str) -> Option<Self> {
+        [
+            Self::Customer,
+            Self::Payment,
+            Self::Attempt,
+            Self::PaymentMethod,
+            Self::Refund,
+            Self::PaymentMethodSession,
+            Self::Token,
+        ]
+        .into_iter()
+        .find(|entity| entity.prefix() == prefix)
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Customer => "customer",
+            Self::Payment => "payment",
+            Self::PaymentMethod => "payment method",
+            Self::Attempt => "payment attempt",
+            Self::Refund => "refund",
+            Self::PaymentMethodSession => "payment method session",
+            Self::Token => "token",
+        }
+    }
+
+    /// Describe the id of this entity for error messages, eg: `a refund id (ref_...)`
+    fn describe_id(self) -> String {
+        format!("a {} id ({}_...)", self.name(), self.prefix())
+    }
 }
 
 /// Cell identifier for an instance / deployment of application
@@ -122,6 +153,37 @@ pub(crate) enum GlobalIdError {
     CellIdError(#[from] CellIdError),
 }
 
+/// Error generated when a global id received for a specific entity could not be parsed
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum GlobalIdParsingError {
+    /// The id belongs to a different entity than the one expected
+    #[error("expected {expected}, got {received}")]
+    EntityMismatch {
+        /// Description of the expected id
+        expected: String,
+        /// Description of the received id
+        received: String,
+    },
+
+    /// The id does not satisfy the length constraints of a global id
+    #[error("invalid length for {expected}: {reason}")]
+    InvalidLength {
+        /// Description of the expected id
+        expected: String,
+        /// The length constraint that was violated
+        reason: String,
+    },
+
+    /// The id is not in the global id format
+    #[error("invalid format for {expected}: {reason}")]
+    InvalidFormat {
+        /// Description of the expected id
+        expected: String,
+        /// The reason the format is invalid
+        reason: String,
+    },
+}
+
 impl GlobalId {
     /// Create a new global id from entity and cell information
     /// The entity prefix is used to identify the entity, `cus` for customers, `pay`` for payments etc.
@@ -146,6 +208,59 @@ impl GlobalId {
         Ok(Self(length_id))
     }
 
+    /// Parse a global id, ensuring that it identifies the given entity
+    pub(crate) fn from_string_for_entity(
+        input_string: std::borrow::Cow<'static, str>,
+        entity: GlobalEntity,
+    ) -> Result<Self, GlobalIdParsingError> {
+        let global_id = Self::from_string(input_string).map_err(|error| match error {
+            GlobalIdError::LengthIdError(length_id_error) => GlobalIdParsingError::InvalidLength {
+                expected: entity.describe_id(),
+                reason: length_id_error.to_string(),
+            },
+            GlobalIdError::InvalidIdFormat | GlobalIdError::CellIdError(_) => {
+                GlobalIdParsingError::InvalidFormat {
+                    expected: entity.describe_id(),
+                    reason: error.to_string(),
+                }
+            }
+        })?;
+
+        let received_prefix = global_id
+            .get_string_repr()
+            .split('_')
+            .nth(1)
+            .ok_or_else(|| GlobalIdParsingError::InvalidFormat {
+                expected: entity.describe_id(),
+                reason: GlobalIdError::InvalidIdFormat.to_string(),
+            })?;
+
+        match GlobalEntity::from_prefix(received_prefix) {
+            Some(received_entity) if received_entity == entity => Ok(global_id),
+            Some(received_entity) => Err(GlobalIdParsingError::EntityMismatch {
+                expected: entity.describe_id(),
+                received: received_entity.describe_id(),
+            }),
+            None => Err(GlobalIdParsingError::EntityMismatch {
+                expected: entity.describe_id(),
+                received: format!("an id with unknown prefix ({received_prefix}_...)"),
+            }),
+        }
+    }
+
+    /// Deserialize a global id, ensuring that it identifies the given entity
+    pub(crate) fn deserialize_for_entity<'de, D>(
+        deserializer: D,
+        entity: GlobalEntity,
+    ) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let deserialized_string = <String as serde::Deserialize<'de>>::deserialize(deserializer)?;
+        Self::from_string_for_entity(deserialized_string.into(), entity)
+            .map_err(serde::de::Error::custom)
+    }
+
     pub(crate) fn get_string_repr(&self) -> &str {
         &self.0 .0 .0
     }
@@ -248,4 +363,51 @@ mod global_id_tests {
         let error_message = global_id.unwrap_err().to_string();
         assert_eq!(error_message, expected_error_message);
     }
+
+    #[test]
+    fn test_global_id_for_entity_valid() {
+        let input_string = "12345_ref_abcdefghijklmnopqrstuvwxyz1234567890";
+        let global_id =
+            GlobalId::from_string_for_entity(input_string.into(), GlobalEntity::Refund).unwrap();
+        assert_eq!(global_id.get_string_repr(), input_string);
+    }
+
+    #[test]
+    fn test_global_id_for_entity_wrong_prefix() {
+        let error = GlobalId::from_string_for_entity(
+            "12345_pay_abcdefghijklmnopqrstuvwxyz1234567890".into(),
+            GlobalEntity::Refund,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "expected a refund id (ref_...), got a payment id (pay_...)"
+        );
+
+        let error = GlobalId::from_string_for_entity(
+            "12345_xyz_abcdefghijklmnopqrstuvwxyz1234567890".into(),
+            GlobalEntity::Attempt,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "expected a payment attempt id (att_...), got an id with unknown prefix (xyz_...)"
+        );
+    }
+
+    #[test]
+    fn test_global_id_for_entity_wrong_length() {
+        let error =
+            GlobalId::from_string_for_entity("12345_pm".into(), GlobalEntity::PaymentMethod)
+                .unwrap_err();
+        assert_eq!(
+            error,
+            GlobalIdParsingError::InvalidLength {
+                expected: "a payment method id (pm_...)".to_string(),
+                reason: format!(
+                    "the minimum required length for this field is {MIN_GLOBAL_ID_LENGTH}"
+                ),
+            }
+        );
+    }
 }
@@ -1,10 +1,10 @@
 use common_enums::enums;
-use error_stack::ResultExt;
 
 use crate::errors;
 
 crate::global_id_type!(
     GlobalPaymentId,
+    Payment,
     "A global id that can be used to identify a payment.
 
 The format will be `<cell_id>_<entity_prefix>_<time_ordered_id>`.
@@ -42,17 +42,19 @@ impl GlobalPaymentId {
 impl TryFrom<std::borrow::Cow<'static, str>> for GlobalPaymentId {
     type Error = error_stack::Report<errors::ValidationError>;
     fn try_from(value: std::borrow::Cow<'static, str>) -> Result<Self, Self::Error> {
-        let merchant_ref_id = super::GlobalId::from_string(value).change_context(
-            errors::ValidationError::IncorrectValueProvided {
-                field_name: "payment_id",
-            },
-        )?;
+        let merchant_ref_id =
+            super::GlobalId::from_string_for_entity(value, super::GlobalEntity::Payment).map_err(
+                |error| errors::ValidationError::InvalidValue {
+                    message: error.to_string(),
+                },
+            )?;
         Ok(Self(merchant_ref_id))
     }
 }
 
 crate::global_id_type!(
     GlobalAttemptId,
+    Attempt,
     "A global id that can be used to identify a payment attempt"
 );
 
@@ -85,11 +87,12 @@ impl GlobalAttemptId {
 impl TryFrom<std::borrow::Cow<'static, str>> for GlobalAttemptId {
     type Error = error_stack::Report<errors::ValidationError>;
     fn try_from(value: std::borrow::Cow<'static, str>) -> Result<Self, Self::Error> {
-        let global_attempt_id = super::GlobalId::from_string(value).change_context(
-            errors::ValidationError::IncorrectValueProvided {
-                field_name: "payment_id",
-            },
-        )?;
+        let global_attempt_id =
+            super::GlobalId::from_string_for_entity(value, super::GlobalEntity::Attempt).map_err(
+                |error| errors::ValidationError::InvalidValue {
+                    message: error.to_string(),
+                },
+            )?;
         Ok(Self(global_attempt_id))
     }
 }
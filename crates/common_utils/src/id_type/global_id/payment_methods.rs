@@ -6,19 +6,19 @@ use crate::{
 };
 
 /// A global id that can be used to identify a payment method
-#[derive(
-    Debug,
-    Clone,
-    Hash,
-    PartialEq,
-    Eq,
-    serde::Serialize,
-    serde::Deserialize,
-    diesel::expression::AsExpression,
-)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, serde::Serialize, diesel::expression::AsExpression)]
 #[diesel(sql_type = diesel::sql_types::Text)]
 pub struct GlobalPaymentMethodId(GlobalId);
 
+impl<'de> serde::Deserialize<'de> for GlobalPaymentMethodId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        GlobalId::deserialize_for_entity(deserializer, GlobalEntity::PaymentMethod).map(Self)
+    }
+}
+
 /// A global id that can be used to identify a payment method session
 #[derive(
     Debug,
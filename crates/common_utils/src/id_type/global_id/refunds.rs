@@ -1,18 +1,7 @@
-use error_stack::ResultExt;
-
 use crate::errors;
 
 /// A global id that can be used to identify a refund
-#[derive(
-    Debug,
-    Clone,
-    Hash,
-    PartialEq,
-    Eq,
-    serde::Serialize,
-    serde::Deserialize,
-    diesel::expression::AsExpression,
-)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, serde::Serialize, diesel::expression::AsExpression)]
 #[diesel(sql_type = diesel::sql_types::Text)]
 pub struct GlobalRefundId(super::GlobalId);
 
@@ -36,15 +25,25 @@ impl GlobalRefundId {
 impl TryFrom<std::borrow::Cow<'static, str>> for GlobalRefundId {
     type Error = error_stack::Report<errors::ValidationError>;
     fn try_from(value: std::borrow::Cow<'static, str>) -> Result<Self, Self::Error> {
-        let merchant_ref_id = super::GlobalId::from_string(value).change_context(
-            errors::ValidationError::IncorrectValueProvided {
-                field_name: "refund_id",
-            },
-        )?;
+        let merchant_ref_id =
+            super::GlobalId::from_string_for_entity(value, super::GlobalEntity::Refund).map_err(
+                |error| errors::ValidationError::InvalidValue {
+                    message: error.to_string(),
+                },
+            )?;
         Ok(Self(merchant_ref_id))
     }
 }
 
+impl<'de> serde::Deserialize<'de> for GlobalRefundId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        super::GlobalId::deserialize_for_entity(deserializer, super::GlobalEntity::Refund).map(Self)
+    }
+}
+
 // TODO: refactor the macro to include this id use case as well
 impl<DB> diesel::serialize::ToSql<diesel::sql_types::Text, DB> for GlobalRefundId
 where
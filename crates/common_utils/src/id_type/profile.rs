@@ -15,6 +15,14 @@ crate::impl_serializable_secret_id_type!(ProfileId);
 crate::impl_queryable_id_type!(ProfileId);
 crate::impl_to_sql_from_sql_id_type!(ProfileId);
 
+/// All the keys that can be formed from profile id
+impl ProfileId {
+    /// get_refund_limits_config_key
+    pub fn get_refund_limits_config_key(&self) -> String {
+        format!("refund_limits_{}", self.get_string_repr())
+    }
+}
+
 impl crate::events::ApiEventMetric for ProfileId {
     fn get_api_event_type(&self) -> Option<crate::events::ApiEventsType> {
         Some(crate::events::ApiEventsType::BusinessProfile {
@@ -193,6 +193,33 @@ mod id_type {
             #[diesel(sql_type = diesel::sql_types::Text)]
             pub struct $type($crate::id_type::global_id::GlobalId);
         };
+        ($type:ident, $entity:ident, $doc:literal) => {
+            #[doc = $doc]
+            #[derive(
+                Debug,
+                Clone,
+                Hash,
+                PartialEq,
+                Eq,
+                serde::Serialize,
+                diesel::expression::AsExpression,
+            )]
+            #[diesel(sql_type = diesel::sql_types::Text)]
+            pub struct $type($crate::id_type::global_id::GlobalId);
+
+            impl<'de> serde::Deserialize<'de> for $type {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: serde::Deserializer<'de>,
+                {
+                    $crate::id_type::global_id::GlobalId::deserialize_for_entity(
+                        deserializer,
+                        $crate::id_type::global_id::GlobalEntity::$entity,
+                    )
+                    .map(Self)
+                }
+            }
+        };
     }
 
     /// Implements common methods on the specified ID type.
@@ -48,7 +48,9 @@ use crate::{
     consts::{
         self, MAX_DESCRIPTION_LENGTH, MAX_STATEMENT_DESCRIPTOR_LENGTH, PUBLISHABLE_KEY_LENGTH,
     },
-    errors::{CustomResult, ParsingError, PercentageError, ValidationError},
+    errors::{
+        CustomResult, MissingProcessorData, ParsingError, PercentageError, ValidationError,
+    },
     fp_utils::when,
     impl_enum_str,
 };
@@ -1243,7 +1245,7 @@ crate::impl_to_sql_from_sql_json!(BrowserInformation);
 /// the hash value of such identifiers will be stored as connector_transaction_id.
 /// The actual connector's identifier will be stored in a separate column -
 /// processor_transaction_data or something with a similar name.
-#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize, AsExpression)]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, AsExpression)]
 #[diesel(sql_type = sql_types::Text)]
 pub enum ConnectorTransactionId {
     /// Actual transaction identifier
@@ -1252,6 +1254,39 @@ pub enum ConnectorTransactionId {
     HashedData(String),
 }
 
+impl<'de> Deserialize<'de> for ConnectorTransactionId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        enum TaggedConnectorTransactionId {
+            TxnId(String),
+            HashedData(String),
+        }
+
+        /// Connector transaction IDs were serialized as plain strings before long IDs were hashed
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum SerializedConnectorTransactionId {
+            Tagged(TaggedConnectorTransactionId),
+            Legacy(String),
+        }
+
+        Ok(
+            match SerializedConnectorTransactionId::deserialize(deserializer)? {
+                SerializedConnectorTransactionId::Tagged(TaggedConnectorTransactionId::TxnId(
+                    txn_id,
+                )) => Self::TxnId(txn_id),
+                SerializedConnectorTransactionId::Tagged(
+                    TaggedConnectorTransactionId::HashedData(hashed_data),
+                ) => Self::HashedData(hashed_data),
+                SerializedConnectorTransactionId::Legacy(txn_id) => Self::from(txn_id),
+            },
+        )
+    }
+}
+
 impl ConnectorTransactionId {
     /// Implementation for retrieving the inner identifier
     pub fn get_id(&self) -> &String {
@@ -1369,6 +1404,18 @@ pub trait ConnectorTransactionIdTrait {
     fn get_optional_connector_refund_id(&self) -> Option<&String> {
         self.get_optional_connector_transaction_id()
     }
+    /// Returns a connector transaction ID, failing instead of falling back to the hashed ID when
+    /// the original ID is missing
+    fn get_connector_transaction_id_checked(&self) -> Result<&String, MissingProcessorData> {
+        Ok(self.get_connector_transaction_id())
+    }
+    /// Returns an optional connector refund ID, failing instead of falling back to the hashed ID
+    /// when the original ID is missing
+    fn get_optional_connector_refund_id_checked(
+        &self,
+    ) -> Result<Option<&String>, MissingProcessorData> {
+        Ok(self.get_optional_connector_refund_id())
+    }
 }
 
 /// Domain type for PublishableKey
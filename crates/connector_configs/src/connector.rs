@@ -3,7 +3,11 @@ use std::collections::HashMap;
 #[cfg(feature = "payouts")]
 use api_models::enums::PayoutConnectors;
 use api_models::{
-    enums::{AuthenticationConnectors, Connector, PmAuthConnectors, TaxConnectors},
+    admin::AcceptedCountries,
+    enums::{
+        AuthenticationConnectors, Connector, CountryAlpha2, PaymentMethod, PmAuthConnectors,
+        TaxConnectors,
+    },
     payments,
 };
 use serde::Deserialize;
@@ -289,6 +293,135 @@ pub struct ConnectorConfig {
     pub unified_authentication_service: Option<ConnectorTomlConfig>,
 }
 
+impl ConnectorTomlConfig {
+    /// Keeps only the payment method types of the payment method, and those accepted in the
+    /// country, when they are given. The sections which do not depend on the payment method,
+    /// like the authentication details, are always kept.
+    pub fn filter_payment_methods(
+        self,
+        payment_method: Option<PaymentMethod>,
+        country: Option<CountryAlpha2>,
+    ) -> Self {
+        let is_selected = |section: PaymentMethod| {
+            payment_method.map_or(true, |payment_method| payment_method == section)
+        };
+        let is_wallet_selected = is_selected(PaymentMethod::Wallet);
+        let metadata = self.metadata.map(|mut metadata| {
+            if !is_wallet_selected {
+                metadata.google_pay = None;
+                metadata.apple_pay = None;
+            }
+            metadata
+        });
+
+        Self {
+            metadata,
+            connector_wallets_details: self
+                .connector_wallets_details
+                .filter(|_| is_wallet_selected),
+            credit: filter_card_providers(self.credit, is_selected(PaymentMethod::Card), country),
+            debit: filter_card_providers(self.debit, is_selected(PaymentMethod::Card), country),
+            bank_transfer: filter_providers(
+                self.bank_transfer,
+                is_selected(PaymentMethod::BankTransfer),
+                country,
+            ),
+            bank_redirect: filter_providers(
+                self.bank_redirect,
+                is_selected(PaymentMethod::BankRedirect),
+                country,
+            ),
+            bank_debit: filter_providers(
+                self.bank_debit,
+                is_selected(PaymentMethod::BankDebit),
+                country,
+            ),
+            open_banking: filter_providers(
+                self.open_banking,
+                is_selected(PaymentMethod::OpenBanking),
+                country,
+            ),
+            pay_later: filter_providers(
+                self.pay_later,
+                is_selected(PaymentMethod::PayLater),
+                country,
+            ),
+            wallet: filter_providers(self.wallet, is_wallet_selected, country),
+            crypto: filter_providers(self.crypto, is_selected(PaymentMethod::Crypto), country),
+            reward: filter_providers(self.reward, is_selected(PaymentMethod::Reward), country),
+            upi: filter_providers(self.upi, is_selected(PaymentMethod::Upi), country),
+            voucher: filter_providers(self.voucher, is_selected(PaymentMethod::Voucher), country),
+            gift_card: filter_providers(
+                self.gift_card,
+                is_selected(PaymentMethod::GiftCard),
+                country,
+            ),
+            card_redirect: filter_providers(
+                self.card_redirect,
+                is_selected(PaymentMethod::CardRedirect),
+                country,
+            ),
+            real_time_payment: filter_providers(
+                self.real_time_payment,
+                is_selected(PaymentMethod::RealTimePayment),
+                country,
+            ),
+            ..self
+        }
+    }
+}
+
+fn is_accepted_in_country(
+    accepted_countries: Option<&AcceptedCountries>,
+    country: Option<CountryAlpha2>,
+) -> bool {
+    match (accepted_countries, country) {
+        (Some(AcceptedCountries::EnableOnly(countries)), Some(country)) => {
+            countries.contains(&country)
+        }
+        (Some(AcceptedCountries::DisableOnly(countries)), Some(country)) => {
+            !countries.contains(&country)
+        }
+        _ => true,
+    }
+}
+
+fn filter_providers(
+    providers: Option<Vec<Provider>>,
+    is_selected: bool,
+    country: Option<CountryAlpha2>,
+) -> Option<Vec<Provider>> {
+    providers
+        .filter(|_| is_selected)
+        .map(|providers| {
+            providers
+                .into_iter()
+                .filter(|provider| {
+                    is_accepted_in_country(provider.accepted_countries.as_ref(), country)
+                })
+                .collect::<Vec<_>>()
+        })
+        .filter(|providers| !providers.is_empty())
+}
+
+fn filter_card_providers(
+    providers: Option<Vec<CardProvider>>,
+    is_selected: bool,
+    country: Option<CountryAlpha2>,
+) -> Option<Vec<CardProvider>> {
+    providers
+        .filter(|_| is_selected)
+        .map(|providers| {
+            providers
+                .into_iter()
+                .filter(|provider| {
+                    is_accepted_in_country(provider.accepted_countries.as_ref(), country)
+                })
+                .collect::<Vec<_>>()
+        })
+        .filter(|providers| !providers.is_empty())
+}
+
 impl ConnectorConfig {
     fn new() -> Result<Self, String> {
         let config_str = if cfg!(feature = "production") {
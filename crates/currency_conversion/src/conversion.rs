@@ -1,10 +1,10 @@
 use common_enums::Currency;
-use rust_decimal::Decimal;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 use rusty_money::Money;
 
 use crate::{
     error::CurrencyConversionError,
-    types::{currency_match, ExchangeRates},
+    types::{currency_match, ExchangeRates, RoundingMode},
 };
 
 pub fn convert(
@@ -14,18 +14,57 @@ pub fn convert(
     amount: i64,
 ) -> Result<Decimal, CurrencyConversionError> {
     let money_minor = Money::from_minor(amount, currency_match(from_currency));
+    convert_major_amount(ex_rates, from_currency, to_currency, *money_minor.amount())
+}
+
+/// Converts an amount in the major unit of `from_currency` to the major unit of `to_currency`
+fn convert_major_amount(
+    ex_rates: &ExchangeRates,
+    from_currency: Currency,
+    to_currency: Currency,
+    amount: Decimal,
+) -> Result<Decimal, CurrencyConversionError> {
     let base_currency = ex_rates.base_currency;
     if to_currency == base_currency {
-        ex_rates.forward_conversion(*money_minor.amount(), from_currency)
+        ex_rates.forward_conversion(amount, from_currency)
     } else if from_currency == base_currency {
-        ex_rates.backward_conversion(*money_minor.amount(), to_currency)
+        ex_rates.backward_conversion(amount, to_currency)
     } else {
-        let base_conversion_amt =
-            ex_rates.forward_conversion(*money_minor.amount(), from_currency)?;
+        let base_conversion_amt = ex_rates.forward_conversion(amount, from_currency)?;
         ex_rates.backward_conversion(base_conversion_amt, to_currency)
     }
 }
 
+/// Converts an amount in the minor unit of `from_currency` to the minor unit of `to_currency`.
+/// The number of decimal places of both currencies is taken from [`Currency`], and the converted
+/// amount is rounded to a whole minor unit using the given rounding mode.
+pub fn convert_minor_amount(
+    ex_rates: &ExchangeRates,
+    from_currency: Currency,
+    to_currency: Currency,
+    amount: i64,
+    rounding_mode: RoundingMode,
+) -> Result<i64, CurrencyConversionError> {
+    let major_amount = Decimal::new(
+        amount,
+        u32::from(from_currency.number_of_digits_after_decimal_point()),
+    );
+    let converted_amount =
+        convert_major_amount(ex_rates, from_currency, to_currency, major_amount)?;
+    let minor_unit_factor = 10_i64
+        .checked_pow(u32::from(
+            to_currency.number_of_digits_after_decimal_point(),
+        ))
+        .ok_or(CurrencyConversionError::AmountOutOfRange)?;
+
+    converted_amount
+        .checked_mul(Decimal::from(minor_unit_factor))
+        .ok_or(CurrencyConversionError::DecimalMultiplicationFailed)?
+        .round_dp_with_strategy(0, rounding_mode.into())
+        .to_i64()
+        .ok_or(CurrencyConversionError::AmountOutOfRange)
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::expect_used, clippy::print_stdout)]
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use common_enums::Currency;
-use rust_decimal::Decimal;
+use rust_decimal::{Decimal, RoundingStrategy};
 use rusty_money::iso;
 
 use crate::error::CurrencyConversionError;
@@ -13,6 +13,27 @@ pub struct ExchangeRates {
     pub conversion: HashMap<Currency, CurrencyFactors>,
 }
 
+/// How a converted amount is rounded to a whole number of minor units
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum RoundingMode {
+    /// Rounds to the nearest minor unit, halfway amounts being rounded away from zero
+    HalfUp,
+    /// Rounds down to the minor unit below
+    Floor,
+    /// Rounds up to the minor unit above
+    Ceil,
+}
+
+impl From<RoundingMode> for RoundingStrategy {
+    fn from(rounding_mode: RoundingMode) -> Self {
+        match rounding_mode {
+            RoundingMode::HalfUp => Self::MidpointAwayFromZero,
+            RoundingMode::Floor => Self::ToNegativeInfinity,
+            RoundingMode::Ceil => Self::ToPositiveInfinity,
+        }
+    }
+}
+
 /// Stores the multiplicative factor for  conversion between currency to base and vice versa
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct CurrencyFactors {
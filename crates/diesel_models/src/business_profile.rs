@@ -750,6 +750,7 @@ pub struct WebhookDetails {
     pub payment_statuses_enabled: Option<Vec<common_enums::IntentStatus>>,
     pub refund_statuses_enabled: Option<Vec<common_enums::RefundStatus>>,
     pub payout_statuses_enabled: Option<Vec<common_enums::PayoutStatus>>,
+    pub payload_version: Option<u8>,
 }
 
 common_utils::impl_to_sql_from_sql_json!(WebhookDetails);
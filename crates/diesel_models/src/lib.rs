@@ -40,6 +40,7 @@ pub mod payouts;
 pub mod process_tracker;
 pub mod query;
 pub mod refund;
+pub mod refund_batch;
 pub mod relay;
 pub mod reverse_lookup;
 pub mod role;
@@ -35,6 +35,12 @@ pub struct Mandate {
     pub original_payment_id: Option<common_utils::id_type::PaymentId>,
     pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
     pub updated_by: Option<String>,
+    pub frequency: Option<storage_enums::MandateFrequency>,
+    pub last_charged_at: Option<PrimitiveDateTime>,
+    #[serde(default = "common_utils::date_time::now")]
+    pub modified_at: PrimitiveDateTime,
+    /// Metadata set by the merchant, such as their internal contract ids
+    pub merchant_metadata: Option<pii::SecretSerdeValue>,
 }
 
 #[derive(
@@ -73,6 +79,10 @@ pub struct MandateNew {
     pub original_payment_id: Option<common_utils::id_type::PaymentId>,
     pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
     pub updated_by: Option<String>,
+    pub frequency: Option<storage_enums::MandateFrequency>,
+    pub last_charged_at: Option<PrimitiveDateTime>,
+    pub modified_at: Option<PrimitiveDateTime>,
+    pub merchant_metadata: Option<pii::SecretSerdeValue>,
 }
 
 impl MandateNew {
@@ -88,6 +98,7 @@ pub enum MandateUpdate {
     },
     CaptureAmountUpdate {
         amount_captured: Option<i64>,
+        last_charged_at: Option<PrimitiveDateTime>,
     },
     ConnectorReferenceUpdate {
         connector_mandate_ids: Option<pii::SecretSerdeValue>,
@@ -98,6 +109,24 @@ pub enum MandateUpdate {
         payment_method_id: String,
         original_payment_id: Option<common_utils::id_type::PaymentId>,
     },
+    /// Sets the connector reference of a mandate whose reference was received asynchronously,
+    /// along with its status
+    ConnectorMandateIdAndStatusUpdate {
+        connector_mandate_id: Option<String>,
+        connector_mandate_ids: Option<pii::SecretSerdeValue>,
+        mandate_status: storage_enums::MandateStatus,
+    },
+    /// Replaces the merchant metadata of a mandate, the merge with the existing metadata is done
+    /// by the caller
+    MetadataUpdate {
+        merchant_metadata: pii::SecretSerdeValue,
+    },
+    /// Sets the status of a mandate along with its merchant metadata, the merge with the existing
+    /// metadata is done by the caller
+    StatusAndMetadataUpdate {
+        mandate_status: storage_enums::MandateStatus,
+        merchant_metadata: pii::SecretSerdeValue,
+    },
 }
 
 impl MandateUpdate {
@@ -135,6 +164,9 @@ pub struct MandateUpdateInternal {
     payment_method_id: Option<String>,
     original_payment_id: Option<common_utils::id_type::PaymentId>,
     updated_by: Option<String>,
+    last_charged_at: Option<PrimitiveDateTime>,
+    modified_at: Option<PrimitiveDateTime>,
+    merchant_metadata: Option<pii::SecretSerdeValue>,
 }
 
 impl From<MandateUpdate> for MandateUpdateInternal {
@@ -148,8 +180,14 @@ impl From<MandateUpdate> for MandateUpdateInternal {
                 payment_method_id: None,
                 original_payment_id: None,
                 updated_by: None,
+                last_charged_at: None,
+                modified_at: Some(common_utils::date_time::now()),
+                merchant_metadata: None,
             },
-            MandateUpdate::CaptureAmountUpdate { amount_captured } => Self {
+            MandateUpdate::CaptureAmountUpdate {
+                amount_captured,
+                last_charged_at,
+            } => Self {
                 mandate_status: None,
                 amount_captured,
                 connector_mandate_ids: None,
@@ -157,11 +195,15 @@ impl From<MandateUpdate> for MandateUpdateInternal {
                 payment_method_id: None,
                 original_payment_id: None,
                 updated_by: None,
+                last_charged_at,
+                modified_at: Some(common_utils::date_time::now()),
+                merchant_metadata: None,
             },
             MandateUpdate::ConnectorReferenceUpdate {
                 connector_mandate_ids,
             } => Self {
                 connector_mandate_ids,
+                modified_at: Some(common_utils::date_time::now()),
                 ..Default::default()
             },
             MandateUpdate::ConnectorMandateIdUpdate {
@@ -174,6 +216,32 @@ impl From<MandateUpdate> for MandateUpdateInternal {
                 connector_mandate_ids,
                 payment_method_id: Some(payment_method_id),
                 original_payment_id,
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            MandateUpdate::ConnectorMandateIdAndStatusUpdate {
+                connector_mandate_id,
+                connector_mandate_ids,
+                mandate_status,
+            } => Self {
+                mandate_status: Some(mandate_status),
+                connector_mandate_id,
+                connector_mandate_ids,
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            MandateUpdate::MetadataUpdate { merchant_metadata } => Self {
+                merchant_metadata: Some(merchant_metadata),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            MandateUpdate::StatusAndMetadataUpdate {
+                mandate_status,
+                merchant_metadata,
+            } => Self {
+                mandate_status: Some(mandate_status),
+                merchant_metadata: Some(merchant_metadata),
+                modified_at: Some(common_utils::date_time::now()),
                 ..Default::default()
             },
         }
@@ -190,6 +258,9 @@ impl MandateUpdateInternal {
             payment_method_id,
             original_payment_id,
             updated_by,
+            last_charged_at,
+            modified_at,
+            merchant_metadata,
         } = self;
 
         Mandate {
@@ -200,6 +271,9 @@ impl MandateUpdateInternal {
             payment_method_id: payment_method_id.unwrap_or(source.payment_method_id),
             original_payment_id: original_payment_id.map_or(source.original_payment_id, Some),
             updated_by: updated_by.map_or(source.updated_by, Some),
+            last_charged_at: last_charged_at.map_or(source.last_charged_at, Some),
+            modified_at: modified_at.unwrap_or(source.modified_at),
+            merchant_metadata: merchant_metadata.map_or(source.merchant_metadata, Some),
             ..source
         }
     }
@@ -234,6 +308,12 @@ impl From<&MandateNew> for Mandate {
             original_payment_id: mandate_new.original_payment_id.clone(),
             merchant_connector_id: mandate_new.merchant_connector_id.clone(),
             updated_by: mandate_new.updated_by.clone(),
+            frequency: mandate_new.frequency,
+            last_charged_at: mandate_new.last_charged_at,
+            modified_at: mandate_new
+                .modified_at
+                .unwrap_or_else(common_utils::date_time::now),
+            merchant_metadata: mandate_new.merchant_metadata.clone(),
         }
     }
 }
@@ -35,6 +35,7 @@ pub mod payout_attempt;
 pub mod payouts;
 pub mod process_tracker;
 pub mod refund;
+pub mod refund_batch;
 pub mod relay;
 pub mod reverse_lookup;
 pub mod role;
@@ -1,8 +1,15 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
-use error_stack::report;
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods,
+    QueryDsl, Table,
+};
+use error_stack::{report, ResultExt};
+use time::PrimitiveDateTime;
 
 use super::generics;
-use crate::{errors, mandate::*, schema::mandate::dsl, PgPooledConn, StorageResult};
+use crate::{
+    enums as storage_enums, errors, mandate::*, schema::mandate::dsl, PgPooledConn, StorageResult,
+};
 
 impl MandateNew {
     pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Mandate> {
@@ -61,6 +68,123 @@ impl Mandate {
         .await
     }
 
+    /// Active mandates of the merchant created before the given time, oldest first
+    pub async fn find_active_by_merchant_id_created_before(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        created_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        Self::find_by_merchant_id_status_created_before(
+            conn,
+            merchant_id,
+            storage_enums::MandateStatus::Active,
+            created_before,
+            limit,
+        )
+        .await
+    }
+
+    /// Pending mandates of the merchant created before the given time, oldest first
+    pub async fn find_pending_by_merchant_id_created_before(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        created_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        Self::find_by_merchant_id_status_created_before(
+            conn,
+            merchant_id,
+            storage_enums::MandateStatus::Pending,
+            created_before,
+            limit,
+        )
+        .await
+    }
+
+    async fn find_by_merchant_id_status_created_before(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        mandate_status: storage_enums::MandateStatus,
+        created_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::mandate_status.eq(mandate_status))
+                .and(dsl::created_at.lt(created_before)),
+            Some(limit),
+            None,
+            Some((dsl::created_at.asc(), dsl::mandate_id.asc())),
+        )
+        .await
+    }
+
+    /// Mandates of the merchant set up by the given payment
+    pub async fn find_by_merchant_id_original_payment_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        original_payment_id: &common_utils::id_type::PaymentId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::original_payment_id.eq(original_payment_id.to_owned())),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    /// Fetches up to `limit` active mandates of the merchant which do not have a connector
+    /// mandate id, ordered by mandate id and starting after the given mandate id.
+    pub async fn find_active_by_merchant_id_without_connector_mandate_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        starting_after: Option<&str>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::mandate_status.eq(storage_enums::MandateStatus::Active))
+                .and(dsl::connector_mandate_id.is_null())
+                .and(dsl::mandate_id.gt(starting_after.unwrap_or_default().to_owned())),
+            Some(limit),
+            None,
+            Some(dsl::mandate_id.asc()),
+        )
+        .await
+    }
+
+    pub async fn get_count_of_active_by_merchant_id_customer_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> StorageResult<i64> {
+        let query = <Self as HasTable>::table().count().filter(
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned()))
+                .and(dsl::mandate_status.eq(storage_enums::MandateStatus::Active)),
+        );
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        generics::db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_result_async::<i64>(conn),
+            generics::db_metrics::DatabaseOperation::Count,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Failed to get a count of active mandates")
+    }
+
     //Fix this function once V2 mandate is schema is being built
     #[cfg(feature = "v2")]
     pub async fn find_by_global_customer_id(
@@ -267,6 +267,29 @@ impl PaymentAttempt {
         .await
     }
 
+    #[cfg(feature = "v2")]
+    pub async fn get_count_by_id_payment_id(
+        conn: &PgPooledConn,
+        id: &common_utils::id_type::GlobalAttemptId,
+        payment_id: &common_utils::id_type::GlobalPaymentId,
+    ) -> StorageResult<i64> {
+        let query = <Self as HasTable>::table().count().filter(
+            dsl::id
+                .eq(id.to_owned())
+                .and(dsl::payment_id.eq(payment_id.to_owned())),
+        );
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_result_async::<i64>(conn),
+            db_metrics::DatabaseOperation::Count,
+        )
+        .await
+        .change_context(DatabaseError::Others)
+        .attach_printable("Failed to get a count of payment attempts by id and payment id")
+    }
+
     #[cfg(feature = "v2")]
     pub async fn find_by_payment_id(
         conn: &PgPooledConn,
@@ -1,4 +1,7 @@
+#[cfg(feature = "v1")]
+use async_bb8_diesel::AsyncConnection;
 use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
+use time::PrimitiveDateTime;
 
 use super::generics;
 #[cfg(feature = "v1")]
@@ -6,7 +9,7 @@ use crate::schema::refund::dsl;
 #[cfg(feature = "v2")]
 use crate::schema_v2::refund::dsl;
 use crate::{
-    errors,
+    enums as storage_enums, errors,
     refund::{Refund, RefundNew, RefundUpdate, RefundUpdateInternal},
     PgPooledConn, StorageResult,
 };
@@ -30,7 +33,7 @@ impl Refund {
             dsl::refund_id
                 .eq(self.refund_id.to_owned())
                 .and(dsl::merchant_id.eq(self.merchant_id.to_owned())),
-            RefundUpdateInternal::from(refund),
+            RefundUpdateInternal::from(refund.with_merged_metadata(&self)),
         )
         .await
         {
@@ -130,6 +133,169 @@ impl Refund {
         )
         .await
     }
+
+    /// Fetches up to `limit` refunds of the merchant which do not have a merchant connector
+    /// account id, ordered by refund id and starting after the given refund id.
+    pub async fn find_by_merchant_id_without_merchant_connector_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        starting_after: Option<&str>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::merchant_connector_id.is_null())
+                .and(dsl::refund_id.gt(starting_after.unwrap_or_default().to_owned())),
+            Some(limit),
+            None,
+            Some(dsl::refund_id.asc()),
+        )
+        .await
+    }
+
+    /// Fetches up to `limit` refunds of the merchant which were sent to the connector and are still
+    /// pending, but were last modified before `modified_before`, ordered by modification time.
+    pub async fn find_stale_pending_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        modified_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::refund_status.eq(storage_enums::RefundStatus::Pending))
+                .and(dsl::sent_to_gateway.eq(true))
+                .and(dsl::modified_at.lt(modified_before)),
+            Some(limit),
+            None,
+            Some((dsl::modified_at.asc(), dsl::refund_id.asc())),
+        )
+        .await
+    }
+
+    /// Fetches the refunds of the profile created at or after `created_after`, used to compute
+    /// the amount refunded by the profile within a velocity window.
+    pub async fn find_by_merchant_id_profile_id_created_after(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        created_after: PrimitiveDateTime,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::profile_id.eq(profile_id.to_owned()))
+                .and(dsl::created_at.ge(created_after)),
+            None,
+            None,
+            None,
+        )
+        .await
+    }
+
+    /// Fetches up to `limit` refunds of the merchant modified at or after `modified_since`,
+    /// ordered by modification time and refund id. Refunds modified exactly at `modified_since`
+    /// are only fetched when their refund id is greater than `starting_after`, so that a page can
+    /// resume from the last refund of the previous page.
+    pub async fn find_by_merchant_id_modified_since(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        modified_since: PrimitiveDateTime,
+        starting_after: Option<&str>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()).and(
+                dsl::modified_at.gt(modified_since).or(dsl::modified_at
+                    .eq(modified_since)
+                    .and(dsl::refund_id.gt(starting_after.unwrap_or_default().to_owned()))),
+            ),
+            Some(limit),
+            None,
+            Some((dsl::modified_at.asc(), dsl::refund_id.asc())),
+        )
+        .await
+    }
+
+    /// Backfills the processor data of the refunds of the merchant from entries of refund id,
+    /// processor refund data and processor transaction data, updating up to `chunk_size` refunds
+    /// per transaction. Refunds which already have processor data are skipped unless `force` is
+    /// set. Returns the number of refunds updated.
+    pub async fn update_processor_data_batch(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        entries: Vec<(String, Option<String>, Option<String>)>,
+        chunk_size: usize,
+        force: bool,
+    ) -> StorageResult<usize> {
+        let mut updated_refunds = 0;
+
+        for chunk in entries.chunks(chunk_size.max(1)) {
+            let chunk = chunk.to_vec();
+            let merchant_id = merchant_id.to_owned();
+
+            updated_refunds += conn
+                .transaction_async(|conn| async move {
+                    let mut updated_refunds = 0;
+
+                    for (refund_id, processor_refund_data, processor_transaction_data) in chunk {
+                        let predicate = dsl::merchant_id
+                            .eq(merchant_id.to_owned())
+                            .and(dsl::refund_id.eq(refund_id));
+                        let processor_data_update =
+                            crate::refund::RefundProcessorDataUpdateInternal {
+                                processor_refund_data,
+                                processor_transaction_data,
+                                modified_at: common_utils::date_time::now(),
+                            };
+
+                        let update_result = if force {
+                            generics::generic_update::<<Self as HasTable>::Table, _, _>(
+                                &conn,
+                                predicate,
+                                processor_data_update,
+                            )
+                            .await
+                        } else {
+                            generics::generic_update::<<Self as HasTable>::Table, _, _>(
+                                &conn,
+                                predicate
+                                    .and(dsl::processor_refund_data.is_null())
+                                    .and(dsl::processor_transaction_data.is_null()),
+                                processor_data_update,
+                            )
+                            .await
+                        };
+
+                        // The transaction can only fail with errors converted from diesel errors,
+                        // so the report is logged before being replaced
+                        updated_refunds += update_result.map_err(|error| {
+                            router_env::logger::error!(
+                                ?error,
+                                "Failed to backfill the processor data of a refund"
+                            );
+                            errors::DatabaseError::Others
+                        })?;
+                    }
+
+                    Ok::<_, errors::DatabaseError>(updated_refunds)
+                })
+                .await?;
+        }
+
+        Ok(updated_refunds)
+    }
 }
 
 #[cfg(feature = "v2")]
@@ -142,7 +308,7 @@ impl Refund {
         match generics::generic_update_by_id::<<Self as HasTable>::Table, _, _, _>(
             conn,
             self.id.to_owned(),
-            RefundUpdateInternal::from(refund),
+            RefundUpdateInternal::from(refund.with_merged_metadata(&self)),
         )
         .await
         {
@@ -186,4 +352,29 @@ impl Refund {
         )
         .await
     }
+
+    /// Fetches up to `limit` refunds of the merchant modified at or after `modified_since`,
+    /// ordered by modification time and id. Refunds modified exactly at `modified_since` are only
+    /// fetched when their id is greater than `starting_after`, so that a page can resume from the
+    /// last refund of the previous page.
+    pub async fn find_by_merchant_id_modified_since(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        modified_since: PrimitiveDateTime,
+        starting_after: Option<&str>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()).and(
+                dsl::modified_at.gt(modified_since).or(dsl::modified_at
+                    .eq(modified_since)
+                    .and(dsl::id.gt(starting_after.unwrap_or_default().to_owned()))),
+            ),
+            Some(limit),
+            None,
+            Some((dsl::modified_at.asc(), dsl::id.asc())),
+        )
+        .await
+    }
 }
@@ -0,0 +1,115 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    errors,
+    refund_batch::{
+        RefundBatch, RefundBatchEntry, RefundBatchEntryNew, RefundBatchEntryUpdate,
+        RefundBatchEntryUpdateInternal, RefundBatchNew, RefundBatchUpdate,
+        RefundBatchUpdateInternal,
+    },
+    schema::{refund_batch::dsl, refund_batch_entry::dsl as entry_dsl},
+    PgPooledConn, StorageResult,
+};
+
+impl RefundBatchNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<RefundBatch> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl RefundBatch {
+    pub async fn find_by_merchant_id_batch_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::batch_id.eq(batch_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        refund_batch_update: RefundBatchUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::batch_id
+                .eq(self.batch_id.to_owned())
+                .and(dsl::merchant_id.eq(self.merchant_id.to_owned())),
+            RefundBatchUpdateInternal::from(refund_batch_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
+
+impl RefundBatchEntry {
+    pub async fn insert_batch(
+        conn: &PgPooledConn,
+        entries: Vec<RefundBatchEntryNew>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_insert(conn, entries).await
+    }
+
+    pub async fn find_by_merchant_id_batch_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            entry_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(entry_dsl::batch_id.eq(batch_id.to_owned())),
+            None,
+            None,
+            Some(entry_dsl::entry_index.asc()),
+        )
+        .await
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        refund_batch_entry_update: RefundBatchEntryUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            entry_dsl::batch_id
+                .eq(self.batch_id.to_owned())
+                .and(entry_dsl::entry_index.eq(self.entry_index)),
+            RefundBatchEntryUpdateInternal::from(refund_batch_entry_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
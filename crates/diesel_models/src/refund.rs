@@ -1,8 +1,10 @@
 use common_utils::{
+    errors::MissingProcessorData,
     pii,
     types::{ChargeRefunds, ConnectorTransactionId, ConnectorTransactionIdTrait, MinorUnit},
 };
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use masking::{ExposeInterface, PeekInterface};
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
@@ -66,6 +68,8 @@ pub struct Refund {
     pub processor_transaction_data: Option<String>,
     pub issuer_error_code: Option<String>,
     pub issuer_error_message: Option<String>,
+    #[serde(default)]
+    pub origin: storage_enums::RefundOrigin,
 }
 
 #[cfg(feature = "v2")]
@@ -117,6 +121,10 @@ pub struct Refund {
     pub id: common_utils::id_type::GlobalRefundId,
     pub merchant_reference_id: common_utils::id_type::RefundReferenceId,
     pub connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    #[serde(default)]
+    pub origin: storage_enums::RefundOrigin,
+    pub issuer_error_code: Option<String>,
+    pub issuer_error_message: Option<String>,
 }
 
 #[cfg(feature = "v1")]
@@ -164,6 +172,7 @@ pub struct RefundNew {
     pub split_refunds: Option<common_types::refunds::SplitRefund>,
     pub processor_refund_data: Option<String>,
     pub processor_transaction_data: Option<String>,
+    pub origin: storage_enums::RefundOrigin,
 }
 
 #[cfg(feature = "v2")]
@@ -211,6 +220,7 @@ pub struct RefundNew {
     pub split_refunds: Option<common_types::refunds::SplitRefund>,
     pub processor_refund_data: Option<String>,
     pub processor_transaction_data: Option<String>,
+    pub origin: storage_enums::RefundOrigin,
 }
 
 #[cfg(feature = "v1")]
@@ -230,6 +240,13 @@ pub enum RefundUpdate {
         reason: Option<String>,
         updated_by: String,
     },
+    /// Merges the keys of the metadata into the stored metadata instead of replacing it, see
+    /// [`RefundUpdate::with_merged_metadata`]
+    MetadataMergeUpdate {
+        metadata: Option<pii::SecretSerdeValue>,
+        reason: Option<String>,
+        updated_by: String,
+    },
     StatusUpdate {
         connector_refund_id: Option<ConnectorTransactionId>,
         sent_to_gateway: bool,
@@ -255,6 +272,19 @@ pub enum RefundUpdate {
         refund_error_code: Option<String>,
         updated_by: String,
     },
+    MerchantConnectorIdUpdate {
+        merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+        updated_by: String,
+    },
+    SplitRefundUpdate {
+        split_refunds: Option<common_types::refunds::SplitRefund>,
+        charges: Option<ChargeRefunds>,
+        updated_by: String,
+    },
+    ArnUpdate {
+        refund_arn: String,
+        updated_by: String,
+    },
 }
 
 #[cfg(feature = "v2")]
@@ -274,6 +304,13 @@ pub enum RefundUpdate {
         reason: Option<String>,
         updated_by: String,
     },
+    /// Merges the keys of the metadata into the stored metadata instead of replacing it, see
+    /// [`RefundUpdate::with_merged_metadata`]
+    MetadataMergeUpdate {
+        metadata: Option<pii::SecretSerdeValue>,
+        reason: Option<String>,
+        updated_by: String,
+    },
     StatusUpdate {
         connector_refund_id: Option<ConnectorTransactionId>,
         sent_to_gateway: bool,
@@ -290,6 +327,8 @@ pub enum RefundUpdate {
         processor_refund_data: Option<String>,
         unified_code: Option<String>,
         unified_message: Option<String>,
+        issuer_error_code: Option<String>,
+        issuer_error_message: Option<String>,
     },
     ManualUpdate {
         refund_status: Option<storage_enums::RefundStatus>,
@@ -297,6 +336,15 @@ pub enum RefundUpdate {
         refund_error_code: Option<String>,
         updated_by: String,
     },
+    SplitRefundUpdate {
+        split_refunds: Option<common_types::refunds::SplitRefund>,
+        charges: Option<ChargeRefunds>,
+        updated_by: String,
+    },
+    ArnUpdate {
+        refund_arn: String,
+        updated_by: String,
+    },
 }
 
 #[cfg(feature = "v1")]
@@ -318,6 +366,20 @@ pub struct RefundUpdateInternal {
     unified_message: Option<String>,
     issuer_error_code: Option<String>,
     issuer_error_message: Option<String>,
+    merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    split_refunds: Option<common_types::refunds::SplitRefund>,
+    charges: Option<ChargeRefunds>,
+}
+
+/// Changeset backfilling the processor data of a refund, leaving the columns without a value
+/// untouched
+#[cfg(feature = "v1")]
+#[derive(Debug, AsChangeset)]
+#[diesel(table_name = refund)]
+pub(crate) struct RefundProcessorDataUpdateInternal {
+    pub processor_refund_data: Option<String>,
+    pub processor_transaction_data: Option<String>,
+    pub modified_at: PrimitiveDateTime,
 }
 
 #[cfg(feature = "v2")]
@@ -337,6 +399,10 @@ pub struct RefundUpdateInternal {
     processor_refund_data: Option<String>,
     unified_code: Option<String>,
     unified_message: Option<String>,
+    issuer_error_code: Option<String>,
+    issuer_error_message: Option<String>,
+    split_refunds: Option<common_types::refunds::SplitRefund>,
+    charges: Option<ChargeRefunds>,
 }
 
 #[cfg(feature = "v1")]
@@ -356,6 +422,9 @@ impl RefundUpdateInternal {
             processor_refund_data: self.processor_refund_data,
             unified_code: self.unified_code,
             unified_message: self.unified_message,
+            merchant_connector_id: self.merchant_connector_id.or(source.merchant_connector_id),
+            split_refunds: self.split_refunds.or(source.split_refunds),
+            charges: self.charges.or(source.charges),
             ..source
         }
     }
@@ -378,6 +447,8 @@ impl RefundUpdateInternal {
             processor_refund_data: self.processor_refund_data,
             unified_code: self.unified_code,
             unified_message: self.unified_message,
+            split_refunds: self.split_refunds.or(source.split_refunds),
+            charges: self.charges.or(source.charges),
             ..source
         }
     }
@@ -407,15 +478,23 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 refund_reason: None,
                 refund_error_code: None,
                 modified_at: common_utils::date_time::now(),
+                split_refunds: None,
+                charges: None,
                 unified_code: None,
                 unified_message: None,
                 issuer_error_code: None,
                 issuer_error_message: None,
+                merchant_connector_id: None,
             },
             RefundUpdate::MetadataAndReasonUpdate {
                 metadata,
                 reason,
                 updated_by,
+            }
+            | RefundUpdate::MetadataMergeUpdate {
+                metadata,
+                reason,
+                updated_by,
             } => Self {
                 metadata,
                 refund_reason: reason,
@@ -427,11 +506,14 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 refund_arn: None,
                 refund_error_code: None,
                 modified_at: common_utils::date_time::now(),
+                split_refunds: None,
+                charges: None,
                 processor_refund_data: None,
                 unified_code: None,
                 unified_message: None,
                 issuer_error_code: None,
                 issuer_error_message: None,
+                merchant_connector_id: None,
             },
             RefundUpdate::StatusUpdate {
                 connector_refund_id,
@@ -451,10 +533,13 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 refund_reason: None,
                 refund_error_code: None,
                 modified_at: common_utils::date_time::now(),
+                split_refunds: None,
+                charges: None,
                 unified_code: None,
                 unified_message: None,
                 issuer_error_code: None,
                 issuer_error_message: None,
+                merchant_connector_id: None,
             },
             RefundUpdate::ErrorUpdate {
                 refund_status,
@@ -479,10 +564,13 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 metadata: None,
                 refund_reason: None,
                 modified_at: common_utils::date_time::now(),
+                split_refunds: None,
+                charges: None,
                 unified_code,
                 unified_message,
                 issuer_error_code,
                 issuer_error_message,
+                merchant_connector_id: None,
             },
             RefundUpdate::ManualUpdate {
                 refund_status,
@@ -500,11 +588,84 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 metadata: None,
                 refund_reason: None,
                 modified_at: common_utils::date_time::now(),
+                split_refunds: None,
+                charges: None,
+                processor_refund_data: None,
+                unified_code: None,
+                unified_message: None,
+                issuer_error_code: None,
+                issuer_error_message: None,
+                merchant_connector_id: None,
+            },
+            RefundUpdate::MerchantConnectorIdUpdate {
+                merchant_connector_id,
+                updated_by,
+            } => Self {
+                merchant_connector_id: Some(merchant_connector_id),
+                updated_by,
+                connector_refund_id: None,
+                refund_status: None,
+                sent_to_gateway: None,
+                refund_error_message: None,
+                refund_arn: None,
+                metadata: None,
+                refund_reason: None,
+                refund_error_code: None,
+                modified_at: common_utils::date_time::now(),
+                split_refunds: None,
+                charges: None,
+                processor_refund_data: None,
+                unified_code: None,
+                unified_message: None,
+                issuer_error_code: None,
+                issuer_error_message: None,
+            },
+            RefundUpdate::SplitRefundUpdate {
+                split_refunds,
+                charges,
+                updated_by,
+            } => Self {
+                split_refunds,
+                charges,
+                updated_by,
+                connector_refund_id: None,
+                refund_status: None,
+                sent_to_gateway: None,
+                refund_error_message: None,
+                refund_arn: None,
+                metadata: None,
+                refund_reason: None,
+                refund_error_code: None,
+                modified_at: common_utils::date_time::now(),
                 processor_refund_data: None,
                 unified_code: None,
                 unified_message: None,
                 issuer_error_code: None,
                 issuer_error_message: None,
+                merchant_connector_id: None,
+            },
+            RefundUpdate::ArnUpdate {
+                refund_arn,
+                updated_by,
+            } => Self {
+                refund_arn: Some(refund_arn),
+                updated_by,
+                connector_refund_id: None,
+                refund_status: None,
+                sent_to_gateway: None,
+                refund_error_message: None,
+                metadata: None,
+                refund_reason: None,
+                refund_error_code: None,
+                modified_at: common_utils::date_time::now(),
+                processor_refund_data: None,
+                unified_code: None,
+                unified_message: None,
+                issuer_error_code: None,
+                issuer_error_message: None,
+                merchant_connector_id: None,
+                split_refunds: None,
+                charges: None,
             },
         }
     }
@@ -534,13 +695,22 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 refund_reason: None,
                 refund_error_code: None,
                 modified_at: common_utils::date_time::now(),
+                split_refunds: None,
+                charges: None,
                 unified_code: None,
                 unified_message: None,
+                issuer_error_code: None,
+                issuer_error_message: None,
             },
             RefundUpdate::MetadataAndReasonUpdate {
                 metadata,
                 reason,
                 updated_by,
+            }
+            | RefundUpdate::MetadataMergeUpdate {
+                metadata,
+                reason,
+                updated_by,
             } => Self {
                 metadata,
                 refund_reason: reason,
@@ -552,9 +722,13 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 refund_arn: None,
                 refund_error_code: None,
                 modified_at: common_utils::date_time::now(),
+                split_refunds: None,
+                charges: None,
                 processor_refund_data: None,
                 unified_code: None,
                 unified_message: None,
+                issuer_error_code: None,
+                issuer_error_message: None,
             },
             RefundUpdate::StatusUpdate {
                 connector_refund_id,
@@ -574,8 +748,12 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 refund_reason: None,
                 refund_error_code: None,
                 modified_at: common_utils::date_time::now(),
+                split_refunds: None,
+                charges: None,
                 unified_code: None,
                 unified_message: None,
+                issuer_error_code: None,
+                issuer_error_message: None,
             },
             RefundUpdate::ErrorUpdate {
                 refund_status,
@@ -586,6 +764,8 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 updated_by,
                 connector_refund_id,
                 processor_refund_data,
+                issuer_error_code,
+                issuer_error_message,
             } => Self {
                 refund_status,
                 refund_error_message,
@@ -598,8 +778,12 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 metadata: None,
                 refund_reason: None,
                 modified_at: common_utils::date_time::now(),
+                split_refunds: None,
+                charges: None,
                 unified_code,
                 unified_message,
+                issuer_error_code,
+                issuer_error_message,
             },
             RefundUpdate::ManualUpdate {
                 refund_status,
@@ -617,14 +801,109 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 metadata: None,
                 refund_reason: None,
                 modified_at: common_utils::date_time::now(),
+                split_refunds: None,
+                charges: None,
                 processor_refund_data: None,
                 unified_code: None,
                 unified_message: None,
+                issuer_error_code: None,
+                issuer_error_message: None,
             },
+            RefundUpdate::SplitRefundUpdate {
+                split_refunds,
+                charges,
+                updated_by,
+            } => Self {
+                split_refunds,
+                charges,
+                updated_by,
+                connector_refund_id: None,
+                refund_status: None,
+                sent_to_gateway: None,
+                refund_error_message: None,
+                refund_arn: None,
+                metadata: None,
+                refund_reason: None,
+                refund_error_code: None,
+                modified_at: common_utils::date_time::now(),
+                processor_refund_data: None,
+                unified_code: None,
+                unified_message: None,
+                issuer_error_code: None,
+                issuer_error_message: None,
+            },
+            RefundUpdate::ArnUpdate {
+                refund_arn,
+                updated_by,
+            } => Self {
+                refund_arn: Some(refund_arn),
+                updated_by,
+                connector_refund_id: None,
+                refund_status: None,
+                sent_to_gateway: None,
+                refund_error_message: None,
+                metadata: None,
+                refund_reason: None,
+                refund_error_code: None,
+                modified_at: common_utils::date_time::now(),
+                processor_refund_data: None,
+                unified_code: None,
+                unified_message: None,
+                issuer_error_code: None,
+                issuer_error_message: None,
+                split_refunds: None,
+                charges: None,
+            },
+        }
+    }
+}
+
+impl RefundUpdate {
+    /// Resolves a [`RefundUpdate::MetadataMergeUpdate`] into a replacement of the metadata by the
+    /// stored metadata merged with the update, leaving any other update as is. The merge is shallow:
+    /// every key of the update is set on the stored metadata and keys set to `null` are deleted.
+    /// Metadata that is not a JSON object on either side is replaced instead.
+    pub fn with_merged_metadata(self, source: &Refund) -> Self {
+        match self {
+            Self::MetadataMergeUpdate {
+                metadata,
+                reason,
+                updated_by,
+            } => Self::MetadataAndReasonUpdate {
+                metadata: metadata
+                    .map(|metadata| merge_metadata(source.metadata.as_ref(), metadata)),
+                reason,
+                updated_by,
+            },
+            refund_update => refund_update,
         }
     }
 }
 
+fn merge_metadata(
+    stored: Option<&pii::SecretSerdeValue>,
+    metadata: pii::SecretSerdeValue,
+) -> pii::SecretSerdeValue {
+    let keys = match metadata.expose() {
+        serde_json::Value::Object(keys) => keys,
+        metadata => return pii::SecretSerdeValue::new(metadata),
+    };
+    let mut merged = match stored.map(PeekInterface::peek) {
+        Some(serde_json::Value::Object(stored_keys)) => stored_keys.clone(),
+        _ => serde_json::Map::new(),
+    };
+
+    for (key, value) in keys {
+        if value.is_null() {
+            merged.remove(&key);
+        } else {
+            merged.insert(key, value);
+        }
+    }
+
+    pii::SecretSerdeValue::new(serde_json::Value::Object(merged))
+}
+
 #[cfg(feature = "v1")]
 impl RefundUpdate {
     pub fn apply_changeset(self, source: Refund) -> Refund {
@@ -644,7 +923,10 @@ impl RefundUpdate {
             unified_message,
             issuer_error_code,
             issuer_error_message,
-        } = self.into();
+            merchant_connector_id,
+            split_refunds,
+            charges,
+        } = self.with_merged_metadata(&source).into();
         Refund {
             connector_refund_id: connector_refund_id.or(source.connector_refund_id),
             refund_status: refund_status.unwrap_or(source.refund_status),
@@ -661,9 +943,106 @@ impl RefundUpdate {
             unified_message: unified_message.or(source.unified_message),
             issuer_error_code: issuer_error_code.or(source.issuer_error_code),
             issuer_error_message: issuer_error_message.or(source.issuer_error_message),
+            merchant_connector_id: merchant_connector_id.or(source.merchant_connector_id),
+            split_refunds: split_refunds.or(source.split_refunds),
+            charges: charges.or(source.charges),
             ..source
         }
     }
+
+    pub fn build_error_update_for_unified_error_and_message(
+        unified_error_object: (String, String),
+        refund_error_message: Option<String>,
+        refund_error_code: Option<String>,
+        issuer_error_code: Option<String>,
+        issuer_error_message: Option<String>,
+        storage_scheme: &storage_enums::MerchantStorageScheme,
+    ) -> Self {
+        let (unified_code, unified_message) = unified_error_object;
+
+        Self::ErrorUpdate {
+            refund_status: Some(storage_enums::RefundStatus::Failure),
+            refund_error_message,
+            refund_error_code,
+            updated_by: storage_scheme.to_string(),
+            connector_refund_id: None,
+            processor_refund_data: None,
+            unified_code: Some(unified_code),
+            unified_message: Some(unified_message),
+            issuer_error_code,
+            issuer_error_message,
+        }
+    }
+
+    pub fn build_error_update_for_integrity_check_failure(
+        integrity_check_failed_fields: String,
+        connector_refund_id: Option<String>,
+        storage_scheme: &storage_enums::MerchantStorageScheme,
+    ) -> Self {
+        let (connector_refund_id, processor_refund_data) =
+            connector_refund_id.map_or((None, None), |connector_refund_id| {
+                let (connector_refund_id, processor_refund_data) =
+                    ConnectorTransactionId::form_id_and_data(connector_refund_id);
+                (Some(connector_refund_id), processor_refund_data)
+            });
+
+        Self::ErrorUpdate {
+            refund_status: Some(storage_enums::RefundStatus::ManualReview),
+            refund_error_message: Some(format!(
+                "Integrity Check Failed! as data mismatched for fields {}",
+                integrity_check_failed_fields
+            )),
+            refund_error_code: Some("IE".to_string()),
+            updated_by: storage_scheme.to_string(),
+            connector_refund_id,
+            processor_refund_data,
+            unified_code: None,
+            unified_message: None,
+            issuer_error_code: None,
+            issuer_error_message: None,
+        }
+    }
+
+    pub fn build_refund_update(
+        connector_refund_id: String,
+        refund_status: storage_enums::RefundStatus,
+        storage_scheme: &storage_enums::MerchantStorageScheme,
+    ) -> Self {
+        let (connector_refund_id, processor_refund_data) =
+            ConnectorTransactionId::form_id_and_data(connector_refund_id);
+
+        Self::Update {
+            connector_refund_id,
+            refund_status,
+            sent_to_gateway: true,
+            refund_error_message: None,
+            refund_arn: "".to_string(),
+            updated_by: storage_scheme.to_string(),
+            processor_refund_data,
+        }
+    }
+
+    pub fn build_error_update_for_refund_failure(
+        refund_status: Option<storage_enums::RefundStatus>,
+        refund_error_message: Option<String>,
+        refund_error_code: Option<String>,
+        issuer_error_code: Option<String>,
+        issuer_error_message: Option<String>,
+        storage_scheme: &storage_enums::MerchantStorageScheme,
+    ) -> Self {
+        Self::ErrorUpdate {
+            refund_status,
+            refund_error_message,
+            refund_error_code,
+            updated_by: storage_scheme.to_string(),
+            connector_refund_id: None,
+            processor_refund_data: None,
+            unified_code: None,
+            unified_message: None,
+            issuer_error_code,
+            issuer_error_message,
+        }
+    }
 }
 
 #[cfg(feature = "v2")]
@@ -683,7 +1062,11 @@ impl RefundUpdate {
             processor_refund_data,
             unified_code,
             unified_message,
-        } = self.into();
+            issuer_error_code,
+            issuer_error_message,
+            split_refunds,
+            charges,
+        } = self.with_merged_metadata(&source).into();
         Refund {
             connector_refund_id: connector_refund_id.or(source.connector_refund_id),
             refund_status: refund_status.unwrap_or(source.refund_status),
@@ -698,6 +1081,10 @@ impl RefundUpdate {
             processor_refund_data: processor_refund_data.or(source.processor_refund_data),
             unified_code: unified_code.or(source.unified_code),
             unified_message: unified_message.or(source.unified_message),
+            issuer_error_code: issuer_error_code.or(source.issuer_error_code),
+            issuer_error_message: issuer_error_message.or(source.issuer_error_message),
+            split_refunds: split_refunds.or(source.split_refunds),
+            charges: charges.or(source.charges),
             ..source
         }
     }
@@ -706,6 +1093,8 @@ impl RefundUpdate {
         unified_error_object: (String, String),
         refund_error_message: Option<String>,
         refund_error_code: Option<String>,
+        issuer_error_code: Option<String>,
+        issuer_error_message: Option<String>,
         storage_scheme: &storage_enums::MerchantStorageScheme,
     ) -> Self {
         let (unified_code, unified_message) = unified_error_object;
@@ -719,6 +1108,8 @@ impl RefundUpdate {
             processor_refund_data: None,
             unified_code: Some(unified_code),
             unified_message: Some(unified_message),
+            issuer_error_code,
+            issuer_error_message,
         }
     }
 
@@ -739,6 +1130,8 @@ impl RefundUpdate {
             processor_refund_data: connector_refund_id.and_then(|x| x.extract_hashed_data()),
             unified_code: None,
             unified_message: None,
+            issuer_error_code: None,
+            issuer_error_message: None,
         }
     }
 
@@ -762,6 +1155,8 @@ impl RefundUpdate {
         refund_status: Option<storage_enums::RefundStatus>,
         refund_error_message: Option<String>,
         refund_error_code: Option<String>,
+        issuer_error_code: Option<String>,
+        issuer_error_message: Option<String>,
         storage_scheme: &storage_enums::MerchantStorageScheme,
     ) -> Self {
         Self::ErrorUpdate {
@@ -773,6 +1168,8 @@ impl RefundUpdate {
             processor_refund_data: None,
             unified_code: None,
             unified_message: None,
+            issuer_error_code,
+            issuer_error_message,
         }
     }
 }
@@ -819,12 +1216,7 @@ impl common_utils::events::ApiEventMetric for Refund {
 
 impl ConnectorTransactionIdTrait for Refund {
     fn get_optional_connector_refund_id(&self) -> Option<&String> {
-        match self
-            .connector_refund_id
-            .as_ref()
-            .map(|refund_id| refund_id.get_txn_id(self.processor_refund_data.as_ref()))
-            .transpose()
-        {
+        match self.get_optional_connector_refund_id_checked() {
             Ok(refund_id) => refund_id,
 
             // In case hashed data is missing from DB, use the hashed ID as connector transaction ID
@@ -836,29 +1228,527 @@ impl ConnectorTransactionIdTrait for Refund {
     }
 
     fn get_connector_transaction_id(&self) -> &String {
-        match self
-            .connector_transaction_id
-            .get_txn_id(self.processor_transaction_data.as_ref())
-        {
+        match self.get_connector_transaction_id_checked() {
             Ok(txn_id) => txn_id,
 
             // In case hashed data is missing from DB, use the hashed ID as connector transaction ID
             Err(_) => self.connector_transaction_id.get_id(),
         }
     }
+
+    fn get_connector_transaction_id_checked(&self) -> Result<&String, MissingProcessorData> {
+        self.connector_transaction_id
+            .get_txn_id(self.processor_transaction_data.as_ref())
+            .map_err(|_| MissingProcessorData {
+                hashed_id: self.connector_transaction_id.get_id().clone(),
+            })
+    }
+
+    fn get_optional_connector_refund_id_checked(
+        &self,
+    ) -> Result<Option<&String>, MissingProcessorData> {
+        self.connector_refund_id
+            .as_ref()
+            .map(|refund_id| {
+                refund_id
+                    .get_txn_id(self.processor_refund_data.as_ref())
+                    .map_err(|_| MissingProcessorData {
+                        hashed_id: refund_id.get_id().clone(),
+                    })
+            })
+            .transpose()
+    }
 }
 
+#[cfg(test)]
 mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    #[cfg(feature = "v1")]
+    fn refund_with_split_data() -> super::Refund {
+        super::Refund {
+            internal_reference_id: "internal_ref_123".to_string(),
+            refund_id: "refund_456".to_string(),
+            payment_id: common_utils::id_type::PaymentId::default(),
+            merchant_id: common_utils::id_type::MerchantId::default(),
+            connector_transaction_id: "connector_txn_789".to_string().into(),
+            connector: "stripe".to_string(),
+            connector_refund_id: Some("connector_refund_123".to_string().into()),
+            external_reference_id: None,
+            refund_type: super::storage_enums::RefundType::InstantRefund,
+            total_amount: super::MinorUnit::new(10000),
+            currency: super::storage_enums::Currency::USD,
+            refund_amount: super::MinorUnit::new(9500),
+            refund_status: super::storage_enums::RefundStatus::Failure,
+            sent_to_gateway: true,
+            refund_error_message: Some("insufficient balance".to_string()),
+            metadata: None,
+            refund_arn: None,
+            created_at: common_utils::date_time::now(),
+            modified_at: common_utils::date_time::now(),
+            description: Some("partial capture refund".to_string()),
+            attempt_id: "attempt_123".to_string(),
+            refund_reason: Some("duplicate".to_string()),
+            refund_error_code: Some("balance_insufficient".to_string()),
+            profile_id: None,
+            updated_by: "admin".to_string(),
+            merchant_connector_id: None,
+            charges: Some(super::ChargeRefunds {
+                charge_id: "ch_123".to_string(),
+                revert_platform_fee: Some(true),
+                revert_transfer: Some(true),
+            }),
+            organization_id: common_utils::id_type::OrganizationId::default(),
+            connector_refund_data: None,
+            connector_transaction_data: None,
+            split_refunds: Some(common_types::refunds::SplitRefund::XenditSplitRefund(
+                common_types::domain::XenditSplitSubMerchantData {
+                    for_user_id: "sub_merchant_123".to_string(),
+                },
+            )),
+            unified_code: None,
+            unified_message: None,
+            processor_refund_data: None,
+            processor_transaction_data: None,
+            issuer_error_code: None,
+            issuer_error_message: None,
+            origin: super::storage_enums::RefundOrigin::default(),
+        }
+    }
+
+    #[cfg(feature = "v1")]
     #[test]
-    fn test_backwards_compatibility() {
-        let serialized_refund = r#"{
+    fn test_split_refund_update_replaces_split_data() {
+        let source = refund_with_split_data();
+        let split_refunds = Some(common_types::refunds::SplitRefund::XenditSplitRefund(
+            common_types::domain::XenditSplitSubMerchantData {
+                for_user_id: "sub_merchant_456".to_string(),
+            },
+        ));
+        let charges = Some(super::ChargeRefunds {
+            charge_id: "ch_456".to_string(),
+            revert_platform_fee: Some(false),
+            revert_transfer: None,
+        });
+
+        let updated = super::RefundUpdate::SplitRefundUpdate {
+            split_refunds: split_refunds.clone(),
+            charges: charges.clone(),
+            updated_by: "postgres_only".to_string(),
+        }
+        .apply_changeset(source.clone());
+
+        assert_eq!(
+            updated,
+            super::Refund {
+                split_refunds,
+                charges,
+                updated_by: "postgres_only".to_string(),
+                modified_at: updated.modified_at,
+                ..source
+            }
+        );
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_split_refund_update_preserves_previous_split_data() {
+        let source = refund_with_split_data();
+
+        let updated = super::RefundUpdate::SplitRefundUpdate {
+            split_refunds: None,
+            charges: None,
+            updated_by: "postgres_only".to_string(),
+        }
+        .apply_changeset(source.clone());
+
+        assert_eq!(
+            updated,
+            super::Refund {
+                updated_by: "postgres_only".to_string(),
+                modified_at: updated.modified_at,
+                ..source.clone()
+            }
+        );
+
+        let created = super::RefundUpdateInternal::from(super::RefundUpdate::SplitRefundUpdate {
+            split_refunds: None,
+            charges: None,
+            updated_by: "postgres_only".to_string(),
+        })
+        .create_refund(source.clone());
+
+        assert_eq!(created.split_refunds, source.split_refunds);
+        assert_eq!(created.charges, source.charges);
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_arn_update_preserves_other_columns() {
+        let source = super::Refund {
+            refund_status: super::storage_enums::RefundStatus::Success,
+            unified_code: Some("UE_0000".to_string()),
+            unified_message: Some("Refund processed".to_string()),
+            processor_refund_data: Some("a".repeat(150)),
+            ..refund_with_split_data()
+        };
+
+        let updated = super::RefundUpdate::ArnUpdate {
+            refund_arn: "74987654321012345678901".to_string(),
+            updated_by: "postgres_only".to_string(),
+        }
+        .apply_changeset(source.clone());
+
+        assert_eq!(updated.unified_code, source.unified_code);
+        assert_eq!(updated.processor_refund_data, source.processor_refund_data);
+        assert_eq!(
+            updated,
+            super::Refund {
+                refund_arn: Some("74987654321012345678901".to_string()),
+                updated_by: "postgres_only".to_string(),
+                modified_at: updated.modified_at,
+                ..source
+            }
+        );
+    }
+
+    fn metadata(value: serde_json::Value) -> common_utils::pii::SecretSerdeValue {
+        common_utils::pii::SecretSerdeValue::new(value)
+    }
+
+    #[test]
+    fn test_merge_metadata_sets_keys_on_stored_metadata() {
+        let stored = metadata(serde_json::json!({ "order": "o_1", "ticket": "100" }));
+
+        let merged = super::merge_metadata(
+            Some(&stored),
+            metadata(serde_json::json!({ "ticket": "123", "channel": "email" })),
+        );
+
+        assert_eq!(
+            merged,
+            metadata(serde_json::json!({ "order": "o_1", "ticket": "123", "channel": "email" }))
+        );
+        // Nested objects are replaced as a whole rather than merged
+        assert_eq!(
+            super::merge_metadata(
+                Some(&metadata(
+                    serde_json::json!({ "customer": { "id": "c_1", "tier": "gold" } })
+                )),
+                metadata(serde_json::json!({ "customer": { "tier": "silver" } })),
+            ),
+            metadata(serde_json::json!({ "customer": { "tier": "silver" } }))
+        );
+    }
+
+    #[test]
+    fn test_merge_metadata_deletes_keys_set_to_null() {
+        let stored = metadata(serde_json::json!({ "order": "o_1", "ticket": "100" }));
+
+        assert_eq!(
+            super::merge_metadata(
+                Some(&stored),
+                metadata(serde_json::json!({ "ticket": null, "unknown": null })),
+            ),
+            metadata(serde_json::json!({ "order": "o_1" }))
+        );
+        assert_eq!(
+            super::merge_metadata(
+                None,
+                metadata(serde_json::json!({ "ticket": null, "order": "o_1" }))
+            ),
+            metadata(serde_json::json!({ "order": "o_1" }))
+        );
+    }
+
+    #[test]
+    fn test_merge_metadata_replaces_non_object_metadata() {
+        let stored = metadata(serde_json::json!({ "order": "o_1" }));
+
+        assert_eq!(
+            super::merge_metadata(Some(&stored), metadata(serde_json::json!(["o_1", "o_2"]))),
+            metadata(serde_json::json!(["o_1", "o_2"]))
+        );
+        assert_eq!(
+            super::merge_metadata(
+                Some(&metadata(serde_json::json!("o_1"))),
+                metadata(serde_json::json!({ "order": "o_2", "ticket": null })),
+            ),
+            metadata(serde_json::json!({ "order": "o_2" }))
+        );
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_metadata_merge_update_merges_into_stored_metadata() {
+        let source = super::Refund {
+            metadata: Some(metadata(
+                serde_json::json!({ "order": "o_1", "ticket": "100" }),
+            )),
+            ..refund_with_split_data()
+        };
+
+        let updated = super::RefundUpdate::MetadataMergeUpdate {
+            metadata: Some(metadata(
+                serde_json::json!({ "ticket": "123", "order": null }),
+            )),
+            reason: None,
+            updated_by: "postgres_only".to_string(),
+        }
+        .apply_changeset(source.clone());
+
+        assert_eq!(
+            updated.metadata,
+            Some(metadata(serde_json::json!({ "ticket": "123" })))
+        );
+        assert_eq!(updated.refund_reason, source.refund_reason);
+
+        // Without metadata in the update, the stored metadata is left as is
+        let updated = super::RefundUpdate::MetadataMergeUpdate {
+            metadata: None,
+            reason: Some("requested_by_customer".to_string()),
+            updated_by: "postgres_only".to_string(),
+        }
+        .apply_changeset(source.clone());
+
+        assert_eq!(updated.metadata, source.metadata);
+        assert_eq!(
+            updated.refund_reason.as_deref(),
+            Some("requested_by_customer")
+        );
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_checked_connector_ids_with_processor_data() {
+        use common_utils::types::ConnectorTransactionIdTrait;
+
+        let connector_refund_id = "re_".repeat(50);
+        let connector_transaction_id = "pi_".repeat(50);
+        let refund = super::Refund {
+            connector_refund_id: Some(connector_refund_id.clone().into()),
+            processor_refund_data: Some(connector_refund_id.clone()),
+            connector_transaction_id: connector_transaction_id.clone().into(),
+            processor_transaction_data: Some(connector_transaction_id.clone()),
+            ..refund_with_split_data()
+        };
+
+        assert_eq!(
+            refund.get_optional_connector_refund_id_checked(),
+            Ok(Some(&connector_refund_id))
+        );
+        assert_eq!(
+            refund.get_connector_transaction_id_checked(),
+            Ok(&connector_transaction_id)
+        );
+        assert_eq!(
+            refund.get_optional_connector_refund_id(),
+            Some(&connector_refund_id)
+        );
+        assert_eq!(
+            refund.get_connector_transaction_id(),
+            &connector_transaction_id
+        );
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_checked_connector_ids_without_processor_data() {
+        use common_utils::types::ConnectorTransactionIdTrait;
+
+        let connector_refund_id = super::ConnectorTransactionId::from("re_".repeat(50));
+        let connector_transaction_id = super::ConnectorTransactionId::from("pi_".repeat(50));
+        let refund = super::Refund {
+            connector_refund_id: Some(connector_refund_id.clone()),
+            processor_refund_data: None,
+            connector_transaction_id: connector_transaction_id.clone(),
+            processor_transaction_data: None,
+            ..refund_with_split_data()
+        };
+
+        assert_eq!(
+            refund.get_optional_connector_refund_id_checked(),
+            Err(common_utils::errors::MissingProcessorData {
+                hashed_id: connector_refund_id.get_id().clone(),
+            })
+        );
+        assert_eq!(
+            refund.get_connector_transaction_id_checked(),
+            Err(common_utils::errors::MissingProcessorData {
+                hashed_id: connector_transaction_id.get_id().clone(),
+            })
+        );
+        // The unchecked getters fall back to the hashed IDs
+        assert_eq!(
+            refund.get_optional_connector_refund_id(),
+            Some(connector_refund_id.get_id())
+        );
+        assert_eq!(
+            refund.get_connector_transaction_id(),
+            connector_transaction_id.get_id()
+        );
+
+        let unhashed_refund = super::Refund {
+            connector_refund_id: None,
+            ..refund_with_split_data()
+        };
+        assert_eq!(
+            unhashed_refund.get_optional_connector_refund_id_checked(),
+            Ok(None)
+        );
+        assert_eq!(
+            unhashed_refund
+                .get_connector_transaction_id_checked()
+                .map(String::as_str),
+            Ok("connector_txn_789")
+        );
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_build_error_update_for_unified_error_and_message() {
+        let refund_update = super::RefundUpdateInternal::from(
+            super::RefundUpdate::build_error_update_for_unified_error_and_message(
+                ("UE_9000".to_string(), "Something went wrong".to_string()),
+                Some("Card declined".to_string()),
+                Some("card_declined".to_string()),
+                Some("05".to_string()),
+                Some("Do not honor".to_string()),
+                &super::storage_enums::MerchantStorageScheme::PostgresOnly,
+            ),
+        );
+
+        assert_eq!(
+            refund_update.refund_status,
+            Some(super::storage_enums::RefundStatus::Failure)
+        );
+        assert_eq!(
+            refund_update.refund_error_message.as_deref(),
+            Some("Card declined")
+        );
+        assert_eq!(
+            refund_update.refund_error_code.as_deref(),
+            Some("card_declined")
+        );
+        assert_eq!(refund_update.updated_by, "postgres_only");
+        assert_eq!(refund_update.connector_refund_id, None);
+        assert_eq!(refund_update.processor_refund_data, None);
+        assert_eq!(refund_update.unified_code.as_deref(), Some("UE_9000"));
+        assert_eq!(
+            refund_update.unified_message.as_deref(),
+            Some("Something went wrong")
+        );
+        assert_eq!(refund_update.issuer_error_code.as_deref(), Some("05"));
+        assert_eq!(
+            refund_update.issuer_error_message.as_deref(),
+            Some("Do not honor")
+        );
+        assert_eq!(refund_update.sent_to_gateway, None);
+        assert_eq!(refund_update.refund_arn, None);
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_build_error_update_for_integrity_check_failure() {
+        // Connector refund ids longer than 128 characters are stored hashed
+        let long_refund_id = "a".repeat(150);
+        let refund_update = super::RefundUpdateInternal::from(
+            super::RefundUpdate::build_error_update_for_integrity_check_failure(
+                "refund_amount".to_string(),
+                Some(long_refund_id.clone()),
+                &super::storage_enums::MerchantStorageScheme::PostgresOnly,
+            ),
+        );
+
+        assert_eq!(
+            refund_update.refund_status,
+            Some(super::storage_enums::RefundStatus::ManualReview)
+        );
+        assert_eq!(
+            refund_update.refund_error_message.as_deref(),
+            Some("Integrity Check Failed! as data mismatched for fields refund_amount")
+        );
+        assert_eq!(refund_update.refund_error_code.as_deref(), Some("IE"));
+        assert_eq!(refund_update.updated_by, "postgres_only");
+        assert_eq!(
+            refund_update.connector_refund_id,
+            Some(super::ConnectorTransactionId::from(long_refund_id.clone()))
+        );
+        assert_eq!(refund_update.processor_refund_data, Some(long_refund_id));
+        assert_eq!(refund_update.unified_code, None);
+        assert_eq!(refund_update.unified_message, None);
+        assert_eq!(refund_update.issuer_error_code, None);
+        assert_eq!(refund_update.issuer_error_message, None);
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_build_refund_update() {
+        let refund_update =
+            super::RefundUpdateInternal::from(super::RefundUpdate::build_refund_update(
+                "re_123".to_string(),
+                super::storage_enums::RefundStatus::Success,
+                &super::storage_enums::MerchantStorageScheme::PostgresOnly,
+            ));
+
+        assert_eq!(
+            refund_update.connector_refund_id,
+            Some(super::ConnectorTransactionId::from("re_123".to_string()))
+        );
+        assert_eq!(
+            refund_update.refund_status,
+            Some(super::storage_enums::RefundStatus::Success)
+        );
+        assert_eq!(refund_update.sent_to_gateway, Some(true));
+        assert_eq!(refund_update.refund_error_message, None);
+        assert_eq!(refund_update.refund_arn.as_deref(), Some(""));
+        assert_eq!(refund_update.updated_by, "postgres_only");
+        assert_eq!(refund_update.processor_refund_data, None);
+        assert_eq!(refund_update.refund_error_code, None);
+        assert_eq!(refund_update.unified_code, None);
+        assert_eq!(refund_update.issuer_error_code, None);
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_build_error_update_for_refund_failure() {
+        let refund_update = super::RefundUpdateInternal::from(
+            super::RefundUpdate::build_error_update_for_refund_failure(
+                None,
+                Some("Gateway timeout".to_string()),
+                Some("timeout".to_string()),
+                Some("91".to_string()),
+                None,
+                &super::storage_enums::MerchantStorageScheme::RedisKv,
+            ),
+        );
+
+        assert_eq!(refund_update.refund_status, None);
+        assert_eq!(
+            refund_update.refund_error_message.as_deref(),
+            Some("Gateway timeout")
+        );
+        assert_eq!(refund_update.refund_error_code.as_deref(), Some("timeout"));
+        assert_eq!(refund_update.updated_by, "redis_kv");
+        assert_eq!(refund_update.connector_refund_id, None);
+        assert_eq!(refund_update.processor_refund_data, None);
+        assert_eq!(refund_update.unified_code, None);
+        assert_eq!(refund_update.unified_message, None);
+        assert_eq!(refund_update.issuer_error_code.as_deref(), Some("91"));
+        assert_eq!(refund_update.issuer_error_message, None);
+    }
+
+    /// A refund stored before split refunds, unified errors and processor data were added, when
+    /// connector transaction ids were serialized as plain strings
+    #[cfg(feature = "v1")]
+    const REFUND_BEFORE_SPLIT_REFUNDS: &str = r#"{
     "internal_reference_id": "internal_ref_123",
     "refund_id": "refund_456",
     "payment_id": "payment_789",
     "merchant_id": "merchant_123",
     "connector_transaction_id": "connector_txn_789",
     "connector": "stripe",
-    "connector_refund_id": null,
+    "connector_refund_id": "connector_refund_123",
     "external_reference_id": null,
     "refund_type": "instant_refund",
     "total_amount": 10000,
@@ -870,7 +1760,7 @@ mod tests {
     "metadata": null,
     "refund_arn": null,
     "created_at": "2024-02-26T12:00:00Z",
-    "updated_at": "2024-02-26T12:00:00Z",
+    "modified_at": "2024-02-26T12:00:00Z",
     "description": null,
     "attempt_id": "attempt_123",
     "refund_reason": null,
@@ -879,13 +1769,270 @@ mod tests {
     "updated_by": "admin",
     "merchant_connector_id": null,
     "charges": null,
+    "organization_id": "org_123",
+    "connector_refund_data": null,
     "connector_transaction_data": null
+}"#;
+
+    /// A refund stored before issuer errors and refund origins were added
+    #[cfg(feature = "v1")]
+    const REFUND_BEFORE_ISSUER_ERROR_CODE: &str = r#"{
+    "internal_reference_id": "internal_ref_123",
+    "refund_id": "refund_456",
+    "payment_id": "payment_789",
+    "merchant_id": "merchant_123",
+    "connector_transaction_id": {"TxnId": "connector_txn_789"},
+    "connector": "xendit",
+    "connector_refund_id": {"HashedData": "hs_hash_123"},
+    "external_reference_id": null,
+    "refund_type": "instant_refund",
+    "total_amount": 10000,
+    "currency": "IDR",
+    "refund_amount": 9500,
+    "refund_status": "failure",
+    "sent_to_gateway": true,
+    "refund_error_message": "insufficient balance",
+    "metadata": null,
+    "refund_arn": null,
+    "created_at": "2024-08-26T12:00:00.000Z",
+    "modified_at": "2024-08-26T12:05:00.000Z",
+    "description": null,
+    "attempt_id": "attempt_123",
+    "refund_reason": null,
+    "refund_error_code": "balance_insufficient",
+    "profile_id": "pro_123",
+    "updated_by": "redis_kv",
+    "merchant_connector_id": "mca_123",
+    "charges": null,
+    "organization_id": "org_123",
+    "connector_refund_data": null,
+    "connector_transaction_data": null,
+    "split_refunds": {"xendit_split_refund": {"for_user_id": "sub_merchant_123"}},
+    "unified_code": "UE_9000",
+    "unified_message": "Something went wrong",
+    "processor_refund_data": "connector_refund_123",
+    "processor_transaction_data": null
+}"#;
+
+    /// A v2 refund stored before issuer errors were added to the v2 schema
+    #[cfg(feature = "v2")]
+    const REFUND_V2_BEFORE_ISSUER_ERROR_CODE: &str = r#"{
+    "payment_id": "12345_pay_abcdefghijklmnopqrstuvwxyz1234567890",
+    "merchant_id": "merchant_123",
+    "connector_transaction_id": {"TxnId": "connector_txn_789"},
+    "connector": "stripe",
+    "connector_refund_id": {"TxnId": "connector_refund_123"},
+    "external_reference_id": null,
+    "refund_type": "instant_refund",
+    "total_amount": 10000,
+    "currency": "USD",
+    "refund_amount": 9500,
+    "refund_status": "pending",
+    "sent_to_gateway": true,
+    "refund_error_message": null,
+    "metadata": null,
+    "refund_arn": null,
+    "created_at": "2025-02-26T12:00:00.000Z",
+    "modified_at": "2025-02-26T12:00:00.000Z",
+    "description": null,
+    "attempt_id": "12345_att_abcdefghijklmnopqrstuvwxyz1234567890",
+    "refund_reason": null,
+    "refund_error_code": null,
+    "profile_id": "pro_123",
+    "updated_by": "postgres_only",
+    "charges": null,
+    "organization_id": "org_123",
+    "split_refunds": null,
     "unified_code": null,
     "unified_message": null,
+    "processor_refund_data": null,
     "processor_transaction_data": null,
+    "id": "12345_ref_abcdefghijklmnopqrstuvwxyz1234567890",
+    "merchant_reference_id": "ref_123",
+    "connector_id": "mca_123",
+    "origin": "api"
 }"#;
-        let deserialized = serde_json::from_str::<super::Refund>(serialized_refund);
 
-        assert!(deserialized.is_ok());
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_backwards_compatibility() {
+        let refund = serde_json::from_str::<super::Refund>(REFUND_BEFORE_SPLIT_REFUNDS).unwrap();
+
+        assert_eq!(
+            refund.connector_transaction_id,
+            super::ConnectorTransactionId::TxnId("connector_txn_789".to_string())
+        );
+        assert_eq!(
+            refund.connector_refund_id,
+            Some(super::ConnectorTransactionId::TxnId(
+                "connector_refund_123".to_string()
+            ))
+        );
+        assert_eq!(
+            refund.refund_status,
+            super::storage_enums::RefundStatus::Success
+        );
+        assert_eq!(refund.split_refunds, None);
+        assert_eq!(refund.unified_code, None);
+        assert_eq!(refund.processor_refund_data, None);
+        assert_eq!(refund.issuer_error_code, None);
+        assert_eq!(refund.origin, super::storage_enums::RefundOrigin::Legacy);
+
+        let refund =
+            serde_json::from_str::<super::Refund>(REFUND_BEFORE_ISSUER_ERROR_CODE).unwrap();
+
+        assert_eq!(
+            refund.connector_refund_id,
+            Some(super::ConnectorTransactionId::HashedData(
+                "hs_hash_123".to_string()
+            ))
+        );
+        assert_eq!(
+            refund.refund_status,
+            super::storage_enums::RefundStatus::Failure
+        );
+        assert_eq!(
+            refund.split_refunds,
+            Some(common_types::refunds::SplitRefund::XenditSplitRefund(
+                common_types::domain::XenditSplitSubMerchantData {
+                    for_user_id: "sub_merchant_123".to_string(),
+                }
+            ))
+        );
+        assert_eq!(refund.unified_code.as_deref(), Some("UE_9000"));
+        assert_eq!(
+            refund.processor_refund_data.as_deref(),
+            Some("connector_refund_123")
+        );
+        assert_eq!(refund.issuer_error_code, None);
+        assert_eq!(refund.issuer_error_message, None);
+        assert_eq!(refund.origin, super::storage_enums::RefundOrigin::Legacy);
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_refund_without_organization_id_is_rejected() {
+        // There is no organization to default to, and refunds stored in redis before organization
+        // ids were added have since expired
+        let refund_without_organization_id =
+            REFUND_BEFORE_SPLIT_REFUNDS.replace("\"organization_id\": \"org_123\",", "");
+
+        let error = serde_json::from_str::<super::Refund>(&refund_without_organization_id)
+            .err()
+            .map(|error| error.to_string());
+
+        assert!(error.is_some_and(|error| error.contains("organization_id")));
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_serialization_round_trip() {
+        let epoch = time::PrimitiveDateTime::new(
+            time::OffsetDateTime::UNIX_EPOCH.date(),
+            time::OffsetDateTime::UNIX_EPOCH.time(),
+        );
+        let refund = super::Refund {
+            connector_refund_id: Some(super::ConnectorTransactionId::from("re_".repeat(50))),
+            processor_refund_data: Some("re_".repeat(50)),
+            created_at: epoch,
+            modified_at: epoch,
+            unified_code: Some("UE_9000".to_string()),
+            issuer_error_code: Some("05".to_string()),
+            origin: super::storage_enums::RefundOrigin::Dashboard,
+            ..refund_with_split_data()
+        };
+
+        let serialized = serde_json::to_string(&refund).unwrap();
+
+        assert_eq!(
+            serde_json::from_str::<super::Refund>(&serialized).unwrap(),
+            refund
+        );
+    }
+
+    #[cfg(feature = "v2")]
+    #[test]
+    fn test_v2_backwards_compatibility() {
+        let refund =
+            serde_json::from_str::<super::Refund>(REFUND_V2_BEFORE_ISSUER_ERROR_CODE).unwrap();
+
+        assert_eq!(
+            refund.refund_status,
+            super::storage_enums::RefundStatus::Pending
+        );
+        assert_eq!(refund.issuer_error_code, None);
+        assert_eq!(refund.issuer_error_message, None);
+    }
+
+    #[cfg(feature = "v2")]
+    #[test]
+    fn test_v2_serialization_round_trip() {
+        let refund = super::Refund {
+            issuer_error_code: Some("05".to_string()),
+            issuer_error_message: Some("Do not honor".to_string()),
+            ..serde_json::from_str::<super::Refund>(REFUND_V2_BEFORE_ISSUER_ERROR_CODE).unwrap()
+        };
+
+        let serialized = serde_json::to_string(&refund).unwrap();
+
+        assert_eq!(
+            serde_json::from_str::<super::Refund>(&serialized).unwrap(),
+            refund
+        );
+    }
+
+    #[cfg(feature = "v2")]
+    #[test]
+    fn test_v2_error_update_sets_issuer_error() {
+        let refund =
+            serde_json::from_str::<super::Refund>(REFUND_V2_BEFORE_ISSUER_ERROR_CODE).unwrap();
+
+        let refund = super::RefundUpdate::build_error_update_for_unified_error_and_message(
+            ("UE_9000".to_string(), "Something went wrong".to_string()),
+            Some("Card declined".to_string()),
+            Some("card_declined".to_string()),
+            Some("05".to_string()),
+            Some("Do not honor".to_string()),
+            &super::storage_enums::MerchantStorageScheme::PostgresOnly,
+        )
+        .apply_changeset(refund);
+
+        assert_eq!(
+            refund.refund_status,
+            super::storage_enums::RefundStatus::Failure
+        );
+        assert_eq!(refund.unified_code.as_deref(), Some("UE_9000"));
+        assert_eq!(refund.issuer_error_code.as_deref(), Some("05"));
+        assert_eq!(refund.issuer_error_message.as_deref(), Some("Do not honor"));
+
+        // Updates without issuer errors keep the stored issuer errors
+        let refund = super::RefundUpdate::ArnUpdate {
+            refund_arn: "arn_123".to_string(),
+            updated_by: "postgres_only".to_string(),
+        }
+        .apply_changeset(refund);
+
+        assert_eq!(refund.refund_arn.as_deref(), Some("arn_123"));
+        assert_eq!(refund.issuer_error_code.as_deref(), Some("05"));
+        assert_eq!(refund.issuer_error_message.as_deref(), Some("Do not honor"));
+    }
+
+    #[cfg(feature = "v2")]
+    #[test]
+    fn test_v2_build_error_update_for_refund_failure() {
+        let refund_update = super::RefundUpdateInternal::from(
+            super::RefundUpdate::build_error_update_for_refund_failure(
+                Some(super::storage_enums::RefundStatus::Failure),
+                Some("Card declined".to_string()),
+                Some("card_declined".to_string()),
+                Some("91".to_string()),
+                None,
+                &super::storage_enums::MerchantStorageScheme::PostgresOnly,
+            ),
+        );
+
+        assert_eq!(refund_update.issuer_error_code.as_deref(), Some("91"));
+        assert_eq!(refund_update.issuer_error_message, None);
+        assert_eq!(refund_update.unified_code, None);
     }
 }
@@ -0,0 +1,207 @@
+use common_utils::types::MinorUnit;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{refund_batch, refund_batch_entry},
+};
+
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    Identifiable,
+    Queryable,
+    Selectable,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[diesel(table_name = refund_batch, primary_key(batch_id), check_for_backend(diesel::pg::Pg))]
+pub struct RefundBatch {
+    pub batch_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    pub organization_id: common_utils::id_type::OrganizationId,
+    pub status: storage_enums::RefundBatchStatus,
+    pub total_entries: i32,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = refund_batch)]
+pub struct RefundBatchNew {
+    pub batch_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    pub organization_id: common_utils::id_type::OrganizationId,
+    pub status: storage_enums::RefundBatchStatus,
+    pub total_entries: i32,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone)]
+pub enum RefundBatchUpdate {
+    StatusUpdate {
+        status: storage_enums::RefundBatchStatus,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = refund_batch)]
+pub struct RefundBatchUpdateInternal {
+    status: storage_enums::RefundBatchStatus,
+    modified_at: PrimitiveDateTime,
+}
+
+impl From<RefundBatchUpdate> for RefundBatchUpdateInternal {
+    fn from(refund_batch_update: RefundBatchUpdate) -> Self {
+        match refund_batch_update {
+            RefundBatchUpdate::StatusUpdate { status } => Self {
+                status,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
+
+impl RefundBatchUpdate {
+    pub fn apply_changeset(self, source: RefundBatch) -> RefundBatch {
+        let RefundBatchUpdateInternal {
+            status,
+            modified_at,
+        } = self.into();
+        RefundBatch {
+            status,
+            modified_at,
+            ..source
+        }
+    }
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    Identifiable,
+    Queryable,
+    Selectable,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[diesel(table_name = refund_batch_entry, primary_key(batch_id, entry_index), check_for_backend(diesel::pg::Pg))]
+pub struct RefundBatchEntry {
+    pub batch_id: String,
+    pub entry_index: i32,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub refund_id: String,
+    pub amount: Option<MinorUnit>,
+    pub reason: Option<String>,
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    pub status: storage_enums::RefundBatchEntryStatus,
+    pub refund_status: Option<storage_enums::RefundStatus>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = refund_batch_entry)]
+pub struct RefundBatchEntryNew {
+    pub batch_id: String,
+    pub entry_index: i32,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub refund_id: String,
+    pub amount: Option<MinorUnit>,
+    pub reason: Option<String>,
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    pub status: storage_enums::RefundBatchEntryStatus,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone)]
+pub enum RefundBatchEntryUpdate {
+    Submitted {
+        refund_status: storage_enums::RefundStatus,
+    },
+    Failed {
+        error_code: Option<String>,
+        error_message: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = refund_batch_entry)]
+pub struct RefundBatchEntryUpdateInternal {
+    status: storage_enums::RefundBatchEntryStatus,
+    refund_status: Option<storage_enums::RefundStatus>,
+    error_code: Option<String>,
+    error_message: Option<String>,
+    modified_at: PrimitiveDateTime,
+}
+
+impl From<RefundBatchEntryUpdate> for RefundBatchEntryUpdateInternal {
+    fn from(refund_batch_entry_update: RefundBatchEntryUpdate) -> Self {
+        match refund_batch_entry_update {
+            RefundBatchEntryUpdate::Submitted { refund_status } => Self {
+                status: storage_enums::RefundBatchEntryStatus::Submitted,
+                refund_status: Some(refund_status),
+                error_code: None,
+                error_message: None,
+                modified_at: common_utils::date_time::now(),
+            },
+            RefundBatchEntryUpdate::Failed {
+                error_code,
+                error_message,
+            } => Self {
+                status: storage_enums::RefundBatchEntryStatus::Failed,
+                refund_status: None,
+                error_code,
+                error_message,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
+
+impl RefundBatchEntryUpdate {
+    pub fn apply_changeset(self, source: RefundBatchEntry) -> RefundBatchEntry {
+        let RefundBatchEntryUpdateInternal {
+            status,
+            refund_status,
+            error_code,
+            error_message,
+            modified_at,
+        } = self.into();
+        RefundBatchEntry {
+            status,
+            refund_status: refund_status.or(source.refund_status),
+            error_code: error_code.or(source.error_code),
+            error_message: error_message.or(source.error_message),
+            modified_at,
+            ..source
+        }
+    }
+}
+
+/// Tracking data of the process tracker task which submits the entries of a refund batch
+#[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct RefundBatchWorkflow {
+    pub batch_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+}
@@ -715,6 +715,11 @@ diesel::table! {
         merchant_connector_id -> Nullable<Varchar>,
         #[max_length = 64]
         updated_by -> Nullable<Varchar>,
+        #[max_length = 32]
+        frequency -> Nullable<Varchar>,
+        last_charged_at -> Nullable<Timestamp>,
+        modified_at -> Timestamp,
+        merchant_metadata -> Nullable<Jsonb>,
     }
 }
 
@@ -1325,6 +1330,59 @@ diesel::table! {
         #[max_length = 64]
         issuer_error_code -> Nullable<Varchar>,
         issuer_error_message -> Nullable<Text>,
+        #[max_length = 32]
+        origin -> Varchar,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    refund_batch (batch_id) {
+        #[max_length = 64]
+        batch_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        organization_id -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        total_entries -> Int4,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    refund_batch_entry (batch_id, entry_index) {
+        #[max_length = 64]
+        batch_id -> Varchar,
+        entry_index -> Int4,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        refund_id -> Varchar,
+        amount -> Nullable<Int8>,
+        #[max_length = 255]
+        reason -> Nullable<Varchar>,
+        #[max_length = 32]
+        merchant_connector_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        status -> Varchar,
+        refund_status -> Nullable<RefundStatus>,
+        #[max_length = 64]
+        error_code -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
     }
 }
 
@@ -1611,6 +1669,8 @@ diesel::allow_tables_to_appear_in_same_query!(
     payouts,
     process_tracker,
     refund,
+    refund_batch,
+    refund_batch_entry,
     relay,
     reverse_lookup,
     roles,
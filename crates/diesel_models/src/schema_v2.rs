@@ -728,6 +728,11 @@ diesel::table! {
         merchant_connector_id -> Nullable<Varchar>,
         #[max_length = 64]
         updated_by -> Nullable<Varchar>,
+        #[max_length = 32]
+        frequency -> Nullable<Varchar>,
+        last_charged_at -> Nullable<Timestamp>,
+        modified_at -> Timestamp,
+        merchant_metadata -> Nullable<Jsonb>,
     }
 }
 
@@ -1257,6 +1262,11 @@ diesel::table! {
         merchant_reference_id -> Varchar,
         #[max_length = 64]
         connector_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        origin -> Varchar,
+        #[max_length = 64]
+        issuer_error_code -> Nullable<Varchar>,
+        issuer_error_message -> Nullable<Text>,
     }
 }
 
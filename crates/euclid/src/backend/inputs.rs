@@ -62,3 +62,16 @@ pub struct BackendInput {
     pub issuer_data: Option<IssuerDataInput>,
     pub mandate: MandateData,
 }
+
+/// The input of a payout routing program, holding a value for each of the payout routing keys
+#[cfg(feature = "payouts")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PayoutBackendInput {
+    pub amount: common_utils::types::MinorUnit,
+    pub business_country: Option<enums::Country>,
+    pub billing_country: Option<enums::Country>,
+    pub business_label: Option<String>,
+    pub payout_type: Option<enums::PayoutType>,
+    pub wallet_type: Option<enums::PayoutWalletType>,
+    pub bank_transfer_type: Option<enums::PayoutBankTransferType>,
+}
@@ -5,6 +5,7 @@ use common_utils::types::MinorUnit;
 use crate::{
     backend::{self, inputs, EuclidBackend},
     frontend::ast,
+    types::EuclidKey,
 };
 
 pub struct InterpreterBackend<O> {
@@ -38,12 +39,104 @@ where
         Ok(false)
     }
 
+    /// Evaluates a comparison referencing a segment against each of the values of the segment.
+    /// A `not_equal` comparison holds when it holds for all the values, any other comparison when
+    /// it holds for any of them.
+    fn eval_segment_comparison(
+        comparison: &ast::Comparison,
+        name: &str,
+        segments: &ast::Segments,
+        ctx: &types::Context,
+    ) -> Result<bool, types::InterpreterError> {
+        let segment = ast::resolve_segment(segments, name, &comparison.lhs).map_err(|err| {
+            types::InterpreterError {
+                error_type: types::InterpreterErrorType::InvalidSegment(err),
+                metadata: comparison.metadata.clone(),
+            }
+        })?;
+
+        let mut results = segment.values.iter().map(|value| {
+            let value_comparison = ast::Comparison {
+                lhs: comparison.lhs.clone(),
+                comparison: comparison.comparison.clone(),
+                value: value.clone(),
+                metadata: comparison.metadata.clone(),
+            };
+            Self::eval_comparison(&value_comparison, segments, ctx)
+        });
+
+        match comparison.comparison {
+            ast::ComparisonType::NotEqual => {
+                results.try_fold(true, |acc, res| res.map(|res| acc && res))
+            }
+            _ => results.try_fold(false, |acc, res| res.map(|res| acc || res)),
+        }
+    }
+
+    /// Validates that the segments referenced by the conditions of the statement and its nested
+    /// statements can be resolved
+    fn validate_segment_references(
+        stmt: &ast::IfStatement,
+        segments: &ast::Segments,
+    ) -> Result<(), types::InterpreterError> {
+        for comparison in &stmt.condition {
+            if let ast::ValueType::SegmentReference(name) = &comparison.value {
+                ast::resolve_segment(segments, name, &comparison.lhs).map_err(|err| {
+                    types::InterpreterError {
+                        error_type: types::InterpreterErrorType::InvalidSegment(err),
+                        metadata: comparison.metadata.clone(),
+                    }
+                })?;
+            }
+        }
+
+        stmt.nested
+            .iter()
+            .flatten()
+            .try_for_each(|nested| Self::validate_segment_references(nested, segments))
+    }
+
+    /// Evaluates a comparison against an entry of the metadata of the input. An entry missing
+    /// from the metadata fails an `equal` comparison and satisfies a `not_equal` one.
+    fn eval_metadata_comparison(
+        comparison: &ast::Comparison,
+        metadata: &ast::MetadataValue,
+        ctx: &types::Context,
+    ) -> Result<bool, types::InterpreterError> {
+        if comparison.lhs != EuclidKey::Metadata.to_string() {
+            return Err(types::InterpreterError {
+                error_type: types::InterpreterErrorType::InvalidKey(comparison.lhs.clone()),
+                metadata: comparison.metadata.clone(),
+            });
+        }
+
+        let value = ctx.get_metadata_value(&metadata.key);
+
+        match comparison.comparison {
+            ast::ComparisonType::Equal => Ok(value == Some(&metadata.value)),
+            ast::ComparisonType::NotEqual => Ok(value != Some(&metadata.value)),
+            _ => Err(types::InterpreterError {
+                error_type: types::InterpreterErrorType::InvalidComparison,
+                metadata: comparison.metadata.clone(),
+            }),
+        }
+    }
+
     fn eval_comparison(
         comparison: &ast::Comparison,
+        segments: &ast::Segments,
         ctx: &types::Context,
     ) -> Result<bool, types::InterpreterError> {
         use ast::{ComparisonType::*, ValueType::*};
 
+        if let SegmentReference(name) = &comparison.value {
+            return Self::eval_segment_comparison(comparison, name, segments, ctx);
+        }
+
+        if let MetadataVariant(metadata) = &comparison.value {
+            return Self::eval_metadata_comparison(comparison, metadata, ctx);
+        }
+
         let value = ctx
             .get(&comparison.lhs)
             .ok_or_else(|| types::InterpreterError {
@@ -82,10 +175,11 @@ where
 
     fn eval_if_condition(
         condition: &ast::IfCondition,
+        segments: &ast::Segments,
         ctx: &types::Context,
     ) -> Result<bool, types::InterpreterError> {
         for comparison in condition {
-            let res = Self::eval_comparison(comparison, ctx)?;
+            let res = Self::eval_comparison(comparison, segments, ctx)?;
 
             if !res {
                 return Ok(false);
@@ -97,9 +191,10 @@ where
 
     fn eval_if_statement(
         stmt: &ast::IfStatement,
+        segments: &ast::Segments,
         ctx: &types::Context,
     ) -> Result<bool, types::InterpreterError> {
-        let cond_res = Self::eval_if_condition(&stmt.condition, ctx)?;
+        let cond_res = Self::eval_if_condition(&stmt.condition, segments, ctx)?;
 
         if !cond_res {
             return Ok(false);
@@ -107,7 +202,7 @@ where
 
         if let Some(ref nested) = stmt.nested {
             for nested_if in nested {
-                let res = Self::eval_if_statement(nested_if, ctx)?;
+                let res = Self::eval_if_statement(nested_if, segments, ctx)?;
 
                 if res {
                     return Ok(true);
@@ -122,10 +217,11 @@ where
 
     fn eval_rule_statements(
         statements: &[ast::IfStatement],
+        segments: &ast::Segments,
         ctx: &types::Context,
     ) -> Result<bool, types::InterpreterError> {
         for stmt in statements {
-            let res = Self::eval_if_statement(stmt, ctx)?;
+            let res = Self::eval_if_statement(stmt, segments, ctx)?;
 
             if res {
                 return Ok(true);
@@ -138,30 +234,84 @@ where
     #[inline]
     fn eval_rule(
         rule: &ast::Rule<O>,
+        segments: &ast::Segments,
         ctx: &types::Context,
     ) -> Result<bool, types::InterpreterError> {
-        Self::eval_rule_statements(&rule.statements, ctx)
+        Self::eval_rule_statements(&rule.statements, segments, ctx)
     }
 
-    fn eval_program(
+    /// Returns the index of the first rule of the program matching the context
+    fn eval_matching_rule(
         program: &ast::Program<O>,
         ctx: &types::Context,
-    ) -> Result<backend::BackendOutput<O>, types::InterpreterError> {
-        for rule in &program.rules {
-            let res = Self::eval_rule(rule, ctx)?;
+    ) -> Result<Option<usize>, types::InterpreterError> {
+        for (index, rule) in program.rules.iter().enumerate() {
+            let res = Self::eval_rule(rule, &program.segments, ctx)?;
 
             if res {
-                return Ok(backend::BackendOutput {
-                    connector_selection: rule.connector_selection.clone(),
-                    rule_name: Some(rule.name.clone()),
-                });
+                return Ok(Some(index));
             }
         }
 
-        Ok(backend::BackendOutput {
+        Ok(None)
+    }
+
+    fn eval_program(
+        program: &ast::Program<O>,
+        ctx: &types::Context,
+    ) -> Result<backend::BackendOutput<O>, types::InterpreterError> {
+        Ok(Self::eval_program_with_rule_index(program, ctx)?.1)
+    }
+
+    fn eval_program_with_rule_index(
+        program: &ast::Program<O>,
+        ctx: &types::Context,
+    ) -> Result<(Option<usize>, backend::BackendOutput<O>), types::InterpreterError> {
+        let rule_index = Self::eval_matching_rule(program, ctx)?;
+        let output = match rule_index.and_then(|index| program.rules.get(index)) {
+            Some(rule) => backend::BackendOutput {
+                connector_selection: rule.connector_selection.clone(),
+                rule_name: Some(rule.name.clone()),
+            },
+            None => Self::eval_default_selection(program),
+        };
+
+        Ok((rule_index, output))
+    }
+
+    #[inline]
+    fn eval_default_selection(program: &ast::Program<O>) -> backend::BackendOutput<O> {
+        backend::BackendOutput {
             connector_selection: program.default_selection.clone(),
             rule_name: None,
-        })
+        }
+    }
+
+    /// Returns the output of the program when none of its rules match, without evaluating them
+    pub fn execute_default_selection(&self) -> backend::BackendOutput<O> {
+        Self::eval_default_selection(&self.program)
+    }
+
+    /// Executes the program on the input like [`EuclidBackend::execute`], additionally returning
+    /// the index of the rule that matched. The index is `None` when no rule matched and the
+    /// default selection was used.
+    pub fn execute_with_rule_index(
+        &self,
+        input: inputs::BackendInput,
+    ) -> Result<(Option<usize>, backend::BackendOutput<O>), types::InterpreterError> {
+        let ctx: types::Context = input.into();
+        Self::eval_program_with_rule_index(&self.program, &ctx)
+    }
+
+    /// Executes the payout routing program on the payout input, whose keys are the payout
+    /// routing keys instead of the payment ones
+    #[cfg(feature = "payouts")]
+    pub fn execute_payout(
+        &self,
+        input: inputs::PayoutBackendInput,
+    ) -> Result<backend::BackendOutput<O>, types::InterpreterError> {
+        let ctx: types::Context = input.into();
+        Self::eval_program(&self.program, &ctx)
     }
 }
 
@@ -172,6 +322,12 @@ where
     type Error = types::InterpreterError;
 
     fn with_program(program: ast::Program<O>) -> Result<Self, Self::Error> {
+        program
+            .rules
+            .iter()
+            .flat_map(|rule| rule.statements.iter())
+            .try_for_each(|stmt| Self::validate_segment_references(stmt, &program.segments))?;
+
         Ok(Self { program })
     }
 
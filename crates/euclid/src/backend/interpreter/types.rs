@@ -2,7 +2,11 @@ use std::{collections::HashMap, fmt, ops::Deref, string::ToString};
 
 use serde::Serialize;
 
-use crate::{backend::inputs, frontend::ast::ValueType, types::EuclidKey};
+use crate::{
+    backend::inputs,
+    frontend::ast::{self, ValueType},
+    types::EuclidKey,
+};
 
 #[derive(Debug, Clone, Serialize, thiserror::Error)]
 #[serde(tag = "type", content = "data", rename_all = "snake_case")]
@@ -11,6 +15,8 @@ pub enum InterpreterErrorType {
     InvalidKey(String),
     #[error("Invalid Comparison")]
     InvalidComparison,
+    #[error("{0}")]
+    InvalidSegment(ast::SegmentError),
 }
 
 #[derive(Debug, Clone, Serialize, thiserror::Error)]
@@ -25,19 +31,29 @@ impl fmt::Display for InterpreterError {
     }
 }
 
-pub struct Context(HashMap<String, Option<ValueType>>);
+pub struct Context {
+    values: HashMap<String, Option<ValueType>>,
+    metadata: HashMap<String, String>,
+}
+
+impl Context {
+    /// Returns the value of the metadata entry of the input with the given key
+    pub fn get_metadata_value(&self, key: &str) -> Option<&String> {
+        self.metadata.get(key)
+    }
+}
 
 impl Deref for Context {
     type Target = HashMap<String, Option<ValueType>>;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.values
     }
 }
 
 impl From<inputs::BackendInput> for Context {
     fn from(input: inputs::BackendInput) -> Self {
-        let ctx = HashMap::<String, Option<ValueType>>::from_iter([
+        let values = HashMap::<String, Option<ValueType>>::from_iter([
             (
                 EuclidKey::PaymentMethod.to_string(),
                 input
@@ -76,6 +92,58 @@ impl From<inputs::BackendInput> for Context {
             ),
         ]);
 
-        Self(ctx)
+        Self {
+            values,
+            metadata: input.metadata.unwrap_or_default().into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(feature = "payouts")]
+impl From<inputs::PayoutBackendInput> for Context {
+    fn from(input: inputs::PayoutBackendInput) -> Self {
+        let business_country = input
+            .business_country
+            .map(|country| ValueType::EnumVariant(country.to_string()));
+
+        // Keyed by the payout routing keys, the business country being accepted as `country` too
+        let values = HashMap::<String, Option<ValueType>>::from_iter([
+            ("country".to_string(), business_country.clone()),
+            ("business_country".to_string(), business_country),
+            (
+                "billing_country".to_string(),
+                input
+                    .billing_country
+                    .map(|country| ValueType::EnumVariant(country.to_string())),
+            ),
+            (
+                "business_label".to_string(),
+                input.business_label.map(ValueType::StrValue),
+            ),
+            ("amount".to_string(), Some(ValueType::Number(input.amount))),
+            (
+                "payment_method".to_string(),
+                input
+                    .payout_type
+                    .map(|payout_type| ValueType::EnumVariant(payout_type.to_string())),
+            ),
+            (
+                "wallet".to_string(),
+                input
+                    .wallet_type
+                    .map(|wallet_type| ValueType::EnumVariant(wallet_type.to_string())),
+            ),
+            (
+                "bank_transfer".to_string(),
+                input.bank_transfer_type.map(|bank_transfer_type| {
+                    ValueType::EnumVariant(bank_transfer_type.to_string())
+                }),
+            ),
+        ]);
+
+        Self {
+            values,
+            metadata: HashMap::new(),
+        }
     }
 }
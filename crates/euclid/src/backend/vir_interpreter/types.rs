@@ -25,6 +25,7 @@ impl Context {
             types::DataType::MetadataValue => self.atomic_values.contains(value),
             types::DataType::StrValue => self.atomic_values.contains(value),
             types::DataType::EnumVariant => self.atomic_values.contains(value),
+            types::DataType::Segment => self.atomic_values.contains(value),
             types::DataType::Number => {
                 let ctx_num_value = self
                     .numeric_values
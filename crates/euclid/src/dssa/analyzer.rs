@@ -238,6 +238,279 @@ pub fn analyze<O: EuclidAnalysable + EuclidDirFilter>(
     dir::lowering::lower_program(dir_program)
 }
 
+fn is_payment_method_value(value: &dir::DirValue) -> bool {
+    matches!(
+        value,
+        dir::DirValue::PaymentMethod(_)
+            | dir::DirValue::CardType(_)
+            | dir::DirValue::PayLaterType(_)
+            | dir::DirValue::WalletType(_)
+            | dir::DirValue::UpiType(_)
+            | dir::DirValue::VoucherType(_)
+            | dir::DirValue::BankTransferType(_)
+            | dir::DirValue::BankRedirectType(_)
+            | dir::DirValue::BankDebitType(_)
+            | dir::DirValue::CryptoType(_)
+            | dir::DirValue::RewardType(_)
+            | dir::DirValue::GiftCardType(_)
+            | dir::DirValue::CardRedirectType(_)
+            | dir::DirValue::RealTimePaymentType(_)
+            | dir::DirValue::OpenBankingType(_)
+            | dir::DirValue::MobilePaymentType(_)
+    )
+}
+
+/// Analyses whether the payment methods and payment method types asserted by every rule of the
+/// program are supported by at least one of the merchant's connectors, using a knowledge graph
+/// seeded with the merchant's connector accounts.
+///
+/// Unlike the other analyses, an unsupported payment method type does not make the program
+/// invalid, so a warning naming the rule and the payment method type is returned instead.
+pub fn analyze_connector_support<O>(
+    program: &dir::DirProgram<O>,
+    knowledge_graph: &ConstraintGraph<dir::DirValue>,
+    connectors: &[ast::ConnectorChoice],
+) -> Result<Vec<types::AnalysisWarning>, types::AnalysisError> {
+    let connector_values: Vec<dir::DirValue> = connectors
+        .iter()
+        .map(|choice| dir::DirValue::Connector(Box::new(choice.clone())))
+        .collect();
+    let dummy_meta = Metadata::default();
+    let mut warnings = Vec::new();
+
+    for rule in &program.rules {
+        let mut unsupported_values: Vec<dir::DirValue> = Vec::new();
+        let mut ctx_manager = state_machine::RuleContextManager::new(rule, &[]);
+
+        while let Some(ctx) = ctx_manager.advance().map_err(|err| types::AnalysisError {
+            metadata: Default::default(),
+            error_type: types::AnalysisErrorType::StateMachine(err),
+        })? {
+            let payment_method_ctx: types::ConjunctiveContext<'_> = ctx
+                .iter()
+                .filter(|ctx_val| {
+                    ctx_val
+                        .value
+                        .get_assertion()
+                        .is_some_and(is_payment_method_value)
+                })
+                .cloned()
+                .collect();
+
+            if payment_method_ctx.is_empty() {
+                continue;
+            }
+
+            let is_supported = connector_values.iter().any(|connector| {
+                let mut connector_ctx = payment_method_ctx.clone();
+                connector_ctx.push(types::ContextValue::assertion(connector, &dummy_meta));
+
+                knowledge_graph
+                    .perform_context_analysis(&connector_ctx, &mut Memoization::new(), None)
+                    .is_ok()
+            });
+
+            if is_supported {
+                continue;
+            }
+
+            // Report the payment method types when present, as those are what the connectors
+            // are missing support for, and the payment methods otherwise
+            let asserted_values: Vec<&dir::DirValue> = payment_method_ctx
+                .iter()
+                .filter_map(|ctx_val| ctx_val.value.get_assertion())
+                .collect();
+            let has_payment_method_types = asserted_values
+                .iter()
+                .any(|value| !matches!(value, dir::DirValue::PaymentMethod(_)));
+
+            for value in asserted_values {
+                let is_payment_method = matches!(value, dir::DirValue::PaymentMethod(_));
+                if is_payment_method == has_payment_method_types
+                    || unsupported_values.contains(value)
+                {
+                    continue;
+                }
+                unsupported_values.push(value.clone());
+            }
+        }
+
+        warnings.extend(unsupported_values.into_iter().map(|value| {
+            types::AnalysisWarning::UnsupportedPaymentMethodType {
+                rule_name: rule.name.clone(),
+                value,
+            }
+        }));
+    }
+
+    Ok(warnings)
+}
+
+/// Analyses the program for rules which condition on a key without conditioning on the key it
+/// depends on, as declared by [`EuclidDirFilter::KEY_DEPENDENCIES`].
+///
+/// For example, a device display size is only meaningful alongside a device type, so a rule
+/// checking `customer_device_display_size` in a conjunctive context without any condition on
+/// `customer_device_type` produces a warning naming both keys.
+pub fn analyze_key_dependencies<O: EuclidDirFilter>(
+    program: &dir::DirProgram<O>,
+) -> Result<Vec<types::AnalysisWarning>, types::AnalysisError> {
+    let key_dependencies = O::get_key_dependencies();
+    let mut warnings = Vec::new();
+
+    if key_dependencies.is_empty() {
+        return Ok(warnings);
+    }
+
+    for rule in &program.rules {
+        let mut missing_dependencies: Vec<&(dir::DirKeyKind, dir::DirKeyKind)> = Vec::new();
+        let mut ctx_manager = state_machine::RuleContextManager::new(rule, &[]);
+
+        while let Some(ctx) = ctx_manager.advance().map_err(|err| types::AnalysisError {
+            metadata: Default::default(),
+            error_type: types::AnalysisErrorType::StateMachine(err),
+        })? {
+            let ctx_keys: Vec<dir::DirKeyKind> = ctx
+                .iter()
+                .filter_map(|ctx_val| ctx_val.value.get_key())
+                .map(|key| key.kind)
+                .collect();
+
+            for dependency in key_dependencies {
+                let (key, required_key) = dependency;
+                if ctx_keys.contains(key)
+                    && !ctx_keys.contains(required_key)
+                    && !missing_dependencies.contains(&dependency)
+                {
+                    missing_dependencies.push(dependency);
+                }
+            }
+        }
+
+        warnings.extend(missing_dependencies.into_iter().map(|(key, required_key)| {
+            types::AnalysisWarning::MissingKeyDependency {
+                rule_name: rule.name.clone(),
+                key: key.clone(),
+                required_key: required_key.clone(),
+            }
+        }));
+    }
+
+    Ok(warnings)
+}
+
+/// Analyses the program for rules which never match, as every conjunctive context of the rule is
+/// subsumed by a conjunctive context of a single earlier rule.
+///
+/// For example, a rule checking `currency = USD & card_network = Visa` is shadowed by an earlier
+/// rule checking `currency = USD`. Amounts are compared as ranges, while the other values must
+/// match exactly, so a rule shadowed only by a combination of earlier rules is not reported.
+pub fn analyze_shadowed_rules<O>(
+    program: &dir::DirProgram<O>,
+) -> Result<Vec<types::AnalysisWarning>, types::AnalysisError> {
+    let rule_contexts = program
+        .rules
+        .iter()
+        .map(get_rule_contexts)
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut warnings = Vec::new();
+
+    for (rule_index, (rule, contexts)) in program.rules.iter().zip(&rule_contexts).enumerate() {
+        if contexts.is_empty() {
+            continue;
+        }
+
+        let shadowing_rule = rule_contexts
+            .iter()
+            .take(rule_index)
+            .position(|earlier_contexts| is_rule_subsumed(contexts, earlier_contexts))
+            .and_then(|index| program.rules.get(index).map(|rule| (index, rule)));
+
+        if let Some((shadowing_rule_index, shadowing_rule)) = shadowing_rule {
+            warnings.push(types::AnalysisWarning::ShadowedRule {
+                rule_name: rule.name.clone(),
+                rule_index,
+                shadowing_rule_name: shadowing_rule.name.clone(),
+                shadowing_rule_index,
+            });
+        }
+    }
+
+    Ok(warnings)
+}
+
+fn get_rule_contexts<O>(
+    rule: &dir::DirRule<O>,
+) -> Result<Vec<types::ConjunctiveContext<'_>>, types::AnalysisError> {
+    let mut contexts = Vec::new();
+    let mut ctx_manager = state_machine::RuleContextManager::new(rule, &[]);
+
+    while let Some(ctx) = ctx_manager.advance().map_err(|err| types::AnalysisError {
+        metadata: Default::default(),
+        error_type: types::AnalysisErrorType::StateMachine(err),
+    })? {
+        contexts.push(ctx.clone());
+    }
+
+    Ok(contexts)
+}
+
+fn is_rule_subsumed(
+    contexts: &[types::ConjunctiveContext<'_>],
+    earlier_contexts: &[types::ConjunctiveContext<'_>],
+) -> bool {
+    contexts.iter().all(|context| {
+        earlier_contexts
+            .iter()
+            .any(|earlier_context| is_context_subsumed(context, earlier_context))
+    })
+}
+
+/// Whether every payment satisfying `context` also satisfies `earlier_context`
+fn is_context_subsumed(
+    context: &types::ConjunctiveContext<'_>,
+    earlier_context: &types::ConjunctiveContext<'_>,
+) -> bool {
+    earlier_context
+        .iter()
+        .all(|earlier_value| is_context_value_implied(&earlier_value.value, context))
+}
+
+fn is_context_value_implied(
+    value: &types::CtxValueKind<'_>,
+    context: &types::ConjunctiveContext<'_>,
+) -> bool {
+    match value {
+        types::CtxValueKind::Assertion(value) => context
+            .iter()
+            .filter_map(|ctx_val| ctx_val.value.get_assertion())
+            .any(
+                |asserted| match (value.get_num_value(), asserted.get_num_value()) {
+                    (Some(num_value), Some(asserted_num_value)) => {
+                        value.get_key() == asserted.get_key() && num_value.fits(&asserted_num_value)
+                    }
+                    _ => asserted == *value,
+                },
+            ),
+
+        types::CtxValueKind::Negation(negated) => {
+            context.iter().any(|ctx_val| match &ctx_val.value {
+                types::CtxValueKind::Negation(context_negated) => negated
+                    .iter()
+                    .all(|negated_value| context_negated.contains(negated_value)),
+                // Asserting any other value of the key implies the negation
+                types::CtxValueKind::Assertion(asserted) => {
+                    asserted.get_num_value().is_none()
+                        && negated.first().is_some_and(|negated_value| {
+                            negated_value.get_key() == asserted.get_key()
+                        })
+                        && !negated.contains(*asserted)
+                }
+            })
+        }
+    }
+}
+
 #[cfg(all(test, feature = "ast_parser"))]
 mod tests {
     #![allow(clippy::panic, clippy::expect_used)]
@@ -456,4 +729,243 @@ mod tests {
             }
         ));
     }
+
+    fn wallet_connector_graph(
+        wallet_types: &[dir::enums::WalletType],
+    ) -> cgraph::ConstraintGraph<dir::DirValue> {
+        let mut builder = cgraph::ConstraintGraphBuilder::new();
+        let wallet_node =
+            builder.make_value_node(dirval!(PaymentMethod = Wallet).into(), None, None::<()>);
+
+        let wallet_type_aggregators: Vec<_> = wallet_types
+            .iter()
+            .map(|wallet_type| {
+                let wallet_type_node = builder.make_value_node(
+                    dir::DirValue::WalletType(*wallet_type).into(),
+                    None,
+                    None::<()>,
+                );
+                let aggregator = builder
+                    .make_all_aggregator(
+                        &[
+                            (
+                                wallet_node,
+                                cgraph::Relation::Positive,
+                                cgraph::Strength::Strong,
+                            ),
+                            (
+                                wallet_type_node,
+                                cgraph::Relation::Positive,
+                                cgraph::Strength::Strong,
+                            ),
+                        ],
+                        None,
+                        None::<()>,
+                        None,
+                    )
+                    .expect("All aggregator");
+                (
+                    aggregator,
+                    cgraph::Relation::Positive,
+                    cgraph::Strength::Strong,
+                )
+            })
+            .collect();
+
+        let any_aggregator = builder
+            .make_any_aggregator(&wallet_type_aggregators, None, None::<()>, None)
+            .expect("Any aggregator");
+        let connector_node =
+            builder.make_value_node(dirval!(Connector = Stripe).into(), None, None::<()>);
+        builder
+            .make_edge(
+                any_aggregator,
+                connector_node,
+                cgraph::Strength::Normal,
+                cgraph::Relation::Positive,
+                None::<cgraph::DomainId>,
+            )
+            .expect("Edge");
+
+        builder.build()
+    }
+
+    fn amazon_pay_program() -> dir::DirProgram<DummyOutput> {
+        let program_str = r#"
+            default: ["stripe"]
+
+            amazon_pay_rule: ["stripe"]
+            {
+                payment_method = wallet & wallet = amazon_pay
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        ast::lowering::lower_program(program).expect("Lowered program")
+    }
+
+    #[test]
+    fn test_unsupported_payment_method_type_warning() {
+        let graph = wallet_connector_graph(&[dir::enums::WalletType::GooglePay]);
+        let connectors = [ast::ConnectorChoice {
+            connector: crate::enums::RoutableConnectors::Stripe,
+        }];
+
+        let warnings = analyze_connector_support(&amazon_pay_program(), &graph, &connectors)
+            .expect("Analysis");
+
+        assert_eq!(
+            warnings,
+            vec![types::AnalysisWarning::UnsupportedPaymentMethodType {
+                rule_name: "amazon_pay_rule".to_string(),
+                value: dirval!(WalletType = AmazonPay),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_supported_payment_method_type_has_no_warning() {
+        let graph = wallet_connector_graph(&[
+            dir::enums::WalletType::GooglePay,
+            dir::enums::WalletType::AmazonPay,
+        ]);
+        let connectors = [ast::ConnectorChoice {
+            connector: crate::enums::RoutableConnectors::Stripe,
+        }];
+
+        let warnings = analyze_connector_support(&amazon_pay_program(), &graph, &connectors)
+            .expect("Analysis");
+
+        assert!(warnings.is_empty());
+    }
+
+    #[derive(Debug, Clone)]
+    struct DeviceOutput(DummyOutput);
+
+    impl ast::parser::EuclidParsable for DeviceOutput {
+        fn parse_output(input: &str) -> ast::parser::ParseResult<&str, Self> {
+            <DummyOutput as ast::parser::EuclidParsable>::parse_output(input)
+                .map(|(rest, output)| (rest, Self(output)))
+        }
+    }
+
+    impl EuclidDirFilter for DeviceOutput {
+        const ALLOWED: &'static [dir::DirKeyKind] = &[
+            dir::DirKeyKind::CustomerDeviceType,
+            dir::DirKeyKind::CustomerDevicePlatform,
+            dir::DirKeyKind::CustomerDeviceDisplaySize,
+        ];
+        const KEY_DEPENDENCIES: &'static [(dir::DirKeyKind, dir::DirKeyKind)] = &[
+            (
+                dir::DirKeyKind::CustomerDeviceDisplaySize,
+                dir::DirKeyKind::CustomerDeviceType,
+            ),
+            (
+                dir::DirKeyKind::CustomerDevicePlatform,
+                dir::DirKeyKind::CustomerDeviceType,
+            ),
+        ];
+    }
+
+    fn device_program(program_str: &str) -> dir::DirProgram<DeviceOutput> {
+        let (_, program) = ast::parser::program::<DeviceOutput>(program_str).expect("Program");
+        ast::lowering::lower_program(program).expect("Lowered program")
+    }
+
+    #[test]
+    fn test_missing_key_dependency_warning() {
+        let program = device_program(
+            r#"
+            default: ["no_three_ds"]
+
+            small_screen_rule: ["three_ds"]
+            {
+                customer_device_display_size = size320x568
+            }
+        "#,
+        );
+
+        let warnings = analyze_key_dependencies(&program).expect("Analysis");
+
+        assert_eq!(
+            warnings,
+            vec![types::AnalysisWarning::MissingKeyDependency {
+                rule_name: "small_screen_rule".to_string(),
+                key: dir::DirKeyKind::CustomerDeviceDisplaySize,
+                required_key: dir::DirKeyKind::CustomerDeviceType,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_satisfied_key_dependency_has_no_warning() {
+        let program = device_program(
+            r#"
+            default: ["no_three_ds"]
+
+            small_screen_rule: ["three_ds"]
+            {
+                customer_device_type = mobile & customer_device_display_size = size320x568
+            }
+        "#,
+        );
+
+        let warnings = analyze_key_dependencies(&program).expect("Analysis");
+
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_shadowed_rule_warning() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            usd_rule: ["stripe"]
+            {
+                currency = USD
+            }
+
+            usd_visa_rule: ["adyen"]
+            {
+                currency = USD & card_network = Visa
+            }
+
+            high_amount_rule: ["checkout"]
+            {
+                amount > 1000
+            }
+
+            higher_amount_rule: ["adyen"]
+            {
+                currency = (USD, EUR) & amount > 5000
+            }
+
+            low_amount_eur_rule: ["adyen"]
+            {
+                currency = EUR & amount < 500
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let program = ast::lowering::lower_program(program).expect("Lowered program");
+        let warnings = analyze_shadowed_rules(&program).expect("Analysis");
+
+        assert_eq!(
+            warnings,
+            vec![
+                types::AnalysisWarning::ShadowedRule {
+                    rule_name: "usd_visa_rule".to_string(),
+                    rule_index: 1,
+                    shadowing_rule_name: "usd_rule".to_string(),
+                    shadowing_rule_index: 0,
+                },
+                types::AnalysisWarning::ShadowedRule {
+                    rule_name: "higher_amount_rule".to_string(),
+                    rule_index: 3,
+                    shadowing_rule_name: "high_amount_rule".to_string(),
+                    shadowing_rule_index: 2,
+                },
+            ]
+        );
+    }
 }
@@ -202,9 +202,10 @@ impl cgraph::CheckingContext for AnalysisContext {
                 };
 
                 match key.kind.get_type() {
-                    DataType::EnumVariant | DataType::StrValue | DataType::MetadataValue => {
-                        value_set.contains(val)
-                    }
+                    DataType::EnumVariant
+                    | DataType::StrValue
+                    | DataType::MetadataValue
+                    | DataType::Segment => value_set.contains(val),
                     DataType::Number => val.get_num_value().is_some_and(|num_val| {
                         value_set.iter().any(|ctx_val| {
                             ctx_val
@@ -148,12 +148,69 @@ pub enum AnalysisErrorType {
     StateMachine(dssa::state_machine::StateMachineError),
     #[error("Unsupported program key '{0}'")]
     UnsupportedProgramKey(dir::DirKeyKind),
+    #[error("{0}")]
+    InvalidSegment(ast::SegmentError),
     #[error("Ran into an unimplemented feature")]
     NotImplemented,
     #[error("The payment method type is not supported under the payment method")]
     NotSupported,
 }
 
+/// Non fatal findings of the analysis, which do not make the program invalid but are likely to
+/// make it behave differently than intended
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", content = "info", rename_all = "snake_case")]
+pub enum AnalysisWarning {
+    /// None of the merchant's connectors support a payment method or payment method type the rule
+    /// requires, hence payments matching the rule will always fall back to the default selection
+    UnsupportedPaymentMethodType {
+        rule_name: String,
+        value: dir::DirValue,
+    },
+    /// A rule conditions on a key whose meaning depends on another key which the rule neither
+    /// asserts nor negates
+    MissingKeyDependency {
+        rule_name: String,
+        key: dir::DirKeyKind,
+        required_key: dir::DirKeyKind,
+    },
+    /// Every payment a rule matches is also matched by an earlier rule, hence the rule never
+    /// matches
+    ShadowedRule {
+        rule_name: String,
+        rule_index: usize,
+        shadowing_rule_name: String,
+        shadowing_rule_index: usize,
+    },
+}
+
+impl fmt::Display for AnalysisWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedPaymentMethodType { rule_name, value } => write!(
+                f,
+                "Rule '{rule_name}' requires '{value:?}' which is not supported by any connector"
+            ),
+            Self::MissingKeyDependency {
+                rule_name,
+                key,
+                required_key,
+            } => write!(
+                f,
+                "Rule '{rule_name}' uses '{key}' without a condition on '{required_key}'"
+            ),
+            Self::ShadowedRule {
+                rule_name,
+                shadowing_rule_name,
+                ..
+            } => write!(
+                f,
+                "Rule '{rule_name}' never matches, as the earlier rule '{shadowing_rule_name}' matches all of its payments"
+            ),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum ValueType {
     EnumVariants(Vec<EuclidValue>),
@@ -1,6 +1,12 @@
+pub mod hashing;
 pub mod lowering;
+pub mod migration;
 #[cfg(feature = "ast_parser")]
 pub mod parser;
+#[cfg(feature = "ast_parser")]
+pub mod printer;
+
+use std::collections::HashMap;
 
 use common_enums::RoutableConnectors;
 use common_utils::types::MinorUnit;
@@ -43,6 +49,10 @@ pub enum ValueType {
     /// conditions like "500 < amount < 1000"
     /// eg: payment.amount = (> 500, < 1000)
     NumberComparisonArray(Vec<NumberComparison>),
+    /// References a segment of the program by its name. The comparison
+    /// is true when it holds for any of the values of the segment
+    /// eg: { "type": "segment_reference", "value": "high_risk_bins" }
+    SegmentReference(String),
 }
 
 impl ValueType {
@@ -55,10 +65,74 @@ impl ValueType {
             Self::NumberComparisonArray(_) => DataType::Number,
             Self::NumberArray(_) => DataType::Number,
             Self::EnumVariantArray(_) => DataType::EnumVariant,
+            Self::SegmentReference(_) => DataType::Segment,
         }
     }
 }
 
+/// A named list of values for a key, which conditions on that key can reference by name instead
+/// of repeating the values in every rule
+///
+/// ```text
+/// high_risk_bins: card_bin = ("424242", "400000", "510510")
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Segment {
+    /// The key the values are meant for, like "card_bin"
+    pub key: String,
+    /// The values of the segment. These cannot reference other segments
+    pub values: Vec<ValueType>,
+}
+
+/// The segments of a program, keyed by their name
+pub type Segments = HashMap<String, Segment>;
+
+#[derive(Debug, Clone, PartialEq, Serialize, thiserror::Error)]
+#[serde(tag = "type", content = "info", rename_all = "snake_case")]
+pub enum SegmentError {
+    #[error("Segment '{0}' is not defined in the program")]
+    NotFound(String),
+    #[error("Segment '{segment}' holds values for key '{segment_key}' but is compared against key '{key}'")]
+    KeyMismatch {
+        segment: String,
+        segment_key: String,
+        key: String,
+    },
+    #[error("Segment '{0}' references another segment, which is not supported")]
+    NestedReference(String),
+}
+
+/// Looks up the segment referenced by a comparison on `key`, failing when the segment does not
+/// exist, holds values for a different key or itself references a segment
+pub fn resolve_segment<'a>(
+    segments: &'a Segments,
+    name: &str,
+    key: &str,
+) -> Result<&'a Segment, SegmentError> {
+    let segment = segments
+        .get(name)
+        .ok_or_else(|| SegmentError::NotFound(name.to_string()))?;
+
+    if segment.key != key {
+        return Err(SegmentError::KeyMismatch {
+            segment: name.to_string(),
+            segment_key: segment.key.clone(),
+            key: key.to_string(),
+        });
+    }
+
+    if segment
+        .values
+        .iter()
+        .any(|value| matches!(value, ValueType::SegmentReference(_)))
+    {
+        return Err(SegmentError::NestedReference(name.to_string()));
+    }
+
+    Ok(segment)
+}
+
 /// Represents a number comparison for "NumberComparisonArrayValue"
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
@@ -156,6 +230,15 @@ pub struct Program<O> {
     pub rules: Vec<Rule<O>>,
     #[schema(value_type=HashMap<String, serde_json::Value>)]
     pub metadata: Metadata,
+    /// Schema version the program was saved with. Programs saved before versioning was
+    /// introduced are treated as version 0 and upgraded through [`migration::migrate_program`]
+    #[serde(default)]
+    pub version: u32,
+    /// Named value lists which the conditions of the rules can reference through
+    /// [`ValueType::SegmentReference`]
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    #[schema(value_type=HashMap<String, Segment>)]
+    pub segments: Segments,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
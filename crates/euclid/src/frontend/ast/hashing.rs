@@ -0,0 +1,153 @@
+//! Deterministic hashing of programs
+//!
+//! Programs are hashed in their lowered form, serialized with the keys of every object sorted, so
+//! that the hash only changes when the program does, and not with the key order or the formatting
+//! of the JSON the program was read from.
+
+use common_utils::crypto::{GenerateDigest, Sha256};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{
+    dssa::types::AnalysisError,
+    frontend::{ast, ast::lowering, dir::EuclidDirFilter},
+};
+
+#[derive(Debug, Clone, serde::Serialize, thiserror::Error)]
+#[serde(tag = "type", content = "info", rename_all = "snake_case")]
+pub enum ProgramHashError {
+    #[error("Program could not be lowered: {0}")]
+    Lowering(AnalysisError),
+    #[error("Lowered program could not be serialized: {0}")]
+    Serialization(String),
+    #[error("Failed to compute the digest of the program")]
+    Digest,
+}
+
+/// Computes the hex encoded SHA-256 hash of the canonical form of a program
+pub fn hash_program<O>(program: &ast::Program<O>) -> Result<String, ProgramHashError>
+where
+    O: EuclidDirFilter + Clone + Serialize,
+{
+    let lowered_program =
+        lowering::lower_program(program.clone()).map_err(ProgramHashError::Lowering)?;
+    let lowered_program = serde_json::to_value(lowered_program)
+        .map_err(|error| ProgramHashError::Serialization(error.to_string()))?;
+    let canonical_program = canonicalize(lowered_program).to_string();
+
+    let digest = Sha256
+        .generate_digest(canonical_program.as_bytes())
+        .map_err(|_| ProgramHashError::Digest)?;
+
+    Ok(digest.iter().map(|byte| format!("{byte:02x}")).collect())
+}
+
+/// Rebuilds every object of the value with its keys in sorted order
+fn canonicalize(value: Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut entries = map
+                .into_iter()
+                .map(|(key, value)| (key, canonicalize(value)))
+                .collect::<Vec<_>>();
+            entries.sort_by(|(key_a, _), (key_b, _)| key_a.cmp(key_b));
+            Value::Object(entries.into_iter().collect())
+        }
+        Value::Array(values) => Value::Array(values.into_iter().map(canonicalize).collect()),
+        value => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use serde::Deserialize;
+
+    use super::*;
+    use crate::{frontend::dir::DirKeyKind, types::DummyOutput};
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct TestOutput {
+        connectors: Vec<String>,
+    }
+
+    impl EuclidDirFilter for TestOutput {
+        const ALLOWED: &'static [DirKeyKind] = <DummyOutput as EuclidDirFilter>::ALLOWED;
+    }
+
+    fn parse_program(program: &str) -> ast::Program<TestOutput> {
+        serde_json::from_str(program).expect("valid program")
+    }
+
+    fn program_hash(program: &str) -> String {
+        hash_program(&parse_program(program)).expect("program hash")
+    }
+
+    const PROGRAM: &str = r#"{
+        "defaultSelection": { "connectors": ["stripe"] },
+        "metadata": { "created_by": "dashboard", "owner": "payments" },
+        "rules": [{
+            "name": "card_rule",
+            "connectorSelection": { "connectors": ["adyen", "checkout"] },
+            "statements": [{
+                "condition": [{
+                    "lhs": "payment_method",
+                    "comparison": "equal",
+                    "value": { "type": "enum_variant", "value": "card" },
+                    "metadata": { "ui_position": 1, "ui_label": "Payment method" }
+                }],
+                "nested": null
+            }]
+        }]
+    }"#;
+
+    #[test]
+    fn test_program_hash_is_stable_across_serialization_round_trips() {
+        let program = parse_program(PROGRAM);
+        let hash = hash_program(&program).expect("program hash");
+
+        let serialized = serde_json::to_string(&program).expect("serialized program");
+        assert_eq!(program_hash(&serialized), hash);
+
+        let pretty = serde_json::to_string_pretty(&program).expect("serialized program");
+        assert_eq!(program_hash(&pretty), hash);
+    }
+
+    #[test]
+    fn test_program_hash_ignores_key_order_and_whitespace() {
+        let reordered = r#"{"rules":[{"statements":[{"nested":null,"condition":[{
+            "metadata":{"ui_label":"Payment method","ui_position":1},
+            "value":{"value":"card","type":"enum_variant"},
+            "comparison":"equal","lhs":"payment_method"}]}],
+            "connectorSelection":{"connectors":["adyen","checkout"]},"name":"card_rule"}],
+            "metadata":{"owner":"payments","created_by":"dashboard"},
+            "defaultSelection":{"connectors":["stripe"]}}"#;
+
+        assert_eq!(program_hash(reordered), program_hash(PROGRAM));
+    }
+
+    #[test]
+    fn test_program_hash_changes_with_program_values() {
+        let hash = program_hash(PROGRAM);
+
+        let changed_value = PROGRAM.replace(r#""value": "card""#, r#""value": "wallet""#);
+        assert_ne!(program_hash(&changed_value), hash);
+
+        let changed_metadata = PROGRAM.replace(r#""owner": "payments""#, r#""owner": "risk""#);
+        assert_ne!(program_hash(&changed_metadata), hash);
+
+        let reordered_connectors =
+            PROGRAM.replace(r#"["adyen", "checkout"]"#, r#"["checkout", "adyen"]"#);
+        assert_ne!(program_hash(&reordered_connectors), hash);
+    }
+
+    #[test]
+    fn test_program_hash_rejects_invalid_programs() {
+        let invalid = PROGRAM.replace(r#""value": "card""#, r#""value": "not_a_method""#);
+        assert!(matches!(
+            hash_program(&parse_program(&invalid)),
+            Err(ProgramHashError::Lowering(_))
+        ));
+    }
+}
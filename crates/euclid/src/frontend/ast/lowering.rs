@@ -154,6 +154,7 @@ macro_rules! lower_metadata {
 /// can't have greater/less than operations on enum types
 fn lower_comparison_inner<O: EuclidDirFilter>(
     comp: ast::Comparison,
+    segments: &ast::Segments,
 ) -> Result<Vec<dir::DirValue>, AnalysisErrorType> {
     let key_enum = dir::DirKeyKind::from_str(comp.lhs.as_str())
         .map_err(|_| AnalysisErrorType::InvalidKey(comp.lhs.clone()))?;
@@ -162,6 +163,10 @@ fn lower_comparison_inner<O: EuclidDirFilter>(
         return Err(AnalysisErrorType::InvalidKey(key_enum.to_string()));
     }
 
+    if let ast::ValueType::SegmentReference(name) = &comp.value {
+        return lower_segment_reference::<O>(&comp, name, segments);
+    }
+
     match (&comp.comparison, &comp.value) {
         (
             ast::ComparisonType::LessThan
@@ -278,10 +283,40 @@ fn lower_comparison_inner<O: EuclidDirFilter>(
     }
 }
 
+/// lowers a comparison referencing a segment by lowering the comparison against each of the
+/// values of the segment, after validating that the segment exists and holds values for the key
+/// of the comparison
+fn lower_segment_reference<O: EuclidDirFilter>(
+    comp: &ast::Comparison,
+    name: &str,
+    segments: &ast::Segments,
+) -> Result<Vec<dir::DirValue>, AnalysisErrorType> {
+    let segment = ast::resolve_segment(segments, name, &comp.lhs)
+        .map_err(AnalysisErrorType::InvalidSegment)?;
+
+    segment
+        .values
+        .iter()
+        .try_fold(Vec::new(), |mut values, value| {
+            values.extend(lower_comparison_inner::<O>(
+                ast::Comparison {
+                    lhs: comp.lhs.clone(),
+                    comparison: comp.comparison.clone(),
+                    value: value.clone(),
+                    metadata: comp.metadata.clone(),
+                },
+                segments,
+            )?);
+
+            Ok(values)
+        })
+}
+
 /// returns all the comparison values by matching them appropriately to ComparisonTypes and in turn
 /// calls the lower_comparison_inner function
 fn lower_comparison<O: EuclidDirFilter>(
     comp: ast::Comparison,
+    segments: &ast::Segments,
 ) -> Result<dir::DirComparison, AnalysisError> {
     let metadata = comp.metadata.clone();
     let logic = match &comp.comparison {
@@ -292,7 +327,7 @@ fn lower_comparison<O: EuclidDirFilter>(
         ast::ComparisonType::GreaterThanEqual => dir::DirComparisonLogic::PositiveDisjunction,
         ast::ComparisonType::GreaterThan => dir::DirComparisonLogic::PositiveDisjunction,
     };
-    let values = lower_comparison_inner::<O>(comp).map_err(|etype| AnalysisError {
+    let values = lower_comparison_inner::<O>(comp, segments).map_err(|etype| AnalysisError {
         error_type: etype,
         metadata: metadata.clone(),
     })?;
@@ -308,16 +343,21 @@ fn lower_comparison<O: EuclidDirFilter>(
 /// present)
 fn lower_if_statement<O: EuclidDirFilter>(
     stmt: ast::IfStatement,
+    segments: &ast::Segments,
 ) -> Result<dir::DirIfStatement, AnalysisError> {
     Ok(dir::DirIfStatement {
         condition: stmt
             .condition
             .into_iter()
-            .map(lower_comparison::<O>)
+            .map(|comp| lower_comparison::<O>(comp, segments))
             .collect::<Result<_, _>>()?,
         nested: stmt
             .nested
-            .map(|n| n.into_iter().map(lower_if_statement::<O>).collect())
+            .map(|n| {
+                n.into_iter()
+                    .map(|stmt| lower_if_statement::<O>(stmt, segments))
+                    .collect()
+            })
             .transpose()?,
     })
 }
@@ -326,6 +366,15 @@ fn lower_if_statement<O: EuclidDirFilter>(
 /// connector_selection and statements that are a bunch of if statements
 pub fn lower_rule<O: EuclidDirFilter>(
     rule: ast::Rule<O>,
+) -> Result<dir::DirRule<O>, AnalysisError> {
+    lower_rule_with_segments(rule, &ast::Segments::new())
+}
+
+/// lowers the rule like [`lower_rule`], resolving the segments its conditions reference from the
+/// supplied segments of the program
+pub fn lower_rule_with_segments<O: EuclidDirFilter>(
+    rule: ast::Rule<O>,
+    segments: &ast::Segments,
 ) -> Result<dir::DirRule<O>, AnalysisError> {
     Ok(dir::DirRule {
         name: rule.name,
@@ -333,24 +382,156 @@ pub fn lower_rule<O: EuclidDirFilter>(
         statements: rule
             .statements
             .into_iter()
-            .map(lower_if_statement::<O>)
+            .map(|stmt| lower_if_statement::<O>(stmt, segments))
             .collect::<Result<_, _>>()?,
     })
 }
 
 /// uses the above rules and lowers the whole ast Program into DirProgram by specifying
 /// default_selection that is ast ConnectorSelection, a vector of DirRules and clones the metadata
-/// whatever comes in the ast_program
+/// whatever comes in the ast_program. Segments referenced by the rules are resolved to their
+/// values, so the DirProgram does not carry them.
 pub fn lower_program<O: EuclidDirFilter>(
     program: ast::Program<O>,
 ) -> Result<dir::DirProgram<O>, AnalysisError> {
+    let segments = program.segments;
+
     Ok(dir::DirProgram {
         default_selection: program.default_selection,
         rules: program
             .rules
             .into_iter()
-            .map(lower_rule)
+            .map(|rule| lower_rule_with_segments(rule, &segments))
             .collect::<Result<_, _>>()?,
         metadata: program.metadata,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::indexing_slicing)]
+
+    use super::*;
+    use crate::types::DummyOutput;
+
+    fn program_with_segments(
+        lhs: &str,
+        value: ast::ValueType,
+        segments: ast::Segments,
+    ) -> ast::Program<DummyOutput> {
+        let output = DummyOutput {
+            outputs: vec!["stripe".to_string()],
+        };
+
+        ast::Program {
+            default_selection: output.clone(),
+            rules: vec![ast::Rule {
+                name: "segment_rule".to_string(),
+                connector_selection: output,
+                statements: vec![ast::IfStatement {
+                    condition: vec![ast::Comparison {
+                        lhs: lhs.to_string(),
+                        comparison: ast::ComparisonType::Equal,
+                        value,
+                        metadata: Default::default(),
+                    }],
+                    nested: None,
+                }],
+            }],
+            metadata: Default::default(),
+            version: ast::migration::CURRENT_PROGRAM_VERSION,
+            segments,
+        }
+    }
+
+    fn high_risk_bins(key: &str) -> ast::Segments {
+        ast::Segments::from_iter([(
+            "high_risk_bins".to_string(),
+            ast::Segment {
+                key: key.to_string(),
+                values: vec![
+                    ast::ValueType::StrValue("424242".to_string()),
+                    ast::ValueType::StrValue("400000".to_string()),
+                ],
+            },
+        )])
+    }
+
+    fn lowering_error(program: ast::Program<DummyOutput>) -> AnalysisErrorType {
+        lower_program(program)
+            .expect_err("Lowering should fail")
+            .error_type
+    }
+
+    #[test]
+    fn test_segment_reference_resolution() {
+        let program = program_with_segments(
+            "card_bin",
+            ast::ValueType::SegmentReference("high_risk_bins".to_string()),
+            high_risk_bins("card_bin"),
+        );
+
+        let dir_program = lower_program(program).expect("Lowered program");
+        let comparison = &dir_program.rules[0].statements[0].condition[0];
+
+        assert!(matches!(
+            comparison.logic,
+            dir::DirComparisonLogic::PositiveDisjunction
+        ));
+        assert_eq!(
+            comparison.values,
+            vec![
+                dir::DirValue::CardBin(types::StrValue {
+                    value: "424242".to_string()
+                }),
+                dir::DirValue::CardBin(types::StrValue {
+                    value: "400000".to_string()
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_missing_segment() {
+        let program = program_with_segments(
+            "card_bin",
+            ast::ValueType::SegmentReference("unknown_bins".to_string()),
+            high_risk_bins("card_bin"),
+        );
+
+        assert!(matches!(
+            lowering_error(program),
+            AnalysisErrorType::InvalidSegment(ast::SegmentError::NotFound(name))
+                if name == "unknown_bins"
+        ));
+    }
+
+    #[test]
+    fn test_segment_key_mismatch() {
+        let program = program_with_segments(
+            "card_bin",
+            ast::ValueType::SegmentReference("high_risk_bins".to_string()),
+            high_risk_bins("payment_method"),
+        );
+
+        assert!(matches!(
+            lowering_error(program),
+            AnalysisErrorType::InvalidSegment(ast::SegmentError::KeyMismatch { segment_key, key, .. })
+                if segment_key == "payment_method" && key == "card_bin"
+        ));
+    }
+
+    #[test]
+    fn test_program_without_segments_is_serialized_unchanged() {
+        let program = program_with_segments(
+            "payment_method",
+            ast::ValueType::EnumVariant("card".to_string()),
+            ast::Segments::new(),
+        );
+
+        let serialized = serde_json::to_value(&program).expect("Serialized program");
+
+        assert!(serialized.get("segments").is_none());
+        assert!(lower_program(program).is_ok());
+    }
+}
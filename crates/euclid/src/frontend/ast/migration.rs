@@ -0,0 +1,409 @@
+//! Migration of saved programs across schema versions
+//!
+//! Programs are persisted as serialized JSON which references DIR keys and values by name. Every
+//! time a key or a value is renamed, a migration is registered here so that programs saved with
+//! the older names can be upgraded before being deserialized into an [`ast::Program`].
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::frontend::ast;
+
+/// The schema version of programs created with the current set of DIR keys and values
+pub const CURRENT_PROGRAM_VERSION: u32 = 0;
+
+#[derive(Debug, Clone, serde::Serialize, thiserror::Error)]
+#[serde(tag = "type", content = "info", rename_all = "snake_case")]
+pub enum MigrationError {
+    #[error("Program version {found} is newer than the latest supported version {latest}")]
+    UnsupportedVersion { found: u32, latest: u32 },
+    #[error("Malformed program received for migration: {0}")]
+    MalformedProgram(String),
+}
+
+/// A single transformation applied to every comparison in a program
+#[derive(Debug, Clone, Copy)]
+pub enum Transformation {
+    /// Renames the key (the left hand side) of a comparison
+    RenameKey {
+        from: &'static str,
+        to: &'static str,
+    },
+    /// Renames an enum variant value of a comparison against the given key
+    RenameValue {
+        key: &'static str,
+        from: &'static str,
+        to: &'static str,
+    },
+}
+
+/// Upgrades a program from `from_version` to `from_version + 1`
+#[derive(Debug, Clone, Copy)]
+pub struct Migration {
+    pub from_version: u32,
+    pub transformations: &'static [Transformation],
+}
+
+/// Registry of all migrations, ordered by the version they upgrade from. No DIR key or value has
+/// been renamed since versioning was introduced, so there is nothing to migrate yet.
+pub const MIGRATIONS: &[Migration] = &[];
+
+/// Reads the schema version of a serialized program, treating programs saved before versioning
+/// was introduced as version 0
+pub fn get_program_version(program: &Value) -> u32 {
+    program
+        .get("version")
+        .and_then(Value::as_u64)
+        .and_then(|version| u32::try_from(version).ok())
+        .unwrap_or_default()
+}
+
+/// Applies all registered migrations from `from_version` up to [`CURRENT_PROGRAM_VERSION`] and
+/// deserializes the result into a program stamped with the current version.
+pub fn migrate_program<O: DeserializeOwned>(
+    program: Value,
+    from_version: u32,
+) -> Result<ast::Program<O>, MigrationError> {
+    migrate_program_with(program, from_version, MIGRATIONS, CURRENT_PROGRAM_VERSION)
+}
+
+fn migrate_program_with<O: DeserializeOwned>(
+    mut program: Value,
+    from_version: u32,
+    migrations: &[Migration],
+    current_version: u32,
+) -> Result<ast::Program<O>, MigrationError> {
+    if from_version > current_version {
+        return Err(MigrationError::UnsupportedVersion {
+            found: from_version,
+            latest: current_version,
+        });
+    }
+
+    for migration in migrations
+        .iter()
+        .filter(|migration| migration.from_version >= from_version)
+    {
+        let rules = program
+            .get_mut("rules")
+            .and_then(Value::as_array_mut)
+            .ok_or_else(|| MigrationError::MalformedProgram("missing rules".to_string()))?;
+
+        for rule in rules {
+            if let Some(statements) = rule.get_mut("statements").and_then(Value::as_array_mut) {
+                migrate_statements(statements, migration.transformations);
+            }
+        }
+
+        if let Some(segments) = program.get_mut("segments").and_then(Value::as_object_mut) {
+            for segment in segments.values_mut() {
+                migrate_segment(segment, migration.transformations);
+            }
+        }
+    }
+
+    let mut program: ast::Program<O> = serde_json::from_value(program)
+        .map_err(|err| MigrationError::MalformedProgram(err.to_string()))?;
+    program.version = current_version;
+
+    Ok(program)
+}
+
+fn migrate_statements(statements: &mut [Value], transformations: &[Transformation]) {
+    for statement in statements {
+        if let Some(condition) = statement.get_mut("condition").and_then(Value::as_array_mut) {
+            for comparison in condition {
+                migrate_comparison(comparison, transformations);
+            }
+        }
+
+        if let Some(nested) = statement.get_mut("nested").and_then(Value::as_array_mut) {
+            migrate_statements(nested, transformations);
+        }
+    }
+}
+
+/// Migrates the key of a segment and each of its values the same way as the key and the value of
+/// a comparison against the segment's key
+fn migrate_segment(segment: &mut Value, transformations: &[Transformation]) {
+    let Some(key) = segment.get_mut("key") else {
+        return;
+    };
+    let original_key = key.clone();
+
+    let mut key_comparison = serde_json::json!({ "lhs": original_key });
+    migrate_comparison(&mut key_comparison, transformations);
+    if let Some(migrated_key) = key_comparison.get_mut("lhs") {
+        *key = migrated_key.take();
+    }
+
+    let Some(values) = segment.get_mut("values").and_then(Value::as_array_mut) else {
+        return;
+    };
+
+    for value in values {
+        let mut comparison = serde_json::json!({ "lhs": original_key, "value": value.take() });
+        migrate_comparison(&mut comparison, transformations);
+        if let Some(migrated_value) = comparison.get_mut("value") {
+            *value = migrated_value.take();
+        }
+    }
+}
+
+fn migrate_comparison(comparison: &mut Value, transformations: &[Transformation]) {
+    for transformation in transformations {
+        match *transformation {
+            Transformation::RenameKey { from, to } => {
+                if let Some(lhs) = comparison.get_mut("lhs") {
+                    if lhs.as_str() == Some(from) {
+                        *lhs = Value::String(to.to_string());
+                    }
+                }
+            }
+            Transformation::RenameValue { key, from, to } => {
+                if comparison.get("lhs").and_then(Value::as_str) != Some(key) {
+                    continue;
+                }
+
+                let Some(value) = comparison
+                    .get_mut("value")
+                    .and_then(|value| value.get_mut("value"))
+                else {
+                    continue;
+                };
+
+                match value {
+                    Value::String(variant) if *variant == from => *variant = to.to_string(),
+                    Value::Array(variants) => variants
+                        .iter_mut()
+                        .filter(|variant| variant.as_str() == Some(from))
+                        .for_each(|variant| *variant = Value::String(to.to_string())),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::indexing_slicing)]
+
+    use serde_json::json;
+
+    use super::*;
+
+    /// Version reached by applying every migration of [`SAMPLE_MIGRATIONS`]
+    const SAMPLE_PROGRAM_VERSION: u32 = 2;
+
+    /// Sample renames exercising both kinds of transformations, as a real rename would register
+    /// them in [`MIGRATIONS`]
+    const SAMPLE_MIGRATIONS: &[Migration] = &[
+        Migration {
+            from_version: 0,
+            transformations: &[
+                Transformation::RenameKey {
+                    from: "country",
+                    to: "business_country",
+                },
+                Transformation::RenameKey {
+                    from: "device_platform",
+                    to: "customer_device_platform",
+                },
+                Transformation::RenameKey {
+                    from: "device_type",
+                    to: "customer_device_type",
+                },
+                Transformation::RenameKey {
+                    from: "device_display_size",
+                    to: "customer_device_display_size",
+                },
+            ],
+        },
+        Migration {
+            from_version: 1,
+            transformations: &[
+                Transformation::RenameValue {
+                    key: "customer_device_type",
+                    from: "console",
+                    to: "gaming_console",
+                },
+                Transformation::RenameValue {
+                    key: "customer_device_platform",
+                    from: "i_os",
+                    to: "ios",
+                },
+            ],
+        },
+    ];
+
+    fn migrate_sample_program(
+        program: Value,
+        from_version: u32,
+    ) -> Result<ast::Program<ast::ConnectorSelection>, MigrationError> {
+        migrate_program_with(
+            program,
+            from_version,
+            SAMPLE_MIGRATIONS,
+            SAMPLE_PROGRAM_VERSION,
+        )
+    }
+
+    fn v0_program() -> Value {
+        json!({
+            "defaultSelection": { "type": "priority", "data": [] },
+            "metadata": {},
+            "rules": [{
+                "name": "device_rule",
+                "connectorSelection": { "type": "priority", "data": [] },
+                "statements": [{
+                    "condition": [{
+                        "lhs": "country",
+                        "comparison": "equal",
+                        "value": { "type": "enum_variant", "value": "US" },
+                        "metadata": {}
+                    }],
+                    "nested": [{
+                        "condition": [
+                            {
+                                "lhs": "device_type",
+                                "comparison": "equal",
+                                "value": { "type": "enum_variant_array", "value": ["console", "mobile"] },
+                                "metadata": {}
+                            },
+                            {
+                                "lhs": "device_platform",
+                                "comparison": "equal",
+                                "value": { "type": "enum_variant", "value": "i_os" },
+                                "metadata": {}
+                            }
+                        ],
+                        "nested": null
+                    }]
+                }]
+            }]
+        })
+    }
+
+    fn conditions(
+        program: &ast::Program<ast::ConnectorSelection>,
+    ) -> Vec<(String, ast::ValueType)> {
+        let statement = &program.rules[0].statements[0];
+        let nested = statement.nested.as_ref().expect("nested statements");
+        statement
+            .condition
+            .iter()
+            .chain(nested[0].condition.iter())
+            .map(|comparison| (comparison.lhs.clone(), comparison.value.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_v0_program_migration() {
+        let program = migrate_sample_program(v0_program(), get_program_version(&v0_program()))
+            .expect("Migrated program");
+
+        assert_eq!(program.version, SAMPLE_PROGRAM_VERSION);
+        assert_eq!(
+            conditions(&program),
+            vec![
+                (
+                    "business_country".to_string(),
+                    ast::ValueType::EnumVariant("US".to_string())
+                ),
+                (
+                    "customer_device_type".to_string(),
+                    ast::ValueType::EnumVariantArray(vec![
+                        "gaming_console".to_string(),
+                        "mobile".to_string()
+                    ])
+                ),
+                (
+                    "customer_device_platform".to_string(),
+                    ast::ValueType::EnumVariant("ios".to_string())
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_remigration_is_idempotent() {
+        let migrated = migrate_sample_program(v0_program(), 0).expect("Migrated program");
+        let serialized = serde_json::to_value(&migrated).expect("Serialized program");
+
+        let from_current =
+            migrate_sample_program(serialized.clone(), get_program_version(&serialized))
+                .expect("Re-migrated program");
+        let from_zero = migrate_sample_program(serialized, 0).expect("Re-migrated program");
+
+        assert_eq!(conditions(&from_current), conditions(&migrated));
+        assert_eq!(conditions(&from_zero), conditions(&migrated));
+    }
+
+    #[test]
+    fn test_segment_migration() {
+        let mut program = v0_program();
+        program["segments"] = json!({
+            "consoles": {
+                "key": "device_type",
+                "values": [
+                    { "type": "enum_variant", "value": "console" },
+                    { "type": "enum_variant", "value": "mobile" }
+                ]
+            }
+        });
+
+        let program = migrate_sample_program(program, 0).expect("Migrated program");
+
+        assert_eq!(
+            program.segments.get("consoles"),
+            Some(&ast::Segment {
+                key: "customer_device_type".to_string(),
+                values: vec![
+                    ast::ValueType::EnumVariant("gaming_console".to_string()),
+                    ast::ValueType::EnumVariant("mobile".to_string()),
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn test_program_without_registered_migrations_is_unchanged() {
+        let program: ast::Program<ast::ConnectorSelection> =
+            migrate_program(v0_program(), get_program_version(&v0_program()))
+                .expect("Migrated program");
+
+        assert_eq!(program.version, CURRENT_PROGRAM_VERSION);
+        assert_eq!(
+            conditions(&program),
+            vec![
+                (
+                    "country".to_string(),
+                    ast::ValueType::EnumVariant("US".to_string())
+                ),
+                (
+                    "device_type".to_string(),
+                    ast::ValueType::EnumVariantArray(vec![
+                        "console".to_string(),
+                        "mobile".to_string()
+                    ])
+                ),
+                (
+                    "device_platform".to_string(),
+                    ast::ValueType::EnumVariant("i_os".to_string())
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unknown_future_version() {
+        let result =
+            migrate_program::<ast::ConnectorSelection>(v0_program(), CURRENT_PROGRAM_VERSION + 1);
+
+        assert!(matches!(
+            result,
+            Err(MigrationError::UnsupportedVersion { found, .. }) if found == CURRENT_PROGRAM_VERSION + 1
+        ));
+    }
+}
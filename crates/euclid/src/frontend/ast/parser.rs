@@ -197,26 +197,10 @@ pub fn enum_variant_array_value(input: &str) -> ParseResult<&str, ast::ValueType
 }
 
 pub fn number_comparison(input: &str) -> ParseResult<&str, ast::NumberComparison> {
-    let operator = combinator::map_res(
-        branch::alt((
-            complete::tag(">="),
-            complete::tag("<="),
-            complete::tag(">"),
-            complete::tag("<"),
-        )),
-        |s: &str| match s {
-            ">=" => Ok(ast::ComparisonType::GreaterThanEqual),
-            "<=" => Ok(ast::ComparisonType::LessThanEqual),
-            ">" => Ok(ast::ComparisonType::GreaterThan),
-            "<" => Ok(ast::ComparisonType::LessThan),
-            _ => Err(EuclidError::InvalidOperator(s.to_string())),
-        },
-    );
-
     error::context(
         "number_comparison",
         combinator::map(
-            sequence::pair(operator, num_i64),
+            sequence::pair(comparison_type, skip_ws(num_i64)),
             |tup: (ast::ComparisonType, i64)| ast::NumberComparison {
                 comparison_type: tup.0,
                 number: MinorUnit::new(tup.1),
@@ -250,6 +234,16 @@ pub fn number_comparison_array_value(input: &str) -> ParseResult<&str, ast::Valu
     )(input)
 }
 
+pub fn segment_reference_value(input: &str) -> ParseResult<&str, ast::ValueType> {
+    error::context(
+        "segment_reference_value",
+        combinator::map(
+            sequence::preceded(complete::tag("@"), identifier),
+            ast::ValueType::SegmentReference,
+        ),
+    )(input)
+}
+
 pub fn value_type(input: &str) -> ParseResult<&str, ast::ValueType> {
     error::context(
         "value_type",
@@ -260,6 +254,7 @@ pub fn value_type(input: &str) -> ParseResult<&str, ast::ValueType> {
             number_array_value,
             number_comparison_array_value,
             str_value,
+            segment_reference_value,
         )),
     )(input)
 }
@@ -335,7 +330,10 @@ pub fn arbitrary_comparison(input: &str) -> ParseResult<&str, ast::Comparison> {
 pub fn comparison_array(input: &str) -> ParseResult<&str, Vec<ast::Comparison>> {
     let many_with_ampersand = error::context(
         "many_with_amp",
-        multi::many0(sequence::preceded(skip_ws(complete::tag("&")), comparison)),
+        multi::many0(sequence::preceded(
+            skip_ws(complete::tag("&")),
+            branch::alt((comparison, arbitrary_comparison)),
+        )),
     );
 
     let full_sequence = sequence::pair(
@@ -439,6 +437,8 @@ pub fn program<O: EuclidParsable + 'static>(input: &str) -> ParseResult<&str, as
                 default_selection: tup.0,
                 rules: tup.1,
                 metadata: std::collections::HashMap::new(),
+                version: ast::migration::CURRENT_PROGRAM_VERSION,
+                segments: ast::Segments::new(),
             },
         ),
     )(input)
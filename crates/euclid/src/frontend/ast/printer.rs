@@ -0,0 +1,173 @@
+//! Prints the statements of a rule in the syntax accepted by [`parser`](super::parser), such
+//! that parsing the printed text yields the same statements. The metadata of the comparisons is
+//! not part of the syntax and is left out.
+//!
+//! ```text
+//! {
+//!     payment_method = card & amount = (>= 1000, <= 5000)
+//!     {
+//!         currency = (USD, EUR) & "tier" = "gold"
+//!     }
+//! }
+//! ```
+
+use crate::frontend::ast;
+
+const INDENT: &str = "    ";
+
+/// Prints the statements of a rule as parsed by
+/// [`rule_conditions_array`](super::parser::rule_conditions_array)
+pub fn print_rule_conditions(statements: &[ast::IfStatement]) -> String {
+    let mut printed = String::new();
+    print_statements(statements, 0, &mut printed);
+
+    printed
+}
+
+fn print_statements(statements: &[ast::IfStatement], depth: usize, printed: &mut String) {
+    let indent = INDENT.repeat(depth);
+
+    printed.push_str(&format!("{indent}{{\n"));
+    for statement in statements {
+        printed.push_str(&format!(
+            "{indent}{INDENT}{}\n",
+            print_condition(&statement.condition)
+        ));
+        if let Some(nested) = &statement.nested {
+            print_statements(nested, depth + 1, printed);
+        }
+    }
+    printed.push_str(&format!("{indent}}}\n"));
+}
+
+/// Prints the comparisons of a condition as parsed by
+/// [`comparison_array`](super::parser::comparison_array)
+pub fn print_condition(condition: &[ast::Comparison]) -> String {
+    condition
+        .iter()
+        .map(print_comparison)
+        .collect::<Vec<_>>()
+        .join(" & ")
+}
+
+pub fn print_comparison(comparison: &ast::Comparison) -> String {
+    let operator = print_comparison_type(&comparison.comparison);
+
+    match &comparison.value {
+        // Metadata comparisons are written with the metadata key in place of the key
+        ast::ValueType::MetadataVariant(metadata) => {
+            format!("\"{}\" {operator} \"{}\"", metadata.key, metadata.value)
+        }
+        value => format!("{} {operator} {}", comparison.lhs, print_value(value)),
+    }
+}
+
+pub fn print_value(value: &ast::ValueType) -> String {
+    match value {
+        ast::ValueType::Number(number) => number.get_amount_as_i64().to_string(),
+        ast::ValueType::EnumVariant(variant) => variant.clone(),
+        ast::ValueType::MetadataVariant(metadata) => format!("\"{}\"", metadata.value),
+        ast::ValueType::StrValue(string) => format!("\"{string}\""),
+        ast::ValueType::NumberArray(numbers) => format!(
+            "({})",
+            numbers
+                .iter()
+                .map(|number| number.get_amount_as_i64().to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        ast::ValueType::EnumVariantArray(variants) => format!("({})", variants.join(", ")),
+        ast::ValueType::NumberComparisonArray(comparisons) => format!(
+            "({})",
+            comparisons
+                .iter()
+                .map(|comparison| format!(
+                    "{} {}",
+                    print_comparison_type(&comparison.comparison_type),
+                    comparison.number.get_amount_as_i64()
+                ))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        ast::ValueType::SegmentReference(segment) => format!("@{segment}"),
+    }
+}
+
+pub fn print_comparison_type(comparison_type: &ast::ComparisonType) -> &'static str {
+    match comparison_type {
+        ast::ComparisonType::Equal => "=",
+        ast::ComparisonType::NotEqual => "/=",
+        ast::ComparisonType::LessThan => "<",
+        ast::ComparisonType::LessThanEqual => "<=",
+        ast::ComparisonType::GreaterThan => ">",
+        ast::ComparisonType::GreaterThanEqual => ">=",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use super::*;
+    use crate::frontend::ast::parser;
+
+    fn parse_rule_conditions(text: &str) -> Vec<ast::IfStatement> {
+        let (rest, statements) =
+            parser::rule_conditions_array(text).expect("valid rule conditions");
+        assert!(rest.trim().is_empty(), "Unparsed input: {rest}");
+
+        statements
+    }
+
+    fn assert_round_trip(text: &str) -> String {
+        let statements = parse_rule_conditions(text);
+        let printed = print_rule_conditions(&statements);
+
+        assert_eq!(
+            serde_json::to_value(parse_rule_conditions(&printed)).expect("serialized"),
+            serde_json::to_value(&statements).expect("serialized"),
+            "{printed}"
+        );
+
+        printed
+    }
+
+    #[test]
+    fn test_amount_range_and_enum_set_round_trip() {
+        let printed =
+            assert_round_trip("{ amount >= 1000 & amount <= 5000 & currency = (USD, EUR) }");
+
+        assert_eq!(
+            printed,
+            "{\n    amount >= 1000 & amount <= 5000 & currency = (USD, EUR)\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_number_comparison_array_round_trip() {
+        let statements = parse_rule_conditions("{ amount = (>= 1000, < 5000, /= 2500) }");
+
+        assert!(matches!(
+            statements.as_slice(),
+            [ast::IfStatement { condition, .. }] if matches!(
+                condition.as_slice(),
+                [ast::Comparison { value: ast::ValueType::NumberComparisonArray(comparisons), .. }]
+                    if comparisons.len() == 3
+            )
+        ));
+        assert_round_trip("{ amount = (>= 1000, < 5000, /= 2500) }");
+    }
+
+    #[test]
+    fn test_nested_statements_round_trip() {
+        assert_round_trip(
+            r#"{
+                payment_method = card & card_bin = "424242" {
+                    currency /= (USD, EUR) & "tier" = "gold"
+                    amount > 100 & billing_country = @eu_countries
+                }
+                payment_method = wallet
+            }"#,
+        );
+    }
+}
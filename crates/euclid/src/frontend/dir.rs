@@ -341,6 +341,10 @@ where
     Self: 'static,
 {
     const ALLOWED: &'static [DirKeyKind];
+    /// Pairs of `(dependent, prerequisite)` keys, where a rule conditioning on the dependent key is
+    /// expected to also condition on the prerequisite key
+    const KEY_DEPENDENCIES: &'static [(DirKeyKind, DirKeyKind)] = &[];
+
     fn get_allowed_keys() -> &'static [DirKeyKind] {
         Self::ALLOWED
     }
@@ -348,6 +352,10 @@ where
     fn is_key_allowed(key: &DirKeyKind) -> bool {
         Self::ALLOWED.contains(key)
     }
+
+    fn get_key_dependencies() -> &'static [(DirKeyKind, DirKeyKind)] {
+        Self::KEY_DEPENDENCIES
+    }
 }
 
 impl DirKeyKind {
@@ -882,13 +890,13 @@ pub enum PayoutDirValue {
     BankTransferType(enums::PayoutBankTransferType),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub enum DirComparisonLogic {
     NegativeConjunction,
     PositiveDisjunction,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct DirComparison {
     pub values: Vec<DirValue>,
     pub logic: DirComparisonLogic,
@@ -897,20 +905,20 @@ pub struct DirComparison {
 
 pub type DirIfCondition = Vec<DirComparison>;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct DirIfStatement {
     pub condition: DirIfCondition,
     pub nested: Option<Vec<DirIfStatement>>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct DirRule<O> {
     pub name: String,
     pub connector_selection: O,
     pub statements: Vec<DirIfStatement>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct DirProgram<O> {
     pub default_selection: O,
     pub rules: Vec<DirRule<O>>,
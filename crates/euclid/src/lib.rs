@@ -3,4 +3,6 @@ pub mod backend;
 pub mod dssa;
 pub mod enums;
 pub mod frontend;
+#[cfg(any(test, feature = "test_vectors"))]
+pub mod test_vectors;
 pub mod types;
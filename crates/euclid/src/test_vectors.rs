@@ -0,0 +1,236 @@
+//! Canonical program test vectors shared by the backend suites of this crate and the preview
+//! suite of `euclid_wasm`, so that a program accepted by one is exercised against the other.
+//!
+//! Each vector holds a program, an input and the output the program is expected to resolve to.
+//! The vectors live in the `test_vectors` directory of the crate, one file per kind of program.
+//! A suite consumes them through [`test_vector_suite`](crate::test_vector_suite), which also
+//! checks that the suite references every vector of the fixtures.
+//!
+//! Vectors only condition on the keys that both the AST interpreter and the valued interpreter
+//! evaluate: `payment_method`, `authentication_type`, `capture_method`, `amount`, `currency`
+//! and `metadata`. Tiered surcharges are not supported by the surcharge output yet, and have no
+//! vectors.
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{
+    backend::inputs,
+    frontend::{
+        ast,
+        dir::{DirKeyKind, EuclidDirFilter},
+    },
+};
+
+const FIXTURES: [&str; 3] = [
+    include_str!("../test_vectors/routing.json"),
+    include_str!("../test_vectors/surcharge.json"),
+    include_str!("../test_vectors/three_ds_decision.json"),
+];
+
+/// The kind of program a test vector holds, determining the type of its output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProgramKind {
+    Routing,
+    Surcharge,
+    ThreeDsDecision,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExpectedOutput {
+    /// The name of the rule expected to match, `None` when the default selection is expected
+    pub rule_name: Option<String>,
+    pub output: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestVector {
+    pub name: String,
+    pub description: String,
+    /// The program in its serialized form, as saved through the API and sent by the dashboard
+    pub program: serde_json::Value,
+    pub input: inputs::BackendInput,
+    pub expected: ExpectedOutput,
+}
+
+impl TestVector {
+    pub fn get_program<O: DeserializeOwned>(&self) -> Result<ast::Program<O>, serde_json::Error> {
+        serde_json::from_value(self.program.clone())
+    }
+}
+
+/// The test vectors of a fixture file, all holding programs of the same kind
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestVectorSet {
+    pub kind: ProgramKind,
+    pub vectors: Vec<TestVector>,
+}
+
+/// An output accepting any value and allowing conditions on any key, used to execute the
+/// vectors without depending on the output types of the API
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct TestVectorOutput(pub serde_json::Value);
+
+impl EuclidDirFilter for TestVectorOutput {
+    const ALLOWED: &'static [DirKeyKind] = &[];
+
+    fn is_key_allowed(_key: &DirKeyKind) -> bool {
+        true
+    }
+}
+
+pub fn load_test_vector_sets() -> Result<Vec<TestVectorSet>, serde_json::Error> {
+    FIXTURES
+        .iter()
+        .map(|fixture| serde_json::from_str(fixture))
+        .collect()
+}
+
+/// Returns the vector with the given name along with the kind of its program
+pub fn get_test_vector(name: &str) -> Result<Option<(ProgramKind, TestVector)>, serde_json::Error> {
+    Ok(load_test_vector_sets()?.into_iter().find_map(|set| {
+        let kind = set.kind;
+        set.vectors
+            .into_iter()
+            .find(|vector| vector.name == name)
+            .map(|vector| (kind, vector))
+    }))
+}
+
+/// Returns the names of the vectors of the fixtures which are not in `referenced`
+pub fn get_unreferenced_test_vectors(
+    referenced: &[&str],
+) -> Result<Vec<String>, serde_json::Error> {
+    Ok(load_test_vector_sets()?
+        .into_iter()
+        .flat_map(|set| set.vectors)
+        .map(|vector| vector.name)
+        .filter(|name| !referenced.contains(&name.as_str()))
+        .collect())
+}
+
+/// Generates a test per listed vector, executing it through `$runner`, which receives the kind
+/// of the program and the vector. Also generates a test failing when the fixtures hold a vector
+/// which is not listed, so that every suite keeps exercising every vector.
+///
+/// ```ignore
+/// euclid::test_vector_suite! {
+///     run_test_vector;
+///     routing_empty_program,
+///     routing_fallback_only,
+/// }
+/// ```
+#[macro_export]
+macro_rules! test_vector_suite {
+    ($runner:path; $($name:ident),+ $(,)?) => {
+        const REFERENCED_TEST_VECTORS: &[&str] = &[$(stringify!($name)),+];
+
+        $(
+            #[test]
+            fn $name() {
+                let (kind, vector) = $crate::test_vectors::get_test_vector(stringify!($name))
+                    .expect("Failed to load the test vectors")
+                    .expect("Test vector is not defined in the fixtures");
+                $runner(kind, &vector);
+            }
+        )+
+
+        #[test]
+        fn test_vectors_are_all_referenced() {
+            let unreferenced =
+                $crate::test_vectors::get_unreferenced_test_vectors(REFERENCED_TEST_VECTORS)
+                    .expect("Failed to load the test vectors");
+            assert!(
+                unreferenced.is_empty(),
+                "Test vectors not referenced by the suite: {unreferenced:?}"
+            );
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use std::collections::HashSet;
+
+    use super::*;
+    #[cfg(feature = "valued_jit")]
+    use crate::backend::VirInterpreterBackend;
+    use crate::backend::{BackendOutput, EuclidBackend, InterpreterBackend};
+
+    fn assert_output(vector: &TestVector, output: &BackendOutput<TestVectorOutput>) {
+        assert_eq!(
+            output.rule_name, vector.expected.rule_name,
+            "{}",
+            vector.name
+        );
+        assert_eq!(
+            output.get_output().0,
+            vector.expected.output,
+            "{}",
+            vector.name
+        );
+    }
+
+    fn run_test_vector(_kind: ProgramKind, vector: &TestVector) {
+        let program: ast::Program<TestVectorOutput> =
+            vector.get_program().expect("Failed to parse the program");
+
+        let backend =
+            InterpreterBackend::with_program(program.clone()).expect("Failed to load the program");
+        let output = backend
+            .execute(vector.input.clone())
+            .expect("Failed to execute the program");
+        assert_output(vector, &output);
+
+        #[cfg(feature = "valued_jit")]
+        {
+            let backend =
+                VirInterpreterBackend::with_program(program).expect("Failed to lower the program");
+            let output = backend
+                .execute(vector.input.clone())
+                .expect("Failed to execute the program");
+            assert_output(vector, &output);
+        }
+    }
+
+    #[test]
+    fn test_vector_names_are_unique() {
+        let sets = load_test_vector_sets().expect("Failed to load the test vectors");
+        let mut names = HashSet::new();
+
+        for vector in sets.iter().flat_map(|set| set.vectors.iter()) {
+            assert!(
+                names.insert(&vector.name),
+                "Duplicate vector {}",
+                vector.name
+            );
+        }
+    }
+
+    crate::test_vector_suite! {
+        run_test_vector;
+        routing_empty_program,
+        routing_fallback_only,
+        routing_first_matching_rule_wins,
+        routing_nested_statements,
+        routing_nested_statements_unmatched,
+        routing_metadata_condition,
+        routing_metadata_missing_key,
+        routing_volume_split,
+        routing_enum_variant_array,
+        routing_amount_upper_bound_exclusive,
+        routing_not_equal,
+        surcharge_empty_program,
+        surcharge_fallback_only,
+        surcharge_fixed_amount,
+        surcharge_rate_with_tax,
+        surcharge_metadata_not_equal,
+        three_ds_decision_empty_program,
+        three_ds_decision_fallback_only,
+        three_ds_decision_high_amount_challenge,
+        three_ds_decision_low_value_exemption,
+    }
+}
@@ -135,6 +135,7 @@ pub enum DataType {
     EnumVariant,
     MetadataValue,
     StrValue,
+    Segment,
 }
 
 impl EuclidKey {
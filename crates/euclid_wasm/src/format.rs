@@ -0,0 +1,273 @@
+use api_models::routing::ConnectorSelection;
+use euclid::{frontend::dir, types::NumValueRefinement};
+
+const INDENT: &str = "    ";
+
+/// Renders the lowered program as text in the syntax of the routing DSL. The rules keep their
+/// declaration order, while the comparisons of a condition and the values of a comparison are
+/// sorted, so that equivalent programs render identically. The metadata of the program and its
+/// comparisons is left out, as it does not affect routing.
+///
+/// ```text
+/// default: [stripe, adyen]
+///
+/// card_rule: [checkout]
+/// {
+///     card_type = (credit, debit) & payment_method = card
+///     {
+///         amount > 1000
+///     }
+/// }
+/// ```
+pub fn format_program(program: &dir::DirProgram<ConnectorSelection>) -> String {
+    let mut rendered = format!(
+        "default: {}\n",
+        format_connector_selection(&program.default_selection)
+    );
+
+    for rule in &program.rules {
+        rendered.push('\n');
+        rendered.push_str(&format!(
+            "{}: {}\n",
+            rule.name,
+            format_connector_selection(&rule.connector_selection)
+        ));
+        format_statements(&rule.statements, 0, &mut rendered);
+    }
+
+    rendered
+}
+
+fn format_connector_selection(selection: &ConnectorSelection) -> String {
+    let connectors = match selection {
+        ConnectorSelection::Priority(connectors) => connectors
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+        ConnectorSelection::VolumeSplit(splits) => splits
+            .iter()
+            .map(|split| format!("{}: {}%", split.connector, split.split))
+            .collect(),
+    };
+
+    format!("[{}]", connectors.join(", "))
+}
+
+fn format_statements(statements: &[dir::DirIfStatement], depth: usize, rendered: &mut String) {
+    let indent = INDENT.repeat(depth);
+
+    rendered.push_str(&format!("{indent}{{\n"));
+    for statement in statements {
+        rendered.push_str(&format!(
+            "{indent}{INDENT}{}\n",
+            format_condition(&statement.condition)
+        ));
+        if let Some(nested) = &statement.nested {
+            format_statements(nested, depth + 1, rendered);
+        }
+    }
+    rendered.push_str(&format!("{indent}}}\n"));
+}
+
+fn format_condition(condition: &dir::DirIfCondition) -> String {
+    let mut comparisons = condition.iter().map(format_comparison).collect::<Vec<_>>();
+    comparisons.sort();
+
+    comparisons.join(" & ")
+}
+
+fn format_comparison(comparison: &dir::DirComparison) -> String {
+    let key = comparison
+        .values
+        .first()
+        .map(|value| value.get_key().kind.to_string())
+        .unwrap_or_default();
+
+    let operator = match comparison.logic {
+        dir::DirComparisonLogic::PositiveDisjunction => "=",
+        dir::DirComparisonLogic::NegativeConjunction => "/=",
+    };
+
+    // A single refined number carries its own operator, like `amount > 1000`
+    if let (dir::DirComparisonLogic::PositiveDisjunction, [value]) =
+        (&comparison.logic, comparison.values.as_slice())
+    {
+        if let Some(num_value) = value
+            .get_num_value()
+            .filter(|num_value| num_value.refinement.is_some())
+        {
+            return format!("{key} {}", format_num_value(&num_value));
+        }
+    }
+
+    let mut values = comparison
+        .values
+        .iter()
+        .map(format_value)
+        .collect::<Vec<_>>();
+    values.sort();
+    values.dedup();
+
+    match values.as_slice() {
+        [value] => format!("{key} {operator} {value}"),
+        values => format!("{key} {operator} ({})", values.join(", ")),
+    }
+}
+
+fn format_value(value: &dir::DirValue) -> String {
+    if let Some(num_value) = value.get_num_value() {
+        return format_num_value(&num_value);
+    }
+
+    if let dir::DirValue::MetaData(metadata) = value {
+        return format!("{{{:?}: {:?}}}", metadata.key, metadata.value);
+    }
+
+    // Enum values are serialized as their variant name, string values as an object holding the
+    // string
+    match serde_json::to_value(value)
+        .ok()
+        .and_then(|serialized| serialized.get("value").cloned())
+    {
+        Some(serde_json::Value::String(variant)) => variant,
+        Some(serde_json::Value::Object(object)) => match object.get("value") {
+            Some(serde_json::Value::String(string)) => format!("{string:?}"),
+            _ => serde_json::Value::Object(object).to_string(),
+        },
+        Some(other) => other.to_string(),
+        None => String::new(),
+    }
+}
+
+fn format_num_value(num_value: &euclid::types::NumValue) -> String {
+    let number = num_value.number.get_amount_as_i64();
+
+    match &num_value.refinement {
+        None => number.to_string(),
+        Some(refinement) => {
+            let operator = match refinement {
+                NumValueRefinement::NotEqual => "/=",
+                NumValueRefinement::GreaterThan => ">",
+                NumValueRefinement::LessThan => "<",
+                NumValueRefinement::GreaterThanEqual => ">=",
+                NumValueRefinement::LessThanEqual => "<=",
+            };
+            format!("{operator} {number}")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use euclid::frontend::ast;
+
+    use super::*;
+
+    fn lowered_program(conditions: serde_json::Value) -> dir::DirProgram<ConnectorSelection> {
+        let program: ast::Program<ConnectorSelection> = serde_json::from_value(serde_json::json!({
+            "defaultSelection": { "type": "priority", "data": ["stripe", "adyen"] },
+            "metadata": { "created_by": "dashboard" },
+            "rules": [
+                {
+                    "name": "card_rule",
+                    "connectorSelection": { "type": "priority", "data": ["checkout"] },
+                    "statements": [{
+                        "condition": conditions,
+                        "nested": [{
+                            "condition": [{
+                                "lhs": "amount",
+                                "comparison": "greater_than",
+                                "value": { "type": "number", "value": 1000 },
+                                "metadata": {}
+                            }],
+                            "nested": null
+                        }]
+                    }]
+                },
+                {
+                    "name": "eur_rule",
+                    "connectorSelection": {
+                        "type": "volume_split",
+                        "data": [
+                            { "connector": "stripe", "split": 70 },
+                            { "connector": "adyen", "split": 30 }
+                        ]
+                    },
+                    "statements": [{
+                        "condition": [{
+                            "lhs": "currency",
+                            "comparison": "not_equal",
+                            "value": { "type": "enum_variant_array", "value": ["USD", "EUR"] },
+                            "metadata": {}
+                        }],
+                        "nested": null
+                    }]
+                }
+            ]
+        }))
+        .expect("valid program");
+
+        ast::lowering::lower_program(program).expect("lowered program")
+    }
+
+    fn payment_method_condition() -> serde_json::Value {
+        serde_json::json!({
+            "lhs": "payment_method",
+            "comparison": "equal",
+            "value": { "type": "enum_variant", "value": "card" },
+            "metadata": { "ui_position": 1 }
+        })
+    }
+
+    fn card_type_condition() -> serde_json::Value {
+        serde_json::json!({
+            "lhs": "card_type",
+            "comparison": "equal",
+            "value": { "type": "enum_variant_array", "value": ["debit", "credit"] },
+            "metadata": {}
+        })
+    }
+
+    #[test]
+    fn test_program_rendering() {
+        let program = lowered_program(serde_json::json!([
+            payment_method_condition(),
+            card_type_condition()
+        ]));
+
+        assert_eq!(
+            format_program(&program),
+            "default: [stripe, adyen]\n\
+             \n\
+             card_rule: [checkout]\n\
+             {\n    \
+                 card_type = (credit, debit) & payment_method = card\n    \
+                 {\n        \
+                     amount > 1000\n    \
+                 }\n\
+             }\n\
+             \n\
+             eur_rule: [stripe: 70%, adyen: 30%]\n\
+             {\n    \
+                 currency /= (EUR, USD)\n\
+             }\n"
+        );
+    }
+
+    #[test]
+    fn test_equivalent_programs_render_identically() {
+        let program = lowered_program(serde_json::json!([
+            payment_method_condition(),
+            card_type_condition()
+        ]));
+        let reordered_program = lowered_program(serde_json::json!([
+            card_type_condition(),
+            payment_method_condition()
+        ]));
+
+        assert_eq!(format_program(&program), format_program(&program));
+        assert_eq!(format_program(&program), format_program(&reordered_program));
+    }
+}
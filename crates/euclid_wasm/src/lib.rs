@@ -1,27 +1,39 @@
 #![allow(non_upper_case_globals)]
+mod format;
 mod types;
 mod utils;
 use std::{
     collections::{HashMap, HashSet},
     str::FromStr,
-    sync::OnceLock,
+    sync::{Arc, PoisonError, RwLock},
 };
 
 use api_models::{
-    enums as api_model_enums, routing::ConnectorSelection,
-    surcharge_decision_configs::SurchargeDecisionConfigs,
+    enums as api_model_enums,
+    routing::ConnectorSelection,
+    surcharge_decision_configs::{
+        SurchargeDecisionConfigs, SurchargeDetailsOutput, SurchargeOutput,
+    },
 };
 use common_enums::RoutableConnectors;
 use common_types::three_ds_decision_rule_engine::ThreeDSDecisionRule;
+use common_utils::types::MinorUnit;
 use connector_configs::{
     common_config::{ConnectorApiIntegrationPayload, DashboardRequestPayload},
     connector,
 };
 use currency_conversion::{
-    conversion::convert as convert_currency, types as currency_conversion_types,
+    conversion::{convert as convert_currency, convert_minor_amount},
+    error::CurrencyConversionError,
+    types as currency_conversion_types,
 };
 use euclid::{
-    backend::{inputs, interpreter::InterpreterBackend, EuclidBackend},
+    backend::{
+        inputs,
+        interpreter::{types::InterpreterError, InterpreterBackend},
+        vir_interpreter::types::VirInterpreterError,
+        EuclidBackend, VirInterpreterBackend,
+    },
     dssa::{self, analyzer, graph::CgraphExt, state_machine},
     frontend::{
         ast,
@@ -45,43 +57,230 @@ use strum::IntoEnumIterator;
 struct SeedData {
     cgraph: hyperswitch_constraint_graph::ConstraintGraph<dir::DirValue>,
     connectors: Vec<ast::ConnectorChoice>,
+    /// The connector accounts and filters the graph was built from, kept so that the graph can be
+    /// rebuilt when a single connector account changes
+    #[cfg(feature = "v1")]
+    mcas: Vec<api_models::admin::MerchantConnectorResponse>,
+    #[cfg(feature = "v1")]
+    filters: kgraph_utils::types::CountryCurrencyFilter,
+}
+
+/// The latest seeded knowledge graph, replaced whenever the frontend re-seeds it after the
+/// merchant's connector accounts change.
+static SEED_DATA: RwLock<Option<Arc<SeedData>>> = RwLock::new(None);
+
+struct ForexData {
+    exchange_rates: currency_conversion_types::ExchangeRates,
+    /// The timestamp supplied by the frontend when seeding the rates
+    seeded_at: Option<f64>,
+}
+
+/// The latest seeded forex rates, replaced whenever the frontend re-seeds them as the rates are
+/// refreshed.
+static SEED_FOREX: RwLock<Option<Arc<ForexData>>> = RwLock::new(None);
+
+/// Returns the latest seeded knowledge graph. The lock is only held while cloning the handle, so
+/// a re-seed does not wait for analyses running against the previous graph.
+fn get_seed_data() -> Option<Arc<SeedData>> {
+    SEED_DATA
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+}
+
+fn set_seed_data(seed_data: Option<SeedData>) {
+    *SEED_DATA.write().unwrap_or_else(PoisonError::into_inner) = seed_data.map(Arc::new);
+}
+
+/// Replaces the seeded knowledge graph with the one derived from it, holding the lock throughout
+/// so that concurrent updates are not lost.
+#[cfg(feature = "v1")]
+fn update_seed_data(
+    update: impl FnOnce(&SeedData) -> Result<SeedData, JsValue>,
+) -> Result<(), JsValue> {
+    let mut seed_data = SEED_DATA.write().unwrap_or_else(PoisonError::into_inner);
+    let updated_seed_data = update(seed_data.as_deref().ok_or("Data not seeded").err_to_js()?)?;
+    *seed_data = Some(Arc::new(updated_seed_data));
+
+    Ok(())
+}
+
+/// The latest seeded payout knowledge graph, holding the merchant's payout connectors.
+#[cfg(feature = "payouts")]
+static PAYOUT_SEED_DATA: RwLock<Option<Arc<Vec<api_model_enums::PayoutConnectors>>>> =
+    RwLock::new(None);
+
+#[cfg(feature = "payouts")]
+fn get_payout_seed_data() -> Option<Arc<Vec<api_model_enums::PayoutConnectors>>> {
+    PAYOUT_SEED_DATA
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+}
+
+#[cfg(feature = "payouts")]
+fn set_payout_seed_data(payout_connectors: Option<Vec<api_model_enums::PayoutConnectors>>) {
+    *PAYOUT_SEED_DATA
+        .write()
+        .unwrap_or_else(PoisonError::into_inner) = payout_connectors.map(Arc::new);
+}
+
+fn get_forex_data() -> Option<Arc<ForexData>> {
+    SEED_FOREX
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
 }
 
-static SEED_DATA: OnceLock<SeedData> = OnceLock::new();
-static SEED_FOREX: OnceLock<currency_conversion_types::ExchangeRates> = OnceLock::new();
+fn set_forex_data(forex_data: ForexData) {
+    *SEED_FOREX.write().unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(forex_data));
+}
 
 /// This function can be used by the frontend to educate wasm about the forex rates data.
 /// The input argument is a struct fields base_currency and conversion where later is all the conversions associated with the base_currency
-/// to all different currencies present.
+/// to all different currencies present. Seeding again replaces the previously seeded rates, the
+/// optional timestamp is returned as is by `getForexMetadata`.
 #[wasm_bindgen(js_name = setForexData)]
-pub fn seed_forex(forex: JsValue) -> JsResult {
-    let forex: currency_conversion_types::ExchangeRates = serde_wasm_bindgen::from_value(forex)?;
-    SEED_FOREX
-        .set(forex)
-        .map_err(|_| "Forex has already been seeded".to_string())
-        .err_to_js()?;
+pub fn seed_forex(forex: JsValue, seeded_at: Option<f64>) -> JsResult {
+    let exchange_rates: currency_conversion_types::ExchangeRates =
+        serde_wasm_bindgen::from_value(forex)?;
+    set_forex_data(ForexData {
+        exchange_rates,
+        seeded_at,
+    });
 
     Ok(JsValue::NULL)
 }
 
+/// This function can be used by the frontend to know which forex rates are seeded and when they
+/// were seeded, to decide whether they are stale.
+#[wasm_bindgen(js_name = getForexMetadata)]
+pub fn get_forex_metadata() -> JsResult {
+    let forex_data = get_forex_data()
+        .ok_or("Forex Data not seeded")
+        .err_to_js()?;
+
+    let forex_metadata = build_forex_metadata(&forex_data);
+
+    Ok(serde_wasm_bindgen::to_value(&forex_metadata)?)
+}
+
+fn build_forex_metadata(forex_data: &ForexData) -> types::ForexMetadata {
+    let mut target_currencies = forex_data
+        .exchange_rates
+        .conversion
+        .keys()
+        .copied()
+        .collect::<Vec<_>>();
+    target_currencies.sort_by_key(|currency| currency.to_string());
+
+    types::ForexMetadata {
+        base_currency: forex_data.exchange_rates.base_currency,
+        target_currencies,
+        seeded_at: forex_data.seeded_at,
+    }
+}
+
 /// This function can be used to perform currency_conversion on the input amount, from_currency,
 /// to_currency which are all expected to be one of currencies we already have in our Currency
 /// enum.
 #[wasm_bindgen(js_name = convertCurrency)]
 pub fn convert_forex_value(amount: i64, from_currency: JsValue, to_currency: JsValue) -> JsResult {
-    let forex_data = SEED_FOREX
-        .get()
-        .ok_or("Forex Data not seeded")
-        .err_to_js()?;
     let from_currency: common_enums::Currency = serde_wasm_bindgen::from_value(from_currency)?;
     let to_currency: common_enums::Currency = serde_wasm_bindgen::from_value(to_currency)?;
-    let converted_amount = convert_currency(forex_data, from_currency, to_currency, amount)
-        .map_err(|_| "conversion not possible for provided values")
-        .err_to_js()?;
+    let converted_amount = convert_forex_amount(
+        get_forex_data().as_deref(),
+        amount,
+        from_currency,
+        to_currency,
+    )
+    .err_to_js()?;
+
+    Ok(serde_wasm_bindgen::to_value(&converted_amount)?)
+}
+
+fn convert_forex_amount(
+    forex_data: Option<&ForexData>,
+    amount: i64,
+    from_currency: common_enums::Currency,
+    to_currency: common_enums::Currency,
+) -> Result<impl serde::Serialize, String> {
+    let forex_data = forex_data.ok_or("Forex Data not seeded")?;
+
+    convert_currency(
+        &forex_data.exchange_rates,
+        from_currency,
+        to_currency,
+        amount,
+    )
+    .map_err(|error| forex_conversion_error_message(error, from_currency, to_currency))
+}
+
+/// This function can be used to convert an amount in the minor unit of from_currency to the minor
+/// unit of to_currency, like converting cents to fils. The number of decimal places of each
+/// currency is taken into account, and the converted amount is rounded to a whole minor unit using
+/// the rounding mode, which is one of `HalfUp`, `Floor` or `Ceil`.
+#[wasm_bindgen(js_name = convertCurrencyMinorUnits)]
+pub fn convert_forex_minor_units(
+    amount: i64,
+    from_currency: JsValue,
+    to_currency: JsValue,
+    rounding: JsValue,
+) -> JsResult {
+    let from_currency: common_enums::Currency = serde_wasm_bindgen::from_value(from_currency)?;
+    let to_currency: common_enums::Currency = serde_wasm_bindgen::from_value(to_currency)?;
+    let rounding_mode: currency_conversion_types::RoundingMode =
+        serde_wasm_bindgen::from_value(rounding)?;
+    let converted_amount = convert_forex_minor_amount(
+        get_forex_data().as_deref(),
+        MinorUnit::new(amount),
+        from_currency,
+        to_currency,
+        rounding_mode,
+    )
+    .err_to_js()?;
 
     Ok(serde_wasm_bindgen::to_value(&converted_amount)?)
 }
 
+fn convert_forex_minor_amount(
+    forex_data: Option<&ForexData>,
+    amount: MinorUnit,
+    from_currency: common_enums::Currency,
+    to_currency: common_enums::Currency,
+    rounding_mode: currency_conversion_types::RoundingMode,
+) -> Result<MinorUnit, String> {
+    let forex_data = forex_data.ok_or("Forex Data not seeded")?;
+
+    convert_minor_amount(
+        &forex_data.exchange_rates,
+        from_currency,
+        to_currency,
+        amount.get_amount_as_i64(),
+        rounding_mode,
+    )
+    .map(MinorUnit::new)
+    .map_err(|error| forex_conversion_error_message(error, from_currency, to_currency))
+}
+
+fn forex_conversion_error_message(
+    error: CurrencyConversionError,
+    from_currency: common_enums::Currency,
+    to_currency: common_enums::Currency,
+) -> String {
+    match error {
+        CurrencyConversionError::ConversionNotSupported(_) => {
+            format!("Forex rate not available for {from_currency} to {to_currency}")
+        }
+        CurrencyConversionError::DecimalMultiplicationFailed => {
+            "conversion not possible for provided values".to_string()
+        }
+        CurrencyConversionError::AmountOutOfRange => {
+            format!("Converted amount is out of range for {to_currency}")
+        }
+    }
+}
+
 /// This function can be used by the frontend to get all the two letter country codes
 /// along with their country names.
 #[wasm_bindgen(js_name=getTwoLetterCountryCode)]
@@ -96,6 +295,49 @@ pub fn get_two_letter_country_code() -> JsResult {
     Ok(serde_wasm_bindgen::to_value(&country_code_with_name)?)
 }
 
+/// This function returns the details of every country: its two and three letter codes, its
+/// name, its numeric ISO code and the currencies in use in it, the primary one first.
+#[wasm_bindgen(js_name = getCountryDetails)]
+pub fn get_country_details() -> JsResult {
+    Ok(serde_wasm_bindgen::to_value(&build_country_details())?)
+}
+
+fn build_country_details() -> Vec<types::CountryDetails> {
+    CountryAlpha2::iter()
+        .map(|country_code| {
+            let country = common_enums::Country::from_alpha2(country_code);
+            let currencies = country_code.get_currencies();
+            types::CountryDetails {
+                code: country_code,
+                alpha3_code: CountryAlpha2::from_alpha2_to_alpha3(country_code),
+                name: country,
+                numeric_code: country.to_numeric(),
+                primary_currency: currencies.first().copied(),
+                currencies,
+            }
+        })
+        .collect()
+}
+
+/// This function returns the countries in which the currency is in use, along with their names,
+/// for both the countries where it is the primary currency and those where it is not.
+#[wasm_bindgen(js_name = getCountriesForCurrency)]
+pub fn get_countries_for_currency(currency: JsValue) -> JsResult {
+    let currency: common_enums::Currency = serde_wasm_bindgen::from_value(currency)?;
+    let countries = find_countries_for_currency(currency);
+    Ok(serde_wasm_bindgen::to_value(&countries)?)
+}
+
+fn find_countries_for_currency(currency: common_enums::Currency) -> Vec<CountryCodeWithName> {
+    CountryAlpha2::iter()
+        .filter(|country_code| country_code.get_currencies().contains(&currency))
+        .map(|country_code| CountryCodeWithName {
+            code: country_code,
+            name: common_enums::Country::from_alpha2(country_code),
+        })
+        .collect()
+}
+
 /// This function can be used by the frontend to get all the merchant category codes
 /// along with their names.
 #[wasm_bindgen(js_name=getMerchantCategoryCodeWithName)]
@@ -112,14 +354,100 @@ pub fn get_merchant_category_code_with_name() -> JsResult {
     )?)
 }
 
+/// This function can be used by the frontend to search the merchant category codes, instead of
+/// getting all of them. The query is matched case-insensitively against both the code and the
+/// name of each merchant category code, and at most `limit` matches are returned in the order of
+/// `getMerchantCategoryCodeWithName`. An empty query matches every merchant category code.
+#[wasm_bindgen(js_name = searchMerchantCategoryCodes)]
+pub fn search_merchant_category_codes(query: &str, limit: usize) -> JsResult {
+    let merchant_category_codes_with_name = find_merchant_category_codes(query, limit);
+
+    Ok(serde_wasm_bindgen::to_value(
+        &merchant_category_codes_with_name,
+    )?)
+}
+
+fn find_merchant_category_codes(query: &str, limit: usize) -> Vec<MerchantCategoryCodeWithName> {
+    let query = query.trim().to_lowercase();
+
+    MerchantCategoryCode::iter()
+        .map(|mcc_value| MerchantCategoryCodeWithName {
+            code: mcc_value,
+            name: mcc_value.to_merchant_category_name(),
+        })
+        .filter(|mcc_with_name| {
+            query.is_empty()
+                || mcc_with_name.code.to_string().contains(&query)
+                || serde_json::to_value(mcc_with_name.name)
+                    .ok()
+                    .as_ref()
+                    .and_then(serde_json::Value::as_str)
+                    .is_some_and(|name| name.to_lowercase().contains(&query))
+        })
+        .take(limit)
+        .collect()
+}
+
 /// This function can be used by the frontend to provide the WASM with information about
 /// all the merchant's connector accounts. The input argument is a vector of all the merchant's
-/// connector accounts from the API.
+/// connector accounts from the API. The optional second argument is the country and currency
+/// filter configuration of the payment methods from the API, restricting the connectors the
+/// same way the backend does. Seeding again replaces the previously seeded graph, so the
+/// frontend can re-seed after the merchant's connector accounts change.
 #[cfg(feature = "v1")]
 #[wasm_bindgen(js_name = seedKnowledgeGraph)]
-pub fn seed_knowledge_graph(mcas: JsValue) -> JsResult {
+pub fn seed_knowledge_graph(mcas: JsValue, filters: JsValue) -> JsResult {
     let mcas: Vec<api_models::admin::MerchantConnectorResponse> =
         serde_wasm_bindgen::from_value(mcas)?;
+    let filters: Option<kgraph_utils::types::CountryCurrencyFilter> =
+        serde_wasm_bindgen::from_value(filters)
+            .map_err(|err| format!("Invalid payment method filters: {err}"))
+            .err_to_js()?;
+    set_seed_data(Some(make_seed_data(mcas, filters.unwrap_or_default())?));
+
+    Ok(JsValue::NULL)
+}
+
+/// This function seeds the payout knowledge graph with the merchant's payout connector accounts,
+/// which the connectors selected by payout routing programs are validated against. Connector
+/// accounts which are not payout processors are ignored.
+#[cfg(all(feature = "v1", feature = "payouts"))]
+#[wasm_bindgen(js_name = seedPayoutKnowledgeGraph)]
+pub fn seed_payout_knowledge_graph(mcas: JsValue) -> JsResult {
+    let mcas: Vec<api_models::admin::MerchantConnectorResponse> =
+        serde_wasm_bindgen::from_value(mcas)?;
+    set_payout_seed_data(Some(get_payout_connectors_of_mcas(&mcas).err_to_js()?));
+
+    Ok(JsValue::NULL)
+}
+
+#[cfg(all(feature = "v1", feature = "payouts"))]
+fn get_payout_connectors_of_mcas(
+    mcas: &[api_models::admin::MerchantConnectorResponse],
+) -> Result<Vec<api_model_enums::PayoutConnectors>, String> {
+    mcas.iter()
+        .filter(|mca| mca.connector_type == api_model_enums::ConnectorType::PayoutProcessor)
+        .map(|mca| {
+            api_model_enums::PayoutConnectors::from_str(&mca.connector_name)
+                .map_err(|_| format!("Invalid payout connector {}", mca.connector_name))
+        })
+        .collect()
+}
+
+/// This function can be used by the frontend to discard the seeded knowledge graphs, after which
+/// the knowledge graph based validations are skipped until they are seeded again.
+#[wasm_bindgen(js_name = resetKnowledgeGraph)]
+pub fn reset_knowledge_graph() {
+    set_seed_data(None);
+    #[cfg(feature = "payouts")]
+    set_payout_seed_data(None);
+}
+
+#[cfg(feature = "v1")]
+fn make_seed_data(
+    mcas: Vec<api_models::admin::MerchantConnectorResponse>,
+    filters: kgraph_utils::types::CountryCurrencyFilter,
+) -> Result<SeedData, JsValue> {
     let connectors: Vec<ast::ConnectorChoice> = mcas
         .iter()
         .map(|mca| {
@@ -130,389 +458,3238 @@ pub fn seed_knowledge_graph(mcas: JsValue) -> JsResult {
         .collect::<Result<_, _>>()
         .map_err(|_| "invalid connector name received")
         .err_to_js()?;
-    let pm_filter = kgraph_utils::types::PaymentMethodFilters(HashMap::new());
-    let config = kgraph_utils::types::CountryCurrencyFilter {
-        connector_configs: HashMap::new(),
-        default_configs: Some(pm_filter),
-    };
-    let mca_graph = kgraph_utils::mca::make_mca_graph(mcas, &config).err_to_js()?;
+    let mca_graph = kgraph_utils::mca::make_mca_graph(mcas.clone(), &filters).err_to_js()?;
     let analysis_graph = hyperswitch_constraint_graph::ConstraintGraph::combine(
         &mca_graph,
         &dssa::truth::ANALYSIS_GRAPH,
     )
     .err_to_js()?;
 
-    SEED_DATA
-        .set(SeedData {
-            cgraph: analysis_graph,
-            connectors,
-        })
-        .map_err(|_| "Knowledge Graph has been already seeded".to_string())
-        .err_to_js()?;
+    Ok(SeedData {
+        cgraph: analysis_graph,
+        connectors,
+        mcas,
+        filters,
+    })
+}
+
+/// This function adds a connector account to the seeded knowledge graph, replacing the account
+/// with the same id if it was already seeded. Only the connector account portion of the graph is
+/// rebuilt, with the filters it was seeded with.
+#[cfg(feature = "v1")]
+#[wasm_bindgen(js_name = addMerchantConnectorAccount)]
+pub fn add_merchant_connector_account(mca: JsValue) -> JsResult {
+    let mca: api_models::admin::MerchantConnectorResponse = serde_wasm_bindgen::from_value(mca)?;
+    update_seed_data(|seed_data| add_mca_to_seed_data(seed_data, mca))?;
+
+    Ok(JsValue::NULL)
+}
+
+/// This function removes a connector account from the seeded knowledge graph. Removing the last
+/// connector account leaves a graph without any connectors, for which no connector is valid.
+#[cfg(feature = "v1")]
+#[wasm_bindgen(js_name = removeMerchantConnectorAccount)]
+pub fn remove_merchant_connector_account(mca_id: &str) -> JsResult {
+    update_seed_data(|seed_data| {
+        let mcas = get_mcas_without(seed_data, mca_id).err_to_js()?;
+        make_seed_data(mcas, seed_data.filters.clone())
+    })?;
 
     Ok(JsValue::NULL)
 }
 
+#[cfg(feature = "v1")]
+fn add_mca_to_seed_data(
+    seed_data: &SeedData,
+    mca: api_models::admin::MerchantConnectorResponse,
+) -> Result<SeedData, JsValue> {
+    let mut mcas = seed_data
+        .mcas
+        .iter()
+        .filter(|seeded_mca| seeded_mca.merchant_connector_id != mca.merchant_connector_id)
+        .cloned()
+        .collect::<Vec<_>>();
+    mcas.push(mca);
+
+    make_seed_data(mcas, seed_data.filters.clone())
+}
+
+#[cfg(feature = "v1")]
+fn get_mcas_without(
+    seed_data: &SeedData,
+    mca_id: &str,
+) -> Result<Vec<api_models::admin::MerchantConnectorResponse>, String> {
+    let mcas = seed_data
+        .mcas
+        .iter()
+        .filter(|mca| mca.merchant_connector_id.get_string_repr() != mca_id)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    if mcas.len() == seed_data.mcas.len() {
+        return Err(format!(
+            "Merchant connector account '{mca_id}' is not seeded"
+        ));
+    }
+
+    Ok(mcas)
+}
+
 /// This function allows the frontend to get all the merchant's configured
 /// connectors that are valid for a rule based on the conditions specified in
-/// the rule
+/// the rule. The segments of the program can optionally be passed to resolve
+/// the segments the rule references
 #[wasm_bindgen(js_name = getValidConnectorsForRule)]
-pub fn get_valid_connectors_for_rule(rule: JsValue) -> JsResult {
-    let seed_data = SEED_DATA.get().ok_or("Data not seeded").err_to_js()?;
+pub fn get_valid_connectors_for_rule(rule: JsValue, segments: JsValue) -> JsResult {
+    let seed_data = get_seed_data().ok_or("Data not seeded").err_to_js()?;
 
     let rule: ast::Rule<ConnectorSelection> = serde_wasm_bindgen::from_value(rule)?;
-    let dir_rule = ast::lowering::lower_rule(rule).err_to_js()?;
-    let mut valid_connectors: Vec<(ast::ConnectorChoice, dir::DirValue)> = seed_data
-        .connectors
+    let segments: Option<ast::Segments> = serde_wasm_bindgen::from_value(segments)?;
+    let valid_connectors = get_valid_connectors(&seed_data, rule, &segments.unwrap_or_default())?;
+
+    Ok(serde_wasm_bindgen::to_value(&valid_connectors)?)
+}
+
+fn get_valid_connectors(
+    seed_data: &SeedData,
+    rule: ast::Rule<ConnectorSelection>,
+    segments: &ast::Segments,
+) -> Result<Vec<ast::ConnectorChoice>, JsValue> {
+    let connector_values = get_connector_values(&seed_data.connectors);
+    let dir_rule = ast::lowering::lower_rule_with_segments(rule, segments).err_to_js()?;
+
+    get_valid_connectors_for_dir_rule(
+        &seed_data.cgraph,
+        &connector_values,
+        &dir_rule,
+        &mut hyperswitch_constraint_graph::Memoization::new(),
+    )
+    .err_to_js()
+}
+
+fn get_connector_values(
+    connectors: &[ast::ConnectorChoice],
+) -> Vec<(ast::ConnectorChoice, dir::DirValue)> {
+    connectors
         .iter()
         .cloned()
         .map(|choice| (choice.clone(), dir::DirValue::Connector(Box::new(choice))))
-        .collect();
-    let mut invalid_connectors: HashSet<ast::ConnectorChoice> = HashSet::new();
+        .collect()
+}
 
-    let mut ctx_manager = state_machine::RuleContextManager::new(&dir_rule, &[]);
+/// Memoized results only hold for the context they were computed for, so the memoization is
+/// cleared before every analysis and only its allocation is reused across analyses.
+fn get_valid_connectors_for_dir_rule(
+    cgraph: &hyperswitch_constraint_graph::ConstraintGraph<dir::DirValue>,
+    connector_values: &[(ast::ConnectorChoice, dir::DirValue)],
+    dir_rule: &dir::DirRule<ConnectorSelection>,
+    memo: &mut hyperswitch_constraint_graph::Memoization<dir::DirValue>,
+) -> Result<Vec<ast::ConnectorChoice>, RuleConnectorsError> {
+    let mut invalid_connectors: HashSet<&ast::ConnectorChoice> = HashSet::new();
+
+    let mut ctx_manager = state_machine::RuleContextManager::new(dir_rule, &[]);
 
     let dummy_meta = HashMap::new();
 
     // For every conjunctive context in the Rule, verify validity of all still-valid connectors
     // using the knowledge graph
-    while let Some(ctx) = ctx_manager.advance_mut().err_to_js()? {
+    while let Some(ctx) = ctx_manager
+        .advance_mut()
+        .map_err(RuleConnectorsError::StateMachine)?
+    {
         // Standalone conjunctive context analysis to ensure the context itself is valid before
         // checking it against merchant's connectors
-        seed_data
-            .cgraph
-            .perform_context_analysis(
-                ctx,
-                &mut hyperswitch_constraint_graph::Memoization::new(),
-                None,
-            )
-            .err_to_js()?;
+        memo.clear();
+        cgraph
+            .perform_context_analysis(ctx, memo, None)
+            .map_err(RuleConnectorsError::ContextAnalysis)?;
 
         // Update conjunctive context and run analysis on all of merchant's connectors.
-        for (conn, choice) in &valid_connectors {
+        for (conn, choice) in connector_values {
             if invalid_connectors.contains(conn) {
                 continue;
             }
 
             let ctx_val = dssa::types::ContextValue::assertion(choice, &dummy_meta);
             ctx.push(ctx_val);
-            let analysis_result = seed_data.cgraph.perform_context_analysis(
-                ctx,
-                &mut hyperswitch_constraint_graph::Memoization::new(),
-                None,
-            );
+            memo.clear();
+            let analysis_result = cgraph.perform_context_analysis(ctx, memo, None);
             if analysis_result.is_err() {
-                invalid_connectors.insert(conn.clone());
+                invalid_connectors.insert(conn);
             }
             ctx.pop();
         }
     }
 
-    valid_connectors.retain(|(k, _)| !invalid_connectors.contains(k));
-
-    let valid_connectors: Vec<ast::ConnectorChoice> =
-        valid_connectors.into_iter().map(|c| c.0).collect();
+    Ok(connector_values
+        .iter()
+        .filter(|(conn, _)| !invalid_connectors.contains(conn))
+        .map(|(conn, _)| conn.clone())
+        .collect())
+}
 
-    Ok(serde_wasm_bindgen::to_value(&valid_connectors)?)
+#[derive(Debug, serde::Serialize)]
+#[serde(tag = "type", content = "info", rename_all = "snake_case")]
+enum RuleConnectorsError {
+    Lowering(dssa::types::AnalysisError),
+    StateMachine(state_machine::StateMachineError),
+    ContextAnalysis(dssa::graph::AnalysisError<dir::DirValue>),
 }
 
-#[wasm_bindgen(js_name = analyzeProgram)]
-pub fn analyze_program(js_program: JsValue) -> JsResult {
+/// This function allows the frontend to get the merchant's configured connectors that are valid
+/// for every rule of a program in a single call, instead of calling `getValidConnectorsForRule`
+/// for each rule. A rule which fails its analysis gets an error entry, the other rules of the
+/// program are still analyzed.
+#[wasm_bindgen(js_name = getValidConnectorsForProgram)]
+pub fn get_valid_connectors_for_program(js_program: JsValue) -> JsResult {
+    let seed_data = get_seed_data().ok_or("Data not seeded").err_to_js()?;
+
     let program: ast::Program<ConnectorSelection> = serde_wasm_bindgen::from_value(js_program)?;
-    analyzer::analyze(program, SEED_DATA.get().map(|sd| &sd.cgraph)).err_to_js()?;
-    Ok(JsValue::NULL)
-}
+    let rule_connectors =
+        get_program_valid_connectors(&seed_data.cgraph, &seed_data.connectors, program);
 
-#[wasm_bindgen(js_name = runProgram)]
-pub fn run_program(program: JsValue, input: JsValue) -> JsResult {
-    let program: ast::Program<ConnectorSelection> = serde_wasm_bindgen::from_value(program)?;
-    let input: inputs::BackendInput = serde_wasm_bindgen::from_value(input)?;
+    Ok(serde_wasm_bindgen::to_value(&rule_connectors)?)
+}
 
-    let backend = InterpreterBackend::with_program(program).err_to_js()?;
+fn get_program_valid_connectors(
+    cgraph: &hyperswitch_constraint_graph::ConstraintGraph<dir::DirValue>,
+    connectors: &[ast::ConnectorChoice],
+    program: ast::Program<ConnectorSelection>,
+) -> Vec<types::RuleValidConnectors> {
+    let connector_values = get_connector_values(connectors);
+    let mut memo = hyperswitch_constraint_graph::Memoization::new();
 
-    let res: euclid::backend::BackendOutput<ConnectorSelection> =
-        backend.execute(input).err_to_js()?;
+    program
+        .rules
+        .into_iter()
+        .enumerate()
+        .map(|(rule_index, rule)| {
+            let rule_name = rule.name.clone();
+            let valid_connectors = ast::lowering::lower_rule_with_segments(rule, &program.segments)
+                .map_err(RuleConnectorsError::Lowering)
+                .and_then(|dir_rule| {
+                    get_valid_connectors_for_dir_rule(
+                        cgraph,
+                        &connector_values,
+                        &dir_rule,
+                        &mut memo,
+                    )
+                });
 
-    Ok(serde_wasm_bindgen::to_value(&res)?)
+            match valid_connectors {
+                Ok(valid_connectors) => types::RuleValidConnectors {
+                    rule_index,
+                    rule_name,
+                    valid_connectors: Some(valid_connectors),
+                    error: None,
+                },
+                Err(error) => types::RuleValidConnectors {
+                    rule_index,
+                    rule_name,
+                    valid_connectors: None,
+                    error: Some(utils::JsError::from_error(&error)),
+                },
+            }
+        })
+        .collect()
 }
 
-#[wasm_bindgen(js_name = getAllConnectors)]
-pub fn get_all_connectors() -> JsResult {
-    Ok(serde_wasm_bindgen::to_value(RoutableConnectors::VARIANTS)?)
+/// This function allows the frontend to explain why a connector is missing from the connectors
+/// returned by `getValidConnectorsForRule`. For each of the merchant's configured connectors it
+/// returns whether the connector is valid for the rule and, when it is not, the constraint of the
+/// knowledge graph violated by the conditions of the rule. The segments of the program can
+/// optionally be passed to resolve the segments the rule references
+#[wasm_bindgen(js_name = explainConnectorValidity)]
+pub fn explain_connector_validity(rule: JsValue, segments: JsValue) -> JsResult {
+    let seed_data = get_seed_data().ok_or("Data not seeded").err_to_js()?;
+
+    let rule: ast::Rule<ConnectorSelection> = serde_wasm_bindgen::from_value(rule)?;
+    let segments: Option<ast::Segments> = serde_wasm_bindgen::from_value(segments)?;
+    let dir_rule =
+        ast::lowering::lower_rule_with_segments(rule, &segments.unwrap_or_default()).err_to_js()?;
+    let connector_validity =
+        get_connector_validity(&seed_data.cgraph, &seed_data.connectors, &dir_rule).err_to_js()?;
+
+    Ok(serde_wasm_bindgen::to_value(&connector_validity)?)
 }
 
-#[wasm_bindgen(js_name = getAllKeys)]
-pub fn get_all_keys() -> JsResult {
-    let excluded_keys = [
-        "Connector",
-        // 3DS Decision Rule Keys should not be included in the payument routing keys
-        "issuer_name",
-        "issuer_country",
-        "customer_device_platform",
-        "customer_device_type",
-        "customer_device_display_size",
-        "acquirer_country",
-        "acquirer_fraud_rate",
-    ];
-
-    let keys: Vec<&'static str> = dir::DirKeyKind::VARIANTS
+/// Runs the same analysis as [`get_valid_connectors_for_dir_rule`], keeping the violation found
+/// for each invalid connector instead of only dropping the connector
+fn get_connector_validity(
+    cgraph: &hyperswitch_constraint_graph::ConstraintGraph<dir::DirValue>,
+    connectors: &[ast::ConnectorChoice],
+    dir_rule: &dir::DirRule<ConnectorSelection>,
+) -> Result<Vec<types::ConnectorValidity>, RuleConnectorsError> {
+    let connector_values = get_connector_values(connectors);
+    let mut violations: HashMap<&ast::ConnectorChoice, types::ConnectorValidity> = HashMap::new();
+    let mut memo = hyperswitch_constraint_graph::Memoization::new();
+
+    let mut ctx_manager = state_machine::RuleContextManager::new(dir_rule, &[]);
+
+    let dummy_meta = HashMap::new();
+
+    while let Some(ctx) = ctx_manager
+        .advance_mut()
+        .map_err(RuleConnectorsError::StateMachine)?
+    {
+        memo.clear();
+        cgraph
+            .perform_context_analysis(ctx, &mut memo, None)
+            .map_err(RuleConnectorsError::ContextAnalysis)?;
+
+        for (conn, choice) in &connector_values {
+            if violations.contains_key(conn) {
+                continue;
+            }
+
+            let ctx_val = dssa::types::ContextValue::assertion(choice, &dummy_meta);
+            ctx.push(ctx_val);
+            memo.clear();
+            if let Err(error) = cgraph.perform_context_analysis(ctx, &mut memo, None) {
+                // The trace only weakly references the memoized traces, so it is mapped before
+                // the memoization is cleared for the next analysis
+                violations.insert(conn, get_connector_violation(conn, &error));
+            }
+            ctx.pop();
+        }
+    }
+
+    Ok(connector_values
         .iter()
-        .copied()
-        .filter(|s| !excluded_keys.contains(s))
-        .collect();
-    Ok(serde_wasm_bindgen::to_value(&keys)?)
+        .map(|(conn, _)| {
+            violations
+                .remove(conn)
+                .unwrap_or_else(|| types::ConnectorValidity {
+                    connector: conn.clone(),
+                    valid: true,
+                    reason: None,
+                    violation: None,
+                })
+        })
+        .collect())
 }
 
-#[wasm_bindgen(js_name = getKeyType)]
-pub fn get_key_type(key: &str) -> Result<String, String> {
-    let key = dir::DirKeyKind::from_str(key).map_err(|_| "Invalid key received".to_string())?;
-    let key_str = key.get_type().to_string();
-    Ok(key_str)
-}
+fn get_connector_violation(
+    connector: &ast::ConnectorChoice,
+    error: &dssa::graph::AnalysisError<dir::DirValue>,
+) -> types::ConnectorValidity {
+    let violation = match error {
+        dssa::graph::AnalysisError::AssertionTrace { trace, .. }
+        | dssa::graph::AnalysisError::NegationTrace { trace, .. } => trace
+            .upgrade()
+            .map(|trace| types::ConstraintViolation::from_trace(&trace)),
+        dssa::graph::AnalysisError::Graph(_) => None,
+    };
+    let reason = match (&violation, error) {
+        (Some(violation), _) => format!("{} {}", connector.connector, violation.describe()),
+        (None, dssa::graph::AnalysisError::Graph(graph_error)) => {
+            format!(
+                "{} could not be analyzed: {graph_error}",
+                connector.connector
+            )
+        }
+        (None, _) => format!("{} is not supported by the rule", connector.connector),
+    };
 
-#[wasm_bindgen(js_name = getThreeDsKeys)]
-pub fn get_three_ds_keys() -> JsResult {
-    let keys = <common_types::payments::ConditionalConfigs as EuclidDirFilter>::ALLOWED;
-    Ok(serde_wasm_bindgen::to_value(keys)?)
+    types::ConnectorValidity {
+        connector: connector.clone(),
+        valid: false,
+        reason: Some(reason),
+        violation,
+    }
 }
 
-#[wasm_bindgen(js_name= getSurchargeKeys)]
-pub fn get_surcharge_keys() -> JsResult {
-    let keys = <SurchargeDecisionConfigs as EuclidDirFilter>::ALLOWED;
-    Ok(serde_wasm_bindgen::to_value(keys)?)
-}
+/// This function lowers the program to its DIR representation, in which every condition is
+/// resolved to the values it matches, for the frontend to keep alongside its audit logs
+#[wasm_bindgen(js_name = lowerProgram)]
+pub fn lower_program(js_program: JsValue) -> JsResult {
+    let program: ast::Program<ConnectorSelection> = serde_wasm_bindgen::from_value(js_program)?;
+    let dir_program = ast::lowering::lower_program(program).err_to_js()?;
 
-#[wasm_bindgen(js_name= getThreeDsDecisionRuleKeys)]
-pub fn get_three_ds_decision_rule_keys() -> JsResult {
-    let keys = <ThreeDSDecisionRule as EuclidDirFilter>::ALLOWED;
-    Ok(serde_wasm_bindgen::to_value(keys)?)
+    Ok(serde_wasm_bindgen::to_value(&dir_program)?)
 }
 
-#[wasm_bindgen(js_name=parseToString)]
-pub fn parser(val: String) -> String {
-    ron_parser::my_parse(val)
-}
+/// This function renders the lowered program as text, for the frontend to diff the routing
+/// configurations active at different times. Rendering equivalent programs gives identical text,
+/// whatever the order of the conditions of their rules.
+#[wasm_bindgen(js_name = formatProgram)]
+pub fn format_program(js_program: JsValue) -> JsResult {
+    let program: ast::Program<ConnectorSelection> = serde_wasm_bindgen::from_value(js_program)?;
+    let dir_program = ast::lowering::lower_program(program).err_to_js()?;
 
-#[wasm_bindgen(js_name = getVariantValues)]
-pub fn get_variant_values(key: &str) -> Result<JsValue, JsValue> {
-    let key = dir::DirKeyKind::from_str(key).map_err(|_| "Invalid key received".to_string())?;
+    Ok(JsValue::from_str(&format::format_program(&dir_program)))
+}
 
-    let variants: &[&str] = match key {
-        dir::DirKeyKind::PaymentMethod => dir_enums::PaymentMethod::VARIANTS,
-        dir::DirKeyKind::CardType => dir_enums::CardType::VARIANTS,
-        dir::DirKeyKind::CardNetwork => dir_enums::CardNetwork::VARIANTS,
-        dir::DirKeyKind::PayLaterType => dir_enums::PayLaterType::VARIANTS,
-        dir::DirKeyKind::WalletType => dir_enums::WalletType::VARIANTS,
-        dir::DirKeyKind::BankRedirectType => dir_enums::BankRedirectType::VARIANTS,
-        dir::DirKeyKind::CryptoType => dir_enums::CryptoType::VARIANTS,
-        dir::DirKeyKind::RewardType => dir_enums::RewardType::VARIANTS,
-        dir::DirKeyKind::AuthenticationType => dir_enums::AuthenticationType::VARIANTS,
-        dir::DirKeyKind::CaptureMethod => dir_enums::CaptureMethod::VARIANTS,
-        dir::DirKeyKind::PaymentCurrency => dir_enums::PaymentCurrency::VARIANTS,
-        dir::DirKeyKind::BusinessCountry => dir_enums::Country::VARIANTS,
-        dir::DirKeyKind::BillingCountry => dir_enums::Country::VARIANTS,
-        dir::DirKeyKind::BankTransferType => dir_enums::BankTransferType::VARIANTS,
-        dir::DirKeyKind::UpiType => dir_enums::UpiType::VARIANTS,
-        dir::DirKeyKind::SetupFutureUsage => dir_enums::SetupFutureUsage::VARIANTS,
-        dir::DirKeyKind::PaymentType => dir_enums::PaymentType::VARIANTS,
-        dir::DirKeyKind::MandateType => dir_enums::MandateType::VARIANTS,
-        dir::DirKeyKind::MandateAcceptanceType => dir_enums::MandateAcceptanceType::VARIANTS,
-        dir::DirKeyKind::CardRedirectType => dir_enums::CardRedirectType::VARIANTS,
-        dir::DirKeyKind::GiftCardType => dir_enums::GiftCardType::VARIANTS,
-        dir::DirKeyKind::VoucherType => dir_enums::VoucherType::VARIANTS,
-        dir::DirKeyKind::BankDebitType => dir_enums::BankDebitType::VARIANTS,
-        dir::DirKeyKind::RealTimePaymentType => dir_enums::RealTimePaymentType::VARIANTS,
-        dir::DirKeyKind::OpenBankingType => dir_enums::OpenBankingType::VARIANTS,
-        dir::DirKeyKind::MobilePaymentType => dir_enums::MobilePaymentType::VARIANTS,
-        dir::DirKeyKind::IssuerCountry => dir_enums::Country::VARIANTS,
-        dir::DirKeyKind::AcquirerCountry => dir_enums::Country::VARIANTS,
-        dir::DirKeyKind::CustomerDeviceType => dir_enums::CustomerDeviceType::VARIANTS,
-        dir::DirKeyKind::CustomerDevicePlatform => dir_enums::CustomerDevicePlatform::VARIANTS,
-        dir::DirKeyKind::CustomerDeviceDisplaySize => {
-            dir_enums::CustomerDeviceDisplaySize::VARIANTS
-        }
+/// This function analyzes the program for errors, returning the list of warnings found. The
+/// warnings include keys used by rules without the keys they depend on and, when the knowledge
+/// graph has been seeded, payment method types used by rules which none of the merchant's
+/// connectors support.
+#[wasm_bindgen(js_name = analyzeProgram)]
+pub fn analyze_program(js_program: JsValue) -> JsResult {
+    let program: ast::Program<ConnectorSelection> = serde_wasm_bindgen::from_value(js_program)?;
+    let seed_data = get_seed_data();
+    let dir_program = ast::lowering::lower_program(program.clone()).err_to_js()?;
+    analyzer::analyze(program, seed_data.as_deref().map(|sd| &sd.cgraph)).err_to_js()?;
 
-        dir::DirKeyKind::PaymentAmount
-        | dir::DirKeyKind::Connector
-        | dir::DirKeyKind::CardBin
-        | dir::DirKeyKind::BusinessLabel
-        | dir::DirKeyKind::MetaData
-        | dir::DirKeyKind::IssuerName
-        | dir::DirKeyKind::AcquirerFraudRate => Err("Key does not have variants".to_string())?,
-    };
+    let mut warnings = seed_data
+        .as_deref()
+        .map(|sd| analyzer::analyze_connector_support(&dir_program, &sd.cgraph, &sd.connectors))
+        .transpose()
+        .err_to_js()?
+        .unwrap_or_default();
+    warnings.extend(analyzer::analyze_key_dependencies(&dir_program).err_to_js()?);
 
-    Ok(serde_wasm_bindgen::to_value(variants)?)
+    Ok(serde_wasm_bindgen::to_value(&warnings)?)
 }
 
-#[wasm_bindgen(js_name = addTwo)]
-pub fn add_two(n1: i64, n2: i64) -> i64 {
-    n1 + n2
+/// This function validates every rule of the program on its own, returning the diagnostics of
+/// all the rules instead of failing on the first invalid one. Each diagnostic names the rule and,
+/// where possible, the key of the offending condition. The knowledge graph based checks are only
+/// performed when the knowledge graph has been seeded.
+#[wasm_bindgen(js_name = validateProgramDetailed)]
+pub fn validate_program_detailed(js_program: JsValue) -> JsResult {
+    let program: ast::Program<ConnectorSelection> = serde_wasm_bindgen::from_value(js_program)?;
+    let validation = get_program_validation(program, get_seed_data().as_deref());
+
+    Ok(serde_wasm_bindgen::to_value(&validation)?)
 }
 
-#[wasm_bindgen(js_name = getDescriptionCategory)]
-pub fn get_description_category() -> JsResult {
-    let keys = dir::DirKeyKind::VARIANTS
-        .iter()
-        .copied()
-        .filter(|s| s != &"Connector")
-        .collect::<Vec<&'static str>>();
-    let mut category: HashMap<Option<&str>, Vec<types::Details<'_>>> = HashMap::new();
-    for key in keys {
-        let dir_key =
-            dir::DirKeyKind::from_str(key).map_err(|_| "Invalid key received".to_string())?;
-        let details = types::Details {
-            description: dir_key.get_detailed_message(),
-            kind: dir_key.clone(),
+fn get_program_validation(
+    program: ast::Program<ConnectorSelection>,
+    seed_data: Option<&SeedData>,
+) -> types::ProgramValidation {
+    let mut diagnostics = Vec::new();
+
+    for (rule_index, rule) in program.rules.iter().enumerate() {
+        let rule_diagnostic = |key, severity, reason| types::RuleDiagnostic {
+            rule_index,
+            rule_name: rule.name.clone(),
+            key,
+            severity,
+            reason,
         };
-        category
-            .entry(dir_key.get_str("Category"))
-            .and_modify(|val| val.push(details.clone()))
-            .or_insert(vec![details]);
+        let rule_program = ast::Program {
+            default_selection: program.default_selection.clone(),
+            rules: vec![rule.clone()],
+            metadata: program.metadata.clone(),
+            version: program.version,
+            segments: program.segments.clone(),
+        };
+
+        match analyze_rule_program(rule_program, seed_data) {
+            Ok(warnings) => diagnostics.extend(warnings.into_iter().map(|warning| {
+                let key = match &warning {
+                    dssa::types::AnalysisWarning::UnsupportedPaymentMethodType {
+                        value, ..
+                    } => Some(value.get_key().kind),
+                    dssa::types::AnalysisWarning::MissingKeyDependency { key, .. } => {
+                        Some(key.clone())
+                    }
+                    dssa::types::AnalysisWarning::ShadowedRule { .. } => None,
+                };
+                rule_diagnostic(key, types::DiagnosticSeverity::Warning, warning.to_string())
+            })),
+            Err(error) => diagnostics.push(rule_diagnostic(
+                get_analysis_error_key(&error.error_type),
+                types::DiagnosticSeverity::Error,
+                get_analysis_error_reason(&error.error_type),
+            )),
+        }
     }
 
-    Ok(serde_wasm_bindgen::to_value(&category)?)
+    types::ProgramValidation {
+        is_valid: diagnostics
+            .iter()
+            .all(|diagnostic| diagnostic.severity != types::DiagnosticSeverity::Error),
+        diagnostics,
+    }
 }
 
-#[wasm_bindgen(js_name = getConnectorConfig)]
-pub fn get_connector_config(key: &str) -> JsResult {
-    let key = api_model_enums::Connector::from_str(key)
-        .map_err(|_| "Invalid key received".to_string())?;
-    let res = connector::ConnectorConfig::get_connector_config(key)?;
-    Ok(serde_wasm_bindgen::to_value(&res)?)
+/// Runs the same analyses as `analyzeProgram` on a program, returning its warnings
+fn analyze_rule_program(
+    program: ast::Program<ConnectorSelection>,
+    seed_data: Option<&SeedData>,
+) -> Result<Vec<dssa::types::AnalysisWarning>, dssa::types::AnalysisError> {
+    let dir_program = ast::lowering::lower_program(program.clone())?;
+    analyzer::analyze(program, seed_data.map(|sd| &sd.cgraph))?;
+
+    let mut warnings = seed_data
+        .map(|sd| analyzer::analyze_connector_support(&dir_program, &sd.cgraph, &sd.connectors))
+        .transpose()?
+        .unwrap_or_default();
+    warnings.extend(analyzer::analyze_key_dependencies(&dir_program)?);
+
+    Ok(warnings)
 }
 
-#[cfg(feature = "payouts")]
-#[wasm_bindgen(js_name = getPayoutConnectorConfig)]
-pub fn get_payout_connector_config(key: &str) -> JsResult {
-    let key = api_model_enums::PayoutConnectors::from_str(key)
-        .map_err(|_| "Invalid key received".to_string())?;
-    let res = connector::ConnectorConfig::get_payout_connector_config(key)?;
-    Ok(serde_wasm_bindgen::to_value(&res)?)
+/// The value whose analysis against the knowledge graph failed
+fn get_graph_analysis_value(
+    error: &dssa::graph::AnalysisError<dir::DirValue>,
+) -> Option<dir::DirValue> {
+    let trace = match error {
+        dssa::graph::AnalysisError::AssertionTrace { trace, .. }
+        | dssa::graph::AnalysisError::NegationTrace { trace, .. } => trace.upgrade()?,
+        dssa::graph::AnalysisError::Graph(_) => return None,
+    };
+
+    match trace.as_ref() {
+        hyperswitch_constraint_graph::AnalysisTrace::Value {
+            value: hyperswitch_constraint_graph::NodeValue::Value(value),
+            ..
+        }
+        | hyperswitch_constraint_graph::AnalysisTrace::InAggregation {
+            found: Some(value), ..
+        } => Some(value.clone()),
+        _ => None,
+    }
 }
 
-#[wasm_bindgen(js_name = getAuthenticationConnectorConfig)]
-pub fn get_authentication_connector_config(key: &str) -> JsResult {
-    let key = api_model_enums::AuthenticationConnectors::from_str(key)
-        .map_err(|_| "Invalid key received".to_string())?;
-    let res = connector::ConnectorConfig::get_authentication_connector_config(key)?;
+fn get_analysis_error_key(error_type: &dssa::types::AnalysisErrorType) -> Option<dir::DirKeyKind> {
+    use dssa::types::AnalysisErrorType;
+
+    match error_type {
+        AnalysisErrorType::InvalidKey(key)
+        | AnalysisErrorType::InvalidVariant { key, .. }
+        | AnalysisErrorType::InvalidType { key, .. } => dir::DirKeyKind::from_str(key).ok(),
+        AnalysisErrorType::InvalidValue { key, .. }
+        | AnalysisErrorType::UnsupportedProgramKey(key) => Some(key.clone()),
+        AnalysisErrorType::ConflictingAssertions { key, .. }
+        | AnalysisErrorType::ExhaustiveNegation { key, .. } => Some(key.kind.clone()),
+        AnalysisErrorType::NegatedAssertion { value, .. } => Some(value.get_key().kind),
+        AnalysisErrorType::InvalidSegment(ast::SegmentError::KeyMismatch { key, .. }) => {
+            dir::DirKeyKind::from_str(key).ok()
+        }
+        AnalysisErrorType::GraphAnalysis(error, _) => {
+            get_graph_analysis_value(error).map(|value| value.get_key().kind)
+        }
+        AnalysisErrorType::InvalidComparison { .. }
+        | AnalysisErrorType::InvalidSegment(_)
+        | AnalysisErrorType::StateMachine(_)
+        | AnalysisErrorType::NotImplemented
+        | AnalysisErrorType::NotSupported => None,
+    }
+}
+
+fn get_analysis_error_reason(error_type: &dssa::types::AnalysisErrorType) -> String {
+    match error_type {
+        // The graph analysis error is displayed as the whole analysis trace, name the failing
+        // value instead
+        dssa::types::AnalysisErrorType::GraphAnalysis(error, _) => {
+            match get_graph_analysis_value(error) {
+                Some(value) => format!(
+                    "The condition '{value:?}' can not be satisfied along with the other conditions of the rule"
+                ),
+                None => "The conditions of the rule can not be satisfied together".to_string(),
+            }
+        }
+        error_type => error_type.to_string(),
+    }
+}
+
+/// This function detects the rules of the program which never match, as an earlier rule matches
+/// all of their payments, returning a warning naming both rules. The warnings are advisory and do
+/// not make the program invalid, hence they are not returned by `analyzeProgram`.
+#[wasm_bindgen(js_name = detectShadowedRules)]
+pub fn detect_shadowed_rules(js_program: JsValue) -> JsResult {
+    let program: ast::Program<ConnectorSelection> = serde_wasm_bindgen::from_value(js_program)?;
+    let warnings = get_shadowed_rule_warnings(program).err_to_js()?;
+
+    Ok(serde_wasm_bindgen::to_value(&warnings)?)
+}
+
+fn get_shadowed_rule_warnings(
+    program: ast::Program<ConnectorSelection>,
+) -> Result<Vec<dssa::types::AnalysisWarning>, dssa::types::AnalysisError> {
+    let dir_program = ast::lowering::lower_program(program)?;
+
+    analyzer::analyze_shadowed_rules(&dir_program)
+}
+
+/// This function validates the value of every comparison of a rule against the type of its key,
+/// returning an error per offending comparison. Amounts must not be negative, card bins must
+/// be 6 to 8 digits and metadata keys must not be empty. The rule is only walked and not lowered,
+/// so this does not require the knowledge graph to be seeded.
+#[wasm_bindgen(js_name = validateRuleValues)]
+pub fn validate_rule_values(rule: JsValue) -> JsResult {
+    let rule: ast::Rule<ConnectorSelection> = serde_wasm_bindgen::from_value(rule)?;
+
+    Ok(serde_wasm_bindgen::to_value(&get_rule_value_errors(&rule))?)
+}
+
+fn get_rule_value_errors(rule: &ast::Rule<ConnectorSelection>) -> Vec<types::RuleValueError> {
+    let mut errors = Vec::new();
+    collect_statement_value_errors(&rule.statements, &mut Vec::new(), &mut errors);
+
+    errors
+}
+
+fn collect_statement_value_errors(
+    statements: &[ast::IfStatement],
+    statement_path: &mut Vec<usize>,
+    errors: &mut Vec<types::RuleValueError>,
+) {
+    for (statement_index, statement) in statements.iter().enumerate() {
+        statement_path.push(statement_index);
+
+        for (condition_index, comparison) in statement.condition.iter().enumerate() {
+            if let Some(reason) = get_comparison_value_error(comparison) {
+                errors.push(types::RuleValueError {
+                    statement_path: statement_path.clone(),
+                    condition_index,
+                    key: comparison.lhs.clone(),
+                    value: comparison.value.clone(),
+                    reason,
+                });
+            }
+        }
+
+        if let Some(nested) = &statement.nested {
+            collect_statement_value_errors(nested, statement_path, errors);
+        }
+
+        statement_path.pop();
+    }
+}
+
+fn get_comparison_value_error(comparison: &ast::Comparison) -> Option<String> {
+    let Ok(key) = dir::DirKeyKind::from_str(&comparison.lhs) else {
+        return Some(format!("Invalid key received: '{}'", comparison.lhs));
+    };
+
+    let (expected, got) = (key.get_type(), comparison.value.get_type());
+    // Segments are defined on the program, and are checked when the rule is lowered along with
+    // them
+    if matches!(got, euclid::types::DataType::Segment) {
+        return None;
+    }
+    if std::mem::discriminant(&expected) != std::mem::discriminant(&got) {
+        return Some(format!("Expected a value of type {expected}, got {got}"));
+    }
+
+    match &comparison.value {
+        ast::ValueType::Number(number) => get_number_value_error(std::slice::from_ref(number)),
+        ast::ValueType::NumberArray(numbers) => get_number_value_error(numbers),
+        ast::ValueType::NumberComparisonArray(comparisons) => get_number_value_error(
+            &comparisons
+                .iter()
+                .map(|comparison| comparison.number)
+                .collect::<Vec<_>>(),
+        ),
+        ast::ValueType::StrValue(card_bin) if key == dir::DirKeyKind::CardBin => {
+            let is_valid = (6..=8).contains(&card_bin.len())
+                && card_bin.chars().all(|character| character.is_ascii_digit());
+            (!is_valid).then(|| format!("Expected a card bin of 6 to 8 digits, got '{card_bin}'"))
+        }
+        ast::ValueType::MetadataVariant(metadata) if metadata.key.trim().is_empty() => {
+            Some("Expected a non-empty metadata key".to_string())
+        }
+        ast::ValueType::StrValue(_)
+        | ast::ValueType::MetadataVariant(_)
+        | ast::ValueType::EnumVariant(_)
+        | ast::ValueType::EnumVariantArray(_)
+        | ast::ValueType::SegmentReference(_) => None,
+    }
+}
+
+fn get_number_value_error(numbers: &[MinorUnit]) -> Option<String> {
+    numbers
+        .iter()
+        .copied()
+        .map(MinorUnit::get_amount_as_i64)
+        .find(|number| number.is_negative())
+        .map(|number| format!("Expected a non-negative amount, got {number}"))
+}
+
+/// This function can be used by the frontend to upgrade a draft program saved with an older
+/// schema version to the latest version, renaming any deprecated keys and values.
+#[wasm_bindgen(js_name = migrateProgram)]
+pub fn migrate_program(js_program: JsValue, from_version: u32) -> JsResult {
+    let program: serde_json::Value = serde_wasm_bindgen::from_value(js_program)?;
+    let program: ast::Program<ConnectorSelection> =
+        ast::migration::migrate_program(program, from_version).err_to_js()?;
+
+    Ok(serde_wasm_bindgen::to_value(&program)?)
+}
+
+#[wasm_bindgen(js_name = runProgram)]
+pub fn run_program(program: JsValue, input: JsValue) -> JsResult {
+    let program: ast::Program<ConnectorSelection> = serde_wasm_bindgen::from_value(program)?;
+    let input: inputs::BackendInput = serde_wasm_bindgen::from_value(input)?;
+
+    let res = execute_program(program, Some(input)).err_to_js()?;
+
     Ok(serde_wasm_bindgen::to_value(&res)?)
 }
 
-#[wasm_bindgen(js_name = getTaxProcessorConfig)]
-pub fn get_tax_processor_config(key: &str) -> JsResult {
-    let key = api_model_enums::TaxConnectors::from_str(key)
-        .map_err(|_| "Invalid key received".to_string())?;
-    let res = connector::ConnectorConfig::get_tax_processor_config(key)?;
+/// This function returns the output of the program when none of its rules match, without
+/// evaluating them. When the knowledge graph has been seeded, the connectors of the default
+/// selection are validated against the merchant's connectors.
+#[wasm_bindgen(js_name = runProgramFallback)]
+pub fn run_program_fallback(program: JsValue) -> JsResult {
+    let program: ast::Program<ConnectorSelection> = serde_wasm_bindgen::from_value(program)?;
+
+    let res = execute_program(program, None).err_to_js()?;
+    if let Some(seed_data) = get_seed_data() {
+        validate_seeded_connectors(&res.connector_selection, &seed_data.connectors).err_to_js()?;
+    }
+
     Ok(serde_wasm_bindgen::to_value(&res)?)
 }
 
-#[wasm_bindgen(js_name = getPMAuthenticationProcessorConfig)]
-pub fn get_pm_authentication_processor_config(key: &str) -> JsResult {
-    let key: api_model_enums::PmAuthConnectors = api_model_enums::PmAuthConnectors::from_str(key)
-        .map_err(|_| "Invalid key received".to_string())?;
-    let res = connector::ConnectorConfig::get_pm_authentication_processor_config(key)?;
+/// Executes the program against the input, or resolves its default selection when there is no
+/// input
+fn execute_program(
+    program: ast::Program<ConnectorSelection>,
+    input: Option<inputs::BackendInput>,
+) -> Result<euclid::backend::BackendOutput<ConnectorSelection>, InterpreterError> {
+    let backend = InterpreterBackend::with_program(program)?;
+
+    match input {
+        Some(input) => backend.execute(input),
+        None => Ok(backend.execute_default_selection()),
+    }
+}
+
+/// This function executes the program on each of the inputs without any side effects, returning
+/// the connectors selected for every input along with the rule which selected them, or whether
+/// the default selection was used. Only the first matching rule is reported when rules overlap.
+#[wasm_bindgen(js_name = simulateProgram)]
+pub fn simulate_program(program: JsValue, inputs: JsValue) -> JsResult {
+    let program: ast::Program<ConnectorSelection> = serde_wasm_bindgen::from_value(program)?;
+    let inputs: Vec<inputs::BackendInput> = serde_wasm_bindgen::from_value(inputs)?;
+
+    let res = get_program_simulations(program, inputs).err_to_js()?;
+
     Ok(serde_wasm_bindgen::to_value(&res)?)
 }
 
-#[wasm_bindgen(js_name = getRequestPayload)]
-pub fn get_request_payload(input: JsValue, response: JsValue) -> JsResult {
-    let input: DashboardRequestPayload = serde_wasm_bindgen::from_value(input)?;
-    let api_response: ConnectorApiIntegrationPayload = serde_wasm_bindgen::from_value(response)?;
-    let result = DashboardRequestPayload::create_connector_request(input, api_response);
-    Ok(serde_wasm_bindgen::to_value(&result)?)
+fn get_program_simulations(
+    program: ast::Program<ConnectorSelection>,
+    inputs: Vec<inputs::BackendInput>,
+) -> Result<Vec<types::ProgramSimulation>, InterpreterError> {
+    let backend = InterpreterBackend::with_program(program)?;
+
+    inputs
+        .into_iter()
+        .map(|input| {
+            let (rule_index, output) = backend.execute_with_rule_index(input)?;
+
+            Ok(types::ProgramSimulation {
+                rule_index,
+                rule_name: output.rule_name,
+                is_default_selection: rule_index.is_none(),
+                connector_selection: output.connector_selection,
+            })
+        })
+        .collect()
 }
 
-#[wasm_bindgen(js_name = getResponsePayload)]
-pub fn get_response_payload(input: JsValue) -> JsResult {
-    let input: ConnectorApiIntegrationPayload = serde_wasm_bindgen::from_value(input)?;
-    let result = ConnectorApiIntegrationPayload::get_transformed_response_payload(input);
-    Ok(serde_wasm_bindgen::to_value(&result)?)
+fn validate_seeded_connectors(
+    connector_selection: &ConnectorSelection,
+    seeded_connectors: &[ast::ConnectorChoice],
+) -> Result<(), String> {
+    connector_selection
+        .get_connector_list()
+        .into_iter()
+        .find(|choice| {
+            !seeded_connectors
+                .iter()
+                .any(|seeded| seeded.connector == choice.connector)
+        })
+        .map_or(Ok(()), |choice| {
+            Err(format!(
+                "Connector {} is not configured for the merchant",
+                choice.connector
+            ))
+        })
 }
 
-#[cfg(feature = "payouts")]
-#[wasm_bindgen(js_name = getAllPayoutKeys)]
-pub fn get_all_payout_keys() -> JsResult {
-    let keys: Vec<&'static str> = dir::PayoutDirKeyKind::VARIANTS.to_vec();
+#[wasm_bindgen(js_name = getProgramHash)]
+pub fn get_program_hash(program: JsValue) -> JsResult {
+    let program: ast::Program<ConnectorSelection> = serde_wasm_bindgen::from_value(program)?;
+    let hash = ast::hashing::hash_program(&program).err_to_js()?;
+
+    Ok(serde_wasm_bindgen::to_value(&hash)?)
+}
+
+#[wasm_bindgen(js_name = getAllConnectors)]
+pub fn get_all_connectors() -> JsResult {
+    Ok(serde_wasm_bindgen::to_value(RoutableConnectors::VARIANTS)?)
+}
+
+#[wasm_bindgen(js_name = getAllKeys)]
+pub fn get_all_keys() -> JsResult {
+    Ok(serde_wasm_bindgen::to_value(&get_payment_routing_keys())?)
+}
+
+/// Returns every key except the connector and the keys only allowed in 3DS decision rules, which
+/// should not be included in the payment routing keys
+fn get_payment_routing_keys() -> Vec<String> {
+    let three_ds_decision_rule_keys = <ThreeDSDecisionRule as EuclidDirFilter>::ALLOWED;
+    let connector_selection_keys = <ConnectorSelection as EuclidDirFilter>::ALLOWED;
+
+    dir::DirKeyKind::iter()
+        .filter(|key| {
+            *key != dir::DirKeyKind::Connector
+                && (!three_ds_decision_rule_keys.contains(key)
+                    || connector_selection_keys.contains(key))
+        })
+        .map(|key| key.to_string())
+        .collect()
+}
+
+/// Lists the allowed keys of every rule category (`payment_routing`, `surcharge`, `three_ds`,
+/// `three_ds_decision` and `payouts`) in a single call.
+#[wasm_bindgen(js_name = getKeysByRuleCategory)]
+pub fn get_keys_by_rule_category() -> JsResult {
+    let keys: HashMap<types::RuleCategory, Vec<String>> = types::RuleCategory::iter()
+        .map(|category| (category, category.get_allowed_keys()))
+        .collect();
     Ok(serde_wasm_bindgen::to_value(&keys)?)
 }
 
-#[cfg(feature = "payouts")]
-#[wasm_bindgen(js_name = getPayoutVariantValues)]
-pub fn get_payout_variant_values(key: &str) -> Result<JsValue, JsValue> {
-    let key =
-        dir::PayoutDirKeyKind::from_str(key).map_err(|_| "Invalid key received".to_string())?;
+/// Lists the keys usable in the given scope (`payments`, `three_ds` or `all`, which is the
+/// default), each annotated with every scope it can be used in.
+#[wasm_bindgen(js_name = getKeysForScope)]
+pub fn get_keys_for_scope(scope: Option<String>) -> JsResult {
+    let scope = match scope.as_deref() {
+        None | Some("all") => None,
+        Some(scope) => Some(
+            types::KeyScope::from_str(scope).map_err(|_| "Invalid scope received".to_string())?,
+        ),
+    };
+    Ok(serde_wasm_bindgen::to_value(&get_scoped_keys(scope))?)
+}
+
+fn get_scoped_keys(scope: Option<types::KeyScope>) -> Vec<types::ScopedKey> {
+    dir::DirKeyKind::iter()
+        .map(|key| types::ScopedKey {
+            scopes: types::KeyScope::iter()
+                .filter(|key_scope| key_scope.get_allowed_keys().contains(&key))
+                .collect(),
+            key,
+        })
+        .filter(|scoped_key| scope.map_or(true, |scope| scoped_key.scopes.contains(&scope)))
+        .collect()
+}
+
+#[wasm_bindgen(js_name = getKeyType)]
+pub fn get_key_type(key: &str) -> Result<String, String> {
+    let key = dir::DirKeyKind::from_str(key).map_err(|_| "Invalid key received".to_string())?;
+    let key_str = key.get_type().to_string();
+    Ok(key_str)
+}
+
+/// This function returns the metadata the dashboard needs to build a condition on the key: its
+/// value type, whether it can be compared using greater than or less than, its variants and its
+/// category. Keys not holding enum values have `null` variants.
+#[wasm_bindgen(js_name = getKeyMetadata)]
+pub fn get_key_metadata(key: &str) -> JsResult {
+    let key = dir::DirKeyKind::from_str(key).map_err(|_| "Invalid key received".to_string())?;
+    Ok(serde_wasm_bindgen::to_value(&build_key_metadata(key))?)
+}
+
+fn build_key_metadata(key: dir::DirKeyKind) -> types::KeyMetadata {
+    let key_type = key.get_type();
+    types::KeyMetadata {
+        supports_comparison: matches!(key_type, euclid::types::DataType::Number),
+        key_type: key_type.to_string(),
+        variants: get_key_variants(&key),
+        category: key.get_str("Category"),
+        kind: key,
+    }
+}
 
-    let variants: &[&str] = match key {
-        dir::PayoutDirKeyKind::BusinessCountry => dir_enums::BusinessCountry::VARIANTS,
-        dir::PayoutDirKeyKind::BillingCountry => dir_enums::BillingCountry::VARIANTS,
-        dir::PayoutDirKeyKind::PayoutType => dir_enums::PayoutType::VARIANTS,
-        dir::PayoutDirKeyKind::WalletType => dir_enums::PayoutWalletType::VARIANTS,
-        dir::PayoutDirKeyKind::BankTransferType => dir_enums::PayoutBankTransferType::VARIANTS,
+/// This function returns the comparison operators a condition on the key can use, derived from
+/// the type of the key. Checking membership in a set of values uses the equality operators along
+/// with an array value.
+#[wasm_bindgen(js_name = getAllowedOperators)]
+pub fn get_allowed_operators(key: &str) -> JsResult {
+    let key = dir::DirKeyKind::from_str(key).map_err(|_| "Invalid key received".to_string())?;
+    Ok(serde_wasm_bindgen::to_value(get_key_operators(&key))?)
+}
 
-        dir::PayoutDirKeyKind::PayoutAmount | dir::PayoutDirKeyKind::BusinessLabel => {
-            Err("Key does not have variants".to_string())?
+fn get_key_operators(key: &dir::DirKeyKind) -> &'static [ast::ComparisonType] {
+    match key.get_type() {
+        euclid::types::DataType::Number => &[
+            ast::ComparisonType::Equal,
+            ast::ComparisonType::NotEqual,
+            ast::ComparisonType::GreaterThan,
+            ast::ComparisonType::GreaterThanEqual,
+            ast::ComparisonType::LessThan,
+            ast::ComparisonType::LessThanEqual,
+        ],
+        euclid::types::DataType::EnumVariant
+        | euclid::types::DataType::MetadataValue
+        | euclid::types::DataType::StrValue
+        | euclid::types::DataType::Segment => {
+            &[ast::ComparisonType::Equal, ast::ComparisonType::NotEqual]
         }
+    }
+}
+
+#[wasm_bindgen(js_name = getThreeDsKeys)]
+pub fn get_three_ds_keys() -> JsResult {
+    let keys = <common_types::payments::ConditionalConfigs as EuclidDirFilter>::ALLOWED;
+    Ok(serde_wasm_bindgen::to_value(keys)?)
+}
+
+#[wasm_bindgen(js_name= getSurchargeKeys)]
+pub fn get_surcharge_keys() -> JsResult {
+    let keys = <SurchargeDecisionConfigs as EuclidDirFilter>::ALLOWED;
+    Ok(serde_wasm_bindgen::to_value(keys)?)
+}
+
+#[wasm_bindgen(js_name= getThreeDsDecisionRuleKeys)]
+pub fn get_three_ds_decision_rule_keys() -> JsResult {
+    let keys = <ThreeDSDecisionRule as EuclidDirFilter>::ALLOWED;
+    Ok(serde_wasm_bindgen::to_value(keys)?)
+}
+
+/// This function analyzes a three DS decision rule program for errors, returning the list of
+/// warnings found, such as rules using the device display size or platform without a condition
+/// on the device type.
+#[wasm_bindgen(js_name = analyzeThreeDsDecisionRuleProgram)]
+pub fn analyze_three_ds_decision_rule_program(js_program: JsValue) -> JsResult {
+    let program: ast::Program<ThreeDSDecisionRule> = serde_wasm_bindgen::from_value(js_program)?;
+    let dir_program = ast::lowering::lower_program(program).err_to_js()?;
+    let warnings = analyzer::analyze_key_dependencies(&dir_program).err_to_js()?;
+
+    Ok(serde_wasm_bindgen::to_value(&warnings)?)
+}
+
+/// This function executes a three DS decision rule program on the input, returning the decision
+/// of the matching rule, or of the default selection when no rule matches. The program is
+/// executed by the same backend as on the server, so the issuer, acquirer and customer device
+/// data of the input are evaluated, unlike for routing programs. The PSD2 validations which the
+/// server applies to the decision afterwards are not part of the output.
+#[wasm_bindgen(js_name = runThreeDsDecisionProgram)]
+pub fn run_three_ds_decision_program(program: JsValue, input: JsValue) -> JsResult {
+    let program: ast::Program<ThreeDSDecisionRule> = serde_wasm_bindgen::from_value(program)?;
+    let input: inputs::BackendInput = serde_wasm_bindgen::from_value(input)?;
+
+    let output = get_three_ds_decision_program_output(program, input).err_to_js()?;
+
+    Ok(serde_wasm_bindgen::to_value(&output)?)
+}
+
+fn get_three_ds_decision_program_output(
+    program: ast::Program<ThreeDSDecisionRule>,
+    input: inputs::BackendInput,
+) -> Result<types::ThreeDsDecisionProgramOutput, VirInterpreterError> {
+    let backend = VirInterpreterBackend::with_program(program)?;
+    let output = backend.execute(input)?;
+
+    Ok(types::ThreeDsDecisionProgramOutput {
+        decision: output.connector_selection.get_decision(),
+        rule_name: output.rule_name,
+    })
+}
+
+/// This function executes a surcharge decision program on the input, returning the surcharge
+/// details of the matching rule, or of the default selection when no rule matches, along with the
+/// surcharge and the tax on it for the amount of the input.
+#[wasm_bindgen(js_name = runSurchargeProgram)]
+pub fn run_surcharge_program(program: JsValue, input: JsValue) -> JsResult {
+    let program: ast::Program<SurchargeDecisionConfigs> = serde_wasm_bindgen::from_value(program)?;
+    let input: inputs::BackendInput = serde_wasm_bindgen::from_value(input)?;
+
+    let output = get_surcharge_program_output(program, input).err_to_js()?;
+
+    Ok(serde_wasm_bindgen::to_value(&output)?)
+}
+
+fn get_surcharge_program_output(
+    program: ast::Program<SurchargeDecisionConfigs>,
+    input: inputs::BackendInput,
+) -> Result<types::SurchargeProgramOutput, String> {
+    let amount = input.payment.amount;
+    let backend = InterpreterBackend::with_program(program).map_err(|err| err.to_string())?;
+    let output = backend.execute(input).map_err(|err| err.to_string())?;
+    let surcharge_details = output.connector_selection.surcharge_details;
+
+    let (surcharge_amount, tax_on_surcharge_amount) = match &surcharge_details {
+        Some(surcharge_details) => get_surcharge_amounts(surcharge_details, amount)?,
+        None => (MinorUnit::zero(), MinorUnit::zero()),
     };
 
-    Ok(serde_wasm_bindgen::to_value(variants)?)
+    Ok(types::SurchargeProgramOutput {
+        rule_name: output.rule_name,
+        surcharge_details,
+        surcharge_amount,
+        tax_on_surcharge_amount,
+    })
 }
 
-#[cfg(feature = "payouts")]
-#[wasm_bindgen(js_name = getPayoutDescriptionCategory)]
-pub fn get_payout_description_category() -> JsResult {
-    let keys = dir::PayoutDirKeyKind::VARIANTS.to_vec();
-    let mut category: HashMap<Option<&str>, Vec<types::PayoutDetails<'_>>> = HashMap::new();
-    for key in keys {
-        let dir_key =
-            dir::PayoutDirKeyKind::from_str(key).map_err(|_| "Invalid key received".to_string())?;
-        let details = types::PayoutDetails {
-            description: dir_key.get_detailed_message(),
-            kind: dir_key.clone(),
-        };
-        category
-            .entry(dir_key.get_str("Category"))
-            .and_modify(|val| val.push(details.clone()))
-            .or_insert(vec![details]);
-    }
+/// Computes the surcharge and the tax on it the same way the server does, rounding percentages up
+/// to the next minor unit
+fn get_surcharge_amounts(
+    surcharge_details: &SurchargeDetailsOutput,
+    payment_amount: MinorUnit,
+) -> Result<(MinorUnit, MinorUnit), String> {
+    let surcharge_amount = match &surcharge_details.surcharge {
+        SurchargeOutput::Fixed { amount } => *amount,
+        SurchargeOutput::Rate(percentage) => percentage
+            .apply_and_ceil_result(payment_amount)
+            .map_err(|err| err.to_string())?,
+    };
+    let tax_on_surcharge_amount = surcharge_details
+        .tax_on_surcharge
+        .as_ref()
+        .map(|tax_on_surcharge| tax_on_surcharge.apply_and_ceil_result(surcharge_amount))
+        .transpose()
+        .map_err(|err| err.to_string())?
+        .unwrap_or_default();
 
-    Ok(serde_wasm_bindgen::to_value(&category)?)
+    Ok((surcharge_amount, tax_on_surcharge_amount))
 }
 
-#[wasm_bindgen(js_name = getValidWebhookStatus)]
-pub fn get_valid_webhook_status(key: &str) -> JsResult {
-    let event_class = EventClass::from_str(key)
-        .map_err(|_| "Invalid webhook event type received".to_string())
-        .err_to_js()?;
+/// This function analyzes a surcharge decision program for errors without the knowledge graph,
+/// rejecting programs which use keys other than the surcharge keys or values the keys do not
+/// take, and returning the list of warnings found otherwise.
+#[wasm_bindgen(js_name = analyzeSurchargeProgram)]
+pub fn analyze_surcharge_program(js_program: JsValue) -> JsResult {
+    let program: ast::Program<SurchargeDecisionConfigs> =
+        serde_wasm_bindgen::from_value(js_program)?;
+    let warnings = get_surcharge_program_warnings(program).err_to_js()?;
 
-    match event_class {
-        EventClass::Payments => {
-            let statuses: Vec<IntentStatus> = IntentStatus::iter()
-                .filter(|intent_status| Into::<Option<EventType>>::into(*intent_status).is_some())
-                .collect();
-            Ok(serde_wasm_bindgen::to_value(&statuses)?)
-        }
-        EventClass::Refunds => {
-            let statuses: Vec<RefundStatus> = RefundStatus::iter()
-                .filter(|status| Into::<Option<EventType>>::into(*status).is_some())
-                .collect();
-            Ok(serde_wasm_bindgen::to_value(&statuses)?)
-        }
-        EventClass::Disputes => {
-            let statuses: Vec<DisputeStatus> = DisputeStatus::iter().collect();
-            Ok(serde_wasm_bindgen::to_value(&statuses)?)
-        }
-        EventClass::Mandates => {
-            let statuses: Vec<MandateStatus> = MandateStatus::iter()
-                .filter(|status| Into::<Option<EventType>>::into(*status).is_some())
-                .collect();
-            Ok(serde_wasm_bindgen::to_value(&statuses)?)
+    Ok(serde_wasm_bindgen::to_value(&warnings)?)
+}
+
+fn get_surcharge_program_warnings(
+    program: ast::Program<SurchargeDecisionConfigs>,
+) -> Result<Vec<dssa::types::AnalysisWarning>, dssa::types::AnalysisError> {
+    let dir_program = ast::lowering::lower_program(program)?;
+    analyzer::analyze_key_dependencies(&dir_program)
+}
+
+#[wasm_bindgen(js_name=parseToString)]
+pub fn parser(val: String) -> String {
+    ron_parser::my_parse(val)
+}
+
+/// This function parses the statements of a rule written in the routing DSL, like
+/// `{ amount >= 1000 & currency = (USD, EUR) }`, into the statements of the AST.
+#[wasm_bindgen(js_name = parseRuleConditions)]
+pub fn parse_rule_conditions(text: &str) -> JsResult {
+    let statements = get_rule_conditions(text).err_to_js()?;
+
+    Ok(serde_wasm_bindgen::to_value(&statements)?)
+}
+
+fn get_rule_conditions(text: &str) -> Result<Vec<ast::IfStatement>, String> {
+    let (rest, statements) = ast::parser::rule_conditions_array(text)
+        .map_err(|err| format!("Invalid rule conditions: {err}"))?;
+
+    if !rest.trim().is_empty() {
+        return Err(format!(
+            "Unexpected input after the rule conditions: '{rest}'"
+        ));
+    }
+
+    Ok(statements)
+}
+
+/// This function prints the statements of a rule in the routing DSL, which
+/// `parseRuleConditions` parses back into the same statements.
+#[wasm_bindgen(js_name = printRuleConditions)]
+pub fn print_rule_conditions(statements: JsValue) -> Result<String, JsValue> {
+    let statements: Vec<ast::IfStatement> = serde_wasm_bindgen::from_value(statements)?;
+
+    Ok(ast::printer::print_rule_conditions(&statements))
+}
+
+#[wasm_bindgen(js_name = getVariantValues)]
+pub fn get_variant_values(key: &str) -> Result<JsValue, JsValue> {
+    let key = dir::DirKeyKind::from_str(key).map_err(|_| "Invalid key received".to_string())?;
+
+    let variants =
+        get_key_variants(&key).ok_or_else(|| "Key does not have variants".to_string())?;
+
+    Ok(serde_wasm_bindgen::to_value(variants)?)
+}
+
+/// Returns the variants of an enum key, `None` for the keys not holding enum values
+fn get_key_variants(key: &dir::DirKeyKind) -> Option<&'static [&'static str]> {
+    match key {
+        dir::DirKeyKind::PaymentMethod => Some(dir_enums::PaymentMethod::VARIANTS),
+        dir::DirKeyKind::CardType => Some(dir_enums::CardType::VARIANTS),
+        dir::DirKeyKind::CardNetwork => Some(dir_enums::CardNetwork::VARIANTS),
+        dir::DirKeyKind::PayLaterType => Some(dir_enums::PayLaterType::VARIANTS),
+        dir::DirKeyKind::WalletType => Some(dir_enums::WalletType::VARIANTS),
+        dir::DirKeyKind::BankRedirectType => Some(dir_enums::BankRedirectType::VARIANTS),
+        dir::DirKeyKind::CryptoType => Some(dir_enums::CryptoType::VARIANTS),
+        dir::DirKeyKind::RewardType => Some(dir_enums::RewardType::VARIANTS),
+        dir::DirKeyKind::AuthenticationType => Some(dir_enums::AuthenticationType::VARIANTS),
+        dir::DirKeyKind::CaptureMethod => Some(dir_enums::CaptureMethod::VARIANTS),
+        dir::DirKeyKind::PaymentCurrency => Some(dir_enums::PaymentCurrency::VARIANTS),
+        dir::DirKeyKind::BusinessCountry => Some(dir_enums::Country::VARIANTS),
+        dir::DirKeyKind::BillingCountry => Some(dir_enums::Country::VARIANTS),
+        dir::DirKeyKind::BankTransferType => Some(dir_enums::BankTransferType::VARIANTS),
+        dir::DirKeyKind::UpiType => Some(dir_enums::UpiType::VARIANTS),
+        dir::DirKeyKind::SetupFutureUsage => Some(dir_enums::SetupFutureUsage::VARIANTS),
+        dir::DirKeyKind::PaymentType => Some(dir_enums::PaymentType::VARIANTS),
+        dir::DirKeyKind::MandateType => Some(dir_enums::MandateType::VARIANTS),
+        dir::DirKeyKind::MandateAcceptanceType => Some(dir_enums::MandateAcceptanceType::VARIANTS),
+        dir::DirKeyKind::CardRedirectType => Some(dir_enums::CardRedirectType::VARIANTS),
+        dir::DirKeyKind::GiftCardType => Some(dir_enums::GiftCardType::VARIANTS),
+        dir::DirKeyKind::VoucherType => Some(dir_enums::VoucherType::VARIANTS),
+        dir::DirKeyKind::BankDebitType => Some(dir_enums::BankDebitType::VARIANTS),
+        dir::DirKeyKind::RealTimePaymentType => Some(dir_enums::RealTimePaymentType::VARIANTS),
+        dir::DirKeyKind::OpenBankingType => Some(dir_enums::OpenBankingType::VARIANTS),
+        dir::DirKeyKind::MobilePaymentType => Some(dir_enums::MobilePaymentType::VARIANTS),
+        dir::DirKeyKind::IssuerCountry => Some(dir_enums::Country::VARIANTS),
+        dir::DirKeyKind::AcquirerCountry => Some(dir_enums::Country::VARIANTS),
+        dir::DirKeyKind::CustomerDeviceType => Some(dir_enums::CustomerDeviceType::VARIANTS),
+        dir::DirKeyKind::CustomerDevicePlatform => {
+            Some(dir_enums::CustomerDevicePlatform::VARIANTS)
+        }
+        dir::DirKeyKind::CustomerDeviceDisplaySize => {
+            Some(dir_enums::CustomerDeviceDisplaySize::VARIANTS)
+        }
+
+        dir::DirKeyKind::PaymentAmount
+        | dir::DirKeyKind::Connector
+        | dir::DirKeyKind::CardBin
+        | dir::DirKeyKind::BusinessLabel
+        | dir::DirKeyKind::MetaData
+        | dir::DirKeyKind::IssuerName
+        | dir::DirKeyKind::AcquirerFraudRate => None,
+    }
+}
+
+#[wasm_bindgen(js_name = addTwo)]
+pub fn add_two(n1: i64, n2: i64) -> i64 {
+    n1 + n2
+}
+
+#[wasm_bindgen(js_name = getDescriptionCategory)]
+pub fn get_description_category() -> JsResult {
+    let keys = dir::DirKeyKind::VARIANTS
+        .iter()
+        .copied()
+        .filter(|s| s != &"Connector")
+        .collect::<Vec<&'static str>>();
+    let mut category: HashMap<Option<&str>, Vec<types::Details<'_>>> = HashMap::new();
+    for key in keys {
+        let dir_key =
+            dir::DirKeyKind::from_str(key).map_err(|_| "Invalid key received".to_string())?;
+        let details = types::Details {
+            description: dir_key.get_detailed_message(),
+            kind: dir_key.clone(),
+        };
+        category
+            .entry(dir_key.get_str("Category"))
+            .and_modify(|val| val.push(details.clone()))
+            .or_insert(vec![details]);
+    }
+
+    Ok(serde_wasm_bindgen::to_value(&category)?)
+}
+
+#[wasm_bindgen(js_name = getConnectorConfig)]
+pub fn get_connector_config(key: &str) -> JsResult {
+    let key = api_model_enums::Connector::from_str(key)
+        .map_err(|_| "Invalid key received".to_string())?;
+    let res = connector::ConnectorConfig::get_connector_config(key)?;
+    Ok(serde_wasm_bindgen::to_value(&res)?)
+}
+
+/// This function returns the config of the connector like `getConnectorConfig`, keeping only the
+/// sections relevant to the payment method and the payment method types accepted in the country
+/// (an alpha-2 country code), when they are given.
+#[wasm_bindgen(js_name = getConnectorConfigFiltered)]
+pub fn get_connector_config_filtered(
+    key: &str,
+    payment_method: Option<String>,
+    country: Option<String>,
+) -> JsResult {
+    let res = get_filtered_connector_config(key, payment_method.as_deref(), country.as_deref())?;
+    Ok(serde_wasm_bindgen::to_value(&res)?)
+}
+
+fn get_filtered_connector_config(
+    key: &str,
+    payment_method: Option<&str>,
+    country: Option<&str>,
+) -> Result<Option<connector::ConnectorTomlConfig>, String> {
+    let key = api_model_enums::Connector::from_str(key)
+        .map_err(|_| "Invalid key received".to_string())?;
+    let payment_method = payment_method
+        .map(|payment_method| {
+            api_model_enums::PaymentMethod::from_str(payment_method).map_err(|_| {
+                format!(
+                    "Invalid payment method received: '{payment_method}', expected one of {}",
+                    api_model_enums::PaymentMethod::VARIANTS.join(", ")
+                )
+            })
+        })
+        .transpose()?;
+    let country = country
+        .map(|country| {
+            CountryAlpha2::from_str(country)
+                .map_err(|_| format!("Invalid country received: '{country}'"))
+        })
+        .transpose()?;
+
+    Ok(connector::ConnectorConfig::get_connector_config(key)?
+        .map(|config| config.filter_payment_methods(payment_method, country)))
+}
+
+#[cfg(feature = "payouts")]
+#[wasm_bindgen(js_name = getPayoutConnectorConfig)]
+pub fn get_payout_connector_config(key: &str) -> JsResult {
+    let key = api_model_enums::PayoutConnectors::from_str(key)
+        .map_err(|_| "Invalid key received".to_string())?;
+    let res = connector::ConnectorConfig::get_payout_connector_config(key)?;
+    Ok(serde_wasm_bindgen::to_value(&res)?)
+}
+
+#[wasm_bindgen(js_name = getAuthenticationConnectorConfig)]
+pub fn get_authentication_connector_config(key: &str) -> JsResult {
+    let key = api_model_enums::AuthenticationConnectors::from_str(key)
+        .map_err(|_| "Invalid key received".to_string())?;
+    let res = connector::ConnectorConfig::get_authentication_connector_config(key)?;
+    Ok(serde_wasm_bindgen::to_value(&res)?)
+}
+
+#[wasm_bindgen(js_name = getTaxProcessorConfig)]
+pub fn get_tax_processor_config(key: &str) -> JsResult {
+    let key = api_model_enums::TaxConnectors::from_str(key)
+        .map_err(|_| "Invalid key received".to_string())?;
+    let res = connector::ConnectorConfig::get_tax_processor_config(key)?;
+    Ok(serde_wasm_bindgen::to_value(&res)?)
+}
+
+#[wasm_bindgen(js_name = getPMAuthenticationProcessorConfig)]
+pub fn get_pm_authentication_processor_config(key: &str) -> JsResult {
+    let key: api_model_enums::PmAuthConnectors = api_model_enums::PmAuthConnectors::from_str(key)
+        .map_err(|_| "Invalid key received".to_string())?;
+    let res = connector::ConnectorConfig::get_pm_authentication_processor_config(key)?;
+    Ok(serde_wasm_bindgen::to_value(&res)?)
+}
+
+#[wasm_bindgen(js_name = getRequestPayload)]
+pub fn get_request_payload(input: JsValue, response: JsValue) -> JsResult {
+    let input: DashboardRequestPayload = serde_wasm_bindgen::from_value(input)?;
+    let api_response: ConnectorApiIntegrationPayload = serde_wasm_bindgen::from_value(response)?;
+    let result = DashboardRequestPayload::create_connector_request(input, api_response);
+    Ok(serde_wasm_bindgen::to_value(&result)?)
+}
+
+/// Fields of the connector account holding credentials, whose values are left out of the diff of
+/// the request payload
+const SECRET_PAYLOAD_FIELDS: [&str; 7] = [
+    "certificate",
+    "certificate_keys",
+    "payment_processing_certificate",
+    "payment_processing_certificate_key",
+    "pay_wall_secret",
+    "merchant_secret",
+    "additional_secret",
+];
+
+/// This function returns the changes the payload built by `getRequestPayload` makes to the
+/// existing connector account, as the fields added, removed and changed by the merge. Changes to
+/// credentials, like the Apple Pay certificates, are reported without their values.
+#[wasm_bindgen(js_name = getRequestPayloadDiff)]
+pub fn get_request_payload_diff(input: JsValue, response: JsValue) -> JsResult {
+    let input: DashboardRequestPayload = serde_wasm_bindgen::from_value(input)?;
+    let api_response: ConnectorApiIntegrationPayload = serde_wasm_bindgen::from_value(response)?;
+    let diff = get_connector_request_diff(input, api_response).map_err(|err| err.to_string())?;
+    Ok(serde_wasm_bindgen::to_value(&diff)?)
+}
+
+fn get_connector_request_diff(
+    input: DashboardRequestPayload,
+    api_response: ConnectorApiIntegrationPayload,
+) -> Result<utils::JsonDiff, serde_json::Error> {
+    let existing_payload = serde_json::to_value(&api_response)?;
+    let request_payload = DashboardRequestPayload::create_connector_request(input, api_response);
+
+    Ok(utils::diff_json(
+        &existing_payload,
+        &serde_json::to_value(request_payload)?,
+        &SECRET_PAYLOAD_FIELDS,
+    ))
+}
+
+#[wasm_bindgen(js_name = getResponsePayload)]
+pub fn get_response_payload(input: JsValue) -> JsResult {
+    let input: ConnectorApiIntegrationPayload = serde_wasm_bindgen::from_value(input)?;
+    let result = ConnectorApiIntegrationPayload::get_transformed_response_payload(input);
+    Ok(serde_wasm_bindgen::to_value(&result)?)
+}
+
+#[cfg(feature = "payouts")]
+#[wasm_bindgen(js_name = getAllPayoutKeys)]
+pub fn get_all_payout_keys() -> JsResult {
+    let keys: Vec<&'static str> = dir::PayoutDirKeyKind::VARIANTS.to_vec();
+    Ok(serde_wasm_bindgen::to_value(&keys)?)
+}
+
+#[cfg(feature = "payouts")]
+#[wasm_bindgen(js_name = getPayoutVariantValues)]
+pub fn get_payout_variant_values(key: &str) -> Result<JsValue, JsValue> {
+    let key =
+        dir::PayoutDirKeyKind::from_str(key).map_err(|_| "Invalid key received".to_string())?;
+
+    let variants =
+        get_payout_key_variants(&key).ok_or_else(|| "Key does not have variants".to_string())?;
+
+    Ok(serde_wasm_bindgen::to_value(variants)?)
+}
+
+/// Returns the variants of an enum payout key, `None` for the keys not holding enum values
+#[cfg(feature = "payouts")]
+fn get_payout_key_variants(key: &dir::PayoutDirKeyKind) -> Option<&'static [&'static str]> {
+    match key {
+        dir::PayoutDirKeyKind::BusinessCountry => Some(dir_enums::BusinessCountry::VARIANTS),
+        dir::PayoutDirKeyKind::BillingCountry => Some(dir_enums::BillingCountry::VARIANTS),
+        dir::PayoutDirKeyKind::PayoutType => Some(dir_enums::PayoutType::VARIANTS),
+        dir::PayoutDirKeyKind::WalletType => Some(dir_enums::PayoutWalletType::VARIANTS),
+        dir::PayoutDirKeyKind::BankTransferType => {
+            Some(dir_enums::PayoutBankTransferType::VARIANTS)
+        }
+
+        dir::PayoutDirKeyKind::PayoutAmount | dir::PayoutDirKeyKind::BusinessLabel => None,
+    }
+}
+
+/// This function executes the payout routing program on the payout input, returning the name of
+/// the matching rule and the payout connectors it selects. When the payout knowledge graph has
+/// been seeded, the selected connectors are validated against the merchant's payout connectors.
+#[cfg(feature = "payouts")]
+#[wasm_bindgen(js_name = runPayoutProgram)]
+pub fn run_payout_program(program: JsValue, input: JsValue) -> JsResult {
+    let program: ast::Program<ConnectorSelection> = serde_wasm_bindgen::from_value(program)?;
+    let input: inputs::PayoutBackendInput = serde_wasm_bindgen::from_value(input)?;
+
+    let output = get_payout_program_output(
+        program,
+        input,
+        get_payout_seed_data().as_deref().map(Vec::as_slice),
+    )
+    .err_to_js()?;
+
+    Ok(serde_wasm_bindgen::to_value(&output)?)
+}
+
+#[cfg(feature = "payouts")]
+fn get_payout_program_output(
+    program: ast::Program<ConnectorSelection>,
+    input: inputs::PayoutBackendInput,
+    payout_connectors: Option<&[api_model_enums::PayoutConnectors]>,
+) -> Result<types::PayoutProgramOutput, String> {
+    let backend = InterpreterBackend::with_program(program).map_err(|err| err.to_string())?;
+    let output = backend
+        .execute_payout(input)
+        .map_err(|err| err.to_string())?;
+    let connectors = get_payout_connectors_of_selection(&output.connector_selection)?;
+    if let Some(connector) = payout_connectors.and_then(|payout_connectors| {
+        connectors
+            .iter()
+            .find(|connector| !payout_connectors.contains(connector))
+    }) {
+        return Err(format!(
+            "Payout connector {connector} is not configured for the merchant"
+        ));
+    }
+
+    Ok(types::PayoutProgramOutput {
+        rule_name: output.rule_name,
+        connectors,
+    })
+}
+
+/// This function analyzes the payout routing program for errors, returning the list of warnings
+/// found. The program is invalid when it uses keys other than the payout keys, values the keys do
+/// not take, or connectors which do not support payouts. When the payout knowledge graph has not
+/// been seeded, the selected connectors are not validated against the merchant's payout
+/// connectors and a warning saying so is returned instead.
+#[cfg(feature = "payouts")]
+#[wasm_bindgen(js_name = analyzePayoutProgram)]
+pub fn analyze_payout_program(js_program: JsValue) -> JsResult {
+    let program: ast::Program<ConnectorSelection> = serde_wasm_bindgen::from_value(js_program)?;
+    let warnings = get_payout_program_warnings(
+        &program,
+        get_payout_seed_data().as_deref().map(Vec::as_slice),
+    )
+    .err_to_js()?;
+
+    Ok(serde_wasm_bindgen::to_value(&warnings)?)
+}
+
+#[cfg(feature = "payouts")]
+fn get_payout_program_warnings(
+    program: &ast::Program<ConnectorSelection>,
+    payout_connectors: Option<&[api_model_enums::PayoutConnectors]>,
+) -> Result<Vec<types::PayoutProgramWarning>, String> {
+    for rule in &program.rules {
+        validate_payout_statements(&rule.statements, &program.segments)
+            .map_err(|reason| format!("Invalid rule {}: {reason}", rule.name))?;
+    }
+
+    let selections = std::iter::once((None, &program.default_selection)).chain(
+        program
+            .rules
+            .iter()
+            .map(|rule| (Some(&rule.name), &rule.connector_selection)),
+    );
+
+    let mut warnings = Vec::new();
+    for (rule_name, connector_selection) in selections {
+        let connectors = get_payout_connectors_of_selection(connector_selection)?;
+        let Some(payout_connectors) = payout_connectors else {
+            continue;
+        };
+        warnings.extend(
+            connectors
+                .into_iter()
+                .filter(|connector| !payout_connectors.contains(connector))
+                .map(
+                    |connector| types::PayoutProgramWarning::ConnectorNotConfigured {
+                        rule_name: rule_name.cloned(),
+                        connector,
+                    },
+                ),
+        );
+    }
+    if payout_connectors.is_none() {
+        warnings.push(types::PayoutProgramWarning::KnowledgeGraphNotSeeded);
+    }
+
+    Ok(warnings)
+}
+
+#[cfg(feature = "payouts")]
+fn validate_payout_statements(
+    statements: &[ast::IfStatement],
+    segments: &ast::Segments,
+) -> Result<(), String> {
+    statements.iter().try_for_each(|statement| {
+        statement
+            .condition
+            .iter()
+            .try_for_each(|comparison| validate_payout_comparison(comparison, segments))?;
+        statement.nested.as_deref().map_or(Ok(()), |nested| {
+            validate_payout_statements(nested, segments)
+        })
+    })
+}
+
+/// Validates that the comparison is on a payout key, with a value of the key's type. For a
+/// comparison referencing a segment, each of the values of the segment is validated.
+#[cfg(feature = "payouts")]
+fn validate_payout_comparison(
+    comparison: &ast::Comparison,
+    segments: &ast::Segments,
+) -> Result<(), String> {
+    let key = dir::PayoutDirKeyKind::from_str(&comparison.lhs)
+        .map_err(|_| format!("Invalid payout key {}", comparison.lhs))?;
+
+    if let ast::ValueType::SegmentReference(name) = &comparison.value {
+        let segment =
+            ast::resolve_segment(segments, name, &comparison.lhs).map_err(|err| err.to_string())?;
+        return segment.values.iter().try_for_each(|value| {
+            validate_payout_comparison(
+                &ast::Comparison {
+                    value: value.clone(),
+                    ..comparison.clone()
+                },
+                segments,
+            )
+        });
+    }
+
+    let is_valid_value = match (get_payout_key_variants(&key), &comparison.value) {
+        (Some(variants), ast::ValueType::EnumVariant(value)) => variants.contains(&value.as_str()),
+        (Some(variants), ast::ValueType::EnumVariantArray(values)) => values
+            .iter()
+            .all(|value| variants.contains(&value.as_str())),
+        (
+            None,
+            ast::ValueType::Number(_)
+            | ast::ValueType::NumberArray(_)
+            | ast::ValueType::NumberComparisonArray(_),
+        ) => key == dir::PayoutDirKeyKind::PayoutAmount,
+        (None, ast::ValueType::StrValue(_)) => key == dir::PayoutDirKeyKind::BusinessLabel,
+        _ => false,
+    };
+
+    if is_valid_value {
+        Ok(())
+    } else {
+        Err(format!("Invalid value for payout key {key}"))
+    }
+}
+
+/// Returns the connectors of the selection as payout connectors, failing on connectors which do
+/// not support payouts
+#[cfg(feature = "payouts")]
+fn get_payout_connectors_of_selection(
+    connector_selection: &ConnectorSelection,
+) -> Result<Vec<api_model_enums::PayoutConnectors>, String> {
+    connector_selection
+        .get_connector_list()
+        .into_iter()
+        .map(|choice| {
+            api_model_enums::PayoutConnectors::from_str(&choice.connector.to_string())
+                .map_err(|_| format!("Connector {} does not support payouts", choice.connector))
+        })
+        .collect()
+}
+
+#[cfg(feature = "payouts")]
+#[wasm_bindgen(js_name = getPayoutDescriptionCategory)]
+pub fn get_payout_description_category() -> JsResult {
+    let keys = dir::PayoutDirKeyKind::VARIANTS.to_vec();
+    let mut category: HashMap<Option<&str>, Vec<types::PayoutDetails<'_>>> = HashMap::new();
+    for key in keys {
+        let dir_key =
+            dir::PayoutDirKeyKind::from_str(key).map_err(|_| "Invalid key received".to_string())?;
+        let details = types::PayoutDetails {
+            description: dir_key.get_detailed_message(),
+            kind: dir_key.clone(),
+        };
+        category
+            .entry(dir_key.get_str("Category"))
+            .and_modify(|val| val.push(details.clone()))
+            .or_insert(vec![details]);
+    }
+
+    Ok(serde_wasm_bindgen::to_value(&category)?)
+}
+
+#[wasm_bindgen(js_name = getValidWebhookStatus)]
+pub fn get_valid_webhook_status(key: &str) -> JsResult {
+    let event_class = EventClass::from_str(key)
+        .map_err(|_| "Invalid webhook event type received".to_string())
+        .err_to_js()?;
+
+    match get_webhook_status_events(event_class) {
+        types::WebhookStatusEvents::Payments(events) => get_webhook_statuses(&events),
+        types::WebhookStatusEvents::Refunds(events) => get_webhook_statuses(&events),
+        types::WebhookStatusEvents::Disputes(events) => get_webhook_statuses(&events),
+        types::WebhookStatusEvents::Mandates(events) => get_webhook_statuses(&events),
+        #[cfg(feature = "payouts")]
+        types::WebhookStatusEvents::Payouts(events) => get_webhook_statuses(&events),
+    }
+}
+
+/// This function returns the statuses of the event class which trigger a webhook like
+/// `getValidWebhookStatus`, each along with the type of the event sent for it, as
+/// `{ status, event_type }` objects.
+#[wasm_bindgen(js_name = getValidWebhookStatusWithEvents)]
+pub fn get_valid_webhook_status_with_events(key: &str) -> JsResult {
+    let event_class = EventClass::from_str(key)
+        .map_err(|_| "Invalid webhook event type received".to_string())
+        .err_to_js()?;
+
+    let events = get_webhook_status_events(event_class);
+    Ok(serde_wasm_bindgen::to_value(&events)?)
+}
+
+fn get_webhook_statuses<S: serde::Serialize>(events: &[types::WebhookStatusEvent<S>]) -> JsResult {
+    let statuses = events.iter().map(|event| &event.status).collect::<Vec<_>>();
+    Ok(serde_wasm_bindgen::to_value(&statuses)?)
+}
+
+fn get_webhook_status_events(event_class: EventClass) -> types::WebhookStatusEvents {
+    match event_class {
+        EventClass::Payments => types::WebhookStatusEvents::Payments(get_status_events(
+            IntentStatus::iter(),
+            Into::into,
+        )),
+        EventClass::Refunds => {
+            types::WebhookStatusEvents::Refunds(get_status_events(RefundStatus::iter(), Into::into))
         }
+        EventClass::Disputes => types::WebhookStatusEvents::Disputes(get_status_events(
+            DisputeStatus::iter(),
+            |status| Some(status.into()),
+        )),
+        EventClass::Mandates => types::WebhookStatusEvents::Mandates(get_status_events(
+            MandateStatus::iter(),
+            Into::into,
+        )),
         #[cfg(feature = "payouts")]
         EventClass::Payouts => {
-            let statuses: Vec<PayoutStatus> = PayoutStatus::iter()
-                .filter(|status| Into::<Option<EventType>>::into(*status).is_some())
-                .collect();
-            Ok(serde_wasm_bindgen::to_value(&statuses)?)
+            types::WebhookStatusEvents::Payouts(get_status_events(PayoutStatus::iter(), Into::into))
+        }
+    }
+}
+
+/// Keeps the statuses which are mapped to an event type, along with their event type
+fn get_status_events<S: Copy>(
+    statuses: impl Iterator<Item = S>,
+    get_event_type: impl Fn(S) -> Option<EventType>,
+) -> Vec<types::WebhookStatusEvent<S>> {
+    statuses
+        .filter_map(|status| {
+            get_event_type(status)
+                .map(|event_type| types::WebhookStatusEvent { status, event_type })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use common_types::three_ds_decision_rule_engine::ThreeDSDecision;
+
+    use super::*;
+
+    fn find_scoped_key(
+        scoped_keys: &[types::ScopedKey],
+        key: dir::DirKeyKind,
+    ) -> Option<&types::ScopedKey> {
+        scoped_keys.iter().find(|scoped_key| scoped_key.key == key)
+    }
+
+    #[test]
+    fn test_issuer_name_is_only_scoped_to_three_ds() {
+        let all_keys = get_scoped_keys(None);
+        assert_eq!(
+            find_scoped_key(&all_keys, dir::DirKeyKind::IssuerName).map(|key| key.scopes.clone()),
+            Some(vec![types::KeyScope::ThreeDs])
+        );
+
+        let three_ds_keys = get_scoped_keys(Some(types::KeyScope::ThreeDs));
+        assert!(find_scoped_key(&three_ds_keys, dir::DirKeyKind::IssuerName).is_some());
+
+        let payments_keys = get_scoped_keys(Some(types::KeyScope::Payments));
+        assert!(find_scoped_key(&payments_keys, dir::DirKeyKind::IssuerName).is_none());
+    }
+
+    #[test]
+    fn test_payment_method_is_only_scoped_to_payments() {
+        let all_keys = get_scoped_keys(None);
+        assert_eq!(
+            find_scoped_key(&all_keys, dir::DirKeyKind::PaymentMethod)
+                .map(|key| key.scopes.clone()),
+            Some(vec![types::KeyScope::Payments])
+        );
+
+        let payments_keys = get_scoped_keys(Some(types::KeyScope::Payments));
+        assert!(find_scoped_key(&payments_keys, dir::DirKeyKind::PaymentMethod).is_some());
+
+        let three_ds_keys = get_scoped_keys(Some(types::KeyScope::ThreeDs));
+        assert!(find_scoped_key(&three_ds_keys, dir::DirKeyKind::PaymentMethod).is_none());
+    }
+
+    #[test]
+    fn test_key_metadata_of_amount_supports_comparison_without_variants() {
+        let metadata = build_key_metadata(dir::DirKeyKind::PaymentAmount);
+        assert_eq!(metadata.key_type, "number");
+        assert!(metadata.supports_comparison);
+        assert_eq!(metadata.variants, None);
+    }
+
+    #[test]
+    fn test_key_metadata_of_enum_key_lists_variants() {
+        let metadata = build_key_metadata(dir::DirKeyKind::PaymentMethod);
+        assert_eq!(metadata.key_type, "enum_variant");
+        assert!(!metadata.supports_comparison);
+        assert_eq!(metadata.variants, Some(dir_enums::PaymentMethod::VARIANTS));
+        assert_eq!(metadata.category, Some("Payment Methods"));
+    }
+
+    #[test]
+    fn test_key_metadata_of_metadata_key_has_no_variants() {
+        let metadata = build_key_metadata(dir::DirKeyKind::MetaData);
+        assert_eq!(metadata.key_type, "metadata_value");
+        assert!(!metadata.supports_comparison);
+        assert_eq!(metadata.variants, None);
+    }
+
+    #[test]
+    fn test_payment_routing_keys_exclude_three_ds_decision_rule_keys() {
+        let keys = get_payment_routing_keys();
+        let excluded_keys = [
+            "Connector",
+            "issuer_name",
+            "issuer_country",
+            "customer_device_platform",
+            "customer_device_type",
+            "customer_device_display_size",
+            "acquirer_country",
+            "acquirer_fraud_rate",
+        ];
+        for key in excluded_keys {
+            assert!(!keys.iter().any(|routing_key| routing_key == key));
+        }
+        assert_eq!(
+            keys.len(),
+            dir::DirKeyKind::VARIANTS.len() - excluded_keys.len()
+        );
+    }
+
+    #[test]
+    fn test_rule_category_keys_follow_the_allowed_keys_of_each_rule() {
+        assert_eq!(
+            types::RuleCategory::ThreeDsDecision.get_allowed_keys(),
+            <ThreeDSDecisionRule as EuclidDirFilter>::ALLOWED
+                .iter()
+                .map(|key| key.to_string())
+                .collect::<Vec<_>>()
+        );
+        assert!(types::RuleCategory::PaymentRouting
+            .get_allowed_keys()
+            .contains(&dir::DirKeyKind::CardBin.to_string()));
+        assert!(!types::RuleCategory::PaymentRouting
+            .get_allowed_keys()
+            .contains(&dir::DirKeyKind::IssuerName.to_string()));
+    }
+
+    #[test]
+    fn test_all_scope_returns_every_key() {
+        assert_eq!(get_scoped_keys(None).len(), dir::DirKeyKind::VARIANTS.len());
+    }
+
+    const PROGRAM: &str = r#"{
+        "defaultSelection": {
+            "type": "volume_split",
+            "data": [
+                { "split": 60, "connector": "stripe" },
+                { "split": 40, "connector": "adyen" }
+            ]
+        },
+        "metadata": {},
+        "rules": [{
+            "name": "card_rule",
+            "connectorSelection": { "type": "priority", "data": ["checkout", "stripe"] },
+            "statements": [{
+                "condition": [{
+                    "lhs": "payment_method",
+                    "comparison": "equal",
+                    "value": { "type": "enum_variant", "value": "card" },
+                    "metadata": {}
+                }],
+                "nested": null
+            }]
+        }]
+    }"#;
+
+    fn input(payment_method: &str) -> inputs::BackendInput {
+        serde_json::from_value(serde_json::json!({
+            "metadata": null,
+            "payment": { "amount": 1000, "currency": "USD" },
+            "payment_method": { "payment_method": payment_method },
+            "mandate": {}
+        }))
+        .expect("valid input")
+    }
+
+    fn program() -> ast::Program<ConnectorSelection> {
+        serde_json::from_str(PROGRAM).expect("valid program")
+    }
+
+    fn to_json(output: &euclid::backend::BackendOutput<ConnectorSelection>) -> serde_json::Value {
+        serde_json::to_value(output).expect("serialized output")
+    }
+
+    #[test]
+    fn test_fallback_matches_run_without_matching_rules() {
+        let fallback = execute_program(program(), None).expect("fallback output");
+        let unmatched = execute_program(program(), Some(input("wallet"))).expect("program output");
+
+        assert_eq!(to_json(&fallback), to_json(&unmatched));
+        assert!(fallback.rule_name.is_none());
+
+        let matched = execute_program(program(), Some(input("card"))).expect("program output");
+        assert_eq!(matched.rule_name.as_deref(), Some("card_rule"));
+        assert_ne!(to_json(&fallback), to_json(&matched));
+    }
+
+    #[test]
+    fn test_fallback_is_validated_against_seeded_connectors() {
+        let fallback = execute_program(program(), None).expect("fallback output");
+        let seeded = |connectors: &[RoutableConnectors]| {
+            connectors
+                .iter()
+                .map(|connector| ast::ConnectorChoice {
+                    connector: *connector,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert!(validate_seeded_connectors(
+            &fallback.connector_selection,
+            &seeded(&[RoutableConnectors::Stripe, RoutableConnectors::Adyen])
+        )
+        .is_ok());
+        assert!(validate_seeded_connectors(
+            &fallback.connector_selection,
+            &seeded(&[RoutableConnectors::Stripe])
+        )
+        .is_err());
+    }
+
+    fn matched_rules(simulations: &[types::ProgramSimulation]) -> Vec<Option<(usize, &str)>> {
+        simulations
+            .iter()
+            .map(|simulation| simulation.rule_index.zip(simulation.rule_name.as_deref()))
+            .collect()
+    }
+
+    #[test]
+    fn test_simulation_reports_the_first_matching_rule() {
+        let mut program = program();
+        program.rules.push(
+            serde_json::from_value(serde_json::json!({
+                "name": "card_or_wallet_rule",
+                "connectorSelection": { "type": "priority", "data": ["adyen"] },
+                "statements": [{
+                    "condition": [{
+                        "lhs": "payment_method",
+                        "comparison": "equal",
+                        "value": { "type": "enum_variant_array", "value": ["card", "wallet"] },
+                        "metadata": {}
+                    }],
+                    "nested": null
+                }]
+            }))
+            .expect("valid rule"),
+        );
+        let input_without_payment_method = serde_json::from_value(serde_json::json!({
+            "payment": { "amount": 1000, "currency": "USD" },
+            "payment_method": {},
+            "mandate": {}
+        }))
+        .expect("valid input");
+
+        let simulations = get_program_simulations(
+            program,
+            vec![
+                input("card"),
+                input("wallet"),
+                input("pay_later"),
+                input_without_payment_method,
+            ],
+        )
+        .expect("simulations");
+
+        assert_eq!(
+            matched_rules(&simulations),
+            vec![
+                Some((0, "card_rule")),
+                Some((1, "card_or_wallet_rule")),
+                None,
+                None
+            ]
+        );
+        assert_eq!(
+            simulations
+                .iter()
+                .map(|simulation| simulation.is_default_selection)
+                .collect::<Vec<_>>(),
+            vec![false, false, true, true]
+        );
+    }
+
+    #[test]
+    fn test_simulation_of_program_without_rules_uses_the_default_selection() {
+        let mut program = program();
+        program.rules.clear();
+        let fallback = execute_program(program.clone(), None).expect("fallback output");
+
+        let simulations = get_program_simulations(program, vec![input("card"), input("wallet")])
+            .expect("simulations");
+
+        assert_eq!(matched_rules(&simulations), vec![None, None]);
+        for simulation in simulations {
+            assert!(simulation.is_default_selection);
+            assert_eq!(
+                serde_json::to_value(&simulation.connector_selection).expect("serialized"),
+                serde_json::to_value(&fallback.connector_selection).expect("serialized")
+            );
+        }
+    }
+
+    #[cfg(feature = "v1")]
+    fn card_mca(connector_name: &str) -> api_models::admin::MerchantConnectorResponse {
+        card_mca_with_card_type(connector_name, "credit")
+    }
+
+    #[cfg(feature = "v1")]
+    fn card_mca_with_card_type(
+        connector_name: &str,
+        card_type: &str,
+    ) -> api_models::admin::MerchantConnectorResponse {
+        serde_json::from_value(serde_json::json!({
+            "connector_type": "payment_processor",
+            "connector_name": connector_name,
+            "merchant_connector_id": format!("mca_{connector_name}"),
+            "profile_id": "pro_1",
+            "connector_account_details": {},
+            "payment_methods_enabled": [{
+                "payment_method": "card",
+                "payment_method_types": [{ "payment_method_type": card_type }]
+            }],
+            "status": "active"
+        }))
+        .expect("valid merchant connector account")
+    }
+
+    #[cfg(feature = "v1")]
+    fn credit_card_rule() -> ast::Rule<ConnectorSelection> {
+        serde_json::from_value(serde_json::json!({
+            "name": "credit_card_rule",
+            "connectorSelection": { "type": "priority", "data": ["stripe", "adyen"] },
+            "statements": [{
+                "condition": [
+                    {
+                        "lhs": "payment_method",
+                        "comparison": "equal",
+                        "value": { "type": "enum_variant", "value": "card" },
+                        "metadata": {}
+                    },
+                    {
+                        "lhs": "card_type",
+                        "comparison": "equal",
+                        "value": { "type": "enum_variant", "value": "credit" },
+                        "metadata": {}
+                    }
+                ],
+                "nested": null
+            }]
+        }))
+        .expect("valid rule")
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_reseeding_replaces_the_knowledge_graph() {
+        let valid_connectors = || {
+            let seed_data = get_seed_data().expect("seeded knowledge graph");
+            get_valid_connectors(&seed_data, credit_card_rule(), &ast::Segments::new())
+                .expect("valid connectors")
+                .into_iter()
+                .map(|choice| choice.connector)
+                .collect::<Vec<_>>()
+        };
+
+        set_seed_data(Some(
+            make_seed_data(vec![card_mca("stripe")], Default::default()).expect("seed data"),
+        ));
+        assert_eq!(valid_connectors(), vec![RoutableConnectors::Stripe]);
+
+        set_seed_data(Some(
+            make_seed_data(
+                vec![card_mca("stripe"), card_mca("adyen")],
+                Default::default(),
+            )
+            .expect("seed data"),
+        ));
+        assert_eq!(
+            valid_connectors(),
+            vec![RoutableConnectors::Stripe, RoutableConnectors::Adyen]
+        );
+
+        reset_knowledge_graph();
+        assert!(get_seed_data().is_none());
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_single_connector_account_updates() {
+        let valid_connectors = |seed_data: &SeedData| {
+            get_valid_connectors(seed_data, credit_card_rule(), &ast::Segments::new())
+                .expect("valid connectors")
+                .into_iter()
+                .map(|choice| choice.connector)
+                .collect::<Vec<_>>()
+        };
+        let seed_data =
+            make_seed_data(vec![card_mca("stripe")], Default::default()).expect("seed data");
+
+        let seed_data = add_mca_to_seed_data(&seed_data, card_mca("adyen")).expect("seed data");
+        assert_eq!(
+            valid_connectors(&seed_data),
+            vec![RoutableConnectors::Stripe, RoutableConnectors::Adyen]
+        );
+
+        // Adding an already seeded account replaces it
+        let seed_data = add_mca_to_seed_data(&seed_data, card_mca_with_card_type("adyen", "debit"))
+            .expect("seed data");
+        assert_eq!(seed_data.mcas.len(), 2);
+        assert_eq!(
+            valid_connectors(&seed_data),
+            vec![RoutableConnectors::Stripe]
+        );
+
+        let mcas = get_mcas_without(&seed_data, "mca_stripe").expect("seeded account");
+        let seed_data = make_seed_data(mcas, Default::default()).expect("seed data");
+        assert!(valid_connectors(&seed_data).is_empty());
+
+        let mcas = get_mcas_without(&seed_data, "mca_adyen").expect("seeded account");
+        let seed_data = make_seed_data(mcas, Default::default()).expect("seed data");
+        assert!(seed_data.connectors.is_empty());
+        assert!(valid_connectors(&seed_data).is_empty());
+
+        assert_eq!(
+            get_mcas_without(&seed_data, "mca_adyen").map(|mcas| mcas.len()),
+            Err("Merchant connector account 'mca_adyen' is not seeded".to_string())
+        );
+    }
+
+    fn payment_method_condition(payment_method: &str) -> serde_json::Value {
+        serde_json::json!({
+            "lhs": "payment_method",
+            "comparison": "equal",
+            "value": { "type": "enum_variant", "value": payment_method },
+            "metadata": {}
+        })
+    }
+
+    fn program_with_conflicting_rule() -> ast::Program<ConnectorSelection> {
+        serde_json::from_value(serde_json::json!({
+            "defaultSelection": { "type": "priority", "data": ["stripe"] },
+            "metadata": {},
+            "rules": [
+                {
+                    "name": "card_rule",
+                    "connectorSelection": { "type": "priority", "data": ["stripe"] },
+                    "statements": [{
+                        "condition": [payment_method_condition("card")],
+                        "nested": null
+                    }]
+                },
+                {
+                    "name": "conflicting_rule",
+                    "connectorSelection": { "type": "priority", "data": ["stripe"] },
+                    "statements": [{
+                        "condition": [
+                            payment_method_condition("card"),
+                            payment_method_condition("wallet")
+                        ],
+                        "nested": null
+                    }]
+                }
+            ]
+        }))
+        .expect("valid program")
+    }
+
+    fn get_error_diagnostics(validation: &types::ProgramValidation) -> Vec<&types::RuleDiagnostic> {
+        validation
+            .diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.severity == types::DiagnosticSeverity::Error)
+            .collect()
+    }
+
+    #[test]
+    fn test_program_validation_reports_the_offending_rule_without_seed_data() {
+        let validation = get_program_validation(program_with_conflicting_rule(), None);
+
+        assert!(!validation.is_valid);
+        let errors = get_error_diagnostics(&validation);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].rule_index, 1);
+        assert_eq!(errors[0].rule_name, "conflicting_rule");
+        assert_eq!(errors[0].key, Some(dir::DirKeyKind::PaymentMethod));
+        assert!(!errors[0].reason.is_empty());
+    }
+
+    #[test]
+    fn test_program_validation_of_clean_program_has_no_diagnostics() {
+        let mut program = program_with_conflicting_rule();
+        program.rules.truncate(1);
+
+        assert_eq!(
+            get_program_validation(program, None),
+            types::ProgramValidation {
+                is_valid: true,
+                diagnostics: vec![],
+            }
+        );
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_program_validation_reports_the_offending_rule_with_seed_data() {
+        let seed_data =
+            make_seed_data(vec![card_mca("stripe")], Default::default()).expect("seed data");
+        let validation = get_program_validation(program_with_conflicting_rule(), Some(&seed_data));
+
+        assert!(!validation.is_valid);
+        assert!(get_error_diagnostics(&validation).iter().any(|diagnostic| {
+            diagnostic.rule_index == 1
+                && diagnostic.rule_name == "conflicting_rule"
+                && diagnostic.key == Some(dir::DirKeyKind::PaymentMethod)
+        }));
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_program_valid_connectors_report_failing_rules_per_rule() {
+        let seed_data = make_seed_data(
+            vec![card_mca("stripe"), card_mca("adyen")],
+            Default::default(),
+        )
+        .expect("seed data");
+        let undefined_segment_rule = serde_json::from_value(serde_json::json!({
+            "name": "undefined_segment_rule",
+            "connectorSelection": { "type": "priority", "data": ["stripe"] },
+            "statements": [{
+                "condition": [{
+                    "lhs": "payment_method",
+                    "comparison": "equal",
+                    "value": { "type": "segment_reference", "value": "wallets" },
+                    "metadata": {}
+                }],
+                "nested": null
+            }]
+        }))
+        .expect("valid rule");
+        let mut program = program_with_conflicting_rule();
+        program.rules = vec![credit_card_rule(), undefined_segment_rule];
+
+        let rule_connectors =
+            get_program_valid_connectors(&seed_data.cgraph, &seed_data.connectors, program);
+
+        assert_eq!(rule_connectors.len(), 2);
+        assert_eq!(rule_connectors[0].rule_index, 0);
+        assert_eq!(rule_connectors[0].rule_name, "credit_card_rule");
+        assert_eq!(
+            rule_connectors[0]
+                .valid_connectors
+                .as_ref()
+                .map(|choices| choices
+                    .iter()
+                    .map(|choice| choice.connector)
+                    .collect::<Vec<_>>()),
+            Some(vec![RoutableConnectors::Stripe, RoutableConnectors::Adyen])
+        );
+        assert!(rule_connectors[0].error.is_none());
+
+        assert_eq!(rule_connectors[1].rule_index, 1);
+        assert_eq!(rule_connectors[1].rule_name, "undefined_segment_rule");
+        assert!(rule_connectors[1].valid_connectors.is_none());
+        assert_eq!(
+            rule_connectors[1]
+                .error
+                .as_ref()
+                .and_then(|error| error.code.as_deref()),
+            Some("lowering")
+        );
+    }
+
+    #[cfg(feature = "v1")]
+    fn credit_card_rule_with_currency(currency: &str) -> ast::Rule<ConnectorSelection> {
+        let mut rule = credit_card_rule();
+        let currency_condition = serde_json::from_value(serde_json::json!({
+            "lhs": "currency",
+            "comparison": "equal",
+            "value": { "type": "enum_variant", "value": currency },
+            "metadata": {}
+        }))
+        .expect("valid condition");
+        rule.statements
+            .iter_mut()
+            .for_each(|statement| statement.condition.push(currency_condition.clone()));
+        rule
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_currency_filter_rejects_connector() {
+        let filters = serde_json::from_value(serde_json::json!({
+            "connector_configs": {
+                "adyen": { "credit": { "currency": ["EUR"] } }
+            },
+            "default_configs": null
+        }))
+        .expect("valid filters");
+        let seed_data = make_seed_data(vec![card_mca("stripe"), card_mca("adyen")], filters)
+            .expect("seed data");
+        let valid_connectors = |currency| {
+            get_valid_connectors(
+                &seed_data,
+                credit_card_rule_with_currency(currency),
+                &ast::Segments::new(),
+            )
+            .expect("valid connectors")
+            .into_iter()
+            .map(|choice| choice.connector)
+            .collect::<Vec<_>>()
+        };
+
+        assert_eq!(valid_connectors("USD"), vec![RoutableConnectors::Stripe]);
+        assert_eq!(
+            valid_connectors("EUR"),
+            vec![RoutableConnectors::Stripe, RoutableConnectors::Adyen]
+        );
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_connector_validity_explains_invalid_connectors() {
+        let seed_data = make_seed_data(
+            vec![
+                card_mca("stripe"),
+                card_mca_with_card_type("adyen", "debit"),
+            ],
+            Default::default(),
+        )
+        .expect("seed data");
+        let dir_rule =
+            ast::lowering::lower_rule_with_segments(credit_card_rule(), &ast::Segments::new())
+                .expect("lowered rule");
+
+        let connector_validity =
+            get_connector_validity(&seed_data.cgraph, &seed_data.connectors, &dir_rule)
+                .expect("connector validity");
+
+        assert_eq!(connector_validity.len(), 2);
+        assert_eq!(
+            connector_validity.first(),
+            Some(&types::ConnectorValidity {
+                connector: ast::ConnectorChoice {
+                    connector: RoutableConnectors::Stripe,
+                },
+                valid: true,
+                reason: None,
+                violation: None,
+            })
+        );
+
+        let adyen = connector_validity.get(1).expect("adyen validity");
+        assert_eq!(adyen.connector.connector, RoutableConnectors::Adyen);
+        assert!(!adyen.valid);
+        assert!(adyen.violation.is_some());
+        assert!(adyen
+            .reason
+            .as_deref()
+            .is_some_and(|reason| reason.starts_with("adyen ")));
+
+        assert_eq!(
+            get_valid_connectors(&seed_data, credit_card_rule(), &ast::Segments::new())
+                .expect("valid connectors")
+                .into_iter()
+                .map(|choice| choice.connector)
+                .collect::<Vec<_>>(),
+            vec![RoutableConnectors::Stripe]
+        );
+    }
+
+    #[cfg(feature = "payouts")]
+    fn payout_program() -> ast::Program<ConnectorSelection> {
+        serde_json::from_value(serde_json::json!({
+            "defaultSelection": { "type": "priority", "data": ["paypal"] },
+            "metadata": {},
+            "rules": [{
+                "name": "ach_rule",
+                "connectorSelection": { "type": "priority", "data": ["adyen", "wise"] },
+                "statements": [{
+                    "condition": [
+                        {
+                            "lhs": "payment_method",
+                            "comparison": "equal",
+                            "value": { "type": "enum_variant", "value": "bank_transfer" },
+                            "metadata": {}
+                        },
+                        {
+                            "lhs": "bank_transfer",
+                            "comparison": "equal",
+                            "value": { "type": "enum_variant", "value": "ach" },
+                            "metadata": {}
+                        }
+                    ],
+                    "nested": null
+                }]
+            }]
+        }))
+        .expect("valid payout program")
+    }
+
+    #[cfg(feature = "payouts")]
+    fn payout_input(bank_transfer_type: Option<&str>) -> inputs::PayoutBackendInput {
+        serde_json::from_value(serde_json::json!({
+            "amount": 1000,
+            "business_country": null,
+            "billing_country": null,
+            "business_label": null,
+            "payout_type": bank_transfer_type.map(|_| "bank_transfer"),
+            "wallet_type": null,
+            "bank_transfer_type": bank_transfer_type
+        }))
+        .expect("valid payout input")
+    }
+
+    #[cfg(feature = "payouts")]
+    #[test]
+    fn test_payout_program_selects_the_payout_connectors_of_the_matching_rule() {
+        let output = get_payout_program_output(payout_program(), payout_input(Some("ach")), None)
+            .expect("payout program output");
+        assert_eq!(
+            output,
+            types::PayoutProgramOutput {
+                rule_name: Some("ach_rule".to_string()),
+                connectors: vec![
+                    api_model_enums::PayoutConnectors::Adyen,
+                    api_model_enums::PayoutConnectors::Wise
+                ],
+            }
+        );
+
+        let output = get_payout_program_output(payout_program(), payout_input(None), None)
+            .expect("payout program output");
+        assert_eq!(output.rule_name, None);
+        assert_eq!(
+            output.connectors,
+            vec![api_model_enums::PayoutConnectors::Paypal]
+        );
+
+        assert!(get_payout_program_output(
+            payout_program(),
+            payout_input(Some("ach")),
+            Some(&[api_model_enums::PayoutConnectors::Paypal]),
+        )
+        .is_err());
+    }
+
+    #[cfg(feature = "payouts")]
+    #[test]
+    fn test_payout_program_analysis_without_seeded_payout_knowledge_graph() {
+        assert_eq!(
+            get_payout_program_warnings(&payout_program(), None),
+            Ok(vec![types::PayoutProgramWarning::KnowledgeGraphNotSeeded])
+        );
+
+        assert_eq!(
+            get_payout_program_warnings(
+                &payout_program(),
+                Some(&[
+                    api_model_enums::PayoutConnectors::Adyen,
+                    api_model_enums::PayoutConnectors::Paypal
+                ]),
+            ),
+            Ok(vec![types::PayoutProgramWarning::ConnectorNotConfigured {
+                rule_name: Some("ach_rule".to_string()),
+                connector: api_model_enums::PayoutConnectors::Wise,
+            }])
+        );
+    }
+
+    #[cfg(feature = "payouts")]
+    #[test]
+    fn test_payout_program_analysis_rejects_payment_only_keys_and_connectors() {
+        let mut program = payout_program();
+        program.rules[0].statements[0].condition[1].lhs = "card_network".to_string();
+        assert!(get_payout_program_warnings(&program, None).is_err());
+
+        let mut program = payout_program();
+        program.default_selection =
+            ConnectorSelection::Priority(vec![api_models::routing::RoutableConnectorChoice {
+                choice_kind: api_models::routing::RoutableChoiceKind::FullStruct,
+                connector: RoutableConnectors::Checkout,
+                merchant_connector_id: None,
+            }]);
+        assert!(get_payout_program_warnings(&program, None).is_err());
+    }
+
+    fn three_ds_decision_rule(
+        name: &str,
+        decision: &str,
+        condition: serde_json::Value,
+    ) -> serde_json::Value {
+        serde_json::json!({
+            "name": name,
+            "connectorSelection": { "decision": decision },
+            "statements": [{ "condition": condition, "nested": null }]
+        })
+    }
+
+    fn three_ds_decision_program() -> ast::Program<ThreeDSDecisionRule> {
+        serde_json::from_value(serde_json::json!({
+            "defaultSelection": { "decision": "no_three_ds" },
+            "metadata": {},
+            "rules": [
+                three_ds_decision_rule(
+                    "issuer_challenge",
+                    "challenge_requested",
+                    serde_json::json!([{
+                        "lhs": "issuer_name",
+                        "comparison": "equal",
+                        "value": { "type": "str_value", "value": "Risky Bank" },
+                        "metadata": {}
+                    }]),
+                ),
+                three_ds_decision_rule(
+                    "mobile_exemption",
+                    "three_ds_exemption_requested_low_value",
+                    serde_json::json!([
+                        {
+                            "lhs": "issuer_country",
+                            "comparison": "equal",
+                            "value": { "type": "enum_variant", "value": "Germany" },
+                            "metadata": {}
+                        },
+                        {
+                            "lhs": "acquirer_country",
+                            "comparison": "equal",
+                            "value": { "type": "enum_variant", "value": "France" },
+                            "metadata": {}
+                        },
+                        {
+                            "lhs": "customer_device_type",
+                            "comparison": "equal",
+                            "value": { "type": "enum_variant", "value": "mobile" },
+                            "metadata": {}
+                        }
+                    ]),
+                )
+            ]
+        }))
+        .expect("valid three DS decision program")
+    }
+
+    fn three_ds_decision(
+        issuer: serde_json::Value,
+        device_type: &str,
+    ) -> types::ThreeDsDecisionProgramOutput {
+        let input = serde_json::from_value(serde_json::json!({
+            "metadata": null,
+            "payment": { "amount": 2000, "currency": "EUR" },
+            "payment_method": {},
+            "mandate": {},
+            "issuer_data": issuer,
+            "acquirer_data": { "country": "France", "fraud_rate": 0.01 },
+            "customer_device_data": { "platform": "ios", "device_type": device_type }
+        }))
+        .expect("valid input");
+
+        get_three_ds_decision_program_output(three_ds_decision_program(), input)
+            .expect("three DS decision program output")
+    }
+
+    #[test]
+    fn test_three_ds_decision_program_evaluates_issuer_acquirer_and_device_keys() {
+        assert_eq!(
+            three_ds_decision(
+                serde_json::json!({ "name": "Risky Bank", "country": "Germany" }),
+                "mobile"
+            ),
+            types::ThreeDsDecisionProgramOutput {
+                rule_name: Some("issuer_challenge".to_string()),
+                decision: ThreeDSDecision::ChallengeRequested,
+            }
+        );
+        assert_eq!(
+            three_ds_decision(
+                serde_json::json!({ "name": "Other Bank", "country": "Germany" }),
+                "mobile"
+            ),
+            types::ThreeDsDecisionProgramOutput {
+                rule_name: Some("mobile_exemption".to_string()),
+                decision: ThreeDSDecision::ThreeDsExemptionRequestedLowValue,
+            }
+        );
+        assert_eq!(
+            three_ds_decision(
+                serde_json::json!({ "name": "Other Bank", "country": "Germany" }),
+                "desktop"
+            ),
+            types::ThreeDsDecisionProgramOutput {
+                rule_name: None,
+                decision: ThreeDSDecision::NoThreeDs,
+            }
+        );
+    }
+
+    fn surcharge_rule(
+        name: &str,
+        surcharge: serde_json::Value,
+        tax_on_surcharge: Option<f32>,
+        condition: serde_json::Value,
+    ) -> serde_json::Value {
+        serde_json::json!({
+            "name": name,
+            "connectorSelection": {
+                "surcharge_details": {
+                    "surcharge": surcharge,
+                    "tax_on_surcharge": tax_on_surcharge.map(|percentage| {
+                        serde_json::json!({ "percentage": percentage })
+                    })
+                }
+            },
+            "statements": [{ "condition": condition, "nested": null }]
+        })
+    }
+
+    fn surcharge_program(condition_key: &str) -> ast::Program<SurchargeDecisionConfigs> {
+        serde_json::from_value(serde_json::json!({
+            "defaultSelection": { "surcharge_details": null },
+            "metadata": {},
+            "rules": [
+                surcharge_rule(
+                    "card_rate",
+                    serde_json::json!({ "type": "rate", "value": { "percentage": 2.5 } }),
+                    Some(10.0),
+                    serde_json::json!([
+                        payment_method_condition("card"),
+                        {
+                            "lhs": condition_key,
+                            "comparison": "equal",
+                            "value": { "type": "enum_variant_array", "value": ["USD", "JPY"] },
+                            "metadata": {}
+                        }
+                    ]),
+                ),
+                surcharge_rule(
+                    "eur_fixed",
+                    serde_json::json!({ "type": "fixed", "value": { "amount": 150 } }),
+                    Some(20.0),
+                    serde_json::json!([{
+                        "lhs": "currency",
+                        "comparison": "equal",
+                        "value": { "type": "enum_variant", "value": "EUR" },
+                        "metadata": {}
+                    }]),
+                )
+            ]
+        }))
+        .expect("valid surcharge program")
+    }
+
+    fn surcharge_input(amount: i64, currency: &str) -> inputs::BackendInput {
+        serde_json::from_value(serde_json::json!({
+            "metadata": null,
+            "payment": { "amount": amount, "currency": currency },
+            "payment_method": { "payment_method": "card" },
+            "mandate": {}
+        }))
+        .expect("valid input")
+    }
+
+    fn surcharge_amounts(amount: i64, currency: &str) -> (Option<String>, i64, i64) {
+        let output = get_surcharge_program_output(
+            surcharge_program("currency"),
+            surcharge_input(amount, currency),
+        )
+        .expect("surcharge program output");
+
+        (
+            output.rule_name,
+            output.surcharge_amount.get_amount_as_i64(),
+            output.tax_on_surcharge_amount.get_amount_as_i64(),
+        )
+    }
+
+    #[test]
+    fn test_surcharge_program_rounds_rates_up_to_minor_units() {
+        assert_eq!(
+            surcharge_amounts(10000, "USD"),
+            (Some("card_rate".to_string()), 250, 25)
+        );
+        // 2.5% of 999 yen is 24.975 yen, and the tax of 10% on the 25 yen surcharge is 2.5 yen
+        assert_eq!(
+            surcharge_amounts(999, "JPY"),
+            (Some("card_rate".to_string()), 25, 3)
+        );
+    }
+
+    #[test]
+    fn test_surcharge_program_applies_fixed_amounts_and_default_selection() {
+        assert_eq!(
+            surcharge_amounts(5000, "EUR"),
+            (Some("eur_fixed".to_string()), 150, 30)
+        );
+
+        let output = get_surcharge_program_output(
+            surcharge_program("currency"),
+            surcharge_input(5000, "GBP"),
+        )
+        .expect("surcharge program output");
+        assert_eq!(
+            output,
+            types::SurchargeProgramOutput {
+                rule_name: None,
+                surcharge_details: None,
+                surcharge_amount: MinorUnit::zero(),
+                tax_on_surcharge_amount: MinorUnit::zero(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_surcharge_program_analysis_rejects_disallowed_keys() {
+        let warnings = get_surcharge_program_warnings(surcharge_program("currency"))
+            .expect("valid surcharge program");
+        assert!(warnings.is_empty());
+
+        let error = get_surcharge_program_warnings(surcharge_program("capture_method"))
+            .expect_err("capture method is not a surcharge key");
+        assert!(matches!(
+            error.error_type,
+            dssa::types::AnalysisErrorType::InvalidKey(key) if key == "capture_method"
+        ));
+    }
+
+    #[test]
+    fn test_request_payload_diff_redacts_credentials() {
+        let input: DashboardRequestPayload = serde_json::from_value(serde_json::json!({
+            "connector": "stripe",
+            "metadata": {
+                "merchant_name": "New Name",
+                "apple_pay": { "terminal_uuid": "terminal", "pay_wall_secret": "new_secret" }
+            }
+        }))
+        .expect("valid request payload");
+        let api_response: ConnectorApiIntegrationPayload =
+            serde_json::from_value(serde_json::json!({
+                "connector_type": "payment_processor",
+                "profile_id": "pro_stripe",
+                "connector_name": "stripe",
+                "merchant_connector_id": "mca_stripe",
+                "disabled": false,
+                "test_mode": true,
+                "payment_methods_enabled": null,
+                "metadata": {
+                    "merchant_name": "Old Name",
+                    "apple_pay": { "terminal_uuid": "terminal", "pay_wall_secret": "old_secret" }
+                },
+                "connector_webhook_details": null
+            }))
+            .expect("valid connector account");
+
+        let diff = get_connector_request_diff(input, api_response).expect("request payload diff");
+
+        assert_eq!(
+            diff.added,
+            vec![utils::JsonFieldValue {
+                path: "payment_methods_enabled".to_string(),
+                value: serde_json::json!([]),
+            }]
+        );
+        assert!(diff.removed.is_empty());
+        assert_eq!(
+            diff.changed,
+            vec![
+                utils::JsonFieldChange {
+                    path: "metadata.apple_pay.pay_wall_secret".to_string(),
+                    old: None,
+                    new: None,
+                },
+                utils::JsonFieldChange {
+                    path: "metadata.merchant_name".to_string(),
+                    old: Some(serde_json::json!("Old Name")),
+                    new: Some(serde_json::json!("New Name")),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_filtered_connector_config_keeps_the_sections_of_the_payment_method() {
+        let config = get_filtered_connector_config("stripe", Some("card"), None)
+            .expect("filtered connector config")
+            .expect("stripe connector config");
+
+        assert!(config.credit.is_some());
+        assert!(config.debit.is_some());
+        assert!(config.wallet.is_none());
+        assert!(config.bank_redirect.is_none());
+        assert!(config.connector_wallets_details.is_none());
+        assert!(config.connector_auth.is_some());
+
+        let error = get_filtered_connector_config("stripe", Some("cards"), None)
+            .expect_err("unknown payment method");
+        assert!(error.contains("'cards'"));
+        assert!(error.contains("card, "));
+
+        let unfiltered = get_filtered_connector_config("stripe", None, None)
+            .expect("connector config")
+            .map(|config| serde_json::to_value(config).expect("serialized config"));
+        let config =
+            connector::ConnectorConfig::get_connector_config(api_model_enums::Connector::Stripe)
+                .expect("connector config")
+                .map(|config| serde_json::to_value(config).expect("serialized config"));
+        assert_eq!(unfiltered, config);
+    }
+
+    #[test]
+    fn test_filtered_connector_config_keeps_the_payment_method_types_of_the_country() {
+        let config: connector::ConnectorTomlConfig = serde_json::from_value(serde_json::json!({
+            "pay_later": [
+                {
+                    "payment_method_type": "klarna",
+                    "accepted_countries": { "type": "enable_only", "list": ["DE", "SE"] }
+                },
+                {
+                    "payment_method_type": "affirm",
+                    "accepted_countries": { "type": "disable_only", "list": ["DE"] }
+                },
+                { "payment_method_type": "afterpay_clearpay" }
+            ],
+            "crypto": [{
+                "payment_method_type": "crypto_currency",
+                "accepted_countries": { "type": "enable_only", "list": ["US"] }
+            }]
+        }))
+        .expect("valid connector config");
+
+        let config = config.filter_payment_methods(None, Some(CountryAlpha2::DE));
+        let pay_later_types = config
+            .pay_later
+            .unwrap_or_default()
+            .into_iter()
+            .map(|provider| provider.payment_method_type)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            pay_later_types,
+            vec![
+                api_model_enums::PaymentMethodType::Klarna,
+                api_model_enums::PaymentMethodType::AfterpayClearpay
+            ]
+        );
+        assert!(config.crypto.is_none());
+    }
+
+    fn webhook_status_events_json(event_class: EventClass) -> serde_json::Value {
+        serde_json::to_value(get_webhook_status_events(event_class))
+            .expect("serialized webhook status events")
+    }
+
+    #[test]
+    fn test_webhook_status_events_serialize_as_status_and_event_type_pairs() {
+        assert_eq!(
+            webhook_status_events_json(EventClass::Refunds),
+            serde_json::json!([
+                { "status": "failure", "event_type": "refund_failed" },
+                { "status": "success", "event_type": "refund_succeeded" }
+            ])
+        );
+        assert_eq!(
+            webhook_status_events_json(EventClass::Mandates),
+            serde_json::json!([
+                { "status": "active", "event_type": "mandate_active" },
+                { "status": "revoked", "event_type": "mandate_revoked" }
+            ])
+        );
+
+        let payment_events = webhook_status_events_json(EventClass::Payments);
+        let payment_events = payment_events.as_array().expect("array of payment events");
+        assert!(payment_events.contains(&serde_json::json!({
+            "status": "requires_capture",
+            "event_type": "payment_authorized"
+        })));
+        assert!(payment_events
+            .iter()
+            .all(|event| event.get("status") != Some(&serde_json::json!("requires_confirmation"))));
+    }
+
+    #[test]
+    fn test_dispute_statuses_go_through_the_event_type_mapping() {
+        let dispute_events = webhook_status_events_json(EventClass::Disputes);
+        let dispute_events = dispute_events.as_array().expect("array of dispute events");
+
+        assert_eq!(dispute_events.len(), DisputeStatus::iter().count());
+        assert!(dispute_events.iter().all(|event| {
+            event.get("status").is_some() && event.get("status") == event.get("event_type")
+        }));
+    }
+
+    #[test]
+    fn test_country_details_of_multi_currency_country() {
+        let country_details = build_country_details();
+        assert_eq!(country_details.len(), CountryAlpha2::iter().count());
+
+        let panama = country_details
+            .iter()
+            .find(|details| details.code == CountryAlpha2::PA)
+            .expect("details of panama");
+        assert_eq!(
+            serde_json::to_value(panama).expect("serialized country details"),
+            serde_json::json!({
+                "code": "PA",
+                "alpha3_code": "PAN",
+                "name": "Panama",
+                "numeric_code": 591,
+                "primary_currency": "PAB",
+                "currencies": ["PAB", "USD"]
+            })
+        );
+    }
+
+    #[test]
+    fn test_countries_for_currency_include_secondary_currencies() {
+        let codes = |currency| {
+            find_countries_for_currency(currency)
+                .into_iter()
+                .map(|country| country.code)
+                .collect::<Vec<_>>()
+        };
+
+        let usd_countries = codes(common_enums::Currency::USD);
+        assert!(usd_countries.contains(&CountryAlpha2::US));
+        assert!(usd_countries.contains(&CountryAlpha2::PA));
+        assert!(usd_countries.contains(&CountryAlpha2::ZW));
+
+        let zar_countries = codes(common_enums::Currency::ZAR);
+        assert_eq!(
+            zar_countries,
+            vec![CountryAlpha2::LS, CountryAlpha2::NA, CountryAlpha2::ZA]
+        );
+    }
+
+    #[test]
+    fn test_rule_value_errors() {
+        let rule: ast::Rule<ConnectorSelection> = serde_json::from_value(serde_json::json!({
+            "name": "card_rule",
+            "connectorSelection": { "type": "priority", "data": ["stripe"] },
+            "statements": [{
+                "condition": [
+                    {
+                        "lhs": "amount",
+                        "comparison": "greater_than",
+                        "value": { "type": "number", "value": -100 },
+                        "metadata": {}
+                    },
+                    {
+                        "lhs": "card_bin",
+                        "comparison": "equal",
+                        "value": { "type": "str_value", "value": "42424242" },
+                        "metadata": {}
+                    }
+                ],
+                "nested": [{
+                    "condition": [
+                        {
+                            "lhs": "card_bin",
+                            "comparison": "equal",
+                            "value": { "type": "str_value", "value": "4242" },
+                            "metadata": {}
+                        },
+                        {
+                            "lhs": "metadata",
+                            "comparison": "equal",
+                            "value": {
+                                "type": "metadata_variant",
+                                "value": { "key": " ", "value": "gold" }
+                            },
+                            "metadata": {}
+                        },
+                        {
+                            "lhs": "currency",
+                            "comparison": "equal",
+                            "value": { "type": "number", "value": 840 },
+                            "metadata": {}
+                        },
+                        {
+                            "lhs": "amount",
+                            "comparison": "equal",
+                            "value": { "type": "segment_reference", "value": "high_value" },
+                            "metadata": {}
+                        }
+                    ],
+                    "nested": null
+                }]
+            }]
+        }))
+        .expect("valid rule");
+
+        let errors = get_rule_value_errors(&rule)
+            .into_iter()
+            .map(|error| (error.statement_path, error.condition_index, error.reason))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            errors,
+            vec![
+                (
+                    vec![0],
+                    0,
+                    "Expected a non-negative amount, got -100".to_string()
+                ),
+                (
+                    vec![0, 0],
+                    0,
+                    "Expected a card bin of 6 to 8 digits, got '4242'".to_string()
+                ),
+                (
+                    vec![0, 0],
+                    1,
+                    "Expected a non-empty metadata key".to_string()
+                ),
+                (
+                    vec![0, 0],
+                    2,
+                    "Expected a value of type enum_variant, got number".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rule_conditions_parsing() {
+        let statements =
+            get_rule_conditions("{ amount > 1000 & currency = (USD, EUR) }").expect("parsed");
+        assert_eq!(
+            ast::printer::print_rule_conditions(&statements),
+            "{\n    amount > 1000 & currency = (USD, EUR)\n}\n"
+        );
+
+        assert_eq!(
+            get_rule_conditions("{ amount > 1000 } currency = USD"),
+            Err("Unexpected input after the rule conditions: ' currency = USD'".to_string())
+        );
+    }
+
+    #[test]
+    fn test_shadowed_rule_warnings() {
+        let program: ast::Program<ConnectorSelection> = serde_json::from_value(serde_json::json!({
+            "defaultSelection": { "type": "priority", "data": ["stripe"] },
+            "metadata": {},
+            "rules": [
+                {
+                    "name": "card_rule",
+                    "connectorSelection": { "type": "priority", "data": ["stripe"] },
+                    "statements": [{
+                        "condition": [payment_method_condition("card")],
+                        "nested": null
+                    }]
+                },
+                {
+                    "name": "credit_card_rule",
+                    "connectorSelection": { "type": "priority", "data": ["adyen"] },
+                    "statements": [{
+                        "condition": [
+                            payment_method_condition("card"),
+                            {
+                                "lhs": "card_type",
+                                "comparison": "equal",
+                                "value": { "type": "enum_variant", "value": "credit" },
+                                "metadata": {}
+                            }
+                        ],
+                        "nested": null
+                    }]
+                }
+            ]
+        }))
+        .expect("valid program");
+
+        let warnings = get_shadowed_rule_warnings(program).expect("analysis");
+
+        assert_eq!(
+            warnings,
+            vec![dssa::types::AnalysisWarning::ShadowedRule {
+                rule_name: "credit_card_rule".to_string(),
+                rule_index: 1,
+                shadowing_rule_name: "card_rule".to_string(),
+                shadowing_rule_index: 0,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_key_operators() {
+        let equality_operators = [ast::ComparisonType::Equal, ast::ComparisonType::NotEqual];
+
+        assert_eq!(
+            get_key_operators(&dir::DirKeyKind::PaymentAmount),
+            [
+                ast::ComparisonType::Equal,
+                ast::ComparisonType::NotEqual,
+                ast::ComparisonType::GreaterThan,
+                ast::ComparisonType::GreaterThanEqual,
+                ast::ComparisonType::LessThan,
+                ast::ComparisonType::LessThanEqual,
+            ]
+        );
+        assert_eq!(
+            get_key_operators(&dir::DirKeyKind::CustomerDeviceDisplaySize),
+            equality_operators
+        );
+        assert_eq!(
+            get_key_operators(&dir::DirKeyKind::CardBin),
+            equality_operators
+        );
+        assert_eq!(
+            get_key_operators(&dir::DirKeyKind::MetaData),
+            equality_operators
+        );
+    }
+
+    fn forex_data(base_currency: &str, target_currencies: &[&str], seeded_at: f64) -> ForexData {
+        let conversion = target_currencies
+            .iter()
+            .map(|currency| {
+                (
+                    currency.to_string(),
+                    serde_json::json!({ "to_factor": "2", "from_factor": "0.5" }),
+                )
+            })
+            .collect::<serde_json::Map<_, _>>();
+        ForexData {
+            exchange_rates: serde_json::from_value(serde_json::json!({
+                "base_currency": base_currency,
+                "conversion": conversion,
+            }))
+            .expect("Failed to deserialize exchange rates"),
+            seeded_at: Some(seeded_at),
+        }
+    }
+
+    #[test]
+    fn test_search_merchant_category_codes() {
+        assert_eq!(
+            find_merchant_category_codes("", 2),
+            MerchantCategoryCode::iter()
+                .take(2)
+                .map(|code| MerchantCategoryCodeWithName {
+                    code,
+                    name: code.to_merchant_category_name(),
+                })
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            find_merchant_category_codes("5411", 10),
+            vec![MerchantCategoryCodeWithName {
+                code: MerchantCategoryCode::Mcc5411,
+                name: common_enums::MerchantCategory::GroceryStoresSupermarkets,
+            }]
+        );
+        assert_eq!(
+            find_merchant_category_codes(" GROCERY ", 10)
+                .into_iter()
+                .map(|mcc_with_name| mcc_with_name.code)
+                .collect::<Vec<_>>(),
+            vec![MerchantCategoryCode::Mcc5411]
+        );
+        assert!(find_merchant_category_codes("legal", 0).is_empty());
+        assert!(find_merchant_category_codes("no such category", 10).is_empty());
+    }
+
+    fn usd_forex_data() -> ForexData {
+        ForexData {
+            exchange_rates: serde_json::from_value(serde_json::json!({
+                "base_currency": "USD",
+                "conversion": {
+                    "JPY": { "to_factor": "150", "from_factor": "0.0067" },
+                    "BHD": { "to_factor": "0.376", "from_factor": "2.6596" },
+                },
+            }))
+            .expect("Failed to deserialize exchange rates"),
+            seeded_at: None,
+        }
+    }
+
+    #[test]
+    fn test_convert_minor_units_from_zero_decimal_currency() {
+        let forex_data = usd_forex_data();
+        let convert = |rounding_mode| {
+            convert_forex_minor_amount(
+                Some(&forex_data),
+                MinorUnit::new(1234),
+                common_enums::Currency::JPY,
+                common_enums::Currency::USD,
+                rounding_mode,
+            )
+            .expect("Converted amount")
+        };
+
+        // 1234 JPY is 8.2678 USD, that is 826.78 cents
+        assert_eq!(
+            convert(currency_conversion_types::RoundingMode::HalfUp),
+            MinorUnit::new(827)
+        );
+        assert_eq!(
+            convert(currency_conversion_types::RoundingMode::Floor),
+            MinorUnit::new(826)
+        );
+        assert_eq!(
+            convert(currency_conversion_types::RoundingMode::Ceil),
+            MinorUnit::new(827)
+        );
+    }
+
+    #[test]
+    fn test_convert_minor_units_to_three_decimal_currency() {
+        let forex_data = usd_forex_data();
+        let convert = |rounding_mode| {
+            convert_forex_minor_amount(
+                Some(&forex_data),
+                MinorUnit::new(1999),
+                common_enums::Currency::USD,
+                common_enums::Currency::BHD,
+                rounding_mode,
+            )
+            .expect("Converted amount")
+        };
+
+        // 19.99 USD is 7.51624 BHD, that is 7516.24 fils
+        assert_eq!(
+            convert(currency_conversion_types::RoundingMode::HalfUp),
+            MinorUnit::new(7516)
+        );
+        assert_eq!(
+            convert(currency_conversion_types::RoundingMode::Floor),
+            MinorUnit::new(7516)
+        );
+        assert_eq!(
+            convert(currency_conversion_types::RoundingMode::Ceil),
+            MinorUnit::new(7517)
+        );
+        assert_eq!(
+            convert_forex_minor_amount(
+                Some(&forex_data),
+                MinorUnit::new(1999),
+                common_enums::Currency::USD,
+                common_enums::Currency::EUR,
+                currency_conversion_types::RoundingMode::HalfUp,
+            )
+            .err(),
+            Some("Forex rate not available for USD to EUR".to_string())
+        );
+    }
+
+    #[test]
+    fn test_forex_data_can_be_reseeded_with_a_different_base_currency() {
+        assert_eq!(
+            convert_forex_amount(
+                None,
+                100,
+                common_enums::Currency::USD,
+                common_enums::Currency::EUR
+            )
+            .err(),
+            Some("Forex Data not seeded".to_string())
+        );
+
+        set_forex_data(forex_data("USD", &["INR", "EUR"], 1.0));
+        let seeded_forex_data = get_forex_data().expect("Forex data is seeded");
+        assert_eq!(
+            build_forex_metadata(&seeded_forex_data),
+            types::ForexMetadata {
+                base_currency: common_enums::Currency::USD,
+                target_currencies: vec![common_enums::Currency::EUR, common_enums::Currency::INR],
+                seeded_at: Some(1.0),
+            }
+        );
+        assert!(convert_forex_amount(
+            Some(&seeded_forex_data),
+            100,
+            common_enums::Currency::EUR,
+            common_enums::Currency::INR
+        )
+        .is_ok());
+
+        set_forex_data(forex_data("EUR", &["GBP"], 2.0));
+        let reseeded_forex_data = get_forex_data().expect("Forex data is seeded");
+        assert_eq!(
+            build_forex_metadata(&reseeded_forex_data),
+            types::ForexMetadata {
+                base_currency: common_enums::Currency::EUR,
+                target_currencies: vec![common_enums::Currency::GBP],
+                seeded_at: Some(2.0),
+            }
+        );
+        assert!(convert_forex_amount(
+            Some(&reseeded_forex_data),
+            100,
+            common_enums::Currency::EUR,
+            common_enums::Currency::GBP
+        )
+        .is_ok());
+        assert_eq!(
+            convert_forex_amount(
+                Some(&reseeded_forex_data),
+                100,
+                common_enums::Currency::EUR,
+                common_enums::Currency::INR
+            )
+            .err(),
+            Some("Forex rate not available for EUR to INR".to_string())
+        );
+    }
+
+    /// The shared program test vectors, executed both as the previews of the dashboard execute
+    /// them and as the server executes them, each with the output type of its program kind
+    mod test_vectors {
+        use api_models::{
+            routing::ConnectorSelection, surcharge_decision_configs::SurchargeDecisionConfigs,
+        };
+        use common_types::three_ds_decision_rule_engine::ThreeDSDecisionRule;
+        use euclid::{
+            backend::{BackendOutput, EuclidBackend, InterpreterBackend, VirInterpreterBackend},
+            frontend::dir::EuclidDirFilter,
+            test_vectors::{ProgramKind, TestVector},
+        };
+        use serde::{de::DeserializeOwned, Serialize};
+
+        fn assert_output<O: Serialize>(vector: &TestVector, output: &BackendOutput<O>) {
+            assert_eq!(
+                output.rule_name, vector.expected.rule_name,
+                "{}",
+                vector.name
+            );
+            assert_eq!(
+                serde_json::to_value(output.get_output()).expect("Failed to serialize the output"),
+                vector.expected.output,
+                "{}",
+                vector.name
+            );
+        }
+
+        fn run_typed_test_vector<O>(vector: &TestVector)
+        where
+            O: DeserializeOwned + Serialize + Clone + EuclidDirFilter,
+        {
+            let program = vector
+                .get_program::<O>()
+                .expect("Failed to parse the program");
+
+            let preview_output = InterpreterBackend::with_program(program.clone())
+                .expect("Failed to load the program")
+                .execute(vector.input.clone())
+                .expect("Failed to execute the program");
+            assert_output(vector, &preview_output);
+
+            let server_output = VirInterpreterBackend::with_program(program)
+                .expect("Failed to lower the program")
+                .execute(vector.input.clone())
+                .expect("Failed to execute the program");
+            assert_output(vector, &server_output);
+        }
+
+        fn run_test_vector(kind: ProgramKind, vector: &TestVector) {
+            match kind {
+                ProgramKind::Routing => run_typed_test_vector::<ConnectorSelection>(vector),
+                ProgramKind::Surcharge => run_typed_test_vector::<SurchargeDecisionConfigs>(vector),
+                ProgramKind::ThreeDsDecision => {
+                    run_typed_test_vector::<ThreeDSDecisionRule>(vector)
+                }
+            }
+        }
+
+        euclid::test_vector_suite! {
+            run_test_vector;
+            routing_empty_program,
+            routing_fallback_only,
+            routing_first_matching_rule_wins,
+            routing_nested_statements,
+            routing_nested_statements_unmatched,
+            routing_metadata_condition,
+            routing_metadata_missing_key,
+            routing_volume_split,
+            routing_enum_variant_array,
+            routing_amount_upper_bound_exclusive,
+            routing_not_equal,
+            surcharge_empty_program,
+            surcharge_fallback_only,
+            surcharge_fixed_amount,
+            surcharge_rate_with_tax,
+            surcharge_metadata_not_equal,
+            three_ds_decision_empty_program,
+            three_ds_decision_fallback_only,
+            three_ds_decision_high_amount_challenge,
+            three_ds_decision_low_value_exemption,
         }
     }
 }
@@ -1,7 +1,36 @@
-use euclid::frontend::dir::DirKeyKind;
+use std::sync::Weak;
+
+#[cfg(feature = "payouts")]
+use api_models::enums::PayoutConnectors;
+use api_models::{
+    routing::ConnectorSelection,
+    surcharge_decision_configs::{SurchargeDecisionConfigs, SurchargeDetailsOutput},
+};
+#[cfg(feature = "payouts")]
+use common_enums::PayoutStatus;
+use common_enums::{
+    Country, CountryAlpha2, CountryAlpha3, Currency, DisputeStatus, EventType, IntentStatus,
+    MandateStatus, RefundStatus,
+};
+use common_types::{
+    payments::ConditionalConfigs,
+    three_ds_decision_rule_engine::{ThreeDSDecision, ThreeDSDecisionRule},
+};
+use common_utils::types::MinorUnit;
 #[cfg(feature = "payouts")]
 use euclid::frontend::dir::PayoutDirKeyKind;
+use euclid::frontend::{
+    ast::{ConnectorChoice, ValueType},
+    dir::{DirKeyKind, DirValue, EuclidDirFilter},
+};
+use hyperswitch_constraint_graph::{
+    error::ValueTracePredecessor, AnalysisTrace, NodeValue, Relation,
+};
 use serde::Serialize;
+#[cfg(feature = "payouts")]
+use strum::IntoEnumIterator;
+
+use crate::utils::JsError;
 
 #[derive(Serialize, Clone)]
 pub struct Details<'a> {
@@ -15,3 +44,400 @@ pub struct PayoutDetails<'a> {
     pub description: Option<&'a str>,
     pub kind: PayoutDirKeyKind,
 }
+
+/// The rule builder a key can be used in
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq, strum::EnumString, strum::EnumIter)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum KeyScope {
+    Payments,
+    ThreeDs,
+}
+
+impl KeyScope {
+    pub fn get_allowed_keys(self) -> &'static [DirKeyKind] {
+        match self {
+            Self::Payments => <ConnectorSelection as EuclidDirFilter>::ALLOWED,
+            Self::ThreeDs => <ThreeDSDecisionRule as EuclidDirFilter>::ALLOWED,
+        }
+    }
+}
+
+/// The type of rule built on the dashboard, each allowing its own set of keys
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq, Hash, strum::EnumIter)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleCategory {
+    PaymentRouting,
+    Surcharge,
+    ThreeDs,
+    ThreeDsDecision,
+    #[cfg(feature = "payouts")]
+    Payouts,
+}
+
+impl RuleCategory {
+    pub fn get_allowed_keys(self) -> Vec<String> {
+        match self {
+            Self::PaymentRouting => get_key_names(<ConnectorSelection as EuclidDirFilter>::ALLOWED),
+            Self::Surcharge => {
+                get_key_names(<SurchargeDecisionConfigs as EuclidDirFilter>::ALLOWED)
+            }
+            Self::ThreeDs => get_key_names(<ConditionalConfigs as EuclidDirFilter>::ALLOWED),
+            Self::ThreeDsDecision => {
+                get_key_names(<ThreeDSDecisionRule as EuclidDirFilter>::ALLOWED)
+            }
+            #[cfg(feature = "payouts")]
+            Self::Payouts => PayoutDirKeyKind::iter()
+                .map(|key| key.to_string())
+                .collect(),
+        }
+    }
+}
+
+fn get_key_names(keys: &[DirKeyKind]) -> Vec<String> {
+    keys.iter().map(|key| key.to_string()).collect()
+}
+
+/// The forex rates currently seeded, for the dashboard to tell whether they are stale
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct ForexMetadata {
+    pub base_currency: common_enums::Currency,
+    /// The currencies having a rate against the base currency, sorted by their code
+    pub target_currencies: Vec<common_enums::Currency>,
+    /// The timestamp supplied when the rates were seeded
+    pub seeded_at: Option<f64>,
+}
+
+/// The codes, name and currencies of a country
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct CountryDetails {
+    pub code: CountryAlpha2,
+    pub alpha3_code: CountryAlpha3,
+    pub name: Country,
+    pub numeric_code: u32,
+    /// `None` for territories without a currency of their own
+    pub primary_currency: Option<Currency>,
+    pub currencies: &'static [Currency],
+}
+
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct ScopedKey {
+    pub key: DirKeyKind,
+    pub scopes: Vec<KeyScope>,
+}
+
+/// Everything the dashboard needs to render the value input of a condition on a key
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct KeyMetadata {
+    pub kind: DirKeyKind,
+    pub key_type: String,
+    /// Whether the key can be compared using greater than or less than
+    pub supports_comparison: bool,
+    /// The allowed values, `None` for keys not holding enum values
+    pub variants: Option<&'static [&'static str]>,
+    pub category: Option<&'static str>,
+}
+
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DiagnosticSeverity {
+    /// The rule is invalid
+    Error,
+    /// The rule is valid, but likely to behave differently than intended
+    Warning,
+}
+
+/// A finding of the detailed validation of a routing program, scoped to the rule it was found in
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct RuleDiagnostic {
+    pub rule_index: usize,
+    pub rule_name: String,
+    /// The key of the offending condition, when the finding can be attributed to a single key
+    pub key: Option<DirKeyKind>,
+    pub severity: DiagnosticSeverity,
+    pub reason: String,
+}
+
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct ProgramValidation {
+    /// Whether none of the diagnostics is an error
+    pub is_valid: bool,
+    pub diagnostics: Vec<RuleDiagnostic>,
+}
+
+/// A comparison of a rule holding a value which is invalid for the type of its key
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct RuleValueError {
+    /// The indices of the statements leading to the comparison, the first index being that of
+    /// the top level statement of the rule, followed by the indices of its nested statements
+    pub statement_path: Vec<usize>,
+    /// The index of the comparison in the condition of the statement
+    pub condition_index: usize,
+    pub key: String,
+    pub value: ValueType,
+    pub reason: String,
+}
+
+/// The connectors valid for a rule of a program, or the error which prevented analyzing the rule
+#[derive(Serialize, Debug)]
+pub struct RuleValidConnectors {
+    pub rule_index: usize,
+    pub rule_name: String,
+    /// `None` when the rule could not be analyzed
+    pub valid_connectors: Option<Vec<ConnectorChoice>>,
+    pub error: Option<JsError>,
+}
+
+/// The outcome of executing a program on one of the inputs of a simulation
+#[derive(Serialize, Clone, Debug)]
+pub struct ProgramSimulation {
+    /// The index of the first rule matching the input, `None` if the default selection was used
+    pub rule_index: Option<usize>,
+    pub rule_name: Option<String>,
+    pub is_default_selection: bool,
+    pub connector_selection: ConnectorSelection,
+}
+
+/// The output of a surcharge decision program executed on an input, with the surcharge computed
+/// the way the server computes it when the payment is made
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct SurchargeProgramOutput {
+    /// The name of the matching rule, `None` if the default selection was used
+    pub rule_name: Option<String>,
+    pub surcharge_details: Option<SurchargeDetailsOutput>,
+    /// The surcharge on the amount of the input, in minor units of the currency of the input.
+    /// Rates are rounded up to the next minor unit.
+    pub surcharge_amount: MinorUnit,
+    /// The tax on the surcharge, in minor units of the currency of the input
+    pub tax_on_surcharge_amount: MinorUnit,
+}
+
+/// The decision of a three DS decision rule program executed on an input
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct ThreeDsDecisionProgramOutput {
+    /// The name of the matching rule, `None` if the default selection was used
+    pub rule_name: Option<String>,
+    pub decision: ThreeDSDecision,
+}
+
+/// The output of a payout routing program executed on a payout input
+#[cfg(feature = "payouts")]
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct PayoutProgramOutput {
+    /// The name of the matching rule, `None` if the default selection was used
+    pub rule_name: Option<String>,
+    pub connectors: Vec<PayoutConnectors>,
+}
+
+/// A finding of the analysis of a payout routing program which does not invalidate the program
+#[cfg(feature = "payouts")]
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum PayoutProgramWarning {
+    /// The payout knowledge graph has not been seeded, so the selected connectors could not be
+    /// validated against the merchant's payout connectors
+    KnowledgeGraphNotSeeded,
+    /// A connector selected by the rule, or by the default selection when the rule name is
+    /// `None`, is not one of the merchant's payout connectors
+    ConnectorNotConfigured {
+        rule_name: Option<String>,
+        connector: PayoutConnectors,
+    },
+}
+
+/// A constraint of the knowledge graph violated by the conditions of a rule, mapped from the
+/// analysis trace of the constraint graph
+#[derive(Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ConstraintViolation {
+    /// A value required by the constraint when the relation is positive, prohibited by it when
+    /// the relation is negative. `value` is `None` when the constraint is on any value of the key.
+    Value {
+        key: DirKeyKind,
+        value: Option<DirValue>,
+        relation: Relation,
+        info: Option<&'static str>,
+        /// The violated constraints the value depends on
+        causes: Vec<ConstraintViolation>,
+    },
+    /// All of the constraints had to be satisfied
+    AllOf {
+        info: Option<&'static str>,
+        unsatisfied: Vec<ConstraintViolation>,
+    },
+    /// Any of the constraints had to be satisfied
+    AnyOf {
+        info: Option<&'static str>,
+        unsatisfied: Vec<ConstraintViolation>,
+    },
+    /// The value of the key had to be one of the expected values when the relation is positive,
+    /// none of them when the relation is negative
+    In {
+        expected: Vec<DirValue>,
+        found: Option<DirValue>,
+        relation: Relation,
+        info: Option<&'static str>,
+    },
+    Contradiction,
+}
+
+impl ConstraintViolation {
+    /// Maps the analysis trace, dropping the predecessors which are no longer alive. The
+    /// predecessors are only weakly referenced by the trace, so it has to be mapped before the
+    /// memoization of the analysis is cleared.
+    pub fn from_trace(trace: &AnalysisTrace<DirValue>) -> Self {
+        match trace {
+            AnalysisTrace::Value {
+                value,
+                relation,
+                predecessors,
+                info,
+                ..
+            } => {
+                let (key, value) = match value {
+                    NodeValue::Key(key) => (key.kind.clone(), None),
+                    NodeValue::Value(value) => (value.get_key().kind, Some(value.clone())),
+                };
+                let causes = match predecessors {
+                    Some(ValueTracePredecessor::Mandatory(predecessor)) => {
+                        Self::from_traces(std::slice::from_ref(predecessor.as_ref()))
+                    }
+                    Some(ValueTracePredecessor::OneOf(predecessors)) => {
+                        Self::from_traces(predecessors)
+                    }
+                    None => Vec::new(),
+                };
+
+                Self::Value {
+                    key,
+                    value,
+                    relation: *relation,
+                    info: *info,
+                    causes,
+                }
+            }
+            AnalysisTrace::AllAggregation {
+                unsatisfied, info, ..
+            } => Self::AllOf {
+                info: *info,
+                unsatisfied: Self::from_traces(unsatisfied),
+            },
+            AnalysisTrace::AnyAggregation {
+                unsatisfied, info, ..
+            } => Self::AnyOf {
+                info: *info,
+                unsatisfied: Self::from_traces(unsatisfied),
+            },
+            AnalysisTrace::InAggregation {
+                expected,
+                found,
+                relation,
+                info,
+                ..
+            } => Self::In {
+                expected: expected.clone(),
+                found: found.clone(),
+                relation: *relation,
+                info: *info,
+            },
+            AnalysisTrace::Contradiction { .. } => Self::Contradiction,
+        }
+    }
+
+    fn from_traces(traces: &[Weak<AnalysisTrace<DirValue>>]) -> Vec<Self> {
+        traces
+            .iter()
+            .filter_map(Weak::upgrade)
+            .map(|trace| Self::from_trace(&trace))
+            .collect()
+    }
+
+    /// Describes the first violated constraint which does not depend on another one, like
+    /// "does not support currency INR"
+    pub fn describe(&self) -> String {
+        if let Some(cause) = self.get_causes().first() {
+            return cause.describe();
+        }
+
+        match self {
+            Self::Value {
+                key,
+                value,
+                relation,
+                ..
+            } => match (relation, value) {
+                (Relation::Positive, Some(value)) => format!("requires {}", describe_value(value)),
+                (Relation::Positive, None) => format!("requires a value for {key}"),
+                (Relation::Negative, Some(value)) => {
+                    format!("does not support {}", describe_value(value))
+                }
+                (Relation::Negative, None) => format!("does not support any value for {key}"),
+            },
+            Self::In {
+                found: Some(found), ..
+            } => format!("does not support {}", describe_value(found)),
+            Self::In {
+                expected,
+                found: None,
+                ..
+            } => match expected.first() {
+                Some(value) => format!("requires a value for {}", value.get_key().kind),
+                None => "requires a value which can not be provided".to_string(),
+            },
+            Self::AllOf { .. } | Self::AnyOf { .. } => "has unsatisfied constraints".to_string(),
+            Self::Contradiction => "has contradicting constraints".to_string(),
+        }
+    }
+
+    fn get_causes(&self) -> &[Self] {
+        match self {
+            Self::Value { causes, .. } => causes,
+            Self::AllOf { unsatisfied, .. } | Self::AnyOf { unsatisfied, .. } => unsatisfied,
+            Self::In { .. } | Self::Contradiction => &[],
+        }
+    }
+}
+
+/// Describes a value as its key followed by its value, like "currency INR"
+fn describe_value(value: &DirValue) -> String {
+    let key = value.get_key().kind;
+    match serde_json::to_value(value)
+        .ok()
+        .and_then(|serialized| serialized.get("value").cloned())
+    {
+        Some(serde_json::Value::String(value)) => format!("{key} {value}"),
+        Some(value) => format!("{key} {value}"),
+        None => key.to_string(),
+    }
+}
+
+/// Whether one of the merchant's connectors is valid for a rule, along with the violated
+/// constraint of the knowledge graph when it is not
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct ConnectorValidity {
+    pub connector: ConnectorChoice,
+    pub valid: bool,
+    /// Why the connector is not valid, like "stripe does not support currency INR"
+    pub reason: Option<String>,
+    /// `None` when the connector is valid, or when the analysis failed without a trace
+    pub violation: Option<ConstraintViolation>,
+}
+
+/// A status which triggers a webhook, along with the type of the event sent
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct WebhookStatusEvent<S> {
+    pub status: S,
+    pub event_type: EventType,
+}
+
+/// The statuses of an event class which trigger a webhook
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum WebhookStatusEvents {
+    Payments(Vec<WebhookStatusEvent<IntentStatus>>),
+    Refunds(Vec<WebhookStatusEvent<RefundStatus>>),
+    Disputes(Vec<WebhookStatusEvent<DisputeStatus>>),
+    Mandates(Vec<WebhookStatusEvent<MandateStatus>>),
+    #[cfg(feature = "payouts")]
+    Payouts(Vec<WebhookStatusEvent<PayoutStatus>>),
+}
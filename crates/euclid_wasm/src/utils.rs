@@ -1,5 +1,251 @@
 use wasm_bindgen::prelude::*;
 
+/// Maximum number of context frames serialized for a single error, bounding the payload size
+/// of deeply nested analysis traces.
+pub const MAX_ERROR_FRAMES: usize = 16;
+
+/// Keys which carry a human readable description of a frame, in order of preference.
+const MESSAGE_KEYS: [&str; 3] = ["message", "reason", "info"];
+
+/// Structured error handed over to the frontend. Errors are serialized as tagged enums, so the
+/// chain of nested tagged values is flattened into `frames`, outermost first.
+#[derive(Debug, serde::Serialize)]
+pub struct JsError {
+    pub message: String,
+    pub code: Option<String>,
+    pub frames: Vec<serde_json::Value>,
+    pub truncated: bool,
+}
+
+impl JsError {
+    /// Builds the structured error from a serialized error value. Plain values (such as string
+    /// errors) carry no context and are handed back untouched.
+    pub fn from_value(value: serde_json::Value) -> Result<Self, serde_json::Value> {
+        let code = match &value {
+            serde_json::Value::Object(map) => map
+                .get("type")
+                .and_then(serde_json::Value::as_str)
+                .map(str::to_owned),
+            _ => return Err(value),
+        };
+
+        let mut frames = Vec::new();
+        let mut current = Some(value);
+        while let Some(value) = current.take() {
+            if frames.len() == MAX_ERROR_FRAMES {
+                current = Some(value);
+                break;
+            }
+            let (frame, next) = split_frame(value);
+            frames.push(frame);
+            current = next;
+        }
+
+        let message = frames
+            .iter()
+            .rev()
+            .find_map(frame_message)
+            .or_else(|| code.clone())
+            .unwrap_or_else(|| "Unknown error".to_string());
+
+        Ok(Self {
+            message,
+            code,
+            truncated: current.is_some(),
+            frames,
+        })
+    }
+
+    /// Builds the structured error of an error reported as part of a result rather than thrown.
+    /// Errors which are not serialized as tagged values keep their serialized form as the message.
+    pub fn from_error<E: serde::Serialize>(error: &E) -> Self {
+        let value = serde_json::to_value(error)
+            .unwrap_or_else(|error| serde_json::Value::String(error.to_string()));
+
+        Self::from_value(value).unwrap_or_else(|value| Self {
+            message: value
+                .as_str()
+                .map_or_else(|| value.to_string(), str::to_owned),
+            code: None,
+            frames: vec![value],
+            truncated: false,
+        })
+    }
+}
+
+/// Whether the value is a serialized tagged enum, i.e. an object carrying a `type` field.
+fn is_tagged(value: &serde_json::Value) -> bool {
+    value.as_object().is_some_and(is_tagged_map)
+}
+
+/// Splits a value into the frame describing it and the nested tagged value it wraps, if any.
+fn split_frame(value: serde_json::Value) -> (serde_json::Value, Option<serde_json::Value>) {
+    let serde_json::Value::Object(mut map) = value else {
+        return (value, None);
+    };
+
+    // Adjacently tagged enums keep their fields under a single content key, lift them up so
+    // that the frame reads as one flat object
+    if map.len() == 2 && map.contains_key("type") {
+        let content_key = map.keys().find(|key| *key != "type").cloned();
+        if let Some(content_key) = content_key {
+            match map.remove(&content_key) {
+                Some(serde_json::Value::Object(content)) if !is_tagged_map(&content) => {
+                    map.extend(content);
+                }
+                Some(content) => {
+                    map.insert(content_key, content);
+                }
+                None => {}
+            }
+        }
+    }
+
+    let nested_key = map
+        .iter()
+        .find(|(key, field)| {
+            *key != "type"
+                && (is_tagged(field)
+                    || field
+                        .as_array()
+                        .is_some_and(|elements| elements.iter().any(is_tagged)))
+        })
+        .map(|(key, _)| key.clone());
+
+    let next = nested_key
+        .and_then(|key| map.remove(&key))
+        .and_then(|nested| match nested {
+            serde_json::Value::Array(elements) => elements.into_iter().find(is_tagged),
+            other => Some(other),
+        });
+
+    (serde_json::Value::Object(map), next)
+}
+
+fn is_tagged_map(map: &serde_json::Map<String, serde_json::Value>) -> bool {
+    map.get("type").is_some_and(serde_json::Value::is_string)
+}
+
+fn frame_message(frame: &serde_json::Value) -> Option<String> {
+    let map = frame.as_object()?;
+    MESSAGE_KEYS
+        .iter()
+        .find_map(|key| map.get(*key).and_then(serde_json::Value::as_str))
+        .map(str::to_owned)
+}
+
+/// A field present in only one of the diffed values, `path` being the dot separated path of the
+/// field with array elements addressed by their index, like `metadata.card_brands[1]`
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct JsonFieldValue {
+    pub path: String,
+    pub value: serde_json::Value,
+}
+
+/// A field whose value differs between the diffed values. Both values are `None` when the field
+/// is redacted.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct JsonFieldChange {
+    pub path: String,
+    pub old: Option<serde_json::Value>,
+    pub new: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize)]
+pub struct JsonDiff {
+    pub added: Vec<JsonFieldValue>,
+    pub removed: Vec<JsonFieldValue>,
+    pub changed: Vec<JsonFieldChange>,
+}
+
+/// Recursively diffs two JSON values. Objects are diffed field by field and arrays element by
+/// element, a `null` field being considered absent. Any difference under a field named in
+/// `redacted_fields` is reported as a change of the field, without the values.
+pub fn diff_json(
+    old: &serde_json::Value,
+    new: &serde_json::Value,
+    redacted_fields: &[&str],
+) -> JsonDiff {
+    let mut diff = JsonDiff::default();
+    diff_json_values(
+        String::new(),
+        Some(old),
+        Some(new),
+        false,
+        redacted_fields,
+        &mut diff,
+    );
+    diff
+}
+
+fn diff_json_values(
+    path: String,
+    old: Option<&serde_json::Value>,
+    new: Option<&serde_json::Value>,
+    redacted: bool,
+    redacted_fields: &[&str],
+    diff: &mut JsonDiff,
+) {
+    let old = old.filter(|value| !value.is_null());
+    let new = new.filter(|value| !value.is_null());
+
+    match (old, new) {
+        (None, None) => {}
+        (Some(old), Some(new)) if old == new => {}
+        _ if redacted => diff.changed.push(JsonFieldChange {
+            path,
+            old: None,
+            new: None,
+        }),
+        (None, Some(new)) => diff.added.push(JsonFieldValue {
+            path,
+            value: new.clone(),
+        }),
+        (Some(old), None) => diff.removed.push(JsonFieldValue {
+            path,
+            value: old.clone(),
+        }),
+        (Some(serde_json::Value::Object(old)), Some(serde_json::Value::Object(new))) => {
+            let fields = old
+                .keys()
+                .chain(new.keys())
+                .collect::<std::collections::BTreeSet<_>>();
+            for field in fields {
+                let field_path = if path.is_empty() {
+                    field.clone()
+                } else {
+                    format!("{path}.{field}")
+                };
+                diff_json_values(
+                    field_path,
+                    old.get(field),
+                    new.get(field),
+                    redacted_fields.contains(&field.as_str()),
+                    redacted_fields,
+                    diff,
+                );
+            }
+        }
+        (Some(serde_json::Value::Array(old)), Some(serde_json::Value::Array(new))) => {
+            for index in 0..old.len().max(new.len()) {
+                diff_json_values(
+                    format!("{path}[{index}]"),
+                    old.get(index),
+                    new.get(index),
+                    false,
+                    redacted_fields,
+                    diff,
+                );
+            }
+        }
+        (Some(old), Some(new)) => diff.changed.push(JsonFieldChange {
+            path,
+            old: Some(old.clone()),
+            new: Some(new.clone()),
+        }),
+    }
+}
+
 pub trait JsResultExt<T> {
     fn err_to_js(self) -> Result<T, JsValue>;
 }
@@ -11,7 +257,164 @@ where
     fn err_to_js(self) -> Result<T, JsValue> {
         match self {
             Ok(t) => Ok(t),
-            Err(e) => Err(serde_wasm_bindgen::to_value(&e)?),
+            Err(e) => match serde_json::to_value(&e).map(JsError::from_value) {
+                Ok(Ok(error)) => Err(serde::Serialize::serialize(
+                    &error,
+                    &serde_wasm_bindgen::Serializer::json_compatible(),
+                )?),
+                Ok(Err(_)) | Err(_) => Err(serde_wasm_bindgen::to_value(&e)?),
+            },
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use std::collections::HashMap;
+
+    use euclid::{
+        dssa::{self, graph::CgraphExt},
+        frontend::dir::{self, enums as dir_enums},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_context_analysis_error_keeps_trace_frames() {
+        let metadata = HashMap::new();
+        let card_type = dir::DirValue::CardType(dir_enums::CardType::Credit);
+        let payment_method = dir::DirValue::PaymentMethod(dir_enums::PaymentMethod::Wallet);
+        let ctx = vec![
+            dssa::types::ContextValue::assertion(&card_type, &metadata),
+            dssa::types::ContextValue::assertion(&payment_method, &metadata),
+        ];
+        let mut memo = hyperswitch_constraint_graph::Memoization::new();
+
+        let error = dssa::truth::ANALYSIS_GRAPH
+            .perform_context_analysis(&ctx, &mut memo, None)
+            .expect_err("card type without card payment method");
+        let value = serde_json::to_value(&error).expect("serialized error");
+        let js_error = JsError::from_value(value).expect("structured error");
+
+        assert_eq!(js_error.code.as_deref(), Some("assertion_trace"));
+        assert!(js_error.frames.len() >= 2);
+        assert!(!js_error.truncated);
+    }
+
+    #[test]
+    fn test_plain_string_error_is_left_untouched() {
+        let value = serde_json::json!("Data not seeded");
+        assert_eq!(JsError::from_value(value.clone()).err(), Some(value));
+    }
+
+    #[test]
+    fn test_json_diff_of_nested_metadata() {
+        let old = serde_json::json!({
+            "disabled": false,
+            "metadata": {
+                "merchant_name": "Old Name",
+                "card_brands": ["visa", "mastercard"],
+                "google_pay": {
+                    "merchant_info": { "merchant_name": "Shop" },
+                    "terminal_uuid": null
+                },
+                "account_name": "primary"
+            }
+        });
+        let new = serde_json::json!({
+            "disabled": false,
+            "metadata": {
+                "merchant_name": "New Name",
+                "card_brands": ["visa"],
+                "google_pay": {
+                    "merchant_info": { "merchant_name": "Shop", "merchant_id": "m_1" },
+                    "terminal_uuid": "t_1"
+                }
+            }
+        });
+
+        assert_eq!(
+            diff_json(&old, &new, &[]),
+            JsonDiff {
+                added: vec![
+                    JsonFieldValue {
+                        path: "metadata.google_pay.merchant_info.merchant_id".to_string(),
+                        value: serde_json::json!("m_1"),
+                    },
+                    JsonFieldValue {
+                        path: "metadata.google_pay.terminal_uuid".to_string(),
+                        value: serde_json::json!("t_1"),
+                    },
+                ],
+                removed: vec![
+                    JsonFieldValue {
+                        path: "metadata.account_name".to_string(),
+                        value: serde_json::json!("primary"),
+                    },
+                    JsonFieldValue {
+                        path: "metadata.card_brands[1]".to_string(),
+                        value: serde_json::json!("mastercard"),
+                    },
+                ],
+                changed: vec![JsonFieldChange {
+                    path: "metadata.merchant_name".to_string(),
+                    old: Some(serde_json::json!("Old Name")),
+                    new: Some(serde_json::json!("New Name")),
+                }],
+            }
+        );
+        assert_eq!(diff_json(&new, &new, &[]), JsonDiff::default());
+    }
+
+    #[test]
+    fn test_json_diff_redacts_fields_without_values() {
+        let old = serde_json::json!({
+            "apple_pay": {
+                "session_token_data": { "certificate": "old", "display_name": "Shop" }
+            }
+        });
+        let new = serde_json::json!({
+            "apple_pay": {
+                "session_token_data": {
+                    "certificate": "new",
+                    "certificate_keys": { "key": "value" },
+                    "display_name": "Shop"
+                }
+            }
+        });
+
+        let diff = diff_json(&old, &new, &["certificate", "certificate_keys"]);
+        assert!(diff.added.is_empty());
+        assert!(diff.removed.is_empty());
+        assert_eq!(
+            diff.changed,
+            vec![
+                JsonFieldChange {
+                    path: "apple_pay.session_token_data.certificate".to_string(),
+                    old: None,
+                    new: None,
+                },
+                JsonFieldChange {
+                    path: "apple_pay.session_token_data.certificate_keys".to_string(),
+                    old: None,
+                    new: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_frames_are_bounded() {
+        let mut value = serde_json::json!({ "type": "leaf", "info": "innermost" });
+        for _ in 0..MAX_ERROR_FRAMES * 2 {
+            value = serde_json::json!({ "type": "wrapper", "inner": value });
+        }
+
+        let js_error = JsError::from_value(value).expect("structured error");
+        assert_eq!(js_error.code.as_deref(), Some("wrapper"));
+        assert_eq!(js_error.frames.len(), MAX_ERROR_FRAMES);
+        assert!(js_error.truncated);
+    }
+}
@@ -60,8 +60,8 @@ use hyperswitch_interfaces::{
         self,
         disputes::{AcceptDispute, DefendDispute, Dispute, SubmitEvidence},
         files::{FilePurpose, FileUpload, RetrieveFile, UploadFile},
-        CaptureSyncMethod, ConnectorCommon, ConnectorIntegration, ConnectorSpecifications,
-        ConnectorValidation,
+        CaptureSyncMethod, ConnectorAmountConversion, ConnectorCommon, ConnectorIntegration,
+        ConnectorSpecifications, ConnectorValidation,
     },
     configs::Connectors,
     consts::{NO_ERROR_CODE, NO_ERROR_MESSAGE},
@@ -110,6 +110,23 @@ impl Adyen {
         }
     }
 }
+impl ConnectorAmountConversion for Adyen {
+    type AuthorizeAmount = MinorUnit;
+    type RefundAmount = MinorUnit;
+
+    fn get_authorize_amount_converter(
+        &self,
+    ) -> &'static (dyn AmountConvertor<Output = MinorUnit> + Sync) {
+        self.amount_converter
+    }
+
+    fn get_refund_amount_converter(
+        &self,
+    ) -> &'static (dyn AmountConvertor<Output = MinorUnit> + Sync) {
+        self.amount_converter
+    }
+}
+
 impl ConnectorCommon for Adyen {
     fn id(&self) -> &'static str {
         "adyen"
@@ -858,7 +875,7 @@ impl ConnectorIntegration<Authorize, PaymentsAuthorizeData, PaymentsResponseData
         _connectors: &Connectors,
     ) -> CustomResult<RequestContent, errors::ConnectorError> {
         let amount = convert_amount(
-            self.amount_converter,
+            self.get_authorize_amount_converter(),
             req.request.minor_amount,
             req.request.currency,
         )?;
@@ -1636,7 +1653,7 @@ impl ConnectorIntegration<Execute, RefundsData, RefundsResponseData> for Adyen {
         _connectors: &Connectors,
     ) -> CustomResult<RequestContent, errors::ConnectorError> {
         let refund_amount = convert_amount(
-            self.amount_converter,
+            self.get_refund_amount_converter(),
             req.request.minor_refund_amount,
             req.request.currency,
         )?;
@@ -3044,3 +3061,13 @@ impl ConnectorSpecifications for Adyen {
         Some(ADYEN_SUPPORTED_WEBHOOK_FLOWS)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_authorize_and_refund_amount_conversion_consistency() {
+        crate::utils::assert_amount_conversion_consistency(Adyen::new());
+    }
+}
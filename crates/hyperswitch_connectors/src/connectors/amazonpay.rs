@@ -1,56 +1,323 @@
 pub mod transformers;
 
+use std::{
+    collections::HashMap,
+    sync::{LazyLock, Mutex},
+};
+
+use base64::Engine;
+use common_enums::enums;
 use common_utils::{
+    consts,
     errors::CustomResult,
     ext_traits::BytesExt,
     request::{Method, Request, RequestBuilder, RequestContent},
-    types::{AmountConvertor, StringMinorUnit, StringMinorUnitForConnector},
+    types::{AmountConvertor, MinorUnit, StringMajorUnit, StringMajorUnitForConnector},
 };
 use error_stack::{report, ResultExt};
 use hyperswitch_domain_models::{
-    router_data::{AccessToken, ConnectorAuthType, ErrorResponse, RouterData},
+    payment_method_data::PaymentMethodData,
+    router_data::{AccessToken, ErrorResponse, RouterData},
     router_flow_types::{
         access_token_auth::AccessTokenAuth,
-        payments::{Authorize, Capture, PSync, PaymentMethodToken, Session, SetupMandate, Void},
+        mandate_revoke::MandateRevoke,
+        payments::{
+            Authorize, Capture, CompleteAuthorize, PSync, PaymentMethodToken, Session,
+            SetupMandate, Void,
+        },
         refunds::{Execute, RSync},
     },
     router_request_types::{
-        AccessTokenRequestData, PaymentMethodTokenizationData, PaymentsAuthorizeData,
-        PaymentsCancelData, PaymentsCaptureData, PaymentsSessionData, PaymentsSyncData,
-        RefundsData, SetupMandateRequestData,
+        AccessTokenRequestData, CompleteAuthorizeData, MandateRevokeRequestData,
+        PaymentMethodTokenizationData, PaymentsAuthorizeData, PaymentsCancelData,
+        PaymentsCaptureData, PaymentsSessionData, PaymentsSyncData, RefundsData,
+        SetupMandateRequestData,
+    },
+    router_response_types::{
+        ConnectorInfo, MandateRevokeResponseData, PaymentMethodDetails, PaymentsResponseData,
+        RefundsResponseData, SupportedPaymentMethods, SupportedPaymentMethodsExt,
     },
-    router_response_types::{PaymentsResponseData, RefundsResponseData},
     types::{
-        PaymentsAuthorizeRouterData, PaymentsCaptureRouterData, PaymentsSyncRouterData,
-        RefundSyncRouterData, RefundsRouterData,
+        MandateRevokeRouterData, PaymentsAuthorizeRouterData, PaymentsCancelRouterData,
+        PaymentsCaptureRouterData, PaymentsCompleteAuthorizeRouterData, PaymentsSessionRouterData,
+        PaymentsSyncRouterData, RefundSyncRouterData, RefundsRouterData, SetupMandateRouterData,
     },
 };
 use hyperswitch_interfaces::{
     api::{
-        self, ConnectorCommon, ConnectorCommonExt, ConnectorIntegration, ConnectorSpecifications,
-        ConnectorValidation,
+        self, ConnectorAmountConversion, ConnectorCommon, ConnectorCommonExt, ConnectorIntegration,
+        ConnectorSpecifications, ConnectorValidation,
     },
     configs::Connectors,
+    disputes::DisputePayload,
     errors,
     events::connector_api_logs::ConnectorEvent,
     types::{self, Response},
     webhooks,
 };
-use masking::{ExposeInterface, Mask};
+use masking::{ExposeInterface, Mask, PeekInterface, Secret};
+use openssl::{
+    hash::MessageDigest,
+    pkey::{PKey, Private},
+    rsa::Padding,
+    sign::{RsaPssSaltlen, Signer},
+};
+use ring::digest;
+use time::{format_description, OffsetDateTime};
 use transformers as amazonpay;
+use url::Url;
 
-use crate::{constants::headers, types::ResponseRouterData, utils};
+use crate::{
+    constants::headers,
+    types::ResponseRouterData,
+    utils::{
+        self, PaymentMethodDataType, PaymentsAuthorizeRequestData, PaymentsSyncRequestData,
+        RevokeMandateRequestData,
+    },
+};
+
+pub const AMAZONPAY_SIGNATURE_ALGORITHM: &str = "AMZN-PAY-RSASSA-PSS-V2";
+const AMAZONPAY_SIGNATURE_SALT_LENGTH: u32 = 20;
+
+/// Maximum number of merchant accounts whose parsed private key is kept in memory
+const AMAZONPAY_PRIVATE_KEY_CACHE_CAPACITY: usize = 64;
+
+/// Private keys the requests are signed with, keyed by the SHA-256 hash of their PEM so that the
+/// PEM is parsed once per key instead of once per request, and is not kept in memory itself
+static AMAZONPAY_PRIVATE_KEYS: LazyLock<Mutex<HashMap<Vec<u8>, PKey<Private>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
 
 #[derive(Clone)]
 pub struct Amazonpay {
-    amount_converter: &'static (dyn AmountConvertor<Output = StringMinorUnit> + Sync),
+    amount_converter: &'static (dyn AmountConvertor<Output = StringMajorUnit> + Sync),
 }
 
 impl Amazonpay {
     pub fn new() -> &'static Self {
         &Self {
-            amount_converter: &StringMinorUnitForConnector,
+            amount_converter: &StringMajorUnitForConnector,
+        }
+    }
+
+    /// Builds the canonical request which is signed for Amazon Pay, the signed headers are
+    /// expected to be lowercase and sorted by name
+    pub fn get_canonical_request(
+        http_method: Method,
+        url: &Url,
+        signed_headers: &[(&str, String)],
+        payload: &str,
+    ) -> String {
+        let canonical_uri = Self::get_canonical_uri(url);
+        let canonical_query_string = Self::get_canonical_query_string(url);
+        let canonical_headers = signed_headers
+            .iter()
+            .map(|(name, value)| format!("{name}:{value}\n"))
+            .collect::<String>();
+        let signed_header_names = Self::get_signed_header_names(signed_headers);
+        let hashed_payload = hex::encode(digest::digest(&digest::SHA256, payload.as_bytes()));
+        format!(
+            "{http_method}\n{canonical_uri}\n{canonical_query_string}\n{canonical_headers}\n{signed_header_names}\n{hashed_payload}"
+        )
+    }
+
+    /// The path of the url with every segment URI-encoded, segments which are already
+    /// percent-encoded are decoded first so that they are not encoded twice
+    fn get_canonical_uri(url: &Url) -> String {
+        url.path()
+            .split('/')
+            .map(|segment| {
+                let segment = urlencoding::decode(segment)
+                    .map(|decoded| decoded.into_owned())
+                    .unwrap_or_else(|_| segment.to_string());
+                urlencoding::encode(&segment).into_owned()
+            })
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    /// The query parameters of the url URI-encoded and sorted by name, then by value
+    fn get_canonical_query_string(url: &Url) -> String {
+        let mut query_parameters = url
+            .query_pairs()
+            .map(|(name, value)| {
+                (
+                    urlencoding::encode(&name).into_owned(),
+                    urlencoding::encode(&value).into_owned(),
+                )
+            })
+            .collect::<Vec<_>>();
+        query_parameters.sort();
+        query_parameters
+            .iter()
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+
+    /// Builds the endpoint of the API for the region and the environment of the request, by
+    /// calling the host of the region and prefixing the path of the base url with the environment
+    pub fn build_env_specific_endpoint(
+        base_url: &str,
+        region: amazonpay::AmazonpayRegion,
+        environment: amazonpay::AmazonpayEnvironment,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let mut url = Url::parse(base_url).change_context(
+            errors::ConnectorError::InvalidConnectorConfig {
+                config: "amazonpay.base_url",
+            },
+        )?;
+        url.set_host(Some(region.host())).change_context(
+            errors::ConnectorError::InvalidConnectorConfig {
+                config: "amazonpay.base_url",
+            },
+        )?;
+        let path = format!("/{environment}{}", url.path().trim_end_matches('/'));
+        url.set_path(&path);
+        Ok(url.to_string())
+    }
+
+    fn get_endpoint<Flow, Request, Response>(
+        &self,
+        req: &RouterData<Flow, Request, Response>,
+        connectors: &Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let auth = amazonpay::AmazonpayAuthType::try_from(&req.connector_auth_type)?;
+        Self::build_env_specific_endpoint(
+            self.base_url(connectors),
+            auth.region,
+            amazonpay::AmazonpayEnvironment::from_test_mode(req.test_mode),
+        )
+    }
+
+    /// The headers signed for Amazon Pay, the host and region must be those of the called
+    /// endpoint for the signature to be accepted
+    pub fn get_signed_headers(
+        content_type: &str,
+        date: &str,
+        host: &str,
+        region: amazonpay::AmazonpayRegion,
+    ) -> [(&'static str, String); 5] {
+        [
+            ("accept", content_type.to_string()),
+            ("content-type", content_type.to_string()),
+            ("x-amz-pay-date", date.to_string()),
+            ("x-amz-pay-host", host.to_string()),
+            ("x-amz-pay-region", region.to_string()),
+        ]
+    }
+
+    fn get_signed_header_names(signed_headers: &[(&str, String)]) -> String {
+        signed_headers
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>()
+            .join(";")
+    }
+
+    /// Signs the canonical request with the private key of the merchant account, failing when
+    /// the request cannot be signed instead of sending an unsigned request to Amazon Pay
+    pub fn create_authorization_header(
+        &self,
+        auth: &amazonpay::AmazonpayAuthType,
+        canonical_request: &str,
+        signed_headers: &[(&str, String)],
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let signature = self
+            .generate_signature(&auth.private_key, canonical_request)
+            .attach_printable("Failed to sign the Amazon Pay request")?;
+        Ok(format!(
+            "{AMAZONPAY_SIGNATURE_ALGORITHM} PublicKeyId={}, SignedHeaders={}, Signature={signature}",
+            auth.public_key_id.peek(),
+            Self::get_signed_header_names(signed_headers),
+        ))
+    }
+
+    pub fn generate_signature(
+        &self,
+        private_key: &Secret<String>,
+        canonical_request: &str,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let hashed_request = hex::encode(digest::digest(
+            &digest::SHA256,
+            canonical_request.as_bytes(),
+        ));
+        let string_to_sign = format!("{AMAZONPAY_SIGNATURE_ALGORITHM}\n{hashed_request}");
+
+        Self::sign(&Self::get_private_key(private_key)?, &string_to_sign)
+    }
+
+    fn parse_private_key(
+        private_key: &Secret<String>,
+    ) -> CustomResult<PKey<Private>, errors::ConnectorError> {
+        PKey::private_key_from_pem(private_key.peek().as_bytes())
+            .change_context(errors::ConnectorError::InvalidConnectorConfig {
+                config: "connector_account_details.api_secret",
+            })
+            .attach_printable("Private key is not a valid PEM encoded key")
+    }
+
+    /// The parsed private key, parsed only for the first request signed with the key of the
+    /// merchant account. Invalid keys are not cached, so that they keep failing every request.
+    fn get_private_key(
+        private_key: &Secret<String>,
+    ) -> CustomResult<PKey<Private>, errors::ConnectorError> {
+        let key_hash = digest::digest(&digest::SHA256, private_key.peek().as_bytes())
+            .as_ref()
+            .to_vec();
+        if let Some(cached_private_key) = AMAZONPAY_PRIVATE_KEYS
+            .lock()
+            .ok()
+            .and_then(|private_keys| private_keys.get(&key_hash).cloned())
+        {
+            return Ok(cached_private_key);
         }
+
+        let parsed_private_key = Self::parse_private_key(private_key)?;
+        if let Ok(mut private_keys) = AMAZONPAY_PRIVATE_KEYS.lock() {
+            if private_keys.len() >= AMAZONPAY_PRIVATE_KEY_CACHE_CAPACITY {
+                let evicted_key_hash = private_keys.keys().next().cloned();
+                if let Some(evicted_key_hash) = evicted_key_hash {
+                    private_keys.remove(&evicted_key_hash);
+                }
+            }
+            private_keys.insert(key_hash, parsed_private_key.clone());
+        }
+        Ok(parsed_private_key)
+    }
+
+    fn sign(
+        private_key: &PKey<Private>,
+        string_to_sign: &str,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let mut signer = Signer::new(MessageDigest::sha256(), private_key)
+            .change_context(errors::ConnectorError::RequestEncodingFailed)
+            .attach_printable("Failed to create the signer")?;
+        signer
+            .set_rsa_padding(Padding::PKCS1_PSS)
+            .change_context(errors::ConnectorError::RequestEncodingFailed)
+            .attach_printable("Private key does not support RSA-PSS padding")?;
+        signer
+            .set_rsa_pss_saltlen(RsaPssSaltlen::custom(AMAZONPAY_SIGNATURE_SALT_LENGTH))
+            .change_context(errors::ConnectorError::RequestEncodingFailed)
+            .attach_printable("Failed to set the RSA-PSS salt length")?;
+        signer
+            .update(string_to_sign.as_bytes())
+            .change_context(errors::ConnectorError::RequestEncodingFailed)
+            .attach_printable("Failed to hash the string to sign")?;
+        let signature = signer
+            .sign_to_vec()
+            .change_context(errors::ConnectorError::RequestEncodingFailed)
+            .attach_printable("Failed to sign the string to sign")?;
+
+        Ok(consts::BASE64_ENGINE.encode(signature))
+    }
+
+    pub fn get_current_date_time() -> CustomResult<String, errors::ConnectorError> {
+        let format = format_description::parse("[year][month][day]T[hour][minute][second]Z")
+            .change_context(errors::ConnectorError::InvalidDateFormat)?;
+        OffsetDateTime::now_utc()
+            .format(&format)
+            .change_context(errors::ConnectorError::InvalidDateFormat)
     }
 }
 
@@ -59,6 +326,7 @@ impl api::PaymentSession for Amazonpay {}
 impl api::ConnectorAccessToken for Amazonpay {}
 impl api::MandateSetup for Amazonpay {}
 impl api::PaymentAuthorize for Amazonpay {}
+impl api::PaymentsCompleteAuthorize for Amazonpay {}
 impl api::PaymentSync for Amazonpay {}
 impl api::PaymentCapture for Amazonpay {}
 impl api::PaymentVoid for Amazonpay {}
@@ -66,6 +334,7 @@ impl api::Refund for Amazonpay {}
 impl api::RefundExecute for Amazonpay {}
 impl api::RefundSync for Amazonpay {}
 impl api::PaymentToken for Amazonpay {}
+impl api::ConnectorMandateRevoke for Amazonpay {}
 
 impl ConnectorIntegration<PaymentMethodToken, PaymentMethodTokenizationData, PaymentsResponseData>
     for Amazonpay
@@ -80,15 +349,70 @@ where
     fn build_headers(
         &self,
         req: &RouterData<Flow, Request, Response>,
-        _connectors: &Connectors,
+        connectors: &Connectors,
     ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
-        let mut header = vec![(
-            headers::CONTENT_TYPE.to_string(),
-            self.get_content_type().to_string().into(),
-        )];
-        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
-        header.append(&mut api_key);
-        Ok(header)
+        let auth = amazonpay::AmazonpayAuthType::try_from(&req.connector_auth_type)?;
+        let http_method = self.get_http_method();
+        let url = Url::parse(&self.get_url(req, connectors)?)
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        let host = url
+            .host_str()
+            .ok_or(errors::ConnectorError::RequestEncodingFailed)?;
+        // Requests with a body, including the DELETE of a charge cancellation, are signed over
+        // the body
+        let payload = match http_method {
+            Method::Get => String::new(),
+            Method::Post | Method::Put | Method::Patch | Method::Delete => self
+                .get_request_body(req, connectors)?
+                .get_inner_value()
+                .expose(),
+        };
+        let date = Amazonpay::get_current_date_time()?;
+
+        let signed_headers =
+            Amazonpay::get_signed_headers(self.get_content_type(), &date, host, auth.region);
+        let canonical_request =
+            Amazonpay::get_canonical_request(http_method, &url, &signed_headers, &payload);
+        let authorization =
+            self.create_authorization_header(&auth, &canonical_request, &signed_headers)?;
+
+        Ok(vec![
+            (
+                headers::ACCEPT.to_string(),
+                self.common_get_content_type().to_string().into(),
+            ),
+            (
+                headers::CONTENT_TYPE.to_string(),
+                self.get_content_type().to_string().into(),
+            ),
+            (headers::X_AMZ_PAY_DATE.to_string(), date.into()),
+            (headers::X_AMZ_PAY_HOST.to_string(), host.to_string().into()),
+            (
+                headers::X_AMZ_PAY_REGION.to_string(),
+                auth.region.to_string().into(),
+            ),
+            (
+                headers::AUTHORIZATION.to_string(),
+                authorization.into_masked(),
+            ),
+        ])
+    }
+}
+
+impl ConnectorAmountConversion for Amazonpay {
+    type AuthorizeAmount = StringMajorUnit;
+    type RefundAmount = StringMajorUnit;
+
+    fn get_authorize_amount_converter(
+        &self,
+    ) -> &'static (dyn AmountConvertor<Output = StringMajorUnit> + Sync) {
+        self.amount_converter
+    }
+
+    fn get_refund_amount_converter(
+        &self,
+    ) -> &'static (dyn AmountConvertor<Output = StringMajorUnit> + Sync) {
+        self.amount_converter
     }
 }
 
@@ -105,72 +429,537 @@ impl ConnectorCommon for Amazonpay {
         //    if connector accepts amount in base unit (i.e dollars for USD) then return api::CurrencyUnit::Base
     }
 
-    fn common_get_content_type(&self) -> &'static str {
-        "application/json"
-    }
+    fn common_get_content_type(&self) -> &'static str {
+        "application/json"
+    }
+
+    fn base_url<'a>(&self, connectors: &'a Connectors) -> &'a str {
+        connectors.amazonpay.base_url.as_ref()
+    }
+
+    fn build_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        let response: Result<amazonpay::AmazonpayErrorResponse, _> =
+            res.response.parse_struct("AmazonpayErrorResponse");
+
+        match response {
+            Ok(response) => {
+                event_builder.map(|i| i.set_response_body(&response));
+                router_env::logger::info!(connector_response=?response);
+                Ok(response.into_error_response(res.status_code))
+            }
+            Err(error) => {
+                event_builder.map(|event| {
+                    event.set_error(serde_json::json!({
+                        "error": res.response.escape_ascii().to_string(),
+                        "status_code": res.status_code
+                    }))
+                });
+                router_env::logger::error!(deserialization_error=?error);
+                utils::handle_json_response_deserialization_failure(res, "amazonpay")
+            }
+        }
+    }
+}
+
+impl ConnectorValidation for Amazonpay {
+    fn validate_connector_against_payment_request(
+        &self,
+        capture_method: Option<enums::CaptureMethod>,
+        _payment_method: enums::PaymentMethod,
+        _pmt: Option<enums::PaymentMethodType>,
+    ) -> CustomResult<(), errors::ConnectorError> {
+        let capture_method = capture_method.unwrap_or_default();
+        if AMAZONPAY_SUPPORTED_CAPTURE_METHODS.contains(&capture_method) {
+            Ok(())
+        } else {
+            Err(utils::construct_not_supported_error_report(
+                capture_method,
+                self.id(),
+            ))
+        }
+    }
+
+    fn validate_mandate_payment(
+        &self,
+        pm_type: Option<enums::PaymentMethodType>,
+        pm_data: PaymentMethodData,
+    ) -> CustomResult<(), errors::ConnectorError> {
+        let mandate_supported_pmd =
+            std::collections::HashSet::from([PaymentMethodDataType::AmazonPayRedirect]);
+        utils::is_mandate_supported(pm_data, pm_type, mandate_supported_pmd, self.id())
+    }
+}
+
+impl Amazonpay {
+    /// Keeps the response of Amazon Pay, with the personal data of the buyer masked, to be
+    /// returned when the whole connector response is requested
+    fn with_masked_connector_response<F, Req, Resp>(
+        router_data: RouterData<F, Req, Resp>,
+        res: &Response,
+    ) -> RouterData<F, Req, Resp> {
+        RouterData {
+            whole_connector_response: amazonpay::mask_connector_response(&res.response),
+            ..router_data
+        }
+    }
+
+    /// Rejects the payments Amazon Pay would decline for their currency or amount, before any
+    /// request is sent to Amazon Pay
+    pub fn validate_amount_and_currency(
+        &self,
+        amount: MinorUnit,
+        currency: enums::Currency,
+    ) -> CustomResult<(), errors::ConnectorError> {
+        if !AMAZONPAY_SUPPORTED_CURRENCIES.contains(&currency) {
+            return Err(errors::ConnectorError::NotSupported {
+                message: format!("{currency} currency"),
+                connector: self.id(),
+            }
+            .into());
+        }
+        if amount.get_amount_as_i64() < AMAZONPAY_MINIMUM_AMOUNT {
+            return Err(errors::ConnectorError::NotSupported {
+                message: "Zero amount authorization".to_string(),
+                connector: self.id(),
+            }
+            .into());
+        }
+        Ok(())
+    }
+}
+
+impl Amazonpay {
+    fn get_checkout_session_request(
+        &self,
+        req: &PaymentsSessionRouterData,
+    ) -> CustomResult<amazonpay::AmazonpayCheckoutSessionRequest, errors::ConnectorError> {
+        let amount = utils::convert_amount(
+            self.get_authorize_amount_converter(),
+            req.request.minor_amount,
+            req.request.currency,
+        )?;
+        let connector_router_data = amazonpay::AmazonpayRouterData::from((amount, req));
+        amazonpay::AmazonpayCheckoutSessionRequest::try_from(&connector_router_data)
+    }
+}
+
+impl ConnectorIntegration<Session, PaymentsSessionData, PaymentsResponseData> for Amazonpay {
+    fn get_headers(
+        &self,
+        req: &PaymentsSessionRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
+        let mut headers = self.build_headers(req, connectors)?;
+        headers.push((
+            headers::X_AMZ_PAY_IDEMPOTENCY_KEY.to_string(),
+            amazonpay::get_idempotency_key(&req.connector_request_reference_id).into(),
+        ));
+        Ok(headers)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &PaymentsSessionRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}/checkoutSessions",
+            self.get_endpoint(req, connectors)?
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &PaymentsSessionRouterData,
+        _connectors: &Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        self.validate_amount_and_currency(req.request.minor_amount, req.request.currency)?;
+        let connector_req = self.get_checkout_session_request(req)?;
+        Ok(RequestContent::Json(Box::new(connector_req)))
+    }
+
+    fn build_request(
+        &self,
+        req: &PaymentsSessionRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Option<Request>, errors::ConnectorError> {
+        Ok(Some(
+            RequestBuilder::new()
+                .method(Method::Post)
+                .url(&types::PaymentsSessionType::get_url(self, req, connectors)?)
+                .attach_default_headers()
+                .headers(types::PaymentsSessionType::get_headers(
+                    self, req, connectors,
+                )?)
+                .set_body(types::PaymentsSessionType::get_request_body(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &PaymentsSessionRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<PaymentsSessionRouterData, errors::ConnectorError> {
+        let checkout_session: amazonpay::AmazonpayCreateCheckoutSessionResponse = res
+            .response
+            .parse_struct("amazonpay CreateCheckoutSessionResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        event_builder.map(|i| i.set_response_body(&checkout_session));
+        router_env::logger::info!(connector_response=?checkout_session);
+
+        // The button is rendered with the payload the checkout session was created with, signed
+        // the same way as the requests to Amazon Pay
+        let auth = amazonpay::AmazonpayAuthType::try_from(&data.connector_auth_type)?;
+        let payload = RequestContent::Json(Box::new(self.get_checkout_session_request(data)?))
+            .get_inner_value()
+            .expose();
+        let signature = self
+            .generate_signature(&auth.private_key, &payload)
+            .attach_printable("Failed to sign the Amazon Pay button payload")?;
+
+        RouterData::try_from(ResponseRouterData {
+            response: amazonpay::AmazonpaySessionTokenData {
+                checkout_session,
+                payload,
+                signature,
+            },
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+impl ConnectorIntegration<AccessTokenAuth, AccessTokenRequestData, AccessToken> for Amazonpay {}
+
+impl ConnectorIntegration<SetupMandate, SetupMandateRequestData, PaymentsResponseData>
+    for Amazonpay
+{
+    fn get_headers(
+        &self,
+        req: &SetupMandateRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
+        let mut headers = self.build_headers(req, connectors)?;
+        headers.push((
+            headers::X_AMZ_PAY_IDEMPOTENCY_KEY.to_string(),
+            amazonpay::get_idempotency_key(&req.connector_request_reference_id).into(),
+        ));
+        Ok(headers)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &SetupMandateRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}/checkoutSessions",
+            self.get_endpoint(req, connectors)?
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &SetupMandateRouterData,
+        _connectors: &Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let amount = utils::convert_amount(
+            self.get_authorize_amount_converter(),
+            req.request.minor_amount.unwrap_or_default(),
+            req.request.currency,
+        )?;
+        let connector_router_data = amazonpay::AmazonpayRouterData::from((amount, req));
+        let connector_req =
+            amazonpay::AmazonpayCheckoutSessionRequest::try_from(&connector_router_data)?;
+        Ok(RequestContent::Json(Box::new(connector_req)))
+    }
+
+    fn build_request(
+        &self,
+        req: &SetupMandateRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Option<Request>, errors::ConnectorError> {
+        Ok(Some(
+            RequestBuilder::new()
+                .method(Method::Post)
+                .url(&types::SetupMandateType::get_url(self, req, connectors)?)
+                .attach_default_headers()
+                .headers(types::SetupMandateType::get_headers(self, req, connectors)?)
+                .set_body(types::SetupMandateType::get_request_body(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &SetupMandateRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<SetupMandateRouterData, errors::ConnectorError> {
+        let response: amazonpay::AmazonpayCreateCheckoutSessionResponse = res
+            .response
+            .parse_struct("amazonpay CreateCheckoutSessionResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+        RouterData::try_from(ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+impl ConnectorIntegration<MandateRevoke, MandateRevokeRequestData, MandateRevokeResponseData>
+    for Amazonpay
+{
+    fn get_headers(
+        &self,
+        req: &MandateRevokeRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_http_method(&self) -> Method {
+        Method::Delete
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &MandateRevokeRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}/chargePermissions/{}/close",
+            self.get_endpoint(req, connectors)?,
+            req.request.get_connector_mandate_id()?
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &MandateRevokeRouterData,
+        _connectors: &Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let connector_req = amazonpay::AmazonpayCloseChargePermissionRequest::try_from(req)?;
+        Ok(RequestContent::Json(Box::new(connector_req)))
+    }
+
+    fn build_request(
+        &self,
+        req: &MandateRevokeRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Option<Request>, errors::ConnectorError> {
+        Ok(Some(
+            RequestBuilder::new()
+                .method(Method::Delete)
+                .url(&types::MandateRevokeType::get_url(self, req, connectors)?)
+                .attach_default_headers()
+                .headers(types::MandateRevokeType::get_headers(
+                    self, req, connectors,
+                )?)
+                .set_body(types::MandateRevokeType::get_request_body(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &MandateRevokeRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<MandateRevokeRouterData, errors::ConnectorError> {
+        let response: amazonpay::AmazonpayChargePermissionResponse = res
+            .response
+            .parse_struct("amazonpay ChargePermissionResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+        RouterData::try_from(ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+impl ConnectorIntegration<Authorize, PaymentsAuthorizeData, PaymentsResponseData> for Amazonpay {
+    fn get_headers(
+        &self,
+        req: &PaymentsAuthorizeRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
+        let mut headers = self.build_headers(req, connectors)?;
+        headers.push((
+            headers::X_AMZ_PAY_IDEMPOTENCY_KEY.to_string(),
+            amazonpay::get_idempotency_key(&req.connector_request_reference_id).into(),
+        ));
+        Ok(headers)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &PaymentsAuthorizeRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        // Payments initiated by the merchant are charged on the charge permission of the mandate,
+        // the other payments are charged once the buyer approves their checkout session
+        match req.request.connector_mandate_id() {
+            Some(_) => Ok(format!("{}/charges", self.get_endpoint(req, connectors)?)),
+            None => Ok(format!(
+                "{}/checkoutSessions",
+                self.get_endpoint(req, connectors)?
+            )),
+        }
+    }
+
+    fn get_request_body(
+        &self,
+        req: &PaymentsAuthorizeRouterData,
+        _connectors: &Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        self.validate_amount_and_currency(req.request.minor_amount, req.request.currency)?;
+        let amount = utils::convert_amount(
+            self.get_authorize_amount_converter(),
+            req.request.minor_amount,
+            req.request.currency,
+        )?;
 
-    fn base_url<'a>(&self, connectors: &'a Connectors) -> &'a str {
-        connectors.amazonpay.base_url.as_ref()
+        let connector_router_data = amazonpay::AmazonpayRouterData::from((amount, req));
+        if req.request.connector_mandate_id().is_some() {
+            let connector_req =
+                amazonpay::AmazonpayChargeRequest::try_from(&connector_router_data)?;
+            return Ok(RequestContent::Json(Box::new(connector_req)));
+        }
+        let connector_req =
+            amazonpay::AmazonpayCheckoutSessionRequest::try_from(&connector_router_data)?;
+        Ok(RequestContent::Json(Box::new(connector_req)))
     }
 
-    fn get_auth_header(
+    fn build_request(
         &self,
-        auth_type: &ConnectorAuthType,
-    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
-        let auth = amazonpay::AmazonpayAuthType::try_from(auth_type)
-            .change_context(errors::ConnectorError::FailedToObtainAuthType)?;
-        Ok(vec![(
-            headers::AUTHORIZATION.to_string(),
-            auth.api_key.expose().into_masked(),
-        )])
+        req: &PaymentsAuthorizeRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Option<Request>, errors::ConnectorError> {
+        Ok(Some(
+            RequestBuilder::new()
+                .method(Method::Post)
+                .url(&types::PaymentsAuthorizeType::get_url(
+                    self, req, connectors,
+                )?)
+                .attach_default_headers()
+                .headers(types::PaymentsAuthorizeType::get_headers(
+                    self, req, connectors,
+                )?)
+                .set_body(types::PaymentsAuthorizeType::get_request_body(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
     }
 
-    fn build_error_response(
+    fn handle_response(
         &self,
-        res: Response,
+        data: &PaymentsAuthorizeRouterData,
         event_builder: Option<&mut ConnectorEvent>,
-    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
-        let response: amazonpay::AmazonpayErrorResponse = res
+        res: Response,
+    ) -> CustomResult<PaymentsAuthorizeRouterData, errors::ConnectorError> {
+        if data.request.connector_mandate_id().is_some() {
+            let response: amazonpay::AmazonpayChargeResponse = res
+                .response
+                .parse_struct("amazonpay ChargeResponse")
+                .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+            event_builder.map(|i| i.set_response_body(&response));
+            router_env::logger::info!(connector_response=?response);
+            return RouterData::try_from(ResponseRouterData {
+                response,
+                data: data.clone(),
+                http_code: res.status_code,
+            })
+            .map(|router_data| Self::with_masked_connector_response(router_data, &res));
+        }
+        let response: amazonpay::AmazonpayCreateCheckoutSessionResponse = res
             .response
-            .parse_struct("AmazonpayErrorResponse")
+            .parse_struct("amazonpay CreateCheckoutSessionResponse")
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
-
         event_builder.map(|i| i.set_response_body(&response));
         router_env::logger::info!(connector_response=?response);
-
-        Ok(ErrorResponse {
-            status_code: res.status_code,
-            code: response.code,
-            message: response.message,
-            reason: response.reason,
-            attempt_status: None,
-            connector_transaction_id: None,
-            network_advice_code: None,
-            network_decline_code: None,
-            network_error_message: None,
+        RouterData::try_from(ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
         })
+        .map(|router_data| Self::with_masked_connector_response(router_data, &res))
     }
-}
-
-impl ConnectorValidation for Amazonpay {
-    //TODO: implement functions when support enabled
-}
 
-impl ConnectorIntegration<Session, PaymentsSessionData, PaymentsResponseData> for Amazonpay {
-    //TODO: implement sessions flow
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
 }
 
-impl ConnectorIntegration<AccessTokenAuth, AccessTokenRequestData, AccessToken> for Amazonpay {}
-
-impl ConnectorIntegration<SetupMandate, SetupMandateRequestData, PaymentsResponseData>
+impl ConnectorIntegration<CompleteAuthorize, CompleteAuthorizeData, PaymentsResponseData>
     for Amazonpay
 {
-}
-
-impl ConnectorIntegration<Authorize, PaymentsAuthorizeData, PaymentsResponseData> for Amazonpay {
     fn get_headers(
         &self,
-        req: &PaymentsAuthorizeRouterData,
+        req: &PaymentsCompleteAuthorizeRouterData,
         connectors: &Connectors,
     ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
         self.build_headers(req, connectors)
@@ -182,44 +971,48 @@ impl ConnectorIntegration<Authorize, PaymentsAuthorizeData, PaymentsResponseData
 
     fn get_url(
         &self,
-        _req: &PaymentsAuthorizeRouterData,
-        _connectors: &Connectors,
+        req: &PaymentsCompleteAuthorizeRouterData,
+        connectors: &Connectors,
     ) -> CustomResult<String, errors::ConnectorError> {
-        Err(errors::ConnectorError::NotImplemented("get_url method".to_string()).into())
+        let checkout_session_id = amazonpay::get_checkout_session_id(&req.request)?;
+        Ok(format!(
+            "{}/checkoutSessions/{checkout_session_id}/complete",
+            self.get_endpoint(req, connectors)?
+        ))
     }
 
     fn get_request_body(
         &self,
-        req: &PaymentsAuthorizeRouterData,
+        req: &PaymentsCompleteAuthorizeRouterData,
         _connectors: &Connectors,
     ) -> CustomResult<RequestContent, errors::ConnectorError> {
         let amount = utils::convert_amount(
-            self.amount_converter,
+            self.get_authorize_amount_converter(),
             req.request.minor_amount,
             req.request.currency,
         )?;
-
         let connector_router_data = amazonpay::AmazonpayRouterData::from((amount, req));
-        let connector_req = amazonpay::AmazonpayPaymentsRequest::try_from(&connector_router_data)?;
+        let connector_req =
+            amazonpay::AmazonpayCompleteCheckoutSessionRequest::try_from(&connector_router_data)?;
         Ok(RequestContent::Json(Box::new(connector_req)))
     }
 
     fn build_request(
         &self,
-        req: &PaymentsAuthorizeRouterData,
+        req: &PaymentsCompleteAuthorizeRouterData,
         connectors: &Connectors,
     ) -> CustomResult<Option<Request>, errors::ConnectorError> {
         Ok(Some(
             RequestBuilder::new()
                 .method(Method::Post)
-                .url(&types::PaymentsAuthorizeType::get_url(
+                .url(&types::PaymentsCompleteAuthorizeType::get_url(
                     self, req, connectors,
                 )?)
                 .attach_default_headers()
-                .headers(types::PaymentsAuthorizeType::get_headers(
+                .headers(types::PaymentsCompleteAuthorizeType::get_headers(
                     self, req, connectors,
                 )?)
-                .set_body(types::PaymentsAuthorizeType::get_request_body(
+                .set_body(types::PaymentsCompleteAuthorizeType::get_request_body(
                     self, req, connectors,
                 )?)
                 .build(),
@@ -228,13 +1021,13 @@ impl ConnectorIntegration<Authorize, PaymentsAuthorizeData, PaymentsResponseData
 
     fn handle_response(
         &self,
-        data: &PaymentsAuthorizeRouterData,
+        data: &PaymentsCompleteAuthorizeRouterData,
         event_builder: Option<&mut ConnectorEvent>,
         res: Response,
-    ) -> CustomResult<PaymentsAuthorizeRouterData, errors::ConnectorError> {
-        let response: amazonpay::AmazonpayPaymentsResponse = res
+    ) -> CustomResult<PaymentsCompleteAuthorizeRouterData, errors::ConnectorError> {
+        let response: amazonpay::AmazonpayCheckoutSessionResponse = res
             .response
-            .parse_struct("Amazonpay PaymentsAuthorizeResponse")
+            .parse_struct("amazonpay CheckoutSessionResponse")
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
         event_builder.map(|i| i.set_response_body(&response));
         router_env::logger::info!(connector_response=?response);
@@ -243,6 +1036,7 @@ impl ConnectorIntegration<Authorize, PaymentsAuthorizeData, PaymentsResponseData
             data: data.clone(),
             http_code: res.status_code,
         })
+        .map(|router_data| Self::with_masked_connector_response(router_data, &res))
     }
 
     fn get_error_response(
@@ -269,10 +1063,19 @@ impl ConnectorIntegration<PSync, PaymentsSyncData, PaymentsResponseData> for Ama
 
     fn get_url(
         &self,
-        _req: &PaymentsSyncRouterData,
-        _connectors: &Connectors,
+        req: &PaymentsSyncRouterData,
+        connectors: &Connectors,
     ) -> CustomResult<String, errors::ConnectorError> {
-        Err(errors::ConnectorError::NotImplemented("get_url method".to_string()).into())
+        let connector_transaction_id = req.request.get_connector_transaction_id()?;
+        let resource_type = amazonpay::AmazonpayResourceType::from_connector_transaction_id(
+            &connector_transaction_id,
+            req.request.connector_meta.as_ref(),
+        );
+        Ok(format!(
+            "{}/{}/{connector_transaction_id}",
+            self.get_endpoint(req, connectors)?,
+            resource_type.path_segment(),
+        ))
     }
 
     fn build_request(
@@ -296,17 +1099,40 @@ impl ConnectorIntegration<PSync, PaymentsSyncData, PaymentsResponseData> for Ama
         event_builder: Option<&mut ConnectorEvent>,
         res: Response,
     ) -> CustomResult<PaymentsSyncRouterData, errors::ConnectorError> {
-        let response: amazonpay::AmazonpayPaymentsResponse = res
-            .response
-            .parse_struct("amazonpay PaymentsSyncResponse")
-            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
-        event_builder.map(|i| i.set_response_body(&response));
-        router_env::logger::info!(connector_response=?response);
-        RouterData::try_from(ResponseRouterData {
-            response,
-            data: data.clone(),
-            http_code: res.status_code,
-        })
+        let connector_transaction_id = data.request.get_connector_transaction_id()?;
+        match amazonpay::AmazonpayResourceType::from_connector_transaction_id(
+            &connector_transaction_id,
+            data.request.connector_meta.as_ref(),
+        ) {
+            amazonpay::AmazonpayResourceType::Charge => {
+                let response: amazonpay::AmazonpayChargeResponse = res
+                    .response
+                    .parse_struct("amazonpay ChargeResponse")
+                    .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+                event_builder.map(|i| i.set_response_body(&response));
+                router_env::logger::info!(connector_response=?response);
+                RouterData::try_from(ResponseRouterData {
+                    response,
+                    data: data.clone(),
+                    http_code: res.status_code,
+                })
+                .map(|router_data| Self::with_masked_connector_response(router_data, &res))
+            }
+            amazonpay::AmazonpayResourceType::CheckoutSession => {
+                let response: amazonpay::AmazonpayCheckoutSessionResponse = res
+                    .response
+                    .parse_struct("amazonpay CheckoutSessionResponse")
+                    .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+                event_builder.map(|i| i.set_response_body(&response));
+                router_env::logger::info!(connector_response=?response);
+                RouterData::try_from(ResponseRouterData {
+                    response,
+                    data: data.clone(),
+                    http_code: res.status_code,
+                })
+                .map(|router_data| Self::with_masked_connector_response(router_data, &res))
+            }
+        }
     }
 
     fn get_error_response(
@@ -384,6 +1210,7 @@ impl ConnectorIntegration<Capture, PaymentsCaptureData, PaymentsResponseData> fo
             data: data.clone(),
             http_code: res.status_code,
         })
+        .map(|router_data| Self::with_masked_connector_response(router_data, &res))
     }
 
     fn get_error_response(
@@ -395,7 +1222,90 @@ impl ConnectorIntegration<Capture, PaymentsCaptureData, PaymentsResponseData> fo
     }
 }
 
-impl ConnectorIntegration<Void, PaymentsCancelData, PaymentsResponseData> for Amazonpay {}
+impl ConnectorIntegration<Void, PaymentsCancelData, PaymentsResponseData> for Amazonpay {
+    fn get_headers(
+        &self,
+        req: &PaymentsCancelRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_http_method(&self) -> Method {
+        Method::Delete
+    }
+
+    fn get_url(
+        &self,
+        req: &PaymentsCancelRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}/charges/{}/cancel",
+            self.get_endpoint(req, connectors)?,
+            req.request.connector_transaction_id
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &PaymentsCancelRouterData,
+        _connectors: &Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let connector_req = amazonpay::AmazonpayCancelRequest::try_from(req)?;
+        Ok(RequestContent::Json(Box::new(connector_req)))
+    }
+
+    fn build_request(
+        &self,
+        req: &PaymentsCancelRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Option<Request>, errors::ConnectorError> {
+        Ok(Some(
+            RequestBuilder::new()
+                .method(Method::Delete)
+                .url(&types::PaymentsVoidType::get_url(self, req, connectors)?)
+                .attach_default_headers()
+                .headers(types::PaymentsVoidType::get_headers(self, req, connectors)?)
+                .set_body(types::PaymentsVoidType::get_request_body(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &PaymentsCancelRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<PaymentsCancelRouterData, errors::ConnectorError> {
+        let response: amazonpay::AmazonpayCancelResponse = res
+            .response
+            .parse_struct("Amazonpay PaymentsCancelResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+        RouterData::try_from(ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .map(|router_data| Self::with_masked_connector_response(router_data, &res))
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
 
 impl ConnectorIntegration<Execute, RefundsData, RefundsResponseData> for Amazonpay {
     fn get_headers(
@@ -403,7 +1313,12 @@ impl ConnectorIntegration<Execute, RefundsData, RefundsResponseData> for Amazonp
         req: &RefundsRouterData<Execute>,
         connectors: &Connectors,
     ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
-        self.build_headers(req, connectors)
+        let mut headers = self.build_headers(req, connectors)?;
+        headers.push((
+            headers::X_AMZ_PAY_IDEMPOTENCY_KEY.to_string(),
+            amazonpay::get_idempotency_key(&req.request.refund_id).into(),
+        ));
+        Ok(headers)
     }
 
     fn get_content_type(&self) -> &'static str {
@@ -412,10 +1327,10 @@ impl ConnectorIntegration<Execute, RefundsData, RefundsResponseData> for Amazonp
 
     fn get_url(
         &self,
-        _req: &RefundsRouterData<Execute>,
-        _connectors: &Connectors,
+        req: &RefundsRouterData<Execute>,
+        connectors: &Connectors,
     ) -> CustomResult<String, errors::ConnectorError> {
-        Err(errors::ConnectorError::NotImplemented("get_url method".to_string()).into())
+        Ok(format!("{}/refunds", self.get_endpoint(req, connectors)?))
     }
 
     fn get_request_body(
@@ -424,7 +1339,7 @@ impl ConnectorIntegration<Execute, RefundsData, RefundsResponseData> for Amazonp
         _connectors: &Connectors,
     ) -> CustomResult<RequestContent, errors::ConnectorError> {
         let refund_amount = utils::convert_amount(
-            self.amount_converter,
+            self.get_refund_amount_converter(),
             req.request.minor_refund_amount,
             req.request.currency,
         )?;
@@ -470,6 +1385,7 @@ impl ConnectorIntegration<Execute, RefundsData, RefundsResponseData> for Amazonp
             data: data.clone(),
             http_code: res.status_code,
         })
+        .map(|router_data| Self::with_masked_connector_response(router_data, &res))
     }
 
     fn get_error_response(
@@ -494,12 +1410,25 @@ impl ConnectorIntegration<RSync, RefundsData, RefundsResponseData> for Amazonpay
         self.common_get_content_type()
     }
 
+    fn get_http_method(&self) -> Method {
+        Method::Get
+    }
+
     fn get_url(
         &self,
-        _req: &RefundSyncRouterData,
-        _connectors: &Connectors,
+        req: &RefundSyncRouterData,
+        connectors: &Connectors,
     ) -> CustomResult<String, errors::ConnectorError> {
-        Err(errors::ConnectorError::NotImplemented("get_url method".to_string()).into())
+        let connector_refund_id = req
+            .request
+            .connector_refund_id
+            .as_ref()
+            .filter(|connector_refund_id| !connector_refund_id.is_empty())
+            .ok_or(errors::ConnectorError::MissingConnectorRefundID)?;
+        Ok(format!(
+            "{}/refunds/{connector_refund_id}",
+            self.get_endpoint(req, connectors)?,
+        ))
     }
 
     fn build_request(
@@ -513,9 +1442,6 @@ impl ConnectorIntegration<RSync, RefundsData, RefundsResponseData> for Amazonpay
                 .url(&types::RefundSyncType::get_url(self, req, connectors)?)
                 .attach_default_headers()
                 .headers(types::RefundSyncType::get_headers(self, req, connectors)?)
-                .set_body(types::RefundSyncType::get_request_body(
-                    self, req, connectors,
-                )?)
                 .build(),
         ))
     }
@@ -537,6 +1463,7 @@ impl ConnectorIntegration<RSync, RefundsData, RefundsResponseData> for Amazonpay
             data: data.clone(),
             http_code: res.status_code,
         })
+        .map(|router_data| Self::with_masked_connector_response(router_data, &res))
     }
 
     fn get_error_response(
@@ -552,24 +1479,533 @@ impl ConnectorIntegration<RSync, RefundsData, RefundsResponseData> for Amazonpay
 impl webhooks::IncomingWebhook for Amazonpay {
     fn get_webhook_object_reference_id(
         &self,
-        _request: &webhooks::IncomingWebhookRequestDetails<'_>,
+        request: &webhooks::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<api_models::webhooks::ObjectReferenceId, errors::ConnectorError> {
-        Err(report!(errors::ConnectorError::WebhooksNotImplemented))
+        let message = amazonpay::AmazonpayWebhookMessage::from_webhook_body(request.body)?;
+        match message.object_type {
+            amazonpay::AmazonpayWebhookObjectType::Charge => {
+                Ok(api_models::webhooks::ObjectReferenceId::PaymentId(
+                    api_models::payments::PaymentIdType::ConnectorTransactionId(message.object_id),
+                ))
+            }
+            amazonpay::AmazonpayWebhookObjectType::Refund => {
+                Ok(api_models::webhooks::ObjectReferenceId::RefundId(
+                    api_models::webhooks::RefundIdType::ConnectorRefundId(message.object_id),
+                ))
+            }
+            amazonpay::AmazonpayWebhookObjectType::Dispute => {
+                Ok(api_models::webhooks::ObjectReferenceId::PaymentId(
+                    api_models::payments::PaymentIdType::ConnectorTransactionId(
+                        message.get_dispute()?.charge_id,
+                    ),
+                ))
+            }
+            amazonpay::AmazonpayWebhookObjectType::Unknown => {
+                Err(report!(errors::ConnectorError::WebhookReferenceIdNotFound))
+            }
+        }
     }
 
     fn get_webhook_event_type(
         &self,
-        _request: &webhooks::IncomingWebhookRequestDetails<'_>,
+        request: &webhooks::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<api_models::webhooks::IncomingWebhookEvent, errors::ConnectorError> {
-        Err(report!(errors::ConnectorError::WebhooksNotImplemented))
+        let message = amazonpay::AmazonpayWebhookMessage::from_webhook_body(request.body)?;
+        match message.object_type {
+            amazonpay::AmazonpayWebhookObjectType::Dispute => {
+                api_models::webhooks::IncomingWebhookEvent::try_from(
+                    &message.get_dispute()?.status_details,
+                )
+            }
+            amazonpay::AmazonpayWebhookObjectType::Charge
+            | amazonpay::AmazonpayWebhookObjectType::Refund
+            | amazonpay::AmazonpayWebhookObjectType::Unknown => {
+                Ok(api_models::webhooks::IncomingWebhookEvent::EventNotSupported)
+            }
+        }
     }
 
     fn get_webhook_resource_object(
         &self,
-        _request: &webhooks::IncomingWebhookRequestDetails<'_>,
+        request: &webhooks::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<Box<dyn masking::ErasedMaskSerialize>, errors::ConnectorError> {
-        Err(report!(errors::ConnectorError::WebhooksNotImplemented))
+        let message = amazonpay::AmazonpayWebhookMessage::from_webhook_body(request.body)
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+        Ok(Box::new(message))
+    }
+
+    fn get_dispute_details(
+        &self,
+        request: &webhooks::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<DisputePayload, errors::ConnectorError> {
+        amazonpay::AmazonpayWebhookMessage::from_webhook_body(request.body)?
+            .get_dispute()?
+            .try_into()
+    }
+}
+
+/// Capture methods supported by Amazon Pay, charges are captured when they are authorized until
+/// the capture flow is implemented
+const AMAZONPAY_SUPPORTED_CAPTURE_METHODS: [enums::CaptureMethod; 2] = [
+    enums::CaptureMethod::Automatic,
+    enums::CaptureMethod::SequentialAutomatic,
+];
+
+/// Presentment currencies supported by Amazon Pay across its regions
+const AMAZONPAY_SUPPORTED_CURRENCIES: [enums::Currency; 12] = [
+    enums::Currency::AUD,
+    enums::Currency::CHF,
+    enums::Currency::DKK,
+    enums::Currency::EUR,
+    enums::Currency::GBP,
+    enums::Currency::HKD,
+    enums::Currency::JPY,
+    enums::Currency::NOK,
+    enums::Currency::NZD,
+    enums::Currency::SEK,
+    enums::Currency::USD,
+    enums::Currency::ZAR,
+];
+
+/// Smallest amount in minor units Amazon Pay authorizes, as it does not support zero amount
+/// authorizations
+const AMAZONPAY_MINIMUM_AMOUNT: i64 = 1;
+
+static AMAZONPAY_SUPPORTED_PAYMENT_METHODS: LazyLock<SupportedPaymentMethods> =
+    LazyLock::new(|| {
+        let mut amazonpay_supported_payment_methods = SupportedPaymentMethods::new();
+
+        amazonpay_supported_payment_methods.add(
+            enums::PaymentMethod::Wallet,
+            enums::PaymentMethodType::AmazonPay,
+            PaymentMethodDetails {
+                mandates: enums::FeatureStatus::Supported,
+                refunds: enums::FeatureStatus::Supported,
+                supported_capture_methods: AMAZONPAY_SUPPORTED_CAPTURE_METHODS.to_vec(),
+                specific_features: None,
+            },
+        );
+
+        amazonpay_supported_payment_methods
+    });
+
+static AMAZONPAY_CONNECTOR_INFO: ConnectorInfo = ConnectorInfo {
+    display_name: "Amazon Pay",
+    description: "Amazon Pay lets customers pay on third party websites and apps with the payment methods and addresses stored in their Amazon account.",
+    connector_type: enums::PaymentConnectorCategory::AlternativePaymentMethod,
+};
+
+static AMAZONPAY_SUPPORTED_WEBHOOK_FLOWS: [enums::EventClass; 1] = [enums::EventClass::Disputes];
+
+impl ConnectorSpecifications for Amazonpay {
+    fn get_connector_about(&self) -> Option<&'static ConnectorInfo> {
+        Some(&AMAZONPAY_CONNECTOR_INFO)
+    }
+
+    fn get_supported_payment_methods(&self) -> Option<&'static SupportedPaymentMethods> {
+        Some(&*AMAZONPAY_SUPPORTED_PAYMENT_METHODS)
+    }
+
+    fn get_supported_webhook_flows(&self) -> Option<&'static [enums::EventClass]> {
+        Some(&AMAZONPAY_SUPPORTED_WEBHOOK_FLOWS)
     }
 }
 
-impl ConnectorSpecifications for Amazonpay {}
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_authorize_and_refund_amount_conversion_consistency() {
+        utils::assert_amount_conversion_consistency(Amazonpay::new());
+    }
+
+    fn auth_type(private_key: String) -> amazonpay::AmazonpayAuthType {
+        amazonpay::AmazonpayAuthType::try_from(
+            &hyperswitch_domain_models::router_data::ConnectorAuthType::SignatureKey {
+                api_key: Secret::new("SANDBOX-PUBLIC_KEY_ID".to_string()),
+                key1: Secret::new("na".to_string()),
+                api_secret: Secret::new(private_key),
+            },
+        )
+        .unwrap()
+    }
+
+    fn signed_headers() -> [(&'static str, String); 5] {
+        Amazonpay::get_signed_headers(
+            "application/json",
+            "20250101T000000Z",
+            "pay-api.amazon.com",
+            amazonpay::AmazonpayRegion::Na,
+        )
+    }
+
+    #[test]
+    fn test_invalid_private_key_fails_signing() {
+        let auth = auth_type("not a private key".to_string());
+        let signed_headers = signed_headers();
+        let canonical_request = Amazonpay::get_canonical_request(
+            Method::Post,
+            &Url::parse("https://pay-api.amazon.com/sandbox/v2/refunds").unwrap(),
+            &signed_headers,
+            "{}",
+        );
+
+        let error = Amazonpay::new()
+            .create_authorization_header(&auth, &canonical_request, &signed_headers)
+            .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ConnectorError::InvalidConnectorConfig {
+                config: "connector_account_details.api_secret"
+            }
+        ));
+    }
+
+    #[test]
+    fn test_authorization_header_of_valid_private_key() {
+        let private_key = openssl::rsa::Rsa::generate(2048)
+            .and_then(PKey::from_rsa)
+            .and_then(|private_key| private_key.private_key_to_pem_pkcs8())
+            .unwrap();
+        let auth = auth_type(String::from_utf8(private_key).unwrap());
+        let signed_headers = signed_headers();
+        let canonical_request = Amazonpay::get_canonical_request(
+            Method::Post,
+            &Url::parse("https://pay-api.amazon.com/sandbox/v2/refunds").unwrap(),
+            &signed_headers,
+            "{}",
+        );
+
+        let authorization = Amazonpay::new()
+            .create_authorization_header(&auth, &canonical_request, &signed_headers)
+            .unwrap();
+        assert!(authorization.starts_with(
+            "AMZN-PAY-RSASSA-PSS-V2 PublicKeyId=SANDBOX-PUBLIC_KEY_ID, SignedHeaders=accept;content-type;x-amz-pay-date;x-amz-pay-host;x-amz-pay-region, Signature="
+        ));
+    }
+
+    fn verify_signature(
+        private_key: &PKey<Private>,
+        string_to_sign: &str,
+        signature: &str,
+    ) -> bool {
+        let signature = consts::BASE64_ENGINE.decode(signature).unwrap();
+        let mut verifier =
+            openssl::sign::Verifier::new(MessageDigest::sha256(), private_key).unwrap();
+        verifier.set_rsa_padding(Padding::PKCS1_PSS).unwrap();
+        verifier
+            .set_rsa_pss_saltlen(RsaPssSaltlen::custom(AMAZONPAY_SIGNATURE_SALT_LENGTH))
+            .unwrap();
+        verifier.update(string_to_sign.as_bytes()).unwrap();
+        verifier.verify(&signature).unwrap()
+    }
+
+    #[test]
+    fn test_cached_and_parsed_private_keys_sign_alike() {
+        let private_key = openssl::rsa::Rsa::generate(2048)
+            .and_then(PKey::from_rsa)
+            .and_then(|private_key| private_key.private_key_to_pem_pkcs8())
+            .map(|private_key| Secret::new(String::from_utf8(private_key).unwrap()))
+            .unwrap();
+        let canonical_request = "POST\n/sandbox/v2/refunds\n\naccept:application/json\n\naccept\n44136fa355b3678a1146ad16f7e8649e94fb4fc21fe77e8310c060f61caaff8a";
+        let string_to_sign = format!(
+            "{AMAZONPAY_SIGNATURE_ALGORITHM}\n{}",
+            hex::encode(digest::digest(
+                &digest::SHA256,
+                canonical_request.as_bytes()
+            ))
+        );
+
+        let parsed_private_key = Amazonpay::parse_private_key(&private_key).unwrap();
+        let cached_private_key = Amazonpay::get_private_key(&private_key).unwrap();
+        assert_eq!(
+            Amazonpay::get_private_key(&private_key)
+                .unwrap()
+                .private_key_to_der()
+                .unwrap(),
+            parsed_private_key.private_key_to_der().unwrap()
+        );
+        assert_eq!(
+            cached_private_key.private_key_to_der().unwrap(),
+            parsed_private_key.private_key_to_der().unwrap()
+        );
+
+        // RSA-PSS signatures are salted, so the signatures are compared by verifying them
+        let cached_signature = Amazonpay::new()
+            .generate_signature(&private_key, canonical_request)
+            .unwrap();
+        let uncached_signature = Amazonpay::sign(&parsed_private_key, &string_to_sign).unwrap();
+        assert!(verify_signature(
+            &parsed_private_key,
+            &string_to_sign,
+            &cached_signature
+        ));
+        assert!(verify_signature(
+            &parsed_private_key,
+            &string_to_sign,
+            &uncached_signature
+        ));
+        assert!(!verify_signature(
+            &parsed_private_key,
+            "AMZN-PAY-RSASSA-PSS-V2\nother request",
+            &cached_signature
+        ));
+    }
+
+    #[test]
+    fn test_non_json_error_response_is_reported_as_a_failure() {
+        let response = Response {
+            headers: None,
+            response: bytes::Bytes::from("<html><body>502 Bad Gateway</body></html>"),
+            status_code: 502,
+        };
+
+        let error_response = Amazonpay::new()
+            .build_error_response(response, None)
+            .unwrap();
+        assert_eq!(error_response.status_code, 502);
+        assert_eq!(
+            error_response.reason.as_deref(),
+            Some("<html><body>502 Bad Gateway</body></html>")
+        );
+        assert_eq!(error_response.attempt_status, None);
+    }
+
+    fn signed_header_value<'a>(signed_headers: &'a [(&str, String)], name: &str) -> &'a str {
+        signed_headers
+            .iter()
+            .find(|(header_name, _)| *header_name == name)
+            .map(|(_, value)| value.as_str())
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn test_sandbox_na_headers() {
+        let endpoint = Amazonpay::build_env_specific_endpoint(
+            "https://pay-api.amazon.com/v2",
+            amazonpay::AmazonpayRegion::Na,
+            amazonpay::AmazonpayEnvironment::Sandbox,
+        )
+        .unwrap();
+        assert_eq!(endpoint, "https://pay-api.amazon.com/sandbox/v2");
+
+        let url = Url::parse(&format!("{endpoint}/refunds")).unwrap();
+        let host = url.host_str().unwrap();
+        let signed_headers = Amazonpay::get_signed_headers(
+            "application/json",
+            "20250101T000000Z",
+            host,
+            amazonpay::AmazonpayRegion::Na,
+        );
+        assert_eq!(
+            signed_header_value(&signed_headers, "x-amz-pay-host"),
+            "pay-api.amazon.com"
+        );
+        assert_eq!(
+            signed_header_value(&signed_headers, "x-amz-pay-region"),
+            "na"
+        );
+
+        let canonical_request =
+            Amazonpay::get_canonical_request(Method::Post, &url, &signed_headers, "{}");
+        assert!(canonical_request.starts_with("POST\n/sandbox/v2/refunds\n"));
+        assert!(canonical_request.contains("x-amz-pay-host:pay-api.amazon.com\n"));
+    }
+
+    #[test]
+    fn test_live_eu_headers() {
+        let endpoint = Amazonpay::build_env_specific_endpoint(
+            "https://pay-api.amazon.com/v2",
+            amazonpay::AmazonpayRegion::Eu,
+            amazonpay::AmazonpayEnvironment::Live,
+        )
+        .unwrap();
+        assert_eq!(endpoint, "https://pay-api.amazon.eu/live/v2");
+
+        let url = Url::parse(&format!("{endpoint}/refunds/refund_id")).unwrap();
+        let host = url.host_str().unwrap();
+        let signed_headers = Amazonpay::get_signed_headers(
+            "application/json",
+            "20250101T000000Z",
+            host,
+            amazonpay::AmazonpayRegion::Eu,
+        );
+        assert_eq!(
+            signed_header_value(&signed_headers, "x-amz-pay-host"),
+            "pay-api.amazon.eu"
+        );
+        assert_eq!(
+            signed_header_value(&signed_headers, "x-amz-pay-region"),
+            "eu"
+        );
+
+        let canonical_request =
+            Amazonpay::get_canonical_request(Method::Get, &url, &signed_headers, "");
+        assert!(canonical_request.starts_with("GET\n/live/v2/refunds/refund_id\n"));
+        assert!(canonical_request.contains("x-amz-pay-host:pay-api.amazon.eu\n"));
+    }
+
+    fn canonical_request_of(url: &str) -> String {
+        Amazonpay::get_canonical_request(
+            Method::Get,
+            &Url::parse(url).unwrap(),
+            &signed_headers(),
+            "",
+        )
+    }
+
+    #[test]
+    fn test_canonical_request_without_query_string() {
+        assert_eq!(
+            canonical_request_of("https://pay-api.amazon.com/sandbox/v2/refunds/refund_id"),
+            "GET\n\
+             /sandbox/v2/refunds/refund_id\n\
+             \n\
+             accept:application/json\n\
+             content-type:application/json\n\
+             x-amz-pay-date:20250101T000000Z\n\
+             x-amz-pay-host:pay-api.amazon.com\n\
+             x-amz-pay-region:na\n\
+             \n\
+             accept;content-type;x-amz-pay-date;x-amz-pay-host;x-amz-pay-region\n\
+             e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+
+    #[test]
+    fn test_canonical_query_string_is_encoded_and_sorted() {
+        let canonical_request = canonical_request_of(
+            "https://pay-api.amazon.com/sandbox/v2/charges?status=Open&chargePermissionId=P01-1&note=a%20b%2Bc",
+        );
+        assert!(canonical_request.starts_with(
+            "GET\n/sandbox/v2/charges\nchargePermissionId=P01-1&note=a%20b%2Bc&status=Open\n"
+        ));
+    }
+
+    #[test]
+    fn test_canonical_uri_of_trailing_slash() {
+        let endpoint = Amazonpay::build_env_specific_endpoint(
+            "https://pay-api.amazon.com/v2/",
+            amazonpay::AmazonpayRegion::Na,
+            amazonpay::AmazonpayEnvironment::Sandbox,
+        )
+        .unwrap();
+        assert!(
+            canonical_request_of(&format!("{endpoint}/refunds/refund_id"))
+                .starts_with("GET\n/sandbox/v2/refunds/refund_id\n\n")
+        );
+        assert!(
+            canonical_request_of("https://pay-api.amazon.com/sandbox/v2/refunds/")
+                .starts_with("GET\n/sandbox/v2/refunds/\n\n")
+        );
+        assert!(canonical_request_of("https://pay-api.amazon.com").starts_with("GET\n/\n\n"));
+    }
+
+    #[test]
+    fn test_canonical_uri_of_percent_encoded_segment() {
+        assert!(canonical_request_of(
+            "https://pay-api.amazon.com/sandbox/v2/refunds/refund%20id%3A1"
+        )
+        .starts_with("GET\n/sandbox/v2/refunds/refund%20id%3A1\n\n"));
+        assert!(
+            canonical_request_of("https://pay-api.amazon.com/sandbox/v2/refunds/refund id:1")
+                .starts_with("GET\n/sandbox/v2/refunds/refund%20id%3A1\n\n")
+        );
+    }
+
+    #[test]
+    fn test_manual_capture_is_rejected() {
+        let connector = Amazonpay::new();
+        for capture_method in [
+            None,
+            Some(enums::CaptureMethod::Automatic),
+            Some(enums::CaptureMethod::SequentialAutomatic),
+        ] {
+            assert!(connector
+                .validate_connector_against_payment_request(
+                    capture_method,
+                    enums::PaymentMethod::Wallet,
+                    Some(enums::PaymentMethodType::AmazonPay),
+                )
+                .is_ok());
+        }
+
+        for capture_method in [
+            enums::CaptureMethod::Manual,
+            enums::CaptureMethod::ManualMultiple,
+            enums::CaptureMethod::Scheduled,
+        ] {
+            let error = connector
+                .validate_connector_against_payment_request(
+                    Some(capture_method),
+                    enums::PaymentMethod::Wallet,
+                    Some(enums::PaymentMethodType::AmazonPay),
+                )
+                .unwrap_err();
+            assert_eq!(
+                error.current_context(),
+                &errors::ConnectorError::NotSupported {
+                    message: capture_method.to_string(),
+                    connector: "amazonpay",
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_unsupported_currency_is_rejected() {
+        let error = Amazonpay::new()
+            .validate_amount_and_currency(MinorUnit::new(1000), enums::Currency::INR)
+            .unwrap_err();
+        assert_eq!(
+            error.current_context(),
+            &errors::ConnectorError::NotSupported {
+                message: "INR currency".to_string(),
+                connector: "amazonpay",
+            }
+        );
+    }
+
+    #[test]
+    fn test_zero_amount_is_rejected() {
+        let error = Amazonpay::new()
+            .validate_amount_and_currency(MinorUnit::zero(), enums::Currency::USD)
+            .unwrap_err();
+        assert_eq!(
+            error.current_context(),
+            &errors::ConnectorError::NotSupported {
+                message: "Zero amount authorization".to_string(),
+                connector: "amazonpay",
+            }
+        );
+    }
+
+    #[test]
+    fn test_supported_amount_and_currency() {
+        for currency in AMAZONPAY_SUPPORTED_CURRENCIES {
+            assert!(Amazonpay::new()
+                .validate_amount_and_currency(MinorUnit::new(AMAZONPAY_MINIMUM_AMOUNT), currency)
+                .is_ok());
+        }
+    }
+
+    #[test]
+    fn test_supported_payment_methods_match_validation() {
+        let payment_method_details = Amazonpay::new()
+            .get_supported_payment_methods()
+            .and_then(|supported_payment_methods| {
+                supported_payment_methods.get(&enums::PaymentMethod::Wallet)
+            })
+            .and_then(|payment_method_types| {
+                payment_method_types.get(&enums::PaymentMethodType::AmazonPay)
+            })
+            .unwrap();
+        assert_eq!(
+            payment_method_details.supported_capture_methods,
+            AMAZONPAY_SUPPORTED_CAPTURE_METHODS.to_vec()
+        );
+    }
+}
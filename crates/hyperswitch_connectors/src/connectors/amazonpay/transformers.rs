@@ -1,30 +1,57 @@
+use std::str::FromStr;
+
+use api_models::{
+    payments::{AmazonPaySessionTokenResponse, SessionToken},
+    webhooks::IncomingWebhookEvent,
+};
 use common_enums::enums;
-use common_utils::types::StringMinorUnit;
+use common_utils::{
+    errors::CustomResult,
+    pii,
+    request::Method,
+    types::{StringMajorUnit, StringMajorUnitForConnector, StringMinorUnitForConnector},
+};
+use error_stack::{report, ResultExt};
 use hyperswitch_domain_models::{
-    payment_method_data::PaymentMethodData,
-    router_data::{ConnectorAuthType, RouterData},
-    router_flow_types::refunds::{Execute, RSync},
-    router_request_types::ResponseId,
-    router_response_types::{PaymentsResponseData, RefundsResponseData},
-    types::{PaymentsAuthorizeRouterData, RefundsRouterData},
+    address::Address,
+    payment_method_data::{PaymentMethodData, WalletData},
+    router_data::{ConnectorAuthType, ErrorResponse, RouterData},
+    router_flow_types::mandate_revoke::MandateRevoke,
+    router_request_types::{CompleteAuthorizeData, MandateRevokeRequestData, ResponseId},
+    router_response_types::{
+        MandateReference, MandateRevokeResponseData, PaymentsResponseData, RedirectForm,
+        RefundsResponseData,
+    },
+    types::{
+        MandateRevokeRouterData, PaymentsAuthorizeRouterData, PaymentsCancelRouterData,
+        PaymentsCompleteAuthorizeRouterData, PaymentsSessionRouterData, RefundsRouterData,
+        SetupMandateRouterData,
+    },
 };
-use hyperswitch_interfaces::errors;
-use masking::Secret;
+use hyperswitch_interfaces::{consts::NO_ERROR_CODE, disputes::DisputePayload, errors};
+use masking::{PeekInterface, Secret};
+use ring::digest;
 use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use url::Url;
 
 use crate::{
-    types::{RefundsResponseRouterData, ResponseRouterData},
-    utils::PaymentsAuthorizeRequestData,
+    metrics,
+    types::{PaymentsSessionResponseRouterData, RefundsResponseRouterData, ResponseRouterData},
+    utils::{
+        self, AddressDetailsData, PaymentsAuthorizeRequestData, PaymentsSetupMandateRequestData,
+        PhoneDetailsData, RouterData as _,
+    },
 };
 
 //TODO: Fill the struct with respective fields
 pub struct AmazonpayRouterData<T> {
-    pub amount: StringMinorUnit, // The type of amount that a connector accepts, for example, String, i64, f64, etc.
+    pub amount: StringMajorUnit, // The type of amount that a connector accepts, for example, String, i64, f64, etc.
     pub router_data: T,
 }
 
-impl<T> From<(StringMinorUnit, T)> for AmazonpayRouterData<T> {
-    fn from((amount, item): (StringMinorUnit, T)) -> Self {
+impl<T> From<(StringMajorUnit, T)> for AmazonpayRouterData<T> {
+    fn from((amount, item): (StringMajorUnit, T)) -> Self {
         //Todo :  use utils to convert the amount to the type of amount that a connector accepts
         Self {
             amount,
@@ -33,58 +60,69 @@ impl<T> From<(StringMinorUnit, T)> for AmazonpayRouterData<T> {
     }
 }
 
-//TODO: Fill the struct with respective fields
-#[derive(Default, Debug, Serialize, PartialEq)]
-pub struct AmazonpayPaymentsRequest {
-    amount: StringMinorUnit,
-    card: AmazonpayCard,
+/// Region of an Amazon Pay merchant account, which determines the host the API is called on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display, strum::EnumString)]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum AmazonpayRegion {
+    Na,
+    Eu,
+    Jp,
 }
 
-#[derive(Default, Debug, Serialize, Eq, PartialEq)]
-pub struct AmazonpayCard {
-    number: cards::CardNumber,
-    expiry_month: Secret<String>,
-    expiry_year: Secret<String>,
-    cvc: Secret<String>,
-    complete: bool,
+impl AmazonpayRegion {
+    pub fn host(self) -> &'static str {
+        match self {
+            Self::Na => "pay-api.amazon.com",
+            Self::Eu => "pay-api.amazon.eu",
+            Self::Jp => "pay-api.amazon.jp",
+        }
+    }
 }
 
-impl TryFrom<&AmazonpayRouterData<&PaymentsAuthorizeRouterData>> for AmazonpayPaymentsRequest {
-    type Error = error_stack::Report<errors::ConnectorError>;
-    fn try_from(
-        item: &AmazonpayRouterData<&PaymentsAuthorizeRouterData>,
-    ) -> Result<Self, Self::Error> {
-        match item.router_data.request.payment_method_data.clone() {
-            PaymentMethodData::Card(req_card) => {
-                let card = AmazonpayCard {
-                    number: req_card.card_number,
-                    expiry_month: req_card.card_exp_month,
-                    expiry_year: req_card.card_exp_year,
-                    cvc: req_card.card_cvc,
-                    complete: item.router_data.request.is_auto_capture()?,
-                };
-                Ok(Self {
-                    amount: item.amount.clone(),
-                    card,
-                })
-            }
-            _ => Err(errors::ConnectorError::NotImplemented("Payment method".to_string()).into()),
+/// Environment of the Amazon Pay API, given by the first segment of the request path
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display)]
+#[strum(serialize_all = "lowercase")]
+pub enum AmazonpayEnvironment {
+    Sandbox,
+    Live,
+}
+
+impl AmazonpayEnvironment {
+    pub fn from_test_mode(test_mode: Option<bool>) -> Self {
+        if test_mode.unwrap_or(true) {
+            Self::Sandbox
+        } else {
+            Self::Live
         }
     }
 }
 
-//TODO: Fill the struct with respective fields
 // Auth Struct
 pub struct AmazonpayAuthType {
-    pub(super) api_key: Secret<String>,
+    /// Identifier of the public key registered with Amazon Pay
+    pub(super) public_key_id: Secret<String>,
+    /// Region of the merchant account
+    pub(super) region: AmazonpayRegion,
+    /// PEM encoded RSA private key the requests are signed with
+    pub(super) private_key: Secret<String>,
 }
 
 impl TryFrom<&ConnectorAuthType> for AmazonpayAuthType {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(auth_type: &ConnectorAuthType) -> Result<Self, Self::Error> {
         match auth_type {
-            ConnectorAuthType::HeaderKey { api_key } => Ok(Self {
-                api_key: api_key.to_owned(),
+            ConnectorAuthType::SignatureKey {
+                api_key,
+                key1,
+                api_secret,
+            } => Ok(Self {
+                public_key_id: api_key.to_owned(),
+                region: AmazonpayRegion::from_str(key1.peek().trim()).change_context(
+                    errors::ConnectorError::InvalidConnectorConfig {
+                        config: "connector_account_details.key1",
+                    },
+                )?,
+                private_key: api_secret.to_owned(),
             }),
             _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
         }
@@ -111,11 +149,20 @@ impl From<AmazonpayPaymentStatus> for common_enums::AttemptStatus {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AmazonpayStatusDetails {
+    reason_code: String,
+    reason_description: Option<String>,
+}
+
 //TODO: Fill the struct with respective fields
 #[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
 pub struct AmazonpayPaymentsResponse {
     status: AmazonpayPaymentStatus,
     id: String,
+    status_details: Option<AmazonpayStatusDetails>,
 }
 
 impl<F, T> TryFrom<ResponseRouterData<F, AmazonpayPaymentsResponse, T, PaymentsResponseData>>
@@ -125,6 +172,24 @@ impl<F, T> TryFrom<ResponseRouterData<F, AmazonpayPaymentsResponse, T, PaymentsR
     fn try_from(
         item: ResponseRouterData<F, AmazonpayPaymentsResponse, T, PaymentsResponseData>,
     ) -> Result<Self, Self::Error> {
+        if item.response.status == AmazonpayPaymentStatus::Failed {
+            if let Some(status_details) = item.response.status_details {
+                let error_response = get_error_response(
+                    status_details.reason_code,
+                    status_details.reason_description,
+                    item.http_code,
+                    Some(item.response.id),
+                );
+                return Ok(Self {
+                    status: error_response
+                        .attempt_status
+                        .unwrap_or(common_enums::AttemptStatus::Failure),
+                    response: Err(error_response),
+                    ..item.data
+                });
+            }
+        }
+
         Ok(Self {
             status: common_enums::AttemptStatus::from(item.response.status),
             response: Ok(PaymentsResponseData::TransactionResponse {
@@ -142,87 +207,2748 @@ impl<F, T> TryFrom<ResponseRouterData<F, AmazonpayPaymentsResponse, T, PaymentsR
     }
 }
 
-//TODO: Fill the struct with respective fields
-// REFUND :
-// Type definition for RefundRequest
-#[derive(Default, Debug, Serialize)]
-pub struct AmazonpayRefundRequest {
-    pub amount: StringMinorUnit,
+/// Reason sent to Amazon Pay when a charge is canceled without a cancellation reason, as the
+/// charge cancellation API requires one
+const DEFAULT_CANCELLATION_REASON: &str = "Canceled by merchant";
+
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AmazonpayCancelRequest {
+    cancellation_reason: String,
 }
 
-impl<F> TryFrom<&AmazonpayRouterData<&RefundsRouterData<F>>> for AmazonpayRefundRequest {
+impl AmazonpayCancelRequest {
+    fn new(cancellation_reason: Option<String>) -> Self {
+        Self {
+            cancellation_reason: cancellation_reason
+                .unwrap_or_else(|| DEFAULT_CANCELLATION_REASON.to_string()),
+        }
+    }
+}
+
+impl TryFrom<&PaymentsCancelRouterData> for AmazonpayCancelRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
-    fn try_from(item: &AmazonpayRouterData<&RefundsRouterData<F>>) -> Result<Self, Self::Error> {
+    fn try_from(item: &PaymentsCancelRouterData) -> Result<Self, Self::Error> {
+        Ok(Self::new(item.request.cancellation_reason.clone()))
+    }
+}
+
+/// State of an Amazon Pay charge
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum AmazonpayChargeState {
+    AuthorizationInitiated,
+    Authorized,
+    CaptureInitiated,
+    Captured,
+    Canceled,
+    Declined,
+}
+
+impl From<AmazonpayChargeState> for common_enums::AttemptStatus {
+    fn from(item: AmazonpayChargeState) -> Self {
+        match item {
+            AmazonpayChargeState::AuthorizationInitiated => Self::Authorizing,
+            AmazonpayChargeState::Authorized => Self::Authorized,
+            AmazonpayChargeState::CaptureInitiated => Self::CaptureInitiated,
+            AmazonpayChargeState::Captured => Self::Charged,
+            AmazonpayChargeState::Canceled => Self::Voided,
+            AmazonpayChargeState::Declined => Self::Failure,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AmazonpayChargeStatusDetails {
+    state: AmazonpayChargeState,
+    reason_code: Option<String>,
+    reason_description: Option<String>,
+}
+
+/// Charge returned by the charge cancellation API
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AmazonpayCancelResponse {
+    charge_id: String,
+    status_details: AmazonpayChargeStatusDetails,
+}
+
+impl<F, T> TryFrom<ResponseRouterData<F, AmazonpayCancelResponse, T, PaymentsResponseData>>
+    for RouterData<F, T, PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: ResponseRouterData<F, AmazonpayCancelResponse, T, PaymentsResponseData>,
+    ) -> Result<Self, Self::Error> {
+        let status_details = item.response.status_details;
+        if status_details.state == AmazonpayChargeState::Declined {
+            let error_response = get_error_response(
+                status_details
+                    .reason_code
+                    .unwrap_or_else(|| NO_ERROR_CODE.to_string()),
+                status_details.reason_description,
+                item.http_code,
+                Some(item.response.charge_id),
+            );
+            return Ok(Self {
+                status: error_response
+                    .attempt_status
+                    .unwrap_or(common_enums::AttemptStatus::VoidFailed),
+                response: Err(error_response),
+                ..item.data
+            });
+        }
+
         Ok(Self {
-            amount: item.amount.to_owned(),
+            status: common_enums::AttemptStatus::from(status_details.state),
+            response: Ok(PaymentsResponseData::TransactionResponse {
+                resource_id: ResponseId::ConnectorTransactionId(item.response.charge_id),
+                redirection_data: Box::new(None),
+                mandate_reference: Box::new(None),
+                connector_metadata: None,
+                network_txn_id: None,
+                connector_response_reference_id: None,
+                incremental_authorization_allowed: None,
+                charges: None,
+            }),
+            ..item.data
         })
     }
 }
 
-// Type definition for Refund Response
+/// Amazon Pay resource which the connector transaction id of a payment refers to. The id is that
+/// of the checkout session until the shopper completes the checkout, and that of the charge after.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AmazonpayResourceType {
+    Charge,
+    CheckoutSession,
+}
 
-#[allow(dead_code)]
-#[derive(Debug, Serialize, Default, Deserialize, Clone)]
-pub enum RefundStatus {
-    Succeeded,
-    Failed,
-    #[default]
-    Processing,
+impl AmazonpayResourceType {
+    /// The resource type stored in the connector metadata of the payment, or else the one given
+    /// by the format of the id, as checkout session ids are UUIDs while charge ids are of the form
+    /// `S01-0000000-0000000-C000000`
+    pub fn from_connector_transaction_id(
+        connector_transaction_id: &str,
+        connector_meta: Option<&serde_json::Value>,
+    ) -> Self {
+        connector_meta
+            .cloned()
+            .and_then(|connector_meta| {
+                serde_json::from_value::<AmazonpayConnectorMetadata>(connector_meta).ok()
+            })
+            .map(|connector_meta| connector_meta.resource_type)
+            .unwrap_or_else(|| {
+                if uuid::Uuid::parse_str(connector_transaction_id).is_ok() {
+                    Self::CheckoutSession
+                } else {
+                    Self::Charge
+                }
+            })
+    }
+
+    /// Path segment of the API of the resource
+    pub fn path_segment(self) -> &'static str {
+        match self {
+            Self::Charge => "charges",
+            Self::CheckoutSession => "checkoutSessions",
+        }
+    }
 }
 
-impl From<RefundStatus> for enums::RefundStatus {
-    fn from(item: RefundStatus) -> Self {
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AmazonpayConnectorMetadata {
+    pub resource_type: AmazonpayResourceType,
+}
+
+/// Charge returned by the charge retrieval API
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AmazonpayChargeResponse {
+    charge_id: String,
+    status_details: AmazonpayChargeStatusDetails,
+}
+
+impl<F, T> TryFrom<ResponseRouterData<F, AmazonpayChargeResponse, T, PaymentsResponseData>>
+    for RouterData<F, T, PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: ResponseRouterData<F, AmazonpayChargeResponse, T, PaymentsResponseData>,
+    ) -> Result<Self, Self::Error> {
+        let status_details = item.response.status_details;
+        if status_details.state == AmazonpayChargeState::Declined {
+            let error_response = get_error_response(
+                status_details
+                    .reason_code
+                    .unwrap_or_else(|| NO_ERROR_CODE.to_string()),
+                status_details.reason_description,
+                item.http_code,
+                Some(item.response.charge_id),
+            );
+            return Ok(Self {
+                status: error_response
+                    .attempt_status
+                    .unwrap_or(common_enums::AttemptStatus::Failure),
+                response: Err(error_response),
+                ..item.data
+            });
+        }
+
+        Ok(Self {
+            status: common_enums::AttemptStatus::from(status_details.state),
+            response: Ok(PaymentsResponseData::TransactionResponse {
+                resource_id: ResponseId::ConnectorTransactionId(item.response.charge_id),
+                redirection_data: Box::new(None),
+                mandate_reference: Box::new(None),
+                connector_metadata: None,
+                network_txn_id: None,
+                connector_response_reference_id: None,
+                incremental_authorization_allowed: None,
+                charges: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+/// State of an Amazon Pay checkout session
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum AmazonpayCheckoutSessionState {
+    Open,
+    Completed,
+    Canceled,
+}
+
+impl From<AmazonpayCheckoutSessionState> for common_enums::AttemptStatus {
+    fn from(item: AmazonpayCheckoutSessionState) -> Self {
         match item {
-            RefundStatus::Succeeded => Self::Success,
-            RefundStatus::Failed => Self::Failure,
-            RefundStatus::Processing => Self::Pending,
-            //TODO: Review mapping
+            // The shopper has not completed the checkout yet
+            AmazonpayCheckoutSessionState::Open => Self::AuthenticationPending,
+            // The charge of the session has been created, its state is known on the next sync
+            AmazonpayCheckoutSessionState::Completed => Self::Pending,
+            AmazonpayCheckoutSessionState::Canceled => Self::Failure,
         }
     }
 }
 
-//TODO: Fill the struct with respective fields
-#[derive(Default, Debug, Clone, Serialize, Deserialize)]
-pub struct RefundResponse {
-    id: String,
-    status: RefundStatus,
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AmazonpayCheckoutSessionStatusDetails {
+    state: AmazonpayCheckoutSessionState,
+    reason_code: Option<String>,
+    reason_description: Option<String>,
+}
+
+/// Checkout session returned by the checkout session retrieval API
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AmazonpayCheckoutSessionResponse {
+    checkout_session_id: String,
+    status_details: AmazonpayCheckoutSessionStatusDetails,
+    /// Id of the charge created when the checkout session is completed
+    charge_id: Option<String>,
+    /// Id of the charge permission created when the checkout session is completed, which is the
+    /// mandate of the subsequent payments of a recurring checkout session
+    charge_permission_id: Option<String>,
+}
+
+impl AmazonpayCheckoutSessionResponse {
+    /// The resource the payment is to be synced with from now on, which is the charge of the
+    /// session once it has been created
+    fn get_resource(&self) -> (String, AmazonpayResourceType) {
+        match (&self.status_details.state, &self.charge_id) {
+            (AmazonpayCheckoutSessionState::Completed, Some(charge_id)) => {
+                (charge_id.clone(), AmazonpayResourceType::Charge)
+            }
+            _ => (
+                self.checkout_session_id.clone(),
+                AmazonpayResourceType::CheckoutSession,
+            ),
+        }
+    }
+
+    /// Status of the attempt, a completed checkout session without a charge has only set up the
+    /// charge permission of a mandate
+    fn get_attempt_status(&self) -> common_enums::AttemptStatus {
+        match (
+            &self.status_details.state,
+            &self.charge_id,
+            &self.charge_permission_id,
+        ) {
+            (AmazonpayCheckoutSessionState::Completed, None, Some(_)) => {
+                common_enums::AttemptStatus::Charged
+            }
+            (state, _, _) => common_enums::AttemptStatus::from(*state),
+        }
+    }
+
+    fn get_mandate_reference(&self) -> Option<MandateReference> {
+        match self.status_details.state {
+            AmazonpayCheckoutSessionState::Completed => {
+                self.charge_permission_id
+                    .clone()
+                    .map(|charge_permission_id| MandateReference {
+                        connector_mandate_id: Some(charge_permission_id),
+                        payment_method_id: None,
+                        mandate_metadata: None,
+                        connector_mandate_request_reference_id: None,
+                    })
+            }
+            AmazonpayCheckoutSessionState::Open | AmazonpayCheckoutSessionState::Canceled => None,
+        }
+    }
 }
 
-impl TryFrom<RefundsResponseRouterData<Execute, RefundResponse>> for RefundsRouterData<Execute> {
+impl<F, T> TryFrom<ResponseRouterData<F, AmazonpayCheckoutSessionResponse, T, PaymentsResponseData>>
+    for RouterData<F, T, PaymentsResponseData>
+{
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(
-        item: RefundsResponseRouterData<Execute, RefundResponse>,
+        item: ResponseRouterData<F, AmazonpayCheckoutSessionResponse, T, PaymentsResponseData>,
     ) -> Result<Self, Self::Error> {
+        let (resource_id, resource_type) = item.response.get_resource();
+        let status = item.response.get_attempt_status();
+        let mandate_reference = item.response.get_mandate_reference();
+        let status_details = item.response.status_details;
+        if status_details.state == AmazonpayCheckoutSessionState::Canceled {
+            let error_response = get_error_response(
+                status_details
+                    .reason_code
+                    .unwrap_or_else(|| NO_ERROR_CODE.to_string()),
+                status_details.reason_description,
+                item.http_code,
+                Some(resource_id),
+            );
+            return Ok(Self {
+                status: error_response
+                    .attempt_status
+                    .unwrap_or(common_enums::AttemptStatus::Failure),
+                response: Err(error_response),
+                ..item.data
+            });
+        }
+
+        let connector_metadata = serde_json::to_value(AmazonpayConnectorMetadata { resource_type })
+            .change_context(errors::ConnectorError::ResponseHandlingFailed)?;
         Ok(Self {
-            response: Ok(RefundsResponseData {
-                connector_refund_id: item.response.id.to_string(),
-                refund_status: enums::RefundStatus::from(item.response.status),
+            status,
+            response: Ok(PaymentsResponseData::TransactionResponse {
+                resource_id: ResponseId::ConnectorTransactionId(resource_id),
+                redirection_data: Box::new(None),
+                mandate_reference: Box::new(mandate_reference),
+                connector_metadata: Some(connector_metadata),
+                network_txn_id: None,
+                connector_response_reference_id: None,
+                incremental_authorization_allowed: None,
+                charges: None,
             }),
             ..item.data
         })
     }
 }
 
-impl TryFrom<RefundsResponseRouterData<RSync, RefundResponse>> for RefundsRouterData<RSync> {
+/// Metadata of an Amazon Pay merchant connector account
+#[derive(Debug, Deserialize)]
+pub struct AmazonpayConnectorMetadataObject {
+    /// Identifier of the Amazon Pay store the checkout sessions are created for
+    pub store_id: Secret<String>,
+}
+
+impl TryFrom<&Option<pii::SecretSerdeValue>> for AmazonpayConnectorMetadataObject {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(meta_data: &Option<pii::SecretSerdeValue>) -> Result<Self, Self::Error> {
+        utils::to_connector_meta_from_secret(meta_data.clone()).change_context(
+            errors::ConnectorError::InvalidConnectorConfig {
+                config: "metadata.store_id",
+            },
+        )
+    }
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+pub enum AmazonpayCheckoutMode {
+    /// The charge is created as soon as the shopper completes the checkout, without a review
+    /// page on the merchant side
+    ProcessOrder,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AmazonpayWebCheckoutDetails {
+    checkout_result_return_url: String,
+    checkout_mode: AmazonpayCheckoutMode,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+pub enum AmazonpayChargePermissionType {
+    OneTime,
+    Recurring,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+pub enum AmazonpayPaymentIntent {
+    AuthorizeWithCapture,
+    /// The checkout session only creates the charge permission, without charging the buyer
+    Confirm,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+pub enum AmazonpayFrequencyUnit {
+    /// The subsequent payments are charged at no fixed interval
+    Variable,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AmazonpayFrequency {
+    unit: AmazonpayFrequencyUnit,
+    value: String,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AmazonpayRecurringMetadata {
+    frequency: AmazonpayFrequency,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AmazonpayPaymentDetails {
+    payment_intent: AmazonpayPaymentIntent,
+    charge_amount: AmazonpayPrice,
+    presentment_currency: enums::Currency,
+    /// Description of the charge shown on the statement of the buyer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    soft_descriptor: Option<String>,
+}
+
+/// Maximum lengths of the fields of a checkout session, Amazon Pay rejects the whole request when
+/// a field is longer
+const AMAZONPAY_MERCHANT_REFERENCE_ID_MAX_LENGTH: usize = 256;
+const AMAZONPAY_NOTE_TO_BUYER_MAX_LENGTH: usize = 255;
+const AMAZONPAY_CUSTOM_INFORMATION_MAX_LENGTH: usize = 4096;
+const AMAZONPAY_SOFT_DESCRIPTOR_MAX_LENGTH: usize = 16;
+const AMAZONPAY_ADDRESS_NAME_MAX_LENGTH: usize = 50;
+const AMAZONPAY_ADDRESS_LINE_MAX_LENGTH: usize = 180;
+const AMAZONPAY_CITY_MAX_LENGTH: usize = 50;
+const AMAZONPAY_STATE_OR_REGION_MAX_LENGTH: usize = 50;
+const AMAZONPAY_POSTAL_CODE_MAX_LENGTH: usize = 20;
+const AMAZONPAY_PHONE_NUMBER_MAX_LENGTH: usize = 20;
+
+/// The value cut down to the maximum length of the field, so that a long value does not fail the
+/// payment. The value itself is not logged, as it may be personal data of the buyer.
+fn truncate_to_max_length(field_name: &str, mut value: String, max_length: usize) -> String {
+    if let Some((truncated_length, _)) = value.char_indices().nth(max_length) {
+        router_env::logger::warn!(
+            field_name,
+            length = value.chars().count(),
+            max_length,
+            "Truncating the Amazon Pay field exceeding its maximum length"
+        );
+        value.truncate(truncated_length);
+    }
+    value
+}
+
+fn truncate_secret_to_max_length(
+    field_name: &str,
+    value: Secret<String>,
+    max_length: usize,
+) -> Secret<String> {
+    value.map(|value| truncate_to_max_length(field_name, value, max_length))
+}
+
+/// Details of the order the merchant shares with Amazon Pay, shown to the buyer and in the
+/// reports of the merchant
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AmazonpayMerchantMetadata {
+    merchant_reference_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    note_to_buyer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    custom_information: Option<String>,
+}
+
+impl AmazonpayMerchantMetadata {
+    fn new(
+        merchant_reference_id: String,
+        note_to_buyer: Option<String>,
+        custom_information: Option<String>,
+    ) -> Self {
+        Self {
+            merchant_reference_id: truncate_to_max_length(
+                "merchantMetadata.merchantReferenceId",
+                merchant_reference_id,
+                AMAZONPAY_MERCHANT_REFERENCE_ID_MAX_LENGTH,
+            ),
+            note_to_buyer: note_to_buyer.map(|note_to_buyer| {
+                truncate_to_max_length(
+                    "merchantMetadata.noteToBuyer",
+                    note_to_buyer,
+                    AMAZONPAY_NOTE_TO_BUYER_MAX_LENGTH,
+                )
+            }),
+            custom_information: custom_information.map(|custom_information| {
+                truncate_to_max_length(
+                    "merchantMetadata.customInformation",
+                    custom_information,
+                    AMAZONPAY_CUSTOM_INFORMATION_MAX_LENGTH,
+                )
+            }),
+        }
+    }
+}
+
+/// Address the order is shipped to
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AmazonpayAddressDetails {
+    name: Secret<String>,
+    address_line1: Secret<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    address_line2: Option<Secret<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    address_line3: Option<Secret<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    city: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state_or_region: Option<Secret<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    postal_code: Option<Secret<String>>,
+    country_code: enums::CountryAlpha2,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    phone_number: Option<Secret<String>>,
+}
+
+impl AmazonpayAddressDetails {
+    /// The address in the shape of Amazon Pay, when it has the name, first line and country
+    /// Amazon Pay requires
+    fn from_address(address: &Address) -> Option<Self> {
+        let address_details = address.address.as_ref()?;
+        let truncate_address_line = |field_name, address_line| {
+            truncate_secret_to_max_length(
+                field_name,
+                address_line,
+                AMAZONPAY_ADDRESS_LINE_MAX_LENGTH,
+            )
+        };
+        Some(Self {
+            name: truncate_secret_to_max_length(
+                "addressDetails.name",
+                address_details.get_optional_full_name()?,
+                AMAZONPAY_ADDRESS_NAME_MAX_LENGTH,
+            ),
+            address_line1: truncate_address_line(
+                "addressDetails.addressLine1",
+                address_details.get_optional_line1()?,
+            ),
+            address_line2: address_details
+                .get_optional_line2()
+                .map(|line2| truncate_address_line("addressDetails.addressLine2", line2)),
+            address_line3: address_details
+                .get_optional_line3()
+                .map(|line3| truncate_address_line("addressDetails.addressLine3", line3)),
+            city: address_details.get_optional_city().map(|city| {
+                truncate_to_max_length("addressDetails.city", city, AMAZONPAY_CITY_MAX_LENGTH)
+            }),
+            state_or_region: address_details.get_optional_state().map(|state| {
+                truncate_secret_to_max_length(
+                    "addressDetails.stateOrRegion",
+                    state,
+                    AMAZONPAY_STATE_OR_REGION_MAX_LENGTH,
+                )
+            }),
+            postal_code: address_details.get_optional_zip().map(|zip| {
+                truncate_secret_to_max_length(
+                    "addressDetails.postalCode",
+                    zip,
+                    AMAZONPAY_POSTAL_CODE_MAX_LENGTH,
+                )
+            }),
+            country_code: address_details.get_optional_country()?,
+            phone_number: address
+                .phone
+                .as_ref()
+                .and_then(|phone| phone.get_number_with_country_code().ok())
+                .map(|phone_number| {
+                    truncate_secret_to_max_length(
+                        "addressDetails.phoneNumber",
+                        phone_number,
+                        AMAZONPAY_PHONE_NUMBER_MAX_LENGTH,
+                    )
+                }),
+        })
+    }
+}
+
+/// Request creating a checkout session, which is also the payload the Amazon Pay button is
+/// rendered with
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AmazonpayCheckoutSessionRequest {
+    web_checkout_details: AmazonpayWebCheckoutDetails,
+    store_id: Secret<String>,
+    charge_permission_type: AmazonpayChargePermissionType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recurring_metadata: Option<AmazonpayRecurringMetadata>,
+    payment_details: AmazonpayPaymentDetails,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    address_details: Option<AmazonpayAddressDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    merchant_metadata: Option<AmazonpayMerchantMetadata>,
+}
+
+impl AmazonpayCheckoutSessionRequest {
+    fn new(
+        store_id: Secret<String>,
+        return_url: String,
+        amount: StringMajorUnit,
+        currency: enums::Currency,
+    ) -> Self {
+        Self {
+            web_checkout_details: AmazonpayWebCheckoutDetails {
+                checkout_result_return_url: return_url,
+                checkout_mode: AmazonpayCheckoutMode::ProcessOrder,
+            },
+            store_id,
+            charge_permission_type: AmazonpayChargePermissionType::OneTime,
+            recurring_metadata: None,
+            payment_details: AmazonpayPaymentDetails {
+                payment_intent: AmazonpayPaymentIntent::AuthorizeWithCapture,
+                charge_amount: AmazonpayPrice {
+                    amount,
+                    currency_code: currency,
+                },
+                presentment_currency: currency,
+                soft_descriptor: None,
+            },
+            address_details: None,
+            merchant_metadata: None,
+        }
+    }
+
+    /// Checkout session carrying the details of the order, which Amazon Pay may require to
+    /// accept the charge, such as the shipping address of physical goods
+    fn with_order_details(
+        self,
+        address_details: Option<AmazonpayAddressDetails>,
+        merchant_metadata: AmazonpayMerchantMetadata,
+        soft_descriptor: Option<String>,
+    ) -> Self {
+        Self {
+            address_details,
+            merchant_metadata: Some(merchant_metadata),
+            payment_details: AmazonpayPaymentDetails {
+                soft_descriptor: soft_descriptor.map(|soft_descriptor| {
+                    truncate_to_max_length(
+                        "paymentDetails.softDescriptor",
+                        soft_descriptor,
+                        AMAZONPAY_SOFT_DESCRIPTOR_MAX_LENGTH,
+                    )
+                }),
+                ..self.payment_details
+            },
+            ..self
+        }
+    }
+
+    /// Checkout session creating the recurring charge permission of a mandate, the subsequent
+    /// payments are charged on the charge permission
+    fn new_recurring(
+        store_id: Secret<String>,
+        return_url: String,
+        amount: StringMajorUnit,
+        currency: enums::Currency,
+    ) -> Self {
+        let checkout_session = Self::new(store_id, return_url, amount, currency);
+        Self {
+            charge_permission_type: AmazonpayChargePermissionType::Recurring,
+            recurring_metadata: Some(AmazonpayRecurringMetadata {
+                frequency: AmazonpayFrequency {
+                    unit: AmazonpayFrequencyUnit::Variable,
+                    value: "0".to_string(),
+                },
+            }),
+            payment_details: AmazonpayPaymentDetails {
+                payment_intent: AmazonpayPaymentIntent::Confirm,
+                ..checkout_session.payment_details
+            },
+            ..checkout_session
+        }
+    }
+}
+
+impl TryFrom<&AmazonpayRouterData<&PaymentsSessionRouterData>> for AmazonpayCheckoutSessionRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &AmazonpayRouterData<&PaymentsSessionRouterData>,
+    ) -> Result<Self, Self::Error> {
+        let metadata =
+            AmazonpayConnectorMetadataObject::try_from(&item.router_data.connector_meta_data)?;
+        let return_url = item.router_data.request.return_url.clone().ok_or(
+            errors::ConnectorError::MissingRequiredField {
+                field_name: "return_url",
+            },
+        )?;
+        Ok(Self::new(
+            metadata.store_id,
+            return_url,
+            item.amount.clone(),
+            item.router_data.request.currency,
+        ))
+    }
+}
+
+impl TryFrom<&AmazonpayRouterData<&PaymentsAuthorizeRouterData>>
+    for AmazonpayCheckoutSessionRequest
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &AmazonpayRouterData<&PaymentsAuthorizeRouterData>,
+    ) -> Result<Self, Self::Error> {
+        match &item.router_data.request.payment_method_data {
+            PaymentMethodData::Wallet(WalletData::AmazonPayRedirect(_)) => {
+                let metadata = AmazonpayConnectorMetadataObject::try_from(
+                    &item.router_data.connector_meta_data,
+                )?;
+                let router_data = item.router_data;
+                // The buyer returns to the complete authorize url once the checkout session is
+                // approved, where the checkout session is completed
+                let checkout_session = Self::new(
+                    metadata.store_id,
+                    router_data.request.get_complete_authorize_url()?,
+                    item.amount.clone(),
+                    router_data.request.currency,
+                );
+                // Payments without a separate shipping address are shipped to the billing address
+                let address_details = router_data
+                    .get_optional_shipping()
+                    .or(router_data.get_optional_billing())
+                    .and_then(AmazonpayAddressDetails::from_address);
+                let merchant_metadata = AmazonpayMerchantMetadata::new(
+                    router_data.connector_request_reference_id.clone(),
+                    router_data.description.clone(),
+                    router_data
+                        .request
+                        .metadata
+                        .as_ref()
+                        .map(|metadata| metadata.to_string()),
+                );
+                let soft_descriptor = router_data
+                    .request
+                    .statement_descriptor
+                    .clone()
+                    .or(router_data.request.statement_descriptor_suffix.clone());
+                Ok(checkout_session.with_order_details(
+                    address_details,
+                    merchant_metadata,
+                    soft_descriptor,
+                ))
+            }
+            _ => Err(errors::ConnectorError::NotImplemented(
+                utils::get_unimplemented_payment_method_error_message("amazonpay"),
+            )
+            .into()),
+        }
+    }
+}
+
+impl TryFrom<&AmazonpayRouterData<&SetupMandateRouterData>> for AmazonpayCheckoutSessionRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &AmazonpayRouterData<&SetupMandateRouterData>) -> Result<Self, Self::Error> {
+        let metadata =
+            AmazonpayConnectorMetadataObject::try_from(&item.router_data.connector_meta_data)?;
+        Ok(Self::new_recurring(
+            metadata.store_id,
+            item.router_data.request.get_router_return_url()?,
+            item.amount.clone(),
+            item.router_data.request.currency,
+        ))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AmazonpayWebCheckoutDetailsResponse {
+    /// Url the buyer is redirected to, to approve the checkout session on Amazon Pay
+    amazon_pay_redirect_url: Option<String>,
+}
+
+/// Checkout session returned by the checkout session creation API
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AmazonpayCreateCheckoutSessionResponse {
+    checkout_session_id: String,
+    web_checkout_details: Option<AmazonpayWebCheckoutDetailsResponse>,
+}
+
+impl AmazonpayCreateCheckoutSessionResponse {
+    fn get_redirect_url(&self) -> Option<&str> {
+        self.web_checkout_details
+            .as_ref()
+            .and_then(|web_checkout_details| {
+                web_checkout_details.amazon_pay_redirect_url.as_deref()
+            })
+    }
+
+    /// The page the buyer approves the checkout session on, a checkout session cannot be
+    /// completed before the buyer approves it
+    fn get_redirection_data(&self) -> Result<RedirectForm, errors::ConnectorError> {
+        let redirect_url = self
+            .get_redirect_url()
+            .map(Url::parse)
+            .transpose()
+            .map_err(|_| errors::ConnectorError::ResponseHandlingFailed)?
+            .ok_or(errors::ConnectorError::MissingConnectorRedirectionPayload {
+                field_name: "webCheckoutDetails.amazonPayRedirectUrl",
+            })?;
+        Ok(RedirectForm::from((redirect_url, Method::Get)))
+    }
+}
+
+impl<F, T>
+    TryFrom<ResponseRouterData<F, AmazonpayCreateCheckoutSessionResponse, T, PaymentsResponseData>>
+    for RouterData<F, T, PaymentsResponseData>
+{
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(
-        item: RefundsResponseRouterData<RSync, RefundResponse>,
+        item: ResponseRouterData<
+            F,
+            AmazonpayCreateCheckoutSessionResponse,
+            T,
+            PaymentsResponseData,
+        >,
     ) -> Result<Self, Self::Error> {
+        let redirection_data = item.response.get_redirection_data()?;
+        let connector_metadata = serde_json::to_value(AmazonpayConnectorMetadata {
+            resource_type: AmazonpayResourceType::CheckoutSession,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)?;
         Ok(Self {
-            response: Ok(RefundsResponseData {
-                connector_refund_id: item.response.id.to_string(),
-                refund_status: enums::RefundStatus::from(item.response.status),
+            // The charge, or the charge permission of a mandate, is created once the buyer
+            // approves the checkout session
+            status: common_enums::AttemptStatus::AuthenticationPending,
+            response: Ok(PaymentsResponseData::TransactionResponse {
+                resource_id: ResponseId::ConnectorTransactionId(item.response.checkout_session_id),
+                redirection_data: Box::new(Some(redirection_data)),
+                mandate_reference: Box::new(None),
+                connector_metadata: Some(connector_metadata),
+                network_txn_id: None,
+                connector_response_reference_id: None,
+                incremental_authorization_allowed: None,
+                charges: None,
             }),
             ..item.data
         })
     }
 }
 
-//TODO: Fill the struct with respective fields
-#[derive(Default, Debug, Serialize, Deserialize, PartialEq)]
-pub struct AmazonpayErrorResponse {
-    pub status_code: u16,
-    pub code: String,
-    pub message: String,
-    pub reason: Option<String>,
+/// Query parameter the checkout session id is appended to the return url with, once the buyer
+/// approves the checkout session
+const AMAZONPAY_CHECKOUT_SESSION_ID_PARAM: &str = "amazonCheckoutSessionId";
+
+/// Id of the checkout session the buyer returned from, falling back to the checkout session
+/// created by the authorize request
+pub fn get_checkout_session_id(
+    request: &CompleteAuthorizeData,
+) -> Result<String, errors::ConnectorError> {
+    request
+        .redirect_response
+        .as_ref()
+        .and_then(|redirect_response| redirect_response.params.as_ref())
+        .and_then(|params| get_checkout_session_id_from_params(params.peek()))
+        .or_else(|| request.connector_transaction_id.clone())
+        .ok_or(errors::ConnectorError::MissingConnectorTransactionID)
+}
+
+fn get_checkout_session_id_from_params(params: &str) -> Option<String> {
+    url::form_urlencoded::parse(params.trim_start_matches('?').as_bytes())
+        .find(|(key, _)| key == AMAZONPAY_CHECKOUT_SESSION_ID_PARAM)
+        .map(|(_, checkout_session_id)| checkout_session_id.into_owned())
+        .filter(|checkout_session_id| !checkout_session_id.is_empty())
+}
+
+/// Request completing the checkout session the buyer approved, which creates its charge
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AmazonpayCompleteCheckoutSessionRequest {
+    charge_amount: AmazonpayPrice,
+}
+
+impl TryFrom<&AmazonpayRouterData<&PaymentsCompleteAuthorizeRouterData>>
+    for AmazonpayCompleteCheckoutSessionRequest
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &AmazonpayRouterData<&PaymentsCompleteAuthorizeRouterData>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            charge_amount: AmazonpayPrice {
+                amount: item.amount.clone(),
+                currency_code: item.router_data.request.currency,
+            },
+        })
+    }
+}
+
+/// Request charging the charge permission of a mandate, for the payments initiated by the
+/// merchant
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AmazonpayChargeRequest {
+    charge_permission_id: String,
+    charge_amount: AmazonpayPrice,
+    capture_now: bool,
+    can_handle_pending_authorization: bool,
+}
+
+impl AmazonpayChargeRequest {
+    fn new(
+        charge_permission_id: String,
+        amount: StringMajorUnit,
+        currency_code: enums::Currency,
+        capture_now: bool,
+    ) -> Self {
+        Self {
+            charge_permission_id,
+            charge_amount: AmazonpayPrice {
+                amount,
+                currency_code,
+            },
+            capture_now,
+            can_handle_pending_authorization: false,
+        }
+    }
+}
+
+impl TryFrom<&AmazonpayRouterData<&PaymentsAuthorizeRouterData>> for AmazonpayChargeRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &AmazonpayRouterData<&PaymentsAuthorizeRouterData>,
+    ) -> Result<Self, Self::Error> {
+        let charge_permission_id = item
+            .router_data
+            .request
+            .connector_mandate_id()
+            .ok_or(errors::ConnectorError::MissingConnectorMandateID)?;
+        Ok(Self::new(
+            charge_permission_id,
+            item.amount.clone(),
+            item.router_data.request.currency,
+            item.router_data.request.is_auto_capture()?,
+        ))
+    }
+}
+
+/// Reason sent to Amazon Pay when the charge permission of a mandate is closed
+const MANDATE_REVOKE_CLOSURE_REASON: &str = "Mandate revoked by merchant";
+
+/// Request closing the charge permission of a mandate, the pending charges of the charge
+/// permission are left to complete
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AmazonpayCloseChargePermissionRequest {
+    closure_reason: String,
+    cancel_pending_charges: bool,
+}
+
+impl TryFrom<&MandateRevokeRouterData> for AmazonpayCloseChargePermissionRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(_item: &MandateRevokeRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            closure_reason: MANDATE_REVOKE_CLOSURE_REASON.to_string(),
+            cancel_pending_charges: false,
+        })
+    }
+}
+
+/// State of an Amazon Pay charge permission
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum AmazonpayChargePermissionState {
+    Chargeable,
+    NonChargeable,
+    Closed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AmazonpayChargePermissionStatusDetails {
+    state: AmazonpayChargePermissionState,
+    reasons: Option<Vec<AmazonpayChargePermissionReason>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AmazonpayChargePermissionReason {
+    reason_code: String,
+    reason_description: Option<String>,
+}
+
+/// Charge permission returned by the charge permission close API
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AmazonpayChargePermissionResponse {
+    charge_permission_id: String,
+    status_details: AmazonpayChargePermissionStatusDetails,
+}
+
+impl
+    TryFrom<
+        ResponseRouterData<
+            MandateRevoke,
+            AmazonpayChargePermissionResponse,
+            MandateRevokeRequestData,
+            MandateRevokeResponseData,
+        >,
+    > for MandateRevokeRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: ResponseRouterData<
+            MandateRevoke,
+            AmazonpayChargePermissionResponse,
+            MandateRevokeRequestData,
+            MandateRevokeResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let status_details = item.response.status_details;
+        let response = match status_details.state {
+            AmazonpayChargePermissionState::Closed => Ok(MandateRevokeResponseData {
+                mandate_status: common_enums::MandateStatus::Revoked,
+            }),
+            AmazonpayChargePermissionState::Chargeable
+            | AmazonpayChargePermissionState::NonChargeable => {
+                let reason = status_details
+                    .reasons
+                    .and_then(|reasons| reasons.into_iter().next());
+                Err(get_error_response(
+                    reason
+                        .as_ref()
+                        .map(|reason| reason.reason_code.clone())
+                        .unwrap_or_else(|| NO_ERROR_CODE.to_string()),
+                    reason.and_then(|reason| reason.reason_description),
+                    item.http_code,
+                    Some(item.response.charge_permission_id),
+                ))
+            }
+        };
+        Ok(Self {
+            response,
+            ..item.data
+        })
+    }
+}
+
+/// Created checkout session along with the signed payload of the Amazon Pay button
+#[derive(Debug, Clone, PartialEq)]
+pub struct AmazonpaySessionTokenData {
+    pub checkout_session: AmazonpayCreateCheckoutSessionResponse,
+    /// Request body the checkout session was created with
+    pub payload: String,
+    /// Signature of the payload with the private key of the merchant account
+    pub signature: String,
+}
+
+impl AmazonpaySessionTokenData {
+    fn into_session_token(self, public_key_id: &Secret<String>) -> SessionToken {
+        SessionToken::AmazonPay(Box::new(AmazonPaySessionTokenResponse {
+            checkout_session_id: self.checkout_session.checkout_session_id,
+            payload: self.payload,
+            signature: self.signature,
+            public_key_id: public_key_id.peek().to_string(),
+        }))
+    }
+}
+
+impl TryFrom<PaymentsSessionResponseRouterData<AmazonpaySessionTokenData>>
+    for PaymentsSessionRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: PaymentsSessionResponseRouterData<AmazonpaySessionTokenData>,
+    ) -> Result<Self, Self::Error> {
+        let auth = AmazonpayAuthType::try_from(&item.data.connector_auth_type)?;
+        Ok(Self {
+            response: Ok(PaymentsResponseData::SessionResponse {
+                session_token: item.response.into_session_token(&auth.public_key_id),
+            }),
+            ..item.data
+        })
+    }
+}
+
+// REFUND :
+// Type definition for RefundRequest
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AmazonpayRefundRequest {
+    charge_id: String,
+    refund_amount: AmazonpayPrice,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AmazonpayPrice {
+    amount: StringMajorUnit,
+    currency_code: enums::Currency,
+}
+
+impl AmazonpayRefundRequest {
+    fn new(charge_id: String, amount: StringMajorUnit, currency_code: enums::Currency) -> Self {
+        Self {
+            charge_id,
+            refund_amount: AmazonpayPrice {
+                amount,
+                currency_code,
+            },
+        }
+    }
+}
+
+impl<F> TryFrom<&AmazonpayRouterData<&RefundsRouterData<F>>> for AmazonpayRefundRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &AmazonpayRouterData<&RefundsRouterData<F>>) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            item.router_data.request.connector_transaction_id.clone(),
+            item.amount.to_owned(),
+            item.router_data.request.currency,
+        ))
+    }
+}
+
+/// Maximum length of the idempotency key of an Amazon Pay request
+const AMAZONPAY_IDEMPOTENCY_KEY_MAX_LENGTH: usize = 32;
+
+/// Idempotency key of the request creating the resource with the given reference, such as the
+/// refund id of a refund. Every partial refund of a charge has its own refund id, so that the
+/// refunds of a charge are not mistaken for retries of one another. References which are longer
+/// than Amazon Pay allows or hold other characters than alphanumerics, `_` and `-` are replaced
+/// by a prefix of their SHA-256 hash.
+pub fn get_idempotency_key(reference: &str) -> String {
+    if reference.len() <= AMAZONPAY_IDEMPOTENCY_KEY_MAX_LENGTH
+        && reference
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    {
+        reference.to_string()
+    } else {
+        let mut idempotency_key =
+            hex::encode(digest::digest(&digest::SHA256, reference.as_bytes()));
+        idempotency_key.truncate(AMAZONPAY_IDEMPOTENCY_KEY_MAX_LENGTH);
+        idempotency_key
+    }
+}
+
+/// Keys of the Amazon Pay resources holding the personal data of the buyer
+const AMAZONPAY_BUYER_PII_KEYS: &[&str] = &[
+    "buyerId",
+    "name",
+    "email",
+    "phoneNumber",
+    "shippingAddress",
+    "billingAddress",
+];
+
+/// Value replacing the personal data of the buyer in the masked responses
+const AMAZONPAY_MASKED_VALUE: &str = "*** masked ***";
+
+fn mask_buyer_pii(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(fields) => {
+            for (key, value) in fields.iter_mut() {
+                if AMAZONPAY_BUYER_PII_KEYS.contains(&key.as_str()) {
+                    if !value.is_null() {
+                        *value = serde_json::Value::String(AMAZONPAY_MASKED_VALUE.to_string());
+                    }
+                } else {
+                    mask_buyer_pii(value);
+                }
+            }
+        }
+        serde_json::Value::Array(values) => values.iter_mut().for_each(mask_buyer_pii),
+        _ => {}
+    }
+}
+
+/// The response of Amazon Pay with the name, email, phone number and addresses of the buyer
+/// masked. Responses which are not JSON cannot be masked and are not kept.
+pub fn mask_connector_response(response: &[u8]) -> Option<String> {
+    let mut response = serde_json::from_slice::<serde_json::Value>(response).ok()?;
+    mask_buyer_pii(&mut response);
+    Some(response.to_string())
+}
+
+// Type definition for Refund Response
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum RefundStatus {
+    RefundInitiated,
+    Refunded,
+    Declined,
+}
+
+impl From<RefundStatus> for enums::RefundStatus {
+    fn from(item: RefundStatus) -> Self {
+        match item {
+            RefundStatus::RefundInitiated => Self::Pending,
+            RefundStatus::Refunded => Self::Success,
+            RefundStatus::Declined => Self::Failure,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AmazonpayRefundStatusDetails {
+    state: RefundStatus,
+    reason_code: Option<String>,
+    reason_description: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefundResponse {
+    refund_id: String,
+    charge_id: String,
+    refund_amount: AmazonpayPrice,
+    status_details: AmazonpayRefundStatusDetails,
+}
+
+impl<F> TryFrom<RefundsResponseRouterData<F, RefundResponse>> for RefundsRouterData<F> {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: RefundsResponseRouterData<F, RefundResponse>) -> Result<Self, Self::Error> {
+        let status_details = item.response.status_details;
+        let response = match status_details.state {
+            RefundStatus::Declined => Err(get_error_response(
+                status_details
+                    .reason_code
+                    .unwrap_or_else(|| NO_ERROR_CODE.to_string()),
+                status_details.reason_description,
+                item.http_code,
+                Some(item.response.refund_id),
+            )),
+            status => Ok(RefundsResponseData {
+                connector_refund_id: item.response.refund_id,
+                refund_status: enums::RefundStatus::from(status),
+            }),
+        };
+        Ok(Self {
+            response,
+            ..item.data
+        })
+    }
+}
+
+// WEBHOOKS :
+/// Format of the timestamps of Amazon Pay, such as `20190714T155300Z`
+const AMAZONPAY_TIMESTAMP_FORMAT: &[time::format_description::BorrowedFormatItem<'static>] =
+    time::macros::format_description!("[year][month][day]T[hour][minute][second]Z");
+
+/// Notification of Amazon Pay, delivered through Amazon SNS with the details of the
+/// notification serialized as a JSON string
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AmazonpayWebhookNotification {
+    message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AmazonpayWebhookObjectType {
+    Charge,
+    Refund,
+    Dispute,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AmazonpayWebhookMessage {
+    pub object_type: AmazonpayWebhookObjectType,
+    pub object_id: String,
+    /// The dispute the notification is sent for, present in the notifications of disputes
+    pub dispute: Option<AmazonpayDispute>,
+}
+
+impl AmazonpayWebhookMessage {
+    pub fn from_webhook_body(body: &[u8]) -> CustomResult<Self, errors::ConnectorError> {
+        let notification: AmazonpayWebhookNotification = serde_json::from_slice(body)
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+        serde_json::from_str(&notification.message)
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)
+    }
+
+    pub fn get_dispute(self) -> CustomResult<AmazonpayDispute, errors::ConnectorError> {
+        self.dispute
+            .ok_or(errors::ConnectorError::WebhookResourceObjectNotFound)
+            .attach_printable("Dispute notification without the dispute")
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, strum::Display)]
+pub enum AmazonpayDisputeState {
+    UnderReview,
+    ActionRequired,
+    Resolved,
+    Closed,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum AmazonpayDisputeResolution {
+    BuyerWon,
+    MerchantWon,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AmazonpayDisputeStatusDetails {
+    pub state: AmazonpayDisputeState,
+    /// Party the dispute was resolved in favour of, once the dispute is resolved
+    pub resolution: Option<AmazonpayDisputeResolution>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AmazonpayDispute {
+    pub dispute_id: String,
+    pub charge_id: String,
+    pub reason_code: Option<String>,
+    pub reason_description: Option<String>,
+    pub disputed_amount: AmazonpayPrice,
+    pub resolution_deadline: Option<String>,
+    pub status_details: AmazonpayDisputeStatusDetails,
+    pub creation_timestamp: Option<String>,
+}
+
+fn parse_timestamp(
+    timestamp: Option<String>,
+) -> CustomResult<Option<PrimitiveDateTime>, errors::ConnectorError> {
+    timestamp
+        .map(|timestamp| {
+            PrimitiveDateTime::parse(&timestamp, AMAZONPAY_TIMESTAMP_FORMAT)
+                .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)
+                .attach_printable_lazy(|| format!("Invalid Amazon Pay timestamp {timestamp}"))
+        })
+        .transpose()
+}
+
+impl TryFrom<&AmazonpayDisputeStatusDetails> for IncomingWebhookEvent {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(status_details: &AmazonpayDisputeStatusDetails) -> Result<Self, Self::Error> {
+        match (status_details.state, status_details.resolution) {
+            (AmazonpayDisputeState::ActionRequired, _) => Ok(Self::DisputeOpened),
+            (AmazonpayDisputeState::UnderReview, _) => Ok(Self::DisputeChallenged),
+            (AmazonpayDisputeState::Resolved, Some(AmazonpayDisputeResolution::MerchantWon)) => {
+                Ok(Self::DisputeWon)
+            }
+            (AmazonpayDisputeState::Resolved, Some(AmazonpayDisputeResolution::BuyerWon)) => {
+                Ok(Self::DisputeLost)
+            }
+            (AmazonpayDisputeState::Closed, _) => Ok(Self::DisputeCancelled),
+            (AmazonpayDisputeState::Resolved, None) => {
+                Err(report!(errors::ConnectorError::WebhookEventTypeNotFound))
+                    .attach_printable("Resolved dispute without a resolution")
+            }
+        }
+    }
+}
+
+impl TryFrom<AmazonpayDispute> for DisputePayload {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(dispute: AmazonpayDispute) -> Result<Self, Self::Error> {
+        let currency = dispute.disputed_amount.currency_code;
+        let amount = utils::convert_back_amount_to_minor_units(
+            &StringMajorUnitForConnector,
+            dispute.disputed_amount.amount,
+            currency,
+        )?;
+        Ok(Self {
+            amount: utils::convert_amount(&StringMinorUnitForConnector, amount, currency)?,
+            currency,
+            dispute_stage: enums::DisputeStage::Dispute,
+            connector_status: dispute.status_details.state.to_string(),
+            connector_dispute_id: dispute.dispute_id,
+            connector_reason: dispute.reason_description,
+            connector_reason_code: dispute.reason_code,
+            challenge_required_by: parse_timestamp(dispute.resolution_deadline)?,
+            created_at: parse_timestamp(dispute.creation_timestamp)?,
+            updated_at: None,
+        })
+    }
+}
+
+/// Error returned by the Amazon Pay API. Every field is optional so that an error of an
+/// unexpected shape is still reported as a failure of the request.
+#[derive(Default, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AmazonpayErrorResponse {
+    pub reason_code: Option<String>,
+    pub message: Option<String>,
+    /// Id of the charge the request failed for, when the error concerns a charge
+    pub charge_id: Option<String>,
+    /// Id of the checkout session the request failed for, when the error concerns a checkout
+    /// session
+    pub checkout_session_id: Option<String>,
+}
+
+impl AmazonpayErrorResponse {
+    pub fn into_error_response(self, status_code: u16) -> ErrorResponse {
+        get_error_response(
+            self.reason_code
+                .unwrap_or_else(|| NO_ERROR_CODE.to_string()),
+            self.message,
+            status_code,
+            self.charge_id.or(self.checkout_session_id),
+        )
+    }
+}
+
+/// Unified error details of an Amazon Pay reason code
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AmazonpayErrorMapping {
+    pub unified_code: &'static str,
+    pub unified_message: &'static str,
+    /// Status of the attempt, `None` when the error does not conclude the attempt
+    pub attempt_status: Option<enums::AttemptStatus>,
+}
+
+const fn mapping(
+    unified_code: &'static str,
+    unified_message: &'static str,
+    attempt_status: Option<enums::AttemptStatus>,
+) -> AmazonpayErrorMapping {
+    AmazonpayErrorMapping {
+        unified_code,
+        unified_message,
+        attempt_status,
+    }
+}
+
+/// Mapping used for reason codes which are not present in [`AMAZONPAY_REASON_CODE_MAPPINGS`]
+pub const AMAZONPAY_DEFAULT_ERROR_MAPPING: AmazonpayErrorMapping =
+    mapping("UE_9000", "Something went wrong", None);
+
+/// Documented Amazon Pay reason codes of charge declines and API errors.
+///
+/// The reason code and the unified message are sent as the error code and message of the
+/// connector error response, which are used to look up the unified code of the payment attempt in
+/// the gateway status map. Any change to this table must be accompanied by a migration updating
+/// the `amazonpay` entries of the gateway status map.
+pub const AMAZONPAY_REASON_CODE_MAPPINGS: &[(&str, AmazonpayErrorMapping)] = &[
+    (
+        "SoftDeclined",
+        mapping(
+            "UE_1000",
+            "Payment was declined, the customer may retry the payment",
+            Some(enums::AttemptStatus::AuthorizationFailed),
+        ),
+    ),
+    (
+        "HardDeclined",
+        mapping(
+            "UE_1000",
+            "Payment was declined, the customer should use a different payment method",
+            Some(enums::AttemptStatus::AuthorizationFailed),
+        ),
+    ),
+    (
+        "AmazonRejected",
+        mapping(
+            "UE_1000",
+            "Payment was rejected by Amazon Pay",
+            Some(enums::AttemptStatus::Failure),
+        ),
+    ),
+    (
+        "PaymentMethodNotAllowed",
+        mapping(
+            "UE_1000",
+            "Payment method is not allowed for this payment",
+            Some(enums::AttemptStatus::Failure),
+        ),
+    ),
+    (
+        "BuyerCanceled",
+        mapping(
+            "UE_1000",
+            "Payment was canceled by the customer",
+            Some(enums::AttemptStatus::Failure),
+        ),
+    ),
+    (
+        "CheckoutSessionCanceled",
+        mapping(
+            "UE_1000",
+            "Checkout session was canceled by the customer",
+            Some(enums::AttemptStatus::Failure),
+        ),
+    ),
+    (
+        "TransactionAmountExceeded",
+        mapping(
+            "UE_2000",
+            "Payment amount exceeds the amount authorized by the customer",
+            Some(enums::AttemptStatus::Failure),
+        ),
+    ),
+    (
+        "CurrencyMismatch",
+        mapping(
+            "UE_2000",
+            "Payment currency does not match the currency of the checkout session",
+            Some(enums::AttemptStatus::Failure),
+        ),
+    ),
+    (
+        "ExpiredUnused",
+        mapping(
+            "UE_2000",
+            "Authorization expired before it was captured",
+            Some(enums::AttemptStatus::Failure),
+        ),
+    ),
+    (
+        "MerchantCanceled",
+        mapping(
+            "UE_2000",
+            "Payment was canceled by the merchant",
+            Some(enums::AttemptStatus::Voided),
+        ),
+    ),
+    (
+        "ChargePermissionCanceled",
+        mapping(
+            "UE_2000",
+            "Charge permission of the payment has been canceled",
+            Some(enums::AttemptStatus::Failure),
+        ),
+    ),
+    (
+        "InvalidChargePermissionStatus",
+        mapping(
+            "UE_2000",
+            "Charge permission is not in a state which allows this operation",
+            Some(enums::AttemptStatus::Failure),
+        ),
+    ),
+    (
+        "InvalidCheckoutSessionStatus",
+        mapping(
+            "UE_2000",
+            "Checkout session is not in a state which allows this operation",
+            Some(enums::AttemptStatus::Failure),
+        ),
+    ),
+    (
+        "ProcessingFailure",
+        mapping(
+            "UE_3000",
+            "Amazon Pay could not process the payment",
+            Some(enums::AttemptStatus::Failure),
+        ),
+    ),
+    (
+        "TransactionTimedOut",
+        mapping(
+            "UE_3000",
+            "Payment timed out at Amazon Pay",
+            Some(enums::AttemptStatus::Failure),
+        ),
+    ),
+    (
+        "AmazonCanceled",
+        mapping(
+            "UE_3000",
+            "Payment was canceled by Amazon Pay",
+            Some(enums::AttemptStatus::Failure),
+        ),
+    ),
+    (
+        "TooManyRequests",
+        mapping("UE_3000", "Amazon Pay is throttling requests", None),
+    ),
+    (
+        "InternalServerError",
+        mapping("UE_3000", "Amazon Pay encountered an internal error", None),
+    ),
+    (
+        "ServiceUnavailable",
+        mapping("UE_3000", "Amazon Pay is temporarily unavailable", None),
+    ),
+    (
+        "InvalidParameterValue",
+        mapping(
+            "UE_4000",
+            "Request contains an invalid parameter value",
+            None,
+        ),
+    ),
+    (
+        "MissingParameterValue",
+        mapping("UE_4000", "Request is missing a required parameter", None),
+    ),
+    (
+        "UnauthorizedAccess",
+        mapping(
+            "UE_4000",
+            "Amazon Pay credentials are not authorized for this request",
+            None,
+        ),
+    ),
+    (
+        "InvalidRequestSignature",
+        mapping(
+            "UE_4000",
+            "Request signature could not be verified by Amazon Pay",
+            None,
+        ),
+    ),
+    (
+        "ResourceNotFound",
+        mapping(
+            "UE_4000",
+            "Requested resource was not found at Amazon Pay",
+            None,
+        ),
+    ),
+];
+
+pub fn get_amazonpay_error_mapping(reason_code: &str) -> AmazonpayErrorMapping {
+    AMAZONPAY_REASON_CODE_MAPPINGS
+        .iter()
+        .find(|(code, _)| *code == reason_code)
+        .map(|(_, mapping)| *mapping)
+        .unwrap_or_else(|| {
+            router_env::logger::warn!("Unmapped Amazon Pay reason code: {reason_code}");
+            metrics::CONNECTOR_UNMAPPED_ERROR_CODE.add(
+                1,
+                router_env::metric_attributes!(
+                    ("connector", "amazonpay"),
+                    ("reason_code", reason_code.to_owned())
+                ),
+            );
+            AMAZONPAY_DEFAULT_ERROR_MAPPING
+        })
+}
+
+pub fn get_error_response(
+    reason_code: String,
+    reason_description: Option<String>,
+    status_code: u16,
+    connector_transaction_id: Option<String>,
+) -> ErrorResponse {
+    let error_mapping = get_amazonpay_error_mapping(&reason_code);
+    ErrorResponse {
+        status_code,
+        code: reason_code,
+        message: error_mapping.unified_message.to_string(),
+        reason: reason_description,
+        attempt_status: error_mapping.attempt_status,
+        connector_transaction_id,
+        network_advice_code: None,
+        network_decline_code: None,
+        network_error_message: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn test_reason_code_mappings() {
+        let mut reason_codes = HashSet::new();
+        for (reason_code, error_mapping) in AMAZONPAY_REASON_CODE_MAPPINGS {
+            assert!(
+                reason_codes.insert(*reason_code),
+                "duplicate mapping for {reason_code}"
+            );
+            assert_eq!(get_amazonpay_error_mapping(reason_code), *error_mapping);
+            assert_ne!(*error_mapping, AMAZONPAY_DEFAULT_ERROR_MAPPING);
+            assert!(error_mapping.unified_code.starts_with("UE_"));
+
+            let error_response = get_error_response(
+                reason_code.to_string(),
+                Some("Amazon Pay description".to_string()),
+                400,
+                None,
+            );
+            assert_eq!(error_response.code, *reason_code);
+            assert_eq!(error_response.message, error_mapping.unified_message);
+            assert_eq!(error_response.attempt_status, error_mapping.attempt_status);
+        }
+    }
+
+    #[test]
+    fn test_unmapped_reason_code_uses_default_mapping() {
+        let error_response = get_error_response(
+            "SomeNewReasonCode".to_string(),
+            Some("Something new happened".to_string()),
+            400,
+            Some("charge_id".to_string()),
+        );
+
+        assert_eq!(error_response.code, "SomeNewReasonCode");
+        assert_eq!(
+            error_response.message,
+            AMAZONPAY_DEFAULT_ERROR_MAPPING.unified_message
+        );
+        assert_eq!(error_response.reason.unwrap(), "Something new happened");
+        assert_eq!(error_response.attempt_status, None);
+        assert_eq!(
+            error_response.connector_transaction_id.unwrap(),
+            "charge_id"
+        );
+    }
+
+    #[test]
+    fn test_cancel_request_body() {
+        let cancel_request =
+            AmazonpayCancelRequest::new(Some("Order canceled by customer".to_string()));
+        assert_eq!(
+            serde_json::to_value(&cancel_request).unwrap(),
+            serde_json::json!({ "cancellationReason": "Order canceled by customer" })
+        );
+
+        let default_cancel_request = AmazonpayCancelRequest::new(None);
+        assert_eq!(
+            serde_json::to_value(&default_cancel_request).unwrap(),
+            serde_json::json!({ "cancellationReason": DEFAULT_CANCELLATION_REASON })
+        );
+    }
+
+    #[test]
+    fn test_charge_state_mapping() {
+        for (state, attempt_status) in [
+            (
+                AmazonpayChargeState::AuthorizationInitiated,
+                enums::AttemptStatus::Authorizing,
+            ),
+            (
+                AmazonpayChargeState::Authorized,
+                enums::AttemptStatus::Authorized,
+            ),
+            (
+                AmazonpayChargeState::CaptureInitiated,
+                enums::AttemptStatus::CaptureInitiated,
+            ),
+            (
+                AmazonpayChargeState::Captured,
+                enums::AttemptStatus::Charged,
+            ),
+            (AmazonpayChargeState::Canceled, enums::AttemptStatus::Voided),
+            (
+                AmazonpayChargeState::Declined,
+                enums::AttemptStatus::Failure,
+            ),
+        ] {
+            assert_eq!(enums::AttemptStatus::from(state), attempt_status);
+        }
+    }
+
+    #[test]
+    fn test_cancel_response_deserialization() {
+        let cancel_response: AmazonpayCancelResponse = serde_json::from_value(serde_json::json!({
+            "chargeId": "S01-5105180-3221187-C056351",
+            "chargePermissionId": "S01-5105180-3221187",
+            "chargeAmount": { "amount": "14.00", "currencyCode": "USD" },
+            "statusDetails": {
+                "state": "Canceled",
+                "reasonCode": "MerchantCanceled",
+                "reasonDescription": "Order canceled by customer",
+                "lastUpdatedTimestamp": "20190714T155300Z"
+            },
+            "creationTimestamp": "20190714T155300Z"
+        }))
+        .unwrap();
+
+        assert_eq!(cancel_response.charge_id, "S01-5105180-3221187-C056351");
+        assert_eq!(
+            enums::AttemptStatus::from(cancel_response.status_details.state),
+            enums::AttemptStatus::Voided
+        );
+    }
+
+    #[test]
+    fn test_resource_type_of_connector_transaction_id() {
+        assert_eq!(
+            AmazonpayResourceType::from_connector_transaction_id(
+                "S01-5105180-3221187-C056351",
+                None
+            ),
+            AmazonpayResourceType::Charge
+        );
+        assert_eq!(
+            AmazonpayResourceType::from_connector_transaction_id(
+                "bd504926-f659-4ad7-a1a9-9a747aaf5275",
+                None
+            ),
+            AmazonpayResourceType::CheckoutSession
+        );
+        assert_eq!(
+            AmazonpayResourceType::from_connector_transaction_id(
+                "bd504926-f659-4ad7-a1a9-9a747aaf5275",
+                Some(&serde_json::json!({ "resource_type": "charge" }))
+            ),
+            AmazonpayResourceType::Charge
+        );
+        assert_eq!(
+            AmazonpayResourceType::from_connector_transaction_id(
+                "S01-5105180-3221187-C056351",
+                Some(&serde_json::json!({ "resource_type": "checkout_session" }))
+            ),
+            AmazonpayResourceType::CheckoutSession
+        );
+        assert_eq!(
+            AmazonpayResourceType::from_connector_transaction_id(
+                "bd504926-f659-4ad7-a1a9-9a747aaf5275",
+                Some(&serde_json::json!({ "unrelated": true }))
+            ),
+            AmazonpayResourceType::CheckoutSession
+        );
+    }
+
+    #[test]
+    fn test_checkout_session_state_mapping() {
+        for (state, attempt_status) in [
+            (
+                AmazonpayCheckoutSessionState::Open,
+                enums::AttemptStatus::AuthenticationPending,
+            ),
+            (
+                AmazonpayCheckoutSessionState::Completed,
+                enums::AttemptStatus::Pending,
+            ),
+            (
+                AmazonpayCheckoutSessionState::Canceled,
+                enums::AttemptStatus::Failure,
+            ),
+        ] {
+            assert_eq!(enums::AttemptStatus::from(state), attempt_status);
+        }
+    }
+
+    fn checkout_session_response_fixture(
+        state: &str,
+        reason_code: Option<&str>,
+        charge_id: Option<&str>,
+    ) -> AmazonpayCheckoutSessionResponse {
+        serde_json::from_value(serde_json::json!({
+            "checkoutSessionId": "bd504926-f659-4ad7-a1a9-9a747aaf5275",
+            "chargePermissionId": null,
+            "chargeId": charge_id,
+            "statusDetails": {
+                "state": state,
+                "reasonCode": reason_code,
+                "reasonDescription": null,
+                "lastUpdatedTimestamp": "20191015T204327Z"
+            },
+            "creationTimestamp": "20191015T204313Z"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_open_checkout_session_is_synced_again() {
+        let checkout_session = checkout_session_response_fixture("Open", None, None);
+        assert_eq!(
+            checkout_session.get_resource(),
+            (
+                "bd504926-f659-4ad7-a1a9-9a747aaf5275".to_string(),
+                AmazonpayResourceType::CheckoutSession
+            )
+        );
+    }
+
+    #[test]
+    fn test_completed_checkout_session_is_synced_with_its_charge() {
+        let checkout_session = checkout_session_response_fixture(
+            "Completed",
+            None,
+            Some("S01-5105180-3221187-C056351"),
+        );
+        assert_eq!(
+            checkout_session.get_resource(),
+            (
+                "S01-5105180-3221187-C056351".to_string(),
+                AmazonpayResourceType::Charge
+            )
+        );
+    }
+
+    #[test]
+    fn test_canceled_checkout_session_reason_code() {
+        let checkout_session =
+            checkout_session_response_fixture("Canceled", Some("BuyerCanceled"), None);
+        assert_eq!(
+            checkout_session.status_details.state,
+            AmazonpayCheckoutSessionState::Canceled
+        );
+        assert_eq!(
+            get_error_response(
+                checkout_session.status_details.reason_code.unwrap(),
+                None,
+                200,
+                None
+            )
+            .attempt_status,
+            Some(enums::AttemptStatus::Failure)
+        );
+    }
+
+    #[test]
+    fn test_checkout_session_request() {
+        let checkout_session_request = AmazonpayCheckoutSessionRequest::new(
+            Secret::new("amzn1.application-oa2-client.8b5e45312b5248b69eeaStoreId".to_string()),
+            "https://example.com/checkout/return".to_string(),
+            common_utils::types::AmountConvertor::convert(
+                &common_utils::types::StringMajorUnitForConnector,
+                common_utils::types::MinorUnit::new(1400),
+                enums::Currency::EUR,
+            )
+            .unwrap(),
+            enums::Currency::EUR,
+        );
+
+        assert_eq!(
+            serde_json::to_value(&checkout_session_request).unwrap(),
+            serde_json::json!({
+                "webCheckoutDetails": {
+                    "checkoutResultReturnUrl": "https://example.com/checkout/return",
+                    "checkoutMode": "ProcessOrder"
+                },
+                "storeId": "amzn1.application-oa2-client.8b5e45312b5248b69eeaStoreId",
+                "chargePermissionType": "OneTime",
+                "paymentDetails": {
+                    "paymentIntent": "AuthorizeWithCapture",
+                    "chargeAmount": { "amount": "14.00", "currencyCode": "EUR" },
+                    "presentmentCurrency": "EUR"
+                }
+            })
+        );
+    }
+
+    fn shipping_address(line1: &str) -> Address {
+        Address {
+            address: Some(hyperswitch_domain_models::address::AddressDetails {
+                city: Some("Seattle".to_string()),
+                country: Some(enums::CountryAlpha2::US),
+                line1: Some(Secret::new(line1.to_string())),
+                line2: Some(Secret::new("Suite 200".to_string())),
+                line3: None,
+                zip: Some(Secret::new("98101".to_string())),
+                state: Some(Secret::new("WA".to_string())),
+                first_name: Some(Secret::new("John".to_string())),
+                last_name: Some(Secret::new("Doe".to_string())),
+            }),
+            phone: Some(hyperswitch_domain_models::address::PhoneDetails {
+                number: Some(Secret::new("2065550100".to_string())),
+                country_code: Some("+1".to_string()),
+            }),
+            email: None,
+        }
+    }
+
+    #[test]
+    fn test_checkout_session_request_with_order_details() {
+        let checkout_session_request = AmazonpayCheckoutSessionRequest::new(
+            Secret::new("store_id".to_string()),
+            "https://example.com/payments/complete".to_string(),
+            string_major_unit(1400, enums::Currency::USD),
+            enums::Currency::USD,
+        )
+        .with_order_details(
+            AmazonpayAddressDetails::from_address(&shipping_address("410 Terry Ave N")),
+            AmazonpayMerchantMetadata::new(
+                "pay_RwjuCpKPDsbP2I1EZjs7_1".to_string(),
+                Some("Thank you for your order".to_string()),
+                Some(r#"{"order_id":"order_1"}"#.to_string()),
+            ),
+            Some("HYPERSWITCH".to_string()),
+        );
+
+        assert_eq!(
+            serde_json::to_value(&checkout_session_request).unwrap(),
+            serde_json::json!({
+                "webCheckoutDetails": {
+                    "checkoutResultReturnUrl": "https://example.com/payments/complete",
+                    "checkoutMode": "ProcessOrder"
+                },
+                "storeId": "store_id",
+                "chargePermissionType": "OneTime",
+                "paymentDetails": {
+                    "paymentIntent": "AuthorizeWithCapture",
+                    "chargeAmount": { "amount": "14.00", "currencyCode": "USD" },
+                    "presentmentCurrency": "USD",
+                    "softDescriptor": "HYPERSWITCH"
+                },
+                "addressDetails": {
+                    "name": "John Doe",
+                    "addressLine1": "410 Terry Ave N",
+                    "addressLine2": "Suite 200",
+                    "city": "Seattle",
+                    "stateOrRegion": "WA",
+                    "postalCode": "98101",
+                    "countryCode": "US",
+                    "phoneNumber": "+12065550100"
+                },
+                "merchantMetadata": {
+                    "merchantReferenceId": "pay_RwjuCpKPDsbP2I1EZjs7_1",
+                    "noteToBuyer": "Thank you for your order",
+                    "customInformation": "{\"order_id\":\"order_1\"}"
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_checkout_session_fields_are_truncated_to_their_max_length() {
+        let checkout_session_request = AmazonpayCheckoutSessionRequest::new(
+            Secret::new("store_id".to_string()),
+            "https://example.com/payments/complete".to_string(),
+            string_major_unit(1400, enums::Currency::USD),
+            enums::Currency::USD,
+        )
+        .with_order_details(
+            AmazonpayAddressDetails::from_address(&shipping_address(&"é".repeat(200))),
+            AmazonpayMerchantMetadata::new("r".repeat(300), Some("n".repeat(300)), None),
+            Some("HYPERSWITCH PAYMENTS".to_string()),
+        );
+
+        assert_eq!(
+            checkout_session_request.payment_details.soft_descriptor,
+            Some("HYPERSWITCH PAYM".to_string())
+        );
+        let address_details = checkout_session_request.address_details.unwrap();
+        assert_eq!(
+            address_details.address_line1.peek(),
+            &"é".repeat(AMAZONPAY_ADDRESS_LINE_MAX_LENGTH)
+        );
+        let merchant_metadata = checkout_session_request.merchant_metadata.unwrap();
+        assert_eq!(
+            merchant_metadata.merchant_reference_id,
+            "r".repeat(AMAZONPAY_MERCHANT_REFERENCE_ID_MAX_LENGTH)
+        );
+        assert_eq!(
+            merchant_metadata.note_to_buyer,
+            Some("n".repeat(AMAZONPAY_NOTE_TO_BUYER_MAX_LENGTH))
+        );
+        assert_eq!(merchant_metadata.custom_information, None);
+    }
+
+    #[test]
+    fn test_address_without_required_fields_is_not_sent() {
+        let mut address = shipping_address("410 Terry Ave N");
+        if let Some(address_details) = address.address.as_mut() {
+            address_details.country = None;
+        }
+        assert_eq!(AmazonpayAddressDetails::from_address(&address), None);
+    }
+
+    #[test]
+    fn test_connector_metadata_without_store_id() {
+        let metadata = Some(pii::SecretSerdeValue::new(serde_json::json!({})));
+        assert!(AmazonpayConnectorMetadataObject::try_from(&metadata).is_err());
+        assert!(AmazonpayConnectorMetadataObject::try_from(&None).is_err());
+
+        let metadata = Some(pii::SecretSerdeValue::new(
+            serde_json::json!({ "store_id": "store_id" }),
+        ));
+        assert_eq!(
+            AmazonpayConnectorMetadataObject::try_from(&metadata)
+                .unwrap()
+                .store_id
+                .peek(),
+            "store_id"
+        );
+    }
+
+    #[test]
+    fn test_session_token_of_created_checkout_session() {
+        let checkout_session: AmazonpayCreateCheckoutSessionResponse =
+            serde_json::from_value(serde_json::json!({
+                "checkoutSessionId": "bd504926-f659-4ad7-a1a9-9a747aaf5275",
+                "webCheckoutDetails": {
+                    "checkoutReviewReturnUrl": null,
+                    "checkoutResultReturnUrl": "https://example.com/checkout/return",
+                    "amazonPayRedirectUrl": null
+                },
+                "statusDetails": { "state": "Open" },
+                "creationTimestamp": "20191015T204313Z"
+            }))
+            .unwrap();
+        let session_token = AmazonpaySessionTokenData {
+            checkout_session,
+            payload: "{\"storeId\":\"store_id\"}".to_string(),
+            signature: "signature".to_string(),
+        }
+        .into_session_token(&Secret::new("SANDBOX-PUBLIC_KEY_ID".to_string()));
+
+        assert_eq!(
+            session_token,
+            SessionToken::AmazonPay(Box::new(AmazonPaySessionTokenResponse {
+                checkout_session_id: "bd504926-f659-4ad7-a1a9-9a747aaf5275".to_string(),
+                payload: "{\"storeId\":\"store_id\"}".to_string(),
+                signature: "signature".to_string(),
+                public_key_id: "SANDBOX-PUBLIC_KEY_ID".to_string(),
+            }))
+        );
+    }
+
+    fn string_major_unit(minor_amount: i64, currency: enums::Currency) -> StringMajorUnit {
+        common_utils::types::AmountConvertor::convert(
+            &common_utils::types::StringMajorUnitForConnector,
+            common_utils::types::MinorUnit::new(minor_amount),
+            currency,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_recurring_checkout_session_request() {
+        let checkout_session_request = AmazonpayCheckoutSessionRequest::new_recurring(
+            Secret::new("store_id".to_string()),
+            "https://example.com/payments/redirect".to_string(),
+            string_major_unit(1400, enums::Currency::USD),
+            enums::Currency::USD,
+        );
+
+        assert_eq!(
+            serde_json::to_value(&checkout_session_request).unwrap(),
+            serde_json::json!({
+                "webCheckoutDetails": {
+                    "checkoutResultReturnUrl": "https://example.com/payments/redirect",
+                    "checkoutMode": "ProcessOrder"
+                },
+                "storeId": "store_id",
+                "chargePermissionType": "Recurring",
+                "recurringMetadata": {
+                    "frequency": { "unit": "Variable", "value": "0" }
+                },
+                "paymentDetails": {
+                    "paymentIntent": "Confirm",
+                    "chargeAmount": { "amount": "14.00", "currencyCode": "USD" },
+                    "presentmentCurrency": "USD"
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_created_checkout_session_redirect_url() {
+        let checkout_session: AmazonpayCreateCheckoutSessionResponse =
+            serde_json::from_value(serde_json::json!({
+                "checkoutSessionId": "bd504926-f659-4ad7-a1a9-9a747aaf5275",
+                "webCheckoutDetails": {
+                    "checkoutResultReturnUrl": "https://example.com/payments/redirect",
+                    "amazonPayRedirectUrl": "https://pay.amazon.com/checkout?amazonCheckoutSessionId=bd504926-f659-4ad7-a1a9-9a747aaf5275"
+                }
+            }))
+            .unwrap();
+        assert_eq!(
+            checkout_session.get_redirect_url(),
+            Some("https://pay.amazon.com/checkout?amazonCheckoutSessionId=bd504926-f659-4ad7-a1a9-9a747aaf5275")
+        );
+    }
+
+    #[test]
+    fn test_created_checkout_session_redirects_the_buyer() {
+        let checkout_session: AmazonpayCreateCheckoutSessionResponse =
+            serde_json::from_value(serde_json::json!({
+                "checkoutSessionId": "bd504926-f659-4ad7-a1a9-9a747aaf5275",
+                "webCheckoutDetails": {
+                    "amazonPayRedirectUrl": "https://pay.amazon.com/checkout?amazonCheckoutSessionId=bd504926-f659-4ad7-a1a9-9a747aaf5275"
+                }
+            }))
+            .unwrap();
+
+        assert_eq!(
+            checkout_session.get_redirection_data().unwrap(),
+            RedirectForm::Form {
+                endpoint: "https://pay.amazon.com/checkout".to_string(),
+                method: Method::Get,
+                form_fields: std::collections::HashMap::from([(
+                    "amazonCheckoutSessionId".to_string(),
+                    "bd504926-f659-4ad7-a1a9-9a747aaf5275".to_string()
+                )]),
+            }
+        );
+    }
+
+    #[test]
+    fn test_created_checkout_session_without_redirect_url() {
+        let checkout_session: AmazonpayCreateCheckoutSessionResponse =
+            serde_json::from_value(serde_json::json!({
+                "checkoutSessionId": "bd504926-f659-4ad7-a1a9-9a747aaf5275",
+                "webCheckoutDetails": { "amazonPayRedirectUrl": null }
+            }))
+            .unwrap();
+
+        assert!(matches!(
+            checkout_session.get_redirection_data(),
+            Err(errors::ConnectorError::MissingConnectorRedirectionPayload {
+                field_name: "webCheckoutDetails.amazonPayRedirectUrl"
+            })
+        ));
+    }
+
+    #[test]
+    fn test_checkout_session_id_of_redirect_params() {
+        assert_eq!(
+            get_checkout_session_id_from_params(
+                "?amazonCheckoutSessionId=bd504926-f659-4ad7-a1a9-9a747aaf5275"
+            ),
+            Some("bd504926-f659-4ad7-a1a9-9a747aaf5275".to_string())
+        );
+        assert_eq!(
+            get_checkout_session_id_from_params(
+                "status=succeeded&amazonCheckoutSessionId=bd504926-f659-4ad7-a1a9-9a747aaf5275"
+            ),
+            Some("bd504926-f659-4ad7-a1a9-9a747aaf5275".to_string())
+        );
+        assert_eq!(
+            get_checkout_session_id_from_params("amazonCheckoutSessionId="),
+            None
+        );
+        assert_eq!(get_checkout_session_id_from_params(""), None);
+    }
+
+    #[test]
+    fn test_completed_checkout_session_after_redirect() {
+        let checkout_session: AmazonpayCheckoutSessionResponse =
+            serde_json::from_value(serde_json::json!({
+                "checkoutSessionId": "bd504926-f659-4ad7-a1a9-9a747aaf5275",
+                "chargePermissionId": "S01-5105180-3221187",
+                "chargeId": "S01-5105180-3221187-C056351",
+                "statusDetails": { "state": "Completed" }
+            }))
+            .unwrap();
+
+        // The charge is synced for its final state, as the checkout session only reports that
+        // it has been created
+        assert_eq!(
+            checkout_session.get_attempt_status(),
+            enums::AttemptStatus::Pending
+        );
+        assert_eq!(
+            checkout_session.get_resource(),
+            (
+                "S01-5105180-3221187-C056351".to_string(),
+                AmazonpayResourceType::Charge
+            )
+        );
+    }
+
+    #[test]
+    fn test_complete_checkout_session_request() {
+        let request = AmazonpayCompleteCheckoutSessionRequest {
+            charge_amount: AmazonpayPrice {
+                amount: string_major_unit(1400, enums::Currency::USD),
+                currency_code: enums::Currency::USD,
+            },
+        };
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({
+                "chargeAmount": { "amount": "14.00", "currencyCode": "USD" }
+            })
+        );
+    }
+
+    #[test]
+    fn test_completed_recurring_checkout_session_sets_up_the_mandate() {
+        let checkout_session: AmazonpayCheckoutSessionResponse =
+            serde_json::from_value(serde_json::json!({
+                "checkoutSessionId": "bd504926-f659-4ad7-a1a9-9a747aaf5275",
+                "chargePermissionId": "B01-5105180-3221187",
+                "chargeId": null,
+                "statusDetails": { "state": "Completed" }
+            }))
+            .unwrap();
+
+        assert_eq!(
+            checkout_session.get_attempt_status(),
+            enums::AttemptStatus::Charged
+        );
+        assert_eq!(
+            checkout_session
+                .get_mandate_reference()
+                .and_then(|mandate_reference| mandate_reference.connector_mandate_id),
+            Some("B01-5105180-3221187".to_string())
+        );
+    }
+
+    #[test]
+    fn test_open_checkout_session_has_no_mandate() {
+        let checkout_session: AmazonpayCheckoutSessionResponse =
+            serde_json::from_value(serde_json::json!({
+                "checkoutSessionId": "bd504926-f659-4ad7-a1a9-9a747aaf5275",
+                "chargePermissionId": null,
+                "statusDetails": { "state": "Open" }
+            }))
+            .unwrap();
+
+        assert_eq!(
+            checkout_session.get_attempt_status(),
+            enums::AttemptStatus::AuthenticationPending
+        );
+        assert!(checkout_session.get_mandate_reference().is_none());
+    }
+
+    #[test]
+    fn test_merchant_initiated_charge_request() {
+        let charge_request = AmazonpayChargeRequest::new(
+            "B01-5105180-3221187".to_string(),
+            string_major_unit(2500, enums::Currency::USD),
+            enums::Currency::USD,
+            true,
+        );
+
+        assert_eq!(
+            serde_json::to_value(&charge_request).unwrap(),
+            serde_json::json!({
+                "chargePermissionId": "B01-5105180-3221187",
+                "chargeAmount": { "amount": "25.00", "currencyCode": "USD" },
+                "captureNow": true,
+                "canHandlePendingAuthorization": false
+            })
+        );
+    }
+
+    #[test]
+    fn test_closed_charge_permission_response() {
+        let charge_permission: AmazonpayChargePermissionResponse =
+            serde_json::from_value(serde_json::json!({
+                "chargePermissionId": "B01-5105180-3221187",
+                "chargePermissionType": "Recurring",
+                "statusDetails": {
+                    "state": "Closed",
+                    "reasons": [
+                        {
+                            "reasonCode": "MerchantClosed",
+                            "reasonDescription": "Mandate revoked by merchant"
+                        }
+                    ],
+                    "lastUpdatedTimestamp": "20190714T155300Z"
+                }
+            }))
+            .unwrap();
+
+        assert_eq!(
+            charge_permission.status_details.state,
+            AmazonpayChargePermissionState::Closed
+        );
+    }
+
+    fn signature_key_auth(region: &str) -> ConnectorAuthType {
+        ConnectorAuthType::SignatureKey {
+            api_key: Secret::new("public_key_id".to_string()),
+            key1: Secret::new(region.to_string()),
+            api_secret: Secret::new("private_key".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_region_of_auth_type() {
+        for (region, expected_region, host) in [
+            ("na", AmazonpayRegion::Na, "pay-api.amazon.com"),
+            ("EU", AmazonpayRegion::Eu, "pay-api.amazon.eu"),
+            (" jp ", AmazonpayRegion::Jp, "pay-api.amazon.jp"),
+        ] {
+            let auth = AmazonpayAuthType::try_from(&signature_key_auth(region)).unwrap();
+            assert_eq!(auth.region, expected_region);
+            assert_eq!(auth.region.host(), host);
+        }
+
+        assert!(AmazonpayAuthType::try_from(&signature_key_auth("us")).is_err());
+    }
+
+    #[test]
+    fn test_environment_from_test_mode() {
+        assert_eq!(
+            AmazonpayEnvironment::from_test_mode(Some(true)),
+            AmazonpayEnvironment::Sandbox
+        );
+        assert_eq!(
+            AmazonpayEnvironment::from_test_mode(None),
+            AmazonpayEnvironment::Sandbox
+        );
+        assert_eq!(
+            AmazonpayEnvironment::from_test_mode(Some(false)),
+            AmazonpayEnvironment::Live
+        );
+    }
+
+    fn error_response_fixture(payload: serde_json::Value, status_code: u16) -> ErrorResponse {
+        serde_json::from_value::<AmazonpayErrorResponse>(payload)
+            .unwrap()
+            .into_error_response(status_code)
+    }
+
+    #[test]
+    fn test_api_error_payload() {
+        let error_response = error_response_fixture(
+            serde_json::json!({
+                "reasonCode": "InvalidChargePermissionStatus",
+                "message": "You tried to call an operation on a Charge Permission that is in a state where that operation is not allowed"
+            }),
+            422,
+        );
+
+        assert_eq!(error_response.code, "InvalidChargePermissionStatus");
+        assert_eq!(
+            error_response.message,
+            "Charge permission is not in a state which allows this operation"
+        );
+        assert_eq!(
+            error_response.reason.as_deref(),
+            Some("You tried to call an operation on a Charge Permission that is in a state where that operation is not allowed")
+        );
+        assert_eq!(
+            error_response.attempt_status,
+            Some(enums::AttemptStatus::Failure)
+        );
+        assert_eq!(error_response.connector_transaction_id, None);
+        assert_eq!(error_response.status_code, 422);
+    }
+
+    #[test]
+    fn test_decline_error_payloads_carry_the_charge_id() {
+        for (reason_code, attempt_status) in [
+            ("SoftDeclined", enums::AttemptStatus::AuthorizationFailed),
+            ("HardDeclined", enums::AttemptStatus::AuthorizationFailed),
+            ("TransactionAmountExceeded", enums::AttemptStatus::Failure),
+            ("ProcessingFailure", enums::AttemptStatus::Failure),
+        ] {
+            let error_response = error_response_fixture(
+                serde_json::json!({
+                    "reasonCode": reason_code,
+                    "message": "The charge was declined",
+                    "chargeId": "S01-5105180-3221187-C056351"
+                }),
+                400,
+            );
+
+            assert_eq!(error_response.code, reason_code);
+            assert_eq!(error_response.attempt_status, Some(attempt_status));
+            assert_eq!(
+                error_response.reason.as_deref(),
+                Some("The charge was declined")
+            );
+            assert_eq!(
+                error_response.connector_transaction_id.as_deref(),
+                Some("S01-5105180-3221187-C056351")
+            );
+        }
+    }
+
+    #[test]
+    fn test_checkout_session_error_payload() {
+        let error_response = error_response_fixture(
+            serde_json::json!({
+                "reasonCode": "InvalidCheckoutSessionStatus",
+                "message": "You tried to call an operation on a Checkout Session that is in a state where that operation is not allowed",
+                "checkoutSessionId": "bd504926-f659-4ad7-a1a9-9a747aaf5275"
+            }),
+            422,
+        );
+
+        assert_eq!(
+            error_response.connector_transaction_id.as_deref(),
+            Some("bd504926-f659-4ad7-a1a9-9a747aaf5275")
+        );
+    }
+
+    #[test]
+    fn test_unknown_error_payloads_produce_a_generic_failure() {
+        let unknown_reason_code = error_response_fixture(
+            serde_json::json!({
+                "reasonCode": "SomeNewReasonCode",
+                "message": "Something new happened",
+                "unexpectedField": true
+            }),
+            400,
+        );
+        assert_eq!(unknown_reason_code.code, "SomeNewReasonCode");
+        assert_eq!(
+            unknown_reason_code.message,
+            AMAZONPAY_DEFAULT_ERROR_MAPPING.unified_message
+        );
+
+        let missing_reason_code = error_response_fixture(serde_json::json!({}), 500);
+        assert_eq!(missing_reason_code.code, NO_ERROR_CODE);
+        assert_eq!(
+            missing_reason_code.message,
+            AMAZONPAY_DEFAULT_ERROR_MAPPING.unified_message
+        );
+        assert_eq!(missing_reason_code.reason, None);
+        assert_eq!(missing_reason_code.status_code, 500);
+    }
+
+    #[test]
+    fn test_partial_refunds_of_one_charge() {
+        let refunds =
+            [("ref_partial_1", 500), ("ref_partial_2", 300)].map(|(refund_id, refund_amount)| {
+                let refund_request = AmazonpayRefundRequest::new(
+                    "S01-5105180-3221187-C056351".to_string(),
+                    common_utils::types::AmountConvertor::convert(
+                        &common_utils::types::StringMajorUnitForConnector,
+                        common_utils::types::MinorUnit::new(refund_amount),
+                        enums::Currency::USD,
+                    )
+                    .unwrap(),
+                    enums::Currency::USD,
+                );
+                (
+                    serde_json::to_value(refund_request).unwrap(),
+                    get_idempotency_key(refund_id),
+                )
+            });
+
+        assert_eq!(
+            refunds[0].0,
+            serde_json::json!({
+                "chargeId": "S01-5105180-3221187-C056351",
+                "refundAmount": { "amount": "5.00", "currencyCode": "USD" }
+            })
+        );
+        assert_eq!(
+            refunds[1].0,
+            serde_json::json!({
+                "chargeId": "S01-5105180-3221187-C056351",
+                "refundAmount": { "amount": "3.00", "currencyCode": "USD" }
+            })
+        );
+        assert_eq!(refunds[0].1, "ref_partial_1");
+        assert_eq!(refunds[1].1, "ref_partial_2");
+    }
+
+    #[test]
+    fn test_refund_idempotency_key_of_long_refund_id() {
+        let long_refund_ids = [
+            "merchant_refund_0123456789_0123456789_1",
+            "merchant_refund_0123456789_0123456789_2",
+        ];
+        let idempotency_keys = long_refund_ids.map(get_idempotency_key);
+
+        for idempotency_key in &idempotency_keys {
+            assert_eq!(idempotency_key.len(), AMAZONPAY_IDEMPOTENCY_KEY_MAX_LENGTH);
+        }
+        assert_ne!(idempotency_keys[0], idempotency_keys[1]);
+        assert_eq!(get_idempotency_key(long_refund_ids[0]), idempotency_keys[0]);
+        assert_eq!(get_idempotency_key("ref id").len(), 32);
+    }
+
+    #[test]
+    fn test_mask_connector_response() {
+        let response = serde_json::json!({
+            "checkoutSessionId": "bd504926-f659-4ad7-a1a9-9a747aaf5275",
+            "chargeId": "S01-5105180-3221187-C056351",
+            "chargePermissionId": "S01-5105180-3221187",
+            "statusDetails": {
+                "state": "Completed",
+                "reasonCode": null,
+            },
+            "buyer": {
+                "buyerId": "amzn1.account.AH2JJYPV6UBQP3GHPXUEHIJT4Y2A",
+                "name": "John Doe",
+                "email": "johndoe@example.com",
+                "phoneNumber": null,
+            },
+            "shippingAddress": {
+                "name": "John Doe",
+                "addressLine1": "1st Avenue",
+                "city": "Seattle",
+                "postalCode": "98101",
+                "countryCode": "US",
+            },
+            "billingAddress": null,
+            "paymentPreferences": [{
+                "paymentDescriptor": "Visa ****1111",
+                "billingAddress": { "addressLine1": "1st Avenue" },
+            }],
+        });
+
+        let masked: serde_json::Value = serde_json::from_str(
+            &mask_connector_response(response.to_string().as_bytes()).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            masked,
+            serde_json::json!({
+                "checkoutSessionId": "bd504926-f659-4ad7-a1a9-9a747aaf5275",
+                "chargeId": "S01-5105180-3221187-C056351",
+                "chargePermissionId": "S01-5105180-3221187",
+                "statusDetails": {
+                    "state": "Completed",
+                    "reasonCode": null,
+                },
+                "buyer": {
+                    "buyerId": AMAZONPAY_MASKED_VALUE,
+                    "name": AMAZONPAY_MASKED_VALUE,
+                    "email": AMAZONPAY_MASKED_VALUE,
+                    "phoneNumber": null,
+                },
+                "shippingAddress": AMAZONPAY_MASKED_VALUE,
+                "billingAddress": null,
+                "paymentPreferences": [{
+                    "paymentDescriptor": "Visa ****1111",
+                    "billingAddress": AMAZONPAY_MASKED_VALUE,
+                }],
+            })
+        );
+        assert!(!masked.to_string().contains("johndoe@example.com"));
+        assert_eq!(mask_connector_response(b"<html>Bad Gateway</html>"), None);
+    }
+
+    fn dispute_notification(status_details: serde_json::Value) -> Vec<u8> {
+        let message = serde_json::json!({
+            "MerchantID": "A3URCZVLDMDI45",
+            "ObjectType": "DISPUTE",
+            "ObjectId": "S01-5105180-3221187-D094351",
+            "ChargePermissionId": "S01-5105180-3221187",
+            "NotificationType": "STATE_CHANGE",
+            "NotificationId": "a0c8d2c4-ba26-4b1a-9e5b-1b1e1d6f0c9a",
+            "NotificationVersion": "V2",
+            "Dispute": {
+                "disputeId": "S01-5105180-3221187-D094351",
+                "chargeId": "S01-5105180-3221187-C056351",
+                "reasonCode": "ItemNotReceived",
+                "reasonDescription": "Buyer did not receive the item",
+                "disputedAmount": { "amount": "14.00", "currencyCode": "USD" },
+                "resolutionDeadline": "20250301T235959Z",
+                "statusDetails": status_details,
+                "creationTimestamp": "20250215T101500Z"
+            }
+        });
+        serde_json::json!({
+            "Type": "Notification",
+            "MessageId": "5b1ea7b6-0b52-55d8-9c7e-3f2a1f1e4e2b",
+            "TopicArn": "arn:aws:sns:us-east-1:291180941288:A3URCZVLDMDI45",
+            "Message": message.to_string(),
+            "Timestamp": "2025-02-15T10:15:01.000Z"
+        })
+        .to_string()
+        .into_bytes()
+    }
+
+    #[test]
+    fn test_dispute_notification_maps_to_dispute_payload() {
+        let message = AmazonpayWebhookMessage::from_webhook_body(&dispute_notification(
+            serde_json::json!({ "state": "ActionRequired" }),
+        ))
+        .unwrap();
+        assert_eq!(message.object_type, AmazonpayWebhookObjectType::Dispute);
+
+        let dispute_payload = DisputePayload::try_from(message.get_dispute().unwrap()).unwrap();
+
+        assert_eq!(
+            dispute_payload.amount,
+            utils::convert_amount(
+                &StringMinorUnitForConnector,
+                common_utils::types::MinorUnit::new(1400),
+                enums::Currency::USD
+            )
+            .unwrap()
+        );
+        assert_eq!(dispute_payload.currency, enums::Currency::USD);
+        assert_eq!(dispute_payload.dispute_stage, enums::DisputeStage::Dispute);
+        assert_eq!(dispute_payload.connector_status, "ActionRequired");
+        assert_eq!(
+            dispute_payload.connector_dispute_id,
+            "S01-5105180-3221187-D094351"
+        );
+        assert_eq!(
+            dispute_payload.connector_reason_code.as_deref(),
+            Some("ItemNotReceived")
+        );
+        assert_eq!(
+            dispute_payload.challenge_required_by,
+            Some(time::macros::datetime!(2025-03-01 23:59:59))
+        );
+        assert_eq!(
+            dispute_payload.created_at,
+            Some(time::macros::datetime!(2025-02-15 10:15:00))
+        );
+    }
+
+    #[test]
+    fn test_dispute_state_mapping() {
+        let cases = [
+            (
+                serde_json::json!({ "state": "ActionRequired" }),
+                IncomingWebhookEvent::DisputeOpened,
+            ),
+            (
+                serde_json::json!({ "state": "UnderReview" }),
+                IncomingWebhookEvent::DisputeChallenged,
+            ),
+            (
+                serde_json::json!({ "state": "Resolved", "resolution": "MerchantWon" }),
+                IncomingWebhookEvent::DisputeWon,
+            ),
+            (
+                serde_json::json!({ "state": "Resolved", "resolution": "BuyerWon" }),
+                IncomingWebhookEvent::DisputeLost,
+            ),
+            (
+                serde_json::json!({ "state": "Closed" }),
+                IncomingWebhookEvent::DisputeCancelled,
+            ),
+        ];
+
+        for (status_details, expected_event) in cases {
+            let dispute =
+                AmazonpayWebhookMessage::from_webhook_body(&dispute_notification(status_details))
+                    .and_then(AmazonpayWebhookMessage::get_dispute)
+                    .unwrap();
+            assert_eq!(
+                IncomingWebhookEvent::try_from(&dispute.status_details).unwrap(),
+                expected_event
+            );
+        }
+
+        let unresolved = AmazonpayWebhookMessage::from_webhook_body(&dispute_notification(
+            serde_json::json!({ "state": "Resolved" }),
+        ))
+        .and_then(AmazonpayWebhookMessage::get_dispute)
+        .unwrap();
+        assert!(IncomingWebhookEvent::try_from(&unresolved.status_details).is_err());
+    }
+
+    #[test]
+    fn test_charge_notification_without_dispute() {
+        let body = serde_json::json!({
+            "Type": "Notification",
+            "Message": serde_json::json!({
+                "ObjectType": "CHARGE",
+                "ObjectId": "S01-5105180-3221187-C056351",
+                "NotificationType": "STATE_CHANGE"
+            })
+            .to_string()
+        })
+        .to_string();
+
+        let message = AmazonpayWebhookMessage::from_webhook_body(body.as_bytes()).unwrap();
+
+        assert_eq!(message.object_type, AmazonpayWebhookObjectType::Charge);
+        assert_eq!(message.object_id, "S01-5105180-3221187-C056351");
+        assert!(message.get_dispute().is_err());
+    }
+
+    crate::refund_status_mapping_test!(
+        test_refund_status_mapping,
+        RefundStatus {
+            terminal: [Refunded => Success, Declined => Failure],
+            non_terminal: [RefundInitiated => Pending],
+        }
+    );
 }
@@ -43,8 +43,8 @@ use hyperswitch_interfaces::{
         self,
         disputes::{AcceptDispute, DefendDispute, Dispute, SubmitEvidence},
         files::{FilePurpose, FileUpload, RetrieveFile, UploadFile},
-        CaptureSyncMethod, ConnectorCommon, ConnectorCommonExt, ConnectorIntegration,
-        ConnectorSpecifications, ConnectorValidation, MandateSetup,
+        CaptureSyncMethod, ConnectorAmountConversion, ConnectorCommon, ConnectorCommonExt,
+        ConnectorIntegration, ConnectorSpecifications, ConnectorValidation, MandateSetup,
     },
     configs::Connectors,
     consts,
@@ -107,6 +107,23 @@ where
     }
 }
 
+impl ConnectorAmountConversion for Checkout {
+    type AuthorizeAmount = MinorUnit;
+    type RefundAmount = MinorUnit;
+
+    fn get_authorize_amount_converter(
+        &self,
+    ) -> &'static (dyn AmountConvertor<Output = MinorUnit> + Sync) {
+        self.amount_converter
+    }
+
+    fn get_refund_amount_converter(
+        &self,
+    ) -> &'static (dyn AmountConvertor<Output = MinorUnit> + Sync) {
+        self.amount_converter
+    }
+}
+
 impl ConnectorCommon for Checkout {
     fn id(&self) -> &'static str {
         "checkout"
@@ -554,7 +571,7 @@ impl ConnectorIntegration<Authorize, PaymentsAuthorizeData, PaymentsResponseData
         _connectors: &Connectors,
     ) -> CustomResult<RequestContent, errors::ConnectorError> {
         let amount = utils::convert_amount(
-            self.amount_converter,
+            self.get_authorize_amount_converter(),
             req.request.minor_amount,
             req.request.currency,
         )?;
@@ -723,7 +740,7 @@ impl ConnectorIntegration<Execute, RefundsData, RefundsResponseData> for Checkou
         _connectors: &Connectors,
     ) -> CustomResult<RequestContent, errors::ConnectorError> {
         let amount = utils::convert_amount(
-            self.amount_converter,
+            self.get_refund_amount_converter(),
             req.request.minor_refund_amount,
             req.request.currency,
         )?;
@@ -1574,3 +1591,13 @@ impl ConnectorSpecifications for Checkout {
         Some(&CHECKOUT_SUPPORTED_WEBHOOK_FLOWS)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_authorize_and_refund_amount_conversion_consistency() {
+        utils::assert_amount_conversion_consistency(Checkout::new());
+    }
+}
@@ -319,8 +319,10 @@ impl From<FiservPaymentStatus> for enums::RefundStatus {
             FiservPaymentStatus::Succeeded
             | FiservPaymentStatus::Authorized
             | FiservPaymentStatus::Captured => Self::Success,
-            FiservPaymentStatus::Declined | FiservPaymentStatus::Failed => Self::Failure,
-            FiservPaymentStatus::Voided | FiservPaymentStatus::Processing => Self::Pending,
+            FiservPaymentStatus::Declined
+            | FiservPaymentStatus::Failed
+            | FiservPaymentStatus::Voided => Self::Failure,
+            FiservPaymentStatus::Processing => Self::Pending,
         }
     }
 }
@@ -717,3 +719,23 @@ impl TryFrom<RefundsResponseRouterData<RSync, FiservSyncResponse>>
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::refund_status_mapping_test!(
+        test_refund_status_mapping,
+        FiservPaymentStatus {
+            terminal: [
+                Succeeded => Success,
+                Authorized => Success,
+                Captured => Success,
+                Declined => Failure,
+                Failed => Failure,
+                Voided => Failure,
+            ],
+            non_terminal: [Processing => Pending],
+        }
+    );
+}
@@ -1134,4 +1134,9 @@ impl ConnectorSpecifications for Fiuu {
     fn get_supported_webhook_flows(&self) -> Option<&'static [common_enums::EventClass]> {
         Some(&FIUU_SUPPORTED_WEBHOOK_FLOWS)
     }
+
+    // The mandate token of a payment is sent in the payment webhook
+    fn is_mandate_reference_async(&self) -> bool {
+        true
+    }
 }
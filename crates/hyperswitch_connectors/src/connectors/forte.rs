@@ -36,8 +36,8 @@ use hyperswitch_domain_models::{
 };
 use hyperswitch_interfaces::{
     api::{
-        self, ConnectorCommon, ConnectorCommonExt, ConnectorIntegration, ConnectorSpecifications,
-        ConnectorValidation,
+        self, ConnectorAmountConversion, ConnectorCommon, ConnectorCommonExt, ConnectorIntegration,
+        ConnectorSpecifications, ConnectorValidation,
     },
     configs::Connectors,
     consts::NO_ERROR_CODE,
@@ -106,11 +106,32 @@ where
     }
 }
 
+impl ConnectorAmountConversion for Forte {
+    type AuthorizeAmount = FloatMajorUnit;
+    type RefundAmount = FloatMajorUnit;
+
+    fn get_authorize_amount_converter(
+        &self,
+    ) -> &'static (dyn AmountConvertor<Output = FloatMajorUnit> + Sync) {
+        self.amount_converter
+    }
+
+    fn get_refund_amount_converter(
+        &self,
+    ) -> &'static (dyn AmountConvertor<Output = FloatMajorUnit> + Sync) {
+        self.amount_converter
+    }
+}
+
 impl ConnectorCommon for Forte {
     fn id(&self) -> &'static str {
         "forte"
     }
 
+    fn get_currency_unit(&self) -> api::CurrencyUnit {
+        api::CurrencyUnit::Base
+    }
+
     fn common_get_content_type(&self) -> &'static str {
         "application/json"
     }
@@ -226,7 +247,7 @@ impl ConnectorIntegration<Authorize, PaymentsAuthorizeData, PaymentsResponseData
         _connectors: &Connectors,
     ) -> CustomResult<RequestContent, errors::ConnectorError> {
         let amount = convert_amount(
-            self.amount_converter,
+            self.get_authorize_amount_converter(),
             req.request.minor_amount,
             req.request.currency,
         )?;
@@ -564,7 +585,7 @@ impl ConnectorIntegration<Execute, RefundsData, RefundsResponseData> for Forte {
         _connectors: &Connectors,
     ) -> CustomResult<RequestContent, errors::ConnectorError> {
         let refund_amount = convert_amount(
-            self.amount_converter,
+            self.get_refund_amount_converter(),
             req.request.minor_refund_amount,
             req.request.currency,
         )?;
@@ -800,3 +821,13 @@ impl ConnectorSpecifications for Forte {
         Some(&FORTE_SUPPORTED_WEBHOOK_FLOWS)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_authorize_and_refund_amount_conversion_consistency() {
+        crate::utils::assert_amount_conversion_consistency(Forte::new());
+    }
+}
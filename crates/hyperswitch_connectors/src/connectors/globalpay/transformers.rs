@@ -261,9 +261,12 @@ impl From<GlobalpayPaymentStatus> for common_enums::RefundStatus {
     fn from(item: GlobalpayPaymentStatus) -> Self {
         match item {
             GlobalpayPaymentStatus::Captured | GlobalpayPaymentStatus::Funded => Self::Success,
-            GlobalpayPaymentStatus::Declined | GlobalpayPaymentStatus::Rejected => Self::Failure,
-            GlobalpayPaymentStatus::Initiated | GlobalpayPaymentStatus::Pending => Self::Pending,
-            _ => Self::Pending,
+            GlobalpayPaymentStatus::Declined
+            | GlobalpayPaymentStatus::Rejected
+            | GlobalpayPaymentStatus::Reversed => Self::Failure,
+            GlobalpayPaymentStatus::Initiated
+            | GlobalpayPaymentStatus::Pending
+            | GlobalpayPaymentStatus::Preauthorized => Self::Pending,
         }
     }
 }
@@ -618,3 +621,26 @@ impl MultipleCaptureSyncResponse for GlobalpayPaymentsResponse {
         self.reference.clone()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::refund_status_mapping_test!(
+        test_refund_status_mapping,
+        GlobalpayPaymentStatus {
+            terminal: [
+                Captured => Success,
+                Funded => Success,
+                Declined => Failure,
+                Rejected => Failure,
+                Reversed => Failure,
+            ],
+            non_terminal: [
+                Initiated => Pending,
+                Pending => Pending,
+                Preauthorized => Pending,
+            ],
+        }
+    );
+}
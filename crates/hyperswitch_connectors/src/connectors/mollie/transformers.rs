@@ -610,3 +610,24 @@ pub struct MollieErrorResponse {
     pub detail: String,
     pub field: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::refund_status_mapping_test!(
+        test_refund_status_mapping,
+        MollieRefundStatus {
+            terminal: [
+                Refunded => Success,
+                Failed => Failure,
+                Canceled => Failure,
+            ],
+            non_terminal: [
+                Queued => Pending,
+                Pending => Pending,
+                Processing => Pending,
+            ],
+        }
+    );
+}
@@ -33,8 +33,8 @@ use hyperswitch_domain_models::{
 };
 use hyperswitch_interfaces::{
     api::{
-        self, ConnectorCommon, ConnectorCommonExt, ConnectorIntegration, ConnectorSpecifications,
-        ConnectorValidation,
+        self, ConnectorAmountConversion, ConnectorCommon, ConnectorCommonExt, ConnectorIntegration,
+        ConnectorSpecifications, ConnectorValidation,
     },
     configs::Connectors,
     errors,
@@ -131,11 +131,32 @@ fn get_auth_header(
     )])
 }
 
+impl ConnectorAmountConversion for Noon {
+    type AuthorizeAmount = StringMajorUnit;
+    type RefundAmount = StringMajorUnit;
+
+    fn get_authorize_amount_converter(
+        &self,
+    ) -> &'static (dyn AmountConvertor<Output = StringMajorUnit> + Sync) {
+        self.amount_converter
+    }
+
+    fn get_refund_amount_converter(
+        &self,
+    ) -> &'static (dyn AmountConvertor<Output = StringMajorUnit> + Sync) {
+        self.amount_converter
+    }
+}
+
 impl ConnectorCommon for Noon {
     fn id(&self) -> &'static str {
         "noon"
     }
 
+    fn get_currency_unit(&self) -> api::CurrencyUnit {
+        api::CurrencyUnit::Base
+    }
+
     fn common_get_content_type(&self) -> &'static str {
         "application/json"
     }
@@ -272,7 +293,7 @@ impl ConnectorIntegration<Authorize, PaymentsAuthorizeData, PaymentsResponseData
         _connectors: &Connectors,
     ) -> CustomResult<RequestContent, errors::ConnectorError> {
         let amount = connector_utils::convert_amount(
-            self.amount_converter,
+            self.get_authorize_amount_converter(),
             req.request.minor_amount,
             req.request.currency,
         )?;
@@ -671,7 +692,7 @@ impl ConnectorIntegration<Execute, RefundsData, RefundsResponseData> for Noon {
         _connectors: &Connectors,
     ) -> CustomResult<RequestContent, errors::ConnectorError> {
         let refund_amount = connector_utils::convert_amount(
-            self.amount_converter,
+            self.get_refund_amount_converter(),
             req.request.minor_refund_amount,
             req.request.currency,
         )?;
@@ -911,3 +932,13 @@ impl webhooks::IncomingWebhook for Noon {
 }
 
 impl ConnectorSpecifications for Noon {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_authorize_and_refund_amount_conversion_consistency() {
+        connector_utils::assert_amount_conversion_consistency(Noon::new());
+    }
+}
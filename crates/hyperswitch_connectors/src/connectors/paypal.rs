@@ -54,8 +54,8 @@ use hyperswitch_domain_models::{
 use hyperswitch_interfaces::types::{PayoutFulfillType, PayoutSyncType};
 use hyperswitch_interfaces::{
     api::{
-        self, ConnectorCommon, ConnectorCommonExt, ConnectorIntegration, ConnectorRedirectResponse,
-        ConnectorSpecifications, ConnectorValidation,
+        self, ConnectorAmountConversion, ConnectorCommon, ConnectorCommonExt, ConnectorIntegration,
+        ConnectorRedirectResponse, ConnectorSpecifications, ConnectorValidation,
     },
     configs::Connectors,
     consts::{NO_ERROR_CODE, NO_ERROR_MESSAGE},
@@ -266,6 +266,23 @@ fn construct_auth_assertion_header(
     format!("{algorithm}.{encoded_credentials}.")
 }
 
+impl ConnectorAmountConversion for Paypal {
+    type AuthorizeAmount = StringMajorUnit;
+    type RefundAmount = StringMajorUnit;
+
+    fn get_authorize_amount_converter(
+        &self,
+    ) -> &'static (dyn AmountConvertor<Output = StringMajorUnit> + Sync) {
+        self.amount_converter
+    }
+
+    fn get_refund_amount_converter(
+        &self,
+    ) -> &'static (dyn AmountConvertor<Output = StringMajorUnit> + Sync) {
+        self.amount_converter
+    }
+}
+
 impl ConnectorCommon for Paypal {
     fn id(&self) -> &'static str {
         "paypal"
@@ -1013,7 +1030,7 @@ impl ConnectorIntegration<Authorize, PaymentsAuthorizeData, PaymentsResponseData
         _connectors: &Connectors,
     ) -> CustomResult<RequestContent, errors::ConnectorError> {
         let amount = connector_utils::convert_amount(
-            self.amount_converter,
+            self.get_authorize_amount_converter(),
             req.request.minor_amount,
             req.request.currency,
         )?;
@@ -1678,7 +1695,7 @@ impl ConnectorIntegration<Execute, RefundsData, RefundsResponseData> for Paypal
         _connectors: &Connectors,
     ) -> CustomResult<RequestContent, errors::ConnectorError> {
         let amount = connector_utils::convert_amount(
-            self.amount_converter,
+            self.get_refund_amount_converter(),
             req.request.minor_refund_amount,
             req.request.currency,
         )?;
@@ -2186,3 +2203,13 @@ impl ConnectorErrorTypeMapping for Paypal {
 }
 
 impl ConnectorSpecifications for Paypal {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_authorize_and_refund_amount_conversion_consistency() {
+        connector_utils::assert_amount_conversion_consistency(Paypal::new());
+    }
+}
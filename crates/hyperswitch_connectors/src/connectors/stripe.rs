@@ -70,7 +70,7 @@ use hyperswitch_interfaces::{
         PaymentsUpdateMetadataType, PaymentsVoidType, RefundExecuteType, RefundSyncType, Response,
         RetrieveFileType, SubmitEvidenceType, TokenizationType, UploadFileType,
     },
-    webhooks::{IncomingWebhook, IncomingWebhookRequestDetails},
+    webhooks::{ConnectorInitiatedRefundDetails, IncomingWebhook, IncomingWebhookRequestDetails},
 };
 use masking::{Mask as _, Maskable, PeekInterface};
 use router_env::{instrument, tracing};
@@ -2342,6 +2342,34 @@ impl IncomingWebhook for Stripe {
             updated_at: None,
         })
     }
+
+    fn get_connector_initiated_refund_details(
+        &self,
+        request: &IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<ConnectorInitiatedRefundDetails>, ConnectorError> {
+        let details: stripe::WebhookEvent = request
+            .body
+            .parse_struct("WebhookEvent")
+            .change_context(ConnectorError::WebhookBodyDecodingFailed)?;
+        let event_object = details.event_data.event_object;
+
+        Ok(match event_object.object {
+            stripe::WebhookEventObjectType::Refund => {
+                event_object.payment_intent.zip(event_object.amount).map(
+                    |(connector_transaction_id, refund_amount)| ConnectorInitiatedRefundDetails {
+                        connector_transaction_id,
+                        refund_amount,
+                        currency: event_object.currency,
+                        reason: event_object.reason,
+                    },
+                )
+            }
+            stripe::WebhookEventObjectType::PaymentIntent
+            | stripe::WebhookEventObjectType::Dispute
+            | stripe::WebhookEventObjectType::Charge
+            | stripe::WebhookEventObjectType::Source => None,
+        })
+    }
 }
 
 impl ConnectorRedirectResponse for Stripe {
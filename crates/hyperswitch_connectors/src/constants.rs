@@ -25,6 +25,10 @@ pub(crate) mod headers {
     pub(crate) const X_REQUEST_DATE: &str = "X-RequestDate";
     pub(crate) const X_VERSION: &str = "X-Version";
     pub(crate) const X_API_KEY: &str = "X-Api-Key";
+    pub(crate) const X_AMZ_PAY_DATE: &str = "x-amz-pay-date";
+    pub(crate) const X_AMZ_PAY_HOST: &str = "x-amz-pay-host";
+    pub(crate) const X_AMZ_PAY_IDEMPOTENCY_KEY: &str = "x-amz-pay-idempotency-key";
+    pub(crate) const X_AMZ_PAY_REGION: &str = "x-amz-pay-region";
     pub(crate) const CORRELATION_ID: &str = "Correlation-Id";
     pub(crate) const WP_API_VERSION: &str = "WP-Api-Version";
     pub(crate) const STRIPE_COMPATIBLE_CONNECT_ACCOUNT: &str = "Stripe-Account";
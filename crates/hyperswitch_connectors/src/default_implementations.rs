@@ -878,7 +878,6 @@ default_imp_for_complete_authorize!(
     connectors::Aci,
     connectors::Adyen,
     connectors::Adyenplatform,
-    connectors::Amazonpay,
     connectors::Archipel,
     connectors::Bamboraapac,
     connectors::Bankofamerica,
@@ -4126,7 +4125,6 @@ default_imp_for_revoking_mandates!(
     connectors::Adyen,
     connectors::Adyenplatform,
     connectors::Airwallex,
-    connectors::Amazonpay,
     connectors::Archipel,
     connectors::Authorizedotnet,
     connectors::Bambora,
@@ -387,6 +387,90 @@ pub(crate) fn convert_amount<T>(
         .change_context(errors::ConnectorError::AmountConversionFailed)
 }
 
+/// Amounts covering two, zero and three decimal currencies, used to check that a connector
+/// converts authorize and refund amounts in the same way
+#[cfg(test)]
+const AMOUNT_CONVERSION_TEST_CASES: [(i64, enums::Currency); 5] = [
+    (1, enums::Currency::USD),
+    (1000, enums::Currency::USD),
+    (123456, enums::Currency::EUR),
+    (100, enums::Currency::JPY),
+    (1000, enums::Currency::KWD),
+];
+
+/// Converts each test amount through the authorize and refund converters of the connector and
+/// asserts that both serialized amounts read back, under the connector's declared currency unit,
+/// to the original minor unit amount
+#[cfg(test)]
+pub(crate) fn assert_amount_conversion_consistency<C>(connector: &C)
+where
+    C: api::ConnectorAmountConversion,
+{
+    let currency_unit = connector.get_currency_unit();
+    for (amount, currency) in AMOUNT_CONVERSION_TEST_CASES {
+        let amount = MinorUnit::new(amount);
+        let authorize_amount =
+            convert_amount(connector.get_authorize_amount_converter(), amount, currency)
+                .expect("Failed to convert the authorize amount");
+        let refund_amount =
+            convert_amount(connector.get_refund_amount_converter(), amount, currency)
+                .expect("Failed to convert the refund amount");
+
+        let authorize_amount =
+            get_minor_unit_of_serialized_amount(&authorize_amount, &currency_unit, currency);
+        let refund_amount =
+            get_minor_unit_of_serialized_amount(&refund_amount, &currency_unit, currency);
+
+        assert_eq!(
+            authorize_amount,
+            Some(amount),
+            "{} authorize amount does not match its currency unit for {amount:?} {currency}",
+            connector.id()
+        );
+        assert_eq!(
+            refund_amount,
+            Some(amount),
+            "{} refund amount does not match its currency unit for {amount:?} {currency}",
+            connector.id()
+        );
+    }
+}
+
+#[cfg(test)]
+fn get_minor_unit_of_serialized_amount<T: Serialize>(
+    amount: &T,
+    currency_unit: &api::CurrencyUnit,
+    currency: enums::Currency,
+) -> Option<MinorUnit> {
+    use common_utils::types::{
+        FloatMajorUnit, FloatMajorUnitForConnector, StringMajorUnit, StringMajorUnitForConnector,
+        StringMinorUnit, StringMinorUnitForConnector,
+    };
+
+    let value = serde_json::to_value(amount).ok()?;
+    match (currency_unit, value.is_string()) {
+        (api::CurrencyUnit::Minor, false) => serde_json::from_value(value).ok(),
+        (api::CurrencyUnit::Minor, true) => {
+            let amount: StringMinorUnit = serde_json::from_value(value).ok()?;
+            StringMinorUnitForConnector
+                .convert_back(amount, currency)
+                .ok()
+        }
+        (api::CurrencyUnit::Base, false) => {
+            let amount: FloatMajorUnit = serde_json::from_value(value).ok()?;
+            FloatMajorUnitForConnector
+                .convert_back(amount, currency)
+                .ok()
+        }
+        (api::CurrencyUnit::Base, true) => {
+            let amount: StringMajorUnit = serde_json::from_value(value).ok()?;
+            StringMajorUnitForConnector
+                .convert_back(amount, currency)
+                .ok()
+        }
+    }
+}
+
 pub(crate) fn validate_currency(
     request_currency: enums::Currency,
     merchant_config_currency: Option<enums::Currency>,
@@ -2539,6 +2623,56 @@ macro_rules! unimplemented_payment_method {
     };
 }
 
+/// Generates a test asserting the `RefundStatus` every variant of a connector's refund status maps
+/// to. The expected statuses are listed in a `match` without a wildcard arm, so a variant added to
+/// the connector's refund status without an expected mapping fails compilation. Variants listed as
+/// `terminal` are the final states of the refund at the connector and must never map to `Pending`.
+#[cfg(test)]
+#[macro_export]
+macro_rules! refund_status_mapping_test {
+    (
+        $test_name:ident,
+        $connector_refund_status:ident {
+            terminal: [$($terminal_variant:ident => $terminal_refund_status:ident),* $(,)?],
+            non_terminal: [$($non_terminal_variant:ident => $non_terminal_refund_status:ident),* $(,)?] $(,)?
+        }
+    ) => {
+        #[test]
+        fn $test_name() {
+            fn expected_refund_status(
+                connector_refund_status: &$connector_refund_status,
+            ) -> (common_enums::RefundStatus, bool) {
+                match connector_refund_status {
+                    $($connector_refund_status::$terminal_variant => {
+                        (common_enums::RefundStatus::$terminal_refund_status, true)
+                    })*
+                    $($connector_refund_status::$non_terminal_variant => {
+                        (common_enums::RefundStatus::$non_terminal_refund_status, false)
+                    })*
+                }
+            }
+
+            for connector_refund_status in [
+                $($connector_refund_status::$terminal_variant,)*
+                $($connector_refund_status::$non_terminal_variant,)*
+            ] {
+                let variant = format!("{connector_refund_status:?}");
+                let (expected_refund_status, is_terminal) =
+                    expected_refund_status(&connector_refund_status);
+                assert!(
+                    !is_terminal || expected_refund_status != common_enums::RefundStatus::Pending,
+                    "terminal connector refund status {variant} must not map to pending"
+                );
+                assert_eq!(
+                    common_enums::RefundStatus::try_from(connector_refund_status).ok(),
+                    Some(expected_refund_status),
+                    "unexpected refund status for connector refund status {variant}"
+                );
+            }
+        }
+    };
+}
+
 impl ForeignTryFrom<String> for UsStatesAbbreviation {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn foreign_try_from(value: String) -> Result<Self, Self::Error> {
@@ -211,6 +211,13 @@ impl Profile {
     pub fn get_id(&self) -> &common_utils::id_type::ProfileId {
         &self.id
     }
+
+    pub fn get_outgoing_webhook_payload_version(&self) -> u8 {
+        self.webhook_details
+            .as_ref()
+            .and_then(|webhook_details| webhook_details.payload_version)
+            .unwrap_or(api_models::consts::DEFAULT_OUTGOING_WEBHOOK_PAYLOAD_VERSION)
+    }
 }
 
 #[cfg(feature = "v1")]
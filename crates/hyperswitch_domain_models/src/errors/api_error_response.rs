@@ -50,6 +50,8 @@ pub enum ApiErrorResponse {
     VerificationFailed { data: Option<serde_json::Value> },
     #[error(error_type = ErrorType::ProcessingError, code = "CE_08", message = "Dispute operation failed while processing with connector. Retry operation")]
     DisputeFailed { data: Option<serde_json::Value> },
+    #[error(error_type = ErrorType::ProcessingError, code = "CE_09", message = "Refunds temporarily suspended for this connector account")]
+    RefundsSuspendedForConnectorAccount { merchant_connector_id: String },
 
     #[error(error_type = ErrorType::LockTimeout, code = "HE_00", message = "Resource is busy. Please try again later.")]
     ResourceBusy,
@@ -299,6 +301,8 @@ pub enum ApiErrorResponse {
         max_length: usize,
         received_length: usize,
     },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_48", message = "{message}")]
+    RefundAmountLimitExceeded { message: String },
     #[error(error_type = ErrorType::InvalidRequestError, code = "WE_01", message = "Failed to authenticate the webhook")]
     WebhookAuthenticationFailed,
     #[error(error_type = ErrorType::InvalidRequestError, code = "WE_02", message = "Bad request received in webhook")]
@@ -379,6 +383,9 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::DisputeFailed { data } => {
                 AER::BadRequest(ApiError::new("CE", 8, "Dispute operation failed while processing with connector. Retry operation", Some(Extra { data: data.clone(), ..Default::default()})))
             }
+            Self::RefundsSuspendedForConnectorAccount { merchant_connector_id } => {
+                AER::BadRequest(ApiError::new("CE", 9, "Refunds temporarily suspended for this connector account", Some(Extra { data: Some(serde_json::json!({ "merchant_connector_id": merchant_connector_id })), ..Default::default()})))
+            }
 
             Self::ResourceBusy => {
                 AER::Unprocessable(ApiError::new("HE", 0, "There was an issue processing the webhook body", None))
@@ -666,6 +673,9 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::MaxFieldLengthViolated { connector, field_name,  max_length, received_length} => {
                 AER::BadRequest(ApiError::new("IR", 47, format!("Connector '{connector}' rejected field '{field_name}': length {received_length} exceeds maximum of {max_length}"), Some(Extra {connector: Some(connector.to_string()), ..Default::default()})))
             }
+            Self::RefundAmountLimitExceeded { message } => {
+                AER::BadRequest(ApiError::new("IR", 48, message.to_string(), None))
+            }
             Self::WebhookAuthenticationFailed => {
                 AER::Unauthorized(ApiError::new("WE", 1, "Webhook authentication failed", None))
             }
@@ -12,6 +12,7 @@ use common_utils::{
     types::MinorUnit,
 };
 use error_stack::ResultExt;
+use masking::PeekInterface;
 use time::PrimitiveDateTime;
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
@@ -62,6 +63,8 @@ pub struct MandateData {
     pub customer_acceptance: Option<common_payments_types::CustomerAcceptance>,
     /// A way to select the type of mandate used
     pub mandate_type: Option<MandateDataType>,
+    /// Metadata to be set on the mandate by the merchant
+    pub metadata: Option<pii::SecretSerdeValue>,
 }
 
 impl From<MandateType> for MandateDataType {
@@ -141,6 +144,7 @@ impl From<ApiMandateData> for MandateData {
             customer_acceptance: value.customer_acceptance,
             mandate_type: value.mandate_type.map(|d| d.into()),
             update_mandate_id: value.update_mandate_id,
+            metadata: value.metadata,
         }
     }
 }
@@ -160,6 +164,15 @@ impl MandateAmountData {
     pub fn get_metadata(&self) -> Option<pii::SecretSerdeValue> {
         self.metadata.clone()
     }
+    /// Get the interval at which the mandate is expected to be charged, read from the
+    /// `frequency` key of the mandate metadata
+    pub fn get_frequency(&self) -> Option<common_enums::MandateFrequency> {
+        self.metadata
+            .as_ref()
+            .and_then(|metadata| metadata.peek().get("frequency"))
+            .and_then(serde_json::Value::as_str)
+            .and_then(|frequency| frequency.parse().ok())
+    }
 }
 
 #[cfg(feature = "v1")]
@@ -180,6 +180,15 @@ pub trait PaymentAttemptInterface {
         storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> error_stack::Result<PaymentAttempt, Self::Error>;
 
+    /// Checks whether the attempt belongs to the payment, without decrypting the attempt
+    #[cfg(feature = "v2")]
+    async fn attempt_belongs_to_payment(
+        &self,
+        attempt_id: &id_type::GlobalAttemptId,
+        payment_id: &id_type::GlobalPaymentId,
+        storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> error_stack::Result<bool, Self::Error>;
+
     #[cfg(feature = "v2")]
     async fn find_payment_attempts_by_payment_intent_id(
         &self,
@@ -1,3 +1,8 @@
+#[cfg(feature = "v1")]
+use base64::Engine;
+#[cfg(feature = "v1")]
+use common_utils::consts::BASE64_ENGINE_URL_SAFE;
+
 #[cfg(feature = "v2")]
 use crate::business_profile::Profile;
 #[cfg(feature = "v1")]
@@ -16,6 +21,42 @@ pub struct RefundListConstraints {
     pub merchant_connector_id: Option<Vec<common_utils::id_type::MerchantConnectorAccountId>>,
     pub currency: Option<Vec<common_enums::Currency>>,
     pub refund_status: Option<Vec<common_enums::RefundStatus>>,
+    pub origin: Option<Vec<common_enums::RefundOrigin>>,
+    pub unified_code: Option<Vec<String>>,
+    pub issuer_error_code: Option<Vec<String>>,
+    pub has_unified_error: Option<bool>,
+    /// Lists the refunds following this position instead of skipping the offset
+    pub starting_after: Option<RefundListCursor>,
+}
+
+/// Position of a refund among the refunds of a merchant ordered by creation time and refund id,
+/// both descending, handed out as an opaque cursor by the refunds list
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefundListCursor {
+    pub created_at: time::PrimitiveDateTime,
+    pub refund_id: String,
+}
+
+#[cfg(feature = "v1")]
+impl RefundListCursor {
+    pub fn encode(&self) -> String {
+        let timestamp = self.created_at.assume_utc().unix_timestamp_nanos();
+        BASE64_ENGINE_URL_SAFE.encode(format!("{timestamp}:{}", self.refund_id))
+    }
+
+    /// Decodes a cursor returned by [`Self::encode`], returns `None` if the cursor is malformed
+    pub fn decode(cursor: &str) -> Option<Self> {
+        let decoded = String::from_utf8(BASE64_ENGINE_URL_SAFE.decode(cursor).ok()?).ok()?;
+        let (timestamp, refund_id) = decoded.split_once(':')?;
+        let created_at =
+            time::OffsetDateTime::from_unix_timestamp_nanos(timestamp.parse().ok()?).ok()?;
+
+        Some(Self {
+            created_at: common_utils::date_time::convert_to_pdt(created_at),
+            refund_id: refund_id.to_string(),
+        })
+    }
 }
 
 #[cfg(feature = "v2")]
@@ -31,6 +72,7 @@ pub struct RefundListConstraints {
     pub connector_id_list: Option<Vec<common_utils::id_type::MerchantConnectorAccountId>>,
     pub currency: Option<Vec<common_enums::Currency>>,
     pub refund_status: Option<Vec<common_enums::RefundStatus>>,
+    pub origin: Option<Vec<common_enums::RefundOrigin>>,
 }
 
 #[cfg(feature = "v1")]
@@ -60,7 +102,24 @@ impl
             time_range,
             amount_filter,
             merchant_connector_id,
+            origin,
+            unified_code,
+            issuer_error_code,
+            has_unified_error,
+            cursor,
         } = value;
+        let starting_after = cursor
+            .as_deref()
+            .map(|cursor| {
+                RefundListCursor::decode(cursor).ok_or_else(|| {
+                    error_stack::Report::new(
+                        errors::api_error_response::ApiErrorResponse::InvalidRequestData {
+                            message: "cursor is invalid".to_string(),
+                        },
+                    )
+                })
+            })
+            .transpose()?;
         let profile_id_from_request_body = profile_id;
         let profile_id_list = match (profile_id_from_request_body, auth_profile_id_list) {
             (None, None) => None,
@@ -97,6 +156,11 @@ impl
             merchant_connector_id,
             currency,
             refund_status,
+            origin,
+            unified_code,
+            issuer_error_code,
+            has_unified_error,
+            starting_after,
         })
     }
 }
@@ -115,6 +179,7 @@ impl From<(api_models::refunds::RefundListRequest, Profile)> for RefundListConst
             time_range,
             amount_filter,
             connector_id_list,
+            origin,
         } = value;
 
         Self {
@@ -129,6 +194,7 @@ impl From<(api_models::refunds::RefundListRequest, Profile)> for RefundListConst
             connector_id_list,
             currency,
             refund_status,
+            origin,
         }
     }
 }
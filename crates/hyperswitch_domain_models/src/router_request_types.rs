@@ -940,6 +940,8 @@ pub struct PaymentsSessionData {
     pub minor_amount: MinorUnit,
     pub apple_pay_recurring_details: Option<api_models::payments::ApplePayRecurringPaymentRequest>,
     pub customer_name: Option<Secret<String>>,
+    /// Url the customer is redirected to once the wallet session is completed
+    pub return_url: Option<String>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -34,6 +34,7 @@ use common_enums::{
 use common_utils::{
     errors::CustomResult,
     request::{Method, Request, RequestContent},
+    types::AmountConvertor,
 };
 use error_stack::ResultExt;
 use hyperswitch_domain_models::{
@@ -366,6 +367,26 @@ pub trait ConnectorCommon {
     }
 }
 
+/// The trait that exposes the amount converters used while building the authorize and refund
+/// request bodies, so that both flows can be checked against `get_currency_unit`
+pub trait ConnectorAmountConversion: ConnectorCommon {
+    /// Amount type sent in the authorize request body
+    type AuthorizeAmount: serde::Serialize;
+
+    /// Amount type sent in the refund execute request body
+    type RefundAmount: serde::Serialize;
+
+    /// Converter used for the amount in the authorize request body
+    fn get_authorize_amount_converter(
+        &self,
+    ) -> &'static (dyn AmountConvertor<Output = Self::AuthorizeAmount> + Sync);
+
+    /// Converter used for the amount in the refund execute request body
+    fn get_refund_amount_converter(
+        &self,
+    ) -> &'static (dyn AmountConvertor<Output = Self::RefundAmount> + Sync);
+}
+
 /// The trait that provides specifications about the connector
 pub trait ConnectorSpecifications {
     /// Details related to payment method supported by the connector
@@ -383,6 +404,12 @@ pub trait ConnectorSpecifications {
         None
     }
 
+    /// Whether the connector may confirm the setup of a mandate after the payment, sending the
+    /// mandate reference in a webhook instead of the payment response
+    fn is_mandate_reference_async(&self) -> bool {
+        false
+    }
+
     #[cfg(feature = "v2")]
     /// Generate connector request reference ID
     fn generate_connector_request_reference_id(
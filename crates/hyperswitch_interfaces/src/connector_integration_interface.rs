@@ -23,7 +23,10 @@ use crate::{
     disputes, errors,
     events::connector_api_logs::ConnectorEvent,
     types,
-    webhooks::{IncomingWebhook, IncomingWebhookFlowError, IncomingWebhookRequestDetails},
+    webhooks::{
+        ConnectorInitiatedRefundDetails, IncomingWebhook, IncomingWebhookFlowError,
+        IncomingWebhookRequestDetails,
+    },
 };
 
 /// RouterDataConversion trait
@@ -389,6 +392,16 @@ impl IncomingWebhook for ConnectorEnum {
         }
     }
 
+    fn get_connector_initiated_refund_details(
+        &self,
+        request: &IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<ConnectorInitiatedRefundDetails>, errors::ConnectorError> {
+        match self {
+            Self::Old(connector) => connector.get_connector_initiated_refund_details(request),
+            Self::New(connector) => connector.get_connector_initiated_refund_details(request),
+        }
+    }
+
     #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
     fn get_revenue_recovery_invoice_details(
         &self,
@@ -519,6 +532,14 @@ impl ConnectorSpecifications for ConnectorEnum {
         }
     }
 
+    /// Whether the connector sends mandate references asynchronously
+    fn is_mandate_reference_async(&self) -> bool {
+        match self {
+            Self::Old(connector) => connector.is_mandate_reference_async(),
+            Self::New(connector) => connector.is_mandate_reference_async(),
+        }
+    }
+
     #[cfg(feature = "v2")]
     /// Generate connector request reference ID
     fn generate_connector_request_reference_id(
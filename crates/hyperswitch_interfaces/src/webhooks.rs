@@ -24,6 +24,22 @@ pub struct IncomingWebhookRequestDetails<'a> {
     pub query_params: String,
 }
 
+/// struct ConnectorInitiatedRefundDetails
+///
+/// Details of a refund which was initiated directly on the connector (for instance from the
+/// processor's dashboard) and is only known to us through an incoming webhook
+#[derive(Debug, Clone)]
+pub struct ConnectorInitiatedRefundDetails {
+    /// connector transaction id of the payment that was refunded
+    pub connector_transaction_id: String,
+    /// refund_amount
+    pub refund_amount: common_utils::types::MinorUnit,
+    /// currency
+    pub currency: common_enums::enums::Currency,
+    /// reason
+    pub reason: Option<String>,
+}
+
 /// IncomingWebhookFlowError enum defining the error type for incoming webhook
 #[derive(Debug)]
 pub enum IncomingWebhookFlowError {
@@ -276,6 +292,14 @@ pub trait IncomingWebhook: ConnectorCommon + Sync {
         Ok(None)
     }
 
+    /// fn get_connector_initiated_refund_details
+    fn get_connector_initiated_refund_details(
+        &self,
+        _request: &IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<ConnectorInitiatedRefundDetails>, errors::ConnectorError> {
+        Ok(None)
+    }
+
     #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
     /// get revenue recovery invoice details
     fn get_revenue_recovery_attempt_details(
@@ -95,6 +95,9 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::refunds::refunds_retrieve,
         routes::refunds::refunds_update,
         routes::refunds::refunds_list,
+        routes::refunds::refunds_list_modified_since,
+        routes::refunds::refunds_export,
+        routes::refunds::refunds_reconciliation_export,
 
         // Routes for Organization
         routes::organization::organization_create,
@@ -124,7 +127,10 @@ Never share your secret api keys. Keep them guarded and secure.
         // Routes for mandates
         routes::mandates::get_mandate,
         routes::mandates::revoke_mandate,
+        routes::mandates::update_mandate,
+        routes::mandates::get_mandate_revocation_eligibility,
         routes::mandates::customers_mandates_list,
+        routes::mandates::customers_mandates_revoke,
 
         //Routes for customers
         routes::customers::customers_create,
@@ -149,6 +155,9 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::profile::profile_retrieve,
         routes::profile::profile_update,
         routes::profile::profile_delete,
+        routes::profile::refund_limits_config_upsert,
+        routes::profile::refund_limits_config_retrieve,
+        routes::profile::refund_limits_config_delete,
 
         // Routes for disputes
         routes::disputes::retrieve_dispute,
@@ -156,6 +165,7 @@ Never share your secret api keys. Keep them guarded and secure.
 
         // Routes for routing
         routes::routing::routing_create_config,
+        routes::routing::routing_validate_import,
         routes::routing::routing_link_config,
         routes::routing::routing_retrieve_config,
         routes::routing::list_routing_configs,
@@ -261,6 +271,13 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::refunds::RefundType,
         api_models::refunds::RefundResponse,
         api_models::refunds::RefundStatus,
+        api_models::refunds::RefundWebhookResponse,
+        api_models::refunds::RefundWebhookResponseV3,
+        api_models::refunds::SplitRefundSummary,
+        api_models::refunds::SplitRefundType,
+        api_models::enums::RefundRecommendedAction,
+        api_models::enums::RefundErrorCategory,
+        api_models::enums::RefundOrigin,
         api_models::refunds::RefundUpdateRequest,
         api_models::organization::OrganizationCreateRequest,
         api_models::organization::OrganizationUpdateRequest,
@@ -502,6 +519,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::GpaySessionTokenResponse,
         api_models::payments::GooglePayThirdPartySdkData,
         api_models::payments::KlarnaSessionTokenResponse,
+        api_models::payments::AmazonPaySessionTokenResponse,
         api_models::payments::PaypalSessionTokenResponse,
         api_models::payments::ApplepaySessionTokenResponse,
         api_models::payments::SdkNextAction,
@@ -591,6 +609,16 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payment_methods::TokenizePaymentMethodRequest,
         api_models::refunds::RefundListRequest,
         api_models::refunds::RefundListResponse,
+        api_models::refunds::RefundsModifiedSinceResponse,
+        api_models::refunds::RefundExportRequest,
+        api_models::refunds::RefundExportFormat,
+        api_models::refunds::RefundExportField,
+        api_models::refunds::RefundReconciliationRequest,
+        api_models::refunds::RefundReconciliationRules,
+        api_models::refunds::RefundMismatchRule,
+        api_models::refunds::RefundMismatch,
+        api_models::refunds::RefundLimitsConfigRequest,
+        api_models::refunds::RefundLimitsConfigResponse,
         api_models::relay::RelayRequest,
         api_models::relay::RelayResponse,
         api_models::enums::RelayType,
@@ -600,6 +628,14 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::relay::RelayError,
         api_models::payments::AmountFilter,
         api_models::mandates::MandateRevokedResponse,
+        api_models::mandates::MandateRevokeRequest,
+        api_models::mandates::MandateUpdateRequest,
+        api_models::mandates::MandateRevocationEligibilityResponse,
+        api_models::mandates::MandateRevocationVerdict,
+        api_models::mandates::MandateRevocationReason,
+        api_models::mandates::CustomerMandatesRevokedResponse,
+        api_models::mandates::CustomerMandateRevocationResult,
+        api_models::mandates::MandateRevocationOutcome,
         api_models::mandates::MandateResponse,
         api_models::mandates::MandateCardDetails,
         api_models::mandates::RecurringDetails,
@@ -671,6 +707,12 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::ExtendedCardInfoResponse,
         api_models::payments::GooglePayAssuranceDetails,
         api_models::routing::RoutingConfigRequest,
+        api_models::routing::RoutingImportValidationRequest,
+        api_models::routing::RoutingImportValidationResponse,
+        api_models::routing::AnalysisDiagnostic,
+        api_models::routing::DiagnosticSeverity,
+        api_models::routing::AnalysisDiagnosticCode,
+        api_models::routing::DiagnosticLocation,
         api_models::routing::RoutingDictionaryRecord,
         api_models::routing::RoutingKind,
         api_models::routing::RoutableConnectorChoice,
@@ -714,6 +756,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::routing::ast::ValueType,
         api_models::routing::ast::MetadataValue,
         api_models::routing::ast::NumberComparison,
+        api_models::routing::ast::Segment,
         api_models::payment_methods::RequestPaymentMethodTypes,
         api_models::payments::PaymentLinkStatus,
         api_models::blocklist::BlocklistRequest,
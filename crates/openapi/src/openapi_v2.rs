@@ -151,6 +151,7 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::refunds::refunds_metadata_update,
         routes::refunds::refunds_retrieve,
         routes::refunds::refunds_list,
+        routes::refunds::refunds_list_modified_since,
 
         // Routes for Revenue Recovery flow under Process Tracker
         routes::revenue_recovery::revenue_recovery_pt_retrieve_api,
@@ -208,6 +209,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::refunds::RefundType,
         api_models::refunds::RefundResponse,
         api_models::refunds::RefundStatus,
+        api_models::refunds::SplitRefundSummary,
+        api_models::refunds::SplitRefundType,
+        api_models::enums::RefundOrigin,
         api_models::refunds::RefundMetadataUpdateRequest,
         api_models::organization::OrganizationCreateRequest,
         api_models::organization::OrganizationUpdateRequest,
@@ -469,6 +473,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::GpaySessionTokenResponse,
         api_models::payments::GooglePayThirdPartySdkData,
         api_models::payments::KlarnaSessionTokenResponse,
+        api_models::payments::AmazonPaySessionTokenResponse,
         api_models::payments::PaypalSessionTokenResponse,
         api_models::payments::ApplepaySessionTokenResponse,
         api_models::payments::SdkNextAction,
@@ -580,6 +585,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payment_methods::TokenizePaymentMethodRequest,
         api_models::refunds::RefundListRequest,
         api_models::refunds::RefundListResponse,
+        api_models::refunds::RefundsModifiedSinceResponse,
         api_models::payments::AmountFilter,
         api_models::mandates::MandateRevokedResponse,
         api_models::mandates::MandateResponse,
@@ -699,6 +705,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::routing::ast::ValueType,
         api_models::routing::ast::MetadataValue,
         api_models::routing::ast::NumberComparison,
+        api_models::routing::ast::Segment,
         api_models::routing::RoutingAlgorithmId,
         api_models::payment_methods::RequestPaymentMethodTypes,
         api_models::payments::PaymentLinkStatus,
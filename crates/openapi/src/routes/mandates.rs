@@ -19,16 +19,18 @@ pub async fn get_mandate() {}
 
 /// Mandates - Revoke Mandate
 ///
-/// Revokes a mandate created using the Payments/Create API
+/// Revokes a mandate created using the Payments/Create API. With `force`, the mandate is revoked
+/// locally even when the connector fails to revoke it.
 #[utoipa::path(
     post,
     path = "/mandates/revoke/{mandate_id}",
     params(
         ("mandate_id" = String, Path, description = "The identifier for a mandate")
     ),
+    request_body = MandateRevokeRequest,
     responses(
         (status = 200, description = "The mandate was revoked successfully", body = MandateRevokedResponse),
-        (status = 400, description = "Mandate does not exist in our records")
+        (status = 400, description = "The revoke request is invalid or the mandate does not exist in our records")
     ),
     tag = "Mandates",
     operation_id = "Revoke a Mandate",
@@ -36,6 +38,46 @@ pub async fn get_mandate() {}
 )]
 pub async fn revoke_mandate() {}
 
+/// Mandates - Update Mandate
+///
+/// Updates the metadata of a mandate, merging it into the existing metadata
+#[utoipa::path(
+    patch,
+    path = "/mandates/{mandate_id}",
+    params(
+        ("mandate_id" = String, Path, description = "The identifier for a mandate")
+    ),
+    request_body = MandateUpdateRequest,
+    responses(
+        (status = 200, description = "The mandate was updated successfully", body = MandateResponse),
+        (status = 400, description = "Invalid mandate metadata"),
+        (status = 404, description = "Mandate does not exist in our records")
+    ),
+    tag = "Mandates",
+    operation_id = "Update a Mandate",
+    security(("api_key" = []))
+)]
+pub async fn update_mandate() {}
+
+/// Mandates - Revocation Eligibility
+///
+/// Checks whether a mandate can be revoked, without revoking it or calling the connector
+#[utoipa::path(
+    get,
+    path = "/mandates/{mandate_id}/revocation_eligibility",
+    params(
+        ("mandate_id" = String, Path, description = "The identifier for a mandate")
+    ),
+    responses(
+        (status = 200, description = "The revocation eligibility of the mandate", body = MandateRevocationEligibilityResponse),
+        (status = 404, description = "Mandate does not exist in our records")
+    ),
+    tag = "Mandates",
+    operation_id = "Check Mandate Revocation Eligibility",
+    security(("api_key" = []))
+)]
+pub async fn get_mandate_revocation_eligibility() {}
+
 /// Mandates - List Mandates
 #[utoipa::path(
     get,
@@ -62,12 +104,20 @@ pub async fn retrieve_mandates_list() {}
 
 /// Mandates - Customer Mandates List
 ///
-/// Lists all the mandates for a particular customer id.
+/// Lists all the mandates for a particular customer id, optionally filtered and paginated.
 #[utoipa::path(
     get,
     path = "/customers/{customer_id}/mandates",
     params(
-        ("customer_id" = String, Path, description = "The unique identifier for the customer")
+        ("customer_id" = String, Path, description = "The unique identifier for the customer"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of Mandate Objects to include in the response"),
+        ("offset" = Option<i64>, Query, description = "The number of Mandate Objects to skip"),
+        ("mandate_status" = Option<MandateStatus>, Query, description = "The status of mandate"),
+        ("connector" = Option<String>, Query, description = "The connector linked to mandate"),
+        ("created_time.lt" = Option<PrimitiveDateTime>, Query, description = "Time less than the mandate created time"),
+        ("created_time.gt" = Option<PrimitiveDateTime>, Query, description = "Time greater than the mandate created time"),
+        ("created_time.lte" = Option<PrimitiveDateTime>, Query, description = "Time less than or equals to the mandate created time"),
+        ("created_time.gte" = Option<PrimitiveDateTime>, Query, description = "Time greater than or equals to the mandate created time"),
     ),
     responses(
         (status = 200, description = "List of retrieved mandates for a customer", body = Vec<MandateResponse>),
@@ -78,3 +128,23 @@ pub async fn retrieve_mandates_list() {}
     security(("api_key" = []))
 )]
 pub async fn customers_mandates_list() {}
+
+/// Mandates - Revoke Customer Mandates
+///
+/// Revokes all the mandates of a particular customer id. Mandates which have already been revoked
+/// are skipped, and the failure to revoke a mandate does not stop the others from being revoked.
+#[utoipa::path(
+    post,
+    path = "/customers/{customer_id}/mandates/revoke",
+    params(
+        ("customer_id" = String, Path, description = "The unique identifier for the customer")
+    ),
+    responses(
+        (status = 200, description = "The outcome of the revocation for each mandate of the customer", body = CustomerMandatesRevokedResponse),
+        (status = 404, description = "No mandates found for the customer"),
+    ),
+    tag = "Mandates",
+    operation_id = "Revoke Mandates for a Customer",
+    security(("api_key" = []))
+)]
+pub async fn customers_mandates_revoke() {}
@@ -576,6 +576,7 @@ pub fn payments_create() {}
         ("client_secret" = Option<String>, Query, description = "This is a token which expires after 15 minutes, used from the client to authenticate and create sessions from the SDK"),
         ("expand_attempts" = Option<bool>, Query, description = "If enabled provides list of attempts linked to payment intent"),
         ("expand_captures" = Option<bool>, Query, description = "If enabled provides list of captures linked to latest attempt"),
+        ("expand_mandate" = Option<bool>, Query, description = "If enabled provides the mandate created or used by the payment"),
     ),
     responses(
         (status = 200, description = "Gets the payment with final status", body = PaymentsResponse),
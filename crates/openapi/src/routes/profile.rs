@@ -90,6 +90,70 @@ pub async fn profile_update() {}
 )]
 pub async fn profile_retrieve() {}
 
+#[cfg(feature = "v1")]
+/// Profile - Upsert Refund Limits
+///
+/// Create or update the limits applied to the refunds of the payments of a *profile*. The limits replace the default refund window and refund count, and optionally restrict the total amount refunded within a velocity window, for a single payment and by the profile within a day
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/refund_limits",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    request_body = RefundLimitsConfigRequest,
+    responses(
+        (status = 200, description = "Refund Limits Configured", body = RefundLimitsConfigResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Profile",
+    operation_id = "Upsert Refund Limits of a Profile",
+    security(("api_key" = []))
+)]
+pub async fn refund_limits_config_upsert() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Retrieve Refund Limits
+///
+/// Retrieve the refund limits configured for a *profile*
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/refund_limits",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Refund Limits Retrieved", body = RefundLimitsConfigResponse),
+        (status = 404, description = "Refund limits are not configured for the profile")
+    ),
+    tag = "Profile",
+    operation_id = "Retrieve Refund Limits of a Profile",
+    security(("api_key" = []))
+)]
+pub async fn refund_limits_config_retrieve() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Delete Refund Limits
+///
+/// Delete the refund limits configured for a *profile*, the default limits apply to its refunds afterwards
+#[utoipa::path(
+    delete,
+    path = "/account/{account_id}/business_profile/{profile_id}/refund_limits",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Refund Limits Deleted", body = RefundLimitsConfigResponse),
+        (status = 404, description = "Refund limits are not configured for the profile")
+    ),
+    tag = "Profile",
+    operation_id = "Delete Refund Limits of a Profile",
+    security(("api_key" = []))
+)]
+pub async fn refund_limits_config_delete() {}
+
 // ******************************************** Common profile routes ******************************************** //
 
 /// Profile - Delete
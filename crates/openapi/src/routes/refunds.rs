@@ -54,7 +54,9 @@ pub async fn refunds_create() {}
     get,
     path = "/refunds/{refund_id}",
     params(
-        ("refund_id" = String, Path, description = "The identifier for refund")
+        ("refund_id" = String, Path, description = "The identifier for refund"),
+        ("force_sync" = Option<bool>, Query, description = "Decider to enable or disable the connector call for refund retrieve request"),
+        ("wait_seconds" = Option<u64>, Query, description = "Number of seconds, capped at 30, to wait for the refund to reach a terminal status. The `x-refund-status-terminal` response header indicates whether it did")
     ),
     responses(
         (status = 200, description = "Refund retrieved", body = RefundResponse),
@@ -132,6 +134,64 @@ pub async fn refunds_update() {}
 #[cfg(feature = "v1")]
 pub fn refunds_list() {}
 
+/// Refunds - Export
+///
+/// Exports the refunds matching the refunds list filters as a CSV or JSON lines file, with the requested columns. All the exportable columns are included if no columns are requested
+#[utoipa::path(
+    post,
+    path = "/refunds/export",
+    request_body=RefundExportRequest,
+    responses(
+        (status = 200, description = "The refunds export", content_type = "text/csv"),
+        (status = 400, description = "Unknown export field or invalid limit")
+    ),
+    tag = "Refunds",
+    operation_id = "Export Refunds",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub fn refunds_export() {}
+
+/// Refunds - Reconciliation Export
+///
+/// Exports as CSV the refunds older than the age threshold whose status does not match their connector data, such as successful refunds never sent to the connector or pending refunds which already have a connector refund id. A sync with the connector can be scheduled for each of them
+#[utoipa::path(
+    post,
+    path = "/refunds/reconciliation/export",
+    request_body=RefundReconciliationRequest,
+    responses(
+        (status = 200, description = "The mismatched refunds along with the rule each of them violates", content_type = "text/csv"),
+        (status = 400, description = "Invalid limit")
+    ),
+    tag = "Refunds",
+    operation_id = "Export Refund Reconciliation Mismatches",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub fn refunds_reconciliation_export() {}
+
+/// Refunds - List modified since
+///
+/// Lists the refunds of the merchant modified at or after the given time, ordered by the time they were last modified. Pass the `next_cursor` of a response in the next request to incrementally sync the refunds created or updated since then
+#[utoipa::path(
+    get,
+    path = "/refunds/modified_since",
+    params(
+        ("since" = Option<PrimitiveDateTime>, Query, description = "Refunds modified at or after this time are returned, ignored when a cursor is provided"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of refunds to include in the response"),
+        ("cursor" = Option<String>, Query, description = "The `next_cursor` of the previous response"),
+    ),
+    responses(
+        (status = 200, description = "List of refunds modified since the given time", body = RefundsModifiedSinceResponse),
+        (status = 400, description = "Invalid cursor or limit")
+    ),
+    tag = "Refunds",
+    operation_id = "List Refunds modified since",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub fn refunds_list_modified_since() {}
+
 /// Refunds - List For the Given profiles
 ///
 /// Lists all the refunds associated with the merchant or a payment_id if payment_id is not provided
@@ -284,3 +344,25 @@ pub async fn refunds_retrieve() {}
 )]
 #[cfg(feature = "v2")]
 pub fn refunds_list() {}
+
+/// Refunds - List modified since
+///
+/// Lists the refunds of the merchant modified at or after the given time, ordered by the time they were last modified. Pass the `next_cursor` of a response in the next request to incrementally sync the refunds created or updated since then
+#[utoipa::path(
+    get,
+    path = "/v2/refunds/modified_since",
+    params(
+        ("since" = Option<PrimitiveDateTime>, Query, description = "Refunds modified at or after this time are returned, ignored when a cursor is provided"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of refunds to include in the response"),
+        ("cursor" = Option<String>, Query, description = "The `next_cursor` of the previous response"),
+    ),
+    responses(
+        (status = 200, description = "List of refunds modified since the given time", body = RefundsModifiedSinceResponse),
+        (status = 400, description = "Invalid cursor or limit")
+    ),
+    tag = "Refunds",
+    operation_id = "List Refunds modified since",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v2")]
+pub fn refunds_list_modified_since() {}
@@ -20,6 +20,27 @@
 )]
 pub async fn routing_create_config() {}
 
+#[cfg(feature = "v1")]
+/// Routing - Validate Import
+///
+/// Validate a routing program exported by the dashboard against the connectors of a profile, without persisting it
+#[utoipa::path(
+    post,
+    path = "/routing/validate-import",
+    request_body = RoutingImportValidationRequest,
+    responses(
+        (status = 200, description = "Routing program validated", body = RoutingImportValidationResponse),
+        (status = 400, description = "Request body is malformed"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Validate a routing program import",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_validate_import() {}
+
 #[cfg(feature = "v2")]
 /// Routing - Create
 ///
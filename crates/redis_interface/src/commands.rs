@@ -444,6 +444,14 @@ impl super::RedisConnectionPool {
         Ok(hsetnx)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn increment_key(&self, key: &RedisKey) -> CustomResult<i64, errors::RedisError> {
+        self.pool
+            .incr(key.tenant_aware_key(self))
+            .await
+            .change_context(errors::RedisError::IncrementKeyFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn increment_fields_in_hash<T>(
         &self,
@@ -509,6 +509,9 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::CardExpired { data: _ } => Self::ExpiredCard,
             errors::ApiErrorResponse::RefundNotPossible { connector: _ } => Self::RefundFailed,
             errors::ApiErrorResponse::RefundFailed { data: _ } => Self::RefundFailed, // Nothing at stripe to map
+            errors::ApiErrorResponse::RefundsSuspendedForConnectorAccount { .. } => {
+                Self::RefundFailed
+            }
             errors::ApiErrorResponse::PayoutFailed { data: _ } => Self::PayoutFailed,
             errors::ApiErrorResponse::ExternalVaultFailed => Self::ExternalVaultFailed,
 
@@ -594,6 +597,9 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
                 param: field_name.to_string(),
             },
             errors::ApiErrorResponse::MaximumRefundCount => Self::MaximumRefundCount,
+            errors::ApiErrorResponse::RefundAmountLimitExceeded { message } => {
+                Self::InvalidRequestData { message }
+            }
             errors::ApiErrorResponse::PaymentNotSucceeded => Self::PaymentFailed,
             errors::ApiErrorResponse::DuplicateMandate => Self::DuplicateMandate,
             errors::ApiErrorResponse::SuccessfulPaymentNotFound => Self::SuccessfulPaymentNotFound,
@@ -777,6 +777,7 @@ impl ForeignTryFrom<(Option<MandateData>, Option<String>)> for Option<payments::
                 }),
             }),
             update_mandate_id: None,
+            metadata: None,
         });
         Ok(mandate_data)
     }
@@ -54,7 +54,13 @@ pub async fn refund_create(
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            refunds::refund_create_core(state, merchant_context, None, req)
+            refunds::refund_create_core(
+                state,
+                merchant_context,
+                None,
+                req,
+                common_enums::RefundOrigin::Api,
+            )
         },
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
@@ -110,6 +110,7 @@ pub async fn setup_intents_retrieve(
         expand_attempts: None,
         expand_captures: None,
         all_keys_required: None,
+        expand_mandate: None,
     };
 
     let api_auth = auth::ApiKeyAuth {
@@ -105,6 +105,7 @@ pub struct StripeMandateResponse {
     pub status: StripeMandateStatus,
     pub payment_method_id: String,
     pub payment_method: String,
+    pub metadata: Option<common_utils::pii::SecretSerdeValue>,
 }
 
 #[cfg(feature = "payouts")]
@@ -234,6 +235,7 @@ impl From<api_models::mandates::MandateResponse> for StripeMandateResponse {
             payment_method: res.payment_method,
             payment_method_id: res.payment_method_id,
             status: StripeMandateStatus::from(res.status),
+            metadata: res.metadata,
         }
     }
 }
@@ -329,7 +331,9 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             api::OutgoingWebhookContent::PaymentDetails(payment) => {
                 Self::PaymentIntent(Box::new((*payment).into()))
             }
-            api::OutgoingWebhookContent::RefundDetails(refund) => Self::Refund((*refund).into()),
+            api::OutgoingWebhookContent::RefundDetails(refund) => {
+                Self::Refund(refund.get_refund().clone().into())
+            }
             api::OutgoingWebhookContent::DisputeDetails(dispute) => {
                 Self::Dispute((*dispute).into())
             }
@@ -86,11 +86,43 @@ impl Default for super::settings::SupportedConnectors {
     }
 }
 
+impl Default for super::settings::MandateFrequencyGuard {
+    fn default() -> Self {
+        Self {
+            tolerance_in_minutes: 60,
+        }
+    }
+}
+
 impl Default for super::settings::Refund {
     fn default() -> Self {
         Self {
             max_attempts: 10,
             max_age: 365,
+            bulk_max_entries: 1000,
+            bulk_max_concurrency_per_connector_account: 5,
+        }
+    }
+}
+
+impl Default for super::settings::RefundCircuitBreaker {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            failure_threshold: 5,
+            failure_window_in_secs: 600,
+            cool_down_in_secs: 900,
+        }
+    }
+}
+
+impl Default for super::settings::RefundRetry {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_attempts: 5,
+            base_delay_in_secs: 60,
+            max_delay_in_secs: 3600,
         }
     }
 }
@@ -470,6 +470,8 @@ pub(crate) async fn fetch_raw_secrets(
         connectors: conf.connectors,
         forex_api,
         refund: conf.refund,
+        refund_circuit_breaker: conf.refund_circuit_breaker,
+        refund_retry: conf.refund_retry,
         eph_key: conf.eph_key,
         scheduler: conf.scheduler,
         jwekey,
@@ -488,6 +490,7 @@ pub(crate) async fn fetch_raw_secrets(
         user: conf.user,
         mandates: conf.mandates,
         zero_mandates: conf.zero_mandates,
+        mandate_frequency_guard: conf.mandate_frequency_guard,
         network_transaction_id_supported_connectors: conf
             .network_transaction_id_supported_connectors,
         required_fields: conf.required_fields,
@@ -82,6 +82,8 @@ pub struct Settings<S: SecretState> {
     pub connectors: Connectors,
     pub forex_api: SecretStateContainer<ForexApi, S>,
     pub refund: Refund,
+    pub refund_circuit_breaker: RefundCircuitBreaker,
+    pub refund_retry: RefundRetry,
     pub eph_key: EphemeralConfig,
     pub scheduler: Option<SchedulerSettings>,
     #[cfg(feature = "kv_store")]
@@ -105,6 +107,7 @@ pub struct Settings<S: SecretState> {
     pub cors: CorsSettings,
     pub mandates: Mandates,
     pub zero_mandates: ZeroMandates,
+    pub mandate_frequency_guard: MandateFrequencyGuard,
     pub network_transaction_id_supported_connectors: NetworkTransactionIdSupportedConnectors,
     pub required_fields: RequiredFields,
     pub delayed_session_response: DelayedSessionConfig,
@@ -742,6 +745,36 @@ pub enum DecryptionScheme {
 pub struct Refund {
     pub max_attempts: usize,
     pub max_age: i64,
+    pub bulk_max_entries: usize,
+    pub bulk_max_concurrency_per_connector_account: usize,
+}
+
+/// Suspends the refunds of a merchant connector account once its refunds repeatedly fail due to
+/// its credentials or its configuration
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct RefundCircuitBreaker {
+    pub enabled: bool,
+    pub failure_threshold: u32,
+    pub failure_window_in_secs: i64,
+    pub cool_down_in_secs: i64,
+}
+
+/// Re-executes refunds which failed due to a transient connector error, with an exponentially
+/// increasing delay between the attempts
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct RefundRetry {
+    pub enabled: bool,
+    pub max_attempts: u32,
+    pub base_delay_in_secs: i64,
+    pub max_delay_in_secs: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct MandateFrequencyGuard {
+    pub tolerance_in_minutes: i64,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -1035,6 +1068,8 @@ impl Settings<SecuredSecret> {
             .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.to_string()))?;
 
         self.lock_settings.validate()?;
+        self.refund_circuit_breaker.validate()?;
+        self.refund_retry.validate()?;
         self.events.validate()?;
 
         #[cfg(feature = "olap")]
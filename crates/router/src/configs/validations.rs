@@ -181,6 +181,48 @@ impl super::settings::LockSettings {
     }
 }
 
+impl super::settings::RefundCircuitBreaker {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.failure_threshold == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "refund circuit breaker failure_threshold must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.failure_window_in_secs <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "refund circuit breaker failure_window_in_secs must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.cool_down_in_secs <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "refund circuit breaker cool_down_in_secs must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::RefundRetry {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.base_delay_in_secs <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "refund retry base_delay_in_secs must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.max_delay_in_secs < self.base_delay_in_secs, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "refund retry max_delay_in_secs must not be less than base_delay_in_secs".into(),
+            ))
+        })
+    }
+}
+
 impl super::settings::WebhooksSettings {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
@@ -4,7 +4,7 @@ pub mod user;
 pub mod user_role;
 use std::collections::HashSet;
 
-use api_models::enums::Country;
+use api_models::enums::{Connector, Country};
 use common_utils::consts;
 pub use hyperswitch_domain_models::consts::{
     CONNECTOR_MANDATE_REQUEST_REFERENCE_ID_LENGTH, ROUTING_ENABLED_PAYMENT_METHODS,
@@ -31,6 +31,13 @@ pub const REQUEST_TIMEOUT_PAYMENT_NOT_FOUND: &str = "Timed out ,payment not foun
 pub const REQUEST_TIMEOUT_ERROR_MESSAGE_FROM_PSYNC: &str =
     "This Payment has been moved to failed as there is no response from the connector";
 
+/// Error code of a refund failed because refunds are not implemented for the connector
+pub const REFUND_NOT_IMPLEMENTED_ERROR_CODE: &str = "NOT_IMPLEMENTED";
+/// Error code of a refund failed because the connector does not support it for the payment
+pub const REFUND_NOT_SUPPORTED_ERROR_CODE: &str = "NOT_SUPPORTED";
+/// Error code of a refund failed because the refunds of its connector account are suspended
+pub const REFUND_SUSPENDED_ERROR_CODE: &str = "REFUNDS_SUSPENDED";
+
 ///Payment intent fulfillment default timeout (in seconds)
 pub const DEFAULT_FULFILLMENT_TIME: i64 = 15 * 60;
 
@@ -220,6 +227,80 @@ pub const AUTHENTICATION_SERVICE_ELIGIBLE_CONFIG: &str =
 /// Refund flow identifier used for performing GSM operations
 pub const REFUND_FLOW_STR: &str = "refund_flow";
 
+/// `updated_by` marker for refunds created from connector initiated refund webhooks
+pub const CONNECTOR_WEBHOOK_REFUND_UPDATED_BY: &str = "connector_webhook";
+
+/// `updated_by` marker for refunds whose merchant connector account id was set by the backfill
+pub const REFUND_MERCHANT_CONNECTOR_ID_BACKFILL_UPDATED_BY: &str = "merchant_connector_id_backfill";
+
+/// Default number of refunds processed in a single merchant connector account id backfill batch
+pub const DEFAULT_REFUND_BACKFILL_BATCH_SIZE: u32 = 100;
+
+/// Maximum number of refunds processed in a single merchant connector account id backfill batch
+pub const MAX_REFUND_BACKFILL_BATCH_SIZE: u32 = 1000;
+
+/// Default time in seconds since the last update after which a pending refund is synced by the
+/// stale refunds sync
+pub const DEFAULT_STALE_REFUND_AGE_IN_SECS: i64 = 24 * 60 * 60;
+
+/// Default number of refunds synced in a single stale refunds sync
+pub const DEFAULT_STALE_REFUND_SYNC_LIMIT: u32 = 100;
+
+/// Maximum number of refunds synced in a single stale refunds sync
+pub const MAX_STALE_REFUND_SYNC_LIMIT: u32 = 1000;
+
+/// Interval after which a refund batch whose entries could not all be submitted is run again
+pub const REFUND_BATCH_EXECUTE_RETRY_INTERVAL_IN_SECONDS: i64 = 60;
+
+/// Maximum number of times a refund batch whose entries could not all be submitted is run again
+pub const MAX_REFUND_BATCH_EXECUTE_RETRIES: i32 = 3;
+
+/// Default number of mandates processed in a single connector mandate id backfill batch
+pub const DEFAULT_MANDATE_BACKFILL_BATCH_SIZE: u32 = 100;
+
+/// Maximum number of mandates processed in a single connector mandate id backfill batch
+pub const MAX_MANDATE_BACKFILL_BATCH_SIZE: u32 = 1000;
+
+/// Time after which a mandate still awaiting the asynchronous mandate reference is marked inactive
+pub const PENDING_MANDATE_TIMEOUT_IN_SECONDS: i64 = 24 * 60 * 60;
+
+/// Default number of mandates processed in a single stale pending mandate sweep batch
+pub const DEFAULT_PENDING_MANDATE_SWEEP_BATCH_SIZE: u32 = 100;
+
+/// Maximum number of mandates processed in a single stale pending mandate sweep batch
+pub const MAX_PENDING_MANDATE_SWEEP_BATCH_SIZE: u32 = 1000;
+
+/// Maximum number of mandates of a customer revoked with the connectors at once
+pub const MAX_CONCURRENT_CUSTOMER_MANDATE_REVOCATIONS: usize = 5;
+
+/// The key of the mandate metadata recording the connector error of a mandate revoked locally
+/// by a forced revocation
+pub const FORCED_MANDATE_REVOCATION_METADATA_KEY: &str = "forced_revocation";
+
+/// Maximum number of keys in the metadata set by the merchant on a mandate
+pub const MAX_MANDATE_METADATA_KEYS: usize = 50;
+
+/// Maximum length of a key in the metadata set by the merchant on a mandate
+pub const MAX_MANDATE_METADATA_KEY_LENGTH: usize = 40;
+
+/// Maximum length of a value in the metadata set by the merchant on a mandate
+pub const MAX_MANDATE_METADATA_VALUE_LENGTH: usize = 500;
+
+/// Content type of the JSON lines refunds export
+pub const JSONL_CONTENT_TYPE: &str = "application/x-ndjson";
+
+/// Maximum time a refund retrieve request may wait for the refund to reach a terminal status
+pub const MAX_REFUND_RETRIEVE_WAIT_SECONDS: u64 = 30;
+
+/// Interval at which the refund status is re-checked while waiting for a terminal status
+pub const REFUND_RETRIEVE_WAIT_POLL_INTERVAL_IN_MILLISECONDS: u64 = 500;
+
+/// Minimum interval between refund syncs with the connector while waiting for a terminal status
+pub const REFUND_RETRIEVE_WAIT_SYNC_INTERVAL_IN_SECONDS: u64 = 5;
+
+/// Maximum number of refund retrieve requests of a merchant waiting for a terminal status at once
+pub const MAX_CONCURRENT_REFUND_RETRIEVE_WAITS_PER_MERCHANT: usize = 10;
+
 /// Minimum IBAN length (country-dependent), as per ISO 13616 standard
 pub const IBAN_MIN_LENGTH: usize = 15;
 
@@ -307,3 +388,12 @@ pub const PSD2_COUNTRIES: [Country; 27] = [
     Country::Spain,
     Country::Sweden,
 ];
+
+/// Connectors which support revoking a mandate at the connector, mandates of other connectors are
+/// only revoked locally
+pub const MANDATE_REVOKE_SUPPORTED_CONNECTORS: [Connector; 4] = [
+    Connector::Braintree,
+    Connector::Cybersource,
+    Connector::Noon,
+    Connector::Wellsfargo,
+];
@@ -41,6 +41,12 @@ pub mod poll;
 #[cfg(feature = "recon")]
 pub mod recon;
 #[cfg(feature = "v1")]
+pub mod refund_circuit_breaker;
+#[cfg(feature = "v1")]
+pub mod refund_limits;
+#[cfg(feature = "v1")]
+pub mod refund_retry;
+#[cfg(feature = "v1")]
 pub mod refunds;
 #[cfg(feature = "v2")]
 pub mod refunds_v2;
@@ -813,18 +813,16 @@ impl CustomerDeleteBridge for id_type::CustomerId {
             .await
             .switch()?;
 
-        let customer_mandates = db
-            .find_mandate_by_merchant_id_customer_id(
+        let active_mandates_count = db
+            .count_active_mandates_by_customer(
                 merchant_context.get_merchant_account().get_id(),
                 self,
             )
             .await
             .switch()?;
 
-        for mandate in customer_mandates.into_iter() {
-            if mandate.mandate_status == enums::MandateStatus::Active {
-                Err(errors::CustomersErrorResponse::MandateActive)?
-            }
+        if active_mandates_count > 0 {
+            Err(errors::CustomersErrorResponse::MandateActive)?
         }
 
         match db
@@ -310,6 +310,10 @@ pub enum RoutingError {
     DslMissingInDb,
     #[error("Unable to parse DSL from JSON")]
     DslParsingError,
+    #[error("Unable to migrate DSL to the latest program version")]
+    DslProgramMigrationError,
+    #[error("Unable to compute the hash of the DSL program")]
+    DslProgramHashError,
     #[error("Failed to initialize DSL backend")]
     DslBackendInitError,
     #[error("Error updating merchant with latest dsl cache contents")]
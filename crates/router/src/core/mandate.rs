@@ -2,10 +2,17 @@ pub mod helpers;
 pub mod utils;
 use api_models::payments;
 use common_types::payments as common_payments_types;
+#[cfg(feature = "v1")]
+use common_utils::pii;
 use common_utils::{ext_traits::Encode, id_type};
 use diesel_models::enums as storage_enums;
 use error_stack::{report, ResultExt};
 use futures::future;
+#[cfg(feature = "v1")]
+use futures::StreamExt;
+use hyperswitch_interfaces::api::ConnectorSpecifications;
+#[cfg(feature = "v1")]
+use masking::ExposeInterface;
 use router_env::{instrument, logger, tracing};
 
 use super::payments::helpers as payment_helper;
@@ -14,7 +21,7 @@ use crate::{
         errors::{self, RouterResponse, StorageErrorExt},
         payments::CallConnectorAction,
     },
-    db::StorageInterface,
+    events::domain_events::DomainEvent,
     routes::{metrics, SessionState},
     services,
     types::{
@@ -29,6 +36,12 @@ use crate::{
     },
     utils::OptionExt,
 };
+#[cfg(feature = "v1")]
+use crate::{
+    db::StorageInterface,
+    events::audit_events::{AuditEvent, AuditEventType},
+    routes::app::ReqState,
+};
 
 #[instrument(skip(state))]
 pub async fn get_mandate(
@@ -57,12 +70,103 @@ pub async fn get_mandate(
     ))
 }
 
+/// Checks whether a mandate can be revoked, without calling the connector. Revoking a mandate and
+/// checking its revocation eligibility both go through this, so that the two always agree.
+#[cfg(feature = "v1")]
+pub fn get_mandate_revocation_verdict(
+    mandate: &storage::Mandate,
+) -> (
+    mandates::MandateRevocationVerdict,
+    Option<mandates::MandateRevocationReason>,
+) {
+    let is_revoke_supported_by_connector = mandate
+        .connector
+        .parse::<api_models::enums::Connector>()
+        .is_ok_and(|connector| {
+            crate::consts::MANDATE_REVOKE_SUPPORTED_CONNECTORS.contains(&connector)
+        });
+    let has_connector_mandate_reference =
+        mandate.connector_mandate_id.is_some() || mandate.connector_mandate_ids.is_some();
+
+    match mandate.mandate_status {
+        common_enums::MandateStatus::Revoked => (
+            mandates::MandateRevocationVerdict::Ineligible,
+            Some(mandates::MandateRevocationReason::AlreadyRevoked),
+        ),
+        common_enums::MandateStatus::Active
+        | common_enums::MandateStatus::Inactive
+        | common_enums::MandateStatus::Pending => {
+            if !is_revoke_supported_by_connector {
+                (
+                    mandates::MandateRevocationVerdict::EligibleLocalOnly,
+                    Some(mandates::MandateRevocationReason::ConnectorRevokeNotSupported),
+                )
+            } else if !has_connector_mandate_reference {
+                (
+                    mandates::MandateRevocationVerdict::Ineligible,
+                    Some(mandates::MandateRevocationReason::MissingConnectorMandateReference),
+                )
+            } else {
+                (mandates::MandateRevocationVerdict::Eligible, None)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "v1")]
+fn get_mandate_revocation_failure_message(
+    reason: Option<mandates::MandateRevocationReason>,
+) -> String {
+    match reason {
+        Some(mandates::MandateRevocationReason::AlreadyRevoked) => {
+            "Mandate has already been revoked"
+        }
+        Some(mandates::MandateRevocationReason::MissingConnectorMandateReference) => {
+            "Mandate does not have a connector mandate reference"
+        }
+        Some(mandates::MandateRevocationReason::ConnectorRevokeNotSupported) | None => {
+            "Mandate can not be revoked"
+        }
+    }
+    .to_string()
+}
+
 #[cfg(feature = "v1")]
 #[instrument(skip(state))]
-pub async fn revoke_mandate(
+pub async fn get_mandate_revocation_eligibility(
     state: SessionState,
     merchant_context: domain::MerchantContext,
     req: mandates::MandateId,
+) -> RouterResponse<mandates::MandateRevocationEligibilityResponse> {
+    let mandate = state
+        .store
+        .as_ref()
+        .find_mandate_by_merchant_id_mandate_id(
+            merchant_context.get_merchant_account().get_id(),
+            &req.mandate_id,
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
+    let (verdict, reason) = get_mandate_revocation_verdict(&mandate);
+
+    Ok(services::ApplicationResponse::Json(
+        mandates::MandateRevocationEligibilityResponse {
+            mandate_id: mandate.mandate_id,
+            status: mandate.mandate_status,
+            verdict,
+            reason,
+        },
+    ))
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip(state, req_state))]
+pub async fn revoke_mandate(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_context: domain::MerchantContext,
+    req: mandates::MandateRevokeRequest,
 ) -> RouterResponse<mandates::MandateRevokedResponse> {
     let db = state.store.as_ref();
     let mandate = db
@@ -73,102 +177,394 @@ pub async fn revoke_mandate(
         )
         .await
         .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
-    match mandate.mandate_status {
-        common_enums::MandateStatus::Active
-        | common_enums::MandateStatus::Inactive
-        | common_enums::MandateStatus::Pending => {
-            let profile_id =
-                helpers::get_profile_id_for_mandate(&state, &merchant_context, mandate.clone())
-                    .await?;
+    let RevokedMandate {
+        mandate: revoked_mandate,
+        connector_error,
+    } = revoke_db_mandate(&state, &merchant_context, mandate, req.force).await?;
 
-            let merchant_connector_account = payment_helper::get_merchant_connector_account(
-                &state,
-                merchant_context.get_merchant_account().get_id(),
-                None,
-                merchant_context.get_merchant_key_store(),
-                &profile_id,
-                &mandate.connector.clone(),
-                mandate.merchant_connector_id.as_ref(),
-            )
-            .await?;
+    let (error_code, error_message) = match connector_error {
+        Some((error_code, error_message)) => {
+            req_state
+                .event_context
+                .event(AuditEvent::new(AuditEventType::MandateForceRevoked {
+                    mandate_id: revoked_mandate.mandate_id.clone(),
+                    connector: revoked_mandate.connector.clone(),
+                    error_code: error_code.clone(),
+                    error_message: error_message.clone(),
+                }))
+                .emit();
+            (Some(error_code), Some(error_message))
+        }
+        None => (None, None),
+    };
 
-            let connector_data = ConnectorData::get_connector_by_name(
-                &state.conf.connectors,
-                &mandate.connector,
-                GetToken::Connector,
-                mandate.merchant_connector_id.clone(),
-            )?;
-            let connector_integration: services::BoxedMandateRevokeConnectorIntegrationInterface<
-                types::api::MandateRevoke,
-                types::MandateRevokeRequestData,
-                types::MandateRevokeResponseData,
-            > = connector_data.connector.get_connector_integration();
-
-            let router_data = utils::construct_mandate_revoke_router_data(
-                &state,
-                merchant_connector_account,
-                &merchant_context,
-                mandate.clone(),
-            )
-            .await?;
-
-            let response = services::execute_connector_processing_step(
-                &state,
-                connector_integration,
-                &router_data,
-                CallConnectorAction::Trigger,
-                None,
-                None,
-            )
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError)?;
-
-            match response.response {
-                Ok(_) => {
-                    let update_mandate = db
-                        .update_mandate_by_merchant_id_mandate_id(
-                            merchant_context.get_merchant_account().get_id(),
-                            &req.mandate_id,
-                            storage::MandateUpdate::StatusUpdate {
-                                mandate_status: storage::enums::MandateStatus::Revoked,
-                            },
-                            mandate,
-                            merchant_context.get_merchant_account().storage_scheme,
-                        )
-                        .await
-                        .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
-                    Ok(services::ApplicationResponse::Json(
-                        mandates::MandateRevokedResponse {
-                            mandate_id: update_mandate.mandate_id,
-                            status: update_mandate.mandate_status,
-                            error_code: None,
-                            error_message: None,
-                        },
-                    ))
-                }
+    Ok(services::ApplicationResponse::Json(
+        mandates::MandateRevokedResponse {
+            mandate_id: revoked_mandate.mandate_id,
+            status: revoked_mandate.mandate_status,
+            revoked_locally_only: error_code.is_some(),
+            error_code,
+            error_message,
+        },
+    ))
+}
 
-                Err(err) => Err(errors::ApiErrorResponse::ExternalConnectorError {
-                    code: err.code,
-                    message: err.message,
-                    connector: mandate.connector,
-                    status_code: err.status_code,
-                    reason: err.reason,
-                }
-                .into()),
-            }
+/// A mandate marked as revoked, along with the code and message of the connector error when the
+/// mandate was force revoked locally after the connector failed to revoke it
+#[cfg(feature = "v1")]
+struct RevokedMandate {
+    mandate: storage::Mandate,
+    connector_error: Option<(String, String)>,
+}
+
+/// Revokes the mandate at the connector when the connector supports it, and marks it as revoked.
+/// With `force`, the mandate is still marked as revoked when the connector fails to revoke it.
+#[cfg(feature = "v1")]
+async fn revoke_db_mandate(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    mandate: storage::Mandate,
+    force: bool,
+) -> errors::RouterResult<RevokedMandate> {
+    let (verdict, reason) = get_mandate_revocation_verdict(&mandate);
+    match verdict {
+        mandates::MandateRevocationVerdict::Eligible => {
+            let connector_revocation =
+                revoke_mandate_at_connector(state, merchant_context, &mandate).await;
+            let connector_error =
+                get_forced_revocation_connector_error(connector_revocation, force)?;
+
+            let merchant_metadata = connector_error.as_ref().map(|(error_code, error_message)| {
+                logger::warn!(
+                    mandate_id = %mandate.mandate_id,
+                    connector = %mandate.connector,
+                    error_code = %error_code,
+                    error_message = %error_message,
+                    "Force revoking mandate locally as the connector failed to revoke it"
+                );
+                get_forced_revocation_metadata(
+                    mandate.merchant_metadata.clone(),
+                    &mandate.connector,
+                    error_code,
+                    error_message,
+                )
+            });
+            let mandate =
+                mark_mandate_revoked(state, merchant_context, mandate, merchant_metadata).await?;
+
+            Ok(RevokedMandate {
+                mandate,
+                connector_error,
+            })
+        }
+        mandates::MandateRevocationVerdict::EligibleLocalOnly => {
+            logger::info!(
+                mandate_id = %mandate.mandate_id,
+                connector = %mandate.connector,
+                "Revoking mandate locally as the connector does not support revoking mandates"
+            );
+            let mandate = mark_mandate_revoked(state, merchant_context, mandate, None).await?;
+
+            Ok(RevokedMandate {
+                mandate,
+                connector_error: None,
+            })
         }
-        common_enums::MandateStatus::Revoked => {
+        mandates::MandateRevocationVerdict::Ineligible => {
             Err(errors::ApiErrorResponse::MandateValidationFailed {
-                reason: "Mandate has already been revoked".to_string(),
+                reason: get_mandate_revocation_failure_message(reason),
             }
             .into())
         }
     }
 }
 
-#[instrument(skip(db))]
-pub async fn update_connector_mandate_id(
+#[cfg(feature = "v1")]
+async fn revoke_mandate_at_connector(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    mandate: &storage::Mandate,
+) -> errors::RouterResult<()> {
+    let profile_id =
+        helpers::get_profile_id_for_mandate(state, merchant_context, mandate.clone()).await?;
+
+    let merchant_connector_account = payment_helper::get_merchant_connector_account(
+        state,
+        merchant_context.get_merchant_account().get_id(),
+        None,
+        merchant_context.get_merchant_key_store(),
+        &profile_id,
+        &mandate.connector.clone(),
+        mandate.merchant_connector_id.as_ref(),
+    )
+    .await?;
+
+    let connector_data = ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &mandate.connector,
+        GetToken::Connector,
+        mandate.merchant_connector_id.clone(),
+    )?;
+    let connector_integration: services::BoxedMandateRevokeConnectorIntegrationInterface<
+        types::api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > = connector_data.connector.get_connector_integration();
+
+    let router_data = utils::construct_mandate_revoke_router_data(
+        state,
+        merchant_connector_account,
+        merchant_context,
+        mandate.clone(),
+    )
+    .await?;
+
+    let response = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        CallConnectorAction::Trigger,
+        None,
+        None,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    response.response.map(|_| ()).map_err(|err| {
+        errors::ApiErrorResponse::ExternalConnectorError {
+            code: err.code,
+            message: err.message,
+            connector: mandate.connector.clone(),
+            status_code: err.status_code,
+            reason: err.reason,
+        }
+        .into()
+    })
+}
+
+/// Decides how the failure of the connector to revoke a mandate is handled: the error fails the
+/// revocation, unless the revocation is forced, in which case the code and message of the error
+/// are returned to be recorded on the mandate revoked locally
+#[cfg(feature = "v1")]
+fn get_forced_revocation_connector_error(
+    connector_revocation: errors::RouterResult<()>,
+    force: bool,
+) -> errors::RouterResult<Option<(String, String)>> {
+    match connector_revocation {
+        Ok(()) => Ok(None),
+        Err(error) if force => Ok(Some(get_mandate_revocation_error_details(
+            error.current_context(),
+        ))),
+        Err(error) => Err(error),
+    }
+}
+
+/// Records the connector error of a forced revocation in the metadata of the mandate, leaving the
+/// other keys as they are
+#[cfg(feature = "v1")]
+fn get_forced_revocation_metadata(
+    existing_metadata: Option<pii::SecretSerdeValue>,
+    connector: &str,
+    error_code: &str,
+    error_message: &str,
+) -> pii::SecretSerdeValue {
+    merge_mandate_metadata(
+        existing_metadata,
+        pii::SecretSerdeValue::new(serde_json::json!({
+            (crate::consts::FORCED_MANDATE_REVOCATION_METADATA_KEY): {
+                "connector": connector,
+                "error_code": error_code,
+                "error_message": error_message,
+            }
+        })),
+    )
+}
+
+#[cfg(feature = "v1")]
+async fn mark_mandate_revoked(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    mandate: storage::Mandate,
+    merchant_metadata: Option<pii::SecretSerdeValue>,
+) -> errors::RouterResult<storage::Mandate> {
+    let previous_status = mandate.mandate_status;
+    let mandate_id = mandate.mandate_id.clone();
+    let mandate_update = match merchant_metadata {
+        Some(merchant_metadata) => storage::MandateUpdate::StatusAndMetadataUpdate {
+            mandate_status: storage::enums::MandateStatus::Revoked,
+            merchant_metadata,
+        },
+        None => storage::MandateUpdate::StatusUpdate {
+            mandate_status: storage::enums::MandateStatus::Revoked,
+        },
+    };
+    let update_mandate = state
+        .store
+        .update_mandate_by_merchant_id_mandate_id(
+            merchant_context.get_merchant_account().get_id(),
+            &mandate_id,
+            mandate_update,
+            mandate,
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
+    DomainEvent::mandate_revoked(previous_status, &update_mandate).emit(state);
+    trigger_mandate_webhook(state, merchant_context, None, &update_mandate).await;
+
+    Ok(update_mandate)
+}
+
+/// The event of the outgoing webhook notifying the merchant of the status of the mandate, absent
+/// for the statuses which are not notified
+pub fn get_mandate_webhook_event_type(
+    mandate: &storage::Mandate,
+) -> Option<api_models::enums::EventType> {
+    mandate.mandate_status.into()
+}
+
+/// Notifies the merchant of the status of the mandate through an outgoing webhook, looking up the
+/// profile of the mandate when it is not known. The mandate has already been updated by then, so
+/// failing to send the webhook is only logged.
+#[cfg(feature = "v1")]
+async fn trigger_mandate_webhook(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    business_profile: Option<&domain::Profile>,
+    mandate: &storage::Mandate,
+) {
+    let result = async {
+        let business_profile = match business_profile {
+            Some(business_profile) => business_profile.clone(),
+            None => {
+                let profile_id =
+                    helpers::get_profile_id_for_mandate(state, merchant_context, mandate.clone())
+                        .await?;
+                state
+                    .store
+                    .find_business_profile_by_profile_id(
+                        &state.into(),
+                        merchant_context.get_merchant_key_store(),
+                        &profile_id,
+                    )
+                    .await
+                    .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+                        id: profile_id.get_string_repr().to_owned(),
+                    })?
+            }
+        };
+        crate::utils::trigger_mandate_outgoing_webhook(
+            state,
+            merchant_context,
+            business_profile,
+            mandate,
+        )
+        .await
+    }
+    .await;
+
+    if let Err(error) = result {
+        logger::error!(
+            ?error,
+            mandate_id = %mandate.mandate_id,
+            "Failed to trigger the outgoing webhook of the mandate"
+        );
+    }
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip(state))]
+pub async fn update_mandate(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    req: mandates::MandateUpdateRequest,
+) -> RouterResponse<mandates::MandateResponse> {
+    helpers::validate_mandate_metadata(&req.metadata)?;
+
+    let db = state.store.as_ref();
+    let mandate = db
+        .find_mandate_by_merchant_id_mandate_id(
+            merchant_context.get_merchant_account().get_id(),
+            &req.mandate_id,
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
+
+    let updated_mandate = update_mandate_metadata(
+        db,
+        mandate,
+        req.metadata,
+        merchant_context.get_merchant_account().storage_scheme,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        mandates::MandateResponse::from_db_mandate(
+            &state,
+            merchant_context.get_merchant_key_store().clone(),
+            updated_mandate,
+            merchant_context.get_merchant_account(),
+        )
+        .await?,
+    ))
+}
+
+#[cfg(feature = "v1")]
+async fn update_mandate_metadata(
     db: &dyn StorageInterface,
+    mandate: storage::Mandate,
+    metadata: pii::SecretSerdeValue,
+    storage_scheme: MerchantStorageScheme,
+) -> errors::RouterResult<storage::Mandate> {
+    let merchant_metadata = merge_mandate_metadata(mandate.merchant_metadata.clone(), metadata);
+    helpers::validate_mandate_metadata(&merchant_metadata)?;
+
+    let merchant_id = mandate.merchant_id.clone();
+    let mandate_id = mandate.mandate_id.clone();
+    db.update_mandate_by_merchant_id_mandate_id(
+        &merchant_id,
+        &mandate_id,
+        storage::MandateUpdate::MetadataUpdate { merchant_metadata },
+        mandate,
+        storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)
+}
+
+/// Merges the metadata received in a mandate update into the existing metadata of the mandate.
+/// Keys present in the update replace the existing ones, and keys with a `null` value are removed.
+#[cfg(feature = "v1")]
+fn merge_mandate_metadata(
+    existing_metadata: Option<pii::SecretSerdeValue>,
+    metadata: pii::SecretSerdeValue,
+) -> pii::SecretSerdeValue {
+    let mut merged_metadata = match existing_metadata.map(ExposeInterface::expose) {
+        Some(serde_json::Value::Object(existing_metadata)) => existing_metadata,
+        _ => serde_json::Map::new(),
+    };
+
+    if let serde_json::Value::Object(metadata) = metadata.expose() {
+        for (key, value) in metadata {
+            if value.is_null() {
+                merged_metadata.remove(&key);
+            } else {
+                merged_metadata.insert(key, value);
+            }
+        }
+    }
+
+    pii::SecretSerdeValue::new(serde_json::Value::Object(merged_metadata))
+}
+
+/// Stores the connector mandate reference received for the payment on its mandate, unless the
+/// mandate already has one, and notifies the merchant of the updated mandate
+#[cfg(feature = "v1")]
+#[instrument(skip(state, key_store))]
+pub async fn update_connector_mandate_id(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
     merchant_id: &id_type::MerchantId,
     mandate_ids_opt: Option<String>,
     payment_method_id: Option<String>,
@@ -187,6 +583,7 @@ pub async fn update_connector_mandate_id(
 
     //Ignore updation if the payment_attempt mandate_id or connector_mandate_id is not present
     if let Some((mandate_id, connector_id)) = mandate_ids_opt.zip(connector_mandate_id) {
+        let db = state.store.as_ref();
         let mandate = db
             .find_mandate_by_merchant_id_mandate_id(merchant_id, &mandate_id, storage_scheme)
             .await
@@ -207,26 +604,86 @@ pub async fn update_connector_mandate_id(
 
         // only update the connector_mandate_id if existing is none
         if mandate.connector_mandate_id.is_none() {
-            db.update_mandate_by_merchant_id_mandate_id(
-                merchant_id,
-                &mandate_id,
-                update_mandate_details,
-                mandate,
-                storage_scheme,
-            )
-            .await
-            .change_context(errors::ApiErrorResponse::MandateUpdateFailed)?;
+            let updated_mandate = db
+                .update_mandate_by_merchant_id_mandate_id(
+                    merchant_id,
+                    &mandate_id,
+                    update_mandate_details,
+                    mandate,
+                    storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::MandateUpdateFailed)?;
+
+            match db
+                .find_merchant_account_by_merchant_id(&state.into(), merchant_id, key_store)
+                .await
+            {
+                Ok(merchant_account) => {
+                    let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                        domain::Context(merchant_account, key_store.clone()),
+                    ));
+                    trigger_mandate_webhook(state, &merchant_context, None, &updated_mandate).await;
+                }
+                Err(error) => logger::error!(
+                    ?error,
+                    mandate_id = %updated_mandate.mandate_id,
+                    "Failed to fetch the merchant account for the outgoing webhook of the mandate"
+                ),
+            }
         }
     }
     Ok(services::ApplicationResponse::StatusOk)
 }
+/// Lists the mandates of the customer matching the constraints, all of them when no constraints
+/// are given. A customer without any matching mandate gets an empty list.
 #[cfg(feature = "v1")]
 #[instrument(skip(state))]
 pub async fn get_customer_mandates(
     state: SessionState,
     merchant_context: domain::MerchantContext,
     customer_id: id_type::CustomerId,
+    constraints: api_models::mandates::MandateListConstraints,
 ) -> RouterResponse<Vec<mandates::MandateResponse>> {
+    validate_mandate_list_constraints(&constraints)?;
+    let mandates = state
+        .store
+        .find_mandates_by_merchant_id_customer_id(
+            merchant_context.get_merchant_account().get_id(),
+            &customer_id,
+            constraints,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while finding mandate: merchant_id: {:?}, customer_id: {:?}",
+                merchant_context.get_merchant_account().get_id(),
+                customer_id,
+            )
+        })?;
+
+    let mandates_list = future::try_join_all(mandates.into_iter().map(|mandate| {
+        mandates::MandateResponse::from_db_mandate(
+            &state,
+            merchant_context.get_merchant_key_store().clone(),
+            mandate,
+            merchant_context.get_merchant_account(),
+        )
+    }))
+    .await?;
+    Ok(services::ApplicationResponse::Json(mandates_list))
+}
+
+/// Revokes all the mandates of the customer. Mandates which have already been revoked are
+/// skipped, and a mandate which fails to be revoked is reported without stopping the others.
+#[cfg(feature = "v1")]
+#[instrument(skip(state))]
+pub async fn revoke_customer_mandates(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    customer_id: id_type::CustomerId,
+) -> RouterResponse<mandates::CustomerMandatesRevokedResponse> {
     let mandates = state
         .store
         .find_mandate_by_merchant_id_customer_id(
@@ -244,26 +701,90 @@ pub async fn get_customer_mandates(
         })?;
 
     if mandates.is_empty() {
-        Err(report!(errors::ApiErrorResponse::MandateNotFound).attach_printable("No Mandate found"))
-    } else {
-        let mut response_vec = Vec::with_capacity(mandates.len());
-        for mandate in mandates {
-            response_vec.push(
-                mandates::MandateResponse::from_db_mandate(
-                    &state,
-                    merchant_context.get_merchant_key_store().clone(),
-                    mandate,
-                    merchant_context.get_merchant_account(),
-                )
-                .await?,
+        return Err(
+            report!(errors::ApiErrorResponse::MandateNotFound).attach_printable("No Mandate found")
+        );
+    }
+
+    let revocation_results = futures::stream::iter(mandates)
+        .map(|mandate| revoke_customer_mandate(&state, &merchant_context, mandate))
+        .buffered(crate::consts::MAX_CONCURRENT_CUSTOMER_MANDATE_REVOCATIONS)
+        .collect::<Vec<_>>()
+        .await;
+
+    Ok(services::ApplicationResponse::Json(
+        mandates::CustomerMandatesRevokedResponse {
+            customer_id,
+            mandates: revocation_results,
+        },
+    ))
+}
+
+#[cfg(feature = "v1")]
+async fn revoke_customer_mandate(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    mandate: storage::Mandate,
+) -> mandates::CustomerMandateRevocationResult {
+    let mandate_id = mandate.mandate_id.clone();
+    let connector = mandate.connector.clone();
+    let previous_status = mandate.mandate_status;
+
+    if previous_status == storage_enums::MandateStatus::Revoked {
+        return mandates::CustomerMandateRevocationResult {
+            mandate_id,
+            status: previous_status,
+            outcome: mandates::MandateRevocationOutcome::Skipped,
+            connector,
+            error_code: None,
+            error_message: None,
+        };
+    }
+
+    match revoke_db_mandate(state, merchant_context, mandate, false).await {
+        Ok(revoked_mandate) => mandates::CustomerMandateRevocationResult {
+            mandate_id,
+            status: revoked_mandate.mandate.mandate_status,
+            outcome: mandates::MandateRevocationOutcome::Revoked,
+            connector,
+            error_code: None,
+            error_message: None,
+        },
+        Err(error) => {
+            logger::error!(
+                ?error,
+                mandate_id = %mandate_id,
+                connector = %connector,
+                "Failed to revoke mandate of the customer"
             );
+            let (error_code, error_message) =
+                get_mandate_revocation_error_details(error.current_context());
+            mandates::CustomerMandateRevocationResult {
+                mandate_id,
+                status: previous_status,
+                outcome: mandates::MandateRevocationOutcome::Failed,
+                connector,
+                error_code: Some(error_code),
+                error_message: Some(error_message),
+            }
         }
-        Ok(services::ApplicationResponse::Json(response_vec))
     }
 }
 
-fn get_insensitive_payment_method_data_if_exists<F, FData>(
-    router_data: &types::RouterData<F, FData, types::PaymentsResponseData>,
+/// The code and message reported for a mandate which failed to be revoked, which are the ones
+/// returned by the connector when the connector rejected the revocation
+#[cfg(feature = "v1")]
+fn get_mandate_revocation_error_details(error: &errors::ApiErrorResponse) -> (String, String) {
+    match error {
+        errors::ApiErrorResponse::ExternalConnectorError { code, message, .. } => {
+            (code.clone(), message.clone())
+        }
+        error => (error.error_code(), error.error_message()),
+    }
+}
+
+fn get_insensitive_payment_method_data_if_exists<F, FData>(
+    router_data: &types::RouterData<F, FData, types::PaymentsResponseData>,
 ) -> Option<domain::PaymentMethodData>
 where
     FData: MandateBehaviour,
@@ -274,18 +795,65 @@ where
     }
 }
 
+/// Shortest duration between two consecutive charges of a mandate with the given frequency.
+/// Calendar based frequencies use the shortest period they can span, so that a mandate charged on
+/// the same day of every month, quarter or year is never rejected.
+fn get_mandate_frequency_interval(frequency: storage_enums::MandateFrequency) -> time::Duration {
+    match frequency {
+        storage_enums::MandateFrequency::Daily => time::Duration::days(1),
+        storage_enums::MandateFrequency::Weekly => time::Duration::weeks(1),
+        storage_enums::MandateFrequency::Monthly => time::Duration::days(28),
+        storage_enums::MandateFrequency::Quarterly => time::Duration::days(89),
+        storage_enums::MandateFrequency::Yearly => time::Duration::days(365),
+    }
+}
+
+/// Rejects a payment against a mandate that was last charged more recently than the interval
+/// given by its frequency, less the configured tolerance. [`mandate_procedure`] only runs once the
+/// connector has processed the payment, so this is called when the mandate is loaded for the
+/// recurring payment instead, while [`mandate_procedure`] records the time of the charge. The
+/// payment setting up the mandate counts as its first charge.
+pub fn validate_mandate_frequency(
+    mandate: &storage::Mandate,
+    current_time: time::PrimitiveDateTime,
+    tolerance: time::Duration,
+    bypass_frequency_check: bool,
+) -> errors::RouterResult<()> {
+    let (Some(frequency), Some(last_charged_at)) = (mandate.frequency, mandate.last_charged_at)
+    else {
+        return Ok(());
+    };
+
+    let next_charge_allowed_at =
+        last_charged_at + get_mandate_frequency_interval(frequency) - tolerance;
+
+    if !bypass_frequency_check && current_time < next_charge_allowed_at {
+        return Err(report!(errors::ApiErrorResponse::MandateValidationFailed {
+            reason: format!(
+                "mandate with {frequency} frequency was last charged at {last_charged_at}, \
+                 the next charge is allowed only after {next_charge_allowed_at}"
+            ),
+        }));
+    }
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
 pub async fn mandate_procedure<F, FData>(
     state: &SessionState,
     resp: &types::RouterData<F, FData, types::PaymentsResponseData>,
     customer_id: &Option<id_type::CustomerId>,
     pm_id: Option<String>,
     merchant_connector_id: Option<id_type::MerchantConnectorAccountId>,
-    storage_scheme: MerchantStorageScheme,
+    merchant_context: &domain::MerchantContext,
+    #[cfg(feature = "v1")] business_profile: &domain::Profile,
     payment_id: &id_type::PaymentId,
 ) -> errors::RouterResult<Option<String>>
 where
     FData: MandateBehaviour,
 {
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
     let Ok(ref response) = resp.response else {
         return Ok(None);
     };
@@ -328,6 +896,7 @@ where
                                 orig_mandate.amount_captured.unwrap_or(0)
                                     + resp.request.get_amount(),
                             ),
+                            last_charged_at: Some(common_utils::date_time::now()),
                         },
                         orig_mandate,
                         storage_scheme,
@@ -335,6 +904,19 @@ where
                     .await
                     .change_context(errors::ApiErrorResponse::MandateUpdateFailed),
             }?;
+            // Single use mandates are revoked once they have been used
+            #[cfg(feature = "v1")]
+            {
+                if mandate.mandate_status == storage_enums::MandateStatus::Revoked {
+                    trigger_mandate_webhook(
+                        state,
+                        merchant_context,
+                        Some(business_profile),
+                        &mandate,
+                    )
+                    .await;
+                }
+            }
             metrics::SUBSEQUENT_MANDATE_PAYMENT.add(
                 1,
                 router_env::metric_attributes!(("connector", mandate.connector)),
@@ -363,7 +945,20 @@ where
                 })
                 .transpose()?;
 
-            let Some(new_mandate_data) = payment_helper::generate_mandate(
+            let is_mandate_reference_async = ConnectorData::get_connector_by_name(
+                &state.conf.connectors,
+                &resp.connector,
+                GetToken::Connector,
+                merchant_connector_id.clone(),
+            )?
+            .connector
+            .is_mandate_reference_async();
+            let is_mandate_pending = is_mandate_reference_pending(
+                (*mandate_reference).as_ref(),
+                is_mandate_reference_async,
+            );
+
+            let Some(mut new_mandate_data) = payment_helper::generate_mandate(
                 resp.merchant_id.clone(),
                 payment_id.to_owned(),
                 resp.connector.clone(),
@@ -380,16 +975,26 @@ where
                 return Ok(None);
             };
 
+            // The mandate is activated once the connector sends the mandate reference in the
+            // payment webhook
+            if is_mandate_pending {
+                new_mandate_data.set_mandate_status(storage_enums::MandateStatus::Pending);
+            }
+
             let connector = new_mandate_data.connector.clone();
             logger::debug!("{:?}", new_mandate_data);
 
             let res_mandate_id = new_mandate_data.mandate_id.clone();
 
-            state
+            let mandate = state
                 .store
                 .insert_mandate(new_mandate_data, storage_scheme)
                 .await
                 .to_duplicate_response(errors::ApiErrorResponse::DuplicateMandate)?;
+            DomainEvent::mandate_created(&mandate).emit(state);
+            #[cfg(feature = "v1")]
+            trigger_mandate_webhook(state, merchant_context, Some(business_profile), &mandate)
+                .await;
             metrics::MANDATE_COUNT.add(1, router_env::metric_attributes!(("connector", connector)));
             Ok(Some(res_mandate_id))
         }
@@ -402,6 +1007,7 @@ pub async fn retrieve_mandates_list(
     merchant_context: domain::MerchantContext,
     constraints: api_models::mandates::MandateListConstraints,
 ) -> RouterResponse<Vec<api_models::mandates::MandateResponse>> {
+    validate_mandate_list_constraints(&constraints)?;
     let mandates = state
         .store
         .as_ref()
@@ -424,6 +1030,482 @@ pub async fn retrieve_mandates_list(
     Ok(services::ApplicationResponse::Json(mandates_list))
 }
 
+fn validate_mandate_list_constraints(
+    constraints: &api_models::mandates::MandateListConstraints,
+) -> errors::RouterResult<()> {
+    validate_time_range(
+        "created_time",
+        constraints
+            .created_time_gt
+            .map(|time| (time, true))
+            .into_iter()
+            .chain(constraints.created_time_gte.map(|time| (time, false))),
+        constraints
+            .created_time_lt
+            .map(|time| (time, true))
+            .into_iter()
+            .chain(constraints.created_time_lte.map(|time| (time, false))),
+    )?;
+    validate_time_range(
+        "modified_time",
+        constraints.modified_time_gte.map(|time| (time, false)),
+        constraints.modified_time_lte.map(|time| (time, false)),
+    )
+}
+
+/// Rejects a time range whose lower bound lies after its upper bound. Each bound is paired with
+/// whether it is exclusive, since equal exclusive bounds can never match any mandate either.
+fn validate_time_range(
+    field_name: &str,
+    lower_bounds: impl IntoIterator<Item = (time::PrimitiveDateTime, bool)>,
+    upper_bounds: impl IntoIterator<Item = (time::PrimitiveDateTime, bool)> + Clone,
+) -> errors::RouterResult<()> {
+    for (lower, lower_is_exclusive) in lower_bounds {
+        for (upper, upper_is_exclusive) in upper_bounds.clone() {
+            if lower > upper || (lower == upper && (lower_is_exclusive || upper_is_exclusive)) {
+                return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "Invalid `{field_name}` range, the lower bound {lower} must be before the upper bound {upper}"
+                    ),
+                }));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Outcome of expanding the mandate created or used by a payment in its payments response
+#[derive(Debug, PartialEq)]
+pub enum MandateExpansion {
+    Expanded(Box<mandates::MandateResponse>),
+    /// The mandate id has been linked to the payment attempt, but the mandate itself has not been
+    /// persisted yet
+    Pending,
+}
+
+#[cfg(feature = "v1")]
+impl MandateExpansion {
+    fn apply(self, payments_response: &mut payments::PaymentsResponse) {
+        match self {
+            Self::Expanded(mandate) => {
+                payments_response.mandate = Some(mandate);
+                payments_response.mandate_expansion_pending = None;
+            }
+            Self::Pending => {
+                payments_response.mandate = None;
+                payments_response.mandate_expansion_pending = Some(true);
+            }
+        }
+    }
+}
+
+/// Returns the mandate if it has been persisted, and `None` if the mandate insert has not yet
+/// caught up with the payment attempt referencing it
+#[cfg(feature = "v1")]
+fn get_mandate_if_created(
+    lookup_result: errors::CustomResult<storage::Mandate, errors::StorageError>,
+) -> errors::RouterResult<Option<storage::Mandate>> {
+    match lookup_result {
+        Ok(mandate) => Ok(Some(mandate)),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while fetching the mandate to be expanded")),
+    }
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn expand_mandate_in_payments_response(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    expand_mandate: Option<bool>,
+    response: RouterResponse<payments::PaymentsResponse>,
+) -> RouterResponse<payments::PaymentsResponse> {
+    if !expand_mandate.unwrap_or(false) {
+        return response;
+    }
+
+    let (mut payments_response, headers) = match response? {
+        services::ApplicationResponse::Json(payments_response) => (payments_response, None),
+        services::ApplicationResponse::JsonWithHeaders((payments_response, headers)) => {
+            (payments_response, Some(headers))
+        }
+        response => return Ok(response),
+    };
+
+    if let Some(mandate_id) = payments_response.mandate_id.clone() {
+        let lookup_result = state
+            .store
+            .find_mandate_by_merchant_id_mandate_id(
+                merchant_context.get_merchant_account().get_id(),
+                &mandate_id,
+                merchant_context.get_merchant_account().storage_scheme,
+            )
+            .await;
+
+        let expansion = match get_mandate_if_created(lookup_result)? {
+            Some(mandate) => MandateExpansion::Expanded(Box::new(
+                mandates::MandateResponse::from_db_mandate(
+                    state,
+                    merchant_context.get_merchant_key_store().clone(),
+                    mandate,
+                    merchant_context.get_merchant_account(),
+                )
+                .await?,
+            )),
+            None => {
+                logger::info!(
+                    "Mandate {mandate_id} not yet created, responding with the mandate id only"
+                );
+                MandateExpansion::Pending
+            }
+        };
+        expansion.apply(&mut payments_response);
+    }
+
+    Ok(match headers {
+        Some(headers) => {
+            services::ApplicationResponse::JsonWithHeaders((payments_response, headers))
+        }
+        None => services::ApplicationResponse::Json(payments_response),
+    })
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[instrument(skip(state))]
+pub async fn backfill_mandates_connector_mandate_id(
+    state: SessionState,
+    req: mandates::MandateConnectorReferenceBackfillRequest,
+) -> RouterResponse<mandates::MandateConnectorReferenceBackfillResponse> {
+    let key_manager_state = &(&state).into();
+    let key_store = state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &req.merchant_id,
+            &state.store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        .attach_printable("Error while fetching the key store by merchant_id")?;
+    let merchant_account = state
+        .store
+        .find_merchant_account_by_merchant_id(key_manager_state, &req.merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        .attach_printable("Error while fetching the merchant_account by merchant_id")?;
+
+    let batch_size = req
+        .batch_size
+        .unwrap_or(crate::consts::DEFAULT_MANDATE_BACKFILL_BATCH_SIZE)
+        .clamp(1, crate::consts::MAX_MANDATE_BACKFILL_BATCH_SIZE);
+
+    let response = backfill_mandates_connector_mandate_id_batch(
+        &*state.store,
+        merchant_account.get_id(),
+        merchant_account.storage_scheme,
+        req.starting_after.as_deref(),
+        batch_size,
+        req.dry_run,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+async fn backfill_mandates_connector_mandate_id_batch(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    storage_scheme: MerchantStorageScheme,
+    starting_after: Option<&str>,
+    batch_size: u32,
+    dry_run: bool,
+) -> errors::RouterResult<mandates::MandateConnectorReferenceBackfillResponse> {
+    let mandates_batch = db
+        .find_active_mandates_without_connector_mandate_id(
+            merchant_id,
+            starting_after,
+            i64::from(batch_size),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch mandates without connector mandate id")?;
+
+    // A batch smaller than the batch size implies that there are no more mandates to backfill
+    let mandates_processed = mandates_batch.len();
+    let next_starting_after = mandates_batch
+        .last()
+        .filter(|_| usize::try_from(batch_size).is_ok_and(|size| mandates_processed == size))
+        .map(|mandate| mandate.mandate_id.clone());
+
+    let mut mandates_updated = 0;
+    let mut outcomes = Vec::with_capacity(mandates_processed);
+    for mandate in mandates_batch {
+        let mandate_id = mandate.mandate_id.clone();
+        let status = match mandate.original_payment_id.clone() {
+            None => mandates::MandateConnectorReferenceBackfillStatus::OriginalPaymentMissing,
+            Some(original_payment_id) => {
+                let payment_attempts = db
+                    .find_attempts_by_merchant_id_payment_id(
+                        merchant_id,
+                        &original_payment_id,
+                        storage_scheme,
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable_lazy(|| {
+                        format!(
+                            "Failed to fetch original payment attempts: mandate_id: {mandate_id}"
+                        )
+                    })?;
+
+                match get_mandate_reference_from_payment_attempts(&mandate_id, &payment_attempts) {
+                    Err(status) => status,
+                    Ok(_) if dry_run => {
+                        mandates::MandateConnectorReferenceBackfillStatus::WouldUpdate
+                    }
+                    Ok(mandate_reference) => {
+                        let connector_mandate_ids = mandate_reference
+                            .encode_to_value()
+                            .change_context(errors::ApiErrorResponse::InternalServerError)
+                            .attach_printable("Failed to encode the connector mandate reference")?;
+                        let payment_method_id = mandate.payment_method_id.clone();
+                        db.update_mandate_by_merchant_id_mandate_id(
+                            merchant_id,
+                            &mandate_id,
+                            storage::MandateUpdate::ConnectorMandateIdUpdate {
+                                connector_mandate_id: mandate_reference.connector_mandate_id,
+                                connector_mandate_ids: Some(masking::Secret::new(
+                                    connector_mandate_ids,
+                                )),
+                                payment_method_id,
+                                original_payment_id: Some(original_payment_id),
+                            },
+                            mandate,
+                            storage_scheme,
+                        )
+                        .await
+                        .change_context(errors::ApiErrorResponse::MandateUpdateFailed)
+                        .attach_printable_lazy(|| {
+                            format!("Failed while updating mandate: mandate_id: {mandate_id}")
+                        })?;
+                        mandates_updated += 1;
+                        mandates::MandateConnectorReferenceBackfillStatus::Updated
+                    }
+                }
+            }
+        };
+        outcomes.push(mandates::MandateConnectorReferenceBackfillOutcome { mandate_id, status });
+    }
+
+    logger::info!(
+        mandates_processed,
+        mandates_updated,
+        dry_run,
+        "Backfilled connector mandate id of mandates"
+    );
+
+    Ok(mandates::MandateConnectorReferenceBackfillResponse {
+        mandates_processed,
+        mandates_updated,
+        dry_run,
+        outcomes,
+        next_starting_after,
+    })
+}
+
+/// Resolves the connector mandate reference stored on the payment attempt which set up the
+/// mandate, or the reason why it could not be resolved
+#[cfg(all(feature = "v1", feature = "olap"))]
+fn get_mandate_reference_from_payment_attempts(
+    mandate_id: &str,
+    payment_attempts: &[storage::PaymentAttempt],
+) -> Result<types::MandateReference, mandates::MandateConnectorReferenceBackfillStatus> {
+    let mut mandate_attempts = payment_attempts
+        .iter()
+        .filter(|payment_attempt| payment_attempt.mandate_id.as_deref() == Some(mandate_id))
+        .peekable();
+
+    if mandate_attempts.peek().is_none() {
+        return Err(mandates::MandateConnectorReferenceBackfillStatus::PaymentAttemptNotFound);
+    }
+
+    mandate_attempts
+        .filter_map(|payment_attempt| payment_attempt.connector_mandate_detail.as_ref())
+        .find(|mandate_detail| mandate_detail.connector_mandate_id.is_some())
+        .map(|mandate_detail| types::MandateReference {
+            connector_mandate_id: mandate_detail.connector_mandate_id.clone(),
+            payment_method_id: mandate_detail.payment_method_id.clone(),
+            mandate_metadata: mandate_detail.mandate_metadata.clone(),
+            connector_mandate_request_reference_id: mandate_detail
+                .connector_mandate_request_reference_id
+                .clone(),
+        })
+        .ok_or(mandates::MandateConnectorReferenceBackfillStatus::MandateReferenceNotFound)
+}
+
+/// Whether the mandate set up by the payment awaits the mandate reference, which connectors
+/// returning it asynchronously send in the payment webhook
+fn is_mandate_reference_pending(
+    mandate_reference: Option<&types::MandateReference>,
+    is_mandate_reference_async: bool,
+) -> bool {
+    is_mandate_reference_async
+        && mandate_reference.map_or(true, |reference| reference.connector_mandate_id.is_none())
+}
+
+/// Activates the pending mandate set up by the payment with the connector mandate id received
+/// in the payment webhook, returning `None` when the payment has no pending mandate
+#[cfg(feature = "v1")]
+pub async fn activate_pending_mandate(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+    connector_mandate_id: String,
+    storage_scheme: MerchantStorageScheme,
+) -> errors::RouterResult<Option<storage::Mandate>> {
+    let mandates = db
+        .find_mandates_by_merchant_id_original_payment_id(merchant_id, payment_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch mandates by original payment id")?;
+
+    let Some(mandate) = mandates
+        .into_iter()
+        .find(|mandate| mandate.mandate_status == storage_enums::MandateStatus::Pending)
+    else {
+        return Ok(None);
+    };
+
+    let mandate_reference = types::MandateReference {
+        connector_mandate_id: Some(connector_mandate_id.clone()),
+        payment_method_id: Some(mandate.payment_method_id.clone()),
+        mandate_metadata: None,
+        connector_mandate_request_reference_id: None,
+    };
+    let connector_mandate_ids = mandate_reference
+        .encode_to_value()
+        .change_context(errors::ApiErrorResponse::MandateSerializationFailed)?;
+
+    let mandate_id = mandate.mandate_id.clone();
+    let updated_mandate = db
+        .update_mandate_by_merchant_id_mandate_id(
+            merchant_id,
+            &mandate_id,
+            storage::MandateUpdate::ConnectorMandateIdAndStatusUpdate {
+                connector_mandate_id: Some(connector_mandate_id),
+                connector_mandate_ids: Some(masking::Secret::new(connector_mandate_ids)),
+                mandate_status: storage_enums::MandateStatus::Active,
+            },
+            mandate,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::MandateUpdateFailed)
+        .attach_printable_lazy(|| {
+            format!("Failed while activating pending mandate: mandate_id: {mandate_id}")
+        })?;
+
+    Ok(Some(updated_mandate))
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[instrument(skip(state))]
+pub async fn sweep_stale_pending_mandates(
+    state: SessionState,
+    req: mandates::PendingMandateSweepRequest,
+) -> RouterResponse<mandates::PendingMandateSweepResponse> {
+    let key_manager_state = &(&state).into();
+    let key_store = state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &req.merchant_id,
+            &state.store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        .attach_printable("Error while fetching the key store by merchant_id")?;
+    let merchant_account = state
+        .store
+        .find_merchant_account_by_merchant_id(key_manager_state, &req.merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        .attach_printable("Error while fetching the merchant_account by merchant_id")?;
+
+    let batch_size = req
+        .batch_size
+        .unwrap_or(crate::consts::DEFAULT_PENDING_MANDATE_SWEEP_BATCH_SIZE)
+        .clamp(1, crate::consts::MAX_PENDING_MANDATE_SWEEP_BATCH_SIZE);
+    let created_before = common_utils::date_time::now()
+        - time::Duration::seconds(crate::consts::PENDING_MANDATE_TIMEOUT_IN_SECONDS);
+
+    let response = sweep_stale_pending_mandates_batch(
+        &*state.store,
+        merchant_account.get_id(),
+        merchant_account.storage_scheme,
+        created_before,
+        batch_size,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+async fn sweep_stale_pending_mandates_batch(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    storage_scheme: MerchantStorageScheme,
+    created_before: time::PrimitiveDateTime,
+    batch_size: u32,
+) -> errors::RouterResult<mandates::PendingMandateSweepResponse> {
+    let mandates_batch = db
+        .find_pending_mandates_by_merchant_created_before(
+            merchant_id,
+            created_before,
+            i64::from(batch_size),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch stale pending mandates")?;
+
+    // A full batch implies that there may be more stale pending mandates to sweep
+    let has_more = usize::try_from(batch_size).is_ok_and(|size| mandates_batch.len() == size);
+
+    let mut mandate_ids = Vec::with_capacity(mandates_batch.len());
+    for mandate in mandates_batch {
+        let mandate_id = mandate.mandate_id.clone();
+        db.update_mandate_by_merchant_id_mandate_id(
+            merchant_id,
+            &mandate_id,
+            storage::MandateUpdate::StatusUpdate {
+                mandate_status: storage_enums::MandateStatus::Inactive,
+            },
+            mandate,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::MandateUpdateFailed)
+        .attach_printable_lazy(|| {
+            format!("Failed while inactivating pending mandate: mandate_id: {mandate_id}")
+        })?;
+        mandate_ids.push(mandate_id);
+    }
+
+    logger::info!(
+        mandates_inactivated = mandate_ids.len(),
+        "Swept stale pending mandates"
+    );
+
+    Ok(mandates::PendingMandateSweepResponse {
+        mandate_ids,
+        has_more,
+    })
+}
+
 impl ForeignFrom<Result<types::PaymentsResponseData, types::ErrorResponse>>
     for Option<types::MandateReference>
 {
@@ -448,3 +1530,1576 @@ pub trait MandateBehaviour {
     ) -> Option<&hyperswitch_domain_models::mandates::MandateData>;
     fn get_customer_acceptance(&self) -> Option<common_payments_types::CustomerAcceptance>;
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use masking::PeekInterface;
+
+    use super::*;
+
+    fn monthly_mandate_last_charged_at(
+        last_charged_at: time::PrimitiveDateTime,
+    ) -> storage::Mandate {
+        storage::Mandate::from(&storage::MandateNew {
+            mandate_type: storage_enums::MandateType::MultiUse,
+            frequency: Some(storage_enums::MandateFrequency::Monthly),
+            last_charged_at: Some(last_charged_at),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_mandate_charged_within_interval_is_rejected() {
+        let now = common_utils::date_time::now();
+        let mandate = monthly_mandate_last_charged_at(now - time::Duration::days(1));
+
+        let result = validate_mandate_frequency(&mandate, now, time::Duration::hours(1), false);
+
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::MandateValidationFailed { .. }
+        ));
+    }
+
+    #[test]
+    fn test_mandate_charged_after_interval_is_accepted() {
+        let now = common_utils::date_time::now();
+        let mandate = monthly_mandate_last_charged_at(now - time::Duration::days(28));
+        assert!(validate_mandate_frequency(&mandate, now, time::Duration::hours(1), false).is_ok());
+
+        // Charged slightly before the interval has elapsed, but within the tolerance
+        let mandate = monthly_mandate_last_charged_at(
+            now - time::Duration::days(28) + time::Duration::minutes(30),
+        );
+        assert!(validate_mandate_frequency(&mandate, now, time::Duration::hours(1), false).is_ok());
+    }
+
+    #[test]
+    fn test_mandate_frequency_check_is_bypassed_by_override() {
+        let now = common_utils::date_time::now();
+        let mandate = monthly_mandate_last_charged_at(now - time::Duration::days(1));
+
+        assert!(validate_mandate_frequency(&mandate, now, time::Duration::hours(1), true).is_ok());
+    }
+
+    #[test]
+    fn test_mandate_without_frequency_or_previous_charge_is_accepted() {
+        let now = common_utils::date_time::now();
+        let mandate = storage::Mandate::from(&storage::MandateNew {
+            mandate_type: storage_enums::MandateType::MultiUse,
+            last_charged_at: Some(now),
+            ..Default::default()
+        });
+        assert!(validate_mandate_frequency(&mandate, now, time::Duration::ZERO, false).is_ok());
+
+        let mandate = storage::Mandate::from(&storage::MandateNew {
+            mandate_type: storage_enums::MandateType::MultiUse,
+            frequency: Some(storage_enums::MandateFrequency::Daily),
+            ..Default::default()
+        });
+        assert!(validate_mandate_frequency(&mandate, now, time::Duration::ZERO, false).is_ok());
+    }
+
+    #[test]
+    fn test_recurring_payment_right_after_mandate_setup_is_rejected() {
+        let setup_mandate_details = hyperswitch_domain_models::mandates::MandateData {
+            customer_acceptance: Some(common_payments_types::CustomerAcceptance {
+                acceptance_type: common_payments_types::AcceptanceType::Offline,
+                accepted_at: None,
+                online: None,
+            }),
+            mandate_type: Some(
+                hyperswitch_domain_models::mandates::MandateDataType::MultiUse(Some(
+                    hyperswitch_domain_models::mandates::MandateAmountData {
+                        amount: common_utils::types::MinorUnit::new(1000),
+                        currency: storage_enums::Currency::USD,
+                        start_date: None,
+                        end_date: None,
+                        metadata: Some(common_utils::pii::SecretSerdeValue::new(
+                            serde_json::json!({ "frequency": "monthly" }),
+                        )),
+                    },
+                )),
+            ),
+            ..Default::default()
+        };
+        let mandate_new = payment_helper::generate_mandate(
+            crate::test_utils::merchant_id(),
+            id_type::PaymentId::try_from(std::borrow::Cow::Borrowed("pay_mandate_setup")).unwrap(),
+            "stripe".to_string(),
+            Some(setup_mandate_details),
+            &Some(id_type::CustomerId::default()),
+            "pm_mandate_setup".to_string(),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap()
+        .unwrap();
+        let mandate = storage::Mandate::from(&mandate_new);
+        assert_eq!(
+            mandate.frequency,
+            Some(storage_enums::MandateFrequency::Monthly)
+        );
+
+        let result = validate_mandate_frequency(
+            &mandate,
+            common_utils::date_time::now(),
+            time::Duration::hours(1),
+            false,
+        );
+
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::MandateValidationFailed { .. }
+        ));
+    }
+
+    /// Stores a wallet payment method of [`crate::test_utils::merchant_id`], whose mandates are
+    /// expanded without fetching the card from the locker
+    #[cfg(feature = "v1")]
+    async fn insert_wallet_payment_method(
+        state: &SessionState,
+        key_store: &domain::MerchantKeyStore,
+        payment_method_id: &str,
+    ) {
+        let now = common_utils::date_time::now();
+        state
+            .store
+            .insert_payment_method(
+                &state.into(),
+                key_store,
+                domain::PaymentMethod {
+                    customer_id: id_type::CustomerId::default(),
+                    merchant_id: crate::test_utils::merchant_id(),
+                    payment_method_id: payment_method_id.to_string(),
+                    accepted_currency: None,
+                    scheme: None,
+                    token: None,
+                    cardholder_name: None,
+                    issuer_name: None,
+                    issuer_country: None,
+                    payer_country: None,
+                    is_stored: None,
+                    swift_code: None,
+                    direct_debit_token: None,
+                    created_at: now,
+                    last_modified: now,
+                    payment_method: Some(storage_enums::PaymentMethod::Wallet),
+                    payment_method_type: Some(storage_enums::PaymentMethodType::ApplePay),
+                    payment_method_issuer: None,
+                    payment_method_issuer_code: None,
+                    metadata: None,
+                    payment_method_data: None,
+                    locker_id: None,
+                    last_used_at: now,
+                    connector_mandate_details: None,
+                    customer_acceptance: None,
+                    status: storage_enums::PaymentMethodStatus::Active,
+                    network_transaction_id: None,
+                    client_secret: None,
+                    payment_method_billing_address: None,
+                    updated_by: None,
+                    version: common_types::consts::API_VERSION,
+                    network_token_requestor_reference_id: None,
+                    network_token_locker_id: None,
+                    network_token_payment_method_data: None,
+                },
+                MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+    }
+
+    /// Expands the mandate of a payments response referencing the mandate id
+    #[cfg(feature = "v1")]
+    async fn expand_mandate_of_payment(
+        state: &SessionState,
+        merchant_context: &domain::MerchantContext,
+        mandate_id: &str,
+    ) -> payments::PaymentsResponse {
+        let response = Ok(services::ApplicationResponse::Json(
+            payments::PaymentsResponse {
+                mandate_id: Some(mandate_id.to_string()),
+                ..Default::default()
+            },
+        ));
+
+        match expand_mandate_in_payments_response(state, merchant_context, Some(true), response)
+            .await
+            .unwrap()
+        {
+            services::ApplicationResponse::Json(payments_response) => Some(payments_response),
+            _ => None,
+        }
+        .expect("Expected a JSON payments response")
+    }
+
+    #[cfg(feature = "v1")]
+    #[tokio::test]
+    async fn test_created_mandate_is_expanded() {
+        let state = crate::test_utils::mock_session_state(Default::default()).await;
+        let key_store = crate::test_utils::merchant_key_store(&state).await;
+        insert_wallet_payment_method(&state, &key_store, "pm_created").await;
+        let merchant_context = crate::test_utils::merchant_context(key_store);
+        state
+            .store
+            .insert_mandate(
+                storage::MandateNew {
+                    mandate_id: "man_created".to_string(),
+                    merchant_id: crate::test_utils::merchant_id(),
+                    payment_method_id: "pm_created".to_string(),
+                    mandate_status: storage_enums::MandateStatus::Active,
+                    connector: "stripe".to_string(),
+                    ..Default::default()
+                },
+                MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+
+        let payments_response =
+            expand_mandate_of_payment(&state, &merchant_context, "man_created").await;
+
+        let mandate = payments_response.mandate.unwrap();
+        assert_eq!(mandate.mandate_id, "man_created");
+        assert_eq!(mandate.payment_method_id, "pm_created");
+        assert_eq!(mandate.status, storage_enums::MandateStatus::Active);
+        assert_eq!(payments_response.mandate_expansion_pending, None);
+    }
+
+    #[cfg(feature = "v1")]
+    #[tokio::test]
+    async fn test_reused_mandate_is_expanded() {
+        let state = crate::test_utils::mock_session_state(Default::default()).await;
+        let key_store = crate::test_utils::merchant_key_store(&state).await;
+        insert_wallet_payment_method(&state, &key_store, "pm_reused").await;
+        let merchant_context = crate::test_utils::merchant_context(key_store);
+        // A multi use mandate charged by an earlier payment
+        state
+            .store
+            .insert_mandate(
+                storage::MandateNew {
+                    mandate_id: "man_reused".to_string(),
+                    merchant_id: crate::test_utils::merchant_id(),
+                    payment_method_id: "pm_reused".to_string(),
+                    mandate_status: storage_enums::MandateStatus::Active,
+                    mandate_type: storage_enums::MandateType::MultiUse,
+                    frequency: Some(storage_enums::MandateFrequency::Monthly),
+                    connector: "stripe".to_string(),
+                    last_charged_at: Some(
+                        common_utils::date_time::now() - time::Duration::days(30),
+                    ),
+                    ..Default::default()
+                },
+                MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+
+        let payments_response =
+            expand_mandate_of_payment(&state, &merchant_context, "man_reused").await;
+
+        let mandate = payments_response.mandate.unwrap();
+        assert_eq!(mandate.mandate_id, "man_reused");
+        assert_eq!(mandate.payment_method_id, "pm_reused");
+        assert_eq!(payments_response.mandate_expansion_pending, None);
+    }
+
+    #[cfg(feature = "v1")]
+    #[tokio::test]
+    async fn test_mandate_insert_race_falls_back_to_id_only() {
+        let state = crate::test_utils::mock_session_state(Default::default()).await;
+        let merchant_context = crate::test_utils::merchant_context(
+            crate::test_utils::merchant_key_store(&state).await,
+        );
+
+        // The payment attempt references the mandate before the mandate insert has completed
+        let payments_response =
+            expand_mandate_of_payment(&state, &merchant_context, "man_not_yet_created").await;
+
+        assert_eq!(
+            payments_response.mandate_id.as_deref(),
+            Some("man_not_yet_created")
+        );
+        assert!(payments_response.mandate.is_none());
+        assert_eq!(payments_response.mandate_expansion_pending, Some(true));
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_mandate_not_yet_created_falls_back_to_id_only() {
+        let lookup_result = Err(report!(errors::StorageError::ValueNotFound(
+            "mandate".to_string()
+        )));
+        assert!(get_mandate_if_created(lookup_result).unwrap().is_none());
+
+        let lookup_result = Err(report!(errors::StorageError::DatabaseConnectionError));
+        assert!(matches!(
+            get_mandate_if_created(lookup_result)
+                .unwrap_err()
+                .current_context(),
+            errors::ApiErrorResponse::InternalServerError
+        ));
+    }
+
+    #[test]
+    fn test_mandate_list_rejects_inconsistent_time_ranges() {
+        let now = common_utils::date_time::now();
+        let earlier = now - time::Duration::days(1);
+
+        let constraints = api_models::mandates::MandateListConstraints {
+            created_time_gte: Some(now),
+            created_time_lte: Some(earlier),
+            ..Default::default()
+        };
+        assert!(matches!(
+            validate_mandate_list_constraints(&constraints)
+                .unwrap_err()
+                .current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { .. }
+        ));
+
+        let constraints = api_models::mandates::MandateListConstraints {
+            created_time_gt: Some(now),
+            created_time_lte: Some(now),
+            ..Default::default()
+        };
+        assert!(validate_mandate_list_constraints(&constraints).is_err());
+
+        let constraints = api_models::mandates::MandateListConstraints {
+            modified_time_gte: Some(now),
+            modified_time_lte: Some(earlier),
+            ..Default::default()
+        };
+        assert!(validate_mandate_list_constraints(&constraints).is_err());
+
+        let constraints = api_models::mandates::MandateListConstraints {
+            created_time_gte: Some(earlier),
+            created_time_lte: Some(now),
+            modified_time_gte: Some(now),
+            modified_time_lte: Some(now),
+            ..Default::default()
+        };
+        assert!(validate_mandate_list_constraints(&constraints).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_mandate_list_filters_by_status_and_time_range_with_sort_order() {
+        let mockdb = crate::db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create mock DB");
+        let db: &dyn StorageInterface = &mockdb;
+        let merchant_id = id_type::MerchantId::default();
+        let now = common_utils::date_time::now();
+
+        for (mandate_id, mandate_status, created_days_ago, modified_days_ago) in [
+            (
+                "man_revoked_old",
+                storage_enums::MandateStatus::Revoked,
+                30,
+                20,
+            ),
+            (
+                "man_revoked_recent",
+                storage_enums::MandateStatus::Revoked,
+                25,
+                2,
+            ),
+            (
+                "man_revoked_latest",
+                storage_enums::MandateStatus::Revoked,
+                10,
+                1,
+            ),
+            (
+                "man_active_recent",
+                storage_enums::MandateStatus::Active,
+                5,
+                1,
+            ),
+        ] {
+            db.insert_mandate(
+                storage::MandateNew {
+                    mandate_id: mandate_id.to_string(),
+                    merchant_id: merchant_id.clone(),
+                    mandate_status,
+                    created_at: Some(now - time::Duration::days(created_days_ago)),
+                    modified_at: Some(now - time::Duration::days(modified_days_ago)),
+                    ..Default::default()
+                },
+                MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+        }
+
+        let merchant_id = &merchant_id;
+        let list_mandate_ids = |constraints| async move {
+            db.find_mandates_by_merchant_id(merchant_id, constraints)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|mandate| mandate.mandate_id)
+                .collect::<Vec<_>>()
+        };
+
+        let recently_revoked = api_models::mandates::MandateListConstraints {
+            mandate_status: Some(storage_enums::MandateStatus::Revoked),
+            modified_time_gte: Some(now - time::Duration::days(7)),
+            modified_time_lte: Some(now),
+            sort_by: Some(api_models::mandates::MandateSortBy::ModifiedAt),
+            ..Default::default()
+        };
+        assert_eq!(
+            list_mandate_ids(recently_revoked.clone()).await,
+            vec!["man_revoked_latest", "man_revoked_recent"]
+        );
+
+        let recently_revoked_ascending = api_models::mandates::MandateListConstraints {
+            sort_order: Some(payments::SortBy::Asc),
+            ..recently_revoked
+        };
+        assert_eq!(
+            list_mandate_ids(recently_revoked_ascending).await,
+            vec!["man_revoked_recent", "man_revoked_latest"]
+        );
+
+        let revoked_by_creation_ascending = api_models::mandates::MandateListConstraints {
+            mandate_status: Some(storage_enums::MandateStatus::Revoked),
+            created_time_gte: Some(now - time::Duration::days(28)),
+            sort_order: Some(payments::SortBy::Asc),
+            ..Default::default()
+        };
+        assert_eq!(
+            list_mandate_ids(revoked_by_creation_ascending).await,
+            vec!["man_revoked_recent", "man_revoked_latest"]
+        );
+
+        let default_order = api_models::mandates::MandateListConstraints {
+            mandate_status: Some(storage_enums::MandateStatus::Revoked),
+            ..Default::default()
+        };
+        assert_eq!(
+            list_mandate_ids(default_order).await,
+            vec![
+                "man_revoked_latest",
+                "man_revoked_recent",
+                "man_revoked_old"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_customer_mandate_list_filters_and_paginates() {
+        let mockdb = crate::db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create mock DB");
+        let db: &dyn StorageInterface = &mockdb;
+        let merchant_id = id_type::MerchantId::default();
+        let customer_id =
+            id_type::CustomerId::try_from(std::borrow::Cow::Borrowed("cus_1")).unwrap();
+        let other_customer_id =
+            id_type::CustomerId::try_from(std::borrow::Cow::Borrowed("cus_2")).unwrap();
+        let now = common_utils::date_time::now();
+
+        for (mandate_id, customer_id, connector, mandate_status, created_days_ago) in [
+            (
+                "man_stripe_active",
+                &customer_id,
+                "stripe",
+                storage_enums::MandateStatus::Active,
+                1,
+            ),
+            (
+                "man_stripe_revoked",
+                &customer_id,
+                "stripe",
+                storage_enums::MandateStatus::Revoked,
+                2,
+            ),
+            (
+                "man_adyen_active",
+                &customer_id,
+                "adyen",
+                storage_enums::MandateStatus::Active,
+                3,
+            ),
+            (
+                "man_other_customer",
+                &other_customer_id,
+                "stripe",
+                storage_enums::MandateStatus::Active,
+                1,
+            ),
+        ] {
+            db.insert_mandate(
+                storage::MandateNew {
+                    mandate_id: mandate_id.to_string(),
+                    merchant_id: merchant_id.clone(),
+                    customer_id: customer_id.clone(),
+                    connector: connector.to_string(),
+                    mandate_status,
+                    created_at: Some(now - time::Duration::days(created_days_ago)),
+                    ..Default::default()
+                },
+                MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+        }
+
+        let (merchant_id, customer_id) = (&merchant_id, &customer_id);
+        let list_mandate_ids = |constraints| async move {
+            db.find_mandates_by_merchant_id_customer_id(merchant_id, customer_id, constraints)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|mandate| mandate.mandate_id)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            list_mandate_ids(Default::default()).await,
+            vec![
+                "man_stripe_active",
+                "man_stripe_revoked",
+                "man_adyen_active"
+            ]
+        );
+
+        let active_stripe = api_models::mandates::MandateListConstraints {
+            mandate_status: Some(storage_enums::MandateStatus::Active),
+            connector: Some("stripe".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            list_mandate_ids(active_stripe).await,
+            vec!["man_stripe_active"]
+        );
+
+        let second_page = api_models::mandates::MandateListConstraints {
+            created_time_gte: Some(now - time::Duration::days(7)),
+            limit: Some(2),
+            offset: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(
+            list_mandate_ids(second_page).await,
+            vec!["man_stripe_revoked", "man_adyen_active"]
+        );
+
+        let no_matches = api_models::mandates::MandateListConstraints {
+            connector: Some("cybersource".to_string()),
+            ..Default::default()
+        };
+        assert!(list_mandate_ids(no_matches).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_active_mandate_finders_over_large_fixture() {
+        let mockdb = crate::db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create mock DB");
+        let db: &dyn StorageInterface = &mockdb;
+        let merchant_id = id_type::MerchantId::default();
+        let other_merchant_id =
+            id_type::MerchantId::try_from(std::borrow::Cow::from("other_merchant")).unwrap();
+        let customer_ids = ["cus_even", "cus_odd"]
+            .map(|customer_id| id_type::CustomerId::try_from(std::borrow::Cow::from(customer_id)))
+            .map(Result::unwrap);
+        let now = common_utils::date_time::now();
+
+        // Mandates are inserted newest first so that the finder has to order them itself
+        for index in (0..300_i64).rev() {
+            let mandate_status = match index % 3 {
+                0 => storage_enums::MandateStatus::Revoked,
+                _ => storage_enums::MandateStatus::Active,
+            };
+            let index_usize = usize::try_from(index).unwrap();
+            db.insert_mandate(
+                storage::MandateNew {
+                    mandate_id: format!("man_{index:03}"),
+                    merchant_id: if index % 10 == 9 {
+                        other_merchant_id.clone()
+                    } else {
+                        merchant_id.clone()
+                    },
+                    customer_id: customer_ids[index_usize % 2].clone(),
+                    mandate_status,
+                    created_at: Some(now - time::Duration::hours(300 - index)),
+                    ..Default::default()
+                },
+                MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+        }
+
+        let is_expected = |index: &i64| index % 3 != 0 && index % 10 != 9;
+
+        let created_before = now - time::Duration::hours(100);
+        let oldest_active = db
+            .find_active_mandates_by_merchant_created_before(&merchant_id, created_before, 50)
+            .await
+            .unwrap();
+        let expected = (0..300_i64)
+            .filter(is_expected)
+            .take(50)
+            .map(|index| format!("man_{index:03}"))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            oldest_active
+                .iter()
+                .map(|mandate| mandate.mandate_id.clone())
+                .collect::<Vec<_>>(),
+            expected
+        );
+        assert!(oldest_active
+            .windows(2)
+            .all(|pair| pair[0].created_at <= pair[1].created_at));
+
+        let all_active_before = db
+            .find_active_mandates_by_merchant_created_before(&merchant_id, created_before, 1000)
+            .await
+            .unwrap();
+        let expected_count = (0..200_i64).filter(is_expected).count();
+        assert_eq!(all_active_before.len(), expected_count);
+        assert!(all_active_before
+            .iter()
+            .all(|mandate| mandate.created_at < created_before));
+
+        for (parity, customer_id) in customer_ids.iter().enumerate() {
+            let parity = i64::try_from(parity).unwrap();
+            let expected_count = (0..300_i64)
+                .filter(|index| is_expected(index) && index % 2 == parity)
+                .count();
+            assert_eq!(
+                db.count_active_mandates_by_customer(&merchant_id, customer_id)
+                    .await
+                    .unwrap(),
+                i64::try_from(expected_count).unwrap()
+            );
+        }
+    }
+
+    #[cfg(feature = "v1")]
+    fn mandate_with_status(
+        connector: &str,
+        mandate_status: storage_enums::MandateStatus,
+        connector_mandate_id: Option<&str>,
+    ) -> storage::Mandate {
+        storage::Mandate::from(&storage::MandateNew {
+            connector: connector.to_string(),
+            mandate_status,
+            connector_mandate_id: connector_mandate_id.map(str::to_string),
+            ..Default::default()
+        })
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_revoked_mandate_is_ineligible_for_revocation() {
+        let mandate = mandate_with_status(
+            "cybersource",
+            storage_enums::MandateStatus::Revoked,
+            Some("connector_mandate_1"),
+        );
+
+        assert_eq!(
+            get_mandate_revocation_verdict(&mandate),
+            (
+                mandates::MandateRevocationVerdict::Ineligible,
+                Some(mandates::MandateRevocationReason::AlreadyRevoked)
+            )
+        );
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_mandate_without_connector_reference_is_ineligible_for_revocation() {
+        let mandate =
+            mandate_with_status("cybersource", storage_enums::MandateStatus::Active, None);
+
+        assert_eq!(
+            get_mandate_revocation_verdict(&mandate),
+            (
+                mandates::MandateRevocationVerdict::Ineligible,
+                Some(mandates::MandateRevocationReason::MissingConnectorMandateReference)
+            )
+        );
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_mandate_of_connector_without_revoke_support_is_eligible_locally() {
+        for connector_mandate_id in [None, Some("connector_mandate_1")] {
+            let mandate = mandate_with_status(
+                "stripe",
+                storage_enums::MandateStatus::Active,
+                connector_mandate_id,
+            );
+
+            assert_eq!(
+                get_mandate_revocation_verdict(&mandate),
+                (
+                    mandates::MandateRevocationVerdict::EligibleLocalOnly,
+                    Some(mandates::MandateRevocationReason::ConnectorRevokeNotSupported)
+                )
+            );
+        }
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_mandate_of_connector_with_revoke_support_is_eligible() {
+        for mandate_status in [
+            storage_enums::MandateStatus::Active,
+            storage_enums::MandateStatus::Inactive,
+            storage_enums::MandateStatus::Pending,
+        ] {
+            let mandate =
+                mandate_with_status("cybersource", mandate_status, Some("connector_mandate_1"));
+
+            assert_eq!(
+                get_mandate_revocation_verdict(&mandate),
+                (mandates::MandateRevocationVerdict::Eligible, None)
+            );
+        }
+    }
+
+    #[cfg(feature = "v1")]
+    fn connector_revocation_failure() -> errors::RouterResult<()> {
+        Err(report!(errors::ApiErrorResponse::ExternalConnectorError {
+            code: "resource_missing".to_string(),
+            message: "No such mandate".to_string(),
+            connector: "stripe".to_string(),
+            status_code: 404,
+            reason: None,
+        }))
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_connector_revocation_success_is_not_recorded_as_forced() {
+        assert_eq!(
+            get_forced_revocation_connector_error(Ok(()), false).unwrap(),
+            None
+        );
+        assert_eq!(
+            get_forced_revocation_connector_error(Ok(()), true).unwrap(),
+            None
+        );
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_connector_revocation_failure_without_force_fails_the_revocation() {
+        let result = get_forced_revocation_connector_error(connector_revocation_failure(), false);
+
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::ExternalConnectorError { .. }
+        ));
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_connector_revocation_failure_with_force_returns_connector_error() {
+        let result = get_forced_revocation_connector_error(connector_revocation_failure(), true);
+
+        assert_eq!(
+            result.unwrap(),
+            Some((
+                "resource_missing".to_string(),
+                "No such mandate".to_string()
+            ))
+        );
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_forced_revocation_metadata_keeps_existing_keys() {
+        let metadata = get_forced_revocation_metadata(
+            Some(pii::SecretSerdeValue::new(
+                serde_json::json!({ "contract_id": "CON_1234" }),
+            )),
+            "stripe",
+            "resource_missing",
+            "No such mandate",
+        );
+
+        assert_eq!(
+            metadata.peek(),
+            &serde_json::json!({
+                "contract_id": "CON_1234",
+                (crate::consts::FORCED_MANDATE_REVOCATION_METADATA_KEY): {
+                    "connector": "stripe",
+                    "error_code": "resource_missing",
+                    "error_message": "No such mandate",
+                },
+            })
+        );
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_mandate_revocation_error_details_prefer_connector_error() {
+        let connector_error = errors::ApiErrorResponse::ExternalConnectorError {
+            code: "mandate_not_found".to_string(),
+            message: "No such mandate".to_string(),
+            connector: "stripe".to_string(),
+            status_code: 404,
+            reason: None,
+        };
+        assert_eq!(
+            get_mandate_revocation_error_details(&connector_error),
+            (
+                "mandate_not_found".to_string(),
+                "No such mandate".to_string()
+            )
+        );
+
+        let validation_error = errors::ApiErrorResponse::MandateValidationFailed {
+            reason: "Mandate does not have a connector mandate reference".to_string(),
+        };
+        assert_eq!(
+            get_mandate_revocation_error_details(&validation_error),
+            (
+                validation_error.error_code(),
+                validation_error.error_message()
+            )
+        );
+    }
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    fn payment_id(payment_id: &'static str) -> id_type::PaymentId {
+        id_type::PaymentId::try_from(std::borrow::Cow::Borrowed(payment_id)).unwrap()
+    }
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    fn mandate_new(
+        mandate_id: &str,
+        original_payment_id: Option<&'static str>,
+    ) -> storage::MandateNew {
+        storage::MandateNew {
+            mandate_id: mandate_id.to_string(),
+            merchant_id: id_type::MerchantId::default(),
+            payment_method_id: "pm_1".to_string(),
+            mandate_status: storage_enums::MandateStatus::Active,
+            connector: "stripe".to_string(),
+            original_payment_id: original_payment_id.map(payment_id),
+            ..Default::default()
+        }
+    }
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    fn payment_attempt_new(
+        payment_id: id_type::PaymentId,
+        mandate_id: &str,
+        connector_mandate_id: Option<&str>,
+    ) -> storage::PaymentAttemptNew {
+        let attempt_id = format!("{}_1", payment_id.get_string_repr());
+        storage::PaymentAttemptNew {
+            payment_id,
+            merchant_id: id_type::MerchantId::default(),
+            mandate_id: Some(mandate_id.to_string()),
+            connector_mandate_detail: connector_mandate_id.map(|connector_mandate_id| {
+                diesel_models::ConnectorMandateReferenceId {
+                    connector_mandate_id: Some(connector_mandate_id.to_string()),
+                    payment_method_id: Some("pm_1".to_string()),
+                    mandate_metadata: None,
+                    connector_mandate_request_reference_id: None,
+                }
+            }),
+            ..crate::test_utils::payment_attempt_new(&attempt_id)
+        }
+    }
+
+    /// Seeds one mandate per backfill outcome, `man_1` being the only resolvable one
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn mock_db_with_mandates_to_backfill() -> crate::db::MockDb {
+        let mockdb = crate::db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create a mock DB");
+        let db: &dyn StorageInterface = &mockdb;
+        let storage_scheme = MerchantStorageScheme::PostgresOnly;
+
+        for (mandate_id, original_payment_id) in [
+            ("man_1", Some("pay_1")),
+            ("man_2", Some("pay_2")),
+            ("man_3", None),
+            ("man_4", Some("pay_4")),
+        ] {
+            db.insert_mandate(mandate_new(mandate_id, original_payment_id), storage_scheme)
+                .await
+                .unwrap();
+        }
+        for (original_payment_id, mandate_id, connector_mandate_id) in [
+            ("pay_1", "man_1", Some("connector_mandate_1")),
+            ("pay_2", "man_2", None),
+            ("pay_4", "man_other", Some("connector_mandate_4")),
+        ] {
+            db.insert_payment_attempt(
+                payment_attempt_new(
+                    payment_id(original_payment_id),
+                    mandate_id,
+                    connector_mandate_id,
+                ),
+                storage_scheme,
+            )
+            .await
+            .unwrap();
+        }
+        mockdb
+    }
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    fn backfill_statuses(
+        response: &mandates::MandateConnectorReferenceBackfillResponse,
+    ) -> Vec<(&str, mandates::MandateConnectorReferenceBackfillStatus)> {
+        response
+            .outcomes
+            .iter()
+            .map(|outcome| (outcome.mandate_id.as_str(), outcome.status))
+            .collect()
+    }
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    #[tokio::test]
+    async fn test_backfill_resolves_connector_mandate_id_from_original_payment() {
+        let mockdb = mock_db_with_mandates_to_backfill().await;
+        let db: &dyn StorageInterface = &mockdb;
+        let merchant_id = id_type::MerchantId::default();
+        let storage_scheme = MerchantStorageScheme::PostgresOnly;
+
+        let response = backfill_mandates_connector_mandate_id_batch(
+            db,
+            &merchant_id,
+            storage_scheme,
+            None,
+            10,
+            false,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(response.mandates_processed, 4);
+        assert_eq!(response.mandates_updated, 1);
+        assert_eq!(response.next_starting_after, None);
+        assert_eq!(
+            backfill_statuses(&response),
+            vec![
+                (
+                    "man_1",
+                    mandates::MandateConnectorReferenceBackfillStatus::Updated
+                ),
+                (
+                    "man_2",
+                    mandates::MandateConnectorReferenceBackfillStatus::MandateReferenceNotFound
+                ),
+                (
+                    "man_3",
+                    mandates::MandateConnectorReferenceBackfillStatus::OriginalPaymentMissing
+                ),
+                (
+                    "man_4",
+                    mandates::MandateConnectorReferenceBackfillStatus::PaymentAttemptNotFound
+                ),
+            ]
+        );
+
+        let mandate = db
+            .find_mandate_by_merchant_id_mandate_id(&merchant_id, "man_1", storage_scheme)
+            .await
+            .unwrap();
+        assert_eq!(
+            mandate.connector_mandate_id.as_deref(),
+            Some("connector_mandate_1")
+        );
+        assert_eq!(mandate.original_payment_id, Some(payment_id("pay_1")));
+        let connector_mandate_ids = mandate.connector_mandate_ids.unwrap();
+        assert_eq!(
+            masking::PeekInterface::peek(&connector_mandate_ids)["connector_mandate_id"],
+            "connector_mandate_1"
+        );
+
+        // Backfilled mandates are not picked up again
+        let response = backfill_mandates_connector_mandate_id_batch(
+            db,
+            &merchant_id,
+            storage_scheme,
+            None,
+            10,
+            false,
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.mandates_processed, 3);
+        assert_eq!(response.mandates_updated, 0);
+    }
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    #[tokio::test]
+    async fn test_backfill_dry_run_does_not_update_mandates() {
+        let mockdb = mock_db_with_mandates_to_backfill().await;
+        let db: &dyn StorageInterface = &mockdb;
+        let merchant_id = id_type::MerchantId::default();
+        let storage_scheme = MerchantStorageScheme::PostgresOnly;
+
+        let response = backfill_mandates_connector_mandate_id_batch(
+            db,
+            &merchant_id,
+            storage_scheme,
+            None,
+            10,
+            true,
+        )
+        .await
+        .unwrap();
+
+        assert!(response.dry_run);
+        assert_eq!(response.mandates_updated, 0);
+        assert_eq!(
+            backfill_statuses(&response).first(),
+            Some(&(
+                "man_1",
+                mandates::MandateConnectorReferenceBackfillStatus::WouldUpdate
+            ))
+        );
+
+        let mandate = db
+            .find_mandate_by_merchant_id_mandate_id(&merchant_id, "man_1", storage_scheme)
+            .await
+            .unwrap();
+        assert_eq!(mandate.connector_mandate_id, None);
+        assert_eq!(mandate.connector_mandate_ids, None);
+    }
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    #[tokio::test]
+    async fn test_backfill_resumes_from_next_starting_after() {
+        let mockdb = mock_db_with_mandates_to_backfill().await;
+        let db: &dyn StorageInterface = &mockdb;
+        let merchant_id = id_type::MerchantId::default();
+        let storage_scheme = MerchantStorageScheme::PostgresOnly;
+
+        let first_batch = backfill_mandates_connector_mandate_id_batch(
+            db,
+            &merchant_id,
+            storage_scheme,
+            None,
+            3,
+            false,
+        )
+        .await
+        .unwrap();
+        assert_eq!(first_batch.mandates_processed, 3);
+        assert_eq!(first_batch.next_starting_after.as_deref(), Some("man_3"));
+
+        let second_batch = backfill_mandates_connector_mandate_id_batch(
+            db,
+            &merchant_id,
+            storage_scheme,
+            first_batch.next_starting_after.as_deref(),
+            3,
+            false,
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            backfill_statuses(&second_batch),
+            vec![(
+                "man_4",
+                mandates::MandateConnectorReferenceBackfillStatus::PaymentAttemptNotFound
+            )]
+        );
+        assert_eq!(second_batch.next_starting_after, None);
+    }
+
+    #[test]
+    fn test_mandate_is_pending_only_on_async_reference_without_connector_mandate_id() {
+        let mandate_reference = |connector_mandate_id: Option<&str>| types::MandateReference {
+            connector_mandate_id: connector_mandate_id.map(str::to_string),
+            payment_method_id: None,
+            mandate_metadata: None,
+            connector_mandate_request_reference_id: None,
+        };
+
+        assert!(is_mandate_reference_pending(None, true));
+        assert!(is_mandate_reference_pending(
+            Some(&mandate_reference(None)),
+            true
+        ));
+        assert!(!is_mandate_reference_pending(
+            Some(&mandate_reference(Some("connector_mandate_1"))),
+            true
+        ));
+        assert!(!is_mandate_reference_pending(None, false));
+    }
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    #[tokio::test]
+    async fn test_webhook_mandate_reference_activates_pending_mandate() {
+        let mockdb = crate::db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create a mock DB");
+        let db: &dyn StorageInterface = &mockdb;
+        let merchant_id = id_type::MerchantId::default();
+        let storage_scheme = MerchantStorageScheme::PostgresOnly;
+        db.insert_mandate(
+            storage::MandateNew {
+                mandate_status: storage_enums::MandateStatus::Pending,
+                ..mandate_new("man_1", Some("pay_1"))
+            },
+            storage_scheme,
+        )
+        .await
+        .unwrap();
+
+        let no_pending_mandate = activate_pending_mandate(
+            db,
+            &merchant_id,
+            &payment_id("pay_2"),
+            "connector_mandate_1".to_string(),
+            storage_scheme,
+        )
+        .await
+        .unwrap();
+        assert!(no_pending_mandate.is_none());
+
+        let activated_mandate = activate_pending_mandate(
+            db,
+            &merchant_id,
+            &payment_id("pay_1"),
+            "connector_mandate_1".to_string(),
+            storage_scheme,
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        assert_eq!(activated_mandate.mandate_id, "man_1");
+        assert_eq!(
+            activated_mandate.mandate_status,
+            storage_enums::MandateStatus::Active
+        );
+        assert_eq!(
+            activated_mandate.connector_mandate_id.as_deref(),
+            Some("connector_mandate_1")
+        );
+
+        let connector_mandate_ids = activated_mandate.connector_mandate_ids.unwrap();
+        assert_eq!(
+            connector_mandate_ids.peek()["connector_mandate_id"],
+            "connector_mandate_1"
+        );
+        assert_eq!(connector_mandate_ids.peek()["payment_method_id"], "pm_1");
+    }
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    #[tokio::test]
+    async fn test_sweep_inactivates_only_stale_pending_mandates() {
+        let mockdb = crate::db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create a mock DB");
+        let db: &dyn StorageInterface = &mockdb;
+        let merchant_id = id_type::MerchantId::default();
+        let storage_scheme = MerchantStorageScheme::PostgresOnly;
+        let now = common_utils::date_time::now();
+        let created_before = now - time::Duration::hours(24);
+
+        for (mandate_id, mandate_status, created_at) in [
+            (
+                "man_1",
+                storage_enums::MandateStatus::Pending,
+                now - time::Duration::hours(48),
+            ),
+            (
+                "man_2",
+                storage_enums::MandateStatus::Pending,
+                now - time::Duration::hours(1),
+            ),
+            (
+                "man_3",
+                storage_enums::MandateStatus::Active,
+                now - time::Duration::hours(48),
+            ),
+        ] {
+            db.insert_mandate(
+                storage::MandateNew {
+                    mandate_status,
+                    created_at: Some(created_at),
+                    ..mandate_new(mandate_id, None)
+                },
+                storage_scheme,
+            )
+            .await
+            .unwrap();
+        }
+
+        let response = sweep_stale_pending_mandates_batch(
+            db,
+            &merchant_id,
+            storage_scheme,
+            created_before,
+            100,
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.mandate_ids, vec!["man_1".to_string()]);
+        assert!(!response.has_more);
+
+        for (mandate_id, mandate_status) in [
+            ("man_1", storage_enums::MandateStatus::Inactive),
+            ("man_2", storage_enums::MandateStatus::Pending),
+            ("man_3", storage_enums::MandateStatus::Active),
+        ] {
+            let mandate = db
+                .find_mandate_by_merchant_id_mandate_id(&merchant_id, mandate_id, storage_scheme)
+                .await
+                .unwrap();
+            assert_eq!(mandate.mandate_status, mandate_status);
+        }
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_merge_mandate_metadata_replaces_and_removes_keys() {
+        let existing_metadata = pii::SecretSerdeValue::new(serde_json::json!({
+            "order_ref": "ord_1",
+            "plan": "basic",
+            "region": "eu",
+        }));
+        let metadata = pii::SecretSerdeValue::new(serde_json::json!({
+            "plan": "premium",
+            "region": null,
+            "customer_ref": "cus_1",
+        }));
+
+        let merged_metadata = merge_mandate_metadata(Some(existing_metadata), metadata);
+
+        assert_eq!(
+            merged_metadata.peek(),
+            &serde_json::json!({
+                "order_ref": "ord_1",
+                "plan": "premium",
+                "customer_ref": "cus_1",
+            })
+        );
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_mandate_metadata_validation() {
+        let metadata = pii::SecretSerdeValue::new(serde_json::json!({ "plan": "premium" }));
+        assert!(helpers::validate_mandate_metadata(&metadata).is_ok());
+
+        let non_object_metadata = pii::SecretSerdeValue::new(serde_json::json!(["plan"]));
+        let too_many_keys_metadata = pii::SecretSerdeValue::new(serde_json::Value::Object(
+            (0..=crate::consts::MAX_MANDATE_METADATA_KEYS)
+                .map(|index| (format!("key_{index}"), serde_json::json!("value")))
+                .collect(),
+        ));
+        let long_key_metadata = pii::SecretSerdeValue::new(serde_json::json!({
+            ("k".repeat(crate::consts::MAX_MANDATE_METADATA_KEY_LENGTH + 1)): "value",
+        }));
+
+        for metadata in [
+            non_object_metadata,
+            too_many_keys_metadata,
+            long_key_metadata,
+        ] {
+            assert!(matches!(
+                helpers::validate_mandate_metadata(&metadata)
+                    .unwrap_err()
+                    .current_context(),
+                errors::ApiErrorResponse::InvalidRequestData { .. }
+            ));
+        }
+    }
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    #[tokio::test]
+    async fn test_update_mandate_metadata_merges_existing_metadata() {
+        let mockdb = crate::db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create a mock DB");
+        let db: &dyn StorageInterface = &mockdb;
+        let storage_scheme = MerchantStorageScheme::PostgresOnly;
+
+        let mandate = db
+            .insert_mandate(
+                storage::MandateNew {
+                    merchant_metadata: Some(pii::SecretSerdeValue::new(serde_json::json!({
+                        "order_ref": "ord_1",
+                        "plan": "basic",
+                    }))),
+                    ..mandate_new("man_1", None)
+                },
+                storage_scheme,
+            )
+            .await
+            .unwrap();
+
+        let mandate = update_mandate_metadata(
+            db,
+            mandate,
+            pii::SecretSerdeValue::new(serde_json::json!({ "plan": "premium" })),
+            storage_scheme,
+        )
+        .await
+        .unwrap();
+
+        let expected_metadata = serde_json::json!({
+            "order_ref": "ord_1",
+            "plan": "premium",
+        });
+        assert_eq!(
+            mandate.merchant_metadata.as_ref().map(PeekInterface::peek),
+            Some(&expected_metadata)
+        );
+
+        let mandate = db
+            .find_mandate_by_merchant_id_mandate_id(
+                &id_type::MerchantId::default(),
+                "man_1",
+                storage_scheme,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            mandate.merchant_metadata.as_ref().map(PeekInterface::peek),
+            Some(&expected_metadata)
+        );
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_mandate_webhook_payload_includes_metadata() {
+        let content = api_models::webhooks::OutgoingWebhookContent::MandateDetails(Box::new(
+            mandates::MandateResponse {
+                metadata: Some(pii::SecretSerdeValue::new(
+                    serde_json::json!({ "plan": "premium" }),
+                )),
+                ..Default::default()
+            },
+        ));
+
+        let payload = serde_json::to_value(&content).unwrap();
+
+        assert_eq!(payload["object"]["metadata"]["plan"], "premium");
+    }
+
+    #[test]
+    fn test_mandate_webhook_payload_includes_customer_and_connector() {
+        let content = api_models::webhooks::OutgoingWebhookContent::MandateDetails(Box::new(
+            mandates::MandateResponse {
+                mandate_id: "man_1".to_string(),
+                status: storage_enums::MandateStatus::Revoked,
+                customer_id: id_type::CustomerId::try_from(std::borrow::Cow::Borrowed("cus_1"))
+                    .unwrap(),
+                connector: "stripe".to_string(),
+                payment_method_id: "pm_1".to_string(),
+                ..Default::default()
+            },
+        ));
+
+        let payload = serde_json::to_value(&content).unwrap();
+
+        assert_eq!(payload["object"]["mandate_id"], "man_1");
+        assert_eq!(payload["object"]["status"], "revoked");
+        assert_eq!(payload["object"]["customer_id"], "cus_1");
+        assert_eq!(payload["object"]["payment_method_id"], "pm_1");
+        assert_eq!(payload["object"]["connector"], "stripe");
+    }
+
+    #[test]
+    fn test_mandate_webhook_event_type_on_creation_and_revocation() {
+        let mandate_with_status = |mandate_status| {
+            storage::Mandate::from(&storage::MandateNew {
+                mandate_status,
+                ..Default::default()
+            })
+        };
+
+        assert_eq!(
+            get_mandate_webhook_event_type(&mandate_with_status(
+                storage_enums::MandateStatus::Active
+            )),
+            Some(api_models::enums::EventType::MandateActive)
+        );
+        assert_eq!(
+            get_mandate_webhook_event_type(&mandate_with_status(
+                storage_enums::MandateStatus::Revoked
+            )),
+            Some(api_models::enums::EventType::MandateRevoked)
+        );
+        // Mandates awaiting the asynchronous mandate reference are notified once activated
+        assert_eq!(
+            get_mandate_webhook_event_type(&mandate_with_status(
+                storage_enums::MandateStatus::Pending
+            )),
+            None
+        );
+    }
+
+    /// A merchant with outgoing webhooks enabled and a mandate `man_webhook`, created by a payment
+    /// in a profile with a webhook URL
+    #[cfg(feature = "v1")]
+    async fn setup_mandate_with_outgoing_webhooks(
+        mandate_new: storage::MandateNew,
+    ) -> (SessionState, domain::MerchantContext, storage::Mandate) {
+        let mut conf = crate::configs::settings::Settings::default();
+        conf.webhooks.outgoing_enabled = true;
+        let state = crate::test_utils::mock_session_state(conf).await;
+        let key_store = crate::test_utils::merchant_key_store(&state).await;
+        let merchant_context = crate::test_utils::merchant_context(key_store.clone());
+        state
+            .store
+            .insert_merchant(
+                &(&state).into(),
+                merchant_context.get_merchant_account().clone(),
+                &key_store,
+            )
+            .await
+            .unwrap();
+
+        let profile_id = id_type::ProfileId::try_from(std::borrow::Cow::from("pro_1")).unwrap();
+        crate::test_utils::insert_profile_with_webhook_url(&state, &key_store, &profile_id).await;
+        let payment_id =
+            id_type::PaymentId::try_from(std::borrow::Cow::Borrowed("pay_webhook")).unwrap();
+        state
+            .store
+            .insert_payment_intent(
+                &(&state).into(),
+                crate::test_utils::payment_intent(payment_id.clone(), &profile_id),
+                &key_store,
+                MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+        insert_wallet_payment_method(&state, &key_store, "pm_webhook").await;
+
+        let mandate = state
+            .store
+            .insert_mandate(
+                storage::MandateNew {
+                    mandate_id: "man_webhook".to_string(),
+                    merchant_id: crate::test_utils::merchant_id(),
+                    payment_method_id: "pm_webhook".to_string(),
+                    connector: "stripe".to_string(),
+                    original_payment_id: Some(payment_id),
+                    ..mandate_new
+                },
+                MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+
+        (state, merchant_context, mandate)
+    }
+
+    /// The outgoing webhook events of the mandate, waiting for them to be recorded since the
+    /// webhooks are created in a spawned task
+    #[cfg(feature = "v1")]
+    async fn wait_for_mandate_events(
+        state: &SessionState,
+        merchant_context: &domain::MerchantContext,
+        mandate_id: &str,
+    ) -> Vec<domain::Event> {
+        for _ in 0..50 {
+            let events = state
+                .store
+                .list_initial_events_by_merchant_id_primary_object_id(
+                    &state.into(),
+                    merchant_context.get_merchant_account().get_id(),
+                    mandate_id,
+                    merchant_context.get_merchant_key_store(),
+                )
+                .await
+                .unwrap();
+            if !events.is_empty() {
+                return events;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        }
+        Vec::new()
+    }
+
+    #[cfg(feature = "v1")]
+    #[tokio::test]
+    async fn test_revoked_mandate_sends_mandate_revoked_webhook() {
+        let (state, merchant_context, mandate) =
+            setup_mandate_with_outgoing_webhooks(storage::MandateNew {
+                mandate_status: storage_enums::MandateStatus::Active,
+                ..Default::default()
+            })
+            .await;
+
+        mark_mandate_revoked(&state, &merchant_context, mandate, None)
+            .await
+            .unwrap();
+
+        let events = wait_for_mandate_events(&state, &merchant_context, "man_webhook").await;
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            events[0].event_type,
+            api_models::enums::EventType::MandateRevoked
+        );
+        assert_eq!(events[0].event_class, storage_enums::EventClass::Mandates);
+        assert_eq!(
+            events[0].primary_object_type,
+            storage_enums::EventObjectType::MandateDetails
+        );
+    }
+
+    #[cfg(feature = "v1")]
+    #[tokio::test]
+    async fn test_connector_mandate_id_update_sends_mandate_active_webhook() {
+        let (state, merchant_context, _) =
+            setup_mandate_with_outgoing_webhooks(storage::MandateNew {
+                mandate_status: storage_enums::MandateStatus::Active,
+                connector_mandate_id: None,
+                ..Default::default()
+            })
+            .await;
+        let response = Ok(types::PaymentsResponseData::TransactionResponse {
+            resource_id: types::ResponseId::NoResponseId,
+            redirection_data: Box::new(None),
+            mandate_reference: Box::new(Some(types::MandateReference {
+                connector_mandate_id: Some("connector_mandate_1".to_string()),
+                payment_method_id: None,
+                mandate_metadata: None,
+                connector_mandate_request_reference_id: None,
+            })),
+            connector_metadata: None,
+            network_txn_id: None,
+            connector_response_reference_id: None,
+            incremental_authorization_allowed: None,
+            charges: None,
+        });
+
+        update_connector_mandate_id(
+            &state,
+            merchant_context.get_merchant_key_store(),
+            merchant_context.get_merchant_account().get_id(),
+            Some("man_webhook".to_string()),
+            Some("pm_webhook".to_string()),
+            response,
+            MerchantStorageScheme::PostgresOnly,
+        )
+        .await
+        .unwrap();
+
+        let events = wait_for_mandate_events(&state, &merchant_context, "man_webhook").await;
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            events[0].event_type,
+            api_models::enums::EventType::MandateActive
+        );
+        assert_eq!(events[0].event_class, storage_enums::EventClass::Mandates);
+    }
+}
@@ -1,12 +1,14 @@
 use api_models::payments as api_payments;
 use common_enums::enums;
 use common_types::payments as common_payments_types;
-use common_utils::errors::CustomResult;
+use common_utils::{errors::CustomResult, pii};
 use diesel_models::Mandate;
 use error_stack::ResultExt;
 use hyperswitch_domain_models::mandates::MandateData;
+use masking::PeekInterface;
 
 use crate::{
+    consts,
     core::{errors, payments},
     routes::SessionState,
     types::{api, domain},
@@ -46,6 +48,53 @@ pub async fn get_profile_id_for_mandate(
     Ok(profile_id)
 }
 
+/// Validates the metadata set by the merchant on a mandate, which is to be a JSON object within
+/// the limits on the number of keys and on the length of its keys and values
+pub fn validate_mandate_metadata(
+    metadata: &pii::SecretSerdeValue,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let metadata = metadata.peek().as_object().ok_or_else(|| {
+        errors::ApiErrorResponse::InvalidRequestData {
+            message: "mandate metadata is expected to be a JSON object".to_string(),
+        }
+    })?;
+
+    if metadata.len() > consts::MAX_MANDATE_METADATA_KEYS {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "mandate metadata can have at most {} keys",
+                consts::MAX_MANDATE_METADATA_KEYS
+            ),
+        })?
+    }
+
+    for (key, value) in metadata {
+        if key.chars().count() > consts::MAX_MANDATE_METADATA_KEY_LENGTH {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "mandate metadata key `{key}` exceeds {} characters",
+                    consts::MAX_MANDATE_METADATA_KEY_LENGTH
+                ),
+            })?
+        }
+
+        let value_length = match value {
+            serde_json::Value::String(value) => value.chars().count(),
+            value => value.to_string().chars().count(),
+        };
+        if value_length > consts::MAX_MANDATE_METADATA_VALUE_LENGTH {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "value of mandate metadata key `{key}` exceeds {} characters",
+                    consts::MAX_MANDATE_METADATA_VALUE_LENGTH
+                ),
+            })?
+        }
+    }
+
+    Ok(())
+}
+
 pub fn get_mandate_type(
     mandate_data: Option<api_payments::MandateData>,
     off_session: Option<bool>,
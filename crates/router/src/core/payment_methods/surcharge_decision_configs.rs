@@ -14,6 +14,7 @@ use error_stack::{self, ResultExt};
 use euclid::{
     backend,
     backend::{inputs as dsl_inputs, EuclidBackend},
+    frontend::ast,
 };
 use router_env::{instrument, logger, tracing};
 use serde::{Deserialize, Serialize};
@@ -496,11 +497,18 @@ pub async fn ensure_algorithm_cached(
 
     let value_to_cache = || async {
         let config: diesel_models::Config = store.find_config_by_key(algorithm_id).await?;
-        let record: SurchargeDecisionManagerRecord = config
+        let mut record: SurchargeDecisionManagerRecord = config
             .config
             .parse_struct("Program")
             .change_context(errors::StorageError::DeserializationFailed)
             .attach_printable("Error parsing routing algorithm from configs")?;
+        let version = record.algorithm.version;
+        let program = serde_json::to_value(&record.algorithm)
+            .change_context(errors::StorageError::SerializationFailed)
+            .attach_printable("Error serializing surcharge program for migration")?;
+        record.algorithm = ast::migration::migrate_program(program, version)
+            .change_context(errors::StorageError::DeserializationFailed)
+            .attach_printable("Error migrating surcharge program to the latest version")?;
         VirInterpreterBackendCacheWrapper::try_from(record)
             .change_context(errors::StorageError::ValueNotFound("Program".to_string()))
             .attach_printable("Error initializing DSL interpreter backend")
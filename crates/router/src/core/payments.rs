@@ -2867,6 +2867,7 @@ impl PaymentRedirectFlow for PaymentRedirectSync {
             expand_attempts: None,
             expand_captures: None,
             all_keys_required: None,
+            expand_mandate: None,
         };
         let response = Box::pin(
             payments_core::<api::PSync, api::PaymentsResponse, _, _, _, _>(
@@ -3240,6 +3241,7 @@ impl PaymentRedirectFlow for PaymentAuthenticateCompleteAuthorize {
                 expand_attempts: None,
                 expand_captures: None,
                 all_keys_required: None,
+                expand_mandate: None,
             };
             Box::pin(
                 payments_core::<api::PSync, api::PaymentsResponse, _, _, _, _>(
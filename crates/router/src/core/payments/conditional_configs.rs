@@ -2,6 +2,8 @@ use api_models::{conditional_configs::DecisionManagerRecord, routing};
 use common_utils::ext_traits::StringExt;
 use error_stack::ResultExt;
 use euclid::backend::{self, inputs as dsl_inputs, EuclidBackend};
+#[cfg(feature = "v1")]
+use euclid::frontend::ast;
 use router_env::{instrument, tracing};
 use storage_impl::redis::cache::{self, DECISION_MANAGER_CACHE};
 
@@ -39,8 +41,15 @@ pub async fn perform_decision_management(
             .parse_struct("Program")
             .change_context(errors::StorageError::DeserializationFailed)
             .attach_printable("Error parsing routing algorithm from configs")?;
+        let version = rec.program.version;
+        let program = serde_json::to_value(&rec.program)
+            .change_context(errors::StorageError::SerializationFailed)
+            .attach_printable("Error serializing conditional config program for migration")?;
+        let program = ast::migration::migrate_program(program, version)
+            .change_context(errors::StorageError::DeserializationFailed)
+            .attach_printable("Error migrating conditional config program to the latest version")?;
 
-        backend::VirInterpreterBackend::with_program(rec.program)
+        backend::VirInterpreterBackend::with_program(program)
             .change_context(errors::StorageError::ValueNotFound("Program".to_string()))
             .attach_printable("Error initializing DSL interpreter backend")
     };
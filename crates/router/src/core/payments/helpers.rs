@@ -67,7 +67,7 @@ use crate::{
     core::{
         authentication,
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
-        mandate::helpers::MandateGenericData,
+        mandate::{self, helpers::MandateGenericData},
         payment_methods::{
             self,
             cards::{self},
@@ -791,6 +791,12 @@ pub async fn get_token_for_recurring_mandate(
         req.currency.get_required_value("currency")?,
         mandate.clone(),
     )?;
+    mandate::validate_mandate_frequency(
+        &mandate,
+        common_utils::date_time::now(),
+        time::Duration::minutes(state.conf.mandate_frequency_guard.tolerance_in_minutes),
+        req.bypass_mandate_frequency_check.unwrap_or(false),
+    )?;
 
     let payment_method = db
         .find_payment_method(
@@ -1151,6 +1157,12 @@ fn validate_new_mandate_request(
         Some(api_models::payments::MandateType::MultiUse(details)) => details,
         _ => None,
     };
+    mandate_data
+        .metadata
+        .as_ref()
+        .map(mandate::helpers::validate_mandate_metadata)
+        .transpose()?;
+
     mandate_details.and_then(|md| md.start_date.zip(md.end_date)).map(|(start_date, end_date)|
         utils::when (start_date >= end_date, || {
         Err(report!(errors::ApiErrorResponse::PreconditionFailed {
@@ -3402,7 +3414,8 @@ pub fn generate_mandate(
                 .set_connector_mandate_id(
                     mandate_reference.and_then(|reference| reference.connector_mandate_id),
                 )
-                .set_merchant_connector_id(merchant_connector_id);
+                .set_merchant_connector_id(merchant_connector_id)
+                .set_merchant_metadata(data.metadata);
 
             Ok(Some(
                 match data.mandate_type.get_required_value("mandate_type")? {
@@ -3419,6 +3432,7 @@ pub fn generate_mandate(
                             Some(data) => new_mandate
                                 .set_mandate_amount(Some(data.amount.get_amount_as_i64()))
                                 .set_mandate_currency(Some(data.currency))
+                                .set_frequency(data.get_frequency())
                                 .set_start_date(data.start_date)
                                 .set_end_date(data.end_date),
                             // .set_metadata(data.metadata),
@@ -3426,6 +3440,9 @@ pub fn generate_mandate(
                             None => &mut new_mandate,
                         }
                         .set_mandate_type(storage_enums::MandateType::MultiUse)
+                        // The payment setting up the mandate counts as its first charge, so that
+                        // the frequency of the mandate applies to the first recurring payment
+                        .set_last_charged_at(Some(common_utils::date_time::now()))
                         .to_owned()
                     }
                 },
@@ -3852,6 +3869,41 @@ mod tests {
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_err())
     }
+
+    #[test]
+    fn test_generate_mandate_sets_merchant_metadata() {
+        let metadata = pii::SecretSerdeValue::new(serde_json::json!({ "contract_id": "CON_1234" }));
+        let setup_mandate_details = MandateData {
+            update_mandate_id: None,
+            customer_acceptance: Some(common_types::payments::CustomerAcceptance {
+                acceptance_type: common_types::payments::AcceptanceType::Offline,
+                accepted_at: None,
+                online: None,
+            }),
+            mandate_type: Some(
+                hyperswitch_domain_models::mandates::MandateDataType::MultiUse(None),
+            ),
+            metadata: Some(metadata.clone()),
+        };
+
+        let mandate = generate_mandate(
+            id_type::MerchantId::default(),
+            id_type::PaymentId::try_from(Cow::Borrowed("pay_1")).unwrap(),
+            "stripe".to_string(),
+            Some(setup_mandate_details),
+            &Some(id_type::CustomerId::default()),
+            "pm_1".to_string(),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(mandate.merchant_metadata, Some(metadata));
+    }
 }
 
 // This function will be removed after moving this functionality to server_wrap and using cache instead of config
@@ -234,7 +234,8 @@ impl<F: Send + Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsAuthor
                 &customer_id.clone(),
                 payment_method_id.clone(),
                 merchant_connector_id.clone(),
-                merchant_context.get_merchant_account().storage_scheme,
+                merchant_context,
+                business_profile,
                 payment_data.payment_intent.get_id(),
             )
             .await?;
@@ -1239,7 +1240,8 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::SetupMandateRequestDa
             &customer_id,
             payment_method_id.clone(),
             merchant_connector_id.clone(),
-            merchant_context.get_merchant_account().storage_scheme,
+            merchant_context,
+            business_profile,
             payment_data.payment_intent.get_id(),
         )
         .await?;
@@ -2073,7 +2075,8 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
     );
 
     // When connector requires redirection for mandate creation it can update the connector mandate_id during Psync and CompleteAuthorize
-    let m_db = state.clone().store;
+    let m_state = state.clone();
+    let m_key_store = key_store.clone();
     let m_router_data_merchant_id = router_data.merchant_id.clone();
     let m_payment_method_id = payment_data.payment_attempt.payment_method_id.clone();
     let m_payment_data_mandate_id =
@@ -2089,7 +2092,8 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
     let mandate_update_fut = tokio::spawn(
         async move {
             mandate::update_connector_mandate_id(
-                m_db.as_ref(),
+                &m_state,
+                &m_key_store,
                 &m_router_data_merchant_id,
                 m_payment_data_mandate_id,
                 m_payment_method_id,
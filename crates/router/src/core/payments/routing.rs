@@ -665,18 +665,53 @@ pub async fn refresh_routing_cache_v1(
         algorithm
     };
 
-    let cached_algorithm = match algorithm {
-        routing_types::StaticRoutingAlgorithm::Single(conn) => CachedAlgorithm::Single(conn),
-        routing_types::StaticRoutingAlgorithm::Priority(plist) => CachedAlgorithm::Priority(plist),
+    let arc_cached_algorithm = match algorithm {
+        routing_types::StaticRoutingAlgorithm::Single(conn) => {
+            Arc::new(CachedAlgorithm::Single(conn))
+        }
+        routing_types::StaticRoutingAlgorithm::Priority(plist) => {
+            Arc::new(CachedAlgorithm::Priority(plist))
+        }
         routing_types::StaticRoutingAlgorithm::VolumeSplit(splits) => {
-            CachedAlgorithm::VolumeSplit(splits)
+            Arc::new(CachedAlgorithm::VolumeSplit(splits))
         }
         routing_types::StaticRoutingAlgorithm::Advanced(program) => {
-            let interpreter = backend::VirInterpreterBackend::with_program(program)
-                .change_context(errors::RoutingError::DslBackendInitError)
-                .attach_printable("Error initializing DSL interpreter backend")?;
+            let version = program.version;
+            let program = serde_json::to_value(program)
+                .change_context(errors::RoutingError::DslParsingError)
+                .attach_printable("Error serializing program for migration")?;
+            let program = ast::migration::migrate_program(program, version)
+                .change_context(errors::RoutingError::DslProgramMigrationError)
+                .attach_printable("Error migrating program to the latest version")?;
+            let program_hash = ast::hashing::hash_program(&program)
+                .change_context(errors::RoutingError::DslProgramHashError)
+                .attach_printable("Error computing the hash of the program")?;
+
+            // Identical programs share a single compiled interpreter, irrespective of the
+            // algorithm they were saved under
+            let program_cache_key = CacheKey {
+                key: format!("routing_program_{program_hash}"),
+                prefix: state.tenant.redis_key_prefix.clone(),
+            };
 
-            CachedAlgorithm::Advanced(interpreter)
+            match ROUTING_CACHE
+                .get_val::<Arc<CachedAlgorithm>>(program_cache_key.clone())
+                .await
+            {
+                Some(cached_program) => cached_program,
+                None => {
+                    let interpreter = backend::VirInterpreterBackend::with_program(program)
+                        .change_context(errors::RoutingError::DslBackendInitError)
+                        .attach_printable("Error initializing DSL interpreter backend")?;
+                    let cached_program = Arc::new(CachedAlgorithm::Advanced(interpreter));
+
+                    ROUTING_CACHE
+                        .push(program_cache_key, cached_program.clone())
+                        .await;
+
+                    cached_program
+                }
+            }
         }
         api_models::routing::StaticRoutingAlgorithm::ThreeDsDecisionRule(_program) => {
             Err(errors::RoutingError::InvalidRoutingAlgorithmStructure)
@@ -684,8 +719,6 @@ pub async fn refresh_routing_cache_v1(
         }
     };
 
-    let arc_cached_algorithm = Arc::new(cached_algorithm);
-
     ROUTING_CACHE
         .push(
             CacheKey {
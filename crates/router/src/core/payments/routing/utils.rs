@@ -1036,9 +1036,21 @@ impl TryFrom<ast::Program<ConnectorSelection>> for Program {
             .into_iter()
             .map(convert_rule)
             .collect::<Result<Vec<_>, _>>()?;
+        let globals = p
+            .segments
+            .into_iter()
+            .map(|(name, segment)| {
+                let values = segment
+                    .values
+                    .into_iter()
+                    .map(convert_value)
+                    .collect::<RoutingResult<HashSet<_>>>()?;
+                Ok((name, values))
+            })
+            .collect::<RoutingResult<Globals>>()?;
 
         Ok(Self {
-            globals: HashMap::new(),
+            globals,
             default_selection: convert_output(p.default_selection),
             rules,
             metadata: Some(p.metadata),
@@ -1131,6 +1143,7 @@ fn convert_value(v: ast::ValueType) -> RoutingResult<ValueType> {
                 .collect(),
         )),
         EnumVariantArray(arr) => Ok(ValueType::EnumVariantArray(arr)),
+        SegmentReference(name) => Ok(ValueType::GlobalRef(name)),
         NumberComparisonArray(arr) => Ok(ValueType::NumberComparisonArray(
             arr.into_iter()
                 .map(|nc| NumberComparison {
@@ -804,6 +804,11 @@ pub async fn construct_payment_router_data_for_sdk_session<'a>(
         minor_amount: payment_data.payment_intent.amount_details.order_amount,
         apple_pay_recurring_details,
         customer_name,
+        return_url: payment_data
+            .payment_intent
+            .return_url
+            .as_ref()
+            .map(|return_url| return_url.get_string_repr().to_string()),
     };
 
     // TODO: evaluate the fields in router data, if they are required or not
@@ -2780,6 +2785,7 @@ where
                 }
             }),
             update_mandate_id: d.update_mandate_id.clone(),
+            metadata: d.metadata.clone(),
         });
 
         let order_tax_amount = payment_data
@@ -2833,6 +2839,8 @@ where
             attempts: attempts_response,
             captures: captures_response,
             mandate_id,
+            mandate: None,
+            mandate_expansion_pending: None,
             mandate_data,
             setup_future_usage: payment_attempt.setup_future_usage_applied,
             off_session: payment_intent.off_session,
@@ -4179,6 +4187,11 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsSessionD
             email: payment_data.email,
             apple_pay_recurring_details,
             customer_name: None,
+            return_url: payment_data
+                .payment_intent
+                .return_url
+                .as_ref()
+                .map(|return_url| return_url.get_string_repr().to_string()),
         })
     }
 }
@@ -4265,6 +4278,7 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsSessionD
             surcharge_details: payment_data.surcharge_details,
             apple_pay_recurring_details,
             customer_name: None,
+            return_url: payment_data.payment_intent.return_url,
         })
     }
 }
@@ -0,0 +1,412 @@
+use api_models::refunds::{RefundCircuitBreakerResetRequest, RefundCircuitBreakerResetResponse};
+use common_utils::id_type;
+use error_stack::{report, ResultExt};
+use router_env::logger;
+
+use crate::{
+    configs::settings,
+    core::errors::{self, RouterResponse, RouterResult},
+    routes::SessionState,
+    services, types,
+};
+
+/// The state of the refund circuit breaker of a merchant connector account. It is stored in Redis
+/// as a counter of the consecutive failures, which expires once the failure window elapses, or
+/// once the cool-down elapses when the refunds are suspended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RefundCircuitBreakerState {
+    /// The number of consecutive refunds which failed due to the credentials or the configuration
+    /// of the merchant connector account
+    pub consecutive_failures: u32,
+}
+
+impl RefundCircuitBreakerState {
+    /// The refunds are suspended once the failure threshold is reached, until the counter expires
+    pub fn is_open(&self, config: &settings::RefundCircuitBreaker) -> bool {
+        self.consecutive_failures >= config.failure_threshold
+    }
+
+    /// The expiry to be set on the counter after it was incremented to `consecutive_failures`. The
+    /// first failure starts the failure window and reaching the threshold starts the cool-down,
+    /// while the failures in between keep the expiry of the window.
+    fn get_expiry_in_secs_after_failure(
+        consecutive_failures: u32,
+        config: &settings::RefundCircuitBreaker,
+    ) -> Option<i64> {
+        if consecutive_failures == config.failure_threshold {
+            Some(config.cool_down_in_secs)
+        } else if consecutive_failures == 1 {
+            Some(config.failure_window_in_secs)
+        } else {
+            None
+        }
+    }
+}
+
+/// What the outcome of a refund attempt tells about the merchant connector account it was
+/// processed through
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectorAccountOutcome {
+    /// The refund failed due to the credentials or the configuration of the connector account
+    Failed,
+    /// The connector processed the refund, whether it succeeded or was declined
+    Healthy,
+    /// The refund failed for a reason unrelated to the connector account, such as a timeout
+    Inconclusive,
+}
+
+impl ConnectorAccountOutcome {
+    pub fn from_connector_error(error: &errors::ConnectorError) -> Self {
+        match error {
+            errors::ConnectorError::FailedToObtainAuthType
+            | errors::ConnectorError::FailedToObtainIntegrationUrl
+            | errors::ConnectorError::FailedToObtainCertificate
+            | errors::ConnectorError::FailedToObtainCertificateKey
+            | errors::ConnectorError::NoConnectorMetaData
+            | errors::ConnectorError::InvalidConnectorConfig { .. } => Self::Failed,
+            _ => Self::Inconclusive,
+        }
+    }
+
+    pub fn from_refund_response(
+        response: &Result<types::RefundsResponseData, types::ErrorResponse>,
+    ) -> Self {
+        match response {
+            Ok(_) => Self::Healthy,
+            // The connector rejected the credentials of the connector account
+            Err(error) if matches!(error.status_code, 401 | 403) => Self::Failed,
+            Err(error) if error.status_code >= 500 => Self::Inconclusive,
+            // Any other error is a decline of the refund itself
+            Err(_) => Self::Healthy,
+        }
+    }
+}
+
+fn get_refund_circuit_breaker_key(
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> String {
+    format!(
+        "refund_circuit_breaker_{}",
+        merchant_connector_id.get_string_repr()
+    )
+}
+
+async fn find_refund_circuit_breaker_state(
+    state: &SessionState,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> RouterResult<Option<RefundCircuitBreakerState>> {
+    let consecutive_failures = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .get_key::<Option<u32>>(&get_refund_circuit_breaker_key(merchant_connector_id).into())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the refund circuit breaker state")?;
+
+    Ok(
+        consecutive_failures.map(|consecutive_failures| RefundCircuitBreakerState {
+            consecutive_failures,
+        }),
+    )
+}
+
+/// Fails when the refunds of the merchant connector account are suspended, otherwise returns the
+/// state of its circuit breaker for the outcome of the refund to be recorded against
+pub async fn validate_refund_circuit_breaker(
+    state: &SessionState,
+    merchant_connector_id: Option<&id_type::MerchantConnectorAccountId>,
+) -> RouterResult<Option<RefundCircuitBreakerState>> {
+    let Some(merchant_connector_id) =
+        merchant_connector_id.filter(|_| state.conf.refund_circuit_breaker.enabled)
+    else {
+        return Ok(None);
+    };
+
+    // Refunds are not blocked when the circuit breaker state cannot be fetched
+    let circuit_breaker_state = find_refund_circuit_breaker_state(state, merchant_connector_id)
+        .await
+        .map_err(|error| logger::error!(refund_circuit_breaker_error=?error))
+        .ok()
+        .flatten();
+
+    check_refund_circuit_breaker(
+        circuit_breaker_state.as_ref(),
+        merchant_connector_id,
+        &state.conf.refund_circuit_breaker,
+    )?;
+    Ok(circuit_breaker_state)
+}
+
+fn check_refund_circuit_breaker(
+    circuit_breaker_state: Option<&RefundCircuitBreakerState>,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    config: &settings::RefundCircuitBreaker,
+) -> RouterResult<()> {
+    match circuit_breaker_state {
+        Some(circuit_breaker_state) if circuit_breaker_state.is_open(config) => Err(report!(
+            errors::ApiErrorResponse::RefundsSuspendedForConnectorAccount {
+                merchant_connector_id: merchant_connector_id.get_string_repr().to_owned(),
+            }
+        ))
+        .attach_printable_lazy(|| {
+            format!(
+                "Refunds suspended for {} seconds after {} consecutive failures",
+                config.cool_down_in_secs, circuit_breaker_state.consecutive_failures
+            )
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Records the outcome of a refund against the circuit breaker of the merchant connector account,
+/// failures to update the circuit breaker are logged and do not fail the refund
+pub async fn record_refund_outcome(
+    state: &SessionState,
+    merchant_connector_id: Option<&id_type::MerchantConnectorAccountId>,
+    circuit_breaker_state: Option<&RefundCircuitBreakerState>,
+    outcome: ConnectorAccountOutcome,
+) {
+    let Some(merchant_connector_id) =
+        merchant_connector_id.filter(|_| state.conf.refund_circuit_breaker.enabled)
+    else {
+        return;
+    };
+
+    update_refund_circuit_breaker_state(
+        state,
+        merchant_connector_id,
+        circuit_breaker_state,
+        outcome,
+    )
+    .await
+    .map_err(|error| logger::error!(refund_circuit_breaker_error=?error))
+    .ok();
+}
+
+async fn update_refund_circuit_breaker_state(
+    state: &SessionState,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    circuit_breaker_state: Option<&RefundCircuitBreakerState>,
+    outcome: ConnectorAccountOutcome,
+) -> RouterResult<()> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let key = get_refund_circuit_breaker_key(merchant_connector_id).into();
+
+    match outcome {
+        ConnectorAccountOutcome::Failed => {
+            let config = &state.conf.refund_circuit_breaker;
+            // The counter is incremented atomically so that the failures of concurrent refunds
+            // are all counted
+            let consecutive_failures = redis_conn
+                .increment_key(&key)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to update the refund circuit breaker state")?;
+            let consecutive_failures = u32::try_from(consecutive_failures)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Invalid refund circuit breaker state")?;
+
+            let Some(expiry_in_secs) = RefundCircuitBreakerState::get_expiry_in_secs_after_failure(
+                consecutive_failures,
+                config,
+            ) else {
+                return Ok(());
+            };
+            if consecutive_failures == config.failure_threshold {
+                logger::warn!(
+                    merchant_connector_id = merchant_connector_id.get_string_repr(),
+                    "Suspending the refunds of the merchant connector account"
+                );
+            }
+
+            redis_conn
+                .set_expiry(&key, expiry_in_secs)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to set the expiry of the refund circuit breaker state")
+        }
+        // A refund processed by the connector breaks the run of consecutive failures
+        ConnectorAccountOutcome::Healthy if circuit_breaker_state.is_some() => {
+            redis_conn
+                .delete_key(&key)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to clear the refund circuit breaker state")?;
+            Ok(())
+        }
+        ConnectorAccountOutcome::Healthy | ConnectorAccountOutcome::Inconclusive => Ok(()),
+    }
+}
+
+pub async fn reset_refund_circuit_breaker(
+    state: SessionState,
+    req: RefundCircuitBreakerResetRequest,
+) -> RouterResponse<RefundCircuitBreakerResetResponse> {
+    let circuit_breaker_state =
+        find_refund_circuit_breaker_state(&state, &req.merchant_connector_id).await?;
+
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .delete_key(&get_refund_circuit_breaker_key(&req.merchant_connector_id).into())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to reset the refund circuit breaker state")?;
+
+    Ok(services::ApplicationResponse::Json(
+        RefundCircuitBreakerResetResponse {
+            merchant_connector_id: req.merchant_connector_id,
+            was_suspended: circuit_breaker_state.is_some_and(|circuit_breaker_state| {
+                circuit_breaker_state.is_open(&state.conf.refund_circuit_breaker)
+            }),
+            consecutive_failures: circuit_breaker_state
+                .map(|circuit_breaker_state| circuit_breaker_state.consecutive_failures)
+                .unwrap_or_default(),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use super::*;
+
+    fn merchant_connector_id() -> id_type::MerchantConnectorAccountId {
+        id_type::MerchantConnectorAccountId::wrap("mca_refund_circuit_breaker".to_string()).unwrap()
+    }
+
+    fn state(consecutive_failures: u32) -> RefundCircuitBreakerState {
+        RefundCircuitBreakerState {
+            consecutive_failures,
+        }
+    }
+
+    fn error_response(status_code: u16) -> types::ErrorResponse {
+        types::ErrorResponse {
+            status_code,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_circuit_breaker_trips_after_consecutive_failures() {
+        let config = settings::RefundCircuitBreaker::default();
+
+        assert!(!state(config.failure_threshold - 1).is_open(&config));
+        assert!(state(config.failure_threshold).is_open(&config));
+        // Failures of refunds which were in flight when the circuit breaker tripped
+        assert!(state(config.failure_threshold + 1).is_open(&config));
+    }
+
+    #[test]
+    fn test_counter_expires_with_the_failure_window_and_the_cool_down() {
+        let config = settings::RefundCircuitBreaker::default();
+
+        assert_eq!(
+            RefundCircuitBreakerState::get_expiry_in_secs_after_failure(1, &config),
+            Some(config.failure_window_in_secs)
+        );
+        // The failures within the window do not extend it
+        assert_eq!(
+            RefundCircuitBreakerState::get_expiry_in_secs_after_failure(
+                config.failure_threshold - 1,
+                &config
+            ),
+            None
+        );
+        assert_eq!(
+            RefundCircuitBreakerState::get_expiry_in_secs_after_failure(
+                config.failure_threshold,
+                &config
+            ),
+            Some(config.cool_down_in_secs)
+        );
+        // Nor do the failures after tripping extend the cool-down
+        assert_eq!(
+            RefundCircuitBreakerState::get_expiry_in_secs_after_failure(
+                config.failure_threshold + 1,
+                &config
+            ),
+            None
+        );
+
+        let config = settings::RefundCircuitBreaker {
+            failure_threshold: 1,
+            ..config
+        };
+        assert_eq!(
+            RefundCircuitBreakerState::get_expiry_in_secs_after_failure(1, &config),
+            Some(config.cool_down_in_secs)
+        );
+    }
+
+    #[test]
+    fn test_open_circuit_breaker_short_circuits_refunds() {
+        let config = settings::RefundCircuitBreaker::default();
+
+        let result = check_refund_circuit_breaker(
+            Some(&state(config.failure_threshold)),
+            &merchant_connector_id(),
+            &config,
+        );
+
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::RefundsSuspendedForConnectorAccount { .. }
+        ));
+    }
+
+    #[test]
+    fn test_circuit_breaker_closes_once_the_counter_expires() {
+        let config = settings::RefundCircuitBreaker::default();
+
+        assert!(check_refund_circuit_breaker(
+            Some(&state(config.failure_threshold - 1)),
+            &merchant_connector_id(),
+            &config,
+        )
+        .is_ok());
+        // The counter no longer exists after the cool-down or a reset
+        assert!(check_refund_circuit_breaker(None, &merchant_connector_id(), &config).is_ok());
+    }
+
+    #[test]
+    fn test_refund_declines_do_not_count_as_connector_account_failures() {
+        for (status_code, outcome) in [
+            (400, ConnectorAccountOutcome::Healthy),
+            (402, ConnectorAccountOutcome::Healthy),
+            (422, ConnectorAccountOutcome::Healthy),
+            (401, ConnectorAccountOutcome::Failed),
+            (403, ConnectorAccountOutcome::Failed),
+            (503, ConnectorAccountOutcome::Inconclusive),
+        ] {
+            assert_eq!(
+                ConnectorAccountOutcome::from_refund_response(&Err(error_response(status_code))),
+                outcome,
+                "status code {status_code}"
+            );
+        }
+
+        assert_eq!(
+            ConnectorAccountOutcome::from_connector_error(
+                &errors::ConnectorError::FailedToObtainAuthType
+            ),
+            ConnectorAccountOutcome::Failed
+        );
+        assert_eq!(
+            ConnectorAccountOutcome::from_connector_error(
+                &errors::ConnectorError::RequestTimeoutReceived
+            ),
+            ConnectorAccountOutcome::Inconclusive
+        );
+    }
+}
@@ -0,0 +1,828 @@
+use api_models::refunds::{RefundLimitsConfigRequest, RefundLimitsConfigResponse};
+use common_utils::{
+    ext_traits::{Encode, StringExt},
+    id_type,
+    types::MinorUnit,
+};
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+
+use crate::{
+    configs::settings,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils::refunds_validator,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services::api as service_api,
+    types::{domain, storage::enums},
+    utils,
+};
+
+/// The limits that apply to the refunds created for the payments of a profile, the limits
+/// configured for the profile take precedence over the ones from the application config
+#[derive(Debug, Clone)]
+pub struct RefundLimits {
+    pub max_attempts: usize,
+    pub max_age: i64,
+    pub velocity: Option<RefundVelocityLimit>,
+    pub amount: Option<RefundAmountLimits>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RefundVelocityLimit {
+    pub max_amount: MinorUnit,
+    pub currency: enums::Currency,
+    pub window: time::Duration,
+}
+
+/// Caps on the cumulative amount refunded for a payment and by the profile within a day, in
+/// `currency`
+#[derive(Debug, Clone)]
+pub struct RefundAmountLimits {
+    pub currency: enums::Currency,
+    pub max_amount_per_payment: Option<MinorUnit>,
+    pub daily_max_amount: Option<MinorUnit>,
+}
+
+/// The refund limits of a profile as stored in the configs table, kept separate from the API
+/// response so that the stored format does not change along with the API
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct RefundLimitsConfigRecord {
+    pub profile_id: id_type::ProfileId,
+    pub max_refund_amount_per_window: Option<MinorUnit>,
+    pub currency: Option<enums::Currency>,
+    pub window_size_in_seconds: Option<i64>,
+    pub max_refund_amount_per_payment: Option<MinorUnit>,
+    pub daily_refund_amount_limit: Option<MinorUnit>,
+    pub max_partial_refunds_per_payment: Option<u16>,
+    pub refund_window_days: Option<i64>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+impl From<RefundLimitsConfigRecord> for RefundLimitsConfigResponse {
+    fn from(record: RefundLimitsConfigRecord) -> Self {
+        Self {
+            profile_id: record.profile_id,
+            max_refund_amount_per_window: record.max_refund_amount_per_window,
+            currency: record.currency,
+            window_size_in_seconds: record.window_size_in_seconds,
+            max_refund_amount_per_payment: record.max_refund_amount_per_payment,
+            daily_refund_amount_limit: record.daily_refund_amount_limit,
+            max_partial_refunds_per_payment: record.max_partial_refunds_per_payment,
+            refund_window_days: record.refund_window_days,
+            created_at: record.created_at,
+            modified_at: record.modified_at,
+        }
+    }
+}
+
+impl RefundLimits {
+    pub fn new(defaults: &settings::Refund, config: Option<&RefundLimitsConfigRecord>) -> Self {
+        let velocity = config.and_then(|config| {
+            Some(RefundVelocityLimit {
+                max_amount: config.max_refund_amount_per_window?,
+                currency: config.currency?,
+                window: time::Duration::seconds(config.window_size_in_seconds?),
+            })
+        });
+        let amount = config.and_then(|config| {
+            let has_amount_limits = config.max_refund_amount_per_payment.is_some()
+                || config.daily_refund_amount_limit.is_some();
+            has_amount_limits.then_some(RefundAmountLimits {
+                currency: config.currency?,
+                max_amount_per_payment: config.max_refund_amount_per_payment,
+                daily_max_amount: config.daily_refund_amount_limit,
+            })
+        });
+
+        Self {
+            max_attempts: config
+                .and_then(|config| config.max_partial_refunds_per_payment)
+                .map(usize::from)
+                .unwrap_or(defaults.max_attempts),
+            max_age: config
+                .and_then(|config| config.refund_window_days)
+                .unwrap_or(defaults.max_age),
+            velocity,
+            amount,
+        }
+    }
+}
+
+fn validate_refund_limits_config(request: &RefundLimitsConfigRequest) -> RouterResult<()> {
+    let invalid_request = |message: &str| {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: message.to_string(),
+        }))
+    };
+
+    utils::when(
+        request
+            .window_size_in_seconds
+            .is_some_and(|window_size| window_size <= 0),
+        || invalid_request("window_size_in_seconds must be greater than 0"),
+    )?;
+    utils::when(
+        request.refund_window_days.is_some_and(|days| days <= 0),
+        || invalid_request("refund_window_days must be greater than 0"),
+    )?;
+    utils::when(request.max_partial_refunds_per_payment == Some(0), || {
+        invalid_request("max_partial_refunds_per_payment must be greater than 0")
+    })?;
+    utils::when(
+        request
+            .max_refund_amount_per_window
+            .is_some_and(|amount| amount.get_amount_as_i64() <= 0),
+        || invalid_request("max_refund_amount_per_window must be greater than 0"),
+    )?;
+    utils::when(
+        request
+            .max_refund_amount_per_payment
+            .is_some_and(|amount| amount.get_amount_as_i64() <= 0),
+        || invalid_request("max_refund_amount_per_payment must be greater than 0"),
+    )?;
+    utils::when(
+        request
+            .daily_refund_amount_limit
+            .is_some_and(|amount| amount.get_amount_as_i64() <= 0),
+        || invalid_request("daily_refund_amount_limit must be greater than 0"),
+    )?;
+
+    // The velocity limit is only meaningful when the amount and the window are configured
+    // together, and every amount limit is expressed in `currency`
+    utils::when(
+        request.max_refund_amount_per_window.is_some() != request.window_size_in_seconds.is_some(),
+        || {
+            invalid_request(
+                "max_refund_amount_per_window and window_size_in_seconds must be provided together",
+            )
+        },
+    )?;
+    let has_amount_limits = request.max_refund_amount_per_window.is_some()
+        || request.max_refund_amount_per_payment.is_some()
+        || request.daily_refund_amount_limit.is_some();
+    utils::when(has_amount_limits != request.currency.is_some(), || {
+        invalid_request("currency must be provided if and only if an amount limit is provided")
+    })
+}
+
+pub async fn find_refund_limits_config(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<Option<RefundLimitsConfigRecord>> {
+    match db
+        .find_config_by_key(&profile_id.get_refund_limits_config_key())
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("RefundLimitsConfigRecord")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the refund limits config")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the refund limits config"),
+    }
+}
+
+pub async fn insert_or_update_refund_limits_config(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+    request: RefundLimitsConfigRequest,
+) -> RouterResult<RefundLimitsConfigRecord> {
+    validate_refund_limits_config(&request)?;
+
+    let key = profile_id.get_refund_limits_config_key();
+    let existing_config = find_refund_limits_config(db, profile_id).await?;
+    let now = common_utils::date_time::now();
+
+    let record = RefundLimitsConfigRecord {
+        profile_id: profile_id.clone(),
+        max_refund_amount_per_window: request.max_refund_amount_per_window,
+        currency: request.currency,
+        window_size_in_seconds: request.window_size_in_seconds,
+        max_partial_refunds_per_payment: request.max_partial_refunds_per_payment,
+        refund_window_days: request.refund_window_days,
+        max_refund_amount_per_payment: request.max_refund_amount_per_payment,
+        daily_refund_amount_limit: request.daily_refund_amount_limit,
+        created_at: existing_config
+            .as_ref()
+            .map(|config| config.created_at)
+            .unwrap_or(now),
+        modified_at: now,
+    };
+    let serialized_record = record
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the refund limits config")?;
+
+    // Updating and inserting the config through the configs interface invalidates the cached
+    // config, so that refunds are validated against the new limits right away
+    match existing_config {
+        Some(_) => db
+            .update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(serialized_record),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the refund limits config")?,
+        None => db
+            .insert_config(configs::ConfigNew {
+                key,
+                config: serialized_record,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert the refund limits config")?,
+    };
+
+    Ok(record)
+}
+
+pub async fn get_refund_limits(
+    state: &SessionState,
+    profile_id: Option<&id_type::ProfileId>,
+) -> RouterResult<RefundLimits> {
+    let config = match profile_id {
+        Some(profile_id) => find_refund_limits_config(state.store.as_ref(), profile_id).await?,
+        None => None,
+    };
+
+    Ok(RefundLimits::new(&state.conf.refund, config.as_ref()))
+}
+
+/// Validates the refund against the velocity limit of the profile, on a best effort basis like
+/// [`validate_refund_amount_limits`]
+pub async fn validate_refund_velocity(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    profile_id: &id_type::ProfileId,
+    velocity: &RefundVelocityLimit,
+    currency: enums::Currency,
+    refund_amount: MinorUnit,
+) -> RouterResult<()> {
+    // Only the refunds in the currency of the limit count towards it
+    if currency != velocity.currency {
+        return Ok(());
+    }
+
+    let refunds_in_window = state
+        .store
+        .find_refunds_by_merchant_id_profile_id_created_after(
+            merchant_context.get_merchant_account().get_id(),
+            profile_id,
+            common_utils::date_time::now() - velocity.window,
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the refunds created within the velocity window")?;
+
+    refunds_validator::validate_refund_velocity(
+        &refunds_in_window,
+        velocity.currency,
+        refund_amount.get_amount_as_i64(),
+        velocity.max_amount.get_amount_as_i64(),
+    )
+    .change_context(errors::ApiErrorResponse::PreconditionFailed {
+        message: format!(
+            "Refunds of the profile cannot exceed {} {} every {} seconds",
+            velocity.max_amount,
+            velocity.currency,
+            velocity.window.whole_seconds()
+        ),
+    })
+}
+
+/// Validates the refund against the per payment and daily amount limits of the profile. Refund
+/// creation does not lock the payment, so the limits are enforced on a best effort basis: refunds
+/// created concurrently are each validated against the refunds stored before them and can
+/// together exceed a limit.
+pub async fn validate_refund_amount_limits(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_id: &id_type::PaymentId,
+    profile_id: &id_type::ProfileId,
+    amount_limits: &RefundAmountLimits,
+    currency: enums::Currency,
+    refund_amount: MinorUnit,
+) -> RouterResult<()> {
+    let merchant_account = merchant_context.get_merchant_account();
+
+    refunds_validator::validate_refund_limit_currency(amount_limits.currency, currency)
+        .change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "The refund currency {currency} does not match the currency {} of the refund amount limits of the profile",
+                amount_limits.currency
+            ),
+        })?;
+
+    if let Some(max_amount_per_payment) = amount_limits.max_amount_per_payment {
+        let payment_refunds = state
+            .store
+            .find_refund_by_payment_id_merchant_id(
+                payment_id,
+                merchant_account.get_id(),
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the refunds of the payment")?;
+
+        refunds_validator::validate_refund_amount_limit(
+            &payment_refunds,
+            currency,
+            refund_amount.get_amount_as_i64(),
+            max_amount_per_payment.get_amount_as_i64(),
+        )
+        .change_context(errors::ApiErrorResponse::RefundAmountLimitExceeded {
+            message: format!(
+                "Refunds of a payment cannot exceed {max_amount_per_payment} {currency} for the profile"
+            ),
+        })?;
+    }
+
+    if let Some(daily_max_amount) = amount_limits.daily_max_amount {
+        let refunds_of_the_day = state
+            .store
+            .find_refunds_by_merchant_id_profile_id_created_after(
+                merchant_account.get_id(),
+                profile_id,
+                get_start_of_day(common_utils::date_time::now()),
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the refunds created during the day")?;
+
+        refunds_validator::validate_refund_amount_limit(
+            &refunds_of_the_day,
+            currency,
+            refund_amount.get_amount_as_i64(),
+            daily_max_amount.get_amount_as_i64(),
+        )
+        .change_context(errors::ApiErrorResponse::RefundAmountLimitExceeded {
+            message: format!(
+                "Refunds of the profile cannot exceed {daily_max_amount} {currency} per day"
+            ),
+        })?;
+    }
+
+    Ok(())
+}
+
+/// The daily refund amount limit applies to the calendar day in UTC
+fn get_start_of_day(now: time::PrimitiveDateTime) -> time::PrimitiveDateTime {
+    now.replace_time(time::Time::MIDNIGHT)
+}
+
+async fn validate_profile(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<()> {
+    state
+        .store
+        .find_business_profile_by_merchant_id_profile_id(
+            &state.into(),
+            merchant_context.get_merchant_key_store(),
+            merchant_context.get_merchant_account().get_id(),
+            profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    Ok(())
+}
+
+pub async fn upsert_refund_limits_config(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: id_type::ProfileId,
+    request: RefundLimitsConfigRequest,
+) -> RouterResponse<RefundLimitsConfigResponse> {
+    validate_profile(&state, &merchant_context, &profile_id).await?;
+
+    let record =
+        insert_or_update_refund_limits_config(state.store.as_ref(), &profile_id, request).await?;
+
+    Ok(service_api::ApplicationResponse::Json(record.into()))
+}
+
+pub async fn retrieve_refund_limits_config(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<RefundLimitsConfigResponse> {
+    validate_profile(&state, &merchant_context, &profile_id).await?;
+
+    let record = find_refund_limits_config(state.store.as_ref(), &profile_id)
+        .await?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Refund limits are not configured for the profile".to_string(),
+        })?;
+
+    Ok(service_api::ApplicationResponse::Json(record.into()))
+}
+
+pub async fn delete_refund_limits_config(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<RefundLimitsConfigResponse> {
+    validate_profile(&state, &merchant_context, &profile_id).await?;
+
+    let db = state.store.as_ref();
+    let record = find_refund_limits_config(db, &profile_id).await?.ok_or(
+        errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Refund limits are not configured for the profile".to_string(),
+        },
+    )?;
+
+    db.delete_config_by_key(&profile_id.get_refund_limits_config_key())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to delete the refund limits config")?;
+
+    Ok(service_api::ApplicationResponse::Json(record.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use diesel_models::refund as diesel_refund;
+
+    use super::*;
+    use crate::{db, test_utils};
+
+    fn profile_id() -> id_type::ProfileId {
+        id_type::ProfileId::try_from(std::borrow::Cow::from("pro_refund_limits")).unwrap()
+    }
+
+    fn merchant_id() -> id_type::MerchantId {
+        id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_refund_limits")).unwrap()
+    }
+
+    fn limits_request(
+        max_partial_refunds_per_payment: Option<u16>,
+        max_refund_amount_per_window: Option<i64>,
+    ) -> RefundLimitsConfigRequest {
+        RefundLimitsConfigRequest {
+            max_refund_amount_per_window: max_refund_amount_per_window.map(MinorUnit::new),
+            currency: max_refund_amount_per_window.map(|_| enums::Currency::USD),
+            window_size_in_seconds: max_refund_amount_per_window.map(|_| 3600),
+            max_partial_refunds_per_payment,
+            refund_window_days: None,
+            max_refund_amount_per_payment: None,
+            daily_refund_amount_limit: None,
+        }
+    }
+
+    fn amount_limits_request(
+        max_refund_amount_per_payment: Option<i64>,
+        daily_refund_amount_limit: Option<i64>,
+    ) -> RefundLimitsConfigRequest {
+        RefundLimitsConfigRequest {
+            currency: Some(enums::Currency::USD),
+            max_refund_amount_per_payment: max_refund_amount_per_payment.map(MinorUnit::new),
+            daily_refund_amount_limit: daily_refund_amount_limit.map(MinorUnit::new),
+            ..Default::default()
+        }
+    }
+
+    fn refund(refund_id: &str, refund_amount: i64) -> diesel_refund::Refund {
+        diesel_refund::Refund {
+            merchant_id: merchant_id(),
+            total_amount: MinorUnit::new(10000),
+            refund_amount: MinorUnit::new(refund_amount),
+            profile_id: Some(profile_id()),
+            ..test_utils::refund(refund_id)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_refund_limits_config_crud_round_trip() {
+        let mockdb = db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create a mock DB");
+        let db: &dyn db::StorageInterface = &mockdb;
+        let profile_id = profile_id();
+
+        assert!(find_refund_limits_config(db, &profile_id)
+            .await
+            .unwrap()
+            .is_none());
+
+        let created = insert_or_update_refund_limits_config(
+            db,
+            &profile_id,
+            limits_request(Some(3), Some(5000)),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            find_refund_limits_config(db, &profile_id).await.unwrap(),
+            Some(created.clone())
+        );
+
+        let updated =
+            insert_or_update_refund_limits_config(db, &profile_id, limits_request(Some(5), None))
+                .await
+                .unwrap();
+        assert_eq!(updated.created_at, created.created_at);
+        assert_eq!(updated.max_partial_refunds_per_payment, Some(5));
+        assert_eq!(updated.max_refund_amount_per_window, None);
+        assert_eq!(
+            find_refund_limits_config(db, &profile_id).await.unwrap(),
+            Some(updated)
+        );
+
+        db.delete_config_by_key(&profile_id.get_refund_limits_config_key())
+            .await
+            .unwrap();
+        assert!(find_refund_limits_config(db, &profile_id)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_refund_guards_follow_updated_limits() {
+        let mockdb = db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create a mock DB");
+        let db: &dyn db::StorageInterface = &mockdb;
+        let profile_id = profile_id();
+        let defaults = settings::Refund::default();
+        let refunds = [refund("ref_1", 1000), refund("ref_2", 1000)];
+
+        insert_or_update_refund_limits_config(db, &profile_id, limits_request(Some(1), Some(2500)))
+            .await
+            .unwrap();
+        let config = find_refund_limits_config(db, &profile_id).await.unwrap();
+        let limits = RefundLimits::new(&defaults, config.as_ref());
+        let velocity = limits.velocity.clone().unwrap();
+
+        assert!(
+            refunds_validator::validate_maximum_refund_against_payment_attempt(
+                &refunds,
+                limits.max_attempts
+            )
+            .is_err()
+        );
+        assert!(refunds_validator::validate_refund_velocity(
+            &refunds,
+            velocity.currency,
+            1000,
+            velocity.max_amount.get_amount_as_i64()
+        )
+        .is_err());
+
+        insert_or_update_refund_limits_config(db, &profile_id, limits_request(Some(5), Some(5000)))
+            .await
+            .unwrap();
+        let config = find_refund_limits_config(db, &profile_id).await.unwrap();
+        let limits = RefundLimits::new(&defaults, config.as_ref());
+        let velocity = limits.velocity.clone().unwrap();
+
+        assert!(
+            refunds_validator::validate_maximum_refund_against_payment_attempt(
+                &refunds,
+                limits.max_attempts
+            )
+            .is_ok()
+        );
+        assert!(refunds_validator::validate_refund_velocity(
+            &refunds,
+            velocity.currency,
+            1000,
+            velocity.max_amount.get_amount_as_i64()
+        )
+        .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_updated_refund_limits_replace_previously_read_limits() {
+        let mockdb = db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create a mock DB");
+        let db: &dyn db::StorageInterface = &mockdb;
+        let profile_id = profile_id();
+        let defaults = settings::Refund::default();
+
+        let limits = RefundLimits::new(
+            &defaults,
+            find_refund_limits_config(db, &profile_id)
+                .await
+                .unwrap()
+                .as_ref(),
+        );
+        assert_eq!(limits.max_attempts, defaults.max_attempts);
+        assert_eq!(limits.max_age, defaults.max_age);
+        assert!(limits.velocity.is_none());
+
+        insert_or_update_refund_limits_config(db, &profile_id, limits_request(Some(2), None))
+            .await
+            .unwrap();
+        let limits = RefundLimits::new(
+            &defaults,
+            find_refund_limits_config(db, &profile_id)
+                .await
+                .unwrap()
+                .as_ref(),
+        );
+        assert_eq!(limits.max_attempts, 2);
+
+        insert_or_update_refund_limits_config(db, &profile_id, limits_request(Some(4), None))
+            .await
+            .unwrap();
+        let limits = RefundLimits::new(
+            &defaults,
+            find_refund_limits_config(db, &profile_id)
+                .await
+                .unwrap()
+                .as_ref(),
+        );
+        assert_eq!(limits.max_attempts, 4);
+        assert_eq!(limits.max_age, defaults.max_age);
+    }
+
+    #[tokio::test]
+    async fn test_refund_amount_limit_per_payment_with_partial_refunds() {
+        let mockdb = db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create a mock DB");
+        let db: &dyn db::StorageInterface = &mockdb;
+        let profile_id = profile_id();
+
+        insert_or_update_refund_limits_config(
+            db,
+            &profile_id,
+            amount_limits_request(Some(5000), None),
+        )
+        .await
+        .unwrap();
+        let config = find_refund_limits_config(db, &profile_id).await.unwrap();
+        let limits = RefundLimits::new(&settings::Refund::default(), config.as_ref());
+        let amount_limits = limits.amount.unwrap();
+        let max_amount = amount_limits.max_amount_per_payment.unwrap();
+        assert!(amount_limits.daily_max_amount.is_none());
+        assert!(limits.velocity.is_none());
+
+        let mut refunds = Vec::new();
+        for refund_id in ["ref_1", "ref_2", "ref_3", "ref_4", "ref_5"] {
+            assert!(refunds_validator::validate_refund_amount_limit(
+                &refunds,
+                amount_limits.currency,
+                1000,
+                max_amount.get_amount_as_i64()
+            )
+            .is_ok());
+            refunds.push(refund(refund_id, 1000));
+        }
+
+        // The partial refunds add up to exactly the limit, so nothing more can be refunded
+        assert!(refunds_validator::validate_refund_amount_limit(
+            &refunds,
+            amount_limits.currency,
+            1,
+            max_amount.get_amount_as_i64()
+        )
+        .is_err());
+
+        // Failed refunds do not count towards the limit
+        refunds[0].refund_status = enums::RefundStatus::Failure;
+        assert!(refunds_validator::validate_refund_amount_limit(
+            &refunds,
+            amount_limits.currency,
+            1000,
+            max_amount.get_amount_as_i64()
+        )
+        .is_ok());
+        assert!(refunds_validator::validate_refund_amount_limit(
+            &refunds,
+            amount_limits.currency,
+            1001,
+            max_amount.get_amount_as_i64()
+        )
+        .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_daily_refund_amount_limit_counts_refunds_of_the_day() {
+        let mockdb = db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create a mock DB");
+        let db: &dyn db::StorageInterface = &mockdb;
+        let profile_id = profile_id();
+        let start_of_day = get_start_of_day(common_utils::date_time::now());
+
+        let mut refund_of_the_day = refund("ref_1", 3000);
+        refund_of_the_day.created_at = start_of_day;
+        let mut refund_of_the_previous_day = refund("ref_2", 5000);
+        refund_of_the_previous_day.created_at = start_of_day - time::Duration::hours(1);
+        let mut refund_in_another_currency = refund("ref_3", 2000);
+        refund_in_another_currency.currency = enums::Currency::EUR;
+        mockdb.refunds.lock().await.extend([
+            refund_of_the_day,
+            refund_of_the_previous_day,
+            refund_in_another_currency,
+        ]);
+
+        insert_or_update_refund_limits_config(
+            db,
+            &profile_id,
+            amount_limits_request(None, Some(5000)),
+        )
+        .await
+        .unwrap();
+        let config = find_refund_limits_config(db, &profile_id).await.unwrap();
+        let amount_limits = RefundLimits::new(&settings::Refund::default(), config.as_ref())
+            .amount
+            .unwrap();
+        let daily_max_amount = amount_limits.daily_max_amount.unwrap();
+
+        let refunds_of_the_day = db
+            .find_refunds_by_merchant_id_profile_id_created_after(
+                &merchant_id(),
+                &profile_id,
+                start_of_day,
+                enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+        assert_eq!(refunds_of_the_day.len(), 2);
+
+        assert!(refunds_validator::validate_refund_amount_limit(
+            &refunds_of_the_day,
+            amount_limits.currency,
+            2000,
+            daily_max_amount.get_amount_as_i64()
+        )
+        .is_ok());
+        assert!(refunds_validator::validate_refund_amount_limit(
+            &refunds_of_the_day,
+            amount_limits.currency,
+            2001,
+            daily_max_amount.get_amount_as_i64()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_refund_amount_limit_currency_must_match_refund_currency() {
+        assert!(refunds_validator::validate_refund_limit_currency(
+            enums::Currency::USD,
+            enums::Currency::USD
+        )
+        .is_ok());
+        assert!(refunds_validator::validate_refund_limit_currency(
+            enums::Currency::USD,
+            enums::Currency::EUR
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_refund_limits_config_validation() {
+        assert!(validate_refund_limits_config(&limits_request(Some(2), Some(1000))).is_ok());
+        assert!(validate_refund_limits_config(&limits_request(Some(0), None)).is_err());
+        assert!(validate_refund_limits_config(&limits_request(None, Some(0))).is_err());
+
+        let mut request = limits_request(None, Some(1000));
+        request.window_size_in_seconds = Some(0);
+        assert!(validate_refund_limits_config(&request).is_err());
+
+        request.window_size_in_seconds = None;
+        assert!(validate_refund_limits_config(&request).is_err());
+
+        let request = RefundLimitsConfigRequest {
+            refund_window_days: Some(-1),
+            ..Default::default()
+        };
+        assert!(validate_refund_limits_config(&request).is_err());
+
+        assert!(
+            validate_refund_limits_config(&amount_limits_request(Some(1000), Some(5000))).is_ok()
+        );
+        assert!(validate_refund_limits_config(&amount_limits_request(None, Some(0))).is_err());
+        assert!(validate_refund_limits_config(&amount_limits_request(None, None)).is_err());
+
+        let mut request = amount_limits_request(Some(1000), None);
+        request.currency = None;
+        assert!(validate_refund_limits_config(&request).is_err());
+    }
+}
@@ -0,0 +1,312 @@
+use api_models::enums::RefundErrorCategory;
+use common_utils::ext_traits::ValueExt;
+use diesel_models::refund as diesel_refund;
+use error_stack::ResultExt;
+use masking::PeekInterface;
+use router_env::logger;
+
+use crate::{
+    configs::settings,
+    core::{
+        errors::{self, RouterResult},
+        refunds,
+        utils::refunds_normalization,
+    },
+    db::StorageInterface,
+    types::{
+        self,
+        storage::{self, enums},
+    },
+};
+
+/// The key of the refund metadata holding the number of automatic retries of the refund
+pub const REFUND_RETRY_ATTEMPT_METADATA_KEY: &str = "refund_retry_attempt";
+
+/// The number of automatic retries of the refund so far, as recorded in its metadata
+pub fn get_refund_retry_attempt(refund: &diesel_refund::Refund) -> u32 {
+    refund
+        .metadata
+        .as_ref()
+        .and_then(|metadata| {
+            metadata
+                .peek()
+                .get(REFUND_RETRY_ATTEMPT_METADATA_KEY)
+                .cloned()
+        })
+        .and_then(|attempt| attempt.parse_value::<u32>("RefundRetryAttempt").ok())
+        .unwrap_or(0)
+}
+
+/// Whether the connector error is transient, so that the refund may succeed when retried. Server
+/// errors, rate limits and the errors classified as the connector being unavailable are transient,
+/// every other error is terminal.
+pub fn is_retriable_refund_error(connector: &str, error: &types::ErrorResponse) -> bool {
+    error.status_code >= 500
+        || error.status_code == 429
+        || refunds_normalization::classify_refund_error(
+            connector,
+            Some(&error.code),
+            Some(&error.message),
+        ) == RefundErrorCategory::ProcessorUnavailable
+}
+
+/// Whether the refund which failed with the connector error is to be retried instead of being
+/// marked as failed
+pub fn should_retry_refund(
+    config: &settings::RefundRetry,
+    refund: &diesel_refund::Refund,
+    connector: &str,
+    error: &types::ErrorResponse,
+) -> bool {
+    config.enabled
+        && get_refund_retry_attempt(refund) < config.max_attempts
+        && is_retriable_refund_error(connector, error)
+}
+
+/// Whether the refund is waiting for an automatic retry: it has been retried before and has not
+/// been accepted by the connector yet
+pub fn is_refund_retry_pending(refund: &diesel_refund::Refund) -> bool {
+    refund.refund_status == enums::RefundStatus::Pending
+        && !refund.sent_to_gateway
+        && get_refund_retry_attempt(refund) > 0
+}
+
+/// The delay before the given retry attempt, doubled for every attempt and capped at the
+/// configured maximum delay
+pub fn get_refund_retry_delay(config: &settings::RefundRetry, attempt: u32) -> time::Duration {
+    let multiplier = 2_i64.saturating_pow(attempt.saturating_sub(1));
+    time::Duration::seconds(
+        config
+            .base_delay_in_secs
+            .saturating_mul(multiplier)
+            .min(config.max_delay_in_secs),
+    )
+}
+
+/// Keeps the refund pending with the error of the failed attempt, so that it is retried instead
+/// of being marked as failed
+pub fn build_refund_retry_error_update(
+    error: &types::ErrorResponse,
+    storage_scheme: &enums::MerchantStorageScheme,
+) -> diesel_refund::RefundUpdate {
+    diesel_refund::RefundUpdate::ErrorUpdate {
+        refund_status: Some(enums::RefundStatus::Pending),
+        refund_error_message: error.reason.clone().or(Some(error.message.clone())),
+        refund_error_code: Some(error.code.clone()),
+        updated_by: storage_scheme.to_string(),
+        connector_refund_id: None,
+        processor_refund_data: None,
+        unified_code: None,
+        unified_message: None,
+        issuer_error_code: None,
+        issuer_error_message: None,
+    }
+}
+
+/// Records the retry attempt in the refund metadata, leaving the other keys as they are
+pub fn build_refund_retry_attempt_update(
+    attempt: u32,
+    storage_scheme: &enums::MerchantStorageScheme,
+) -> diesel_refund::RefundUpdate {
+    diesel_refund::RefundUpdate::MetadataMergeUpdate {
+        metadata: Some(masking::Secret::new(serde_json::json!({
+            REFUND_RETRY_ATTEMPT_METADATA_KEY: attempt,
+        }))),
+        reason: None,
+        updated_by: storage_scheme.to_string(),
+    }
+}
+
+/// Schedules the execution of the refund after the backoff delay of its retry attempt. The
+/// execute task of the refund is rescheduled when the retry is triggered by the task, and is
+/// created otherwise.
+pub async fn schedule_refund_retry(
+    db: &dyn StorageInterface,
+    config: &settings::RefundRetry,
+    refund: &diesel_refund::Refund,
+    refund_tracker: Option<&storage::ProcessTracker>,
+) -> RouterResult<()> {
+    let attempt = get_refund_retry_attempt(refund);
+    let schedule_time = common_utils::date_time::now() + get_refund_retry_delay(config, attempt);
+    logger::info!(
+        refund_id = %refund.refund_id,
+        attempt,
+        %schedule_time,
+        "Scheduling the retry of the refund"
+    );
+
+    match refund_tracker {
+        Some(refund_tracker) => db
+            .as_scheduler()
+            .retry_process(refund_tracker.clone(), schedule_time)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!(
+                    "Failed while rescheduling refund execute task: refund_id: {}",
+                    refund.refund_id
+                )
+            }),
+        None => refunds::add_refund_execute_task_at(
+            db,
+            refund,
+            storage::ProcessTrackerRunner::RefundWorkflowRouter,
+            schedule_time,
+        )
+        .await
+        .map(|_| ()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use super::*;
+    use crate::test_utils;
+
+    fn config() -> settings::RefundRetry {
+        settings::RefundRetry {
+            enabled: true,
+            max_attempts: 3,
+            base_delay_in_secs: 60,
+            max_delay_in_secs: 600,
+        }
+    }
+
+    fn error_response(status_code: u16, code: &str) -> types::ErrorResponse {
+        types::ErrorResponse {
+            code: code.to_string(),
+            status_code,
+            ..Default::default()
+        }
+    }
+
+    fn refund_with_attempt(attempt: Option<u32>) -> diesel_refund::Refund {
+        diesel_refund::Refund {
+            refund_status: enums::RefundStatus::Pending,
+            sent_to_gateway: false,
+            metadata: attempt.map(|attempt| {
+                masking::Secret::new(serde_json::json!({
+                    "order_id": "order_1",
+                    REFUND_RETRY_ATTEMPT_METADATA_KEY: attempt,
+                }))
+            }),
+            ..test_utils::refund("ref_1")
+        }
+    }
+
+    #[test]
+    fn transient_connector_errors_are_retriable() {
+        assert!(is_retriable_refund_error(
+            "stripe",
+            &error_response(500, "E")
+        ));
+        assert!(is_retriable_refund_error(
+            "stripe",
+            &error_response(503, "E")
+        ));
+        assert!(is_retriable_refund_error(
+            "stripe",
+            &error_response(429, "E")
+        ));
+        assert!(is_retriable_refund_error(
+            "stripe",
+            &error_response(400, crate::consts::REQUEST_TIMEOUT_ERROR_CODE)
+        ));
+    }
+
+    #[test]
+    fn client_errors_are_terminal() {
+        assert!(!is_retriable_refund_error(
+            "stripe",
+            &error_response(400, "E")
+        ));
+        assert!(!is_retriable_refund_error(
+            "stripe",
+            &error_response(401, "E")
+        ));
+        assert!(!is_retriable_refund_error(
+            "stripe",
+            &error_response(404, "E")
+        ));
+    }
+
+    #[test]
+    fn retry_delay_doubles_up_to_the_maximum() {
+        let config = config();
+        let delays = (1..=6)
+            .map(|attempt| get_refund_retry_delay(&config, attempt).whole_seconds())
+            .collect::<Vec<_>>();
+        assert_eq!(delays, vec![60, 120, 240, 480, 600, 600]);
+        assert_eq!(
+            get_refund_retry_delay(&config, u32::MAX).whole_seconds(),
+            600
+        );
+    }
+
+    #[test]
+    fn retry_attempt_is_read_from_the_metadata() {
+        assert_eq!(get_refund_retry_attempt(&refund_with_attempt(None)), 0);
+        assert_eq!(get_refund_retry_attempt(&refund_with_attempt(Some(2))), 2);
+    }
+
+    #[test]
+    fn refunds_are_retried_up_to_the_max_attempts() {
+        let config = config();
+        let error = error_response(502, "E");
+        assert!(should_retry_refund(
+            &config,
+            &refund_with_attempt(None),
+            "stripe",
+            &error
+        ));
+        assert!(should_retry_refund(
+            &config,
+            &refund_with_attempt(Some(2)),
+            "stripe",
+            &error
+        ));
+        assert!(!should_retry_refund(
+            &config,
+            &refund_with_attempt(Some(3)),
+            "stripe",
+            &error
+        ));
+    }
+
+    #[test]
+    fn refunds_are_not_retried_when_disabled_or_terminal() {
+        let disabled = settings::RefundRetry {
+            enabled: false,
+            ..config()
+        };
+        let refund = refund_with_attempt(None);
+        assert!(!should_retry_refund(
+            &disabled,
+            &refund,
+            "stripe",
+            &error_response(502, "E")
+        ));
+        assert!(!should_retry_refund(
+            &config(),
+            &refund,
+            "stripe",
+            &error_response(400, "E")
+        ));
+    }
+
+    #[test]
+    fn retry_is_pending_only_for_retried_refunds_not_sent_to_the_connector() {
+        assert!(!is_refund_retry_pending(&refund_with_attempt(None)));
+        assert!(is_refund_retry_pending(&refund_with_attempt(Some(1))));
+
+        let mut succeeded = refund_with_attempt(Some(1));
+        succeeded.refund_status = enums::RefundStatus::Success;
+        assert!(!is_refund_retry_pending(&succeeded));
+
+        let mut sent_to_gateway = refund_with_attempt(Some(1));
+        sent_to_gateway.sent_to_gateway = true;
+        assert!(!is_refund_retry_pending(&sent_to_gateway));
+    }
+}
@@ -1,18 +1,27 @@
-#[cfg(feature = "olap")]
-use std::collections::HashMap;
+use std::{
+    collections::{HashMap, HashSet},
+    future::Future,
+    sync::{LazyLock, Mutex},
+    time::Duration,
+};
 
 #[cfg(feature = "olap")]
 use api_models::admin::MerchantConnectorInfo;
 use common_utils::{
+    crypto::{self, GenerateDigest},
     ext_traits::AsyncExt,
-    types::{ConnectorTransactionId, MinorUnit},
+    types::{ConnectorTransactionId, ConnectorTransactionIdTrait, MinorUnit},
 };
 use diesel_models::{process_tracker::business_status, refund as diesel_refund};
 use error_stack::{report, ResultExt};
+use futures::StreamExt;
 use hyperswitch_domain_models::{
     router_data::ErrorResponse, router_request_types::SplitRefundsRequest,
 };
-use hyperswitch_interfaces::integrity::{CheckIntegrity, FlowIntegrity, GetIntegrityObject};
+use hyperswitch_interfaces::{
+    integrity::{CheckIntegrity, FlowIntegrity, GetIntegrityObject},
+    webhooks::ConnectorInitiatedRefundDetails,
+};
 use router_env::{instrument, tracing};
 use scheduler::{consumer::types::process_data, utils as process_tracker_utils};
 #[cfg(feature = "olap")]
@@ -23,13 +32,16 @@ use crate::{
     core::{
         errors::{self, ConnectorErrorExt, RouterResponse, RouterResult, StorageErrorExt},
         payments::{self, access_token, helpers},
+        refund_circuit_breaker, refund_limits, refund_retry,
         refunds::transformers::SplitRefundInput,
         utils::{
-            self as core_utils, refunds_transformers as transformers,
+            self as core_utils, refunds_normalization, refunds_transformers as transformers,
             refunds_validator as validator,
         },
     },
-    db, logger,
+    db,
+    events::domain_events,
+    logger,
     routes::{metrics, SessionState},
     services,
     types::{
@@ -51,6 +63,7 @@ pub async fn refund_create_core(
     merchant_context: domain::MerchantContext,
     _profile_id: Option<common_utils::id_type::ProfileId>,
     req: refunds::RefundRequest,
+    origin: enums::RefundOrigin,
 ) -> RouterResponse<refunds::RefundResponse> {
     let db = &*state.store;
     let (merchant_id, payment_intent, payment_attempt, amount);
@@ -127,6 +140,7 @@ pub async fn refund_create_core(
         amount,
         req,
         creds_identifier,
+        origin,
     ))
     .await
     .map(services::ApplicationResponse::Json)
@@ -155,11 +169,13 @@ pub async fn trigger_refund_to_gateway(
         router_env::metric_attributes!(("connector", routed_through.clone())),
     );
 
+    let merchant_connector_id =
+        core_utils::get_refund_processing_merchant_connector_id(refund, payment_attempt);
     let connector: api::ConnectorData = api::ConnectorData::get_connector_by_name(
         &state.conf.connectors,
         &routed_through,
         api::GetToken::Connector,
-        payment_attempt.merchant_connector_id.clone(),
+        merchant_connector_id.cloned(),
     )?;
 
     let currency = payment_attempt.currency.ok_or_else(|| {
@@ -170,6 +186,17 @@ pub async fn trigger_refund_to_gateway(
 
     validator::validate_for_valid_refunds(payment_attempt, connector.connector_name)?;
 
+    let circuit_breaker_state =
+        match refund_circuit_breaker::validate_refund_circuit_breaker(state, merchant_connector_id)
+            .await
+        {
+            Ok(circuit_breaker_state) => circuit_breaker_state,
+            Err(error) => {
+                fail_refund_suspended_by_circuit_breaker(state, refund, merchant_context).await?;
+                return Err(error);
+            }
+        };
+
     let mut router_data = core_utils::construct_refund_router_data(
         state,
         &routed_through,
@@ -217,49 +244,56 @@ pub async fn trigger_refund_to_gateway(
             None,
         )
         .await;
+        let connector_account_outcome = match &router_data_res {
+            Ok(router_data) => {
+                refund_circuit_breaker::ConnectorAccountOutcome::from_refund_response(
+                    &router_data.response,
+                )
+            }
+            Err(error) => refund_circuit_breaker::ConnectorAccountOutcome::from_connector_error(
+                error.current_context(),
+            ),
+        };
+        refund_circuit_breaker::record_refund_outcome(
+            state,
+            merchant_connector_id,
+            circuit_breaker_state.as_ref(),
+            connector_account_outcome,
+        )
+        .await;
         let option_refund_error_update =
             router_data_res
                 .as_ref()
                 .err()
                 .and_then(|error| match error.current_context() {
-                    errors::ConnectorError::NotImplemented(message) => {
-                        Some(diesel_refund::RefundUpdate::ErrorUpdate {
-                            refund_status: Some(enums::RefundStatus::Failure),
-                            refund_error_message: Some(
+                    errors::ConnectorError::NotImplemented(message) => Some(
+                        diesel_refund::RefundUpdate::build_error_update_for_refund_failure(
+                            Some(enums::RefundStatus::Failure),
+                            Some(
                                 errors::ConnectorError::NotImplemented(message.to_owned())
                                     .to_string(),
                             ),
-                            refund_error_code: Some("NOT_IMPLEMENTED".to_string()),
-                            updated_by: storage_scheme.to_string(),
-                            connector_refund_id: None,
-                            processor_refund_data: None,
-                            unified_code: None,
-                            unified_message: None,
-                            issuer_error_code: None,
-                            issuer_error_message: None,
-                        })
-                    }
-                    errors::ConnectorError::NotSupported { message, connector } => {
-                        Some(diesel_refund::RefundUpdate::ErrorUpdate {
-                            refund_status: Some(enums::RefundStatus::Failure),
-                            refund_error_message: Some(format!(
-                                "{message} is not supported by {connector}"
-                            )),
-                            refund_error_code: Some("NOT_SUPPORTED".to_string()),
-                            updated_by: storage_scheme.to_string(),
-                            connector_refund_id: None,
-                            processor_refund_data: None,
-                            unified_code: None,
-                            unified_message: None,
-                            issuer_error_code: None,
-                            issuer_error_message: None,
-                        })
-                    }
+                            Some(consts::REFUND_NOT_IMPLEMENTED_ERROR_CODE.to_string()),
+                            None,
+                            None,
+                            &storage_scheme,
+                        ),
+                    ),
+                    errors::ConnectorError::NotSupported { message, connector } => Some(
+                        diesel_refund::RefundUpdate::build_error_update_for_refund_failure(
+                            Some(enums::RefundStatus::Failure),
+                            Some(format!("{message} is not supported by {connector}")),
+                            Some(consts::REFUND_NOT_SUPPORTED_ERROR_CODE.to_string()),
+                            None,
+                            None,
+                            &storage_scheme,
+                        ),
+                    ),
                     _ => None,
                 });
         // Update the refund status as failure if connector_error is NotImplemented
         if let Some(refund_error_update) = option_refund_error_update {
-            state
+            let updated_refund = state
                 .store
                 .update_refund(
                     refund.to_owned(),
@@ -274,6 +308,7 @@ pub async fn trigger_refund_to_gateway(
                         refund.refund_id
                     )
                 })?;
+            domain_events::emit_refund_status_changed(state, refund, &updated_refund);
         }
         let mut refund_router_data_res = router_data_res.to_refund_failed_response()?;
         // Initiating Integrity check
@@ -284,10 +319,33 @@ pub async fn trigger_refund_to_gateway(
         refund_router_data_res.integrity_check = integrity_result;
         refund_router_data_res
     } else {
+        // The access token of the connector account could not be obtained
+        refund_circuit_breaker::record_refund_outcome(
+            state,
+            merchant_connector_id,
+            circuit_breaker_state.as_ref(),
+            refund_circuit_breaker::ConnectorAccountOutcome::from_refund_response(
+                &router_data.response,
+            ),
+        )
+        .await;
         router_data
     };
 
+    // Transient connector errors keep the refund pending, for it to be executed again
+    let is_refund_retried = router_data_res.response.as_ref().err().is_some_and(|err| {
+        refund_retry::should_retry_refund(
+            &state.conf.refund_retry,
+            refund,
+            &connector.connector_name.to_string(),
+            err,
+        )
+    });
+
     let refund_update = match router_data_res.response {
+        Err(err) if is_refund_retried => {
+            refund_retry::build_refund_retry_error_update(&err, &storage_scheme)
+        }
         Err(err) => {
             let option_gsm = helpers::get_gsm_record(
                 state,
@@ -321,35 +379,29 @@ pub async fn trigger_refund_to_gateway(
             {
                 (code.to_owned(), message.to_owned())
             } else {
-                (
-                    consts::DEFAULT_UNIFIED_ERROR_CODE.to_owned(),
-                    consts::DEFAULT_UNIFIED_ERROR_MESSAGE.to_owned(),
+                // Errors without a unified error configured are unified by their failure category
+                refunds_normalization::get_refund_failure_unified_error(
+                    refunds_normalization::classify_refund_error(
+                        &connector.connector_name.to_string(),
+                        Some(&err.code),
+                        Some(&err.message),
+                    ),
                 )
             };
 
-            diesel_refund::RefundUpdate::ErrorUpdate {
-                refund_status: Some(enums::RefundStatus::Failure),
-                refund_error_message: err.reason.or(Some(err.message)),
-                refund_error_code: Some(err.code),
-                updated_by: storage_scheme.to_string(),
-                connector_refund_id: None,
-                processor_refund_data: None,
-                unified_code: Some(unified_code),
-                unified_message: Some(unified_message),
-                issuer_error_code: err.network_decline_code,
-                issuer_error_message: err.network_error_message,
-            }
+            diesel_refund::RefundUpdate::build_error_update_for_unified_error_and_message(
+                (unified_code, unified_message),
+                err.reason.or(Some(err.message)),
+                Some(err.code),
+                err.network_decline_code,
+                err.network_error_message,
+                &storage_scheme,
+            )
         }
         Ok(response) => {
             // match on connector integrity checks
             match router_data_res.integrity_check.clone() {
                 Err(err) => {
-                    let (refund_connector_transaction_id, processor_refund_data) =
-                        err.connector_transaction_id.map_or((None, None), |txn_id| {
-                            let (refund_id, refund_data) =
-                                ConnectorTransactionId::form_id_and_data(txn_id);
-                            (Some(refund_id), refund_data)
-                        });
                     metrics::INTEGRITY_CHECK_FAILED.add(
                         1,
                         router_env::metric_attributes!(
@@ -360,21 +412,11 @@ pub async fn trigger_refund_to_gateway(
                             ),
                         ),
                     );
-                    diesel_refund::RefundUpdate::ErrorUpdate {
-                        refund_status: Some(enums::RefundStatus::ManualReview),
-                        refund_error_message: Some(format!(
-                            "Integrity Check Failed! as data mismatched for fields {}",
-                            err.field_names
-                        )),
-                        refund_error_code: Some("IE".to_string()),
-                        updated_by: storage_scheme.to_string(),
-                        connector_refund_id: refund_connector_transaction_id,
-                        processor_refund_data,
-                        unified_code: None,
-                        unified_message: None,
-                        issuer_error_code: None,
-                        issuer_error_message: None,
-                    }
+                    diesel_refund::RefundUpdate::build_error_update_for_integrity_check_failure(
+                        err.field_names,
+                        err.connector_transaction_id,
+                        &storage_scheme,
+                    )
                 }
                 Ok(()) => {
                     if response.refund_status == diesel_models::enums::RefundStatus::Success {
@@ -386,17 +428,11 @@ pub async fn trigger_refund_to_gateway(
                             )),
                         )
                     }
-                    let (connector_refund_id, processor_refund_data) =
-                        ConnectorTransactionId::form_id_and_data(response.connector_refund_id);
-                    diesel_refund::RefundUpdate::Update {
-                        connector_refund_id,
-                        refund_status: response.refund_status,
-                        sent_to_gateway: true,
-                        refund_error_message: None,
-                        refund_arn: "".to_string(),
-                        updated_by: storage_scheme.to_string(),
-                        processor_refund_data,
-                    }
+                    diesel_refund::RefundUpdate::build_refund_update(
+                        response.connector_refund_id,
+                        response.refund_status,
+                        &storage_scheme,
+                    )
                 }
             }
         }
@@ -417,6 +453,29 @@ pub async fn trigger_refund_to_gateway(
                 refund.refund_id
             )
         })?;
+    let response = if is_refund_retried {
+        state
+            .store
+            .update_refund(
+                response,
+                refund_retry::build_refund_retry_attempt_update(
+                    refund_retry::get_refund_retry_attempt(refund) + 1,
+                    &storage_scheme,
+                ),
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!(
+                    "Failed while recording the retry attempt of refund: refund_id: {}",
+                    refund.refund_id
+                )
+            })?
+    } else {
+        response
+    };
+    domain_events::emit_refund_status_changed(state, refund, &response);
     utils::trigger_refund_outgoing_webhook(
         state,
         merchant_context,
@@ -429,6 +488,37 @@ pub async fn trigger_refund_to_gateway(
     Ok(response)
 }
 
+/// Marks a refund as failed without sending it to the connector, as the refunds of its merchant
+/// connector account are suspended by the refund circuit breaker
+async fn fail_refund_suspended_by_circuit_breaker(
+    state: &SessionState,
+    refund: &diesel_refund::Refund,
+    merchant_context: &domain::MerchantContext,
+) -> RouterResult<()> {
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+    let refund_error_update = diesel_refund::RefundUpdate::build_error_update_for_refund_failure(
+        Some(enums::RefundStatus::Failure),
+        Some("Refunds temporarily suspended for this connector account".to_string()),
+        Some(consts::REFUND_SUSPENDED_ERROR_CODE.to_string()),
+        None,
+        None,
+        &storage_scheme,
+    );
+    let updated_refund = state
+        .store
+        .update_refund(refund.to_owned(), refund_error_update, storage_scheme)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while updating refund: refund_id: {}",
+                refund.refund_id
+            )
+        })?;
+    domain_events::emit_refund_status_changed(state, refund, &updated_refund);
+    Ok(())
+}
+
 pub fn check_refund_integrity<T, Request>(
     request: &Request,
     refund_response_data: &Result<types::RefundsResponseData, ErrorResponse>,
@@ -595,7 +685,7 @@ pub async fn sync_refund_with_gateway(
         &state.conf.connectors,
         &connector_id,
         api::GetToken::Connector,
-        payment_attempt.merchant_connector_id.clone(),
+        core_utils::get_refund_processing_merchant_connector_id(refund, payment_attempt).cloned(),
     )
     .change_context(errors::ApiErrorResponse::InternalServerError)
     .attach_printable("Failed to get the connector")?;
@@ -604,6 +694,22 @@ pub async fn sync_refund_with_gateway(
 
     let currency = payment_attempt.currency.get_required_value("currency")?;
 
+    // A connector cannot sync a refund by its hashed IDs, so the sync is failed before calling it
+    // when the original IDs are missing
+    if let Err(error) = refund
+        .get_connector_transaction_id_checked()
+        .and(refund.get_optional_connector_refund_id_checked())
+    {
+        logger::warn!(
+            refund_id = %refund.refund_id,
+            hashed_id = %error.hashed_id,
+            "Processor data is missing for the hashed connector ID of the refund"
+        );
+        return Err(report!(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to get the connector IDs to sync the refund with"));
+    }
+
     let mut router_data = core_utils::construct_refund_router_data::<api::RSync>(
         state,
         &connector_id,
@@ -673,18 +779,14 @@ pub async fn sync_refund_with_gateway(
                 200..=299 => Some(enums::RefundStatus::Failure),
                 _ => None,
             };
-            diesel_refund::RefundUpdate::ErrorUpdate {
+            diesel_refund::RefundUpdate::build_error_update_for_refund_failure(
                 refund_status,
-                refund_error_message: error_message.reason.or(Some(error_message.message)),
-                refund_error_code: Some(error_message.code),
-                updated_by: storage_scheme.to_string(),
-                connector_refund_id: None,
-                processor_refund_data: None,
-                unified_code: None,
-                unified_message: None,
-                issuer_error_code: error_message.network_decline_code,
-                issuer_error_message: error_message.network_error_message,
-            }
+                error_message.reason.or(Some(error_message.message)),
+                Some(error_message.code),
+                error_message.network_decline_code,
+                error_message.network_error_message,
+                &storage_scheme,
+            )
         }
         Ok(response) => match router_data_res.integrity_check.clone() {
             Err(err) => {
@@ -698,42 +800,17 @@ pub async fn sync_refund_with_gateway(
                         ),
                     ),
                 );
-                let (refund_connector_transaction_id, processor_refund_data) = err
-                    .connector_transaction_id
-                    .map_or((None, None), |refund_id| {
-                        let (refund_id, refund_data) =
-                            ConnectorTransactionId::form_id_and_data(refund_id);
-                        (Some(refund_id), refund_data)
-                    });
-                diesel_refund::RefundUpdate::ErrorUpdate {
-                    refund_status: Some(enums::RefundStatus::ManualReview),
-                    refund_error_message: Some(format!(
-                        "Integrity Check Failed! as data mismatched for fields {}",
-                        err.field_names
-                    )),
-                    refund_error_code: Some("IE".to_string()),
-                    updated_by: storage_scheme.to_string(),
-                    connector_refund_id: refund_connector_transaction_id,
-                    processor_refund_data,
-                    unified_code: None,
-                    unified_message: None,
-                    issuer_error_code: None,
-                    issuer_error_message: None,
-                }
-            }
-            Ok(()) => {
-                let (connector_refund_id, processor_refund_data) =
-                    ConnectorTransactionId::form_id_and_data(response.connector_refund_id);
-                diesel_refund::RefundUpdate::Update {
-                    connector_refund_id,
-                    refund_status: response.refund_status,
-                    sent_to_gateway: true,
-                    refund_error_message: None,
-                    refund_arn: "".to_string(),
-                    updated_by: storage_scheme.to_string(),
-                    processor_refund_data,
-                }
+                diesel_refund::RefundUpdate::build_error_update_for_integrity_check_failure(
+                    err.field_names,
+                    err.connector_transaction_id,
+                    &storage_scheme,
+                )
             }
+            Ok(()) => diesel_refund::RefundUpdate::build_refund_update(
+                response.connector_refund_id,
+                response.refund_status,
+                &storage_scheme,
+            ),
         },
     };
 
@@ -752,6 +829,7 @@ pub async fn sync_refund_with_gateway(
                 refund.refund_id
             )
         })?;
+    domain_events::emit_refund_status_changed(state, refund, &response);
     utils::trigger_refund_outgoing_webhook(
         state,
         merchant_context,
@@ -784,7 +862,7 @@ pub async fn refund_update_core(
     let response = db
         .update_refund(
             refund,
-            diesel_refund::RefundUpdate::MetadataAndReasonUpdate {
+            diesel_refund::RefundUpdate::MetadataMergeUpdate {
                 metadata: req.metadata,
                 reason: req.reason,
                 updated_by: merchant_context
@@ -805,6 +883,97 @@ pub async fn refund_update_core(
 
 // ********************************************** VALIDATIONS **********************************************
 
+/// Resolves the merchant connector account through which a refund of the payment attempt is
+/// processed. Payment attempts which do not record their merchant connector account fall back to
+/// the connector account of the attempt's connector configured on the profile.
+#[instrument(skip_all)]
+pub async fn get_refund_merchant_connector_id(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<Option<common_utils::id_type::MerchantConnectorAccountId>> {
+    if let Some(merchant_connector_id) = &payment_attempt.merchant_connector_id {
+        return Ok(Some(merchant_connector_id.clone()));
+    }
+
+    let Some(connector) = payment_attempt.connector.as_deref() else {
+        return Ok(None);
+    };
+
+    match state
+        .store
+        .find_merchant_connector_account_by_profile_id_connector_name(
+            &state.into(),
+            &payment_attempt.profile_id,
+            connector,
+            key_store,
+        )
+        .await
+    {
+        Ok(merchant_connector_account) => Ok(Some(merchant_connector_account.get_id())),
+        Err(error) if error.current_context().is_db_not_found() => {
+            logger::warn!(
+                attempt_id = %payment_attempt.attempt_id,
+                "Unable to resolve the merchant connector account for the refund"
+            );
+            Ok(None)
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the merchant connector account for the refund"),
+    }
+}
+
+/// Resolves the merchant connector account requested in the refund request to process the refund
+/// through instead of the payment's merchant connector account
+#[instrument(skip_all)]
+async fn get_refund_merchant_connector_id_override(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_connector: &str,
+    merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+) -> RouterResult<common_utils::id_type::MerchantConnectorAccountId> {
+    let merchant_connector_account = state
+        .store
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            &state.into(),
+            merchant_context.get_merchant_account().get_id(),
+            &merchant_connector_id,
+            merchant_context.get_merchant_key_store(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_connector_id.get_string_repr().to_string(),
+        })?;
+
+    validate_refund_merchant_connector_account(
+        &merchant_connector_account.connector_name,
+        merchant_connector_account.disabled,
+        payment_connector,
+    )?;
+
+    Ok(merchant_connector_account.get_id())
+}
+
+fn validate_refund_merchant_connector_account(
+    connector_name: &str,
+    disabled: Option<bool>,
+    payment_connector: &str,
+) -> RouterResult<()> {
+    utils::when(connector_name != payment_connector, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "merchant_connector_id must belong to the connector `{payment_connector}` the payment was processed through"
+            ),
+        }))
+    })?;
+    utils::when(disabled.unwrap_or(false), || {
+        Err(report!(
+            errors::ApiErrorResponse::MerchantConnectorAccountDisabled
+        ))
+    })
+}
+
 #[instrument(skip_all)]
 #[allow(clippy::too_many_arguments)]
 pub async fn validate_and_create_refund(
@@ -815,6 +984,7 @@ pub async fn validate_and_create_refund(
     refund_amount: MinorUnit,
     req: refunds::RefundRequest,
     creds_identifier: Option<String>,
+    origin: enums::RefundOrigin,
 ) -> RouterResult<refunds::RefundResponse> {
     let db = &*state.store;
     let split_refunds = core_utils::get_split_refunds(SplitRefundInput {
@@ -860,14 +1030,14 @@ pub async fn validate_and_create_refund(
 
     let currency = payment_attempt.currency.get_required_value("currency")?;
 
+    let refund_limits =
+        refund_limits::get_refund_limits(state, payment_intent.profile_id.as_ref()).await?;
+
     //[#249]: Add Connector Based Validation here.
-    validator::validate_payment_order_age(&payment_intent.created_at, state.conf.refund.max_age)
+    validator::validate_payment_order_age(&payment_intent.created_at, refund_limits.max_age)
         .change_context(errors::ApiErrorResponse::InvalidDataFormat {
             field_name: "created_at".to_string(),
-            expected_format: format!(
-                "created_at not older than {} days",
-                state.conf.refund.max_age,
-            ),
+            expected_format: format!("created_at not older than {} days", refund_limits.max_age),
         })?;
 
     let total_amount_captured = payment_intent
@@ -883,15 +1053,70 @@ pub async fn validate_and_create_refund(
 
     validator::validate_maximum_refund_against_payment_attempt(
         &all_refunds,
-        state.conf.refund.max_attempts,
+        refund_limits.max_attempts,
     )
     .change_context(errors::ApiErrorResponse::MaximumRefundCount)?;
 
+    if let (Some(velocity), Some(profile_id)) =
+        (&refund_limits.velocity, payment_intent.profile_id.as_ref())
+    {
+        refund_limits::validate_refund_velocity(
+            state,
+            merchant_context,
+            profile_id,
+            velocity,
+            currency,
+            refund_amount,
+        )
+        .await?;
+    }
+
+    if let (Some(amount_limits), Some(profile_id)) =
+        (&refund_limits.amount, payment_intent.profile_id.as_ref())
+    {
+        refund_limits::validate_refund_amount_limits(
+            state,
+            merchant_context,
+            &payment_intent.payment_id,
+            profile_id,
+            amount_limits,
+            currency,
+            refund_amount,
+        )
+        .await?;
+    }
+
     let connector = payment_attempt
         .connector
         .clone()
         .ok_or(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("No connector populated in payment attempt")?;
+    let merchant_connector_id = match req.merchant_connector_id {
+        Some(merchant_connector_id) => {
+            let merchant_connector_id = get_refund_merchant_connector_id_override(
+                state,
+                merchant_context,
+                &connector,
+                merchant_connector_id,
+            )
+            .await?;
+            logger::info!(
+                refund_id = %refund_id,
+                merchant_connector_id = %merchant_connector_id.get_string_repr(),
+                payment_merchant_connector_id = ?payment_attempt.merchant_connector_id,
+                "Refund is processed through the requested merchant connector account"
+            );
+            Some(merchant_connector_id)
+        }
+        None => {
+            get_refund_merchant_connector_id(
+                state,
+                merchant_context.get_merchant_key_store(),
+                payment_attempt,
+            )
+            .await?
+        }
+    };
     let (connector_transaction_id, processor_transaction_data) =
         ConnectorTransactionId::form_id_and_data(connector_transaction_id);
     let refund_create_req = diesel_refund::RefundNew {
@@ -914,7 +1139,7 @@ pub async fn validate_and_create_refund(
         attempt_id: payment_attempt.attempt_id.clone(),
         refund_reason: req.reason,
         profile_id: payment_intent.profile_id.clone(),
-        merchant_connector_id: payment_attempt.merchant_connector_id.clone(),
+        merchant_connector_id,
         charges: None,
         split_refunds: req.split_refunds,
         connector_refund_id: None,
@@ -927,6 +1152,7 @@ pub async fn validate_and_create_refund(
             .clone(),
         processor_transaction_data,
         processor_refund_data: None,
+        origin,
     };
 
     let refund = match db
@@ -937,6 +1163,7 @@ pub async fn validate_and_create_refund(
         .await
     {
         Ok(refund) => {
+            domain_events::emit_refund_created(state, &refund);
             Box::pin(schedule_refund_execution(
                 state,
                 refund.clone(),
@@ -1009,6 +1236,17 @@ pub async fn refund_list(
         .await
         .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
 
+    // A full page may be followed by more refunds, listed from the position of its last refund
+    let next_cursor = refund_list
+        .last()
+        .filter(|_| i64::try_from(refund_list.len()).is_ok_and(|count| count == limit))
+        .map(|refund| {
+            hyperswitch_domain_models::refunds::RefundListCursor {
+                created_at: refund.created_at,
+                refund_id: refund.refund_id.clone(),
+            }
+            .encode()
+        });
     let data: Vec<refunds::RefundResponse> = refund_list
         .into_iter()
         .map(ForeignInto::foreign_into)
@@ -1028,10 +1266,286 @@ pub async fn refund_list(
             count: data.len(),
             total_count,
             data,
+            next_cursor,
         },
     ))
 }
 
+/// Exports the refunds matching the refunds list filters as a file, with the requested columns
+#[instrument(skip_all)]
+#[cfg(feature = "olap")]
+pub async fn refund_export(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
+    req: api_models::refunds::RefundExportRequest,
+) -> RouterResponse<serde_json::Value> {
+    let fields = validator::validate_refund_export_fields(req.fields.as_deref())?;
+    let limit = validator::validate_refund_list(req.filters.limit)?;
+    let offset = req.filters.offset.unwrap_or_default();
+
+    let refund_list = state
+        .store
+        .filter_refund_by_constraints(
+            merchant_context.get_merchant_account().get_id(),
+            &(req.filters, profile_id_list).try_into()?,
+            merchant_context.get_merchant_account().storage_scheme,
+            limit,
+            offset,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
+
+    let (export, content_type) = match req.format {
+        api_models::refunds::RefundExportFormat::Csv => (
+            write_refunds_csv_export(&refund_list, &fields)?,
+            mime::TEXT_CSV,
+        ),
+        api_models::refunds::RefundExportFormat::Jsonl => (
+            write_refunds_jsonl_export(&refund_list, &fields)?,
+            consts::JSONL_CONTENT_TYPE
+                .parse::<mime::Mime>()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse the JSONL content type")?,
+        ),
+    };
+
+    Ok(services::ApplicationResponse::FileData((
+        export,
+        content_type,
+    )))
+}
+
+/// Value of a column of the refunds export, `null` if the refund has no value for the column
+#[cfg(feature = "olap")]
+fn get_refund_export_value(
+    refund: &diesel_refund::Refund,
+    field: api_models::refunds::RefundExportField,
+) -> serde_json::Value {
+    use api_models::refunds::RefundExportField;
+
+    let optional = |value: Option<&str>| value.map_or(serde_json::Value::Null, Into::into);
+    let formatted_amount = |amount| {
+        optional(
+            transformers::get_formatted_refund_amount(amount, refund.currency)
+                .as_ref()
+                .map(common_utils::types::StringMajorUnit::get_amount_as_string)
+                .as_deref(),
+        )
+    };
+    let timestamp = |date_time| {
+        common_utils::custom_serde::iso8601::serialize(date_time, serde_json::value::Serializer)
+            .unwrap_or_default()
+    };
+
+    match field {
+        RefundExportField::RefundId => refund.refund_id.as_str().into(),
+        RefundExportField::PaymentId => refund.payment_id.get_string_repr().into(),
+        RefundExportField::ProfileId => optional(
+            refund
+                .profile_id
+                .as_ref()
+                .map(common_utils::id_type::ProfileId::get_string_repr),
+        ),
+        RefundExportField::Connector => refund.connector.as_str().into(),
+        RefundExportField::MerchantConnectorId => optional(
+            refund
+                .merchant_connector_id
+                .as_ref()
+                .map(common_utils::id_type::MerchantConnectorAccountId::get_string_repr),
+        ),
+        RefundExportField::ConnectorRefundId => optional(
+            refund
+                .get_optional_connector_refund_id()
+                .map(String::as_str),
+        ),
+        RefundExportField::RefundArn => optional(refund.refund_arn.as_deref()),
+        RefundExportField::Status => serde_json::to_value(api_models::refunds::RefundStatus::from(
+            refund.refund_status,
+        ))
+        .unwrap_or_default(),
+        RefundExportField::Origin => refund.origin.to_string().into(),
+        RefundExportField::Currency => refund.currency.to_string().into(),
+        RefundExportField::Amount => refund.refund_amount.get_amount_as_i64().into(),
+        RefundExportField::RefundAmountFormatted => formatted_amount(refund.refund_amount),
+        RefundExportField::TotalAmount => refund.total_amount.get_amount_as_i64().into(),
+        RefundExportField::TotalAmountFormatted => formatted_amount(refund.total_amount),
+        RefundExportField::Reason => optional(refund.refund_reason.as_deref()),
+        RefundExportField::ErrorCode => optional(refund.refund_error_code.as_deref()),
+        RefundExportField::ErrorMessage => optional(refund.refund_error_message.as_deref()),
+        RefundExportField::IssuerErrorCode => optional(refund.issuer_error_code.as_deref()),
+        RefundExportField::IssuerErrorMessage => optional(refund.issuer_error_message.as_deref()),
+        RefundExportField::UnifiedCode => optional(refund.unified_code.as_deref()),
+        RefundExportField::UnifiedMessage => optional(refund.unified_message.as_deref()),
+        RefundExportField::CreatedAt => timestamp(&refund.created_at),
+        RefundExportField::UpdatedAt => timestamp(&refund.modified_at),
+    }
+}
+
+/// Writes the refunds as CSV, with a header row naming the columns. Values are quoted by the
+/// writer wherever required, so free text fields may contain commas, quotes and newlines.
+#[cfg(feature = "olap")]
+fn write_refunds_csv_export(
+    refunds: &[diesel_refund::Refund],
+    fields: &[api_models::refunds::RefundExportField],
+) -> RouterResult<Vec<u8>> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer
+        .write_record(fields.iter().map(ToString::to_string))
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to write the header of the refunds export")?;
+
+    for refund in refunds {
+        writer
+            .write_record(fields.iter().map(|field| {
+                match get_refund_export_value(refund, *field) {
+                    serde_json::Value::Null => String::new(),
+                    serde_json::Value::String(value) => value,
+                    value => value.to_string(),
+                }
+            }))
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to write a refund to the refunds export")?;
+    }
+
+    writer
+        .into_inner()
+        .map_err(|error| error.into_error())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to flush the refunds export")
+}
+
+/// A refund with only the columns of the export, serialized in the order of the columns
+#[cfg(feature = "olap")]
+struct RefundExportRow<'a> {
+    refund: &'a diesel_refund::Refund,
+    fields: &'a [api_models::refunds::RefundExportField],
+}
+
+#[cfg(feature = "olap")]
+impl serde::Serialize for RefundExportRow<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        let mut row = serializer.serialize_map(Some(self.fields.len()))?;
+        for field in self.fields {
+            row.serialize_entry(field, &get_refund_export_value(self.refund, *field))?;
+        }
+        row.end()
+    }
+}
+
+/// Writes every refund as a JSON object on its own line
+#[cfg(feature = "olap")]
+fn write_refunds_jsonl_export(
+    refunds: &[diesel_refund::Refund],
+    fields: &[api_models::refunds::RefundExportField],
+) -> RouterResult<Vec<u8>> {
+    let mut export = Vec::new();
+
+    for refund in refunds {
+        serde_json::to_writer(&mut export, &RefundExportRow { refund, fields })
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to write a refund to the refunds export")?;
+        export.push(b'\n');
+    }
+
+    Ok(export)
+}
+
+/// Lists the refunds of the merchant modified at or after the requested time, ordered by
+/// modification time and refund id, for incremental syncs. Every response carries a cursor
+/// positioned after its last refund, so that refunds updated after being synced are returned
+/// again when syncing from that cursor later.
+#[instrument(skip_all)]
+#[cfg(feature = "olap")]
+pub async fn refund_list_modified_since(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    req: api_models::refunds::RefundsModifiedSinceRequest,
+) -> RouterResponse<api_models::refunds::RefundsModifiedSinceResponse> {
+    let limit = validator::validate_refund_list(req.limit)?;
+    let cursor = req
+        .cursor
+        .as_deref()
+        .map(|cursor| {
+            transformers::RefundsModifiedSinceCursor::decode(cursor).ok_or_else(|| {
+                report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "cursor is invalid".to_string(),
+                })
+            })
+        })
+        .transpose()?;
+
+    let response = list_refunds_modified_since_page(
+        &*state.store,
+        merchant_context.get_merchant_account().get_id(),
+        merchant_context.get_merchant_account().storage_scheme,
+        req.since,
+        cursor,
+        limit,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+#[cfg(feature = "olap")]
+async fn list_refunds_modified_since_page(
+    db: &dyn db::StorageInterface,
+    merchant_id: &common_utils::id_type::MerchantId,
+    storage_scheme: enums::MerchantStorageScheme,
+    since: Option<time::PrimitiveDateTime>,
+    cursor: Option<transformers::RefundsModifiedSinceCursor>,
+    limit: i64,
+) -> RouterResult<api_models::refunds::RefundsModifiedSinceResponse> {
+    let (modified_since, starting_after) = match &cursor {
+        Some(cursor) => (cursor.modified_at, Some(cursor.refund_id.as_str())),
+        None => (
+            since.unwrap_or_else(|| {
+                common_utils::date_time::convert_to_pdt(time::OffsetDateTime::UNIX_EPOCH)
+            }),
+            None,
+        ),
+    };
+
+    // One more refund than the limit is fetched to find out if there are more refunds
+    let mut refunds_page = db
+        .list_refunds_modified_since(
+            merchant_id,
+            modified_since,
+            limit + 1,
+            starting_after,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch refunds modified since the given time")?;
+    let page_size = usize::try_from(limit).unwrap_or_default();
+    let has_more = refunds_page.len() > page_size;
+    refunds_page.truncate(page_size);
+
+    let next_cursor = refunds_page
+        .last()
+        .map(|refund| transformers::RefundsModifiedSinceCursor {
+            modified_at: refund.modified_at,
+            refund_id: refund.refund_id.clone(),
+        })
+        .or(cursor);
+    let data: Vec<refunds::RefundResponse> = refunds_page
+        .into_iter()
+        .map(ForeignInto::foreign_into)
+        .collect();
+
+    Ok(api_models::refunds::RefundsModifiedSinceResponse {
+        count: data.len(),
+        data,
+        has_more,
+        high_watermark: next_cursor.as_ref().map(|cursor| cursor.modified_at),
+        next_cursor: next_cursor.map(|cursor| cursor.encode()),
+    })
+}
+
 #[instrument(skip_all)]
 #[cfg(feature = "olap")]
 pub async fn refund_filter_list(
@@ -1118,32 +1632,409 @@ pub async fn refund_retrieve_core_with_refund_id(
     .await
 }
 
+/// Retrieves a refund, waiting up to `wait_seconds` for it to reach a terminal status
+///
+/// The refund is re-read at a short interval and synced with the connector at a longer one. No
+/// database transaction is held open across the wait, and the number of requests of a merchant
+/// waiting at once is bounded.
 #[instrument(skip_all)]
-#[cfg(feature = "olap")]
-pub async fn refund_manual_update(
+pub async fn refund_retrieve_with_wait(
     state: SessionState,
-    req: api_models::refunds::RefundManualUpdateRequest,
-) -> RouterResponse<serde_json::Value> {
-    let key_manager_state = &(&state).into();
-    let key_store = state
-        .store
-        .get_merchant_key_store_by_merchant_id(
-            key_manager_state,
-            &req.merchant_id,
-            &state.store.get_master_key().to_vec().into(),
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    request: refunds::RefundsRetrieveRequest,
+    wait_seconds: Option<u64>,
+) -> RouterResponse<refunds::RefundResponse> {
+    let Some(wait_seconds) = wait_seconds.filter(|wait_seconds| *wait_seconds > 0) else {
+        return refund_response_wrapper(
+            state,
+            merchant_context,
+            profile_id,
+            request,
+            refund_retrieve_core_with_refund_id,
         )
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
-        .attach_printable("Error while fetching the key store by merchant_id")?;
-    let merchant_account = state
-        .store
-        .find_merchant_account_by_merchant_id(key_manager_state, &req.merchant_id, &key_store)
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
-        .attach_printable("Error while fetching the merchant_account by merchant_id")?;
-    let refund = state
-        .store
-        .find_refund_by_merchant_id_refund_id(
+        .await;
+    };
+
+    let _wait_slot =
+        RefundRetrieveWaitSlot::acquire(merchant_context.get_merchant_account().get_id())?;
+
+    let refund = poll_refund_until_terminal(
+        Duration::from_secs(wait_seconds.min(consts::MAX_REFUND_RETRIEVE_WAIT_SECONDS)),
+        Duration::from_millis(consts::REFUND_RETRIEVE_WAIT_POLL_INTERVAL_IN_MILLISECONDS),
+        Duration::from_secs(consts::REFUND_RETRIEVE_WAIT_SYNC_INTERVAL_IN_SECONDS),
+        |should_sync| {
+            let state = state.clone();
+            let merchant_context = merchant_context.clone();
+            let profile_id = profile_id.clone();
+            let request = request.clone();
+            async move {
+                if should_sync {
+                    return Box::pin(refund_retrieve_core_with_refund_id(
+                        state,
+                        merchant_context,
+                        profile_id,
+                        request,
+                    ))
+                    .await;
+                }
+
+                let refund = state
+                    .store
+                    .find_refund_by_merchant_id_refund_id(
+                        merchant_context.get_merchant_account().get_id(),
+                        request.refund_id.as_str(),
+                        merchant_context.get_merchant_account().storage_scheme,
+                    )
+                    .await
+                    .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
+                core_utils::validate_profile_id_from_auth_layer(profile_id, &refund)?;
+                Ok(refund)
+            }
+        },
+    )
+    .await?;
+
+    let is_terminal = is_refund_status_terminal(refund.refund_status);
+
+    Ok(services::ApplicationResponse::JsonWithHeaders((
+        refund.foreign_into(),
+        vec![(
+            crate::headers::X_REFUND_STATUS_TERMINAL.to_string(),
+            masking::Maskable::new_normal(is_terminal.to_string()),
+        )],
+    )))
+}
+
+fn is_refund_status_terminal(refund_status: enums::RefundStatus) -> bool {
+    matches!(
+        refund_status,
+        enums::RefundStatus::Success
+            | enums::RefundStatus::Failure
+            | enums::RefundStatus::TransactionFailure
+    )
+}
+
+/// Fetches the refund until it reaches a terminal status or `wait` elapses. `fetch_refund` is
+/// called with `true` for the first fetch and whenever `sync_interval` has passed since the last
+/// sync, in which case the refund should be synced with the connector.
+async fn poll_refund_until_terminal<F, Fut>(
+    wait: Duration,
+    poll_interval: Duration,
+    sync_interval: Duration,
+    mut fetch_refund: F,
+) -> RouterResult<diesel_refund::Refund>
+where
+    F: FnMut(bool) -> Fut,
+    Fut: Future<Output = RouterResult<diesel_refund::Refund>>,
+{
+    let deadline = tokio::time::Instant::now() + wait;
+    let mut last_synced_at = tokio::time::Instant::now();
+    let mut refund = fetch_refund(true).await?;
+
+    while !is_refund_status_terminal(refund.refund_status) {
+        let remaining = deadline.saturating_duration_since(tokio::time::Instant::now());
+        if remaining.is_zero() {
+            break;
+        }
+        tokio::time::sleep(poll_interval.min(remaining)).await;
+
+        let should_sync = last_synced_at.elapsed() >= sync_interval;
+        if should_sync {
+            last_synced_at = tokio::time::Instant::now();
+        }
+        refund = fetch_refund(should_sync).await?;
+    }
+
+    Ok(refund)
+}
+
+/// Number of refund retrieve requests of each merchant currently waiting for a terminal status
+static REFUND_RETRIEVE_WAITS: LazyLock<Mutex<HashMap<common_utils::id_type::MerchantId, usize>>> =
+    LazyLock::new(Default::default);
+
+/// Slot held by a refund retrieve request while it waits, released when dropped
+struct RefundRetrieveWaitSlot {
+    merchant_id: common_utils::id_type::MerchantId,
+}
+
+impl RefundRetrieveWaitSlot {
+    fn acquire(merchant_id: &common_utils::id_type::MerchantId) -> RouterResult<Self> {
+        let mut waits = REFUND_RETRIEVE_WAITS
+            .lock()
+            .map_err(|_| report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Refund retrieve wait slots lock is poisoned")?;
+        let active_waits = waits.entry(merchant_id.clone()).or_default();
+
+        if *active_waits >= consts::MAX_CONCURRENT_REFUND_RETRIEVE_WAITS_PER_MERCHANT {
+            return Err(report!(errors::ApiErrorResponse::ResourceBusy)).attach_printable(
+                "Too many refund retrieve requests of the merchant are waiting for a terminal status",
+            );
+        }
+        *active_waits += 1;
+
+        Ok(Self {
+            merchant_id: merchant_id.clone(),
+        })
+    }
+}
+
+impl Drop for RefundRetrieveWaitSlot {
+    fn drop(&mut self) {
+        if let Ok(mut waits) = REFUND_RETRIEVE_WAITS.lock() {
+            if let Some(active_waits) = waits.get_mut(&self.merchant_id) {
+                *active_waits = active_waits.saturating_sub(1);
+                if *active_waits == 0 {
+                    waits.remove(&self.merchant_id);
+                }
+            }
+        }
+    }
+}
+
+// ********************************************** CONNECTOR INITIATED REFUND **********************************************
+
+/// Generates the refund id for a refund initiated on the connector's end.
+///
+/// The id is derived from the connector name and the connector refund id, so that duplicate
+/// deliveries of the same webhook always resolve to the same refund row.
+pub fn get_connector_initiated_refund_id(
+    connector_name: &str,
+    connector_refund_id: &str,
+) -> RouterResult<String> {
+    let digest = crypto::Sha256
+        .generate_digest(format!("{connector_name}:{connector_refund_id}").as_bytes())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to generate the connector initiated refund id")?;
+
+    Ok(format!(
+        "ref_{}",
+        hex::encode(digest.get(..16).unwrap_or(digest.as_slice()))
+    ))
+}
+
+pub fn construct_connector_initiated_refund_new(
+    refund_id: String,
+    connector_name: &str,
+    connector_refund_id: String,
+    refund_status: enums::RefundStatus,
+    refund_details: ConnectorInitiatedRefundDetails,
+    payment_attempt: &storage::PaymentAttempt,
+    merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+) -> diesel_refund::RefundNew {
+    let (connector_transaction_id, processor_transaction_data) =
+        ConnectorTransactionId::form_id_and_data(refund_details.connector_transaction_id);
+    let (connector_refund_id, processor_refund_data) =
+        ConnectorTransactionId::form_id_and_data(connector_refund_id);
+    let now = common_utils::date_time::now();
+
+    diesel_refund::RefundNew {
+        refund_id: refund_id.clone(),
+        internal_reference_id: utils::generate_id(consts::ID_LENGTH, "refid"),
+        external_reference_id: Some(refund_id),
+        payment_id: payment_attempt.payment_id.clone(),
+        merchant_id: payment_attempt.merchant_id.clone(),
+        connector_transaction_id,
+        connector: connector_name.to_string(),
+        connector_refund_id: Some(connector_refund_id),
+        // The refund has already been processed by the connector at this point
+        refund_type: enums::RefundType::InstantRefund,
+        total_amount: payment_attempt.get_total_amount(),
+        refund_amount: refund_details.refund_amount,
+        currency: refund_details.currency,
+        created_at: now,
+        modified_at: now,
+        refund_status,
+        metadata: None,
+        description: refund_details.reason.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+        refund_reason: refund_details.reason,
+        profile_id: Some(payment_attempt.profile_id.clone()),
+        merchant_connector_id,
+        charges: None,
+        split_refunds: None,
+        sent_to_gateway: true,
+        refund_arn: None,
+        updated_by: consts::CONNECTOR_WEBHOOK_REFUND_UPDATED_BY.to_string(),
+        organization_id: payment_attempt.organization_id.clone(),
+        processor_transaction_data,
+        processor_refund_data,
+        origin: enums::RefundOrigin::ConnectorWebhook,
+    }
+}
+
+/// Finds the refund with the connector refund id received from the connector. Connector refund ids
+/// which are too long to be stored are hashed when the refund is stored, so the id is hashed the
+/// same way before the lookup.
+pub async fn find_refund_by_connector_refund_id(
+    db: &dyn db::StorageInterface,
+    merchant_id: &common_utils::id_type::MerchantId,
+    connector_refund_id: &str,
+    connector_name: &str,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> errors::CustomResult<diesel_refund::Refund, errors::StorageError> {
+    let connector_refund_id = ConnectorTransactionId::from(connector_refund_id.to_string());
+    db.find_refund_by_merchant_id_connector_refund_id_connector(
+        merchant_id,
+        connector_refund_id.get_id(),
+        connector_name,
+        storage_scheme,
+    )
+    .await
+}
+
+/// Creates a refund row for a refund which was initiated on the connector's end (for instance
+/// from the processor's dashboard), and which we only learn about through an incoming webhook.
+///
+/// The refunded payment must have been processed by the connector which sent the webhook, belong
+/// to the profile the webhook was received for, and have enough captured amount left to refund.
+/// Creation is idempotent, a duplicate delivery of the same webhook returns the existing refund.
+#[instrument(skip_all)]
+pub async fn create_connector_initiated_refund(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    business_profile: &domain::Profile,
+    connector_name: &str,
+    connector_refund_id: String,
+    refund_status: enums::RefundStatus,
+    refund_details: ConnectorInitiatedRefundDetails,
+) -> RouterResult<diesel_refund::Refund> {
+    let db = &*state.store;
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+
+    // Connector transaction ids are hashed the same way as connector refund ids when stored
+    let connector_transaction_id =
+        ConnectorTransactionId::from(refund_details.connector_transaction_id.clone());
+    let payment_attempt = db
+        .find_payment_attempt_by_merchant_id_connector_txn_id(
+            merchant_id,
+            connector_transaction_id.get_id(),
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
+        .attach_printable("Failed to fetch the payment attempt for connector initiated refund")?;
+
+    if payment_attempt.connector.as_deref() != Some(connector_name) {
+        return Err(errors::ApiErrorResponse::WebhookUnprocessableEntity).attach_printable_lazy(
+            || {
+                format!(
+                    "Connector initiated refund received from {connector_name} for a payment of {:?}",
+                    payment_attempt.connector
+                )
+            },
+        );
+    }
+    if &payment_attempt.profile_id != business_profile.get_id() {
+        return Err(errors::ApiErrorResponse::WebhookUnprocessableEntity).attach_printable(
+            "Connector initiated refund received for a payment of another profile",
+        );
+    }
+
+    let refund_id = get_connector_initiated_refund_id(connector_name, &connector_refund_id)?;
+    let all_refunds = db
+        .find_refund_by_merchant_id_connector_transaction_id(
+            merchant_id,
+            connector_transaction_id.get_id(),
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
+
+    // A previous delivery of the same webhook has already created the refund
+    if let Some(refund) = all_refunds
+        .iter()
+        .find(|refund| refund.refund_id == refund_id)
+    {
+        return Ok(refund.clone());
+    }
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &state.into(),
+            &payment_attempt.payment_id,
+            merchant_id,
+            merchant_context.get_merchant_key_store(),
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
+        .attach_printable("Failed to fetch the payment intent for connector initiated refund")?;
+    let total_amount_captured = payment_intent
+        .amount_captured
+        .unwrap_or(payment_attempt.get_total_amount());
+    validator::validate_refund_amount(
+        total_amount_captured.get_amount_as_i64(),
+        &all_refunds,
+        refund_details.refund_amount.get_amount_as_i64(),
+    )
+    .change_context(errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount)
+    .attach_printable("Connector initiated refund exceeds the amount left to refund")?;
+
+    let merchant_connector_id = get_refund_merchant_connector_id(
+        state,
+        merchant_context.get_merchant_key_store(),
+        &payment_attempt,
+    )
+    .await?;
+
+    let refund_new = construct_connector_initiated_refund_new(
+        refund_id.clone(),
+        connector_name,
+        connector_refund_id,
+        refund_status,
+        refund_details,
+        &payment_attempt,
+        merchant_connector_id,
+    );
+
+    match db.insert_refund(refund_new, storage_scheme).await {
+        Ok(refund) => {
+            domain_events::emit_refund_created(state, &refund);
+            metrics::CONNECTOR_INITIATED_REFUND_COUNT.add(
+                1,
+                router_env::metric_attributes!(("connector", connector_name.to_string())),
+            );
+            Ok(refund)
+        }
+        // A concurrent delivery of the same webhook has already created the refund
+        Err(err) if err.current_context().is_db_unique_violation() => db
+            .find_refund_by_merchant_id_refund_id(merchant_id, &refund_id, storage_scheme)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound),
+        Err(err) => Err(err)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert connector initiated refund"),
+    }
+}
+
+#[instrument(skip_all)]
+#[cfg(feature = "olap")]
+pub async fn refund_manual_update(
+    state: SessionState,
+    req: api_models::refunds::RefundManualUpdateRequest,
+) -> RouterResponse<serde_json::Value> {
+    let key_manager_state = &(&state).into();
+    let key_store = state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &req.merchant_id,
+            &state.store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        .attach_printable("Error while fetching the key store by merchant_id")?;
+    let merchant_account = state
+        .store
+        .find_merchant_account_by_merchant_id(key_manager_state, &req.merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        .attach_printable("Error while fetching the merchant_account by merchant_id")?;
+    let refund = state
+        .store
+        .find_refund_by_merchant_id_refund_id(
             merchant_account.get_id(),
             &req.refund_id,
             merchant_account.storage_scheme,
@@ -1156,7 +2047,7 @@ pub async fn refund_manual_update(
         refund_error_code: req.error_code,
         updated_by: merchant_account.storage_scheme.to_string(),
     };
-    state
+    let updated_refund = state
         .store
         .update_refund(
             refund.to_owned(),
@@ -1171,541 +2062,3598 @@ pub async fn refund_manual_update(
                 refund.refund_id
             )
         })?;
+    domain_events::emit_refund_status_changed(&state, &refund, &updated_refund);
     Ok(services::ApplicationResponse::StatusOk)
 }
 
+/// Sets the merchant connector account id of refunds which do not have one to the account they
+/// would have been stamped with at creation, see [`get_refund_merchant_connector_id`]. A single
+/// call processes one batch of refunds ordered by refund id, the backfill is resumed by passing
+/// the `next_starting_after` of the response in the next call.
 #[instrument(skip_all)]
 #[cfg(feature = "olap")]
-pub async fn get_filters_for_refunds(
+pub async fn backfill_refunds_merchant_connector_id(
     state: SessionState,
-    merchant_context: domain::MerchantContext,
-    profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
-) -> RouterResponse<api_models::refunds::RefundListFilters> {
-    let merchant_connector_accounts = if let services::ApplicationResponse::Json(data) =
-        super::admin::list_payment_connectors(
-            state,
-            merchant_context.get_merchant_account().get_id().to_owned(),
-            profile_id_list,
+    req: api_models::refunds::RefundMerchantConnectorIdBackfillRequest,
+) -> RouterResponse<api_models::refunds::RefundMerchantConnectorIdBackfillResponse> {
+    let key_manager_state = &(&state).into();
+    let key_store = state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &req.merchant_id,
+            &state.store.get_master_key().to_vec().into(),
         )
-        .await?
-    {
-        data
-    } else {
-        return Err(errors::ApiErrorResponse::InternalServerError.into());
-    };
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        .attach_printable("Error while fetching the key store by merchant_id")?;
+    let merchant_account = state
+        .store
+        .find_merchant_account_by_merchant_id(key_manager_state, &req.merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        .attach_printable("Error while fetching the merchant_account by merchant_id")?;
 
-    let connector_map = merchant_connector_accounts
-        .into_iter()
-        .filter_map(|merchant_connector_account| {
-            merchant_connector_account
-                .connector_label
-                .clone()
-                .map(|label| {
-                    let info = merchant_connector_account.to_merchant_connector_info(&label);
-                    (merchant_connector_account.connector_name, info)
-                })
-        })
-        .fold(
-            HashMap::new(),
-            |mut map: HashMap<String, Vec<MerchantConnectorInfo>>, (connector_name, info)| {
-                map.entry(connector_name).or_default().push(info);
-                map
-            },
-        );
+    let batch_size = req
+        .batch_size
+        .unwrap_or(consts::DEFAULT_REFUND_BACKFILL_BATCH_SIZE)
+        .clamp(1, consts::MAX_REFUND_BACKFILL_BATCH_SIZE);
 
-    Ok(services::ApplicationResponse::Json(
-        api_models::refunds::RefundListFilters {
-            connector: connector_map,
-            currency: enums::Currency::iter().collect(),
-            refund_status: enums::RefundStatus::iter().collect(),
-        },
-    ))
+    let response = backfill_refunds_merchant_connector_id_batch(
+        &state,
+        &key_store,
+        merchant_account.get_id(),
+        merchant_account.storage_scheme,
+        req.starting_after.as_deref(),
+        batch_size,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(response))
 }
 
-#[instrument(skip_all)]
 #[cfg(feature = "olap")]
-pub async fn get_aggregates_for_refunds(
-    state: SessionState,
-    merchant_context: domain::MerchantContext,
-    profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
-    time_range: common_utils::types::TimeRange,
-) -> RouterResponse<api_models::refunds::RefundAggregateResponse> {
-    let db = state.store.as_ref();
-    let refund_status_with_count = db
-        .get_refund_status_with_count(
-            merchant_context.get_merchant_account().get_id(),
-            profile_id_list,
-            &time_range,
-            merchant_context.get_merchant_account().storage_scheme,
+async fn backfill_refunds_merchant_connector_id_batch(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    merchant_id: &common_utils::id_type::MerchantId,
+    storage_scheme: enums::MerchantStorageScheme,
+    starting_after: Option<&str>,
+    batch_size: u32,
+) -> RouterResult<api_models::refunds::RefundMerchantConnectorIdBackfillResponse> {
+    let db = &*state.store;
+    let refunds_batch = db
+        .find_refunds_without_merchant_connector_id_by_merchant_id(
+            merchant_id,
+            starting_after,
+            i64::from(batch_size),
+            storage_scheme,
         )
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Failed to find status count")?;
-    let mut status_map: HashMap<enums::RefundStatus, i64> =
-        refund_status_with_count.into_iter().collect();
-    for status in enums::RefundStatus::iter() {
-        status_map.entry(status).or_default();
-    }
+        .attach_printable("Failed to fetch refunds without merchant connector id")?;
 
-    Ok(services::ApplicationResponse::Json(
-        api_models::refunds::RefundAggregateResponse {
-            status_with_count: status_map,
-        },
-    ))
-}
+    // A batch smaller than the batch size implies that there are no more refunds to backfill
+    let refunds_processed = refunds_batch.len();
+    let next_starting_after = refunds_batch
+        .last()
+        .filter(|_| usize::try_from(batch_size).is_ok_and(|size| refunds_processed == size))
+        .map(|refund| refund.refund_id.clone());
 
-impl ForeignFrom<diesel_refund::Refund> for api::RefundResponse {
-    fn foreign_from(refund: diesel_refund::Refund) -> Self {
-        let refund = refund;
+    let mut refunds_updated = 0;
+    let mut unresolved_refund_ids = Vec::new();
+    for refund in refunds_batch {
+        let merchant_connector_id = match db
+            .find_payment_attempt_by_attempt_id_merchant_id(
+                &refund.attempt_id,
+                merchant_id,
+                storage_scheme,
+            )
+            .await
+        {
+            Ok(payment_attempt) => {
+                get_refund_merchant_connector_id(state, key_store, &payment_attempt).await?
+            }
+            Err(error) if error.current_context().is_db_not_found() => None,
+            Err(error) => {
+                return Err(error)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable_lazy(|| {
+                        format!(
+                            "Failed to fetch payment attempt for refund: refund_id: {}",
+                            refund.refund_id
+                        )
+                    })
+            }
+        };
 
-        Self {
-            payment_id: refund.payment_id,
-            refund_id: refund.refund_id,
-            amount: refund.refund_amount,
-            currency: refund.currency.to_string(),
-            reason: refund.refund_reason,
-            status: refund.refund_status.foreign_into(),
-            profile_id: refund.profile_id,
-            metadata: refund.metadata,
-            error_message: refund.refund_error_message,
-            error_code: refund.refund_error_code,
-            created_at: Some(refund.created_at),
-            updated_at: Some(refund.modified_at),
-            connector: refund.connector,
-            merchant_connector_id: refund.merchant_connector_id,
-            split_refunds: refund.split_refunds,
-            unified_code: refund.unified_code,
-            unified_message: refund.unified_message,
-            issuer_error_code: refund.issuer_error_code,
-            issuer_error_message: refund.issuer_error_message,
-        }
-    }
-}
-
-// ********************************************** PROCESS TRACKER **********************************************
-
-#[instrument(skip_all)]
-#[allow(clippy::too_many_arguments)]
-pub async fn schedule_refund_execution(
-    state: &SessionState,
-    refund: diesel_refund::Refund,
-    refund_type: api_models::refunds::RefundType,
-    merchant_context: &domain::MerchantContext,
-    payment_attempt: &storage::PaymentAttempt,
-    payment_intent: &storage::PaymentIntent,
-    creds_identifier: Option<String>,
-    split_refunds: Option<SplitRefundsRequest>,
-) -> RouterResult<diesel_refund::Refund> {
-    // refunds::RefundResponse> {
-    let db = &*state.store;
-    let runner = storage::ProcessTrackerRunner::RefundWorkflowRouter;
-    let task = "EXECUTE_REFUND";
-    let task_id = format!("{runner}_{task}_{}", refund.internal_reference_id);
+        let Some(merchant_connector_id) = merchant_connector_id else {
+            unresolved_refund_ids.push(refund.refund_id);
+            continue;
+        };
 
-    let refund_process = db
-        .find_process_by_id(&task_id)
+        let refund_id = refund.refund_id.clone();
+        db.update_refund(
+            refund,
+            diesel_refund::RefundUpdate::MerchantConnectorIdUpdate {
+                merchant_connector_id,
+                updated_by: consts::REFUND_MERCHANT_CONNECTOR_ID_BACKFILL_UPDATED_BY.to_string(),
+            },
+            storage_scheme,
+        )
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Failed to find the process id")?;
+        .attach_printable_lazy(|| {
+            format!("Failed while updating refund: refund_id: {refund_id}")
+        })?;
+        refunds_updated += 1;
+    }
 
-    let result = match refund.refund_status {
-        enums::RefundStatus::Pending | enums::RefundStatus::ManualReview => {
-            match (refund.sent_to_gateway, refund_process) {
-                (false, None) => {
-                    // Execute the refund task based on refund_type
-                    match refund_type {
-                        api_models::refunds::RefundType::Scheduled => {
-                            add_refund_execute_task(db, &refund, runner)
-                                .await
-                                .change_context(errors::ApiErrorResponse::InternalServerError)
-                                .attach_printable_lazy(|| format!("Failed while pushing refund execute task to scheduler, refund_id: {}", refund.refund_id))?;
+    logger::info!(
+        refunds_processed,
+        refunds_updated,
+        refunds_unresolved = unresolved_refund_ids.len(),
+        "Backfilled merchant connector id of refunds"
+    );
 
-                            Ok(refund)
-                        }
-                        api_models::refunds::RefundType::Instant => {
-                            let update_refund = Box::pin(trigger_refund_to_gateway(
-                                state,
-                                &refund,
-                                merchant_context,
-                                payment_attempt,
-                                payment_intent,
-                                creds_identifier,
-                                split_refunds,
-                            ))
-                            .await;
+    Ok(
+        api_models::refunds::RefundMerchantConnectorIdBackfillResponse {
+            refunds_processed,
+            refunds_updated,
+            unresolved_refund_ids,
+            next_starting_after,
+        },
+    )
+}
 
-                            match update_refund {
-                                Ok(updated_refund_data) => {
-                                    add_refund_sync_task(db, &updated_refund_data, runner)
-                                        .await
-                                        .change_context(errors::ApiErrorResponse::InternalServerError)
-                                        .attach_printable_lazy(|| format!(
-                                            "Failed while pushing refund sync task in scheduler: refund_id: {}",
-                                            refund.refund_id
-                                        ))?;
-                                    Ok(updated_refund_data)
-                                }
-                                Err(err) => Err(err),
-                            }
-                        }
-                    }
-                }
-                _ => {
-                    // Sync the refund for status check
-                    //[#300]: return refund status response
-                    match refund_type {
-                        api_models::refunds::RefundType::Scheduled => {
-                            add_refund_sync_task(db, &refund, runner)
-                                .await
-                                .change_context(errors::ApiErrorResponse::InternalServerError)
-                                .attach_printable_lazy(|| format!("Failed while pushing refund sync task in scheduler: refund_id: {}", refund.refund_id))?;
-                            Ok(refund)
-                        }
-                        api_models::refunds::RefundType::Instant => {
-                            // [#255]: This is not possible in schedule_refund_execution as it will always be scheduled
-                            // sync_refund_with_gateway(data, &refund).await
-                            Ok(refund)
-                        }
-                    }
-                }
-            }
-        }
-        //  [#255]: This is not allowed to be otherwise or all
-        _ => Ok(refund),
-    }?;
-    Ok(result)
+/// Syncs the refunds of the merchant which are pending at the connector but have not been updated
+/// for the requested time, for recovering refunds whose webhooks were not received.
+#[instrument(skip_all)]
+#[cfg(feature = "olap")]
+pub async fn refunds_stale_sync_core(
+    state: SessionState,
+    req: api_models::refunds::RefundsStaleSyncRequest,
+) -> RouterResponse<api_models::refunds::RefundsStaleSyncResponse> {
+    let older_than_in_secs = req
+        .older_than_in_secs
+        .unwrap_or(consts::DEFAULT_STALE_REFUND_AGE_IN_SECS);
+    utils::when(older_than_in_secs <= 0, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "older_than_in_secs must be greater than 0".to_string(),
+        })
+    })?;
+    let limit = req
+        .limit
+        .unwrap_or(consts::DEFAULT_STALE_REFUND_SYNC_LIMIT)
+        .clamp(1, consts::MAX_STALE_REFUND_SYNC_LIMIT);
+
+    let response = sync_stale_refunds(
+        &state,
+        &req.merchant_id,
+        time::Duration::seconds(older_than_in_secs),
+        i64::from(limit),
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(response))
 }
 
+/// Syncs up to `limit` refunds of the merchant which were sent to the connector but are still
+/// pending after `older_than`. The refunds of each connector are synced concurrently up to the
+/// bulk concurrency limit of a connector account, and a refund which fails to sync does not stop
+/// the others from being synced.
 #[instrument(skip_all)]
-pub async fn sync_refund_with_gateway_workflow(
+#[cfg(feature = "olap")]
+pub async fn sync_stale_refunds(
     state: &SessionState,
-    refund_tracker: &storage::ProcessTracker,
-) -> Result<(), errors::ProcessTrackerError> {
+    merchant_id: &common_utils::id_type::MerchantId,
+    older_than: time::Duration,
+    limit: i64,
+) -> RouterResult<api_models::refunds::RefundsStaleSyncResponse> {
     let key_manager_state = &state.into();
-    let refund_core = serde_json::from_value::<diesel_refund::RefundCoreWorkflow>(
-        refund_tracker.tracking_data.clone(),
-    )
-    .change_context(errors::ApiErrorResponse::InternalServerError)
-    .attach_printable_lazy(|| {
-        format!(
-            "unable to convert into refund_core {:?}",
-            refund_tracker.tracking_data
-        )
-    })?;
-
     let key_store = state
         .store
         .get_merchant_key_store_by_merchant_id(
             key_manager_state,
-            &refund_core.merchant_id,
+            merchant_id,
             &state.store.get_master_key().to_vec().into(),
         )
-        .await?;
-
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        .attach_printable("Error while fetching the key store by merchant_id")?;
     let merchant_account = state
         .store
-        .find_merchant_account_by_merchant_id(
-            key_manager_state,
-            &refund_core.merchant_id,
-            &key_store,
-        )
-        .await?;
+        .find_merchant_account_by_merchant_id(key_manager_state, merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        .attach_printable("Error while fetching the merchant_account by merchant_id")?;
+    let storage_scheme = merchant_account.storage_scheme;
     let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
-        merchant_account.clone(),
-        key_store.clone(),
+        merchant_account,
+        key_store,
     )));
-    let response = Box::pin(refund_retrieve_core_with_internal_reference_id(
-        state.clone(),
-        merchant_context,
-        None,
-        refund_core.refund_internal_reference_id,
-        Some(true),
-    ))
-    .await?;
-    let terminal_status = [
-        enums::RefundStatus::Success,
-        enums::RefundStatus::Failure,
-        enums::RefundStatus::TransactionFailure,
-    ];
-    match response.refund_status {
-        status if terminal_status.contains(&status) => {
-            state
-                .store
-                .as_scheduler()
-                .finish_process_with_business_status(
-                    refund_tracker.clone(),
-                    business_status::COMPLETED_BY_PT,
-                )
-                .await?
-        }
-        _ => {
-            _ = payment_sync::retry_sync_task(
-                &*state.store,
-                response.connector,
-                response.merchant_id,
-                refund_tracker.to_owned(),
-            )
-            .await?;
-        }
+
+    let stale_refunds = state
+        .store
+        .find_stale_pending_refunds_by_merchant_id(
+            merchant_id,
+            common_utils::date_time::now() - older_than,
+            limit,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the stale pending refunds")?;
+
+    let mut refunds_by_connector: HashMap<String, Vec<diesel_refund::Refund>> = HashMap::new();
+    for refund in stale_refunds {
+        refunds_by_connector
+            .entry(refund.connector.clone())
+            .or_default()
+            .push(refund);
     }
 
-    Ok(())
+    let max_concurrency = state
+        .conf
+        .refund
+        .bulk_max_concurrency_per_connector_account
+        .max(1);
+    let outcomes = futures::future::join_all(refunds_by_connector.into_values().map(|refunds| {
+        futures::stream::iter(refunds)
+            .map(|refund| sync_stale_refund(state, &merchant_context, refund))
+            .buffer_unordered(max_concurrency)
+            .collect::<Vec<_>>()
+    }))
+    .await;
+
+    let response = aggregate_stale_refund_sync_outcomes(outcomes.into_iter().flatten());
+    logger::info!(
+        refunds_synced = response.refunds_synced,
+        refunds_updated = response.refunds_updated,
+        refunds_failed = response.refunds_failed,
+        "Synced stale pending refunds"
+    );
+
+    Ok(response)
 }
 
-#[instrument(skip_all)]
-pub async fn start_refund_workflow(
+#[cfg(feature = "olap")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StaleRefundSyncOutcome {
+    Updated,
+    Unchanged,
+    Failed,
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(refund_id = %refund.refund_id))]
+async fn sync_stale_refund(
     state: &SessionState,
-    refund_tracker: &storage::ProcessTracker,
-) -> Result<(), errors::ProcessTrackerError> {
-    match refund_tracker.name.as_deref() {
-        Some("EXECUTE_REFUND") => {
-            Box::pin(trigger_refund_execute_workflow(state, refund_tracker)).await
+    merchant_context: &domain::MerchantContext,
+    refund: diesel_refund::Refund,
+) -> StaleRefundSyncOutcome {
+    let refund_status = refund.refund_status;
+    let request = refunds::RefundsRetrieveRequest {
+        refund_id: refund.refund_id.clone(),
+        force_sync: Some(true),
+        merchant_connector_details: None,
+    };
+
+    match Box::pin(refund_retrieve_core(
+        state.clone(),
+        merchant_context.clone(),
+        None,
+        request,
+        refund,
+    ))
+    .await
+    {
+        Ok(synced_refund) if synced_refund.refund_status != refund_status => {
+            StaleRefundSyncOutcome::Updated
         }
-        Some("SYNC_REFUND") => {
-            Box::pin(sync_refund_with_gateway_workflow(state, refund_tracker)).await
+        Ok(_) => StaleRefundSyncOutcome::Unchanged,
+        Err(error) => {
+            logger::warn!(?error, "Failed to sync the stale refund");
+            StaleRefundSyncOutcome::Failed
         }
-        _ => Err(errors::ProcessTrackerError::JobNotFound),
     }
 }
 
-#[instrument(skip_all)]
-pub async fn trigger_refund_execute_workflow(
-    state: &SessionState,
-    refund_tracker: &storage::ProcessTracker,
-) -> Result<(), errors::ProcessTrackerError> {
-    let db = &*state.store;
-    let refund_core = serde_json::from_value::<diesel_refund::RefundCoreWorkflow>(
-        refund_tracker.tracking_data.clone(),
+#[cfg(feature = "olap")]
+fn aggregate_stale_refund_sync_outcomes(
+    outcomes: impl IntoIterator<Item = StaleRefundSyncOutcome>,
+) -> api_models::refunds::RefundsStaleSyncResponse {
+    outcomes.into_iter().fold(
+        api_models::refunds::RefundsStaleSyncResponse::default(),
+        |mut response, outcome| {
+            match outcome {
+                StaleRefundSyncOutcome::Updated => {
+                    response.refunds_synced += 1;
+                    response.refunds_updated += 1;
+                }
+                StaleRefundSyncOutcome::Unchanged => response.refunds_synced += 1,
+                StaleRefundSyncOutcome::Failed => response.refunds_failed += 1,
+            }
+            response
+        },
     )
-    .change_context(errors::ApiErrorResponse::InternalServerError)
-    .attach_printable_lazy(|| {
-        format!(
-            "unable to convert into refund_core {:?}",
-            refund_tracker.tracking_data
-        )
-    })?;
-    let key_manager_state = &state.into();
-    let key_store = state
-        .store
-        .get_merchant_key_store_by_merchant_id(
-            key_manager_state,
+}
+
+#[instrument(skip_all)]
+#[cfg(feature = "olap")]
+pub async fn get_filters_for_refunds(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
+) -> RouterResponse<api_models::refunds::RefundListFilters> {
+    let merchant_connector_accounts = if let services::ApplicationResponse::Json(data) =
+        super::admin::list_payment_connectors(
+            state,
+            merchant_context.get_merchant_account().get_id().to_owned(),
+            profile_id_list,
+        )
+        .await?
+    {
+        data
+    } else {
+        return Err(errors::ApiErrorResponse::InternalServerError.into());
+    };
+
+    let connector_map = merchant_connector_accounts
+        .into_iter()
+        .filter_map(|merchant_connector_account| {
+            merchant_connector_account
+                .connector_label
+                .clone()
+                .map(|label| {
+                    let info = merchant_connector_account.to_merchant_connector_info(&label);
+                    (merchant_connector_account.connector_name, info)
+                })
+        })
+        .fold(
+            HashMap::new(),
+            |mut map: HashMap<String, Vec<MerchantConnectorInfo>>, (connector_name, info)| {
+                map.entry(connector_name).or_default().push(info);
+                map
+            },
+        );
+
+    Ok(services::ApplicationResponse::Json(
+        api_models::refunds::RefundListFilters {
+            connector: connector_map,
+            currency: enums::Currency::iter().collect(),
+            refund_status: enums::RefundStatus::iter().collect(),
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+#[cfg(feature = "olap")]
+pub async fn get_aggregates_for_refunds(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
+    time_range: common_utils::types::TimeRange,
+) -> RouterResponse<api_models::refunds::RefundAggregateResponse> {
+    let db = state.store.as_ref();
+    let refund_status_with_count = db
+        .get_refund_status_with_count(
+            merchant_context.get_merchant_account().get_id(),
+            profile_id_list,
+            &time_range,
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find status count")?;
+    let mut status_map: HashMap<enums::RefundStatus, i64> =
+        refund_status_with_count.into_iter().collect();
+    for status in enums::RefundStatus::iter() {
+        status_map.entry(status).or_default();
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        api_models::refunds::RefundAggregateResponse {
+            status_with_count: status_map,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+#[cfg(feature = "olap")]
+pub async fn get_refund_status_counts(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
+    req: api_models::refunds::RefundStatusCountRequest,
+) -> RouterResponse<api_models::refunds::RefundStatusCountResponse> {
+    let db = state.store.as_ref();
+    let status_counts = db
+        .get_refund_status_counts(
+            merchant_context.get_merchant_account().get_id(),
+            profile_id_list,
+            &req.time_range,
+            req.group_by,
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find status counts grouped by connector and currency")?;
+
+    Ok(services::ApplicationResponse::Json(
+        api_models::refunds::RefundStatusCountResponse {
+            status_counts: status_counts
+                .into_iter()
+                .map(|(connector, currency, refund_status, count)| {
+                    api_models::refunds::RefundStatusCount {
+                        connector,
+                        currency,
+                        refund_status,
+                        count,
+                    }
+                })
+                .collect(),
+        },
+    ))
+}
+
+/// Exports as CSV the refunds of the merchant whose status does not match their connector data
+/// according to the requested rules, optionally scheduling a sync with the connector for each of
+/// them
+#[instrument(skip_all)]
+#[cfg(feature = "olap")]
+pub async fn refund_reconciliation_export(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    req: api_models::refunds::RefundReconciliationRequest,
+) -> RouterResponse<serde_json::Value> {
+    let limit = validator::validate_refund_list(req.limit)?;
+    let older_than = common_utils::date_time::now()
+        .saturating_sub(time::Duration::hours(i64::from(req.rules.older_than_hours)));
+    let db = state.store.as_ref();
+
+    let refunds = db
+        .find_inconsistent_refunds(
+            merchant_context.get_merchant_account().get_id(),
+            older_than,
+            &req.rules,
+            limit,
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find refunds inconsistent with their connector data")?;
+
+    let mut mismatches = Vec::with_capacity(refunds.len());
+    for refund in refunds {
+        let Some(violated_rule) = storage::get_refund_mismatch_rule(&refund, &req.rules) else {
+            continue;
+        };
+
+        // A sync task may already be scheduled for the refund, which is left as is
+        let sync_scheduled = if req.schedule_sync {
+            add_refund_sync_task(
+                db,
+                &refund,
+                storage::ProcessTrackerRunner::RefundWorkflowRouter,
+            )
+            .await
+            .map_err(|error| {
+                logger::warn!(
+                    ?error,
+                    refund_id = %refund.refund_id,
+                    "Failed to schedule a sync for the mismatched refund"
+                )
+            })
+            .is_ok()
+        } else {
+            false
+        };
+
+        mismatches.push(api_models::refunds::RefundMismatch {
+            refund_id: refund.refund_id,
+            payment_id: refund.payment_id,
+            connector: refund.connector,
+            refund_status: refund.refund_status,
+            violated_rule,
+            sync_scheduled,
+        });
+    }
+
+    Ok(services::ApplicationResponse::FileData((
+        write_refund_mismatches_csv_export(&mismatches)?,
+        mime::TEXT_CSV,
+    )))
+}
+
+/// Writes the mismatched refunds as CSV, with a header row naming the columns
+#[cfg(feature = "olap")]
+fn write_refund_mismatches_csv_export(
+    mismatches: &[api_models::refunds::RefundMismatch],
+) -> RouterResult<Vec<u8>> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+
+    for mismatch in mismatches {
+        writer
+            .serialize(mismatch)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to write a refund to the reconciliation export")?;
+    }
+
+    writer
+        .into_inner()
+        .map_err(|error| error.into_error())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to flush the reconciliation export")
+}
+
+impl ForeignFrom<diesel_refund::Refund> for api::RefundResponse {
+    fn foreign_from(refund: diesel_refund::Refund) -> Self {
+        let refund = refund;
+        let error_category = refunds_normalization::get_refund_error_category(
+            &refund.connector,
+            refund.refund_status,
+            refund.refund_error_code.as_deref(),
+            refund.refund_error_message.as_deref(),
+        );
+
+        Self {
+            payment_id: refund.payment_id,
+            refund_id: refund.refund_id,
+            amount: refund.refund_amount,
+            refund_amount_formatted: transformers::get_formatted_refund_amount(
+                refund.refund_amount,
+                refund.currency,
+            ),
+            total_amount_formatted: transformers::get_formatted_refund_amount(
+                refund.total_amount,
+                refund.currency,
+            ),
+            currency: refund.currency.to_string(),
+            reason: refund.refund_reason,
+            status: refund.refund_status.foreign_into(),
+            profile_id: refund.profile_id,
+            metadata: refund.metadata,
+            error_category,
+            recommended_action: error_category
+                .map(refunds_normalization::get_refund_recommended_action),
+            error_message: refund.refund_error_message,
+            error_code: refund.refund_error_code,
+            created_at: Some(refund.created_at),
+            updated_at: Some(refund.modified_at),
+            connector: refund.connector,
+            merchant_connector_id: refund.merchant_connector_id,
+            split_refunds: refund.split_refunds,
+            unified_code: refund.unified_code,
+            unified_message: refund.unified_message,
+            issuer_error_code: refund.issuer_error_code,
+            issuer_error_message: refund.issuer_error_message,
+            origin: refund.origin,
+        }
+    }
+}
+
+// ********************************************** BULK REFUND **********************************************
+
+/// Details of the payment required to validate an entry of a refund batch upfront
+#[derive(Debug, Clone, Copy)]
+struct RefundBatchPaymentDetails {
+    status: enums::IntentStatus,
+    amount_captured: Option<MinorUnit>,
+}
+
+/// Eligibility checks run on every entry of a refund batch before it is persisted. The checks
+/// that depend on existing refunds are left to the refund creation flow which every entry goes
+/// through while the batch is being executed.
+fn validate_refund_batch_entry(
+    entry: &refunds::RefundBatchEntryRequest,
+    is_duplicate: bool,
+    payment_details: Option<RefundBatchPaymentDetails>,
+) -> Result<(), errors::ApiErrorResponse> {
+    if is_duplicate {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "payment_id is repeated in the batch".to_string(),
+        });
+    }
+
+    let payment_details = payment_details.ok_or(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    if !(payment_details.status == enums::IntentStatus::Succeeded
+        || payment_details.status == enums::IntentStatus::PartiallyCaptured)
+    {
+        return Err(errors::ApiErrorResponse::PaymentUnexpectedState {
+            current_flow: "refund".into(),
+            field_name: "status".into(),
+            current_value: payment_details.status.to_string(),
+            states: "succeeded, partially_captured".to_string(),
+        });
+    }
+
+    if let Some(amount) = entry.amount {
+        if amount <= MinorUnit::new(0) {
+            return Err(errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: "amount".to_string(),
+                expected_format: "positive integer".to_string(),
+            });
+        }
+
+        if payment_details
+            .amount_captured
+            .is_some_and(|amount_captured| amount > amount_captured)
+        {
+            return Err(errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount);
+        }
+    }
+
+    Ok(())
+}
+
+#[instrument(skip_all)]
+async fn construct_refund_batch_entry_new(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    batch_id: &str,
+    entry_index: usize,
+    entry: refunds::RefundBatchEntryRequest,
+    is_duplicate: bool,
+) -> RouterResult<storage::RefundBatchEntryNew> {
+    let db = &*state.store;
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+
+    let payment_intent = if is_duplicate {
+        None
+    } else {
+        match db
+            .find_payment_intent_by_payment_id_merchant_id(
+                &state.into(),
+                &entry.payment_id,
+                merchant_id,
+                merchant_context.get_merchant_key_store(),
+                storage_scheme,
+            )
+            .await
+        {
+            Ok(payment_intent) => Some(payment_intent),
+            Err(error) if error.current_context().is_db_not_found() => None,
+            Err(error) => {
+                return Err(error)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to fetch payment intent for refund batch entry")
+            }
+        }
+    };
+
+    let payment_details = payment_intent
+        .as_ref()
+        .map(|payment_intent| RefundBatchPaymentDetails {
+            status: payment_intent.status,
+            amount_captured: payment_intent.amount_captured,
+        });
+
+    let (merchant_connector_id, validation_error) =
+        match validate_refund_batch_entry(&entry, is_duplicate, payment_details) {
+            Ok(()) => match db
+                .find_payment_attempt_last_successful_or_partially_captured_attempt_by_payment_id_merchant_id(
+                    &entry.payment_id,
+                    merchant_id,
+                    storage_scheme,
+                )
+                .await
+            {
+                Ok(payment_attempt) => (payment_attempt.merchant_connector_id, None),
+                Err(error) if error.current_context().is_db_not_found() => {
+                    (None, Some(errors::ApiErrorResponse::SuccessfulPaymentNotFound))
+                }
+                Err(error) => {
+                    return Err(error)
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to fetch payment attempt for refund batch entry")
+                }
+            },
+            Err(validation_error) => (None, Some(validation_error)),
+        };
+
+    let status = if validation_error.is_some() {
+        enums::RefundBatchEntryStatus::Invalid
+    } else {
+        enums::RefundBatchEntryStatus::Pending
+    };
+
+    let now = common_utils::date_time::now();
+    Ok(storage::RefundBatchEntryNew {
+        batch_id: batch_id.to_string(),
+        entry_index: i32::try_from(entry_index)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Refund batch entry index exceeds i32")?,
+        merchant_id: merchant_id.clone(),
+        payment_id: entry.payment_id,
+        refund_id: utils::generate_id(consts::ID_LENGTH, "ref"),
+        amount: entry.amount,
+        reason: entry.reason,
+        merchant_connector_id,
+        status,
+        error_code: validation_error.as_ref().map(|error| error.error_code()),
+        error_message: validation_error.as_ref().map(|error| error.error_message()),
+        created_at: now,
+        modified_at: now,
+    })
+}
+
+fn aggregate_refund_batch_entries(
+    entries: &[storage::RefundBatchEntry],
+) -> refunds::RefundBatchSummary {
+    entries.iter().fold(
+        refunds::RefundBatchSummary::default(),
+        |mut summary, entry| {
+            match entry.status {
+                enums::RefundBatchEntryStatus::Pending => summary.pending += 1,
+                enums::RefundBatchEntryStatus::Invalid => summary.invalid += 1,
+                enums::RefundBatchEntryStatus::Submitted => summary.submitted += 1,
+                enums::RefundBatchEntryStatus::Failed => summary.failed += 1,
+            }
+            summary
+        },
+    )
+}
+
+/// The status of a refund batch after its pending entries have been submitted, entries which are
+/// still pending could not be marked as submitted and are left to a later run of the batch
+fn get_refund_batch_status(summary: &refunds::RefundBatchSummary) -> enums::RefundBatchStatus {
+    if summary.pending > 0 {
+        enums::RefundBatchStatus::Processing
+    } else if summary.submitted == 0 {
+        enums::RefundBatchStatus::Failed
+    } else if summary.invalid > 0 || summary.failed > 0 {
+        enums::RefundBatchStatus::PartiallyFailed
+    } else {
+        enums::RefundBatchStatus::Completed
+    }
+}
+
+fn is_refund_batch_processed(status: enums::RefundBatchStatus) -> bool {
+    match status {
+        enums::RefundBatchStatus::Pending | enums::RefundBatchStatus::Processing => false,
+        enums::RefundBatchStatus::Completed
+        | enums::RefundBatchStatus::PartiallyFailed
+        | enums::RefundBatchStatus::Failed => true,
+    }
+}
+
+fn construct_refund_batch_response(
+    refund_batch: storage::RefundBatch,
+    entries: Vec<storage::RefundBatchEntry>,
+) -> refunds::RefundBatchResponse {
+    let summary = aggregate_refund_batch_entries(&entries);
+    refunds::RefundBatchResponse {
+        batch_id: refund_batch.batch_id,
+        status: refund_batch.status,
+        total_entries: refund_batch.total_entries,
+        summary,
+        entries: entries
+            .into_iter()
+            .map(|entry| refunds::RefundBatchEntryResponse {
+                entry_index: entry.entry_index,
+                payment_id: entry.payment_id,
+                refund_id: entry.refund_id,
+                amount: entry.amount,
+                status: entry.status,
+                refund_status: entry.refund_status.map(ForeignFrom::foreign_from),
+                error_code: entry.error_code,
+                error_message: entry.error_message,
+            })
+            .collect(),
+        created_at: refund_batch.created_at,
+        updated_at: refund_batch.modified_at,
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn refund_batch_create_core(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    req: refunds::RefundBatchCreateRequest,
+) -> RouterResponse<refunds::RefundBatchResponse> {
+    let db = &*state.store;
+    let max_entries = state.conf.refund.bulk_max_entries;
+
+    utils::when(
+        req.entries.is_empty() || req.entries.len() > max_entries,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("entries should contain between 1 and {max_entries} items"),
+            }))
+        },
+    )?;
+
+    let batch_id = utils::generate_id(consts::ID_LENGTH, "refbatch");
+
+    let mut seen_payment_ids = HashSet::new();
+    let entries = futures::stream::iter(req.entries.into_iter().enumerate().map(
+        |(entry_index, entry)| {
+            let is_duplicate = !seen_payment_ids.insert(entry.payment_id.clone());
+            (entry_index, entry, is_duplicate)
+        },
+    ))
+    .map(|(entry_index, entry, is_duplicate)| {
+        construct_refund_batch_entry_new(
+            &state,
+            &merchant_context,
+            &batch_id,
+            entry_index,
+            entry,
+            is_duplicate,
+        )
+    })
+    .buffered(10)
+    .collect::<Vec<_>>()
+    .await
+    .into_iter()
+    .collect::<RouterResult<Vec<_>>>()?;
+
+    let now = common_utils::date_time::now();
+    let refund_batch = db
+        .insert_refund_batch(storage::RefundBatchNew {
+            batch_id: batch_id.clone(),
+            merchant_id: merchant_context.get_merchant_account().get_id().clone(),
+            profile_id: profile_id.clone(),
+            organization_id: merchant_context
+                .get_merchant_account()
+                .organization_id
+                .clone(),
+            status: enums::RefundBatchStatus::Pending,
+            total_entries: i32::try_from(entries.len())
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Refund batch size exceeds i32")?,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert refund batch")?;
+
+    let refund_batch_entries = db
+        .insert_refund_batch_entries(entries)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert refund batch entries")?;
+
+    // The batch is processed by the scheduler after the response is sent. A batch whose task could
+    // not be added would never be processed, so it is marked as failed instead
+    if let Err(error) = add_refund_batch_execute_task(
+        db,
+        &refund_batch,
+        storage::ProcessTrackerRunner::RefundWorkflowRouter,
+    )
+    .await
+    {
+        db.update_refund_batch(
+            refund_batch,
+            storage::RefundBatchUpdate::StatusUpdate {
+                status: enums::RefundBatchStatus::Failed,
+            },
+        )
+        .await
+        .inspect_err(|error| logger::error!(?error, "Failed to mark refund batch as failed"))
+        .ok();
+        return Err(error);
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        construct_refund_batch_response(refund_batch, refund_batch_entries),
+    ))
+}
+
+/// Submits the pending entries of a refund batch through the refund creation flow. Entries are
+/// grouped by the merchant connector account of the payment so that the number of concurrent
+/// refund calls made to a single connector account stays bounded.
+///
+/// Entries which were already submitted are skipped, so that a batch whose task is picked up again
+/// after a partial run only submits the remaining entries. The status of the batch is derived from
+/// the statuses of its entries once they have been submitted.
+#[instrument(skip_all, fields(batch_id = %refund_batch.batch_id))]
+async fn execute_refund_batch(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    refund_batch: storage::RefundBatch,
+) -> RouterResult<enums::RefundBatchStatus> {
+    let db = &*state.store;
+    let max_concurrency = state
+        .conf
+        .refund
+        .bulk_max_concurrency_per_connector_account
+        .max(1);
+
+    let entries = db
+        .find_refund_batch_entries_by_merchant_id_batch_id(
+            &refund_batch.merchant_id,
+            &refund_batch.batch_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch refund batch entries")?
+        .into_iter()
+        .filter(|entry| entry.status == enums::RefundBatchEntryStatus::Pending);
+
+    let refund_batch = db
+        .update_refund_batch(
+            refund_batch.clone(),
+            storage::RefundBatchUpdate::StatusUpdate {
+                status: enums::RefundBatchStatus::Processing,
+            },
+        )
+        .await
+        .inspect_err(|error| logger::error!(?error, "Failed to update refund batch status"))
+        .unwrap_or(refund_batch);
+
+    let mut entries_by_connector_account: HashMap<
+        Option<common_utils::id_type::MerchantConnectorAccountId>,
+        Vec<storage::RefundBatchEntry>,
+    > = HashMap::new();
+    for entry in entries {
+        entries_by_connector_account
+            .entry(entry.merchant_connector_id.clone())
+            .or_default()
+            .push(entry);
+    }
+
+    futures::future::join_all(entries_by_connector_account.into_values().map(|entries| {
+        futures::stream::iter(entries).for_each_concurrent(max_concurrency, |entry| {
+            submit_refund_batch_entry(state, merchant_context, profile_id.clone(), entry)
+        })
+    }))
+    .await;
+
+    let entries = db
+        .find_refund_batch_entries_by_merchant_id_batch_id(
+            &refund_batch.merchant_id,
+            &refund_batch.batch_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch refund batch entries")?;
+    let status = get_refund_batch_status(&aggregate_refund_batch_entries(&entries));
+
+    db.update_refund_batch(
+        refund_batch,
+        storage::RefundBatchUpdate::StatusUpdate { status },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update refund batch status")?;
+
+    Ok(status)
+}
+
+#[instrument(skip_all, fields(refund_id = %entry.refund_id))]
+async fn submit_refund_batch_entry(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    entry: storage::RefundBatchEntry,
+) {
+    let refund_request = refunds::RefundRequest {
+        payment_id: entry.payment_id.clone(),
+        refund_id: Some(entry.refund_id.clone()),
+        amount: entry.amount,
+        reason: entry.reason.clone(),
+        ..Default::default()
+    };
+
+    let refund_result = Box::pin(refund_create_core(
+        state.clone(),
+        merchant_context.clone(),
+        profile_id,
+        refund_request,
+        enums::RefundOrigin::BulkJob,
+    ))
+    .await;
+
+    let entry_update = match refund_result {
+        Ok(services::ApplicationResponse::Json(refund)) => {
+            storage::RefundBatchEntryUpdate::Submitted {
+                refund_status: refund.status.into(),
+            }
+        }
+        Ok(_) => storage::RefundBatchEntryUpdate::Failed {
+            error_code: None,
+            error_message: Some("Unexpected response while creating the refund".to_string()),
+        },
+        // The refund reserved for this entry already exists, which happens when the entry is
+        // submitted again after a partial run of the batch
+        Err(error)
+            if matches!(
+                error.current_context(),
+                errors::ApiErrorResponse::DuplicateRefundRequest
+            ) =>
+        {
+            match state
+                .store
+                .find_refund_by_merchant_id_refund_id(
+                    &entry.merchant_id,
+                    &entry.refund_id,
+                    merchant_context.get_merchant_account().storage_scheme,
+                )
+                .await
+            {
+                Ok(refund) => storage::RefundBatchEntryUpdate::Submitted {
+                    refund_status: refund.refund_status,
+                },
+                Err(_) => {
+                    let error = error.current_context();
+                    storage::RefundBatchEntryUpdate::Failed {
+                        error_code: Some(error.error_code()),
+                        error_message: Some(error.error_message()),
+                    }
+                }
+            }
+        }
+        Err(error) => {
+            let error = error.current_context();
+            storage::RefundBatchEntryUpdate::Failed {
+                error_code: Some(error.error_code()),
+                error_message: Some(error.error_message()),
+            }
+        }
+    };
+
+    state
+        .store
+        .update_refund_batch_entry(entry, entry_update)
+        .await
+        .inspect_err(|error| logger::error!(?error, "Failed to update refund batch entry"))
+        .ok();
+}
+
+#[instrument(skip_all)]
+pub async fn refund_batch_retrieve_core(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    req: refunds::RefundBatchRetrieveRequest,
+) -> RouterResponse<refunds::RefundBatchResponse> {
+    let db = &*state.store;
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    let refund_batch = db
+        .find_refund_batch_by_merchant_id_batch_id(merchant_id, &req.batch_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Refund batch does not exist in our records".to_string(),
+        })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &refund_batch)?;
+
+    let entries = db
+        .find_refund_batch_entries_by_merchant_id_batch_id(merchant_id, &req.batch_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch refund batch entries")?;
+
+    Ok(services::ApplicationResponse::Json(
+        construct_refund_batch_response(refund_batch, entries),
+    ))
+}
+
+// ********************************************** PROCESS TRACKER **********************************************
+
+#[instrument(skip_all)]
+#[allow(clippy::too_many_arguments)]
+pub async fn schedule_refund_execution(
+    state: &SessionState,
+    refund: diesel_refund::Refund,
+    refund_type: api_models::refunds::RefundType,
+    merchant_context: &domain::MerchantContext,
+    payment_attempt: &storage::PaymentAttempt,
+    payment_intent: &storage::PaymentIntent,
+    creds_identifier: Option<String>,
+    split_refunds: Option<SplitRefundsRequest>,
+) -> RouterResult<diesel_refund::Refund> {
+    // refunds::RefundResponse> {
+    let db = &*state.store;
+    let runner = storage::ProcessTrackerRunner::RefundWorkflowRouter;
+    let task = "EXECUTE_REFUND";
+    let task_id = format!("{runner}_{task}_{}", refund.internal_reference_id);
+
+    let refund_process = db
+        .find_process_by_id(&task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the process id")?;
+
+    let result = match refund.refund_status {
+        enums::RefundStatus::Pending | enums::RefundStatus::ManualReview => {
+            match (refund.sent_to_gateway, refund_process) {
+                (false, None) => {
+                    // Execute the refund task based on refund_type
+                    match refund_type {
+                        api_models::refunds::RefundType::Scheduled => {
+                            add_refund_execute_task(db, &refund, runner)
+                                .await
+                                .change_context(errors::ApiErrorResponse::InternalServerError)
+                                .attach_printable_lazy(|| format!("Failed while pushing refund execute task to scheduler, refund_id: {}", refund.refund_id))?;
+
+                            Ok(refund)
+                        }
+                        api_models::refunds::RefundType::Instant => {
+                            let update_refund = Box::pin(trigger_refund_to_gateway(
+                                state,
+                                &refund,
+                                merchant_context,
+                                payment_attempt,
+                                payment_intent,
+                                creds_identifier,
+                                split_refunds,
+                            ))
+                            .await;
+
+                            match update_refund {
+                                Ok(updated_refund_data)
+                                    if refund_retry::is_refund_retry_pending(
+                                        &updated_refund_data,
+                                    ) =>
+                                {
+                                    refund_retry::schedule_refund_retry(
+                                        db,
+                                        &state.conf.refund_retry,
+                                        &updated_refund_data,
+                                        None,
+                                    )
+                                    .await?;
+                                    Ok(updated_refund_data)
+                                }
+                                Ok(updated_refund_data) => {
+                                    add_refund_sync_task(db, &updated_refund_data, runner)
+                                        .await
+                                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                                        .attach_printable_lazy(|| format!(
+                                            "Failed while pushing refund sync task in scheduler: refund_id: {}",
+                                            refund.refund_id
+                                        ))?;
+                                    Ok(updated_refund_data)
+                                }
+                                Err(err) => Err(err),
+                            }
+                        }
+                    }
+                }
+                _ => {
+                    // Sync the refund for status check
+                    //[#300]: return refund status response
+                    match refund_type {
+                        api_models::refunds::RefundType::Scheduled => {
+                            add_refund_sync_task(db, &refund, runner)
+                                .await
+                                .change_context(errors::ApiErrorResponse::InternalServerError)
+                                .attach_printable_lazy(|| format!("Failed while pushing refund sync task in scheduler: refund_id: {}", refund.refund_id))?;
+                            Ok(refund)
+                        }
+                        api_models::refunds::RefundType::Instant => {
+                            // [#255]: This is not possible in schedule_refund_execution as it will always be scheduled
+                            // sync_refund_with_gateway(data, &refund).await
+                            Ok(refund)
+                        }
+                    }
+                }
+            }
+        }
+        //  [#255]: This is not allowed to be otherwise or all
+        _ => Ok(refund),
+    }?;
+    Ok(result)
+}
+
+#[instrument(skip_all)]
+pub async fn sync_refund_with_gateway_workflow(
+    state: &SessionState,
+    refund_tracker: &storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let key_manager_state = &state.into();
+    let refund_core = serde_json::from_value::<diesel_refund::RefundCoreWorkflow>(
+        refund_tracker.tracking_data.clone(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable_lazy(|| {
+        format!(
+            "unable to convert into refund_core {:?}",
+            refund_tracker.tracking_data
+        )
+    })?;
+
+    let key_store = state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &refund_core.merchant_id,
+            &state.store.get_master_key().to_vec().into(),
+        )
+        .await?;
+
+    let merchant_account = state
+        .store
+        .find_merchant_account_by_merchant_id(
+            key_manager_state,
+            &refund_core.merchant_id,
+            &key_store,
+        )
+        .await?;
+    let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+        merchant_account.clone(),
+        key_store.clone(),
+    )));
+    let response = Box::pin(refund_retrieve_core_with_internal_reference_id(
+        state.clone(),
+        merchant_context,
+        None,
+        refund_core.refund_internal_reference_id,
+        Some(true),
+    ))
+    .await?;
+    let terminal_status = [
+        enums::RefundStatus::Success,
+        enums::RefundStatus::Failure,
+        enums::RefundStatus::TransactionFailure,
+    ];
+    match response.refund_status {
+        status if terminal_status.contains(&status) => {
+            state
+                .store
+                .as_scheduler()
+                .finish_process_with_business_status(
+                    refund_tracker.clone(),
+                    business_status::COMPLETED_BY_PT,
+                )
+                .await?
+        }
+        _ => {
+            _ = payment_sync::retry_sync_task(
+                &*state.store,
+                response.connector,
+                response.merchant_id,
+                refund_tracker.to_owned(),
+            )
+            .await?;
+        }
+    }
+
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn start_refund_workflow(
+    state: &SessionState,
+    refund_tracker: &storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    match refund_tracker.name.as_deref() {
+        Some("EXECUTE_REFUND") => {
+            Box::pin(trigger_refund_execute_workflow(state, refund_tracker)).await
+        }
+        Some("SYNC_REFUND") => {
+            Box::pin(sync_refund_with_gateway_workflow(state, refund_tracker)).await
+        }
+        Some("EXECUTE_REFUND_BATCH") => {
+            Box::pin(trigger_refund_batch_execute_workflow(state, refund_tracker)).await
+        }
+        _ => Err(errors::ProcessTrackerError::JobNotFound),
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn trigger_refund_execute_workflow(
+    state: &SessionState,
+    refund_tracker: &storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let refund_core = serde_json::from_value::<diesel_refund::RefundCoreWorkflow>(
+        refund_tracker.tracking_data.clone(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable_lazy(|| {
+        format!(
+            "unable to convert into refund_core {:?}",
+            refund_tracker.tracking_data
+        )
+    })?;
+    let key_manager_state = &state.into();
+    let key_store = state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
             &refund_core.merchant_id,
             &state.store.get_master_key().to_vec().into(),
         )
-        .await?;
+        .await?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(
+            key_manager_state,
+            &refund_core.merchant_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+        merchant_account.clone(),
+        key_store.clone(),
+    )));
+    let refund = db
+        .find_refund_by_internal_reference_id_merchant_id(
+            &refund_core.refund_internal_reference_id,
+            &refund_core.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
+    match (&refund.sent_to_gateway, &refund.refund_status) {
+        (false, enums::RefundStatus::Pending) => {
+            let merchant_account = db
+                .find_merchant_account_by_merchant_id(
+                    key_manager_state,
+                    &refund.merchant_id,
+                    &key_store,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+            let payment_attempt = db
+                .find_payment_attempt_by_connector_transaction_id_payment_id_merchant_id(
+                    &refund.connector_transaction_id,
+                    &refund_core.payment_id,
+                    &refund.merchant_id,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+            let payment_intent = db
+                .find_payment_intent_by_payment_id_merchant_id(
+                    &(state.into()),
+                    &payment_attempt.payment_id,
+                    &refund.merchant_id,
+                    &key_store,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+            let split_refunds = core_utils::get_split_refunds(SplitRefundInput {
+                split_payment_request: payment_intent.split_payments.clone(),
+                payment_charges: payment_attempt.charges.clone(),
+                charge_id: payment_attempt.charge_id.clone(),
+                refund_request: refund.split_refunds.clone(),
+            })?;
+
+            //trigger refund request to gateway
+            let updated_refund = Box::pin(trigger_refund_to_gateway(
+                state,
+                &refund,
+                &merchant_context,
+                &payment_attempt,
+                &payment_intent,
+                None,
+                split_refunds,
+            ))
+            .await?;
+            if refund_retry::is_refund_retry_pending(&updated_refund) {
+                refund_retry::schedule_refund_retry(
+                    db,
+                    &state.conf.refund_retry,
+                    &updated_refund,
+                    Some(refund_tracker),
+                )
+                .await?;
+            } else {
+                add_refund_sync_task(
+                    db,
+                    &updated_refund,
+                    storage::ProcessTrackerRunner::RefundWorkflowRouter,
+                )
+                .await?;
+            }
+        }
+        (true, enums::RefundStatus::Pending) => {
+            // create sync task
+            add_refund_sync_task(
+                db,
+                &refund,
+                storage::ProcessTrackerRunner::RefundWorkflowRouter,
+            )
+            .await?;
+        }
+        (_, _) => {
+            //mark task as finished
+            db.as_scheduler()
+                .finish_process_with_business_status(
+                    refund_tracker.clone(),
+                    business_status::COMPLETED_BY_PT,
+                )
+                .await?;
+        }
+    };
+    Ok(())
+}
+
+#[instrument]
+pub fn refund_to_refund_core_workflow_model(
+    refund: &diesel_refund::Refund,
+) -> diesel_refund::RefundCoreWorkflow {
+    diesel_refund::RefundCoreWorkflow {
+        refund_internal_reference_id: refund.internal_reference_id.clone(),
+        connector_transaction_id: refund.connector_transaction_id.clone(),
+        merchant_id: refund.merchant_id.clone(),
+        payment_id: refund.payment_id.clone(),
+        processor_transaction_data: refund.processor_transaction_data.clone(),
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn add_refund_sync_task(
+    db: &dyn db::StorageInterface,
+    refund: &diesel_refund::Refund,
+    runner: storage::ProcessTrackerRunner,
+) -> RouterResult<storage::ProcessTracker> {
+    let task = "SYNC_REFUND";
+    let process_tracker_id = format!("{runner}_{task}_{}", refund.internal_reference_id);
+    let schedule_time = common_utils::date_time::now();
+    let refund_workflow_tracking_data = refund_to_refund_core_workflow_model(refund);
+    let tag = ["REFUND"];
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        refund_workflow_tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct refund sync process tracker task")?;
+
+    let response = db
+        .insert_process(process_tracker_entry)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::DuplicateRefundRequest)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting task in process_tracker: refund_id: {}",
+                refund.refund_id
+            )
+        })?;
+    metrics::TASKS_ADDED_COUNT.add(1, router_env::metric_attributes!(("flow", "Refund")));
+
+    Ok(response)
+}
+
+#[instrument(skip_all)]
+pub async fn add_refund_execute_task(
+    db: &dyn db::StorageInterface,
+    refund: &diesel_refund::Refund,
+    runner: storage::ProcessTrackerRunner,
+) -> RouterResult<storage::ProcessTracker> {
+    add_refund_execute_task_at(db, refund, runner, common_utils::date_time::now()).await
+}
+
+/// Adds a task executing the refund at the schedule time, used for delaying the automatic retries
+/// of a refund
+#[instrument(skip_all)]
+pub async fn add_refund_execute_task_at(
+    db: &dyn db::StorageInterface,
+    refund: &diesel_refund::Refund,
+    runner: storage::ProcessTrackerRunner,
+    schedule_time: time::PrimitiveDateTime,
+) -> RouterResult<storage::ProcessTracker> {
+    let task = "EXECUTE_REFUND";
+    let process_tracker_id = format!("{runner}_{task}_{}", refund.internal_reference_id);
+    let tag = ["REFUND"];
+    let refund_workflow_tracking_data = refund_to_refund_core_workflow_model(refund);
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        refund_workflow_tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct refund execute process tracker task")?;
+
+    let response = db
+        .insert_process(process_tracker_entry)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::DuplicateRefundRequest)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting task in process_tracker: refund_id: {}",
+                refund.refund_id
+            )
+        })?;
+    Ok(response)
+}
+
+#[instrument(skip_all)]
+pub async fn trigger_refund_batch_execute_workflow(
+    state: &SessionState,
+    refund_batch_tracker: &storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let tracking_data = serde_json::from_value::<storage::RefundBatchWorkflow>(
+        refund_batch_tracker.tracking_data.clone(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable_lazy(|| {
+        format!(
+            "unable to convert into refund batch workflow {:?}",
+            refund_batch_tracker.tracking_data
+        )
+    })?;
+    let key_manager_state = &state.into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &tracking_data.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(
+            key_manager_state,
+            &tracking_data.merchant_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+        merchant_account,
+        key_store,
+    )));
+
+    let refund_batch = db
+        .find_refund_batch_by_merchant_id_batch_id(
+            &tracking_data.merchant_id,
+            &tracking_data.batch_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Refund batch does not exist in our records".to_string(),
+        })?;
+    let status = if is_refund_batch_processed(refund_batch.status) {
+        refund_batch.status
+    } else {
+        Box::pin(execute_refund_batch(
+            state,
+            &merchant_context,
+            tracking_data.profile_id,
+            refund_batch,
+        ))
+        .await?
+    };
+
+    if status == enums::RefundBatchStatus::Processing
+        && refund_batch_tracker.retry_count < consts::MAX_REFUND_BATCH_EXECUTE_RETRIES
+    {
+        let schedule_time = common_utils::date_time::now()
+            + time::Duration::seconds(consts::REFUND_BATCH_EXECUTE_RETRY_INTERVAL_IN_SECONDS);
+        db.as_scheduler()
+            .retry_process(refund_batch_tracker.clone(), schedule_time)
+            .await?;
+    } else {
+        db.as_scheduler()
+            .finish_process_with_business_status(
+                refund_batch_tracker.clone(),
+                business_status::COMPLETED_BY_PT,
+            )
+            .await?;
+    }
+    Ok(())
+}
+
+/// Adds a task submitting the pending entries of the refund batch
+#[instrument(skip_all)]
+pub async fn add_refund_batch_execute_task(
+    db: &dyn db::StorageInterface,
+    refund_batch: &storage::RefundBatch,
+    runner: storage::ProcessTrackerRunner,
+) -> RouterResult<storage::ProcessTracker> {
+    let task = "EXECUTE_REFUND_BATCH";
+    let process_tracker_id = format!("{runner}_{task}_{}", refund_batch.batch_id);
+    let tag = ["REFUND"];
+    let refund_batch_workflow_tracking_data = storage::RefundBatchWorkflow {
+        batch_id: refund_batch.batch_id.clone(),
+        merchant_id: refund_batch.merchant_id.clone(),
+        profile_id: refund_batch.profile_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        refund_batch_workflow_tracking_data,
+        None,
+        common_utils::date_time::now(),
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct refund batch execute process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting task in process_tracker: batch_id: {}",
+                refund_batch.batch_id
+            )
+        })
+}
+
+pub async fn get_refund_sync_process_schedule_time(
+    db: &dyn db::StorageInterface,
+    connector: &str,
+    merchant_id: &common_utils::id_type::MerchantId,
+    retry_count: i32,
+) -> Result<Option<time::PrimitiveDateTime>, errors::ProcessTrackerError> {
+    let redis_mapping: errors::CustomResult<process_data::ConnectorPTMapping, errors::RedisError> =
+        db::get_and_deserialize_key(
+            db,
+            &format!("pt_mapping_refund_sync_{connector}"),
+            "ConnectorPTMapping",
+        )
+        .await;
+
+    let mapping = match redis_mapping {
+        Ok(x) => x,
+        Err(err) => {
+            logger::error!("Error: while getting connector mapping: {err:?}");
+            process_data::ConnectorPTMapping::default()
+        }
+    };
+
+    let time_delta =
+        process_tracker_utils::get_schedule_time(mapping, merchant_id, retry_count + 1);
+
+    Ok(process_tracker_utils::get_time_from_delta(time_delta))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use super::*;
+    use crate::test_utils;
+
+    fn batch_entry_request(
+        payment_id: &str,
+        amount: Option<i64>,
+    ) -> refunds::RefundBatchEntryRequest {
+        refunds::RefundBatchEntryRequest {
+            payment_id: common_utils::id_type::PaymentId::try_from(std::borrow::Cow::Owned(
+                payment_id.to_string(),
+            ))
+            .unwrap(),
+            amount: amount.map(MinorUnit::new),
+            reason: None,
+        }
+    }
+
+    fn batch_entry(
+        entry_index: i32,
+        status: enums::RefundBatchEntryStatus,
+    ) -> storage::RefundBatchEntry {
+        let now = common_utils::date_time::now();
+        storage::RefundBatchEntry {
+            batch_id: "refbatch_test".to_string(),
+            entry_index,
+            merchant_id: test_utils::merchant_id(),
+            payment_id: common_utils::id_type::PaymentId::default(),
+            refund_id: format!("ref_{entry_index}"),
+            amount: None,
+            reason: None,
+            merchant_connector_id: None,
+            status,
+            refund_status: None,
+            error_code: None,
+            error_message: None,
+            created_at: now,
+            modified_at: now,
+        }
+    }
+
+    #[test]
+    fn test_validate_refund_batch_entry_for_mixed_batch() {
+        let succeeded = Some(RefundBatchPaymentDetails {
+            status: enums::IntentStatus::Succeeded,
+            amount_captured: Some(MinorUnit::new(1000)),
+        });
+        let failed = Some(RefundBatchPaymentDetails {
+            status: enums::IntentStatus::Failed,
+            amount_captured: None,
+        });
+
+        let results = [
+            validate_refund_batch_entry(&batch_entry_request("pay_valid", None), false, succeeded),
+            validate_refund_batch_entry(
+                &batch_entry_request("pay_partial", Some(500)),
+                false,
+                succeeded,
+            ),
+            validate_refund_batch_entry(&batch_entry_request("pay_valid", None), true, succeeded),
+            validate_refund_batch_entry(&batch_entry_request("pay_missing", None), false, None),
+            validate_refund_batch_entry(&batch_entry_request("pay_failed", None), false, failed),
+            validate_refund_batch_entry(
+                &batch_entry_request("pay_zero", Some(0)),
+                false,
+                succeeded,
+            ),
+            validate_refund_batch_entry(
+                &batch_entry_request("pay_excess", Some(1001)),
+                false,
+                succeeded,
+            ),
+        ];
+
+        assert!(results[0].is_ok());
+        assert!(results[1].is_ok());
+        assert!(matches!(
+            results[2],
+            Err(errors::ApiErrorResponse::InvalidRequestData { .. })
+        ));
+        assert!(matches!(
+            results[3],
+            Err(errors::ApiErrorResponse::PaymentNotFound)
+        ));
+        assert!(matches!(
+            results[4],
+            Err(errors::ApiErrorResponse::PaymentUnexpectedState { .. })
+        ));
+        assert!(matches!(
+            results[5],
+            Err(errors::ApiErrorResponse::InvalidDataFormat { .. })
+        ));
+        assert!(matches!(
+            results[6],
+            Err(errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_refund_batch_is_scheduled_on_the_process_tracker() {
+        let mockdb = db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create a mock DB");
+        let db: &dyn db::StorageInterface = &mockdb;
+        let now = common_utils::date_time::now();
+        let refund_batch = storage::RefundBatch {
+            batch_id: "refbatch_test".to_string(),
+            merchant_id: test_utils::merchant_id(),
+            profile_id: Some(common_utils::id_type::ProfileId::default()),
+            organization_id: common_utils::id_type::OrganizationId::default(),
+            status: enums::RefundBatchStatus::Pending,
+            total_entries: 2,
+            created_at: now,
+            modified_at: now,
+        };
+
+        let process = add_refund_batch_execute_task(
+            db,
+            &refund_batch,
+            storage::ProcessTrackerRunner::RefundWorkflowRouter,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(process.name.as_deref(), Some("EXECUTE_REFUND_BATCH"));
+        assert_eq!(process.runner.as_deref(), Some("REFUND_WORKFLOW_ROUTER"));
+        assert_eq!(
+            serde_json::from_value::<storage::RefundBatchWorkflow>(process.tracking_data).unwrap(),
+            storage::RefundBatchWorkflow {
+                batch_id: refund_batch.batch_id,
+                merchant_id: refund_batch.merchant_id,
+                profile_id: refund_batch.profile_id,
+            }
+        );
+    }
+
+    #[test]
+    fn test_refund_batch_status_is_derived_from_entries() {
+        let status_of = |statuses: &[enums::RefundBatchEntryStatus]| {
+            let entries = statuses
+                .iter()
+                .zip(0..)
+                .map(|(status, entry_index)| batch_entry(entry_index, *status))
+                .collect::<Vec<_>>();
+            get_refund_batch_status(&aggregate_refund_batch_entries(&entries))
+        };
+
+        assert_eq!(
+            status_of(&[
+                enums::RefundBatchEntryStatus::Submitted,
+                enums::RefundBatchEntryStatus::Submitted,
+            ]),
+            enums::RefundBatchStatus::Completed
+        );
+        assert_eq!(
+            status_of(&[
+                enums::RefundBatchEntryStatus::Submitted,
+                enums::RefundBatchEntryStatus::Invalid,
+                enums::RefundBatchEntryStatus::Failed,
+            ]),
+            enums::RefundBatchStatus::PartiallyFailed
+        );
+        assert_eq!(
+            status_of(&[
+                enums::RefundBatchEntryStatus::Invalid,
+                enums::RefundBatchEntryStatus::Failed,
+            ]),
+            enums::RefundBatchStatus::Failed
+        );
+        // Entries which could not be marked as submitted are left to a later run of the batch
+        assert_eq!(
+            status_of(&[
+                enums::RefundBatchEntryStatus::Submitted,
+                enums::RefundBatchEntryStatus::Pending,
+            ]),
+            enums::RefundBatchStatus::Processing
+        );
+        assert!(!is_refund_batch_processed(
+            enums::RefundBatchStatus::Processing
+        ));
+        assert!(is_refund_batch_processed(
+            enums::RefundBatchStatus::PartiallyFailed
+        ));
+    }
+
+    #[test]
+    fn test_aggregate_refund_batch_entries() {
+        let entries = vec![
+            batch_entry(0, enums::RefundBatchEntryStatus::Submitted),
+            batch_entry(1, enums::RefundBatchEntryStatus::Invalid),
+            batch_entry(2, enums::RefundBatchEntryStatus::Submitted),
+            batch_entry(3, enums::RefundBatchEntryStatus::Failed),
+            batch_entry(4, enums::RefundBatchEntryStatus::Pending),
+        ];
+
+        assert_eq!(
+            aggregate_refund_batch_entries(&entries),
+            refunds::RefundBatchSummary {
+                pending: 1,
+                invalid: 1,
+                submitted: 2,
+                failed: 1,
+            }
+        );
+        assert_eq!(
+            aggregate_refund_batch_entries(&[]),
+            refunds::RefundBatchSummary::default()
+        );
+    }
+
+    fn refund_with_status(refund_status: enums::RefundStatus) -> diesel_refund::Refund {
+        diesel_refund::Refund {
+            refund_status,
+            ..test_utils::refund("ref_1")
+        }
+    }
+
+    fn refund_webhook_object_of_version(
+        refund: diesel_refund::Refund,
+        payload_version: u8,
+    ) -> serde_json::Value {
+        let content =
+            api::OutgoingWebhookContent::RefundDetails(Box::new(api::RefundWebhookResponse::new(
+                api::RefundResponse::foreign_from(refund),
+                payload_version,
+            )));
+        serde_json::to_value(content).unwrap()["object"].clone()
+    }
+
+    fn refund_webhook_object(refund: diesel_refund::Refund) -> serde_json::Value {
+        refund_webhook_object_of_version(
+            refund,
+            api_models::consts::LATEST_OUTGOING_WEBHOOK_PAYLOAD_VERSION,
+        )
+    }
+
+    #[test]
+    fn test_failed_refund_webhook_payload_contains_error_details() {
+        let refund = diesel_refund::Refund {
+            refund_error_code: Some("expired_or_canceled_card".to_string()),
+            refund_error_message: Some("The card has expired".to_string()),
+            unified_code: Some("UE_9000".to_string()),
+            unified_message: Some("Something went wrong".to_string()),
+            issuer_error_code: Some("54".to_string()),
+            issuer_error_message: Some("Expired card".to_string()),
+            ..refund_with_status(enums::RefundStatus::Failure)
+        };
+
+        let object = refund_webhook_object(refund);
+
+        assert_eq!(object["unified_code"], "UE_9000");
+        assert_eq!(object["unified_message"], "Something went wrong");
+        assert_eq!(object["issuer_error_code"], "54");
+        assert_eq!(object["issuer_error_message"], "Expired card");
+        assert_eq!(object["error_category"], "processor_declined");
+        assert_eq!(object["recommended_action"], "contact_processor");
+    }
+
+    #[test]
+    fn test_successful_refund_webhook_payload_omits_error_details() {
+        let object = refund_webhook_object(refund_with_status(enums::RefundStatus::Success));
+
+        // The fields which were already part of the payload keep being sent as `null`
+        for field in [
+            "unified_code",
+            "unified_message",
+            "issuer_error_code",
+            "issuer_error_message",
+        ] {
+            assert_eq!(object.get(field), Some(&serde_json::Value::Null));
+        }
+        for field in ["error_category", "recommended_action"] {
+            assert!(object.get(field).is_none(), "{field} should be omitted");
+        }
+        assert_eq!(object["status"], "succeeded");
+    }
+
+    /// The fields of the webhook of a successful refund, as of version 2 of the outgoing webhook
+    /// payload
+    const REFUND_WEBHOOK_PAYLOAD_V2_FIELDS: [&str; 22] = [
+        "refund_id",
+        "payment_id",
+        "amount",
+        "refund_amount_formatted",
+        "total_amount_formatted",
+        "currency",
+        "status",
+        "reason",
+        "metadata",
+        "error_message",
+        "error_code",
+        "unified_code",
+        "unified_message",
+        "created_at",
+        "updated_at",
+        "connector",
+        "profile_id",
+        "merchant_connector_id",
+        "split_refunds",
+        "issuer_error_code",
+        "issuer_error_message",
+        "origin",
+    ];
+
+    fn webhook_object_fields(object: &serde_json::Value) -> std::collections::BTreeSet<&str> {
+        object
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(String::as_str)
+            .collect()
+    }
+
+    fn adyen_split_refund() -> diesel_refund::Refund {
+        let split_item = |reference: &str, amount: i64| common_types::domain::AdyenSplitItem {
+            amount: Some(MinorUnit::new(amount)),
+            split_type: common_enums::AdyenSplitType::BalanceAccount,
+            account: Some("BA_sub_merchant".to_string()),
+            reference: reference.to_string(),
+            description: None,
+        };
+        diesel_refund::Refund {
+            split_refunds: Some(common_types::refunds::SplitRefund::AdyenSplitRefund(
+                common_types::domain::AdyenSplitData {
+                    store: Some("store_1".to_string()),
+                    split_items: vec![split_item("split_1", 700), split_item("split_2", 300)],
+                },
+            )),
+            ..refund_with_status(enums::RefundStatus::Success)
+        }
+    }
+
+    #[test]
+    fn test_refund_webhook_payload_keeps_version_2_shape() {
+        for refund in [
+            refund_with_status(enums::RefundStatus::Success),
+            adyen_split_refund(),
+        ] {
+            let object = refund_webhook_object_of_version(refund, 2);
+
+            assert_eq!(
+                webhook_object_fields(&object),
+                REFUND_WEBHOOK_PAYLOAD_V2_FIELDS.into_iter().collect()
+            );
+        }
+    }
+
+    #[test]
+    fn test_refund_webhook_payload_of_version_3_without_split_refunds() {
+        let object = refund_webhook_object(refund_with_status(enums::RefundStatus::Success));
+
+        assert_eq!(
+            webhook_object_fields(&object),
+            REFUND_WEBHOOK_PAYLOAD_V2_FIELDS.into_iter().collect()
+        );
+    }
+
+    #[test]
+    fn test_split_refund_webhook_payload_contains_split_refund_summary() {
+        let object = refund_webhook_object(adyen_split_refund());
+
+        // The split refund details are still sent as before, version 3 adds the summary on top
+        assert_eq!(
+            webhook_object_fields(&object),
+            REFUND_WEBHOOK_PAYLOAD_V2_FIELDS
+                .into_iter()
+                .chain(["split_refunds_summary"])
+                .collect()
+        );
+        assert_eq!(
+            object["split_refunds"],
+            refund_webhook_object_of_version(adyen_split_refund(), 2)["split_refunds"]
+        );
+        assert_eq!(
+            object["split_refunds"]["adyen_split_refund"]["store"],
+            "store_1"
+        );
+        // The summary leaves out the accounts and references of the split items
+        assert_eq!(
+            object["split_refunds_summary"],
+            serde_json::json!({
+                "split_type": "adyen_split_refund",
+                "split_items_count": 2,
+                "split_items_amount": 1000
+            })
+        );
+    }
+
+    /// The outgoing webhook as it was sent before outgoing webhook payloads were versioned
+    #[derive(serde::Serialize)]
+    struct UnversionedOutgoingWebhook {
+        merchant_id: common_utils::id_type::MerchantId,
+        event_id: String,
+        event_type: common_enums::EventType,
+        content: UnversionedOutgoingWebhookContent,
+        #[serde(with = "common_utils::custom_serde::iso8601")]
+        timestamp: time::PrimitiveDateTime,
+    }
+
+    #[derive(serde::Serialize)]
+    #[serde(tag = "type", content = "object", rename_all = "snake_case")]
+    enum UnversionedOutgoingWebhookContent {
+        RefundDetails(Box<api::RefundResponse>),
+    }
+
+    fn unversioned_refund_webhook(refund: diesel_refund::Refund) -> String {
+        let refund_response = api::RefundResponse {
+            // Not part of the refund response before outgoing webhook payloads were versioned
+            error_category: None,
+            recommended_action: None,
+            ..api::RefundResponse::foreign_from(refund.clone())
+        };
+        serde_json::to_string(&UnversionedOutgoingWebhook {
+            merchant_id: refund.merchant_id,
+            event_id: "evt_1".to_string(),
+            event_type: common_enums::EventType::RefundFailed,
+            content: UnversionedOutgoingWebhookContent::RefundDetails(Box::new(refund_response)),
+            timestamp: refund.created_at,
+        })
+        .unwrap()
+    }
+
+    fn refund_webhook(refund: diesel_refund::Refund, payload_version: u8) -> String {
+        serde_json::to_string(&api::OutgoingWebhook {
+            merchant_id: refund.merchant_id.clone(),
+            event_id: "evt_1".to_string(),
+            event_type: common_enums::EventType::RefundFailed,
+            timestamp: refund.created_at,
+            content: api::OutgoingWebhookContent::RefundDetails(Box::new(
+                api::RefundWebhookResponse::new(
+                    api::RefundResponse::foreign_from(refund),
+                    payload_version,
+                ),
+            )),
+            payload_version,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_refund_webhook_of_default_payload_version_matches_unversioned_webhook() {
+        let failed_refund = diesel_refund::Refund {
+            refund_error_code: Some("expired_or_canceled_card".to_string()),
+            refund_error_message: Some("The card has expired".to_string()),
+            ..refund_with_status(enums::RefundStatus::Failure)
+        };
+
+        for refund in [
+            failed_refund,
+            refund_with_status(enums::RefundStatus::Success),
+            adyen_split_refund(),
+        ] {
+            assert_eq!(
+                refund_webhook(
+                    refund.clone(),
+                    api_models::consts::DEFAULT_OUTGOING_WEBHOOK_PAYLOAD_VERSION
+                ),
+                unversioned_refund_webhook(refund)
+            );
+        }
+    }
+
+    #[test]
+    fn test_refund_webhook_of_newer_payload_version_is_superset_of_unversioned_webhook() {
+        let refund = diesel_refund::Refund {
+            refund_error_code: Some("expired_or_canceled_card".to_string()),
+            ..adyen_split_refund()
+        };
+        let unversioned: serde_json::Value =
+            serde_json::from_str(&unversioned_refund_webhook(refund.clone())).unwrap();
+
+        for payload_version in 2..=api_models::consts::LATEST_OUTGOING_WEBHOOK_PAYLOAD_VERSION {
+            let versioned: serde_json::Value =
+                serde_json::from_str(&refund_webhook(refund.clone(), payload_version)).unwrap();
+
+            assert_eq!(versioned["payload_version"], payload_version);
+            for (field, value) in unversioned["content"]["object"].as_object().unwrap() {
+                assert_eq!(&versioned["content"]["object"][field], value, "{field}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_stripe_split_refund_summary() {
+        let summary = api_models::refunds::SplitRefundSummary::from(
+            &common_types::refunds::SplitRefund::StripeSplitRefund(
+                common_types::refunds::StripeSplitRefundRequest {
+                    revert_platform_fee: Some(true),
+                    revert_transfer: None,
+                },
+            ),
+        );
+
+        assert_eq!(
+            serde_json::to_value(summary).unwrap(),
+            serde_json::json!({
+                "split_type": "stripe_split_refund",
+                "revert_platform_fee": true
+            })
+        );
+    }
+
+    fn export_fields(fields: &[&str]) -> Vec<api_models::refunds::RefundExportField> {
+        let fields = fields.iter().map(ToString::to_string).collect::<Vec<_>>();
+        validator::validate_refund_export_fields(Some(fields.as_slice())).unwrap()
+    }
+
+    #[cfg(feature = "olap")]
+    #[test]
+    fn test_refunds_csv_export_with_selected_fields() {
+        let refund = diesel_refund::Refund {
+            refund_arn: Some("74537605136200000000001".to_string()),
+            issuer_error_code: Some("05".to_string()),
+            ..refund_with_status(enums::RefundStatus::Success)
+        };
+
+        let export = write_refunds_csv_export(
+            &[refund],
+            &export_fields(&[
+                "refund_id",
+                "refund_arn",
+                "issuer_error_code",
+                "unified_code",
+                "refund_amount_formatted",
+                "status",
+            ]),
+        )
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(export).unwrap(),
+            "refund_id,refund_arn,issuer_error_code,unified_code,refund_amount_formatted,status\n\
+             ref_1,74537605136200000000001,05,,10.00,succeeded\n"
+        );
+    }
+
+    #[cfg(feature = "olap")]
+    #[test]
+    fn test_refunds_csv_export_escapes_free_text_fields() {
+        let refund = diesel_refund::Refund {
+            refund_reason: Some("Customer said \"damaged, twice\"\nReturned".to_string()),
+            refund_error_message: Some("Declined, \"do not honor\"".to_string()),
+            ..refund_with_status(enums::RefundStatus::Failure)
+        };
+
+        let export = write_refunds_csv_export(
+            &[refund],
+            &export_fields(&["refund_id", "reason", "error_message"]),
+        )
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(export.clone()).unwrap(),
+            "refund_id,reason,error_message\n\
+             ref_1,\"Customer said \"\"damaged, twice\"\"\nReturned\",\"Declined, \"\"do not honor\"\"\"\n"
+        );
+
+        let mut reader = csv::Reader::from_reader(export.as_slice());
+        let record = reader.records().next().unwrap().unwrap();
+        assert_eq!(&record[1], "Customer said \"damaged, twice\"\nReturned");
+        assert_eq!(&record[2], "Declined, \"do not honor\"");
+    }
+
+    #[cfg(feature = "olap")]
+    #[test]
+    fn test_refunds_jsonl_export_keeps_the_order_of_selected_fields() {
+        let refund = diesel_refund::Refund {
+            refund_reason: Some("Line one\nline \"two\"".to_string()),
+            ..refund_with_status(enums::RefundStatus::Success)
+        };
+
+        let export = write_refunds_jsonl_export(
+            &[refund.clone(), refund],
+            &export_fields(&["status", "refund_arn", "amount", "reason"]),
+        )
+        .unwrap();
+
+        let line = r#"{"status":"succeeded","refund_arn":null,"amount":1000,"reason":"Line one\nline \"two\""}"#;
+        assert_eq!(
+            String::from_utf8(export).unwrap(),
+            format!("{line}\n{line}\n")
+        );
+    }
+
+    #[test]
+    fn test_refund_export_fields_validation() {
+        use api_models::refunds::RefundExportField;
+        use strum::IntoEnumIterator;
+
+        assert_eq!(
+            validator::validate_refund_export_fields(None).unwrap(),
+            RefundExportField::iter().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            export_fields(&["refund_arn", "refund_id"]),
+            vec![RefundExportField::RefundArn, RefundExportField::RefundId]
+        );
+
+        let error = validator::validate_refund_export_fields(Some(&["refund_arm".to_string()][..]))
+            .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { message }
+                if message.contains("'refund_arm'") && message.contains("refund_arn, status")
+        ));
+        assert!(validator::validate_refund_export_fields(Some(&[][..])).is_err());
+    }
+
+    fn merchant_connector_id(id: &str) -> common_utils::id_type::MerchantConnectorAccountId {
+        common_utils::id_type::MerchantConnectorAccountId::wrap(id.to_string()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_connector_initiated_refund_is_stamped_with_merchant_connector_id() {
+        let mockdb = db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create a mock DB");
+        let db: &dyn db::StorageInterface = &mockdb;
+        let payment_attempt = db
+            .insert_payment_attempt(
+                storage::PaymentAttemptNew {
+                    merchant_connector_id: Some(merchant_connector_id("mca_1")),
+                    ..test_utils::payment_attempt_new("attempt_1")
+                },
+                enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+        let refund_details = ConnectorInitiatedRefundDetails {
+            connector_transaction_id: "pi_1".to_string(),
+            refund_amount: MinorUnit::new(400),
+            currency: enums::Currency::USD,
+            reason: None,
+        };
+
+        let refund_new = construct_connector_initiated_refund_new(
+            "ref_1".to_string(),
+            "stripe",
+            "re_1".to_string(),
+            enums::RefundStatus::Success,
+            refund_details,
+            &payment_attempt,
+            payment_attempt.merchant_connector_id.clone(),
+        );
+
+        assert_eq!(
+            refund_new.merchant_connector_id,
+            Some(merchant_connector_id("mca_1"))
+        );
+        assert_eq!(refund_new.attempt_id, "attempt_1");
+        assert_eq!(refund_new.origin, enums::RefundOrigin::ConnectorWebhook);
+    }
+
+    fn connector_initiated_refund_profile_id() -> common_utils::id_type::ProfileId {
+        common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from("pro_1")).unwrap()
+    }
+
+    /// Stores a payment of 10.00 USD captured through stripe, in the profile
+    /// [`connector_initiated_refund_profile_id`], whose connector transaction id is the given one
+    async fn insert_payment_attempt_with_connector_transaction_id(
+        state: &SessionState,
+        key_store: &domain::MerchantKeyStore,
+        connector_transaction_id: &str,
+    ) -> storage::PaymentAttempt {
+        let db = &*state.store;
+        let storage_scheme = enums::MerchantStorageScheme::PostgresOnly;
+        let payment_attempt = db
+            .insert_payment_attempt(
+                storage::PaymentAttemptNew {
+                    merchant_connector_id: Some(merchant_connector_id("mca_1")),
+                    profile_id: connector_initiated_refund_profile_id(),
+                    ..test_utils::payment_attempt_new("attempt_1")
+                },
+                storage_scheme,
+            )
+            .await
+            .unwrap();
+        db.insert_payment_intent(
+            &state.into(),
+            test_utils::payment_intent(
+                payment_attempt.payment_id.clone(),
+                &connector_initiated_refund_profile_id(),
+            ),
+            key_store,
+            storage_scheme,
+        )
+        .await
+        .unwrap();
+        db.update_payment_attempt_with_attempt_id(
+            payment_attempt,
+            storage::PaymentAttemptUpdate::ConnectorResponse {
+                authentication_data: None,
+                encoded_data: None,
+                connector_transaction_id: Some(connector_transaction_id.to_string()),
+                connector: Some("stripe".to_string()),
+                charges: None,
+                updated_by: "test".to_string(),
+            },
+            storage_scheme,
+        )
+        .await
+        .unwrap()
+    }
+
+    async fn connector_initiated_refund_setup(
+        connector_transaction_id: &str,
+    ) -> (
+        SessionState,
+        domain::MerchantContext,
+        domain::Profile,
+        storage::PaymentAttempt,
+    ) {
+        let state = test_utils::mock_session_state(Default::default()).await;
+        let key_store = test_utils::merchant_key_store(&state).await;
+        let business_profile = test_utils::insert_profile_with_webhook_url(
+            &state,
+            &key_store,
+            &connector_initiated_refund_profile_id(),
+        )
+        .await;
+        let payment_attempt = insert_payment_attempt_with_connector_transaction_id(
+            &state,
+            &key_store,
+            connector_transaction_id,
+        )
+        .await;
+        (
+            state,
+            test_utils::merchant_context(key_store),
+            business_profile,
+            payment_attempt,
+        )
+    }
+
+    fn connector_initiated_refund_details(
+        connector_transaction_id: &str,
+    ) -> ConnectorInitiatedRefundDetails {
+        ConnectorInitiatedRefundDetails {
+            connector_transaction_id: connector_transaction_id.to_string(),
+            refund_amount: MinorUnit::new(400),
+            currency: enums::Currency::USD,
+            reason: Some("requested_by_customer".to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_connector_initiated_refund_is_created_once_across_webhook_deliveries() {
+        let (state, merchant_context, business_profile, payment_attempt) =
+            connector_initiated_refund_setup("pi_1").await;
+        let storage_scheme = enums::MerchantStorageScheme::PostgresOnly;
+
+        let create_refund = |refund_status| {
+            create_connector_initiated_refund(
+                &state,
+                &merchant_context,
+                &business_profile,
+                "stripe",
+                "re_1".to_string(),
+                refund_status,
+                connector_initiated_refund_details("pi_1"),
+            )
+        };
+
+        // The first delivery of the webhook creates the refund
+        let refund = Box::pin(create_refund(enums::RefundStatus::Success))
+            .await
+            .unwrap();
+        assert_eq!(
+            refund.refund_id,
+            get_connector_initiated_refund_id("stripe", "re_1").unwrap()
+        );
+        assert_eq!(refund.attempt_id, payment_attempt.attempt_id);
+        assert_eq!(refund.refund_amount, MinorUnit::new(400));
+        assert_eq!(refund.refund_status, enums::RefundStatus::Success);
+        assert_eq!(
+            refund.merchant_connector_id,
+            Some(merchant_connector_id("mca_1"))
+        );
+        assert_eq!(refund.origin, enums::RefundOrigin::ConnectorWebhook);
+        assert_eq!(
+            refund.updated_by,
+            consts::CONNECTOR_WEBHOOK_REFUND_UPDATED_BY
+        );
+
+        // A redelivery of the same webhook resolves to the refund created by the first one
+        let redelivered_refund = Box::pin(create_refund(enums::RefundStatus::Success))
+            .await
+            .unwrap();
+        assert_eq!(redelivered_refund, refund);
+
+        let refunds = state
+            .store
+            .find_refund_by_payment_id_merchant_id(
+                &payment_attempt.payment_id,
+                &test_utils::merchant_id(),
+                storage_scheme,
+            )
+            .await
+            .unwrap();
+        assert_eq!(refunds, vec![refund]);
+    }
+
+    #[tokio::test]
+    async fn test_connector_initiated_refund_with_long_ids_is_found_by_connector_refund_id() {
+        let storage_scheme = enums::MerchantStorageScheme::PostgresOnly;
+        let connector_transaction_id = format!("pi_{}", "1".repeat(200));
+        let connector_refund_id = format!("re_{}", "1".repeat(200));
+        let (state, merchant_context, business_profile, _) =
+            connector_initiated_refund_setup(&connector_transaction_id).await;
+
+        let not_found = find_refund_by_connector_refund_id(
+            &*state.store,
+            &test_utils::merchant_id(),
+            &connector_refund_id,
+            "stripe",
+            storage_scheme,
+        )
+        .await;
+        assert!(not_found.is_err_and(|error| error.current_context().is_db_not_found()));
+
+        let refund = Box::pin(create_connector_initiated_refund(
+            &state,
+            &merchant_context,
+            &business_profile,
+            "stripe",
+            connector_refund_id.clone(),
+            enums::RefundStatus::Pending,
+            connector_initiated_refund_details(&connector_transaction_id),
+        ))
+        .await
+        .unwrap();
+
+        // Ids which are too long to be stored are hashed, the raw ids are kept alongside them
+        assert!(refund
+            .connector_refund_id
+            .as_ref()
+            .is_some_and(|id| id.get_id().starts_with("hs_hash_")));
+        assert_eq!(
+            refund.processor_refund_data,
+            Some(connector_refund_id.clone())
+        );
+
+        let found = find_refund_by_connector_refund_id(
+            &*state.store,
+            &test_utils::merchant_id(),
+            &connector_refund_id,
+            "stripe",
+            storage_scheme,
+        )
+        .await
+        .unwrap();
+        assert_eq!(found, refund);
+    }
+
+    #[tokio::test]
+    async fn test_connector_initiated_refund_is_rejected_unless_it_matches_the_payment() {
+        let (state, merchant_context, business_profile, payment_attempt) =
+            connector_initiated_refund_setup("pi_1").await;
+        let other_profile = test_utils::insert_profile_with_webhook_url(
+            &state,
+            merchant_context.get_merchant_key_store(),
+            &common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from("pro_2")).unwrap(),
+        )
+        .await;
+        let create_refund = |business_profile, connector_name, refund_amount| {
+            create_connector_initiated_refund(
+                &state,
+                &merchant_context,
+                business_profile,
+                connector_name,
+                "re_1".to_string(),
+                enums::RefundStatus::Success,
+                ConnectorInitiatedRefundDetails {
+                    refund_amount: MinorUnit::new(refund_amount),
+                    ..connector_initiated_refund_details("pi_1")
+                },
+            )
+        };
+
+        // The payment was processed through stripe
+        let error = Box::pin(create_refund(&business_profile, "adyen", 400))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::WebhookUnprocessableEntity
+        ));
+        // The payment belongs to another profile than the one the webhook was received for
+        let error = Box::pin(create_refund(&other_profile, "stripe", 400))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::WebhookUnprocessableEntity
+        ));
+        // Only 10.00 USD were captured
+        let error = Box::pin(create_refund(&business_profile, "stripe", 1200))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount
+        ));
+
+        let refunds = state
+            .store
+            .find_refund_by_payment_id_merchant_id(
+                &payment_attempt.payment_id,
+                &test_utils::merchant_id(),
+                enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+        assert!(refunds.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_connector_initiated_refund_counts_existing_refunds_of_the_payment() {
+        let (state, merchant_context, business_profile, _) =
+            connector_initiated_refund_setup("pi_1").await;
+        let create_refund = |connector_refund_id: &str, refund_amount| {
+            create_connector_initiated_refund(
+                &state,
+                &merchant_context,
+                &business_profile,
+                "stripe",
+                connector_refund_id.to_string(),
+                enums::RefundStatus::Success,
+                ConnectorInitiatedRefundDetails {
+                    refund_amount: MinorUnit::new(refund_amount),
+                    ..connector_initiated_refund_details("pi_1")
+                },
+            )
+        };
+
+        Box::pin(create_refund("re_1", 600)).await.unwrap();
+        // Redeliveries are not counted against the amount left to refund
+        Box::pin(create_refund("re_1", 600)).await.unwrap();
+
+        let error = Box::pin(create_refund("re_2", 500)).await.unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount
+        ));
+        Box::pin(create_refund("re_2", 400)).await.unwrap();
+    }
+
+    #[test]
+    fn test_refund_without_origin_is_reported_as_legacy() {
+        let mut refund =
+            serde_json::to_value(refund_with_status(enums::RefundStatus::Success)).unwrap();
+        refund.as_object_mut().unwrap().remove("origin");
+        let refund: diesel_refund::Refund = serde_json::from_value(refund).unwrap();
+
+        assert_eq!(refund.origin, enums::RefundOrigin::Legacy);
+        assert_eq!(
+            api::RefundResponse::foreign_from(refund).origin,
+            enums::RefundOrigin::Legacy
+        );
+    }
+
+    #[cfg(feature = "olap")]
+    #[tokio::test]
+    async fn test_refunds_list_filters_by_origin() {
+        let mockdb = db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create a mock DB");
+        let db: &dyn db::StorageInterface = &mockdb;
+        let merchant_id = test_utils::merchant_id();
+        for (refund_id, origin) in [
+            ("ref_1", enums::RefundOrigin::Api),
+            ("ref_2", enums::RefundOrigin::BulkJob),
+            ("ref_3", enums::RefundOrigin::ConnectorWebhook),
+        ] {
+            db.insert_refund(
+                diesel_refund::RefundNew {
+                    merchant_id: merchant_id.clone(),
+                    profile_id: Some(common_utils::id_type::ProfileId::default()),
+                    origin,
+                    ..test_utils::refund_new(refund_id)
+                },
+                enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+        }
+        let constraints = hyperswitch_domain_models::refunds::RefundListConstraints {
+            payment_id: None,
+            refund_id: None,
+            profile_id: None,
+            limit: None,
+            offset: None,
+            time_range: None,
+            amount_filter: None,
+            connector: None,
+            merchant_connector_id: None,
+            currency: None,
+            refund_status: None,
+            origin: Some(vec![
+                enums::RefundOrigin::BulkJob,
+                enums::RefundOrigin::ConnectorWebhook,
+            ]),
+            unified_code: None,
+            issuer_error_code: None,
+            has_unified_error: None,
+            starting_after: None,
+        };
+
+        let refunds = db
+            .filter_refund_by_constraints(
+                &merchant_id,
+                &constraints,
+                enums::MerchantStorageScheme::PostgresOnly,
+                10,
+                0,
+            )
+            .await
+            .unwrap();
+        let mut refund_ids = refunds
+            .iter()
+            .map(|refund| refund.refund_id.as_str())
+            .collect::<Vec<_>>();
+        refund_ids.sort_unstable();
+        assert_eq!(refund_ids, vec!["ref_2", "ref_3"]);
+
+        let count = db
+            .get_total_count_of_refunds(
+                &merchant_id,
+                &constraints,
+                enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+        assert_eq!(count, 2);
+    }
+
+    #[cfg(feature = "olap")]
+    #[tokio::test]
+    async fn test_refunds_list_filters_by_error_codes() {
+        let mockdb = db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create a mock DB");
+        let db: &dyn db::StorageInterface = &mockdb;
+        let merchant_id = test_utils::merchant_id();
+        for refund_id in ["ref_1", "ref_2", "ref_3"] {
+            db.insert_refund(
+                diesel_refund::RefundNew {
+                    merchant_id: merchant_id.clone(),
+                    profile_id: Some(common_utils::id_type::ProfileId::default()),
+                    ..test_utils::refund_new(refund_id)
+                },
+                enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+        }
+        for refund in mockdb.refunds.lock().await.iter_mut() {
+            let (refund_status, unified_code, issuer_error_code) = match refund.refund_id.as_str() {
+                "ref_2" => (enums::RefundStatus::Failure, Some("UE_9000"), Some("59")),
+                "ref_3" => (enums::RefundStatus::Pending, Some("UE_9000"), Some("05")),
+                _ => (enums::RefundStatus::Failure, None, None),
+            };
+            refund.refund_status = refund_status;
+            refund.unified_code = unified_code.map(str::to_string);
+            refund.issuer_error_code = issuer_error_code.map(str::to_string);
+        }
+
+        let list_refund_ids =
+            |unified_code: Option<&str>, issuer_error_code: Option<&str>, has_unified_error| {
+                let constraints = hyperswitch_domain_models::refunds::RefundListConstraints {
+                    payment_id: None,
+                    refund_id: None,
+                    profile_id: None,
+                    limit: None,
+                    offset: None,
+                    time_range: None,
+                    amount_filter: None,
+                    connector: None,
+                    merchant_connector_id: None,
+                    currency: None,
+                    refund_status: Some(vec![enums::RefundStatus::Failure]),
+                    origin: None,
+                    unified_code: unified_code.map(|code| vec![code.to_string()]),
+                    issuer_error_code: issuer_error_code.map(|code| vec![code.to_string()]),
+                    has_unified_error,
+                };
+
+                let merchant_id = merchant_id.clone();
+                async move {
+                    let refunds = db
+                        .filter_refund_by_constraints(
+                            &merchant_id,
+                            &constraints,
+                            enums::MerchantStorageScheme::PostgresOnly,
+                            10,
+                            0,
+                        )
+                        .await
+                        .unwrap();
+                    let count = db
+                        .get_total_count_of_refunds(
+                            &merchant_id,
+                            &constraints,
+                            enums::MerchantStorageScheme::PostgresOnly,
+                        )
+                        .await
+                        .unwrap();
+                    assert_eq!(count, i64::try_from(refunds.len()).unwrap());
+
+                    let mut refund_ids = refunds
+                        .into_iter()
+                        .map(|refund| refund.refund_id)
+                        .collect::<Vec<_>>();
+                    refund_ids.sort_unstable();
+                    refund_ids
+                }
+            };
+
+        // The error code filters compose with the status filter, excluding the pending ref_3
+        assert_eq!(
+            list_refund_ids(Some("UE_9000"), None, None).await,
+            ["ref_2"]
+        );
+        assert_eq!(list_refund_ids(None, Some("59"), None).await, ["ref_2"]);
+        assert!(list_refund_ids(None, Some("05"), None).await.is_empty());
+        assert_eq!(list_refund_ids(None, None, Some(true)).await, ["ref_2"]);
+        assert_eq!(list_refund_ids(None, None, Some(false)).await, ["ref_1"]);
+        assert!(list_refund_ids(Some("UE_9000"), None, Some(false))
+            .await
+            .is_empty());
+    }
+
+    #[cfg(feature = "olap")]
+    #[tokio::test]
+    async fn test_refund_status_counts_are_grouped_by_connector_and_currency() {
+        use api_models::refunds::RefundStatusCountGroupBy;
+        use enums::{Currency, RefundStatus};
+
+        let mockdb = db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create a mock DB");
+        let db: &dyn db::StorageInterface = &mockdb;
+        let merchant_id = test_utils::merchant_id();
+        let refunds = [
+            ("ref_1", "stripe", Currency::USD, RefundStatus::Success),
+            ("ref_2", "stripe", Currency::USD, RefundStatus::Success),
+            ("ref_3", "stripe", Currency::EUR, RefundStatus::Success),
+            ("ref_4", "adyen", Currency::USD, RefundStatus::Failure),
+        ];
+        for (refund_id, ..) in refunds {
+            db.insert_refund(
+                test_utils::refund_new(refund_id),
+                enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+        }
+        for refund in mockdb.refunds.lock().await.iter_mut() {
+            if let Some((_, connector, currency, refund_status)) = refunds
+                .iter()
+                .find(|(refund_id, ..)| *refund_id == refund.refund_id)
+            {
+                refund.merchant_id = merchant_id.clone();
+                refund.connector = connector.to_string();
+                refund.currency = *currency;
+                refund.refund_status = *refund_status;
+            }
+        }
+
+        let get_status_counts = |time_range, group_by| {
+            let merchant_id = merchant_id.clone();
+            async move {
+                db.get_refund_status_counts(
+                    &merchant_id,
+                    None,
+                    &time_range,
+                    group_by,
+                    enums::MerchantStorageScheme::PostgresOnly,
+                )
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|(connector, currency, refund_status, count)| {
+                    ((connector, currency, refund_status), count)
+                })
+                .collect::<HashMap<_, _>>()
+            }
+        };
+        // Refunds created since the earliest representable time are all counted
+        let all_time = common_utils::types::TimeRange {
+            start_time: time::PrimitiveDateTime::MIN,
+            end_time: Some(time::PrimitiveDateTime::MAX),
+        };
+
+        assert_eq!(
+            get_status_counts(all_time, RefundStatusCountGroupBy::Connector).await,
+            HashMap::from([
+                ((Some("stripe".to_string()), None, RefundStatus::Success), 3),
+                ((Some("adyen".to_string()), None, RefundStatus::Failure), 1),
+            ])
+        );
+        assert_eq!(
+            get_status_counts(all_time, RefundStatusCountGroupBy::Currency).await,
+            HashMap::from([
+                ((None, Some(Currency::USD), RefundStatus::Success), 2),
+                ((None, Some(Currency::EUR), RefundStatus::Success), 1),
+                ((None, Some(Currency::USD), RefundStatus::Failure), 1),
+            ])
+        );
+        assert_eq!(
+            get_status_counts(all_time, RefundStatusCountGroupBy::ConnectorAndCurrency)
+                .await
+                .len(),
+            3
+        );
+
+        let future = common_utils::types::TimeRange {
+            start_time: time::PrimitiveDateTime::MAX,
+            end_time: None,
+        };
+        assert!(
+            get_status_counts(future, RefundStatusCountGroupBy::ConnectorAndCurrency)
+                .await
+                .is_empty()
+        );
+    }
+
+    #[cfg(feature = "olap")]
+    #[tokio::test]
+    async fn test_refund_list_keyset_pagination() {
+        use hyperswitch_domain_models::refunds::{RefundListConstraints, RefundListCursor};
+
+        let mockdb = db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create a mock DB");
+        let db: &dyn db::StorageInterface = &mockdb;
+        let merchant_id = test_utils::merchant_id();
+        let profile_id = common_utils::id_type::ProfileId::default();
+        let now = common_utils::date_time::now();
+        let earlier = now - time::Duration::hours(1);
+        let insert_refund = |refund_id: &'static str, created_at| {
+            let refund_new = diesel_refund::RefundNew {
+                merchant_id: merchant_id.clone(),
+                profile_id: Some(profile_id.clone()),
+                created_at,
+                ..test_utils::refund_new(refund_id)
+            };
+            async move {
+                db.insert_refund(refund_new, enums::MerchantStorageScheme::PostgresOnly)
+                    .await
+                    .unwrap();
+            }
+        };
+        // ref_2, ref_3 and ref_4 are created at the same time, and are ordered by their refund id
+        for (refund_id, created_at) in [
+            ("ref_1", earlier - time::Duration::hours(1)),
+            ("ref_2", earlier),
+            ("ref_3", earlier),
+            ("ref_4", earlier),
+            ("ref_5", now),
+        ] {
+            insert_refund(refund_id, created_at).await;
+        }
+
+        let list_refunds = |starting_after: Option<RefundListCursor>, offset| {
+            let merchant_id = merchant_id.clone();
+            async move {
+                let constraints = RefundListConstraints {
+                    payment_id: None,
+                    refund_id: None,
+                    profile_id: None,
+                    limit: None,
+                    offset: None,
+                    time_range: None,
+                    amount_filter: None,
+                    connector: None,
+                    merchant_connector_id: None,
+                    currency: None,
+                    refund_status: None,
+                    origin: None,
+                    unified_code: None,
+                    issuer_error_code: None,
+                    has_unified_error: None,
+                    starting_after,
+                };
+                db.filter_refund_by_constraints(
+                    &merchant_id,
+                    &constraints,
+                    enums::MerchantStorageScheme::PostgresOnly,
+                    2,
+                    offset,
+                )
+                .await
+                .unwrap()
+            }
+        };
+        let next_cursor = |page: &[diesel_refund::Refund]| {
+            let refund = page.last().unwrap();
+            let cursor = RefundListCursor {
+                created_at: refund.created_at,
+                refund_id: refund.refund_id.clone(),
+            };
+            RefundListCursor::decode(&cursor.encode())
+        };
+        let refund_ids = |page: &[diesel_refund::Refund]| {
+            page.iter()
+                .map(|refund| refund.refund_id.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let first_page = list_refunds(None, 0).await;
+        assert_eq!(refund_ids(&first_page), vec!["ref_5", "ref_4"]);
+        // Offset pagination is still supported without a cursor
+        assert_eq!(
+            refund_ids(&list_refunds(None, 2).await),
+            vec!["ref_3", "ref_2"]
+        );
+
+        // A refund created mid-pagination precedes the cursor and does not shift the next pages
+        insert_refund("ref_6", now + time::Duration::seconds(1)).await;
+        let second_page = list_refunds(next_cursor(&first_page), 3).await;
+        assert_eq!(refund_ids(&second_page), vec!["ref_3", "ref_2"]);
+        let third_page = list_refunds(next_cursor(&second_page), 0).await;
+        assert_eq!(refund_ids(&third_page), vec!["ref_1"]);
+
+        assert_eq!(
+            refund_ids(&list_refunds(None, 0).await),
+            vec!["ref_6", "ref_5"]
+        );
+        assert_eq!(RefundListCursor::decode("not a cursor"), None);
+    }
+
+    #[cfg(feature = "olap")]
+    #[tokio::test]
+    async fn test_inconsistent_refunds_are_classified_by_violated_rule() {
+        use api_models::refunds::{RefundMismatchRule, RefundReconciliationRules};
+        use enums::RefundStatus;
+
+        let mockdb = db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create a mock DB");
+        let db: &dyn db::StorageInterface = &mockdb;
+        let merchant_id = test_utils::merchant_id();
+        let now = common_utils::date_time::now();
+        let earlier = now - time::Duration::days(2);
+        // (refund id, status, sent to gateway, connector refund id, created at)
+        let refunds = [
+            ("ref_1", RefundStatus::Success, false, None, earlier),
+            ("ref_2", RefundStatus::Pending, true, Some("re_2"), earlier),
+            ("ref_3", RefundStatus::Pending, true, Some("re_3"), now),
+            ("ref_4", RefundStatus::Success, true, Some("re_4"), earlier),
+            ("ref_5", RefundStatus::Pending, true, None, earlier),
+        ];
+        for (refund_id, ..) in refunds {
+            db.insert_refund(
+                test_utils::refund_new(refund_id),
+                enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+        }
+        for refund in mockdb.refunds.lock().await.iter_mut() {
+            if let Some((_, refund_status, sent_to_gateway, connector_refund_id, created_at)) =
+                refunds
+                    .iter()
+                    .find(|(refund_id, ..)| *refund_id == refund.refund_id)
+            {
+                refund.merchant_id = merchant_id.clone();
+                refund.refund_status = *refund_status;
+                refund.sent_to_gateway = *sent_to_gateway;
+                refund.connector_refund_id = connector_refund_id.map(|connector_refund_id| {
+                    ConnectorTransactionId::from(connector_refund_id.to_string())
+                });
+                refund.created_at = *created_at;
+            }
+        }
+
+        let rules = RefundReconciliationRules::default();
+        let inconsistent_refunds = db
+            .find_inconsistent_refunds(
+                &merchant_id,
+                now - time::Duration::hours(i64::from(rules.older_than_hours)),
+                &rules,
+                10,
+                enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+
+        // Refunds created within the age threshold are left out even when they violate a rule
+        assert_eq!(
+            inconsistent_refunds
+                .iter()
+                .map(|refund| (
+                    refund.refund_id.as_str(),
+                    storage::get_refund_mismatch_rule(refund, &rules)
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                ("ref_1", Some(RefundMismatchRule::NotSentToGateway)),
+                (
+                    "ref_2",
+                    Some(RefundMismatchRule::UnresolvedWithConnectorRefundId)
+                ),
+            ]
+        );
+
+        let mismatches = inconsistent_refunds
+            .into_iter()
+            .take(1)
+            .map(|refund| api_models::refunds::RefundMismatch {
+                violated_rule: RefundMismatchRule::NotSentToGateway,
+                refund_id: refund.refund_id,
+                payment_id: refund.payment_id,
+                connector: refund.connector,
+                refund_status: refund.refund_status,
+                sync_scheduled: false,
+            })
+            .collect::<Vec<_>>();
+        let export =
+            String::from_utf8(write_refund_mismatches_csv_export(&mismatches).unwrap()).unwrap();
+        let mut lines = export.lines();
+
+        assert_eq!(
+            lines.next(),
+            Some("refund_id,payment_id,connector,refund_status,violated_rule,sync_scheduled")
+        );
+        assert!(lines.next().is_some_and(|line| line.starts_with("ref_1,")
+            && line.ends_with(",stripe,success,not_sent_to_gateway,false")));
+    }
+
+    #[cfg(feature = "olap")]
+    async fn mock_db_with_refunds_modified_at(
+        refund_ids: &[&str],
+        modified_at: time::PrimitiveDateTime,
+    ) -> db::MockDb {
+        let mockdb = db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create a mock DB");
+        let db: &dyn db::StorageInterface = &mockdb;
+        for refund_id in refund_ids {
+            db.insert_refund(
+                test_utils::refund_new(refund_id),
+                enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+        }
+        for refund in mockdb.refunds.lock().await.iter_mut() {
+            refund.modified_at = modified_at;
+        }
+
+        mockdb
+    }
+
+    #[cfg(feature = "olap")]
+    async fn list_refunds_modified_since_from(
+        db: &dyn db::StorageInterface,
+        cursor: Option<&str>,
+        limit: i64,
+    ) -> api_models::refunds::RefundsModifiedSinceResponse {
+        list_refunds_modified_since_page(
+            db,
+            &test_utils::merchant_id(),
+            enums::MerchantStorageScheme::PostgresOnly,
+            None,
+            cursor.map(|cursor| transformers::RefundsModifiedSinceCursor::decode(cursor).unwrap()),
+            limit,
+        )
+        .await
+        .unwrap()
+    }
+
+    #[cfg(feature = "olap")]
+    fn refund_ids(response: &api_models::refunds::RefundsModifiedSinceResponse) -> Vec<&str> {
+        response
+            .data
+            .iter()
+            .map(|refund| refund.refund_id.as_str())
+            .collect()
+    }
+
+    #[cfg(feature = "olap")]
+    #[tokio::test]
+    async fn test_refunds_modified_since_pagination_is_stable_for_same_modified_at() {
+        let modified_at = common_utils::date_time::now() - time::Duration::hours(1);
+        let mockdb = mock_db_with_refunds_modified_at(
+            &["ref_4", "ref_2", "ref_5", "ref_1", "ref_3"],
+            modified_at,
+        )
+        .await;
+        let db: &dyn db::StorageInterface = &mockdb;
+
+        let first_page = list_refunds_modified_since_from(db, None, 2).await;
+        assert_eq!(refund_ids(&first_page), vec!["ref_1", "ref_2"]);
+        assert!(first_page.has_more);
+        assert_eq!(first_page.high_watermark, Some(modified_at));
+
+        let second_page =
+            list_refunds_modified_since_from(db, first_page.next_cursor.as_deref(), 2).await;
+        assert_eq!(refund_ids(&second_page), vec!["ref_3", "ref_4"]);
+        assert!(second_page.has_more);
+
+        let last_page =
+            list_refunds_modified_since_from(db, second_page.next_cursor.as_deref(), 2).await;
+        assert_eq!(refund_ids(&last_page), vec!["ref_5"]);
+        assert!(!last_page.has_more);
+
+        // Syncing from the end returns nothing and keeps the position of the last refund
+        let empty_page =
+            list_refunds_modified_since_from(db, last_page.next_cursor.as_deref(), 2).await;
+        assert!(empty_page.data.is_empty());
+        assert!(!empty_page.has_more);
+        assert_eq!(empty_page.next_cursor, last_page.next_cursor);
+        assert_eq!(empty_page.high_watermark, Some(modified_at));
+    }
+
+    #[cfg(feature = "olap")]
+    #[tokio::test]
+    async fn test_updated_refund_reappears_in_refunds_modified_since() {
+        let modified_at = common_utils::date_time::now() - time::Duration::hours(1);
+        let mockdb = mock_db_with_refunds_modified_at(&["ref_1", "ref_2"], modified_at).await;
+        let db: &dyn db::StorageInterface = &mockdb;
+        let storage_scheme = enums::MerchantStorageScheme::PostgresOnly;
+
+        let synced = list_refunds_modified_since_from(db, None, 10).await;
+        assert_eq!(refund_ids(&synced), vec!["ref_1", "ref_2"]);
+        let caught_up =
+            list_refunds_modified_since_from(db, synced.next_cursor.as_deref(), 10).await;
+        assert!(caught_up.data.is_empty());
+
+        let refund = db
+            .find_refund_by_merchant_id_refund_id(
+                &test_utils::merchant_id(),
+                "ref_1",
+                storage_scheme,
+            )
+            .await
+            .unwrap();
+        db.update_refund(
+            refund,
+            diesel_refund::RefundUpdate::StatusUpdate {
+                connector_refund_id: None,
+                sent_to_gateway: true,
+                refund_status: enums::RefundStatus::Failure,
+                updated_by: "test".to_string(),
+                processor_refund_data: None,
+            },
+            storage_scheme,
+        )
+        .await
+        .unwrap();
 
-    let merchant_account = db
-        .find_merchant_account_by_merchant_id(
-            key_manager_state,
-            &refund_core.merchant_id,
-            &key_store,
+        let updated = list_refunds_modified_since_from(db, synced.next_cursor.as_deref(), 10).await;
+        assert_eq!(refund_ids(&updated), vec!["ref_1"]);
+        assert_eq!(updated.data[0].status, refunds::RefundStatus::Failed);
+        assert!(updated
+            .high_watermark
+            .is_some_and(|high_watermark| high_watermark > modified_at));
+    }
+
+    #[cfg(feature = "olap")]
+    #[tokio::test]
+    async fn test_stale_pending_refunds_are_found_by_last_modification() {
+        let now = common_utils::date_time::now();
+        let mockdb = mock_db_with_refunds_modified_at(
+            &["ref_1", "ref_2", "ref_3", "ref_4", "ref_5"],
+            now - time::Duration::hours(3),
         )
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+        .await;
+        for refund in mockdb.refunds.lock().await.iter_mut() {
+            refund.refund_status = enums::RefundStatus::Pending;
+            match refund.refund_id.as_str() {
+                "ref_2" => refund.modified_at = now - time::Duration::hours(4),
+                // Recently updated, not sent to the connector and not pending respectively
+                "ref_3" => refund.modified_at = now,
+                "ref_4" => refund.sent_to_gateway = false,
+                "ref_5" => refund.refund_status = enums::RefundStatus::Success,
+                _ => {}
+            }
+        }
+        let db: &dyn db::StorageInterface = &mockdb;
 
-    let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
-        merchant_account.clone(),
-        key_store.clone(),
-    )));
-    let refund = db
-        .find_refund_by_internal_reference_id_merchant_id(
-            &refund_core.refund_internal_reference_id,
-            &refund_core.merchant_id,
-            merchant_account.storage_scheme,
+        let stale_refunds = db
+            .find_stale_pending_refunds_by_merchant_id(
+                &test_utils::merchant_id(),
+                now - time::Duration::hours(1),
+                10,
+                enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+        let stale_refund_ids = stale_refunds
+            .iter()
+            .map(|refund| refund.refund_id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(stale_refund_ids, vec!["ref_2", "ref_1"]);
+
+        let limited = db
+            .find_stale_pending_refunds_by_merchant_id(
+                &test_utils::merchant_id(),
+                now - time::Duration::hours(1),
+                1,
+                enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+        assert_eq!(limited.len(), 1);
+        assert_eq!(limited[0].refund_id, "ref_2");
+    }
+
+    #[cfg(feature = "olap")]
+    #[test]
+    fn test_aggregate_stale_refund_sync_outcomes() {
+        let response = aggregate_stale_refund_sync_outcomes([
+            StaleRefundSyncOutcome::Updated,
+            StaleRefundSyncOutcome::Unchanged,
+            StaleRefundSyncOutcome::Failed,
+            StaleRefundSyncOutcome::Updated,
+        ]);
+        assert_eq!(
+            response,
+            api_models::refunds::RefundsStaleSyncResponse {
+                refunds_synced: 3,
+                refunds_updated: 2,
+                refunds_failed: 1,
+            }
+        );
+    }
+
+    #[cfg(feature = "olap")]
+    #[tokio::test]
+    async fn test_refunds_merchant_connector_id_backfill() {
+        let state = test_utils::mock_session_state(Default::default()).await;
+        let key_store = test_utils::merchant_key_store(&state).await;
+        let db = &*state.store;
+        let storage_scheme = enums::MerchantStorageScheme::PostgresOnly;
+        let merchant_id = test_utils::merchant_id();
+        let profile_id =
+            common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from("pro_1")).unwrap();
+        test_utils::insert_merchant_connector_account(
+            &state,
+            &key_store,
+            &merchant_connector_id("mca_2"),
+            &profile_id,
+            "stripe",
         )
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
-    match (&refund.sent_to_gateway, &refund.refund_status) {
-        (false, enums::RefundStatus::Pending) => {
-            let merchant_account = db
-                .find_merchant_account_by_merchant_id(
-                    key_manager_state,
-                    &refund.merchant_id,
-                    &key_store,
-                )
-                .await
-                .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+        .await;
 
-            let payment_attempt = db
-                .find_payment_attempt_by_connector_transaction_id_payment_id_merchant_id(
-                    &refund.connector_transaction_id,
-                    &refund_core.payment_id,
-                    &refund.merchant_id,
-                    merchant_account.storage_scheme,
-                )
+        // `ref_1` is resolved from its attempt, `ref_2` from the stripe account of the profile of
+        // its legacy attempt, `ref_3` is processed through a connector without an account in the
+        // profile and `ref_4` has no payment attempt at all
+        for payment_attempt in [
+            storage::PaymentAttemptNew {
+                merchant_connector_id: Some(merchant_connector_id("mca_1")),
+                ..test_utils::payment_attempt_new("attempt_1")
+            },
+            storage::PaymentAttemptNew {
+                profile_id: profile_id.clone(),
+                ..test_utils::payment_attempt_new("attempt_2")
+            },
+            storage::PaymentAttemptNew {
+                profile_id: profile_id.clone(),
+                connector: Some("adyen".to_string()),
+                ..test_utils::payment_attempt_new("attempt_3")
+            },
+        ] {
+            db.insert_payment_attempt(payment_attempt, storage_scheme)
                 .await
-                .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+                .unwrap();
+        }
+        for (refund_id, attempt_id) in [
+            ("ref_1", "attempt_1"),
+            ("ref_2", "attempt_2"),
+            ("ref_3", "attempt_3"),
+            ("ref_4", "attempt_4"),
+        ] {
+            db.insert_refund(
+                diesel_refund::RefundNew {
+                    attempt_id: attempt_id.to_string(),
+                    ..test_utils::refund_new(refund_id)
+                },
+                storage_scheme,
+            )
+            .await
+            .unwrap();
+        }
 
-            let payment_intent = db
-                .find_payment_intent_by_payment_id_merchant_id(
-                    &(state.into()),
-                    &payment_attempt.payment_id,
-                    &refund.merchant_id,
-                    &key_store,
-                    merchant_account.storage_scheme,
+        let backfill_batch = |starting_after: Option<String>, batch_size| {
+            let (state, key_store, merchant_id) = (&state, &key_store, &merchant_id);
+            async move {
+                backfill_refunds_merchant_connector_id_batch(
+                    state,
+                    key_store,
+                    merchant_id,
+                    storage_scheme,
+                    starting_after.as_deref(),
+                    batch_size,
                 )
                 .await
-                .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
-            let split_refunds = core_utils::get_split_refunds(SplitRefundInput {
-                split_payment_request: payment_intent.split_payments.clone(),
-                payment_charges: payment_attempt.charges.clone(),
-                charge_id: payment_attempt.charge_id.clone(),
-                refund_request: refund.split_refunds.clone(),
-            })?;
+                .unwrap()
+            }
+        };
 
-            //trigger refund request to gateway
-            let updated_refund = Box::pin(trigger_refund_to_gateway(
-                state,
-                &refund,
-                &merchant_context,
-                &payment_attempt,
-                &payment_intent,
-                None,
-                split_refunds,
-            ))
-            .await?;
-            add_refund_sync_task(
-                db,
-                &updated_refund,
-                storage::ProcessTrackerRunner::RefundWorkflowRouter,
-            )
-            .await?;
+        let first_batch = backfill_batch(None, 3).await;
+        assert_eq!(first_batch.refunds_processed, 3);
+        assert_eq!(first_batch.refunds_updated, 2);
+        assert_eq!(first_batch.unresolved_refund_ids, vec!["ref_3".to_string()]);
+        assert_eq!(first_batch.next_starting_after.as_deref(), Some("ref_3"));
+
+        let second_batch = backfill_batch(first_batch.next_starting_after, 3).await;
+        assert_eq!(second_batch.refunds_processed, 1);
+        assert_eq!(second_batch.refunds_updated, 0);
+        assert_eq!(
+            second_batch.unresolved_refund_ids,
+            vec!["ref_4".to_string()]
+        );
+        assert_eq!(second_batch.next_starting_after, None);
+
+        for (refund_id, expected_merchant_connector_id) in [("ref_1", "mca_1"), ("ref_2", "mca_2")]
+        {
+            let backfilled_refund = db
+                .find_refund_by_merchant_id_refund_id(&merchant_id, refund_id, storage_scheme)
+                .await
+                .unwrap();
+            assert_eq!(
+                backfilled_refund.merchant_connector_id,
+                Some(merchant_connector_id(expected_merchant_connector_id))
+            );
+            assert_eq!(
+                backfilled_refund.updated_by,
+                consts::REFUND_MERCHANT_CONNECTOR_ID_BACKFILL_UPDATED_BY
+            );
         }
-        (true, enums::RefundStatus::Pending) => {
-            // create sync task
-            add_refund_sync_task(
-                db,
-                &refund,
-                storage::ProcessTrackerRunner::RefundWorkflowRouter,
+
+        // Resuming from the start only revisits the refunds which could not be resolved
+        let rerun = backfill_batch(None, 10).await;
+        assert_eq!(
+            rerun.unresolved_refund_ids,
+            vec!["ref_3".to_string(), "ref_4".to_string()]
+        );
+        assert_eq!(rerun.refunds_updated, 0);
+    }
+
+    #[test]
+    fn test_validate_refund_merchant_connector_account() {
+        assert!(validate_refund_merchant_connector_account("stripe", None, "stripe").is_ok());
+        assert!(
+            validate_refund_merchant_connector_account("stripe", Some(false), "stripe").is_ok()
+        );
+
+        assert!(matches!(
+            validate_refund_merchant_connector_account("adyen", None, "stripe")
+                .unwrap_err()
+                .current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { .. }
+        ));
+        assert!(matches!(
+            validate_refund_merchant_connector_account("stripe", Some(true), "stripe")
+                .unwrap_err()
+                .current_context(),
+            errors::ApiErrorResponse::MerchantConnectorAccountDisabled
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_refund_is_processed_through_requested_merchant_connector_account() {
+        let mockdb = db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create a mock DB");
+        let db: &dyn db::StorageInterface = &mockdb;
+        let payment_attempt = db
+            .insert_payment_attempt(
+                storage::PaymentAttemptNew {
+                    merchant_connector_id: Some(merchant_connector_id("mca_1")),
+                    ..test_utils::payment_attempt_new("attempt_1")
+                },
+                enums::MerchantStorageScheme::PostgresOnly,
             )
-            .await?;
-        }
-        (_, _) => {
-            //mark task as finished
-            db.as_scheduler()
-                .finish_process_with_business_status(
-                    refund_tracker.clone(),
-                    business_status::COMPLETED_BY_PT,
-                )
-                .await?;
-        }
-    };
-    Ok(())
-}
+            .await
+            .unwrap();
 
-#[instrument]
-pub fn refund_to_refund_core_workflow_model(
-    refund: &diesel_refund::Refund,
-) -> diesel_refund::RefundCoreWorkflow {
-    diesel_refund::RefundCoreWorkflow {
-        refund_internal_reference_id: refund.internal_reference_id.clone(),
-        connector_transaction_id: refund.connector_transaction_id.clone(),
-        merchant_id: refund.merchant_id.clone(),
-        payment_id: refund.payment_id.clone(),
-        processor_transaction_data: refund.processor_transaction_data.clone(),
+        let overridden_refund = diesel_refund::Refund {
+            merchant_connector_id: Some(merchant_connector_id("mca_2")),
+            ..refund_with_status(enums::RefundStatus::Pending)
+        };
+        assert_eq!(
+            core_utils::get_refund_processing_merchant_connector_id(
+                &overridden_refund,
+                &payment_attempt
+            ),
+            Some(&merchant_connector_id("mca_2"))
+        );
+
+        let default_refund = refund_with_status(enums::RefundStatus::Pending);
+        assert_eq!(
+            core_utils::get_refund_processing_merchant_connector_id(
+                &default_refund,
+                &payment_attempt
+            ),
+            Some(&merchant_connector_id("mca_1"))
+        );
     }
-}
 
-#[instrument(skip_all)]
-pub async fn add_refund_sync_task(
-    db: &dyn db::StorageInterface,
-    refund: &diesel_refund::Refund,
-    runner: storage::ProcessTrackerRunner,
-) -> RouterResult<storage::ProcessTracker> {
-    let task = "SYNC_REFUND";
-    let process_tracker_id = format!("{runner}_{task}_{}", refund.internal_reference_id);
-    let schedule_time = common_utils::date_time::now();
-    let refund_workflow_tracking_data = refund_to_refund_core_workflow_model(refund);
-    let tag = ["REFUND"];
-    let process_tracker_entry = storage::ProcessTrackerNew::new(
-        process_tracker_id,
-        task,
-        runner,
-        tag,
-        refund_workflow_tracking_data,
-        None,
-        schedule_time,
-        common_types::consts::API_VERSION,
-    )
-    .change_context(errors::ApiErrorResponse::InternalServerError)
-    .attach_printable("Failed to construct refund sync process tracker task")?;
+    async fn pending_refund(
+        db: &dyn db::StorageInterface,
+        refund_id: &str,
+    ) -> diesel_refund::Refund {
+        db.insert_refund(
+            diesel_refund::RefundNew {
+                refund_status: enums::RefundStatus::Pending,
+                ..test_utils::refund_new(refund_id)
+            },
+            enums::MerchantStorageScheme::PostgresOnly,
+        )
+        .await
+        .unwrap()
+    }
 
-    let response = db
-        .insert_process(process_tracker_entry)
+    async fn find_refund(
+        db: &dyn db::StorageInterface,
+        refund_id: &str,
+    ) -> RouterResult<diesel_refund::Refund> {
+        db.find_refund_by_merchant_id_refund_id(
+            &test_utils::merchant_id(),
+            refund_id,
+            enums::MerchantStorageScheme::PostgresOnly,
+        )
         .await
-        .to_duplicate_response(errors::ApiErrorResponse::DuplicateRefundRequest)
-        .attach_printable_lazy(|| {
-            format!(
-                "Failed while inserting task in process_tracker: refund_id: {}",
-                refund.refund_id
-            )
-        })?;
-    metrics::TASKS_ADDED_COUNT.add(1, router_env::metric_attributes!(("flow", "Refund")));
+        .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)
+    }
 
-    Ok(response)
-}
+    #[tokio::test]
+    async fn test_refund_wait_returns_once_status_turns_terminal() {
+        let mockdb = db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create a mock DB");
+        let db: &dyn db::StorageInterface = &mockdb;
+        pending_refund(db, "ref_wait").await;
 
-#[instrument(skip_all)]
-pub async fn add_refund_execute_task(
-    db: &dyn db::StorageInterface,
-    refund: &diesel_refund::Refund,
-    runner: storage::ProcessTrackerRunner,
-) -> RouterResult<storage::ProcessTracker> {
-    let task = "EXECUTE_REFUND";
-    let process_tracker_id = format!("{runner}_{task}_{}", refund.internal_reference_id);
-    let tag = ["REFUND"];
-    let schedule_time = common_utils::date_time::now();
-    let refund_workflow_tracking_data = refund_to_refund_core_workflow_model(refund);
-    let process_tracker_entry = storage::ProcessTrackerNew::new(
-        process_tracker_id,
-        task,
-        runner,
-        tag,
-        refund_workflow_tracking_data,
-        None,
-        schedule_time,
-        common_types::consts::API_VERSION,
-    )
-    .change_context(errors::ApiErrorResponse::InternalServerError)
-    .attach_printable("Failed to construct refund execute process tracker task")?;
+        let mut syncs = Vec::new();
+        let started_at = tokio::time::Instant::now();
+        let refund = poll_refund_until_terminal(
+            Duration::from_secs(10),
+            Duration::from_millis(10),
+            Duration::from_millis(25),
+            |should_sync| {
+                syncs.push(should_sync);
+                let fetch_count = syncs.len();
+                async move {
+                    // The refund is marked successful, e.g. by a webhook, in the middle of the wait
+                    if fetch_count == 4 {
+                        let refund = find_refund(db, "ref_wait").await?;
+                        db.update_refund(
+                            refund,
+                            diesel_refund::RefundUpdate::StatusUpdate {
+                                connector_refund_id: None,
+                                sent_to_gateway: true,
+                                refund_status: enums::RefundStatus::Success,
+                                updated_by: "test".to_string(),
+                                processor_refund_data: None,
+                            },
+                            enums::MerchantStorageScheme::PostgresOnly,
+                        )
+                        .await
+                        .unwrap();
+                    }
+                    find_refund(db, "ref_wait").await
+                }
+            },
+        )
+        .await
+        .unwrap();
 
-    let response = db
-        .insert_process(process_tracker_entry)
+        assert_eq!(refund.refund_status, enums::RefundStatus::Success);
+        assert_eq!(syncs.len(), 4);
+        assert_eq!(syncs.first(), Some(&true));
+        assert!(started_at.elapsed() < Duration::from_secs(10));
+    }
+
+    #[tokio::test]
+    async fn test_refund_wait_returns_latest_status_on_timeout() {
+        let mockdb = db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create a mock DB");
+        let db: &dyn db::StorageInterface = &mockdb;
+        pending_refund(db, "ref_timeout").await;
+
+        let mut syncs = Vec::new();
+        let started_at = tokio::time::Instant::now();
+        let refund = poll_refund_until_terminal(
+            Duration::from_millis(200),
+            Duration::from_millis(20),
+            Duration::from_millis(50),
+            |should_sync| {
+                syncs.push(should_sync);
+                find_refund(db, "ref_timeout")
+            },
+        )
         .await
-        .to_duplicate_response(errors::ApiErrorResponse::DuplicateRefundRequest)
-        .attach_printable_lazy(|| {
-            format!(
-                "Failed while inserting task in process_tracker: refund_id: {}",
-                refund.refund_id
-            )
-        })?;
-    Ok(response)
-}
+        .unwrap();
 
-pub async fn get_refund_sync_process_schedule_time(
-    db: &dyn db::StorageInterface,
-    connector: &str,
-    merchant_id: &common_utils::id_type::MerchantId,
-    retry_count: i32,
-) -> Result<Option<time::PrimitiveDateTime>, errors::ProcessTrackerError> {
-    let redis_mapping: errors::CustomResult<process_data::ConnectorPTMapping, errors::RedisError> =
-        db::get_and_deserialize_key(
-            db,
-            &format!("pt_mapping_refund_sync_{connector}"),
-            "ConnectorPTMapping",
+        assert_eq!(refund.refund_status, enums::RefundStatus::Pending);
+        assert!(!is_refund_status_terminal(refund.refund_status));
+        assert!(started_at.elapsed() >= Duration::from_millis(200));
+        assert!(syncs.len() > 2);
+        assert!(syncs.iter().filter(|should_sync| **should_sync).count() >= 2);
+        assert!(syncs.iter().any(|should_sync| !should_sync));
+    }
+
+    #[test]
+    fn test_refund_wait_slots_are_bounded_per_merchant() {
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_wait"))
+                .unwrap();
+        let other_merchant_id = common_utils::id_type::MerchantId::try_from(
+            std::borrow::Cow::from("merchant_wait_other"),
         )
-        .await;
+        .unwrap();
 
-    let mapping = match redis_mapping {
-        Ok(x) => x,
-        Err(err) => {
-            logger::error!("Error: while getting connector mapping: {err:?}");
-            process_data::ConnectorPTMapping::default()
-        }
-    };
+        let mut slots = (0..consts::MAX_CONCURRENT_REFUND_RETRIEVE_WAITS_PER_MERCHANT)
+            .map(|_| RefundRetrieveWaitSlot::acquire(&merchant_id).unwrap())
+            .collect::<Vec<_>>();
 
-    let time_delta =
-        process_tracker_utils::get_schedule_time(mapping, merchant_id, retry_count + 1);
+        assert!(matches!(
+            RefundRetrieveWaitSlot::acquire(&merchant_id)
+                .err()
+                .unwrap()
+                .current_context(),
+            errors::ApiErrorResponse::ResourceBusy
+        ));
+        assert!(RefundRetrieveWaitSlot::acquire(&other_merchant_id).is_ok());
 
-    Ok(process_tracker_utils::get_time_from_delta(time_delta))
+        slots.pop();
+        assert!(RefundRetrieveWaitSlot::acquire(&merchant_id).is_ok());
+    }
 }
@@ -21,7 +21,7 @@ use crate::{
     core::{
         errors::{self, ConnectorErrorExt, StorageErrorExt},
         payments::{self, access_token, helpers},
-        utils::{self as core_utils, refunds_validator},
+        utils::{self as core_utils, refunds_transformers, refunds_validator},
     },
     db, logger,
     routes::{metrics, SessionState},
@@ -42,6 +42,7 @@ pub async fn refund_create_core(
     merchant_context: domain::MerchantContext,
     req: refunds::RefundsCreateRequest,
     global_refund_id: id_type::GlobalRefundId,
+    origin: enums::RefundOrigin,
 ) -> errors::RouterResponse<refunds::RefundResponse> {
     let db = &*state.store;
     let (payment_intent, payment_attempt, amount);
@@ -109,6 +110,7 @@ pub async fn refund_create_core(
         req,
         global_refund_id,
         merchant_connector_details,
+        origin,
     ))
     .await
     .map(services::ApplicationResponse::Json)
@@ -399,6 +401,8 @@ async fn get_connector_error_refund_update(
         unified_error_object,
         err.reason.or(Some(err.message)),
         Some(err.code),
+        err.network_decline_code,
+        err.network_error_message,
         storage_scheme,
     )
 }
@@ -524,12 +528,14 @@ impl ForeignFrom<(&errors::ConnectorError, enums::MerchantStorageScheme)>
                     refund_error_message: Some(
                         errors::ConnectorError::NotImplemented(message.to_owned()).to_string(),
                     ),
-                    refund_error_code: Some("NOT_IMPLEMENTED".to_string()),
+                    refund_error_code: Some(consts::REFUND_NOT_IMPLEMENTED_ERROR_CODE.to_string()),
                     updated_by: storage_scheme.to_string(),
                     connector_refund_id: None,
                     processor_refund_data: None,
                     unified_code: None,
                     unified_message: None,
+                    issuer_error_code: None,
+                    issuer_error_message: None,
                 })
             }
             errors::ConnectorError::NotSupported { message, connector } => {
@@ -538,12 +544,14 @@ impl ForeignFrom<(&errors::ConnectorError, enums::MerchantStorageScheme)>
                     refund_error_message: Some(format!(
                         "{message} is not supported by {connector}"
                     )),
-                    refund_error_code: Some("NOT_SUPPORTED".to_string()),
+                    refund_error_code: Some(consts::REFUND_NOT_SUPPORTED_ERROR_CODE.to_string()),
                     updated_by: storage_scheme.to_string(),
                     connector_refund_id: None,
                     processor_refund_data: None,
                     unified_code: None,
                     unified_message: None,
+                    issuer_error_code: None,
+                    issuer_error_message: None,
                 })
             }
             _ => None,
@@ -584,7 +592,7 @@ pub async fn refund_metadata_update_core(
     let response = db
         .update_refund(
             refund,
-            diesel_refund::RefundUpdate::MetadataAndReasonUpdate {
+            diesel_refund::RefundUpdate::MetadataMergeUpdate {
                 metadata: req.metadata,
                 reason: req.reason,
                 updated_by: merchant_account.storage_scheme.to_string(),
@@ -934,6 +942,8 @@ pub fn build_refund_update_for_rsync(
                 refund_status,
                 refund_error_message,
                 refund_error_code,
+                error_message.network_decline_code,
+                error_message.network_error_message,
                 storage_scheme,
             )
         }
@@ -1017,6 +1027,80 @@ pub async fn refund_list(
             count: data.len(),
             total_count,
             data,
+            next_cursor: None,
+        },
+    ))
+}
+
+/// Lists the refunds of the merchant modified at or after the requested time, ordered by
+/// modification time and id, for incremental syncs. Every response carries a cursor positioned
+/// after its last refund, so that refunds updated after being synced are returned again when
+/// syncing from that cursor later.
+#[instrument(skip_all)]
+#[cfg(feature = "olap")]
+pub async fn refund_list_modified_since(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: refunds::RefundsModifiedSinceRequest,
+) -> errors::RouterResponse<refunds::RefundsModifiedSinceResponse> {
+    let db = state.store;
+    let limit = refunds_validator::validate_refund_list(req.limit)?;
+    let cursor = req
+        .cursor
+        .as_deref()
+        .map(|cursor| {
+            refunds_transformers::RefundsModifiedSinceCursor::decode(cursor).ok_or_else(|| {
+                report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "cursor is invalid".to_string(),
+                })
+            })
+        })
+        .transpose()?;
+    let (modified_since, starting_after) = match &cursor {
+        Some(cursor) => (cursor.modified_at, Some(cursor.refund_id.as_str())),
+        None => (
+            req.since.unwrap_or_else(|| {
+                common_utils::date_time::convert_to_pdt(time::OffsetDateTime::UNIX_EPOCH)
+            }),
+            None,
+        ),
+    };
+
+    // One more refund than the limit is fetched to find out if there are more refunds
+    let mut refunds_page = db
+        .list_refunds_modified_since(
+            merchant_account.get_id(),
+            modified_since,
+            limit + 1,
+            starting_after,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch refunds modified since the given time")?;
+    let page_size = usize::try_from(limit).unwrap_or_default();
+    let has_more = refunds_page.len() > page_size;
+    refunds_page.truncate(page_size);
+
+    let next_cursor = refunds_page
+        .last()
+        .map(|refund| refunds_transformers::RefundsModifiedSinceCursor {
+            modified_at: refund.modified_at,
+            refund_id: refund.id.get_string_repr().to_owned(),
+        })
+        .or(cursor);
+    let data: Vec<refunds::RefundResponse> = refunds_page
+        .into_iter()
+        .map(refunds::RefundResponse::foreign_try_from)
+        .collect::<Result<_, _>>()?;
+
+    Ok(services::ApplicationResponse::Json(
+        refunds::RefundsModifiedSinceResponse {
+            count: data.len(),
+            data,
+            has_more,
+            high_watermark: next_cursor.as_ref().map(|cursor| cursor.modified_at),
+            next_cursor: next_cursor.map(|cursor| cursor.encode()),
         },
     ))
 }
@@ -1034,6 +1118,7 @@ pub async fn validate_and_create_refund(
     req: refunds::RefundsCreateRequest,
     global_refund_id: id_type::GlobalRefundId,
     merchant_connector_details: Option<common_types::domain::MerchantConnectorAuthDetails>,
+    origin: enums::RefundOrigin,
 ) -> errors::RouterResult<refunds::RefundResponse> {
     let db = &*state.store;
 
@@ -1099,6 +1184,14 @@ pub async fn validate_and_create_refund(
     )
     .change_context(errors::ApiErrorResponse::MaximumRefundCount)?;
 
+    validate_attempt_belongs_to_payment(
+        db,
+        &payment_attempt.id,
+        &req.payment_id,
+        merchant_context.get_merchant_account().storage_scheme,
+    )
+    .await?;
+
     let connector = payment_attempt
         .connector
         .clone()
@@ -1106,6 +1199,10 @@ pub async fn validate_and_create_refund(
         .attach_printable("No connector populated in payment attempt")?;
     let (connector_transaction_id, processor_transaction_data) =
         common_utils_types::ConnectorTransactionId::form_id_and_data(connector_payment_id);
+    debug_assert_eq!(
+        payment_attempt.payment_id, req.payment_id,
+        "refund is being created for an attempt of a different payment"
+    );
     let refund_create_req = diesel_refund::RefundNew {
         id: global_refund_id,
         merchant_reference_id: merchant_reference_id.clone(),
@@ -1139,6 +1236,7 @@ pub async fn validate_and_create_refund(
             .clone(),
         processor_transaction_data,
         processor_refund_data: None,
+        origin,
     };
 
     let refund = match db
@@ -1192,6 +1290,29 @@ pub async fn validate_and_create_refund(
     api::RefundResponse::foreign_try_from(refund)
 }
 
+/// Ensures that the attempt being refunded belongs to the payment the refund is created for,
+/// before the refund is stored or sent to the connector
+async fn validate_attempt_belongs_to_payment(
+    db: &dyn db::StorageInterface,
+    attempt_id: &id_type::GlobalAttemptId,
+    payment_id: &id_type::GlobalPaymentId,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> errors::RouterResult<()> {
+    let attempt_belongs_to_payment = db
+        .attempt_belongs_to_payment(attempt_id, payment_id, storage_scheme)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to check the payment of the payment attempt")?;
+
+    utils::when(!attempt_belongs_to_payment, || {
+        Err(report!(errors::ApiErrorResponse::InternalServerError)).attach_printable(format!(
+            "Payment attempt {} does not belong to payment {}",
+            attempt_id.get_string_repr(),
+            payment_id.get_string_repr()
+        ))
+    })
+}
+
 impl ForeignTryFrom<diesel_refund::Refund> for api::RefundResponse {
     type Error = error_stack::Report<errors::ApiErrorResponse>;
     fn foreign_try_from(refund: diesel_refund::Refund) -> Result<Self, Self::Error> {
@@ -1217,6 +1338,14 @@ impl ForeignTryFrom<diesel_refund::Refund> for api::RefundResponse {
             payment_id: refund.payment_id,
             id: refund.id.clone(),
             amount: refund.refund_amount,
+            refund_amount_formatted: refunds_transformers::get_formatted_refund_amount(
+                refund.refund_amount,
+                refund.currency,
+            ),
+            total_amount_formatted: refunds_transformers::get_formatted_refund_amount(
+                refund.total_amount,
+                refund.currency,
+            ),
             currency: refund.currency,
             reason: refund.refund_reason,
             status: refunds::RefundStatus::foreign_from(refund.refund_status),
@@ -1231,7 +1360,16 @@ impl ForeignTryFrom<diesel_refund::Refund> for api::RefundResponse {
                 code: refund.refund_error_code.unwrap_or_default(),
                 message: refund.refund_error_message.unwrap_or_default(),
             }),
+            unified_code: refund.unified_code,
+            unified_message: refund.unified_message,
             connector_refund_reference_id: None,
+            split_refunds_summary: refund
+                .split_refunds
+                .as_ref()
+                .map(api_models::refunds::SplitRefundSummary::from),
+            origin: refund.origin,
+            issuer_error_code: refund.issuer_error_code,
+            issuer_error_message: refund.issuer_error_message,
         })
     }
 }
@@ -1438,3 +1576,138 @@ pub async fn add_refund_sync_task(
 
     Ok(response)
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use hyperswitch_domain_models::payments::payment_attempt::{
+        AttemptAmountDetails, AttemptAmountDetailsSetter,
+    };
+
+    use super::*;
+
+    fn payment_attempt(
+        cell_id: &id_type::CellId,
+        payment_id: &id_type::GlobalPaymentId,
+    ) -> storage::PaymentAttempt {
+        let now = common_utils::date_time::now();
+        storage::PaymentAttempt {
+            payment_id: payment_id.clone(),
+            merchant_id: id_type::MerchantId::default(),
+            amount_details: AttemptAmountDetails::from(AttemptAmountDetailsSetter {
+                net_amount: common_utils_types::MinorUnit::new(1000),
+                amount_to_capture: None,
+                surcharge_amount: None,
+                tax_on_surcharge: None,
+                amount_capturable: common_utils_types::MinorUnit::new(0),
+                shipping_cost: None,
+                order_tax_amount: None,
+            }),
+            status: enums::AttemptStatus::Charged,
+            connector: Some("stripe".to_string()),
+            error: None,
+            authentication_type: enums::AuthenticationType::NoThreeDs,
+            created_at: now,
+            modified_at: now,
+            last_synced: None,
+            cancellation_reason: None,
+            browser_info: None,
+            payment_token: None,
+            connector_metadata: None,
+            payment_experience: None,
+            payment_method_data: None,
+            routing_result: None,
+            preprocessing_step_id: None,
+            multiple_capture_count: None,
+            connector_response_reference_id: None,
+            updated_by: "test".to_string(),
+            redirection_data: None,
+            encoded_data: None,
+            merchant_connector_id: None,
+            external_three_ds_authentication_attempted: None,
+            authentication_connector: None,
+            authentication_id: None,
+            fingerprint_id: None,
+            client_source: None,
+            client_version: None,
+            customer_acceptance: None,
+            profile_id: id_type::ProfileId::try_from(std::borrow::Cow::from("pro_1")).unwrap(),
+            organization_id: id_type::OrganizationId::default(),
+            payment_method_type: enums::PaymentMethod::Card,
+            payment_method_id: None,
+            connector_payment_id: Some("pi_1".to_string()),
+            payment_method_subtype: enums::PaymentMethodType::Credit,
+            authentication_applied: None,
+            external_reference_id: None,
+            payment_method_billing_address: None,
+            id: id_type::GlobalAttemptId::generate(cell_id),
+            connector_token_details: None,
+            card_discovery: None,
+            charges: None,
+            feature_metadata: None,
+            processor_merchant_id: id_type::MerchantId::default(),
+            created_by: None,
+            connector_request_reference_id: None,
+        }
+    }
+
+    async fn mock_db_with_attempts(attempts: &[storage::PaymentAttempt]) -> db::MockDb {
+        let mockdb = db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("mock db");
+        mockdb
+            .payment_attempts
+            .lock()
+            .await
+            .extend(attempts.iter().cloned());
+        mockdb
+    }
+
+    #[tokio::test]
+    async fn test_attempt_of_the_payment_is_accepted() {
+        let cell_id = id_type::CellId::from_string("12345").unwrap();
+        let payment_id = id_type::GlobalPaymentId::generate(&cell_id);
+        let attempt = payment_attempt(&cell_id, &payment_id);
+        let mockdb = mock_db_with_attempts(&[attempt.clone()]).await;
+        let db: &dyn db::StorageInterface = &mockdb;
+
+        assert!(validate_attempt_belongs_to_payment(
+            db,
+            &attempt.id,
+            &payment_id,
+            enums::MerchantStorageScheme::PostgresOnly,
+        )
+        .await
+        .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_attempt_of_another_payment_is_rejected() {
+        let cell_id = id_type::CellId::from_string("12345").unwrap();
+        let payment_id = id_type::GlobalPaymentId::generate(&cell_id);
+        let other_payment_id = id_type::GlobalPaymentId::generate(&cell_id);
+        let attempt = payment_attempt(&cell_id, &payment_id);
+        let other_attempt = payment_attempt(&cell_id, &other_payment_id);
+        let mockdb = mock_db_with_attempts(&[attempt.clone(), other_attempt.clone()]).await;
+        let db: &dyn db::StorageInterface = &mockdb;
+
+        for attempt_id in [
+            other_attempt.id,
+            id_type::GlobalAttemptId::generate(&cell_id),
+        ] {
+            let error = validate_attempt_belongs_to_payment(
+                db,
+                &attempt_id,
+                &payment_id,
+                enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap_err();
+            assert!(matches!(
+                error.current_context(),
+                errors::ApiErrorResponse::InternalServerError
+            ));
+        }
+    }
+}
@@ -1,4 +1,6 @@
 pub mod helpers;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod import_validation;
 pub mod transformers;
 use std::collections::HashSet;
 
@@ -582,6 +584,35 @@ pub async fn link_routing_config_under_profile(
     ))
 }
 
+/// Computes the hash of the program of an advanced routing algorithm, recorded on activation
+#[cfg(feature = "v1")]
+fn get_routing_program_hash(routing_algorithm: &RoutingAlgorithm) -> RouterResult<Option<String>> {
+    let algorithm: routing_types::StaticRoutingAlgorithm = routing_algorithm
+        .algorithm_data
+        .clone()
+        .parse_value("RoutingAlgorithm")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to deserialize routing algorithm")?;
+
+    let routing_types::StaticRoutingAlgorithm::Advanced(program) = algorithm else {
+        return Ok(None);
+    };
+
+    let version = program.version;
+    let program = serde_json::to_value(program)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error serializing program for migration")?;
+    let program: euclid::frontend::ast::Program<routing_types::ConnectorSelection> =
+        euclid::frontend::ast::migration::migrate_program(program, version)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error migrating program to the latest version")?;
+
+    euclid::frontend::ast::hashing::hash_program(&program)
+        .map(Some)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error computing the hash of the program")
+}
+
 #[cfg(feature = "v1")]
 pub async fn link_routing_config(
     state: SessionState,
@@ -800,6 +831,16 @@ pub async fn link_routing_config(
                 &transaction_type,
             )
             .await?;
+
+            if let Some(program_hash) = get_routing_program_hash(&routing_algorithm)? {
+                router_env::logger::info!(
+                    routing_flow=?"link_routing_algorithm",
+                    algorithm_id=?routing_algorithm.algorithm_id,
+                    profile_id=?routing_algorithm.profile_id,
+                    program_hash,
+                    "routing_algorithm_activated"
+                );
+            }
         }
     };
     if let Some(euclid_routing_id) = routing_algorithm.decision_engine_routing_id.clone() {
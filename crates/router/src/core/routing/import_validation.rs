@@ -0,0 +1,301 @@
+//! Validation of routing programs imported by pasting the program exported by the dashboard,
+//! reporting every finding instead of failing on the first one.
+
+use std::{collections::HashSet, str::FromStr};
+
+use api_models::routing::{
+    self as routing_types, AnalysisDiagnostic, AnalysisDiagnosticCode, DiagnosticLocation,
+};
+use error_stack::ResultExt;
+use euclid::{
+    dssa::{analyzer, truth},
+    frontend::{ast, dir},
+};
+use hyperswitch_constraint_graph::ConstraintGraph;
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse},
+        payments::routing as payments_routing,
+        utils as core_utils,
+    },
+    routes::SessionState,
+    services::api as service_api,
+    types::domain,
+    utils::OptionExt,
+};
+
+/// Validates a routing program against the connectors of the given profile, without persisting
+/// anything
+pub async fn validate_routing_program_import(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    request: routing_types::RoutingImportValidationRequest,
+) -> RouterResponse<routing_types::RoutingImportValidationResponse> {
+    let key_manager_state = &(&state).into();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let key_store = merchant_context.get_merchant_key_store();
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        state.store.as_ref(),
+        key_manager_state,
+        key_store,
+        Some(&request.profile_id),
+        merchant_id,
+    )
+    .await?
+    .get_required_value("Profile")?;
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    let connectors = state
+        .store
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            key_manager_state,
+            merchant_id,
+            false,
+            key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_id.get_string_repr().to_owned(),
+        })?
+        .filter_based_on_profile_and_connector_type(
+            &request.profile_id,
+            common_enums::ConnectorType::PaymentProcessor,
+        )
+        .into_iter()
+        .filter_map(|mca| {
+            common_enums::RoutableConnectors::from_str(&mca.connector_name)
+                .ok()
+                .map(|connector| ast::ConnectorChoice { connector })
+        })
+        .collect::<Vec<_>>();
+
+    let mca_graph = payments_routing::get_merchant_cgraph(
+        &state,
+        key_store,
+        &request.profile_id,
+        &common_enums::TransactionType::Payment,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct the knowledge graph of the profile")?;
+    let knowledge_graph = ConstraintGraph::combine(&mca_graph, &truth::ANALYSIS_GRAPH)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to combine the knowledge graph of the profile")?;
+
+    let diagnostics =
+        get_routing_import_diagnostics(&request.program, Some(&knowledge_graph), &connectors);
+
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::RoutingImportValidationResponse::new(diagnostics),
+    ))
+}
+
+/// Deserializes, lowers and analyses the serialized program, collecting the findings of every
+/// step. Parse errors carry the position of the error, since nothing else can be checked before
+/// the program has been deserialized.
+fn get_routing_import_diagnostics(
+    serialized_program: &str,
+    knowledge_graph: Option<&ConstraintGraph<dir::DirValue>>,
+    connectors: &[ast::ConnectorChoice],
+) -> Vec<AnalysisDiagnostic> {
+    let program: ast::Program<routing_types::ConnectorSelection> =
+        match serde_json::from_str(serialized_program) {
+            Ok(program) => program,
+            Err(error) => {
+                return vec![AnalysisDiagnostic {
+                    location: Some(DiagnosticLocation {
+                        line: error.line(),
+                        column: error.column(),
+                    }),
+                    ..AnalysisDiagnostic::error(
+                        AnalysisDiagnosticCode::ParseError,
+                        error.to_string(),
+                    )
+                }]
+            }
+        };
+
+    let mut diagnostics = get_unconfigured_connector_diagnostics(&program, connectors);
+
+    if let Err(error) = analyzer::analyze(program.clone(), knowledge_graph) {
+        diagnostics.push(AnalysisDiagnostic::error(
+            AnalysisDiagnosticCode::InvalidProgram,
+            error.error_type.to_string(),
+        ));
+        return diagnostics;
+    }
+
+    let dir_program = match ast::lowering::lower_program(program) {
+        Ok(dir_program) => dir_program,
+        Err(error) => {
+            diagnostics.push(AnalysisDiagnostic::error(
+                AnalysisDiagnosticCode::InvalidProgram,
+                error.error_type.to_string(),
+            ));
+            return diagnostics;
+        }
+    };
+
+    let warnings = knowledge_graph
+        .map(|graph| analyzer::analyze_connector_support(&dir_program, graph, connectors))
+        .transpose()
+        .and_then(|connector_support_warnings| {
+            let mut warnings = connector_support_warnings.unwrap_or_default();
+            warnings.extend(analyzer::analyze_key_dependencies(&dir_program)?);
+            Ok(warnings)
+        });
+    match warnings {
+        Ok(warnings) => diagnostics.extend(warnings.into_iter().map(AnalysisDiagnostic::from)),
+        Err(error) => diagnostics.push(AnalysisDiagnostic::error(
+            AnalysisDiagnosticCode::InvalidProgram,
+            error.error_type.to_string(),
+        )),
+    }
+
+    diagnostics
+}
+
+/// Reports every connector referenced by the default selection or a rule which is not
+/// configured for the profile, once per selection referencing it
+fn get_unconfigured_connector_diagnostics(
+    program: &ast::Program<routing_types::ConnectorSelection>,
+    connectors: &[ast::ConnectorChoice],
+) -> Vec<AnalysisDiagnostic> {
+    let configured_connectors = connectors
+        .iter()
+        .map(|choice| choice.connector)
+        .collect::<HashSet<_>>();
+
+    std::iter::once((None, &program.default_selection))
+        .chain(
+            program
+                .rules
+                .iter()
+                .map(|rule| (Some(&rule.name), &rule.connector_selection)),
+        )
+        .flat_map(|(rule_name, selection)| {
+            let mut reported = HashSet::new();
+            selection
+                .get_connector_list()
+                .into_iter()
+                .filter(|choice| !configured_connectors.contains(&choice.connector))
+                .filter(move |choice| reported.insert(choice.connector))
+                .map(move |choice| AnalysisDiagnostic {
+                    rule_name: rule_name.cloned(),
+                    ..AnalysisDiagnostic::error(
+                        AnalysisDiagnosticCode::ConnectorNotConfigured,
+                        format!(
+                            "Connector '{}' is not configured for the profile",
+                            choice.connector
+                        ),
+                    )
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use super::*;
+
+    const PROGRAM: &str = r#"{
+        "defaultSelection": {
+            "type": "priority",
+            "data": [{ "connector": "stripe", "merchant_connector_id": null }]
+        },
+        "metadata": {},
+        "rules": [{
+            "name": "card_rule",
+            "connectorSelection": {
+                "type": "priority",
+                "data": [
+                    { "connector": "adyen", "merchant_connector_id": null },
+                    { "connector": "stripe", "merchant_connector_id": null }
+                ]
+            },
+            "statements": [{
+                "condition": [{
+                    "lhs": "payment_method",
+                    "comparison": "equal",
+                    "value": { "type": "enum_variant", "value": "card" },
+                    "metadata": {}
+                }],
+                "nested": null
+            }]
+        }]
+    }"#;
+
+    fn connectors(connectors: &[common_enums::RoutableConnectors]) -> Vec<ast::ConnectorChoice> {
+        connectors
+            .iter()
+            .map(|connector| ast::ConnectorChoice {
+                connector: *connector,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_clean_import_has_no_diagnostics() {
+        let diagnostics = get_routing_import_diagnostics(
+            PROGRAM,
+            None,
+            &connectors(&[
+                common_enums::RoutableConnectors::Stripe,
+                common_enums::RoutableConnectors::Adyen,
+            ]),
+        );
+
+        assert_eq!(diagnostics, vec![]);
+        assert!(routing_types::RoutingImportValidationResponse::new(diagnostics).is_valid);
+    }
+
+    #[test]
+    fn test_parse_error_reports_location() {
+        // The value of the rule's `comparison` is missing its closing quote
+        let malformed_program = PROGRAM.replacen(r#""equal","#, r#""equal,"#, 1);
+
+        let diagnostics = get_routing_import_diagnostics(
+            &malformed_program,
+            None,
+            &connectors(&[common_enums::RoutableConnectors::Stripe]),
+        );
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code, AnalysisDiagnosticCode::ParseError);
+        let location = diagnostics[0].location.expect("parse error location");
+        let expected_line = PROGRAM
+            .lines()
+            .position(|line| line.contains(r#""comparison""#))
+            .unwrap()
+            + 1;
+        assert!(location.line >= expected_line);
+        assert!(location.column > 0);
+        assert!(!routing_types::RoutingImportValidationResponse::new(diagnostics).is_valid);
+    }
+
+    #[test]
+    fn test_unconfigured_connector_is_reported() {
+        let diagnostics = get_routing_import_diagnostics(
+            PROGRAM,
+            None,
+            &connectors(&[common_enums::RoutableConnectors::Stripe]),
+        );
+
+        assert_eq!(
+            diagnostics,
+            vec![AnalysisDiagnostic {
+                rule_name: Some("card_rule".to_string()),
+                ..AnalysisDiagnostic::error(
+                    AnalysisDiagnosticCode::ConnectorNotConfigured,
+                    "Connector 'adyen' is not configured for the profile".to_string(),
+                )
+            }]
+        );
+        assert!(!routing_types::RoutingImportValidationResponse::new(diagnostics).is_valid);
+    }
+}
@@ -54,11 +54,11 @@ pub async fn get_three_ds_decision_rule_output(
         .parse_value("Algorithm")
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Error parsing program from three_ds_decision rule algorithm")?;
-    let program: ast::Program<ThreeDSDecisionRule> = algorithm
-        .data
-        .parse_value("Program<ThreeDSDecisionRule>")
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Error parsing program from three_ds_decision rule algorithm")?;
+    let program_version = ast::migration::get_program_version(&algorithm.data);
+    let program: ast::Program<ThreeDSDecisionRule> =
+        ast::migration::migrate_program(algorithm.data, program_version)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error migrating program from three_ds_decision rule algorithm")?;
     // Construct backend input from request
     let backend_input = dsl_inputs::BackendInput::foreign_from(request.clone());
     // Initialize interpreter with the rule program
@@ -1,3 +1,4 @@
+pub mod refunds_normalization;
 pub mod refunds_transformers;
 pub mod refunds_validator;
 
@@ -408,6 +409,19 @@ pub async fn construct_refund_router_data<'a, F>(
     Ok(router_data)
 }
 
+/// The merchant connector account through which a refund is processed. This is the one used by
+/// the payment, unless another account of the same connector was requested for the refund.
+#[cfg(feature = "v1")]
+pub fn get_refund_processing_merchant_connector_id<'a>(
+    refund: &'a diesel_refund::Refund,
+    payment_attempt: &'a storage::PaymentAttempt,
+) -> Option<&'a common_utils::id_type::MerchantConnectorAccountId> {
+    refund
+        .merchant_connector_id
+        .as_ref()
+        .or(payment_attempt.merchant_connector_id.as_ref())
+}
+
 #[cfg(feature = "v1")]
 #[instrument(skip_all)]
 #[allow(clippy::too_many_arguments)]
@@ -429,6 +443,9 @@ pub async fn construct_refund_router_data<'a, F>(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("profile_id is not set in payment_intent")?;
 
+    let merchant_connector_id =
+        get_refund_processing_merchant_connector_id(refund, payment_attempt);
+
     let merchant_connector_account = helpers::get_merchant_connector_account(
         state,
         merchant_context.get_merchant_account().get_id(),
@@ -436,7 +453,7 @@ pub async fn construct_refund_router_data<'a, F>(
         merchant_context.get_merchant_key_store(),
         profile_id,
         connector_id,
-        payment_attempt.merchant_connector_id.as_ref(),
+        merchant_connector_id,
     )
     .await?;
 
@@ -453,9 +470,7 @@ pub async fn construct_refund_router_data<'a, F>(
         .payment_method
         .get_required_value("payment_method_type")
         .change_context(errors::ApiErrorResponse::InternalServerError)?;
-    let merchant_connector_account_id_or_connector_name = payment_attempt
-        .merchant_connector_id
-        .as_ref()
+    let merchant_connector_account_id_or_connector_name = merchant_connector_id
         .map(|mca_id| mca_id.get_string_repr())
         .unwrap_or(connector_id);
 
@@ -1948,6 +1963,12 @@ impl GetProfileId for diesel_models::Refund {
     }
 }
 
+impl GetProfileId for storage::RefundBatch {
+    fn get_profile_id(&self) -> Option<&common_utils::id_type::ProfileId> {
+        self.profile_id.as_ref()
+    }
+}
+
 #[cfg(feature = "v1")]
 impl GetProfileId for api_models::routing::RoutingConfigRequest {
     fn get_profile_id(&self) -> Option<&common_utils::id_type::ProfileId> {
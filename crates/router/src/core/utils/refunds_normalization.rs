@@ -0,0 +1,405 @@
+use api_models::enums::{RefundErrorCategory, RefundRecommendedAction, RefundStatus};
+
+use crate::consts;
+
+/// Get the action recommended to the merchant for a refund which has failed or is under review
+/// for an error of the category
+pub fn get_refund_recommended_action(
+    error_category: RefundErrorCategory,
+) -> RefundRecommendedAction {
+    match error_category {
+        RefundErrorCategory::ProcessorUnavailable
+        | RefundErrorCategory::InsufficientFundsOnAcquirer => RefundRecommendedAction::RetryLater,
+        RefundErrorCategory::RefundNotSupported | RefundErrorCategory::RefundWindowExpired => {
+            RefundRecommendedAction::RefundExternally
+        }
+        RefundErrorCategory::Duplicate => RefundRecommendedAction::None,
+        RefundErrorCategory::UnderReview | RefundErrorCategory::ProcessorDeclined => {
+            RefundRecommendedAction::ContactProcessor
+        }
+    }
+}
+
+/// Get the category of the error of a refund. This is present only for refunds which have failed
+/// or are under review.
+pub fn get_refund_error_category(
+    connector: &str,
+    refund_status: RefundStatus,
+    refund_error_code: Option<&str>,
+    refund_error_message: Option<&str>,
+) -> Option<RefundErrorCategory> {
+    match refund_status {
+        RefundStatus::ManualReview => Some(RefundErrorCategory::UnderReview),
+        RefundStatus::Failure | RefundStatus::TransactionFailure => Some(classify_refund_error(
+            connector,
+            refund_error_code,
+            refund_error_message,
+        )),
+        RefundStatus::Pending | RefundStatus::Success => None,
+    }
+}
+
+/// Part of a refund error returned by a connector that identifies its failure category
+#[derive(Clone, Copy, Debug)]
+enum RefundErrorPattern {
+    /// The error code is equal to the pattern
+    Code(&'static str),
+    /// The error message contains the pattern, ignoring case
+    MessageContains(&'static str),
+}
+
+impl RefundErrorPattern {
+    fn matches(self, code: Option<&str>, message: Option<&str>) -> bool {
+        match self {
+            Self::Code(pattern) => code == Some(pattern),
+            Self::MessageContains(pattern) => message.is_some_and(|message| {
+                message
+                    .to_ascii_lowercase()
+                    .contains(&pattern.to_ascii_lowercase())
+            }),
+        }
+    }
+}
+
+/// Categories of the refund errors of each connector, by the name of the connector. The
+/// first matching entry of the connector is used, so error codes are listed before the messages.
+const REFUND_ERROR_CATEGORIES: &[(&str, RefundErrorPattern, RefundErrorCategory)] = &[
+    (
+        "stripe",
+        RefundErrorPattern::Code("balance_insufficient"),
+        RefundErrorCategory::InsufficientFundsOnAcquirer,
+    ),
+    (
+        "stripe",
+        RefundErrorPattern::Code("insufficient_funds"),
+        RefundErrorCategory::InsufficientFundsOnAcquirer,
+    ),
+    (
+        "stripe",
+        RefundErrorPattern::Code("charge_already_refunded"),
+        RefundErrorCategory::Duplicate,
+    ),
+    (
+        "stripe",
+        RefundErrorPattern::Code("rate_limit"),
+        RefundErrorCategory::ProcessorUnavailable,
+    ),
+    (
+        "adyen",
+        RefundErrorPattern::MessageContains("insufficient balance"),
+        RefundErrorCategory::InsufficientFundsOnAcquirer,
+    ),
+    (
+        "adyen",
+        RefundErrorPattern::MessageContains("refund period"),
+        RefundErrorCategory::RefundWindowExpired,
+    ),
+    (
+        "adyen",
+        RefundErrorPattern::MessageContains("already refunded"),
+        RefundErrorCategory::Duplicate,
+    ),
+    (
+        "adyen",
+        RefundErrorPattern::MessageContains("service unavailable"),
+        RefundErrorCategory::ProcessorUnavailable,
+    ),
+    (
+        "amazonpay",
+        RefundErrorPattern::Code("TransactionTimedOut"),
+        RefundErrorCategory::ProcessorUnavailable,
+    ),
+    (
+        "amazonpay",
+        RefundErrorPattern::Code("TooManyRequests"),
+        RefundErrorCategory::ProcessorUnavailable,
+    ),
+    (
+        "amazonpay",
+        RefundErrorPattern::Code("ServiceUnavailable"),
+        RefundErrorCategory::ProcessorUnavailable,
+    ),
+    (
+        "amazonpay",
+        RefundErrorPattern::Code("InternalServerError"),
+        RefundErrorCategory::ProcessorUnavailable,
+    ),
+    // The refunds of the charge would exceed the amount charged
+    (
+        "amazonpay",
+        RefundErrorPattern::Code("TransactionAmountExceeded"),
+        RefundErrorCategory::Duplicate,
+    ),
+];
+
+/// Classifies a refund error returned by the connector into the category of its cause. Timeouts
+/// and unsupported refunds are categorized alike for every connector, errors which match none of
+/// the categories of the connector are treated as declines.
+pub fn classify_refund_error(
+    connector: &str,
+    code: Option<&str>,
+    message: Option<&str>,
+) -> RefundErrorCategory {
+    match code {
+        Some(consts::REQUEST_TIMEOUT_ERROR_CODE) => RefundErrorCategory::ProcessorUnavailable,
+        Some(consts::REFUND_NOT_IMPLEMENTED_ERROR_CODE)
+        | Some(consts::REFUND_NOT_SUPPORTED_ERROR_CODE) => RefundErrorCategory::RefundNotSupported,
+        _ => REFUND_ERROR_CATEGORIES
+            .iter()
+            .find(|(category_connector, pattern, _)| {
+                *category_connector == connector && pattern.matches(code, message)
+            })
+            .map_or(
+                RefundErrorCategory::ProcessorDeclined,
+                |(_, _, category)| *category,
+            ),
+    }
+}
+
+/// The unified code and message stored on a refund which failed for an error of the category, the
+/// default unified error when the error is a decline without a more specific category
+pub fn get_refund_failure_unified_error(category: RefundErrorCategory) -> (String, String) {
+    let unified_message = match category {
+        RefundErrorCategory::InsufficientFundsOnAcquirer => {
+            "Merchant balance at the connector is insufficient for the refund"
+        }
+        RefundErrorCategory::RefundWindowExpired => {
+            "Payment can no longer be refunded through the connector"
+        }
+        RefundErrorCategory::Duplicate => "Payment has already been refunded",
+        RefundErrorCategory::ProcessorUnavailable => "Connector is temporarily unavailable",
+        RefundErrorCategory::RefundNotSupported => {
+            "Connector does not support refunds for the payment"
+        }
+        RefundErrorCategory::UnderReview | RefundErrorCategory::ProcessorDeclined => {
+            return (
+                consts::DEFAULT_UNIFIED_ERROR_CODE.to_owned(),
+                consts::DEFAULT_UNIFIED_ERROR_MESSAGE.to_owned(),
+            )
+        }
+    };
+
+    (category.to_string(), unified_message.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recommended_action(
+        connector: &str,
+        refund_status: RefundStatus,
+        refund_error_code: Option<&str>,
+    ) -> Option<RefundRecommendedAction> {
+        get_refund_error_category(connector, refund_status, refund_error_code, None)
+            .map(get_refund_recommended_action)
+    }
+
+    #[test]
+    fn test_recommended_action_for_error_categories() {
+        assert_eq!(
+            recommended_action(
+                "adyen",
+                RefundStatus::Failure,
+                Some(consts::REQUEST_TIMEOUT_ERROR_CODE)
+            ),
+            Some(RefundRecommendedAction::RetryLater)
+        );
+        assert_eq!(
+            recommended_action(
+                "adyen",
+                RefundStatus::Failure,
+                Some(consts::REFUND_NOT_SUPPORTED_ERROR_CODE)
+            ),
+            Some(RefundRecommendedAction::RefundExternally)
+        );
+        assert_eq!(
+            recommended_action(
+                "adyen",
+                RefundStatus::TransactionFailure,
+                Some(consts::REFUND_NOT_IMPLEMENTED_ERROR_CODE)
+            ),
+            Some(RefundRecommendedAction::RefundExternally)
+        );
+        assert_eq!(
+            recommended_action("adyen", RefundStatus::ManualReview, Some("IE")),
+            Some(RefundRecommendedAction::ContactProcessor)
+        );
+        assert_eq!(
+            recommended_action("adyen", RefundStatus::Failure, None),
+            Some(RefundRecommendedAction::ContactProcessor)
+        );
+    }
+
+    #[test]
+    fn test_recommended_action_follows_connector_error_category() {
+        assert_eq!(
+            recommended_action(
+                "stripe",
+                RefundStatus::Failure,
+                Some("charge_already_refunded")
+            ),
+            Some(RefundRecommendedAction::None)
+        );
+        assert_eq!(
+            recommended_action(
+                "stripe",
+                RefundStatus::Failure,
+                Some("balance_insufficient")
+            ),
+            Some(RefundRecommendedAction::RetryLater)
+        );
+        // The categories of a connector do not apply to the errors of other connectors
+        assert_eq!(
+            recommended_action(
+                "adyen",
+                RefundStatus::Failure,
+                Some("charge_already_refunded")
+            ),
+            Some(RefundRecommendedAction::ContactProcessor)
+        );
+    }
+
+    #[test]
+    fn test_error_category_absent_for_successful_or_pending_refunds() {
+        assert_eq!(
+            get_refund_error_category("stripe", RefundStatus::Success, None, None),
+            None
+        );
+        assert_eq!(
+            get_refund_error_category(
+                "stripe",
+                RefundStatus::Pending,
+                Some(consts::REQUEST_TIMEOUT_ERROR_CODE),
+                None
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_classify_refund_error() {
+        let cases = [
+            // (connector, code, message, category)
+            (
+                "stripe",
+                Some("balance_insufficient"),
+                None,
+                RefundErrorCategory::InsufficientFundsOnAcquirer,
+            ),
+            (
+                "stripe",
+                Some("charge_already_refunded"),
+                Some("Charge ch_1 has already been refunded."),
+                RefundErrorCategory::Duplicate,
+            ),
+            (
+                "stripe",
+                Some("rate_limit"),
+                None,
+                RefundErrorCategory::ProcessorUnavailable,
+            ),
+            (
+                "stripe",
+                Some("card_declined"),
+                None,
+                RefundErrorCategory::ProcessorDeclined,
+            ),
+            (
+                "adyen",
+                Some("702"),
+                Some("Insufficient balance on payment"),
+                RefundErrorCategory::InsufficientFundsOnAcquirer,
+            ),
+            (
+                "adyen",
+                None,
+                Some("Refund period has expired"),
+                RefundErrorCategory::RefundWindowExpired,
+            ),
+            (
+                "adyen",
+                None,
+                Some("Payment already refunded"),
+                RefundErrorCategory::Duplicate,
+            ),
+            (
+                "amazonpay",
+                Some("TransactionTimedOut"),
+                None,
+                RefundErrorCategory::ProcessorUnavailable,
+            ),
+            (
+                "amazonpay",
+                Some("TransactionAmountExceeded"),
+                None,
+                RefundErrorCategory::Duplicate,
+            ),
+            (
+                "amazonpay",
+                Some("HardDeclined"),
+                None,
+                RefundErrorCategory::ProcessorDeclined,
+            ),
+            // Mappings apply only to the connector they are listed for
+            (
+                "adyen",
+                Some("balance_insufficient"),
+                None,
+                RefundErrorCategory::ProcessorDeclined,
+            ),
+            (
+                "checkout",
+                Some(consts::REQUEST_TIMEOUT_ERROR_CODE),
+                None,
+                RefundErrorCategory::ProcessorUnavailable,
+            ),
+            (
+                "checkout",
+                Some(consts::REFUND_NOT_SUPPORTED_ERROR_CODE),
+                None,
+                RefundErrorCategory::RefundNotSupported,
+            ),
+            (
+                "checkout",
+                None,
+                None,
+                RefundErrorCategory::ProcessorDeclined,
+            ),
+        ];
+
+        for (connector, code, message, category) in cases {
+            assert_eq!(
+                classify_refund_error(connector, code, message),
+                category,
+                "{connector} {code:?} {message:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_refund_failure_unified_error() {
+        let error_category = get_refund_error_category(
+            "adyen",
+            RefundStatus::Failure,
+            None,
+            Some("Refund period has expired"),
+        );
+        assert_eq!(
+            error_category,
+            Some(RefundErrorCategory::RefundWindowExpired)
+        );
+        // The unified code of the refund is the category reported on the refund
+        assert_eq!(
+            get_refund_failure_unified_error(RefundErrorCategory::RefundWindowExpired).0,
+            "refund_window_expired"
+        );
+        assert_eq!(
+            get_refund_failure_unified_error(RefundErrorCategory::ProcessorDeclined),
+            (
+                consts::DEFAULT_UNIFIED_ERROR_CODE.to_owned(),
+                consts::DEFAULT_UNIFIED_ERROR_MESSAGE.to_owned()
+            )
+        );
+    }
+}
@@ -1,6 +1,117 @@
+use base64::Engine;
+use common_utils::{
+    consts::BASE64_ENGINE_URL_SAFE,
+    date_time,
+    types::{AmountConvertor, MinorUnit, StringMajorUnit, StringMajorUnitForConnector},
+};
+use router_env::logger;
+use time::{OffsetDateTime, PrimitiveDateTime};
+
 pub struct SplitRefundInput {
     pub refund_request: Option<common_types::refunds::SplitRefund>,
     pub payment_charges: Option<common_types::payments::ConnectorChargeResponseData>,
     pub split_payment_request: Option<common_types::payments::SplitPaymentsRequest>,
     pub charge_id: Option<String>,
 }
+
+/// Formats a refund amount in the major unit of its currency, exactly as it would be sent to a
+/// connector that accepts string major unit amounts. The formatted amount is only meant for
+/// display and is not returned if the conversion fails.
+pub fn get_formatted_refund_amount(
+    amount: MinorUnit,
+    currency: common_enums::Currency,
+) -> Option<StringMajorUnit> {
+    StringMajorUnitForConnector
+        .convert(amount, currency)
+        .inspect_err(|error| logger::error!(?error, "Failed to format refund amount"))
+        .ok()
+}
+
+/// Position of a refund among the refunds of a merchant ordered by modification time and refund
+/// id, handed out as an opaque cursor by the incremental refunds sync.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefundsModifiedSinceCursor {
+    pub modified_at: PrimitiveDateTime,
+    pub refund_id: String,
+}
+
+impl RefundsModifiedSinceCursor {
+    pub fn encode(&self) -> String {
+        let timestamp = self.modified_at.assume_utc().unix_timestamp_nanos();
+        BASE64_ENGINE_URL_SAFE.encode(format!("{timestamp}:{}", self.refund_id))
+    }
+
+    /// Decodes a cursor returned by [`Self::encode`], returns `None` if the cursor is malformed
+    pub fn decode(cursor: &str) -> Option<Self> {
+        let decoded = String::from_utf8(BASE64_ENGINE_URL_SAFE.decode(cursor).ok()?).ok()?;
+        let (timestamp, refund_id) = decoded.split_once(':')?;
+        let modified_at =
+            OffsetDateTime::from_unix_timestamp_nanos(timestamp.parse().ok()?).ok()?;
+
+        Some(Self {
+            modified_at: date_time::convert_to_pdt(modified_at),
+            refund_id: refund_id.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use common_enums::Currency;
+
+    use super::*;
+
+    fn formatted(amount: i64, currency: Currency) -> String {
+        get_formatted_refund_amount(MinorUnit::new(amount), currency)
+            .unwrap()
+            .get_amount_as_string()
+    }
+
+    #[test]
+    fn test_refund_amount_is_formatted_for_two_decimal_currency() {
+        assert_eq!(formatted(6540, Currency::USD), "65.40");
+        assert_eq!(formatted(5, Currency::EUR), "0.05");
+    }
+
+    #[test]
+    fn test_refund_amount_is_formatted_for_zero_decimal_currency() {
+        assert_eq!(formatted(6540, Currency::JPY), "6540");
+        assert_eq!(formatted(1, Currency::KRW), "1");
+    }
+
+    #[test]
+    fn test_refund_amount_is_formatted_for_three_decimal_currency() {
+        assert_eq!(formatted(6540, Currency::KWD), "6.540");
+        assert_eq!(formatted(1, Currency::BHD), "0.001");
+    }
+
+    #[test]
+    fn test_refund_amount_formatting_matches_connector_amount() {
+        for currency in [Currency::USD, Currency::JPY, Currency::KWD] {
+            let amount = MinorUnit::new(123456);
+            assert_eq!(
+                get_formatted_refund_amount(amount, currency),
+                Some(
+                    StringMajorUnitForConnector
+                        .convert(amount, currency)
+                        .unwrap()
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_refunds_modified_since_cursor_round_trip() {
+        let cursor = RefundsModifiedSinceCursor {
+            modified_at: date_time::now(),
+            refund_id: "ref_1:retry".to_string(),
+        };
+
+        assert_eq!(
+            RefundsModifiedSinceCursor::decode(&cursor.encode()),
+            Some(cursor)
+        );
+        assert_eq!(RefundsModifiedSinceCursor::decode("not a cursor"), None);
+    }
+}
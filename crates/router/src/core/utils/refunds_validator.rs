@@ -1,6 +1,9 @@
+use std::str::FromStr;
+
 use diesel_models::refund as diesel_refund;
 use error_stack::report;
 use router_env::{instrument, tracing};
+use strum::{IntoEnumIterator, VariantNames};
 use time::PrimitiveDateTime;
 
 use crate::{
@@ -30,6 +33,12 @@ pub enum RefundValidationError {
     MaxRefundCountReached,
     #[error("There is already another refund request for this payment attempt")]
     DuplicateRefund,
+    #[error("The refund amount exceeds the refund velocity limit")]
+    RefundVelocityLimitExceeded,
+    #[error("The refund amount exceeds the refund amount limit")]
+    RefundAmountLimitExceeded,
+    #[error("The refund currency does not match the currency of the refund amount limit")]
+    RefundLimitCurrencyMismatch,
 }
 
 #[instrument(skip_all)]
@@ -95,6 +104,57 @@ pub fn validate_maximum_refund_against_payment_attempt(
     })
 }
 
+/// Sums the amounts of the refunds in `currency` that have not failed
+fn get_refunded_amount(refunds: &[diesel_refund::Refund], currency: enums::Currency) -> i64 {
+    refunds
+        .iter()
+        .filter(|refund| {
+            refund.currency == currency
+                && refund.refund_status != enums::RefundStatus::Failure
+                && refund.refund_status != enums::RefundStatus::TransactionFailure
+        })
+        .map(|refund| refund.refund_amount.get_amount_as_i64())
+        .sum()
+}
+
+#[instrument(skip_all)]
+pub fn validate_refund_velocity(
+    refunds_in_window: &[diesel_refund::Refund],
+    currency: enums::Currency,
+    refund_amount: i64,
+    max_amount: i64,
+) -> CustomResult<(), RefundValidationError> {
+    let refunded_amount = get_refunded_amount(refunds_in_window, currency);
+
+    utils::when(refunded_amount + refund_amount > max_amount, || {
+        Err(report!(RefundValidationError::RefundVelocityLimitExceeded))
+    })
+}
+
+#[instrument(skip_all)]
+pub fn validate_refund_limit_currency(
+    limit_currency: enums::Currency,
+    refund_currency: enums::Currency,
+) -> CustomResult<(), RefundValidationError> {
+    utils::when(limit_currency != refund_currency, || {
+        Err(report!(RefundValidationError::RefundLimitCurrencyMismatch))
+    })
+}
+
+#[instrument(skip_all)]
+pub fn validate_refund_amount_limit(
+    refunds: &[diesel_refund::Refund],
+    currency: enums::Currency,
+    refund_amount: i64,
+    max_amount: i64,
+) -> CustomResult<(), RefundValidationError> {
+    let refunded_amount = get_refunded_amount(refunds, currency);
+
+    utils::when(refunded_amount + refund_amount > max_amount, || {
+        Err(report!(RefundValidationError::RefundAmountLimitExceeded))
+    })
+}
+
 pub fn validate_refund_list(limit: Option<i64>) -> CustomResult<i64, errors::ApiErrorResponse> {
     match limit {
         Some(limit_val) => {
@@ -111,6 +171,43 @@ pub fn validate_refund_list(limit: Option<i64>) -> CustomResult<i64, errors::Api
     }
 }
 
+/// Parses the columns requested for a refunds export, all the exportable columns are exported if
+/// none are requested
+pub fn validate_refund_export_fields(
+    fields: Option<&[String]>,
+) -> CustomResult<Vec<api_models::refunds::RefundExportField>, errors::ApiErrorResponse> {
+    use api_models::refunds::RefundExportField;
+
+    let Some(fields) = fields else {
+        return Ok(RefundExportField::iter().collect());
+    };
+    let allowed_fields = || RefundExportField::VARIANTS.join(", ");
+
+    if fields.is_empty() {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "fields must not be empty, the allowed fields are: {}",
+                allowed_fields()
+            ),
+        }
+        .into());
+    }
+
+    fields
+        .iter()
+        .map(|field| {
+            RefundExportField::from_str(field).map_err(|_| {
+                report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "unknown refund export field '{field}', the allowed fields are: {}",
+                        allowed_fields()
+                    ),
+                })
+            })
+        })
+        .collect()
+}
+
 #[cfg(feature = "v1")]
 pub fn validate_for_valid_refunds(
     payment_attempt: &hyperswitch_domain_models::payments::payment_attempt::PaymentAttempt,
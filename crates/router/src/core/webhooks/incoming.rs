@@ -18,7 +18,10 @@ use hyperswitch_domain_models::{
     router_request_types::VerifyWebhookSourceRequestData,
     router_response_types::{VerifyWebhookSourceResponseData, VerifyWebhookStatus},
 };
-use hyperswitch_interfaces::webhooks::{IncomingWebhookFlowError, IncomingWebhookRequestDetails};
+use hyperswitch_interfaces::{
+    api::ConnectorSpecifications,
+    webhooks::{IncomingWebhookFlowError, IncomingWebhookRequestDetails},
+};
 use masking::{ExposeInterface, PeekInterface};
 use router_env::{instrument, tracing, tracing_actix_web::RequestId};
 
@@ -28,13 +31,13 @@ use crate::{
     core::{
         api_locking,
         errors::{self, ConnectorErrorExt, CustomResult, RouterResponse, StorageErrorExt},
-        metrics, payment_methods,
+        mandate, metrics, payment_methods,
         payments::{self, tokenization},
         refunds, relay, utils as core_utils,
         webhooks::{network_tokenization_incoming, utils::construct_webhook_router_data},
     },
     db::StorageInterface,
-    events::api_logs::ApiEvent,
+    events::{api_logs::ApiEvent, domain_events},
     logger,
     routes::{
         app::{ReqState, SessionStateInfo},
@@ -330,7 +333,11 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
             None => {
                 match Box::pin(helper_utils::get_mca_from_object_reference_id(
                     &state,
-                    object_ref_id.clone(),
+                    get_object_reference_id_for_mca_lookup(
+                        &connector,
+                        &request_details,
+                        object_ref_id.clone(),
+                    ),
                     &merchant_context,
                     &connector_name,
                 ))
@@ -500,6 +507,8 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
                     business_profile,
                     webhook_details,
                     connector_name.as_str(),
+                    &connector,
+                    &request_details,
                     source_verified,
                     event_type,
                 ))
@@ -660,6 +669,43 @@ fn handle_incoming_webhook_error(
     }
 }
 
+/// Refunds initiated on the connector's end are only stored once their webhook is processed, so
+/// the merchant connector account of refund webhooks is resolved through the refunded payment
+/// whenever the connector provides its connector transaction id
+fn get_object_reference_id_for_mca_lookup(
+    connector: &ConnectorEnum,
+    request_details: &IncomingWebhookRequestDetails<'_>,
+    object_ref_id: webhooks::ObjectReferenceId,
+) -> webhooks::ObjectReferenceId {
+    match object_ref_id {
+        webhooks::ObjectReferenceId::RefundId(_) => {
+            match connector.get_connector_initiated_refund_details(request_details) {
+                Ok(Some(refund_details)) => {
+                    // Connector transaction ids are hashed the same way when stored
+                    let connector_transaction_id =
+                        common_utils::types::ConnectorTransactionId::from(
+                            refund_details.connector_transaction_id,
+                        );
+                    webhooks::ObjectReferenceId::PaymentId(
+                        api_models::payments::PaymentIdType::ConnectorTransactionId(
+                            connector_transaction_id.get_id().to_owned(),
+                        ),
+                    )
+                }
+                Ok(None) => object_ref_id,
+                Err(error) => {
+                    logger::warn!(
+                        ?error,
+                        "Failed to fetch connector initiated refund details from webhook"
+                    );
+                    object_ref_id
+                }
+            }
+        }
+        _ => object_ref_id,
+    }
+}
+
 #[instrument(skip_all)]
 #[cfg(feature = "v1")]
 async fn network_token_incoming_webhooks_core<W: types::OutgoingWebhookType>(
@@ -803,6 +849,7 @@ async fn payments_incoming_webhook_flow(
                     expand_attempts: None,
                     expand_captures: None,
                     all_keys_required: None,
+                    expand_mandate: None,
                 },
                 services::AuthFlow::Merchant,
                 consume_or_trigger_flow.clone(),
@@ -819,6 +866,15 @@ async fn payments_incoming_webhook_flow(
                     connector,
                     request_details,
                 )
+                .await?;
+                activate_pending_mandate(
+                    &state,
+                    &merchant_context,
+                    &business_profile,
+                    webhook_details.object_reference_id.clone(),
+                    connector,
+                    request_details,
+                )
                 .await?
             };
             lock_action
@@ -1118,6 +1174,8 @@ async fn refunds_incoming_webhook_flow(
     business_profile: domain::Profile,
     webhook_details: api::IncomingWebhookDetails,
     connector_name: &str,
+    connector: &ConnectorEnum,
+    request_details: &IncomingWebhookRequestDetails<'_>,
     source_verified: bool,
     event_type: webhooks::IncomingWebhookEvent,
 ) -> CustomResult<WebhookResponseTracker, errors::ApiErrorResponse> {
@@ -1134,16 +1192,55 @@ async fn refunds_incoming_webhook_flow(
                 .await
                 .change_context(errors::ApiErrorResponse::WebhookResourceNotFound)
                 .attach_printable("Failed to fetch the refund")?,
-            webhooks::RefundIdType::ConnectorRefundId(id) => db
-                .find_refund_by_merchant_id_connector_refund_id_connector(
+            webhooks::RefundIdType::ConnectorRefundId(id) => {
+                match refunds::find_refund_by_connector_refund_id(
+                    db,
                     merchant_context.get_merchant_account().get_id(),
                     &id,
                     connector_name,
                     merchant_context.get_merchant_account().storage_scheme,
                 )
                 .await
-                .change_context(errors::ApiErrorResponse::WebhookResourceNotFound)
-                .attach_printable("Failed to fetch the refund")?,
+                {
+                    Ok(refund) => refund,
+                    // The refund might have been initiated on the connector's end, in which case
+                    // the refund is created from the webhook itself
+                    Err(error) if error.current_context().is_db_not_found() && source_verified => {
+                        let refund_details = connector
+                            .get_connector_initiated_refund_details(request_details)
+                            .switch()
+                            .attach_printable(
+                                "Failed to fetch connector initiated refund details from webhook",
+                            )?
+                            .ok_or(errors::ApiErrorResponse::WebhookResourceNotFound)
+                            .attach_printable("Failed to fetch the refund")?;
+                        let refund_status =
+                            common_enums::RefundStatus::foreign_try_from(event_type)
+                                .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+                                .attach_printable("failed refund status mapping from event type")?;
+                        let refund = Box::pin(refunds::create_connector_initiated_refund(
+                            &state,
+                            &merchant_context,
+                            &business_profile,
+                            connector_name,
+                            id,
+                            refund_status,
+                            refund_details,
+                        ))
+                        .await?;
+                        return Box::pin(trigger_refund_webhook_for_updated_refund(
+                            state,
+                            merchant_context,
+                            business_profile,
+                            refund,
+                        ))
+                        .await;
+                    }
+                    Err(error) => Err(error)
+                        .change_context(errors::ApiErrorResponse::WebhookResourceNotFound)
+                        .attach_printable("Failed to fetch the refund")?,
+                }
+            }
         },
         _ => Err(errors::ApiErrorResponse::WebhookProcessingFailure)
             .attach_printable("received a non-refund id when processing refund webhooks")?,
@@ -1163,14 +1260,19 @@ async fn refunds_incoming_webhook_flow(
                 .to_string(),
             processor_refund_data: None,
         };
-        db.update_refund(
-            refund.to_owned(),
-            refund_update,
-            merchant_context.get_merchant_account().storage_scheme,
-        )
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
-        .attach_printable_lazy(|| format!("Failed while updating refund: refund_id: {refund_id}"))?
+        let updated_refund = db
+            .update_refund(
+                refund.to_owned(),
+                refund_update,
+                merchant_context.get_merchant_account().storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
+            .attach_printable_lazy(|| {
+                format!("Failed while updating refund: refund_id: {refund_id}")
+            })?;
+        domain_events::emit_refund_status_changed(&state, &refund, &updated_refund);
+        updated_refund
     } else {
         Box::pin(refunds::refund_retrieve_core_with_refund_id(
             state.clone(),
@@ -1185,12 +1287,30 @@ async fn refunds_incoming_webhook_flow(
         .await
         .attach_printable_lazy(|| format!("Failed while updating refund: refund_id: {refund_id}"))?
     };
+
+    Box::pin(trigger_refund_webhook_for_updated_refund(
+        state,
+        merchant_context,
+        business_profile,
+        updated_refund,
+    ))
+    .await
+}
+
+async fn trigger_refund_webhook_for_updated_refund(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    business_profile: domain::Profile,
+    updated_refund: diesel_refund::Refund,
+) -> CustomResult<WebhookResponseTracker, errors::ApiErrorResponse> {
+    let refund_id = updated_refund.refund_id.to_owned();
     let event_type: Option<enums::EventType> = updated_refund.refund_status.into();
 
     // If event is NOT an UnsupportedEvent, trigger Outgoing Webhook
     if let Some(outgoing_event_type) = event_type {
         let refund_response: api_models::refunds::RefundResponse =
             updated_refund.clone().foreign_into();
+        let payload_version = business_profile.get_outgoing_webhook_payload_version();
         Box::pin(super::create_event_and_trigger_outgoing_webhook(
             state,
             merchant_context,
@@ -1199,7 +1319,9 @@ async fn refunds_incoming_webhook_flow(
             enums::EventClass::Refunds,
             refund_id,
             enums::EventObjectType::RefundDetails,
-            api::OutgoingWebhookContent::RefundDetails(Box::new(refund_response)),
+            api::OutgoingWebhookContent::RefundDetails(Box::new(
+                api_models::refunds::RefundWebhookResponse::new(refund_response, payload_version),
+            )),
             Some(updated_refund.created_at),
         ))
         .await?;
@@ -2117,6 +2239,75 @@ fn should_update_connector_mandate_details(
     source_verified && event_type == webhooks::IncomingWebhookEvent::PaymentIntentSuccess
 }
 
+/// Activates the pending mandate set up by the payment, for connectors which send the mandate
+/// reference asynchronously in the payment webhook
+async fn activate_pending_mandate(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    business_profile: &domain::Profile,
+    object_ref_id: api::ObjectReferenceId,
+    connector: &ConnectorEnum,
+    request_details: &IncomingWebhookRequestDetails<'_>,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    if !connector.is_mandate_reference_async() {
+        return Ok(());
+    }
+
+    let Some(webhook_mandate_details) = connector
+        .get_mandate_details(request_details)
+        .switch()
+        .attach_printable("Could not find connector mandate details in incoming webhook body")?
+    else {
+        return Ok(());
+    };
+
+    let payment_attempt =
+        get_payment_attempt_from_object_reference_id(state, object_ref_id, merchant_context)
+            .await?;
+
+    let Some(activated_mandate) = mandate::activate_pending_mandate(
+        &*state.store,
+        merchant_context.get_merchant_account().get_id(),
+        &payment_attempt.payment_id,
+        webhook_mandate_details
+            .connector_mandate_id
+            .peek()
+            .to_string(),
+        merchant_context.get_merchant_account().storage_scheme,
+    )
+    .await?
+    else {
+        return Ok(());
+    };
+
+    let mandates_response = Box::new(
+        api::mandates::MandateResponse::from_db_mandate(
+            state,
+            merchant_context.get_merchant_key_store().clone(),
+            activated_mandate.clone(),
+            merchant_context.get_merchant_account(),
+        )
+        .await?,
+    );
+    let event_type: Option<enums::EventType> = activated_mandate.mandate_status.into();
+    if let Some(outgoing_event_type) = event_type {
+        Box::pin(super::create_event_and_trigger_outgoing_webhook(
+            state.clone(),
+            merchant_context.clone(),
+            business_profile.clone(),
+            outgoing_event_type,
+            enums::EventClass::Mandates,
+            activated_mandate.mandate_id.clone(),
+            enums::EventObjectType::MandateDetails,
+            api::OutgoingWebhookContent::MandateDetails(mandates_response),
+            Some(activated_mandate.created_at),
+        ))
+        .await?;
+    }
+
+    Ok(())
+}
+
 async fn update_connector_mandate_details(
     state: &SessionState,
     merchant_context: &domain::MerchantContext,
@@ -2303,3 +2494,75 @@ fn insert_mandate_details(
     )?;
     Ok(connector_mandate_details)
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use super::*;
+    use crate::connector;
+
+    fn get_stripe_object_reference_id_for_mca_lookup(
+        event_object: serde_json::Value,
+    ) -> webhooks::ObjectReferenceId {
+        let body = serde_json::to_vec(&serde_json::json!({
+            "type": "charge.refund.updated",
+            "data": { "object": event_object },
+        }))
+        .unwrap();
+        let headers = actix_web::http::header::HeaderMap::new();
+        let request_details = IncomingWebhookRequestDetails {
+            method: http::Method::POST,
+            uri: http::Uri::default(),
+            headers: &headers,
+            query_params: String::new(),
+            body: &body,
+        };
+
+        get_object_reference_id_for_mca_lookup(
+            &ConnectorEnum::Old(Box::new(connector::Stripe::new())),
+            &request_details,
+            webhooks::ObjectReferenceId::RefundId(webhooks::RefundIdType::ConnectorRefundId(
+                "re_1".to_string(),
+            )),
+        )
+    }
+
+    #[test]
+    fn test_refund_webhook_mca_is_looked_up_through_the_refunded_payment() {
+        let object_reference_id =
+            get_stripe_object_reference_id_for_mca_lookup(serde_json::json!({
+                "id": "re_1",
+                "object": "refund",
+                "amount": 400,
+                "currency": "usd",
+                "payment_intent": "pi_1",
+                "created": 1700000000,
+            }));
+
+        assert!(matches!(
+            object_reference_id,
+            webhooks::ObjectReferenceId::PaymentId(
+                api_models::payments::PaymentIdType::ConnectorTransactionId(connector_transaction_id)
+            ) if connector_transaction_id == "pi_1"
+        ));
+    }
+
+    #[test]
+    fn test_refund_webhook_without_payment_keeps_the_refund_reference() {
+        let object_reference_id =
+            get_stripe_object_reference_id_for_mca_lookup(serde_json::json!({
+                "id": "re_1",
+                "object": "refund",
+                "amount": 400,
+                "currency": "usd",
+                "created": 1700000000,
+            }));
+
+        assert!(matches!(
+            object_reference_id,
+            webhooks::ObjectReferenceId::RefundId(webhooks::RefundIdType::ConnectorRefundId(
+                connector_refund_id
+            )) if connector_refund_id == "re_1"
+        ));
+    }
+}
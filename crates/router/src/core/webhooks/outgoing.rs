@@ -88,6 +88,7 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         event_type,
         content: content.clone(),
         timestamp: now,
+        payload_version: business_profile.get_outgoing_webhook_payload_version(),
     };
 
     let request_content =
@@ -1010,8 +1011,8 @@ impl ForeignFrom<&api::OutgoingWebhookContent> for storage::EventMetadata {
                 payment_id: payments_response.payment_id.clone(),
             },
             webhooks::OutgoingWebhookContent::RefundDetails(refund_response) => Self::Refund {
-                payment_id: refund_response.payment_id.clone(),
-                refund_id: refund_response.refund_id.clone(),
+                payment_id: refund_response.get_refund().payment_id.clone(),
+                refund_id: refund_response.get_refund().refund_id.clone(),
             },
             webhooks::OutgoingWebhookContent::DisputeDetails(dispute_response) => Self::Dispute {
                 payment_id: dispute_response.payment_id.clone(),
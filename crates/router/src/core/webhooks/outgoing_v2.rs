@@ -72,6 +72,7 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         event_type,
         content: content.clone(),
         timestamp: now,
+        payload_version: business_profile.get_outgoing_webhook_payload_version(),
     };
 
     let request_content = get_outgoing_webhook_request(outgoing_webhook, &business_profile)
@@ -30,6 +30,7 @@ pub mod organization;
 pub mod payment_link;
 pub mod payment_method_session;
 pub mod refund;
+pub mod refund_batch;
 pub mod relay;
 pub mod reverse_lookup;
 pub mod role;
@@ -121,6 +122,7 @@ pub trait StorageInterface:
     + PayoutAttemptInterface<Error = StorageError>
     + PayoutsInterface<Error = StorageError>
     + refund::RefundInterface
+    + refund_batch::RefundBatchInterface
     + reverse_lookup::ReverseLookupInterface
     + CardsInfoInterface<Error = StorageError>
     + merchant_key_store::MerchantKeyStoreInterface
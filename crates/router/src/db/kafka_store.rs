@@ -964,6 +964,59 @@ impl MandateInterface for KafkaStore {
             .await
     }
 
+    async fn find_active_mandates_by_merchant_created_before(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        created_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError> {
+        self.diesel_store
+            .find_active_mandates_by_merchant_created_before(merchant_id, created_before, limit)
+            .await
+    }
+
+    async fn find_active_mandates_without_connector_mandate_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        starting_after: Option<&str>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError> {
+        self.diesel_store
+            .find_active_mandates_without_connector_mandate_id(merchant_id, starting_after, limit)
+            .await
+    }
+
+    async fn find_pending_mandates_by_merchant_created_before(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        created_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError> {
+        self.diesel_store
+            .find_pending_mandates_by_merchant_created_before(merchant_id, created_before, limit)
+            .await
+    }
+
+    async fn find_mandates_by_merchant_id_original_payment_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        original_payment_id: &id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError> {
+        self.diesel_store
+            .find_mandates_by_merchant_id_original_payment_id(merchant_id, original_payment_id)
+            .await
+    }
+
+    async fn count_active_mandates_by_customer(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        customer_id: &id_type::CustomerId,
+    ) -> CustomResult<i64, errors::StorageError> {
+        self.diesel_store
+            .count_active_mandates_by_customer(merchant_id, customer_id)
+            .await
+    }
+
     async fn update_mandate_by_merchant_id_mandate_id(
         &self,
         merchant_id: &id_type::MerchantId,
@@ -993,6 +1046,17 @@ impl MandateInterface for KafkaStore {
             .await
     }
 
+    async fn find_mandates_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        customer_id: &id_type::CustomerId,
+        mandate_constraints: api_models::mandates::MandateListConstraints,
+    ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError> {
+        self.diesel_store
+            .find_mandates_by_merchant_id_customer_id(merchant_id, customer_id, mandate_constraints)
+            .await
+    }
+
     async fn insert_mandate(
         &self,
         mandate: storage::MandateNew,
@@ -1669,6 +1733,18 @@ impl PaymentAttemptInterface for KafkaStore {
             .await
     }
 
+    #[cfg(feature = "v2")]
+    async fn attempt_belongs_to_payment(
+        &self,
+        attempt_id: &id_type::GlobalAttemptId,
+        payment_id: &id_type::GlobalPaymentId,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<bool, errors::StorageError> {
+        self.diesel_store
+            .attempt_belongs_to_payment(attempt_id, payment_id, storage_scheme)
+            .await
+    }
+
     #[cfg(feature = "v2")]
     async fn find_payment_attempts_by_payment_intent_id(
         &self,
@@ -2716,6 +2792,24 @@ impl RefundInterface for KafkaStore {
             .await
     }
 
+    #[cfg(feature = "v1")]
+    async fn find_refunds_by_merchant_id_profile_id_created_after(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+        created_after: PrimitiveDateTime,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<diesel_refund::Refund>, errors::StorageError> {
+        self.diesel_store
+            .find_refunds_by_merchant_id_profile_id_created_after(
+                merchant_id,
+                profile_id,
+                created_after,
+                storage_scheme,
+            )
+            .await
+    }
+
     #[cfg(feature = "v1")]
     async fn find_refund_by_merchant_id_refund_id(
         &self,
@@ -2862,6 +2956,56 @@ impl RefundInterface for KafkaStore {
             .await
     }
 
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn find_refunds_without_merchant_connector_id_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        starting_after: Option<&str>,
+        limit: i64,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<diesel_refund::Refund>, errors::StorageError> {
+        self.diesel_store
+            .find_refunds_without_merchant_connector_id_by_merchant_id(
+                merchant_id,
+                starting_after,
+                limit,
+                storage_scheme,
+            )
+            .await
+    }
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn find_stale_pending_refunds_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        modified_before: PrimitiveDateTime,
+        limit: i64,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<diesel_refund::Refund>, errors::StorageError> {
+        self.diesel_store
+            .find_stale_pending_refunds_by_merchant_id(
+                merchant_id,
+                modified_before,
+                limit,
+                storage_scheme,
+            )
+            .await
+    }
+
+    #[cfg(feature = "olap")]
+    async fn list_refunds_modified_since(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        since: PrimitiveDateTime,
+        limit: i64,
+        cursor: Option<&str>,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<diesel_refund::Refund>, errors::StorageError> {
+        self.diesel_store
+            .list_refunds_modified_since(merchant_id, since, limit, cursor, storage_scheme)
+            .await
+    }
+
     #[cfg(all(feature = "v1", feature = "olap"))]
     async fn get_refund_status_with_count(
         &self,
@@ -2875,6 +3019,40 @@ impl RefundInterface for KafkaStore {
             .await
     }
 
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn get_refund_status_counts(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id_list: Option<Vec<id_type::ProfileId>>,
+        time_range: &common_utils::types::TimeRange,
+        group_by: api_models::refunds::RefundStatusCountGroupBy,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage::RefundStatusCountRow>, errors::StorageError> {
+        self.diesel_store
+            .get_refund_status_counts(
+                merchant_id,
+                profile_id_list,
+                time_range,
+                group_by,
+                storage_scheme,
+            )
+            .await
+    }
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn find_inconsistent_refunds(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        older_than: time::PrimitiveDateTime,
+        rules: &api_models::refunds::RefundReconciliationRules,
+        limit: i64,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<diesel_refund::Refund>, errors::StorageError> {
+        self.diesel_store
+            .find_inconsistent_refunds(merchant_id, older_than, rules, limit, storage_scheme)
+            .await
+    }
+
     #[cfg(all(feature = "v1", feature = "olap"))]
     async fn get_total_count_of_refunds(
         &self,
@@ -1,4 +1,5 @@
 use common_utils::id_type;
+use error_stack::ResultExt;
 
 use super::MockDb;
 use crate::{
@@ -28,6 +29,39 @@ pub trait MandateInterface {
         customer_id: &id_type::CustomerId,
     ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError>;
 
+    async fn find_active_mandates_by_merchant_created_before(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        created_before: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError>;
+
+    async fn find_active_mandates_without_connector_mandate_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        starting_after: Option<&str>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError>;
+
+    async fn find_pending_mandates_by_merchant_created_before(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        created_before: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError>;
+
+    async fn find_mandates_by_merchant_id_original_payment_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        original_payment_id: &id_type::PaymentId,
+    ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError>;
+
+    async fn count_active_mandates_by_customer(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        customer_id: &id_type::CustomerId,
+    ) -> CustomResult<i64, errors::StorageError>;
+
     // Fix this function once we move to mandate v2
     #[cfg(feature = "v2")]
     async fn find_mandate_by_global_customer_id(
@@ -50,6 +84,13 @@ pub trait MandateInterface {
         mandate_constraints: api_models::mandates::MandateListConstraints,
     ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError>;
 
+    async fn find_mandates_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        customer_id: &id_type::CustomerId,
+        mandate_constraints: api_models::mandates::MandateListConstraints,
+    ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError>;
+
     async fn insert_mandate(
         &self,
         mandate: storage_types::MandateNew,
@@ -199,6 +240,92 @@ mod storage {
                 .map_err(|error| report!(errors::StorageError::from(error)))
         }
 
+        #[instrument(skip_all)]
+        async fn find_active_mandates_by_merchant_created_before(
+            &self,
+            merchant_id: &id_type::MerchantId,
+            created_before: time::PrimitiveDateTime,
+            limit: i64,
+        ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Mandate::find_active_by_merchant_id_created_before(
+                &conn,
+                merchant_id,
+                created_before,
+                limit,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn find_active_mandates_without_connector_mandate_id(
+            &self,
+            merchant_id: &id_type::MerchantId,
+            starting_after: Option<&str>,
+            limit: i64,
+        ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Mandate::find_active_by_merchant_id_without_connector_mandate_id(
+                &conn,
+                merchant_id,
+                starting_after,
+                limit,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn find_pending_mandates_by_merchant_created_before(
+            &self,
+            merchant_id: &id_type::MerchantId,
+            created_before: time::PrimitiveDateTime,
+            limit: i64,
+        ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Mandate::find_pending_by_merchant_id_created_before(
+                &conn,
+                merchant_id,
+                created_before,
+                limit,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn find_mandates_by_merchant_id_original_payment_id(
+            &self,
+            merchant_id: &id_type::MerchantId,
+            original_payment_id: &id_type::PaymentId,
+        ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Mandate::find_by_merchant_id_original_payment_id(
+                &conn,
+                merchant_id,
+                original_payment_id,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn count_active_mandates_by_customer(
+            &self,
+            merchant_id: &id_type::MerchantId,
+            customer_id: &id_type::CustomerId,
+        ) -> CustomResult<i64, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Mandate::get_count_of_active_by_merchant_id_customer_id(
+                &conn,
+                merchant_id,
+                customer_id,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
         #[cfg(feature = "v2")]
         #[instrument(skip_all)]
         async fn find_mandate_by_global_customer_id(
@@ -249,6 +376,10 @@ mod storage {
                     if let diesel_models::MandateUpdate::ConnectorMandateIdUpdate {
                         connector_mandate_id: Some(val),
                         ..
+                    }
+                    | diesel_models::MandateUpdate::ConnectorMandateIdAndStatusUpdate {
+                        connector_mandate_id: Some(val),
+                        ..
                     } = &mandate_update
                     {
                         let rev_lookup = diesel_models::ReverseLookupNew {
@@ -308,9 +439,32 @@ mod storage {
             mandate_constraints: api_models::mandates::MandateListConstraints,
         ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
             let conn = connection::pg_connection_read(self).await?;
-            storage_types::Mandate::filter_by_constraints(&conn, merchant_id, mandate_constraints)
-                .await
-                .map_err(|error| report!(errors::StorageError::from(error)))
+            storage_types::Mandate::filter_by_constraints(
+                &conn,
+                merchant_id,
+                None,
+                mandate_constraints,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn find_mandates_by_merchant_id_customer_id(
+            &self,
+            merchant_id: &id_type::MerchantId,
+            customer_id: &id_type::CustomerId,
+            mandate_constraints: api_models::mandates::MandateListConstraints,
+        ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Mandate::filter_by_constraints(
+                &conn,
+                merchant_id,
+                Some(customer_id),
+                mandate_constraints,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
         }
 
         #[instrument(skip_all)]
@@ -456,6 +610,92 @@ mod storage {
                 .map_err(|error| report!(errors::StorageError::from(error)))
         }
 
+        #[instrument(skip_all)]
+        async fn find_active_mandates_by_merchant_created_before(
+            &self,
+            merchant_id: &id_type::MerchantId,
+            created_before: time::PrimitiveDateTime,
+            limit: i64,
+        ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Mandate::find_active_by_merchant_id_created_before(
+                &conn,
+                merchant_id,
+                created_before,
+                limit,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn find_active_mandates_without_connector_mandate_id(
+            &self,
+            merchant_id: &id_type::MerchantId,
+            starting_after: Option<&str>,
+            limit: i64,
+        ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Mandate::find_active_by_merchant_id_without_connector_mandate_id(
+                &conn,
+                merchant_id,
+                starting_after,
+                limit,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn find_pending_mandates_by_merchant_created_before(
+            &self,
+            merchant_id: &id_type::MerchantId,
+            created_before: time::PrimitiveDateTime,
+            limit: i64,
+        ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Mandate::find_pending_by_merchant_id_created_before(
+                &conn,
+                merchant_id,
+                created_before,
+                limit,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn find_mandates_by_merchant_id_original_payment_id(
+            &self,
+            merchant_id: &id_type::MerchantId,
+            original_payment_id: &id_type::PaymentId,
+        ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Mandate::find_by_merchant_id_original_payment_id(
+                &conn,
+                merchant_id,
+                original_payment_id,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn count_active_mandates_by_customer(
+            &self,
+            merchant_id: &id_type::MerchantId,
+            customer_id: &id_type::CustomerId,
+        ) -> CustomResult<i64, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Mandate::get_count_of_active_by_merchant_id_customer_id(
+                &conn,
+                merchant_id,
+                customer_id,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
         // Need to fix this once we start moving to mandate v2
         #[cfg(feature = "v2")]
         #[instrument(skip_all)]
@@ -496,9 +736,32 @@ mod storage {
             mandate_constraints: api_models::mandates::MandateListConstraints,
         ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
             let conn = connection::pg_connection_read(self).await?;
-            storage_types::Mandate::filter_by_constraints(&conn, merchant_id, mandate_constraints)
-                .await
-                .map_err(|error| report!(errors::StorageError::from(error)))
+            storage_types::Mandate::filter_by_constraints(
+                &conn,
+                merchant_id,
+                None,
+                mandate_constraints,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn find_mandates_by_merchant_id_customer_id(
+            &self,
+            merchant_id: &id_type::MerchantId,
+            customer_id: &id_type::CustomerId,
+            mandate_constraints: api_models::mandates::MandateListConstraints,
+        ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Mandate::filter_by_constraints(
+                &conn,
+                merchant_id,
+                Some(customer_id),
+                mandate_constraints,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
         }
 
         #[instrument(skip_all)]
@@ -570,6 +833,125 @@ impl MandateInterface for MockDb {
             .collect());
     }
 
+    async fn find_active_mandates_by_merchant_created_before(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        created_before: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
+        let mut mandates = self
+            .mandates
+            .lock()
+            .await
+            .iter()
+            .filter(|mandate| {
+                mandate.merchant_id == *merchant_id
+                    && mandate.mandate_status == storage_types::enums::MandateStatus::Active
+                    && mandate.created_at < created_before
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        mandates.sort_by(|a, b| {
+            a.created_at
+                .cmp(&b.created_at)
+                .then_with(|| a.mandate_id.cmp(&b.mandate_id))
+        });
+        mandates.truncate(usize::try_from(limit).unwrap_or(0));
+        Ok(mandates)
+    }
+
+    async fn find_active_mandates_without_connector_mandate_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        starting_after: Option<&str>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
+        let mut mandates = self
+            .mandates
+            .lock()
+            .await
+            .iter()
+            .filter(|mandate| {
+                mandate.merchant_id == *merchant_id
+                    && mandate.mandate_status == storage_types::enums::MandateStatus::Active
+                    && mandate.connector_mandate_id.is_none()
+                    && mandate.mandate_id.as_str() > starting_after.unwrap_or_default()
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        mandates.sort_by(|a, b| a.mandate_id.cmp(&b.mandate_id));
+        mandates.truncate(usize::try_from(limit).unwrap_or(0));
+        Ok(mandates)
+    }
+
+    async fn find_pending_mandates_by_merchant_created_before(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        created_before: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
+        let mut mandates = self
+            .mandates
+            .lock()
+            .await
+            .iter()
+            .filter(|mandate| {
+                mandate.merchant_id == *merchant_id
+                    && mandate.mandate_status == storage_types::enums::MandateStatus::Pending
+                    && mandate.created_at < created_before
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        mandates.sort_by(|a, b| {
+            a.created_at
+                .cmp(&b.created_at)
+                .then_with(|| a.mandate_id.cmp(&b.mandate_id))
+        });
+        mandates.truncate(usize::try_from(limit).unwrap_or(0));
+        Ok(mandates)
+    }
+
+    async fn find_mandates_by_merchant_id_original_payment_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        original_payment_id: &id_type::PaymentId,
+    ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
+        let mut mandates = self
+            .mandates
+            .lock()
+            .await
+            .iter()
+            .filter(|mandate| {
+                mandate.merchant_id == *merchant_id
+                    && mandate.original_payment_id.as_ref() == Some(original_payment_id)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        mandates.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+        Ok(mandates)
+    }
+
+    async fn count_active_mandates_by_customer(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        customer_id: &id_type::CustomerId,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let count = self
+            .mandates
+            .lock()
+            .await
+            .iter()
+            .filter(|mandate| {
+                mandate.merchant_id == *merchant_id
+                    && &mandate.customer_id == customer_id
+                    && mandate.mandate_status == storage_types::enums::MandateStatus::Active
+            })
+            .count();
+        i64::try_from(count)
+            .change_context(errors::StorageError::MockDbError)
+            .attach_printable("Failed to convert the count of active mandates")
+    }
+
     // Need to fix this once we move to v2 mandate
     #[cfg(feature = "v2")]
     async fn find_mandate_by_global_customer_id(
@@ -595,6 +977,7 @@ impl MandateInterface for MockDb {
             Some(mandate) => {
                 let m_update = diesel_models::MandateUpdateInternal::from(mandate_update);
                 let updated_mandate = m_update.clone().apply_changeset(mandate.clone());
+                *mandate = updated_mandate.clone();
                 Ok(updated_mandate)
             }
             None => {
@@ -609,50 +992,27 @@ impl MandateInterface for MockDb {
         mandate_constraints: api_models::mandates::MandateListConstraints,
     ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
         let mandates = self.mandates.lock().await;
-        let mandates_iter = mandates.iter().filter(|mandate| {
-            let mut checker = mandate.merchant_id == *merchant_id;
-            if let Some(created_time) = mandate_constraints.created_time {
-                checker &= mandate.created_at == created_time;
-            }
-            if let Some(created_time_lt) = mandate_constraints.created_time_lt {
-                checker &= mandate.created_at < created_time_lt;
-            }
-            if let Some(created_time_gt) = mandate_constraints.created_time_gt {
-                checker &= mandate.created_at > created_time_gt;
-            }
-            if let Some(created_time_lte) = mandate_constraints.created_time_lte {
-                checker &= mandate.created_at <= created_time_lte;
-            }
-            if let Some(created_time_gte) = mandate_constraints.created_time_gte {
-                checker &= mandate.created_at >= created_time_gte;
-            }
-            if let Some(connector) = &mandate_constraints.connector {
-                checker &= mandate.connector == *connector;
-            }
-            if let Some(mandate_status) = mandate_constraints.mandate_status {
-                checker &= mandate.mandate_status == mandate_status;
-            }
-            checker
-        });
+        Ok(filter_mandates_by_constraints(
+            &mandates,
+            merchant_id,
+            None,
+            mandate_constraints,
+        ))
+    }
 
-        #[allow(clippy::as_conversions)]
-        let offset = (if mandate_constraints.offset.unwrap_or(0) < 0 {
-            0
-        } else {
-            mandate_constraints.offset.unwrap_or(0)
-        }) as usize;
-
-        let mandates: Vec<storage_types::Mandate> = if let Some(limit) = mandate_constraints.limit {
-            #[allow(clippy::as_conversions)]
-            mandates_iter
-                .skip(offset)
-                .take((if limit < 0 { 0 } else { limit }) as usize)
-                .cloned()
-                .collect()
-        } else {
-            mandates_iter.skip(offset).cloned().collect()
-        };
-        Ok(mandates)
+    async fn find_mandates_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        customer_id: &id_type::CustomerId,
+        mandate_constraints: api_models::mandates::MandateListConstraints,
+    ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
+        let mandates = self.mandates.lock().await;
+        Ok(filter_mandates_by_constraints(
+            &mandates,
+            merchant_id,
+            Some(customer_id),
+            mandate_constraints,
+        ))
     }
 
     async fn insert_mandate(
@@ -688,8 +1048,89 @@ impl MandateInterface for MockDb {
             connector_mandate_ids: mandate_new.connector_mandate_ids,
             merchant_connector_id: mandate_new.merchant_connector_id,
             updated_by: mandate_new.updated_by,
+            frequency: mandate_new.frequency,
+            last_charged_at: mandate_new.last_charged_at,
+            modified_at: mandate_new
+                .modified_at
+                .unwrap_or_else(common_utils::date_time::now),
+            merchant_metadata: mandate_new.merchant_metadata,
         };
         mandates.push(mandate.clone());
         Ok(mandate)
     }
 }
+
+fn filter_mandates_by_constraints(
+    mandates: &[storage_types::Mandate],
+    merchant_id: &id_type::MerchantId,
+    customer_id: Option<&id_type::CustomerId>,
+    mandate_constraints: api_models::mandates::MandateListConstraints,
+) -> Vec<storage_types::Mandate> {
+    let mandates_iter = mandates.iter().filter(|mandate| {
+        let mut checker = mandate.merchant_id == *merchant_id;
+        if let Some(customer_id) = customer_id {
+            checker &= mandate.customer_id == *customer_id;
+        }
+        if let Some(created_time) = mandate_constraints.created_time {
+            checker &= mandate.created_at == created_time;
+        }
+        if let Some(created_time_lt) = mandate_constraints.created_time_lt {
+            checker &= mandate.created_at < created_time_lt;
+        }
+        if let Some(created_time_gt) = mandate_constraints.created_time_gt {
+            checker &= mandate.created_at > created_time_gt;
+        }
+        if let Some(created_time_lte) = mandate_constraints.created_time_lte {
+            checker &= mandate.created_at <= created_time_lte;
+        }
+        if let Some(created_time_gte) = mandate_constraints.created_time_gte {
+            checker &= mandate.created_at >= created_time_gte;
+        }
+        if let Some(modified_time_lte) = mandate_constraints.modified_time_lte {
+            checker &= mandate.modified_at <= modified_time_lte;
+        }
+        if let Some(modified_time_gte) = mandate_constraints.modified_time_gte {
+            checker &= mandate.modified_at >= modified_time_gte;
+        }
+        if let Some(connector) = &mandate_constraints.connector {
+            checker &= mandate.connector == *connector;
+        }
+        if let Some(mandate_status) = mandate_constraints.mandate_status {
+            checker &= mandate.mandate_status == mandate_status;
+        }
+        checker
+    });
+
+    let mut filtered_mandates = mandates_iter.collect::<Vec<_>>();
+    let sort_by = mandate_constraints.sort_by.unwrap_or_default();
+    let sort_order = mandate_constraints.sort_order.clone().unwrap_or_default();
+    filtered_mandates.sort_by(|a, b| {
+        let ordering = match sort_by {
+            api_models::mandates::MandateSortBy::CreatedAt => a.created_at.cmp(&b.created_at),
+            api_models::mandates::MandateSortBy::ModifiedAt => a.modified_at.cmp(&b.modified_at),
+        };
+        match sort_order {
+            api_models::payments::SortBy::Asc => ordering,
+            api_models::payments::SortBy::Desc => ordering.reverse(),
+        }
+    });
+    let mandates_iter = filtered_mandates.into_iter();
+
+    #[allow(clippy::as_conversions)]
+    let offset = (if mandate_constraints.offset.unwrap_or(0) < 0 {
+        0
+    } else {
+        mandate_constraints.offset.unwrap_or(0)
+    }) as usize;
+
+    if let Some(limit) = mandate_constraints.limit {
+        #[allow(clippy::as_conversions)]
+        mandates_iter
+            .skip(offset)
+            .take((if limit < 0 { 0 } else { limit }) as usize)
+            .cloned()
+            .collect()
+    } else {
+        mandates_iter.skip(offset).cloned().collect()
+    }
+}
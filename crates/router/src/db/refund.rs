@@ -33,6 +33,15 @@ pub trait RefundInterface {
         storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<diesel_refund::Refund>, errors::StorageError>;
 
+    #[cfg(feature = "v1")]
+    async fn find_refunds_by_merchant_id_profile_id_created_after(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        created_after: time::PrimitiveDateTime,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<diesel_refund::Refund>, errors::StorageError>;
+
     #[cfg(feature = "v1")]
     async fn find_refund_by_merchant_id_refund_id(
         &self,
@@ -105,6 +114,34 @@ pub trait RefundInterface {
         storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<api_models::refunds::RefundListMetaData, errors::StorageError>;
 
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn find_refunds_without_merchant_connector_id_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        starting_after: Option<&str>,
+        limit: i64,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<diesel_refund::Refund>, errors::StorageError>;
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn find_stale_pending_refunds_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        modified_before: time::PrimitiveDateTime,
+        limit: i64,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<diesel_refund::Refund>, errors::StorageError>;
+
+    #[cfg(feature = "olap")]
+    async fn list_refunds_modified_since(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        since: time::PrimitiveDateTime,
+        limit: i64,
+        cursor: Option<&str>,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<diesel_refund::Refund>, errors::StorageError>;
+
     #[cfg(all(feature = "v1", feature = "olap"))]
     async fn get_refund_status_with_count(
         &self,
@@ -114,6 +151,26 @@ pub trait RefundInterface {
         storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<(common_enums::RefundStatus, i64)>, errors::StorageError>;
 
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn get_refund_status_counts(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
+        time_range: &common_utils::types::TimeRange,
+        group_by: api_models::refunds::RefundStatusCountGroupBy,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<crate::types::storage::RefundStatusCountRow>, errors::StorageError>;
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn find_inconsistent_refunds(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        older_than: time::PrimitiveDateTime,
+        rules: &api_models::refunds::RefundReconciliationRules,
+        limit: i64,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<diesel_refund::Refund>, errors::StorageError>;
+
     #[cfg(all(feature = "v1", feature = "olap"))]
     async fn get_total_count_of_refunds(
         &self,
@@ -282,6 +339,26 @@ mod storage {
                 .map_err(|error| report!(errors::StorageError::from(error)))
         }
 
+        #[cfg(feature = "v1")]
+        #[instrument(skip_all)]
+        async fn find_refunds_by_merchant_id_profile_id_created_after(
+            &self,
+            merchant_id: &common_utils::id_type::MerchantId,
+            profile_id: &common_utils::id_type::ProfileId,
+            created_after: time::PrimitiveDateTime,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<diesel_refund::Refund>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            diesel_refund::Refund::find_by_merchant_id_profile_id_created_after(
+                &conn,
+                merchant_id,
+                profile_id,
+                created_after,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
         #[cfg(all(feature = "v1", feature = "olap"))]
         #[instrument(skip_all)]
         async fn filter_refund_by_constraints(
@@ -326,6 +403,68 @@ mod storage {
             .map_err(|error| report!(errors::StorageError::from(error)))
         }
 
+        #[cfg(all(feature = "v1", feature = "olap"))]
+        #[instrument(skip_all)]
+        async fn find_refunds_without_merchant_connector_id_by_merchant_id(
+            &self,
+            merchant_id: &common_utils::id_type::MerchantId,
+            starting_after: Option<&str>,
+            limit: i64,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<diesel_refund::Refund>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            diesel_refund::Refund::find_by_merchant_id_without_merchant_connector_id(
+                &conn,
+                merchant_id,
+                starting_after,
+                limit,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[cfg(all(feature = "v1", feature = "olap"))]
+        #[instrument(skip_all)]
+        async fn find_stale_pending_refunds_by_merchant_id(
+            &self,
+            merchant_id: &common_utils::id_type::MerchantId,
+            modified_before: time::PrimitiveDateTime,
+            limit: i64,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<diesel_refund::Refund>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            diesel_refund::Refund::find_stale_pending_by_merchant_id(
+                &conn,
+                merchant_id,
+                modified_before,
+                limit,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[cfg(feature = "olap")]
+        #[instrument(skip_all)]
+        async fn list_refunds_modified_since(
+            &self,
+            merchant_id: &common_utils::id_type::MerchantId,
+            since: time::PrimitiveDateTime,
+            limit: i64,
+            cursor: Option<&str>,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<diesel_refund::Refund>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            diesel_refund::Refund::find_by_merchant_id_modified_since(
+                &conn,
+                merchant_id,
+                since,
+                cursor,
+                limit,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
         #[cfg(all(feature = "v1", feature = "olap"))]
         #[instrument(skip_all)]
         async fn filter_refund_by_meta_constraints(
@@ -359,6 +498,50 @@ mod storage {
             .map_err(|error|report!(errors::StorageError::from(error)))
         }
 
+        #[cfg(all(feature = "v1", feature = "olap"))]
+        #[instrument(skip_all)]
+        async fn get_refund_status_counts(
+            &self,
+            merchant_id: &common_utils::id_type::MerchantId,
+            profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
+            time_range: &common_utils::types::TimeRange,
+            group_by: api_models::refunds::RefundStatusCountGroupBy,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<storage_types::RefundStatusCountRow>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            <diesel_models::refund::Refund as storage_types::RefundDbExt>::get_refund_status_counts(
+                &conn,
+                merchant_id,
+                profile_id_list,
+                time_range,
+                group_by,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[cfg(all(feature = "v1", feature = "olap"))]
+        #[instrument(skip_all)]
+        async fn find_inconsistent_refunds(
+            &self,
+            merchant_id: &common_utils::id_type::MerchantId,
+            older_than: time::PrimitiveDateTime,
+            rules: &api_models::refunds::RefundReconciliationRules,
+            limit: i64,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<diesel_refund::Refund>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            <diesel_models::refund::Refund as storage_types::RefundDbExt>::find_inconsistent_refunds(
+                &conn,
+                merchant_id,
+                older_than,
+                rules,
+                limit,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
         #[cfg(all(feature = "v1", feature = "olap"))]
         #[instrument(skip_all)]
         async fn get_total_count_of_refunds(
@@ -545,6 +728,7 @@ mod storage {
                         processor_transaction_data: new.processor_transaction_data.clone(),
                         issuer_error_code: None,
                         issuer_error_message: None,
+                        origin: new.origin,
                         // Below fields are deprecated. Please add any new fields above this line.
                         connector_refund_data: None,
                         connector_transaction_data: None,
@@ -961,6 +1145,26 @@ mod storage {
             }
         }
 
+        #[cfg(feature = "v1")]
+        #[instrument(skip_all)]
+        async fn find_refunds_by_merchant_id_profile_id_created_after(
+            &self,
+            merchant_id: &common_utils::id_type::MerchantId,
+            profile_id: &common_utils::id_type::ProfileId,
+            created_after: time::PrimitiveDateTime,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<diesel_refund::Refund>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            diesel_refund::Refund::find_by_merchant_id_profile_id_created_after(
+                &conn,
+                merchant_id,
+                profile_id,
+                created_after,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
         #[cfg(feature = "v2")]
         #[instrument(skip_all)]
         async fn find_refund_by_id(
@@ -1018,6 +1222,68 @@ mod storage {
             .map_err(|error| report!(errors::StorageError::from(error)))
         }
 
+        #[cfg(all(feature = "v1", feature = "olap"))]
+        #[instrument(skip_all)]
+        async fn find_refunds_without_merchant_connector_id_by_merchant_id(
+            &self,
+            merchant_id: &common_utils::id_type::MerchantId,
+            starting_after: Option<&str>,
+            limit: i64,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<diesel_refund::Refund>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            diesel_refund::Refund::find_by_merchant_id_without_merchant_connector_id(
+                &conn,
+                merchant_id,
+                starting_after,
+                limit,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[cfg(all(feature = "v1", feature = "olap"))]
+        #[instrument(skip_all)]
+        async fn find_stale_pending_refunds_by_merchant_id(
+            &self,
+            merchant_id: &common_utils::id_type::MerchantId,
+            modified_before: time::PrimitiveDateTime,
+            limit: i64,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<diesel_refund::Refund>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            diesel_refund::Refund::find_stale_pending_by_merchant_id(
+                &conn,
+                merchant_id,
+                modified_before,
+                limit,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[cfg(feature = "olap")]
+        #[instrument(skip_all)]
+        async fn list_refunds_modified_since(
+            &self,
+            merchant_id: &common_utils::id_type::MerchantId,
+            since: time::PrimitiveDateTime,
+            limit: i64,
+            cursor: Option<&str>,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<diesel_refund::Refund>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            diesel_refund::Refund::find_by_merchant_id_modified_since(
+                &conn,
+                merchant_id,
+                since,
+                cursor,
+                limit,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
         #[cfg(all(feature = "v1", feature = "olap"))]
         #[instrument(skip_all)]
         async fn filter_refund_by_meta_constraints(
@@ -1047,6 +1313,50 @@ mod storage {
             .map_err(|error| report!(errors::StorageError::from(error)))
         }
 
+        #[cfg(all(feature = "v1", feature = "olap"))]
+        #[instrument(skip_all)]
+        async fn get_refund_status_counts(
+            &self,
+            merchant_id: &common_utils::id_type::MerchantId,
+            profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
+            time_range: &common_utils::types::TimeRange,
+            group_by: api_models::refunds::RefundStatusCountGroupBy,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<storage_types::RefundStatusCountRow>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            <diesel_models::refund::Refund as storage_types::RefundDbExt>::get_refund_status_counts(
+                &conn,
+                merchant_id,
+                profile_id_list,
+                time_range,
+                group_by,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[cfg(all(feature = "v1", feature = "olap"))]
+        #[instrument(skip_all)]
+        async fn find_inconsistent_refunds(
+            &self,
+            merchant_id: &common_utils::id_type::MerchantId,
+            older_than: time::PrimitiveDateTime,
+            rules: &api_models::refunds::RefundReconciliationRules,
+            limit: i64,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<diesel_refund::Refund>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            <diesel_models::refund::Refund as storage_types::RefundDbExt>::find_inconsistent_refunds(
+                &conn,
+                merchant_id,
+                older_than,
+                rules,
+                limit,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
         #[cfg(all(feature = "v1", feature = "olap"))]
         #[instrument(skip_all)]
         async fn get_total_count_of_refunds(
@@ -1114,6 +1424,14 @@ impl RefundInterface for MockDb {
         _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<diesel_refund::Refund, errors::StorageError> {
         let mut refunds = self.refunds.lock().await;
+        if refunds.iter().any(|refund| {
+            refund.merchant_id == new.merchant_id && refund.refund_id == new.refund_id
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "refund_id",
+                key: Some(new.refund_id.clone()),
+            })?
+        }
         let current_time = common_utils::date_time::now();
 
         let refund = diesel_refund::Refund {
@@ -1152,6 +1470,7 @@ impl RefundInterface for MockDb {
             processor_transaction_data: new.processor_transaction_data.clone(),
             issuer_error_code: None,
             issuer_error_message: None,
+            origin: new.origin,
             // Below fields are deprecated. Please add any new fields above this line.
             connector_refund_data: None,
             connector_transaction_data: None,
@@ -1203,6 +1522,9 @@ impl RefundInterface for MockDb {
             unified_message: None,
             processor_refund_data: new.processor_refund_data.clone(),
             processor_transaction_data: new.processor_transaction_data.clone(),
+            origin: new.origin,
+            issuer_error_code: None,
+            issuer_error_message: None,
         };
         refunds.push(refund.clone());
         Ok(refund)
@@ -1240,7 +1562,8 @@ impl RefundInterface for MockDb {
             .find(|refund| this.refund_id == refund.refund_id)
             .map(|r| {
                 let refund_updated =
-                    diesel_refund::RefundUpdateInternal::from(refund).create_refund(r.clone());
+                    diesel_refund::RefundUpdateInternal::from(refund.with_merged_metadata(r))
+                        .create_refund(r.clone());
                 *r = refund_updated.clone();
                 refund_updated
             })
@@ -1264,7 +1587,8 @@ impl RefundInterface for MockDb {
             .find(|refund| this.merchant_reference_id == refund.merchant_reference_id)
             .map(|r| {
                 let refund_updated =
-                    diesel_refund::RefundUpdateInternal::from(refund).create_refund(r.clone());
+                    diesel_refund::RefundUpdateInternal::from(refund.with_merged_metadata(r))
+                        .create_refund(r.clone());
                 *r = refund_updated.clone();
                 refund_updated
             })
@@ -1305,10 +1629,12 @@ impl RefundInterface for MockDb {
         refunds
             .iter()
             .find(|refund| {
+                // Compared against the stored id, which is hashed for long ids, as in the database
                 refund.merchant_id == *merchant_id
                     && refund
-                        .get_optional_connector_refund_id()
-                        .map(|refund_id| refund_id.as_str())
+                        .connector_refund_id
+                        .as_ref()
+                        .map(|refund_id| refund_id.get_id().as_str())
                         == Some(connector_refund_id)
                     && refund.connector == connector
             })
@@ -1334,6 +1660,27 @@ impl RefundInterface for MockDb {
             .collect::<Vec<_>>())
     }
 
+    #[cfg(feature = "v1")]
+    async fn find_refunds_by_merchant_id_profile_id_created_after(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        created_after: time::PrimitiveDateTime,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<diesel_refund::Refund>, errors::StorageError> {
+        let refunds = self.refunds.lock().await;
+
+        Ok(refunds
+            .iter()
+            .filter(|refund| {
+                refund.merchant_id == *merchant_id
+                    && refund.profile_id.as_ref() == Some(profile_id)
+                    && refund.created_at >= created_after
+            })
+            .cloned()
+            .collect::<Vec<_>>())
+    }
+
     #[cfg(feature = "v2")]
     async fn find_refund_by_id(
         &self,
@@ -1398,7 +1745,7 @@ impl RefundInterface for MockDb {
         }
 
         let refunds = self.refunds.lock().await;
-        let filtered_refunds = refunds
+        let mut filtered_refunds = refunds
             .iter()
             .filter(|refund| refund.merchant_id == *merchant_id)
             .filter(|refund| {
@@ -1458,12 +1805,63 @@ impl RefundInterface for MockDb {
             .filter(|refund| {
                 unique_statuses.is_empty() || unique_statuses.contains(&refund.refund_status)
             })
-            .skip(usize::try_from(offset).unwrap_or_default())
-            .take(usize::try_from(limit).unwrap_or(MAX_LIMIT))
+            .filter(|refund| {
+                refund_details
+                    .origin
+                    .as_ref()
+                    .map_or(true, |origins| origins.contains(&refund.origin))
+            })
+            .filter(|refund| {
+                refund_details.unified_code.as_ref().map_or(true, |codes| {
+                    refund
+                        .unified_code
+                        .as_ref()
+                        .is_some_and(|code| codes.contains(code))
+                })
+            })
+            .filter(|refund| {
+                refund_details
+                    .issuer_error_code
+                    .as_ref()
+                    .map_or(true, |codes| {
+                        refund
+                            .issuer_error_code
+                            .as_ref()
+                            .is_some_and(|code| codes.contains(code))
+                    })
+            })
+            .filter(|refund| {
+                refund_details
+                    .has_unified_error
+                    .map_or(true, |has_unified_error| {
+                        refund.unified_code.is_some() == has_unified_error
+                    })
+            })
+            .filter(|refund| {
+                refund_details
+                    .starting_after
+                    .as_ref()
+                    .map_or(true, |cursor| {
+                        (refund.created_at, refund.refund_id.as_str())
+                            < (cursor.created_at, cursor.refund_id.as_str())
+                    })
+            })
             .cloned()
             .collect::<Vec<_>>();
+        filtered_refunds.sort_by(|refund, other| {
+            (other.created_at, &other.refund_id).cmp(&(refund.created_at, &refund.refund_id))
+        });
+        let offset = if refund_details.starting_after.is_some() {
+            0
+        } else {
+            offset
+        };
 
-        Ok(filtered_refunds)
+        Ok(filtered_refunds
+            .into_iter()
+            .skip(usize::try_from(offset).unwrap_or_default())
+            .take(usize::try_from(limit).unwrap_or(MAX_LIMIT))
+            .collect())
     }
 
     #[cfg(all(feature = "v2", feature = "olap"))]
@@ -1567,6 +1965,12 @@ impl RefundInterface for MockDb {
             .filter(|refund| {
                 unique_statuses.is_empty() || unique_statuses.contains(&refund.refund_status)
             })
+            .filter(|refund| {
+                refund_details
+                    .origin
+                    .as_ref()
+                    .map_or(true, |origins| origins.contains(&refund.origin))
+            })
             .skip(usize::try_from(offset).unwrap_or_default())
             .take(usize::try_from(limit).unwrap_or(MAX_LIMIT))
             .cloned()
@@ -1575,6 +1979,110 @@ impl RefundInterface for MockDb {
         Ok(filtered_refunds)
     }
 
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn find_refunds_without_merchant_connector_id_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        starting_after: Option<&str>,
+        limit: i64,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<diesel_refund::Refund>, errors::StorageError> {
+        let refunds = self.refunds.lock().await;
+        let mut matching_refunds = refunds
+            .iter()
+            .filter(|refund| {
+                refund.merchant_id == *merchant_id
+                    && refund.merchant_connector_id.is_none()
+                    && starting_after
+                        .map_or(true, |refund_id| refund.refund_id.as_str() > refund_id)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        matching_refunds.sort_by(|a, b| a.refund_id.cmp(&b.refund_id));
+        matching_refunds.truncate(usize::try_from(limit).unwrap_or_default());
+
+        Ok(matching_refunds)
+    }
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn find_stale_pending_refunds_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        modified_before: time::PrimitiveDateTime,
+        limit: i64,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<diesel_refund::Refund>, errors::StorageError> {
+        let refunds = self.refunds.lock().await;
+        let mut stale_refunds = refunds
+            .iter()
+            .filter(|refund| {
+                refund.merchant_id == *merchant_id
+                    && refund.refund_status == enums::RefundStatus::Pending
+                    && refund.sent_to_gateway
+                    && refund.modified_at < modified_before
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        stale_refunds
+            .sort_by(|a, b| (a.modified_at, &a.refund_id).cmp(&(b.modified_at, &b.refund_id)));
+        stale_refunds.truncate(usize::try_from(limit).unwrap_or_default());
+
+        Ok(stale_refunds)
+    }
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn list_refunds_modified_since(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        since: time::PrimitiveDateTime,
+        limit: i64,
+        cursor: Option<&str>,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<diesel_refund::Refund>, errors::StorageError> {
+        let refunds = self.refunds.lock().await;
+        let starting_after = (since, cursor.unwrap_or_default());
+        let mut matching_refunds = refunds
+            .iter()
+            .filter(|refund| {
+                refund.merchant_id == *merchant_id
+                    && (refund.modified_at, refund.refund_id.as_str()) > starting_after
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        matching_refunds
+            .sort_by(|a, b| (a.modified_at, &a.refund_id).cmp(&(b.modified_at, &b.refund_id)));
+        matching_refunds.truncate(usize::try_from(limit).unwrap_or_default());
+
+        Ok(matching_refunds)
+    }
+
+    #[cfg(all(feature = "v2", feature = "olap"))]
+    async fn list_refunds_modified_since(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        since: time::PrimitiveDateTime,
+        limit: i64,
+        cursor: Option<&str>,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<diesel_refund::Refund>, errors::StorageError> {
+        let refunds = self.refunds.lock().await;
+        let starting_after = (since, cursor.unwrap_or_default());
+        let mut matching_refunds = refunds
+            .iter()
+            .filter(|refund| {
+                refund.merchant_id == *merchant_id
+                    && (refund.modified_at, refund.id.get_string_repr()) > starting_after
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        matching_refunds.sort_by(|a, b| {
+            (a.modified_at, a.id.get_string_repr()).cmp(&(b.modified_at, b.id.get_string_repr()))
+        });
+        matching_refunds.truncate(usize::try_from(limit).unwrap_or_default());
+
+        Ok(matching_refunds)
+    }
+
     #[cfg(all(feature = "v1", feature = "olap"))]
     async fn filter_refund_by_meta_constraints(
         &self,
@@ -1668,6 +2176,77 @@ impl RefundInterface for MockDb {
         Ok(result)
     }
 
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn get_refund_status_counts(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
+        time_range: &common_utils::types::TimeRange,
+        group_by: api_models::refunds::RefundStatusCountGroupBy,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<crate::types::storage::RefundStatusCountRow>, errors::StorageError> {
+        let refunds = self.refunds.lock().await;
+
+        let start_time = time_range.start_time;
+        let end_time = time_range
+            .end_time
+            .unwrap_or_else(common_utils::date_time::now);
+
+        let status_counts = refunds
+            .iter()
+            .filter(|refund| {
+                refund.merchant_id == *merchant_id
+                    && refund.created_at >= start_time
+                    && refund.created_at <= end_time
+                    && profile_id_list
+                        .as_ref()
+                        .zip(refund.profile_id.as_ref())
+                        .map(|(received_profile_list, received_profile_id)| {
+                            received_profile_list.contains(received_profile_id)
+                        })
+                        .unwrap_or(true)
+            })
+            .map(|refund| {
+                (
+                    refund.connector.clone(),
+                    refund.currency,
+                    refund.refund_status,
+                    1,
+                )
+            });
+
+        Ok(crate::types::storage::group_refund_status_counts(
+            status_counts,
+            group_by,
+        ))
+    }
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn find_inconsistent_refunds(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        older_than: time::PrimitiveDateTime,
+        rules: &api_models::refunds::RefundReconciliationRules,
+        limit: i64,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<diesel_refund::Refund>, errors::StorageError> {
+        let refunds = self.refunds.lock().await;
+
+        let mut inconsistent_refunds = refunds
+            .iter()
+            .filter(|refund| {
+                refund.merchant_id == *merchant_id
+                    && refund.created_at <= older_than
+                    && crate::types::storage::get_refund_mismatch_rule(refund, rules).is_some()
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        inconsistent_refunds.sort_by_key(|refund| refund.created_at);
+        inconsistent_refunds.truncate(usize::try_from(limit).unwrap_or_default());
+
+        Ok(inconsistent_refunds)
+    }
+
     #[cfg(all(feature = "v1", feature = "olap"))]
     async fn get_total_count_of_refunds(
         &self,
@@ -1773,6 +2352,38 @@ impl RefundInterface for MockDb {
             .filter(|refund| {
                 unique_statuses.is_empty() || unique_statuses.contains(&refund.refund_status)
             })
+            .filter(|refund| {
+                refund_details
+                    .origin
+                    .as_ref()
+                    .map_or(true, |origins| origins.contains(&refund.origin))
+            })
+            .filter(|refund| {
+                refund_details.unified_code.as_ref().map_or(true, |codes| {
+                    refund
+                        .unified_code
+                        .as_ref()
+                        .is_some_and(|code| codes.contains(code))
+                })
+            })
+            .filter(|refund| {
+                refund_details
+                    .issuer_error_code
+                    .as_ref()
+                    .map_or(true, |codes| {
+                        refund
+                            .issuer_error_code
+                            .as_ref()
+                            .is_some_and(|code| codes.contains(code))
+                    })
+            })
+            .filter(|refund| {
+                refund_details
+                    .has_unified_error
+                    .map_or(true, |has_unified_error| {
+                        refund.unified_code.is_some() == has_unified_error
+                    })
+            })
             .cloned()
             .collect::<Vec<_>>();
 
@@ -1880,6 +2491,12 @@ impl RefundInterface for MockDb {
             .filter(|refund| {
                 unique_statuses.is_empty() || unique_statuses.contains(&refund.refund_status)
             })
+            .filter(|refund| {
+                refund_details
+                    .origin
+                    .as_ref()
+                    .map_or(true, |origins| origins.contains(&refund.origin))
+            })
             .cloned()
             .collect::<Vec<_>>();
 
@@ -0,0 +1,234 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait RefundBatchInterface {
+    async fn insert_refund_batch(
+        &self,
+        refund_batch: storage::RefundBatchNew,
+    ) -> CustomResult<storage::RefundBatch, errors::StorageError>;
+
+    async fn find_refund_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::RefundBatch, errors::StorageError>;
+
+    async fn update_refund_batch(
+        &self,
+        this: storage::RefundBatch,
+        refund_batch_update: storage::RefundBatchUpdate,
+    ) -> CustomResult<storage::RefundBatch, errors::StorageError>;
+
+    async fn insert_refund_batch_entries(
+        &self,
+        entries: Vec<storage::RefundBatchEntryNew>,
+    ) -> CustomResult<Vec<storage::RefundBatchEntry>, errors::StorageError>;
+
+    async fn find_refund_batch_entries_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<Vec<storage::RefundBatchEntry>, errors::StorageError>;
+
+    async fn update_refund_batch_entry(
+        &self,
+        this: storage::RefundBatchEntry,
+        refund_batch_entry_update: storage::RefundBatchEntryUpdate,
+    ) -> CustomResult<storage::RefundBatchEntry, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl RefundBatchInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_refund_batch(
+        &self,
+        refund_batch: storage::RefundBatchNew,
+    ) -> CustomResult<storage::RefundBatch, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        refund_batch
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_refund_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::RefundBatch, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::RefundBatch::find_by_merchant_id_batch_id(&conn, merchant_id, batch_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_refund_batch(
+        &self,
+        this: storage::RefundBatch,
+        refund_batch_update: storage::RefundBatchUpdate,
+    ) -> CustomResult<storage::RefundBatch, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, refund_batch_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_refund_batch_entries(
+        &self,
+        entries: Vec<storage::RefundBatchEntryNew>,
+    ) -> CustomResult<Vec<storage::RefundBatchEntry>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::RefundBatchEntry::insert_batch(&conn, entries)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_refund_batch_entries_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<Vec<storage::RefundBatchEntry>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::RefundBatchEntry::find_by_merchant_id_batch_id(&conn, merchant_id, batch_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_refund_batch_entry(
+        &self,
+        this: storage::RefundBatchEntry,
+        refund_batch_entry_update: storage::RefundBatchEntryUpdate,
+    ) -> CustomResult<storage::RefundBatchEntry, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, refund_batch_entry_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl RefundBatchInterface for MockDb {
+    async fn insert_refund_batch(
+        &self,
+        _refund_batch: storage::RefundBatchNew,
+    ) -> CustomResult<storage::RefundBatch, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_refund_batch_by_merchant_id_batch_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _batch_id: &str,
+    ) -> CustomResult<storage::RefundBatch, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_refund_batch(
+        &self,
+        _this: storage::RefundBatch,
+        _refund_batch_update: storage::RefundBatchUpdate,
+    ) -> CustomResult<storage::RefundBatch, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_refund_batch_entries(
+        &self,
+        _entries: Vec<storage::RefundBatchEntryNew>,
+    ) -> CustomResult<Vec<storage::RefundBatchEntry>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_refund_batch_entries_by_merchant_id_batch_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _batch_id: &str,
+    ) -> CustomResult<Vec<storage::RefundBatchEntry>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_refund_batch_entry(
+        &self,
+        _this: storage::RefundBatchEntry,
+        _refund_batch_entry_update: storage::RefundBatchEntryUpdate,
+    ) -> CustomResult<storage::RefundBatchEntry, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl RefundBatchInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_refund_batch(
+        &self,
+        refund_batch: storage::RefundBatchNew,
+    ) -> CustomResult<storage::RefundBatch, errors::StorageError> {
+        self.diesel_store.insert_refund_batch(refund_batch).await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_refund_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::RefundBatch, errors::StorageError> {
+        self.diesel_store
+            .find_refund_batch_by_merchant_id_batch_id(merchant_id, batch_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_refund_batch(
+        &self,
+        this: storage::RefundBatch,
+        refund_batch_update: storage::RefundBatchUpdate,
+    ) -> CustomResult<storage::RefundBatch, errors::StorageError> {
+        self.diesel_store
+            .update_refund_batch(this, refund_batch_update)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_refund_batch_entries(
+        &self,
+        entries: Vec<storage::RefundBatchEntryNew>,
+    ) -> CustomResult<Vec<storage::RefundBatchEntry>, errors::StorageError> {
+        self.diesel_store.insert_refund_batch_entries(entries).await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_refund_batch_entries_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<Vec<storage::RefundBatchEntry>, errors::StorageError> {
+        self.diesel_store
+            .find_refund_batch_entries_by_merchant_id_batch_id(merchant_id, batch_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_refund_batch_entry(
+        &self,
+        this: storage::RefundBatchEntry,
+        refund_batch_entry_update: storage::RefundBatchEntryUpdate,
+    ) -> CustomResult<storage::RefundBatchEntry, errors::StorageError> {
+        self.diesel_store
+            .update_refund_batch_entry(this, refund_batch_entry_update)
+            .await
+    }
+}
@@ -19,6 +19,7 @@ use crate::{
 pub mod api_logs;
 pub mod audit_events;
 pub mod connector_api_logs;
+pub mod domain_events;
 pub mod event_logger;
 pub mod outgoing_webhook_logs;
 pub mod routing_api_logs;
@@ -39,6 +40,7 @@ pub enum EventType {
     Consolidated,
     Authentication,
     RoutingApiLogs,
+    DomainEvent,
 }
 
 #[derive(Debug, Default, Deserialize, Clone)]
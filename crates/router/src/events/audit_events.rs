@@ -39,6 +39,12 @@ pub enum AuditEventType {
         error_code: Option<String>,
         error_message: Option<String>,
     },
+    MandateForceRevoked {
+        mandate_id: String,
+        connector: String,
+        error_code: String,
+        error_message: String,
+    },
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -83,6 +89,7 @@ impl Event for AuditEvent {
             AuditEventType::PaymentStatus => "payment_status",
             AuditEventType::PaymentCompleteAuthorize => "payment_complete_authorize",
             AuditEventType::PaymentReject { .. } => "payment_rejected",
+            AuditEventType::MandateForceRevoked { .. } => "mandate_force_revoked",
         };
         format!(
             "{event_type}-{}",
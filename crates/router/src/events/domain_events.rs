@@ -0,0 +1,311 @@
+use common_utils::id_type;
+#[cfg(feature = "v1")]
+use common_utils::types::MinorUnit;
+#[cfg(feature = "v1")]
+use diesel_models::refund::Refund;
+use diesel_models::{enums as storage_enums, mandate::Mandate};
+use serde::Serialize;
+use time::{OffsetDateTime, PrimitiveDateTime};
+
+use super::EventType;
+use crate::{
+    routes::{app::SessionStateInfo, SessionState},
+    services::kafka::KafkaMessage,
+};
+
+/// Typed events describing the lifecycle of refunds and mandates, for analytics
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "event_type", rename_all = "snake_case")]
+pub enum DomainEventType {
+    #[cfg(feature = "v1")]
+    RefundCreated {
+        refund_id: String,
+        payment_id: id_type::PaymentId,
+        connector: String,
+        refund_amount: MinorUnit,
+        currency: storage_enums::Currency,
+        refund_status: storage_enums::RefundStatus,
+    },
+    #[cfg(feature = "v1")]
+    RefundStatusChanged {
+        refund_id: String,
+        payment_id: id_type::PaymentId,
+        connector: String,
+        old_status: storage_enums::RefundStatus,
+        new_status: storage_enums::RefundStatus,
+        /// Time elapsed between the creation of the refund and the status change
+        latency_in_secs: i64,
+    },
+    MandateCreated {
+        mandate_id: String,
+        customer_id: id_type::CustomerId,
+        connector: String,
+        mandate_type: storage_enums::MandateType,
+        mandate_status: storage_enums::MandateStatus,
+    },
+    MandateRevoked {
+        mandate_id: String,
+        customer_id: id_type::CustomerId,
+        connector: String,
+        old_status: storage_enums::MandateStatus,
+        /// Time elapsed between the creation of the mandate and its revocation
+        lifetime_in_secs: i64,
+    },
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DomainEvent {
+    merchant_id: id_type::MerchantId,
+    #[serde(flatten)]
+    event_type: DomainEventType,
+    created_at: i128,
+}
+
+impl DomainEvent {
+    fn new(merchant_id: id_type::MerchantId, event_type: DomainEventType) -> Self {
+        Self {
+            merchant_id,
+            event_type,
+            created_at: OffsetDateTime::now_utc().unix_timestamp_nanos(),
+        }
+    }
+
+    #[cfg(feature = "v1")]
+    pub fn refund_created(refund: &Refund) -> Self {
+        Self::new(
+            refund.merchant_id.clone(),
+            DomainEventType::RefundCreated {
+                refund_id: refund.refund_id.clone(),
+                payment_id: refund.payment_id.clone(),
+                connector: refund.connector.clone(),
+                refund_amount: refund.refund_amount,
+                currency: refund.currency,
+                refund_status: refund.refund_status,
+            },
+        )
+    }
+
+    /// Constructs the status change event of a refund, if the update changed its status
+    #[cfg(feature = "v1")]
+    pub fn refund_status_changed(old_refund: &Refund, new_refund: &Refund) -> Option<Self> {
+        (old_refund.refund_status != new_refund.refund_status).then(|| {
+            Self::new(
+                new_refund.merchant_id.clone(),
+                DomainEventType::RefundStatusChanged {
+                    refund_id: new_refund.refund_id.clone(),
+                    payment_id: new_refund.payment_id.clone(),
+                    connector: new_refund.connector.clone(),
+                    old_status: old_refund.refund_status,
+                    new_status: new_refund.refund_status,
+                    latency_in_secs: elapsed_secs(new_refund.created_at, new_refund.modified_at),
+                },
+            )
+        })
+    }
+
+    pub fn mandate_created(mandate: &Mandate) -> Self {
+        Self::new(
+            mandate.merchant_id.clone(),
+            DomainEventType::MandateCreated {
+                mandate_id: mandate.mandate_id.clone(),
+                customer_id: mandate.customer_id.clone(),
+                connector: mandate.connector.clone(),
+                mandate_type: mandate.mandate_type,
+                mandate_status: mandate.mandate_status,
+            },
+        )
+    }
+
+    pub fn mandate_revoked(
+        old_status: storage_enums::MandateStatus,
+        revoked_mandate: &Mandate,
+    ) -> Self {
+        Self::new(
+            revoked_mandate.merchant_id.clone(),
+            DomainEventType::MandateRevoked {
+                mandate_id: revoked_mandate.mandate_id.clone(),
+                customer_id: revoked_mandate.customer_id.clone(),
+                connector: revoked_mandate.connector.clone(),
+                old_status,
+                lifetime_in_secs: elapsed_secs(
+                    revoked_mandate.created_at,
+                    common_utils::date_time::now(),
+                ),
+            },
+        )
+    }
+
+    /// Hands the event over to the event handler, failures are logged and never surfaced
+    pub fn emit(&self, state: &SessionState) {
+        state.event_handler().log_event(self);
+    }
+}
+
+/// Emits the creation event of a refund
+#[cfg(feature = "v1")]
+pub fn emit_refund_created(state: &SessionState, refund: &Refund) {
+    DomainEvent::refund_created(refund).emit(state);
+}
+
+/// Emits the status change event of a refund, if the update changed its status
+#[cfg(feature = "v1")]
+pub fn emit_refund_status_changed(state: &SessionState, old_refund: &Refund, new_refund: &Refund) {
+    if let Some(event) = DomainEvent::refund_status_changed(old_refund, new_refund) {
+        event.emit(state);
+    }
+}
+
+fn elapsed_secs(from: PrimitiveDateTime, to: PrimitiveDateTime) -> i64 {
+    (to - from).whole_seconds().max(0)
+}
+
+impl KafkaMessage for DomainEvent {
+    fn event_type(&self) -> EventType {
+        EventType::DomainEvent
+    }
+
+    fn key(&self) -> String {
+        let resource_id = match &self.event_type {
+            #[cfg(feature = "v1")]
+            DomainEventType::RefundCreated { refund_id, .. }
+            | DomainEventType::RefundStatusChanged { refund_id, .. } => refund_id,
+            DomainEventType::MandateCreated { mandate_id, .. }
+            | DomainEventType::MandateRevoked { mandate_id, .. } => mandate_id,
+        };
+        format!("{}_{}", self.merchant_id.get_string_repr(), resource_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    #[cfg(feature = "v1")]
+    use common_utils::types::ConnectorTransactionId;
+
+    use super::*;
+
+    #[cfg(feature = "v1")]
+    fn refund(refund_status: storage_enums::RefundStatus) -> Refund {
+        let created_at = common_utils::date_time::now();
+        Refund {
+            internal_reference_id: "refid_1".to_string(),
+            refund_id: "ref_1".to_string(),
+            payment_id: id_type::PaymentId::default(),
+            merchant_id: id_type::MerchantId::default(),
+            connector_transaction_id: ConnectorTransactionId::from("pi_1".to_string()),
+            connector: "stripe".to_string(),
+            connector_refund_id: None,
+            external_reference_id: None,
+            refund_type: storage_enums::RefundType::InstantRefund,
+            total_amount: MinorUnit::new(1000),
+            currency: storage_enums::Currency::USD,
+            refund_amount: MinorUnit::new(400),
+            refund_status,
+            sent_to_gateway: true,
+            refund_error_message: None,
+            metadata: None,
+            refund_arn: None,
+            created_at,
+            modified_at: created_at,
+            description: None,
+            attempt_id: "attempt_1".to_string(),
+            refund_reason: None,
+            refund_error_code: None,
+            profile_id: None,
+            updated_by: "test".to_string(),
+            merchant_connector_id: None,
+            charges: None,
+            organization_id: id_type::OrganizationId::default(),
+            connector_refund_data: None,
+            connector_transaction_data: None,
+            split_refunds: None,
+            unified_code: None,
+            unified_message: None,
+            processor_refund_data: None,
+            processor_transaction_data: None,
+            issuer_error_code: None,
+            issuer_error_message: None,
+            origin: storage_enums::RefundOrigin::Api,
+        }
+    }
+
+    fn mandate(mandate_status: storage_enums::MandateStatus) -> Mandate {
+        let created_at = common_utils::date_time::now() - time::Duration::days(30);
+        Mandate {
+            mandate_id: "man_1".to_string(),
+            customer_id: id_type::CustomerId::default(),
+            merchant_id: id_type::MerchantId::default(),
+            payment_method_id: "pm_1".to_string(),
+            mandate_status,
+            mandate_type: storage_enums::MandateType::MultiUse,
+            customer_accepted_at: None,
+            customer_ip_address: None,
+            customer_user_agent: None,
+            network_transaction_id: None,
+            previous_attempt_id: None,
+            created_at,
+            mandate_amount: None,
+            mandate_currency: None,
+            amount_captured: None,
+            connector: "stripe".to_string(),
+            connector_mandate_id: None,
+            start_date: None,
+            end_date: None,
+            metadata: None,
+            connector_mandate_ids: None,
+            original_payment_id: None,
+            merchant_connector_id: None,
+            updated_by: None,
+            frequency: None,
+            last_charged_at: None,
+            modified_at: created_at,
+            merchant_metadata: None,
+        }
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_refund_success_transition_event() {
+        let pending_refund = refund(storage_enums::RefundStatus::Pending);
+        let succeeded_refund = Refund {
+            refund_status: storage_enums::RefundStatus::Success,
+            modified_at: pending_refund.created_at + time::Duration::seconds(42),
+            ..pending_refund.clone()
+        };
+
+        let event = DomainEvent::refund_status_changed(&pending_refund, &succeeded_refund)
+            .expect("status change event");
+        let event = serde_json::to_value(&event).unwrap();
+
+        assert_eq!(event["event_type"], "refund_status_changed");
+        assert_eq!(event["refund_id"], "ref_1");
+        assert_eq!(event["connector"], "stripe");
+        assert_eq!(event["old_status"], "pending");
+        assert_eq!(event["new_status"], "success");
+        assert_eq!(event["latency_in_secs"], 42);
+        assert!(event["merchant_id"].is_string());
+        assert!(event["created_at"].is_number());
+
+        assert!(DomainEvent::refund_status_changed(&succeeded_refund, &succeeded_refund).is_none());
+    }
+
+    #[test]
+    fn test_mandate_revoke_event() {
+        let revoked_mandate = mandate(storage_enums::MandateStatus::Revoked);
+
+        let event =
+            DomainEvent::mandate_revoked(storage_enums::MandateStatus::Active, &revoked_mandate);
+        assert_eq!(
+            event.key(),
+            format!("{}_man_1", revoked_mandate.merchant_id.get_string_repr())
+        );
+
+        let event = serde_json::to_value(&event).unwrap();
+        assert_eq!(event["event_type"], "mandate_revoked");
+        assert_eq!(event["mandate_id"], "man_1");
+        assert_eq!(event["connector"], "stripe");
+        assert_eq!(event["old_status"], "active");
+        assert!(event["lifetime_in_secs"].as_i64().unwrap() >= 30 * 24 * 60 * 60);
+    }
+}
@@ -87,8 +87,8 @@ impl OutgoingWebhookEventMetric for OutgoingWebhookContent {
                     .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
             }),
             Self::RefundDetails(refund_payload) => Some(OutgoingWebhookEventContent::Refund {
-                payment_id: refund_payload.payment_id.clone(),
-                refund_id: refund_payload.get_refund_id_as_string(),
+                payment_id: refund_payload.get_refund().payment_id.clone(),
+                refund_id: refund_payload.get_refund().refund_id.clone(),
                 content: masking::masked_serialize(&refund_payload)
                     .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
             }),
@@ -14,6 +14,9 @@ pub(crate) mod macros;
 pub mod routes;
 pub mod workflows;
 
+#[cfg(all(test, feature = "v1"))]
+pub(crate) mod test_utils;
+
 #[cfg(feature = "olap")]
 pub mod analytics;
 pub mod analytics_validator;
@@ -92,6 +95,7 @@ pub mod headers {
     pub const X_CLIENT_SECRET: &str = "X-Client-Secret";
     pub const X_CUSTOMER_ID: &str = "X-Customer-Id";
     pub const X_CONNECTED_MERCHANT_ID: &str = "x-connected-merchant-id";
+    pub const X_REFUND_STATUS_TERMINAL: &str = "x-refund-status-terminal";
 }
 
 pub mod pii {
@@ -368,8 +372,15 @@ pub fn get_application_builder(
         .content_type_required(true)
         .error_handler(utils::error_parser::custom_json_error_handler);
 
+    #[cfg(feature = "v2")]
+    let path_cfg = actix_web::web::PathConfig::default()
+        .error_handler(utils::error_parser::custom_path_error_handler);
+    #[cfg(not(feature = "v2"))]
+    let path_cfg = actix_web::web::PathConfig::default();
+
     actix_web::App::new()
         .app_data(json_cfg)
+        .app_data(path_cfg)
         .wrap(ErrorHandlers::new().handler(
             StatusCode::NOT_FOUND,
             errors::error_handlers::custom_error_handlers,
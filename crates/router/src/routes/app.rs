@@ -932,6 +932,10 @@ impl Routing {
                     )
                 })),
             )
+            .service(
+                web::resource("/validate-import")
+                    .route(web::post().to(routing::routing_validate_import)),
+            )
             .service(web::resource("/rule/migrate").route(web::post().to(
                 |state, req, query: web::Query<RuleMigrationQuery>| {
                     routing::migrate_routing_rules_for_profile(state, req, query)
@@ -1144,6 +1148,10 @@ impl Customers {
                     web::resource("/{customer_id}/mandates")
                         .route(web::get().to(customers::get_customer_mandates)),
                 )
+                .service(
+                    web::resource("/{customer_id}/mandates/revoke")
+                        .route(web::post().to(customers::revoke_customer_mandates)),
+                )
                 .service(web::resource("/list").route(web::get().to(customers::customers_list)))
         }
 
@@ -1186,6 +1194,15 @@ impl Refunds {
         {
             route = route
                 .service(web::resource("/list").route(web::post().to(refunds_list)))
+                .service(web::resource("/export").route(web::post().to(refunds_export)))
+                .service(
+                    web::resource("/reconciliation/export")
+                        .route(web::post().to(refunds_reconciliation_export)),
+                )
+                .service(
+                    web::resource("/modified_since")
+                        .route(web::get().to(refunds_list_modified_since)),
+                )
                 .service(web::resource("/profile/list").route(web::post().to(refunds_list_profile)))
                 .service(web::resource("/filter").route(web::post().to(refunds_filter_list)))
                 .service(web::resource("/v2/filter").route(web::get().to(get_refunds_filters)))
@@ -1194,6 +1211,14 @@ impl Refunds {
                     web::resource("/profile/aggregate")
                         .route(web::get().to(get_refunds_aggregate_profile)),
                 )
+                .service(
+                    web::resource("/aggregate/status_counts")
+                        .route(web::get().to(get_refunds_status_counts)),
+                )
+                .service(
+                    web::resource("/profile/aggregate/status_counts")
+                        .route(web::get().to(get_refunds_status_counts_profile)),
+                )
                 .service(
                     web::resource("/v2/profile/filter")
                         .route(web::get().to(get_refunds_filters_profile)),
@@ -1201,6 +1226,15 @@ impl Refunds {
                 .service(
                     web::resource("/{id}/manual-update")
                         .route(web::put().to(refunds_manual_update)),
+                )
+                .service(
+                    web::resource("/merchant_connector_id/backfill")
+                        .route(web::post().to(refunds_merchant_connector_id_backfill)),
+                )
+                .service(web::resource("/stale/sync").route(web::post().to(refunds_stale_sync)))
+                .service(
+                    web::resource("/circuit_breaker/{merchant_connector_id}/reset")
+                        .route(web::post().to(refunds_circuit_breaker_reset)),
                 );
         }
         #[cfg(feature = "oltp")]
@@ -1208,6 +1242,10 @@ impl Refunds {
             route = route
                 .service(web::resource("").route(web::post().to(refunds_create)))
                 .service(web::resource("/sync").route(web::post().to(refunds_retrieve_with_body)))
+                .service(web::resource("/batch").route(web::post().to(refunds_batch_create)))
+                .service(
+                    web::resource("/batch/{batch_id}").route(web::get().to(refunds_batch_retrieve)),
+                )
                 .service(
                     web::resource("/{id}")
                         .route(web::get().to(refunds_retrieve))
@@ -1225,8 +1263,12 @@ impl Refunds {
 
         #[cfg(feature = "olap")]
         {
-            route =
-                route.service(web::resource("/list").route(web::post().to(refunds::refunds_list)));
+            route = route
+                .service(web::resource("/list").route(web::post().to(refunds::refunds_list)))
+                .service(
+                    web::resource("/modified_since")
+                        .route(web::get().to(refunds::refunds_list_modified_since)),
+                );
         }
         #[cfg(feature = "oltp")]
         {
@@ -1714,7 +1756,22 @@ impl Mandates {
         {
             route =
                 route.service(web::resource("/list").route(web::get().to(retrieve_mandates_list)));
-            route = route.service(web::resource("/{id}").route(web::get().to(get_mandate)));
+            route = route.service(
+                web::resource("/{id}")
+                    .route(web::get().to(get_mandate))
+                    .route(web::patch().to(update_mandate)),
+            );
+            route = route.service(
+                web::resource("/{id}/revocation_eligibility")
+                    .route(web::get().to(get_mandate_revocation_eligibility)),
+            );
+            route = route.service(
+                web::resource("/connector_mandate_id/backfill")
+                    .route(web::post().to(mandates_connector_mandate_id_backfill)),
+            );
+            route = route.service(
+                web::resource("/pending/sweep").route(web::post().to(mandates_pending_sweep)),
+            );
         }
         #[cfg(feature = "oltp")]
         {
@@ -2173,6 +2230,12 @@ impl Profile {
                 .service(
                     web::resource("/toggle_connector_agnostic_mit")
                         .route(web::post().to(profiles::toggle_connector_agnostic_mit)),
+                )
+                .service(
+                    web::resource("/refund_limits")
+                        .route(web::get().to(profiles::refund_limits_config_retrieve))
+                        .route(web::post().to(profiles::refund_limits_config_upsert))
+                        .route(web::delete().to(profiles::refund_limits_config_delete)),
                 ),
         );
 
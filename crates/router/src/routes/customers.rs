@@ -398,20 +398,29 @@ pub async fn get_customer_mandates(
     state: web::Data<AppState>,
     req: HttpRequest,
     path: web::Path<id_type::CustomerId>,
+    query_payload: web::Query<api_models::mandates::MandateListConstraints>,
 ) -> impl Responder {
     let flow = Flow::CustomersGetMandates;
-    let customer_id = path.into_inner();
+    let payload = api_models::mandates::CustomerMandateListRequestInternal {
+        customer_id: path.into_inner(),
+        constraints: query_payload.into_inner(),
+    };
 
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
-        customer_id,
-        |state, auth: auth::AuthenticationData, customer_id, _| {
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            crate::core::mandate::get_customer_mandates(state, merchant_context, customer_id)
+            crate::core::mandate::get_customer_mandates(
+                state,
+                merchant_context,
+                req.customer_id,
+                req.constraints,
+            )
         },
         auth::auth_type(
             &auth::HeaderAuth(auth::ApiKeyAuth {
@@ -427,3 +436,33 @@ pub async fn get_customer_mandates(
     ))
     .await
 }
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::CustomersRevokeMandates))]
+pub async fn revoke_customer_mandates(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::CustomerId>,
+) -> impl Responder {
+    let flow = Flow::CustomersRevokeMandates;
+    let customer_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        customer_id,
+        |state, auth: auth::AuthenticationData, customer_id, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            crate::core::mandate::revoke_customer_mandates(state, merchant_context, customer_id)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
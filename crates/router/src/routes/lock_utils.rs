@@ -75,6 +75,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RoutingUpdateConfig
             | Flow::RoutingUpdateDefaultConfig
             | Flow::RoutingDeleteConfig
+            | Flow::RoutingValidateImport
             | Flow::DecisionManagerDeleteConfig
             | Flow::DecisionManagerRetrieveConfig
             | Flow::ToggleDynamicRouting
@@ -107,12 +108,19 @@ impl From<Flow> for ApiIdentifier {
             | Flow::CustomersUpdate
             | Flow::CustomersDelete
             | Flow::CustomersGetMandates
+            | Flow::CustomersRevokeMandates
             | Flow::CustomersList => Self::Customers,
 
             Flow::EphemeralKeyCreate | Flow::EphemeralKeyDelete => Self::Ephemeral,
 
             Flow::DeepHealthCheck | Flow::HealthCheck => Self::Health,
-            Flow::MandatesRetrieve | Flow::MandatesRevoke | Flow::MandatesList => Self::Mandates,
+            Flow::MandatesRetrieve
+            | Flow::MandatesRevoke
+            | Flow::MandatesUpdate
+            | Flow::MandatesRevocationEligibility
+            | Flow::MandatesList
+            | Flow::MandatesConnectorMandateIdBackfill
+            | Flow::MandatesPendingSweep => Self::Mandates,
 
             Flow::PaymentMethodsCreate
             | Flow::PaymentMethodsMigrate
@@ -181,9 +189,17 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RefundsRetrieveForceSync
             | Flow::RefundsUpdate
             | Flow::RefundsList
+            | Flow::RefundsListModifiedSince
+            | Flow::RefundsExport
+            | Flow::RefundsReconciliationExport
             | Flow::RefundsFilters
             | Flow::RefundsAggregate
-            | Flow::RefundsManualUpdate => Self::Refunds,
+            | Flow::RefundsBatchCreate
+            | Flow::RefundsBatchRetrieve
+            | Flow::RefundsManualUpdate
+            | Flow::RefundsMerchantConnectorIdBackfill
+            | Flow::RefundsCircuitBreakerReset
+            | Flow::RefundsStaleSync => Self::Refunds,
             Flow::Relay | Flow::RelayRetrieve => Self::Relay,
 
             Flow::FrmFulfillment
@@ -225,7 +241,10 @@ impl From<Flow> for ApiIdentifier {
             | Flow::ProfileDelete
             | Flow::ProfileList
             | Flow::ToggleExtendedCardInfo
-            | Flow::ToggleConnectorAgnosticMit => Self::Profile,
+            | Flow::ToggleConnectorAgnosticMit
+            | Flow::RefundLimitsConfigUpsert
+            | Flow::RefundLimitsConfigRetrieve
+            | Flow::RefundLimitsConfigDelete => Self::Profile,
 
             Flow::PaymentLinkRetrieve
             | Flow::PaymentLinkInitiate
@@ -48,21 +48,76 @@ pub async fn revoke_mandate(
     state: web::Data<AppState>,
     req: HttpRequest,
     path: web::Path<String>,
+    body: web::Bytes,
 ) -> HttpResponse {
     let flow = Flow::MandatesRevoke;
-    let mandate_id = mandates::MandateId {
-        mandate_id: path.into_inner(),
+    let mut payload = match parse_mandate_revoke_request(&body) {
+        Ok(payload) => payload,
+        Err(error) => return api::log_and_return_error_response(error),
     };
+    payload.mandate_id = path.into_inner();
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
-        mandate_id,
+        payload,
+        |state, auth: auth::AuthenticationData, req, req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            mandate::revoke_mandate(state, req_state, merchant_context, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Parses the body of a mandate revoke request, which is optional. A request without a body
+/// revokes the mandate with the default options, a body which is not a valid revoke request is
+/// rejected.
+#[cfg(feature = "v1")]
+fn parse_mandate_revoke_request(
+    body: &[u8],
+) -> crate::core::errors::RouterResult<mandates::MandateRevokeRequest> {
+    if body.is_empty() {
+        return Ok(mandates::MandateRevokeRequest::default());
+    }
+
+    serde_json::from_slice(body).map_err(|error| {
+        error_stack::report!(crate::core::errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Invalid mandate revoke request: {error}"),
+        })
+    })
+}
+
+/// Mandates - Update Mandate
+///
+/// Updates the metadata of a mandate, merging it into the existing metadata
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::MandatesUpdate))]
+pub async fn update_mandate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<mandates::MandateUpdateRequest>,
+) -> HttpResponse {
+    let flow = Flow::MandatesUpdate;
+    let mut payload = json_payload.into_inner();
+    payload.mandate_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
         |state, auth: auth::AuthenticationData, req, _| {
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            mandate::revoke_mandate(state, merchant_context, req)
+            mandate::update_mandate(state, merchant_context, req)
         },
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
@@ -72,6 +127,47 @@ pub async fn revoke_mandate(
     ))
     .await
 }
+
+/// Mandates - Revocation Eligibility
+///
+/// Checks whether a mandate can be revoked, without revoking it
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::MandatesRevocationEligibility))]
+pub async fn get_mandate_revocation_eligibility(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::MandatesRevocationEligibility;
+    let mandate_id = mandates::MandateId {
+        mandate_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        mandate_id,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            mandate::get_mandate_revocation_eligibility(state, merchant_context, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantMandateRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Mandates - List Mandates
 #[instrument(skip_all, fields(flow = ?Flow::MandatesList))]
 pub async fn retrieve_mandates_list(
@@ -106,3 +202,80 @@ pub async fn retrieve_mandates_list(
     ))
     .await
 }
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[instrument(skip_all, fields(flow = ?Flow::MandatesConnectorMandateIdBackfill))]
+pub async fn mandates_connector_mandate_id_backfill(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Json<mandates::MandateConnectorReferenceBackfillRequest>,
+) -> HttpResponse {
+    let flow = Flow::MandatesConnectorMandateIdBackfill;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.into_inner(),
+        |state, _auth, req, _| mandate::backfill_mandates_connector_mandate_id(state, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[instrument(skip_all, fields(flow = ?Flow::MandatesPendingSweep))]
+pub async fn mandates_pending_sweep(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Json<mandates::PendingMandateSweepRequest>,
+) -> HttpResponse {
+    let flow = Flow::MandatesPendingSweep;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.into_inner(),
+        |state, _auth, req, _| mandate::sweep_stale_pending_mandates(state, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_revoke_request_without_body_uses_default_options() {
+        let payload = parse_mandate_revoke_request(b"").unwrap();
+
+        assert!(!payload.force);
+    }
+
+    #[test]
+    fn test_revoke_request_body_is_parsed() {
+        let payload = parse_mandate_revoke_request(br#"{"force": true}"#).unwrap();
+
+        assert!(payload.force);
+    }
+
+    #[test]
+    fn test_malformed_revoke_request_body_is_rejected() {
+        for body in [
+            br#"{"force": "yes"}"#.as_slice(),
+            br#"{"forced": true}"#.as_slice(),
+            b"force=true".as_slice(),
+        ] {
+            let error = parse_mandate_revoke_request(body).unwrap_err();
+
+            assert!(matches!(
+                error.current_context(),
+                crate::core::errors::ApiErrorResponse::InvalidRequestData { .. }
+            ));
+        }
+    }
+}
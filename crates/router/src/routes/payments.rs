@@ -515,6 +515,7 @@ pub async fn payments_retrieve(
         expand_attempts: json_payload.expand_attempts,
         expand_captures: json_payload.expand_captures,
         all_keys_required: json_payload.all_keys_required,
+        expand_mandate: json_payload.expand_mandate,
         ..Default::default()
     };
     let header_payload = match HeaderPayload::foreign_try_from(req.headers()) {
@@ -548,25 +549,38 @@ pub async fn payments_retrieve(
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            payments::payments_core::<
-                api_types::PSync,
-                payment_types::PaymentsResponse,
-                _,
-                _,
-                _,
-                payments::PaymentData<api_types::PSync>,
-            >(
-                state,
-                req_state,
-                merchant_context,
-                auth.profile_id,
-                payments::PaymentStatus,
-                req,
-                auth_flow,
-                payments::CallConnectorAction::Trigger,
-                None,
-                header_payload.clone(),
-            )
+            let header_payload = header_payload.clone();
+            async move {
+                let expand_mandate = req.expand_mandate;
+                let response = payments::payments_core::<
+                    api_types::PSync,
+                    payment_types::PaymentsResponse,
+                    _,
+                    _,
+                    _,
+                    payments::PaymentData<api_types::PSync>,
+                >(
+                    state.clone(),
+                    req_state,
+                    merchant_context.clone(),
+                    auth.profile_id,
+                    payments::PaymentStatus,
+                    req,
+                    auth_flow,
+                    payments::CallConnectorAction::Trigger,
+                    None,
+                    header_payload,
+                )
+                .await;
+
+                crate::core::mandate::expand_mandate_in_payments_response(
+                    &state,
+                    &merchant_context,
+                    expand_mandate,
+                    response,
+                )
+                .await
+            }
         },
         auth::auth_type(
             &*auth_type,
@@ -1986,7 +2000,8 @@ where
             recurring_details.is_network_transaction_id_and_card_details_flow()
         })
         .unwrap_or(false);
-    if is_recurring_details_type_nti_and_card_details {
+    let expand_mandate = req.expand_mandate;
+    let response = if is_recurring_details_type_nti_and_card_details {
         // no list of eligible connectors will be passed in the confirm call
         logger::debug!("Authorize call for NTI and Card Details flow");
         payments::proxy_for_payments_core::<
@@ -1997,9 +2012,9 @@ where
             _,
             payments::PaymentData<api_types::Authorize>,
         >(
-            state,
+            state.clone(),
             req_state,
-            merchant_context,
+            merchant_context.clone(),
             profile_id,
             operation,
             req.clone(),
@@ -2023,9 +2038,9 @@ where
                     _,
                     payments::PaymentData<api_types::Authorize>,
                 >(
-                    state,
+                    state.clone(),
                     req_state,
-                    merchant_context,
+                    merchant_context.clone(),
                     profile_id,
                     operation,
                     req,
@@ -2045,9 +2060,9 @@ where
                     _,
                     payments::PaymentData<api_types::SetupMandate>,
                 >(
-                    state,
+                    state.clone(),
                     req_state,
-                    merchant_context,
+                    merchant_context.clone(),
                     profile_id,
                     operation,
                     req,
@@ -2059,7 +2074,15 @@ where
                 .await
             }
         }
-    }
+    };
+
+    crate::core::mandate::expand_mandate_in_payments_response(
+        &state,
+        &merchant_context,
+        expand_mandate,
+        response,
+    )
+    .await
 }
 
 #[cfg(feature = "v1")]
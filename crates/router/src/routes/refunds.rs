@@ -3,6 +3,8 @@ use common_utils;
 use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
+#[cfg(all(feature = "v1", feature = "olap"))]
+use crate::core::refund_circuit_breaker;
 #[cfg(feature = "v1")]
 use crate::core::refunds::*;
 #[cfg(feature = "v2")]
@@ -44,6 +46,16 @@ mod internal_payload_types {
     }
 }
 
+/// Refunds created with a dashboard (JWT) session are attributed to the dashboard, everything
+/// else reaching the refund create endpoint is attributed to the API
+fn get_refund_origin(req: &HttpRequest) -> common_enums::RefundOrigin {
+    if auth::is_jwt_auth(req.headers()) {
+        common_enums::RefundOrigin::Dashboard
+    } else {
+        common_enums::RefundOrigin::Api
+    }
+}
+
 /// Refunds - Create
 ///
 /// To create a refund against an already processed payment
@@ -56,6 +68,7 @@ pub async fn refunds_create(
     json_payload: web::Json<refunds::RefundRequest>,
 ) -> HttpResponse {
     let flow = Flow::RefundsCreate;
+    let origin = get_refund_origin(&req);
     Box::pin(api::server_wrap(
         flow,
         state,
@@ -65,7 +78,7 @@ pub async fn refunds_create(
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            refund_create_core(state, merchant_context, auth.profile_id, req)
+            refund_create_core(state, merchant_context, auth.profile_id, req, origin)
         },
         auth::auth_type(
             &auth::HeaderAuth(auth::ApiKeyAuth {
@@ -95,6 +108,7 @@ pub async fn refunds_create(
     let global_refund_id =
         common_utils::id_type::GlobalRefundId::generate(&state.conf.cell_information.id);
     let payload = json_payload.into_inner();
+    let origin = get_refund_origin(&req);
 
     let internal_refund_create_payload =
         internal_payload_types::RefundsGenericRequestWithResourceId {
@@ -132,6 +146,7 @@ pub async fn refunds_create(
                 merchant_context,
                 req.payload,
                 global_refund_id.clone(),
+                origin,
             )
         },
         auth_type,
@@ -140,6 +155,85 @@ pub async fn refunds_create(
     .await
 }
 
+/// Refunds - Batch Create
+///
+/// To create refunds against multiple payments at once. The refunds are initiated asynchronously
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::RefundsBatchCreate))]
+// #[post("/batch")]
+pub async fn refunds_batch_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<refunds::RefundBatchCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::RefundsBatchCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            refund_batch_create_core(state, merchant_context, auth.profile_id, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfileRefundWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Refunds - Batch Retrieve
+///
+/// To retrieve the status of a refund batch and of each of its entries
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::RefundsBatchRetrieve))]
+// #[get("/batch/{batch_id}")]
+pub async fn refunds_batch_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::RefundsBatchRetrieve;
+    let batch_request = refunds::RefundBatchRetrieveRequest {
+        batch_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        batch_request,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            refund_batch_retrieve_core(state, merchant_context, auth.profile_id, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfileRefundRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(feature = "v1")]
 /// Refunds - Retrieve (GET)
 ///
@@ -157,6 +251,7 @@ pub async fn refunds_retrieve(
         force_sync: query_params.force_sync,
         merchant_connector_details: None,
     };
+    let wait_seconds = query_params.wait_seconds;
     let flow = match query_params.force_sync {
         Some(true) => Flow::RefundsRetrieveForceSync,
         _ => Flow::RefundsRetrieve,
@@ -173,12 +268,12 @@ pub async fn refunds_retrieve(
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            refund_response_wrapper(
+            refund_retrieve_with_wait(
                 state,
                 merchant_context,
                 auth.profile_id,
                 refund_request,
-                refund_retrieve_core_with_refund_id,
+                wait_seconds,
             )
         },
         auth::auth_type(
@@ -460,6 +555,81 @@ pub async fn refunds_list(
     .await
 }
 
+#[cfg(all(feature = "v1", feature = "olap"))]
+/// Refunds - Export
+///
+/// To export the refunds matching the refunds list filters as a CSV or JSON lines file
+#[instrument(skip_all, fields(flow = ?Flow::RefundsExport))]
+pub async fn refunds_export(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Json<api_models::refunds::RefundExportRequest>,
+) -> HttpResponse {
+    let flow = Flow::RefundsExport;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            refund_export(state, merchant_context, None, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantRefundRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+/// Refunds - Reconciliation Export
+///
+/// To export as CSV the refunds whose status does not match their connector data, optionally
+/// scheduling a sync with the connector for each of them
+#[instrument(skip_all, fields(flow = ?Flow::RefundsReconciliationExport))]
+pub async fn refunds_reconciliation_export(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Json<api_models::refunds::RefundReconciliationRequest>,
+) -> HttpResponse {
+    let flow = Flow::RefundsReconciliationExport;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            refund_reconciliation_export(state, merchant_context, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantRefundWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(all(feature = "v2", feature = "olap"))]
 #[instrument(skip_all, fields(flow = ?Flow::RefundsList))]
 pub async fn refunds_list(
@@ -491,6 +661,74 @@ pub async fn refunds_list(
     .await
 }
 
+#[cfg(all(feature = "v1", feature = "olap"))]
+/// Refunds - List modified since
+///
+/// To list the refunds of the merchant modified at or after a given time, for incrementally syncing refunds
+#[instrument(skip_all, fields(flow = ?Flow::RefundsListModifiedSince))]
+pub async fn refunds_list_modified_since(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_params: web::Query<api_models::refunds::RefundsModifiedSinceRequest>,
+) -> HttpResponse {
+    let flow = Flow::RefundsListModifiedSince;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_params.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            refund_list_modified_since(state, merchant_context, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantRefundRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "v2", feature = "olap"))]
+#[instrument(skip_all, fields(flow = ?Flow::RefundsListModifiedSince))]
+pub async fn refunds_list_modified_since(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_params: web::Query<api_models::refunds::RefundsModifiedSinceRequest>,
+) -> HttpResponse {
+    let flow = Flow::RefundsListModifiedSince;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_params.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            refund_list_modified_since(state, auth.merchant_account, req)
+        },
+        auth::auth_type(
+            &auth::V2ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantRefundRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(all(feature = "v1", feature = "olap"))]
 /// Refunds - List at profile level
 ///
@@ -678,6 +916,41 @@ pub async fn get_refunds_aggregates(
     .await
 }
 
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[instrument(skip_all, fields(flow = ?Flow::RefundsAggregate))]
+pub async fn get_refunds_status_counts(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_params: web::Query<api_models::refunds::RefundStatusCountRequest>,
+) -> HttpResponse {
+    let flow = Flow::RefundsAggregate;
+    let query_params = query_params.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_params,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            get_refund_status_counts(state, merchant_context, None, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantRefundRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(all(feature = "v1", feature = "olap"))]
 #[instrument(skip_all, fields(flow = ?Flow::RefundsManualUpdate))]
 pub async fn refunds_manual_update(
@@ -701,6 +974,69 @@ pub async fn refunds_manual_update(
     .await
 }
 
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[instrument(skip_all, fields(flow = ?Flow::RefundsMerchantConnectorIdBackfill))]
+pub async fn refunds_merchant_connector_id_backfill(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Json<api_models::refunds::RefundMerchantConnectorIdBackfillRequest>,
+) -> HttpResponse {
+    let flow = Flow::RefundsMerchantConnectorIdBackfill;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.into_inner(),
+        |state, _auth, req, _| backfill_refunds_merchant_connector_id(state, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[instrument(skip_all, fields(flow = ?Flow::RefundsStaleSync))]
+pub async fn refunds_stale_sync(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Json<api_models::refunds::RefundsStaleSyncRequest>,
+) -> HttpResponse {
+    let flow = Flow::RefundsStaleSync;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.into_inner(),
+        |state, _auth, req, _| refunds_stale_sync_core(state, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[instrument(skip_all, fields(flow = ?Flow::RefundsCircuitBreakerReset))]
+pub async fn refunds_circuit_breaker_reset(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantConnectorAccountId>,
+) -> HttpResponse {
+    let flow = Flow::RefundsCircuitBreakerReset;
+    let payload = api_models::refunds::RefundCircuitBreakerResetRequest {
+        merchant_connector_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _auth, req, _| refund_circuit_breaker::reset_refund_circuit_breaker(state, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(all(feature = "v1", feature = "olap"))]
 #[instrument(skip_all, fields(flow = ?Flow::RefundsAggregate))]
 pub async fn get_refunds_aggregate_profile(
@@ -740,3 +1076,69 @@ pub async fn get_refunds_aggregate_profile(
     ))
     .await
 }
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[instrument(skip_all, fields(flow = ?Flow::RefundsAggregate))]
+pub async fn get_refunds_status_counts_profile(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_params: web::Query<api_models::refunds::RefundStatusCountRequest>,
+) -> HttpResponse {
+    let flow = Flow::RefundsAggregate;
+    let query_params = query_params.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_params,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            get_refund_status_counts(
+                state,
+                merchant_context,
+                auth.profile_id.map(|profile_id| vec![profile_id]),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfileRefundRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::test::TestRequest;
+
+    use super::*;
+
+    #[test]
+    fn test_refund_origin_is_derived_from_the_authentication() {
+        let dashboard_request = TestRequest::default()
+            .insert_header(("authorization", "Bearer token"))
+            .to_http_request();
+        let api_request = TestRequest::default()
+            .insert_header(("api-key", "dev_key"))
+            .to_http_request();
+
+        assert_eq!(
+            get_refund_origin(&dashboard_request),
+            common_enums::RefundOrigin::Dashboard
+        );
+        assert_eq!(
+            get_refund_origin(&api_request),
+            common_enums::RefundOrigin::Api
+        );
+    }
+}
@@ -303,14 +303,19 @@ where
                                                     }),
                                             );
                                             if all_keys_required == Some(true) {
-                                                let mut decoded = String::from_utf8(body.response.as_ref().to_vec())
-                                                    .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
-                                                if decoded.starts_with('\u{feff}') {
-                                                    decoded = decoded
-                                                        .trim_start_matches('\u{feff}')
-                                                        .to_string();
+                                                // Connectors masking the personal data in their responses provide the response to be returned themselves
+                                                if data.whole_connector_response.is_none() {
+                                                    let mut decoded = String::from_utf8(body.response.as_ref().to_vec())
+                                                        .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+                                                    if decoded.starts_with('\u{feff}') {
+                                                        decoded = decoded
+                                                            .trim_start_matches('\u{feff}')
+                                                            .to_string();
+                                                    }
+                                                    data.whole_connector_response = Some(decoded);
                                                 }
-                                                data.whole_connector_response = Some(decoded);
+                                            } else {
+                                                data.whole_connector_response = None;
                                             }
                                             Ok(data)
                                         }
@@ -39,7 +39,9 @@ pub struct KafkaRefund<'a> {
     pub refund_reason: Option<&'a String>,
     pub refund_error_code: Option<&'a String>,
     pub profile_id: Option<&'a id_type::ProfileId>,
+    pub merchant_connector_id: Option<&'a id_type::MerchantConnectorAccountId>,
     pub organization_id: &'a id_type::OrganizationId,
+    pub origin: &'a storage_enums::RefundOrigin,
 }
 
 #[cfg(feature = "v1")]
@@ -69,7 +71,9 @@ impl<'a> KafkaRefund<'a> {
             refund_reason: refund.refund_reason.as_ref(),
             refund_error_code: refund.refund_error_code.as_ref(),
             profile_id: refund.profile_id.as_ref(),
+            merchant_connector_id: refund.merchant_connector_id.as_ref(),
             organization_id: &refund.organization_id,
+            origin: &refund.origin,
         }
     }
 }
@@ -115,6 +119,7 @@ pub struct KafkaRefund<'a> {
     pub unified_message: Option<&'a String>,
     pub processor_refund_data: Option<&'a String>,
     pub processor_transaction_data: Option<&'a String>,
+    pub origin: &'a storage_enums::RefundOrigin,
 }
 
 #[cfg(feature = "v2")]
@@ -154,6 +159,7 @@ impl<'a> KafkaRefund<'a> {
             id,
             merchant_reference_id,
             connector_id,
+            origin,
         } = refund;
 
         Self {
@@ -192,6 +198,7 @@ impl<'a> KafkaRefund<'a> {
             unified_message: unified_message.as_ref(),
             processor_refund_data: processor_refund_data.as_ref(),
             processor_transaction_data: processor_transaction_data.as_ref(),
+            origin,
         }
     }
 }
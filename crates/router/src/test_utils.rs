@@ -0,0 +1,462 @@
+//! Fixtures shared by the unit tests of the crate. The fixtures only fill the fields which are
+//! required to store the records, tests override the fields they care about with the struct
+//! update syntax.
+#![allow(clippy::unwrap_used)]
+
+use std::{borrow::Cow, sync::Arc};
+
+use common_utils::{
+    id_type, type_name,
+    types::{keymanager::Identifier, ConnectorTransactionId, MinorUnit},
+};
+use diesel_models::{enums, refund as diesel_refund};
+use masking::PeekInterface;
+
+use crate::{
+    routes::{
+        self,
+        app::{settings::Settings, StorageImpl},
+        SessionState,
+    },
+    services,
+    types::{domain, storage},
+};
+
+pub(crate) fn merchant_id() -> id_type::MerchantId {
+    id_type::MerchantId::try_from(Cow::from("merchant_1")).unwrap()
+}
+
+/// A session state of the `public` tenant whose store is a [`crate::db::MockDb`]
+pub(crate) async fn mock_session_state(conf: Settings) -> SessionState {
+    let (tx, _) = tokio::sync::oneshot::channel();
+    let app_state = Box::pin(routes::AppState::with_storage(
+        conf,
+        StorageImpl::Mock,
+        tx,
+        Box::new(services::MockApiClient),
+    ))
+    .await;
+    Arc::new(app_state)
+        .get_session_state(
+            &id_type::TenantId::try_from_string("public".to_string()).unwrap(),
+            None,
+            || {},
+        )
+        .unwrap()
+}
+
+/// Stores and returns a key store for [`merchant_id`]
+pub(crate) async fn merchant_key_store(state: &SessionState) -> domain::MerchantKeyStore {
+    let key_manager_state = &state.into();
+    let master_key = state.store.get_master_key();
+    let key_store = domain::MerchantKeyStore {
+        merchant_id: merchant_id(),
+        key: domain::types::crypto_operation(
+            key_manager_state,
+            type_name!(domain::MerchantKeyStore),
+            domain::types::CryptoOperation::Encrypt(
+                services::generate_aes256_key().unwrap().to_vec().into(),
+            ),
+            Identifier::Merchant(merchant_id()),
+            master_key,
+        )
+        .await
+        .and_then(|val| val.try_into_operation())
+        .unwrap(),
+        created_at: common_utils::date_time::now(),
+    };
+    state
+        .store
+        .insert_merchant_key_store(key_manager_state, key_store, &master_key.to_vec().into())
+        .await
+        .unwrap()
+}
+
+/// A merchant context of [`merchant_id`] with a merchant account using the postgres only storage
+/// scheme
+pub(crate) fn merchant_context(key_store: domain::MerchantKeyStore) -> domain::MerchantContext {
+    let merchant_account = domain::MerchantAccount::from(domain::MerchantAccountSetter {
+        merchant_id: merchant_id(),
+        return_url: None,
+        enable_payment_response_hash: false,
+        payment_response_hash_key: None,
+        redirect_to_merchant_with_http_post: false,
+        merchant_name: None,
+        merchant_details: None,
+        webhook_details: None,
+        sub_merchants_enabled: None,
+        parent_merchant_id: None,
+        publishable_key: "pk_test".to_string(),
+        storage_scheme: enums::MerchantStorageScheme::PostgresOnly,
+        locker_id: None,
+        metadata: None,
+        routing_algorithm: None,
+        primary_business_details: serde_json::Value::default(),
+        frm_routing_algorithm: None,
+        created_at: common_utils::date_time::now(),
+        modified_at: common_utils::date_time::now(),
+        intent_fulfillment_time: None,
+        payout_routing_algorithm: None,
+        organization_id: id_type::OrganizationId::default(),
+        is_recon_enabled: false,
+        default_profile: None,
+        recon_status: enums::ReconStatus::NotRequested,
+        payment_link_config: None,
+        pm_collect_link_config: None,
+        version: common_types::consts::API_VERSION,
+        is_platform_account: false,
+        product_type: None,
+        merchant_account_type: common_enums::MerchantAccountType::Standard,
+    });
+    domain::MerchantContext::NormalMerchant(Box::new(domain::Context(merchant_account, key_store)))
+}
+
+/// Stores an enabled merchant connector account of [`merchant_id`] for the connector in the
+/// profile
+pub(crate) async fn insert_merchant_connector_account(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    profile_id: &id_type::ProfileId,
+    connector_name: &str,
+) -> domain::MerchantConnectorAccount {
+    let key_manager_state = &state.into();
+    let connector_account_details = domain::types::crypto_operation(
+        key_manager_state,
+        type_name!(domain::MerchantConnectorAccount),
+        domain::types::CryptoOperation::Encrypt(serde_json::Value::default().into()),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .unwrap();
+    let merchant_connector_account = domain::MerchantConnectorAccount {
+        merchant_id: merchant_id(),
+        connector_name: connector_name.to_string(),
+        connector_account_details,
+        test_mode: None,
+        disabled: None,
+        merchant_connector_id: merchant_connector_id.clone(),
+        payment_methods_enabled: None,
+        connector_type: enums::ConnectorType::PaymentProcessor,
+        metadata: None,
+        frm_configs: None,
+        connector_label: None,
+        business_country: None,
+        business_label: None,
+        business_sub_label: None,
+        created_at: common_utils::date_time::now(),
+        modified_at: common_utils::date_time::now(),
+        connector_webhook_details: None,
+        profile_id: profile_id.clone(),
+        applepay_verified_domains: None,
+        pm_auth_config: None,
+        status: enums::ConnectorStatus::Active,
+        connector_wallets_details: None,
+        additional_merchant_data: None,
+        version: common_types::consts::API_VERSION,
+    };
+    state
+        .store
+        .insert_merchant_connector_account(key_manager_state, merchant_connector_account, key_store)
+        .await
+        .unwrap()
+}
+
+/// A charged payment attempt of [`merchant_id`] processed through stripe
+pub(crate) fn payment_attempt_new(attempt_id: &str) -> storage::PaymentAttemptNew {
+    storage::PaymentAttemptNew {
+        payment_id: id_type::PaymentId::default(),
+        merchant_id: merchant_id(),
+        attempt_id: attempt_id.to_string(),
+        connector: Some("stripe".to_string()),
+        merchant_connector_id: None,
+        status: enums::AttemptStatus::Charged,
+        created_at: Default::default(),
+        modified_at: Default::default(),
+        net_amount: Default::default(),
+        currency: Default::default(),
+        save_to_locker: Default::default(),
+        error_message: Default::default(),
+        offer_amount: Default::default(),
+        payment_method_id: Default::default(),
+        payment_method: Default::default(),
+        capture_method: Default::default(),
+        capture_on: Default::default(),
+        confirm: Default::default(),
+        authentication_type: Default::default(),
+        last_synced: Default::default(),
+        cancellation_reason: Default::default(),
+        amount_to_capture: Default::default(),
+        mandate_id: Default::default(),
+        browser_info: Default::default(),
+        payment_token: Default::default(),
+        error_code: Default::default(),
+        connector_metadata: Default::default(),
+        payment_experience: Default::default(),
+        payment_method_type: Default::default(),
+        payment_method_data: Default::default(),
+        business_sub_label: Default::default(),
+        straight_through_algorithm: Default::default(),
+        preprocessing_step_id: Default::default(),
+        mandate_details: Default::default(),
+        error_reason: Default::default(),
+        connector_response_reference_id: Default::default(),
+        multiple_capture_count: Default::default(),
+        amount_capturable: Default::default(),
+        updated_by: Default::default(),
+        authentication_data: Default::default(),
+        encoded_data: Default::default(),
+        unified_code: Default::default(),
+        unified_message: Default::default(),
+        external_three_ds_authentication_attempted: Default::default(),
+        authentication_connector: Default::default(),
+        authentication_id: Default::default(),
+        mandate_data: Default::default(),
+        payment_method_billing_address_id: Default::default(),
+        fingerprint_id: Default::default(),
+        client_source: Default::default(),
+        client_version: Default::default(),
+        customer_acceptance: Default::default(),
+        profile_id: id_type::ProfileId::default(),
+        organization_id: Default::default(),
+        connector_mandate_detail: Default::default(),
+        request_extended_authorization: Default::default(),
+        extended_authorization_applied: Default::default(),
+        capture_before: Default::default(),
+        card_discovery: Default::default(),
+        processor_merchant_id: Default::default(),
+        created_by: None,
+        setup_future_usage_applied: Default::default(),
+        routing_approach: Default::default(),
+    }
+}
+
+/// A successful partial refund of the payment attempt `attempt_1` of [`merchant_id`]
+pub(crate) fn refund_new(refund_id: &str) -> diesel_refund::RefundNew {
+    let now = common_utils::date_time::now();
+    diesel_refund::RefundNew {
+        refund_id: refund_id.to_string(),
+        payment_id: id_type::PaymentId::default(),
+        merchant_id: merchant_id(),
+        internal_reference_id: format!("refid_{refund_id}"),
+        external_reference_id: Some(refund_id.to_string()),
+        connector_transaction_id: ConnectorTransactionId::from("pi_1".to_string()),
+        connector: "stripe".to_string(),
+        connector_refund_id: None,
+        refund_type: enums::RefundType::InstantRefund,
+        total_amount: MinorUnit::new(1000),
+        currency: enums::Currency::USD,
+        refund_amount: MinorUnit::new(400),
+        refund_status: enums::RefundStatus::Success,
+        sent_to_gateway: true,
+        metadata: None,
+        refund_arn: None,
+        created_at: now,
+        modified_at: now,
+        description: None,
+        attempt_id: "attempt_1".to_string(),
+        refund_reason: None,
+        profile_id: None,
+        updated_by: "test".to_string(),
+        merchant_connector_id: None,
+        charges: None,
+        organization_id: id_type::OrganizationId::default(),
+        split_refunds: None,
+        processor_refund_data: None,
+        processor_transaction_data: None,
+        origin: enums::RefundOrigin::Api,
+    }
+}
+
+/// A successful full refund of the payment attempt `attempt_1` of [`merchant_id`]
+pub(crate) fn refund(refund_id: &str) -> diesel_refund::Refund {
+    let now = common_utils::date_time::now();
+    diesel_refund::Refund {
+        internal_reference_id: format!("refid_{refund_id}"),
+        refund_id: refund_id.to_string(),
+        payment_id: id_type::PaymentId::default(),
+        merchant_id: merchant_id(),
+        connector_transaction_id: ConnectorTransactionId::from("pi_1".to_string()),
+        connector: "stripe".to_string(),
+        connector_refund_id: None,
+        external_reference_id: None,
+        refund_type: enums::RefundType::InstantRefund,
+        total_amount: MinorUnit::new(1000),
+        currency: enums::Currency::USD,
+        refund_amount: MinorUnit::new(1000),
+        refund_status: enums::RefundStatus::Success,
+        sent_to_gateway: true,
+        refund_error_message: None,
+        metadata: None,
+        refund_arn: None,
+        created_at: now,
+        modified_at: now,
+        description: None,
+        attempt_id: "attempt_1".to_string(),
+        refund_reason: None,
+        refund_error_code: None,
+        profile_id: None,
+        updated_by: "test".to_string(),
+        merchant_connector_id: None,
+        charges: None,
+        organization_id: id_type::OrganizationId::default(),
+        connector_refund_data: None,
+        connector_transaction_data: None,
+        split_refunds: None,
+        unified_code: None,
+        unified_message: None,
+        processor_refund_data: None,
+        processor_transaction_data: None,
+        issuer_error_code: None,
+        issuer_error_message: None,
+        origin: enums::RefundOrigin::Api,
+    }
+}
+
+/// Stores a profile of [`merchant_id`] whose outgoing webhooks are sent to a webhook URL
+pub(crate) async fn insert_profile_with_webhook_url(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &id_type::ProfileId,
+) -> domain::Profile {
+    let profile = domain::Profile::from(domain::ProfileSetter {
+        profile_id: profile_id.clone(),
+        merchant_id: merchant_id(),
+        profile_name: "default".to_string(),
+        created_at: common_utils::date_time::now(),
+        modified_at: common_utils::date_time::now(),
+        return_url: None,
+        enable_payment_response_hash: false,
+        payment_response_hash_key: None,
+        redirect_to_merchant_with_http_post: false,
+        webhook_details: Some(diesel_models::business_profile::WebhookDetails {
+            webhook_version: None,
+            webhook_username: None,
+            webhook_password: None,
+            webhook_url: Some("https://merchant.example.com/webhooks".to_string().into()),
+            payment_created_enabled: None,
+            payment_succeeded_enabled: None,
+            payment_failed_enabled: None,
+            payment_statuses_enabled: None,
+            refund_statuses_enabled: None,
+            payout_statuses_enabled: None,
+            payload_version: None,
+        }),
+        metadata: None,
+        routing_algorithm: None,
+        intent_fulfillment_time: None,
+        frm_routing_algorithm: None,
+        payout_routing_algorithm: None,
+        is_recon_enabled: false,
+        applepay_verified_domains: None,
+        payment_link_config: None,
+        session_expiry: None,
+        authentication_connector_details: None,
+        payout_link_config: None,
+        is_extended_card_info_enabled: None,
+        extended_card_info_config: None,
+        is_connector_agnostic_mit_enabled: None,
+        use_billing_as_payment_method_billing: None,
+        collect_shipping_details_from_wallet_connector: None,
+        collect_billing_details_from_wallet_connector: None,
+        outgoing_webhook_custom_http_headers: None,
+        always_collect_billing_details_from_wallet_connector: None,
+        always_collect_shipping_details_from_wallet_connector: None,
+        tax_connector_id: None,
+        is_tax_connector_enabled: false,
+        dynamic_routing_algorithm: None,
+        is_network_tokenization_enabled: false,
+        is_auto_retries_enabled: false,
+        max_auto_retries_enabled: None,
+        always_request_extended_authorization: None,
+        is_click_to_pay_enabled: false,
+        authentication_product_ids: None,
+        card_testing_guard_config: None,
+        card_testing_secret_key: None,
+        is_clear_pan_retries_enabled: false,
+        force_3ds_challenge: false,
+        is_debit_routing_enabled: false,
+        merchant_business_country: None,
+        is_iframe_redirection_enabled: None,
+        is_pre_network_tokenization_enabled: false,
+        merchant_category_code: None,
+    });
+    state
+        .store
+        .insert_business_profile(&state.into(), key_store, profile)
+        .await
+        .unwrap()
+}
+
+/// A succeeded payment intent of [`merchant_id`] created in the profile
+pub(crate) fn payment_intent(
+    payment_id: id_type::PaymentId,
+    profile_id: &id_type::ProfileId,
+) -> storage::PaymentIntent {
+    let now = common_utils::date_time::now();
+    storage::PaymentIntent {
+        payment_id,
+        merchant_id: merchant_id(),
+        status: enums::IntentStatus::Succeeded,
+        amount: MinorUnit::new(1000),
+        shipping_cost: None,
+        currency: Some(enums::Currency::USD),
+        amount_captured: Some(MinorUnit::new(1000)),
+        customer_id: None,
+        description: None,
+        return_url: None,
+        metadata: None,
+        connector_id: None,
+        shipping_address_id: None,
+        billing_address_id: None,
+        statement_descriptor_name: None,
+        statement_descriptor_suffix: None,
+        created_at: now,
+        modified_at: now,
+        last_synced: None,
+        setup_future_usage: None,
+        off_session: None,
+        client_secret: None,
+        active_attempt: hyperswitch_domain_models::RemoteStorageObject::ForeignID(
+            "attempt_1".to_string(),
+        ),
+        business_country: None,
+        business_label: None,
+        order_details: None,
+        allowed_payment_method_types: None,
+        connector_metadata: None,
+        feature_metadata: None,
+        attempt_count: 1,
+        profile_id: Some(profile_id.clone()),
+        payment_link_id: None,
+        merchant_decision: None,
+        payment_confirm_source: None,
+        updated_by: enums::MerchantStorageScheme::PostgresOnly.to_string(),
+        surcharge_applicable: None,
+        request_incremental_authorization: None,
+        incremental_authorization_allowed: None,
+        authorization_count: None,
+        fingerprint_id: None,
+        session_expiry: None,
+        request_external_three_ds_authentication: None,
+        split_payments: None,
+        frm_metadata: None,
+        customer_details: None,
+        billing_details: None,
+        merchant_order_reference_id: None,
+        shipping_details: None,
+        is_payment_processor_token_flow: None,
+        organization_id: id_type::OrganizationId::default(),
+        tax_details: None,
+        skip_external_tax_calculation: None,
+        request_extended_authorization: None,
+        psd2_sca_exemption_type: None,
+        processor_merchant_id: merchant_id(),
+        created_by: None,
+        force_3ds_challenge: None,
+        force_3ds_challenge_trigger: None,
+        is_iframe_redirection_enabled: None,
+    }
+}
@@ -1,6 +1,12 @@
 use ::payment_methods::controller::PaymentMethodsController;
 use api_models::mandates;
-pub use api_models::mandates::{MandateId, MandateResponse, MandateRevokedResponse};
+pub use api_models::mandates::{
+    MandateConnectorReferenceBackfillOutcome, MandateConnectorReferenceBackfillRequest,
+    MandateConnectorReferenceBackfillResponse, MandateConnectorReferenceBackfillStatus, MandateId,
+    MandateResponse, MandateRevocationEligibilityResponse, MandateRevocationReason,
+    MandateRevocationVerdict, MandateRevokeRequest, MandateRevokedResponse, MandateUpdateRequest,
+    PendingMandateSweepRequest, PendingMandateSweepResponse,
+};
 use common_utils::ext_traits::OptionExt;
 use error_stack::ResultExt;
 use serde::{Deserialize, Serialize};
@@ -111,9 +117,12 @@ impl MandateResponseExt for MandateResponse {
             }),
             card,
             status: mandate.mandate_status,
+            customer_id: mandate.customer_id,
+            connector: mandate.connector,
             payment_method: pm.to_string(),
             payment_method_type,
             payment_method_id: mandate.payment_method_id,
+            metadata: mandate.merchant_metadata,
         })
     }
 }
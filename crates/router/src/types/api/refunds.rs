@@ -1,8 +1,13 @@
 #[cfg(feature = "v1")]
-pub use api_models::refunds::RefundRequest;
+pub use api_models::refunds::{
+    RefundBatchCreateRequest, RefundBatchEntryRequest, RefundBatchEntryResponse,
+    RefundBatchResponse, RefundBatchRetrieveRequest, RefundBatchSummary, RefundRequest,
+    RefundWebhookResponse,
+};
 pub use api_models::refunds::{
     RefundListRequest, RefundListResponse, RefundResponse, RefundStatus, RefundType,
-    RefundUpdateRequest, RefundsRetrieveBody, RefundsRetrieveRequest,
+    RefundUpdateRequest, RefundsModifiedSinceRequest, RefundsModifiedSinceResponse,
+    RefundsRetrieveBody, RefundsRetrieveRequest,
 };
 #[cfg(feature = "v2")]
 pub use api_models::refunds::{RefundMetadataUpdateRequest, RefundsCreateRequest};
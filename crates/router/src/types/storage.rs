@@ -34,6 +34,7 @@ pub mod payment_method;
 pub mod payout_attempt;
 pub mod payouts;
 pub mod refund;
+pub mod refund_batch;
 #[cfg(feature = "v2")]
 pub mod revenue_recovery;
 pub mod reverse_lookup;
@@ -74,6 +75,7 @@ pub use self::{
     dynamic_routing_stats::*, ephemeral_key::*, events::*, file::*, fraud_check::*,
     generic_link::*, gsm::*, locker_mock_up::*, mandate::*, merchant_account::*,
     merchant_connector_account::*, merchant_key_store::*, payment_link::*, payment_method::*,
-    process_tracker::*, refund::*, reverse_lookup::*, role::*, routing_algorithm::*,
-    unified_translations::*, user::*, user_authentication_method::*, user_role::*,
+    process_tracker::*, refund::*, refund_batch::*, reverse_lookup::*, role::*,
+    routing_algorithm::*, unified_translations::*, user::*, user_authentication_method::*,
+    user_role::*,
 };
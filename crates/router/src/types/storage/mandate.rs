@@ -1,3 +1,4 @@
+use api_models::{mandates::MandateSortBy, payments::SortBy};
 use async_bb8_diesel::AsyncRunQueryDsl;
 use common_utils::errors::CustomResult;
 use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
@@ -14,6 +15,7 @@ pub trait MandateDbExt: Sized {
     async fn filter_by_constraints(
         conn: &PgPooledConn,
         merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: Option<&common_utils::id_type::CustomerId>,
         mandate_list_constraints: api_models::mandates::MandateListConstraints,
     ) -> CustomResult<Vec<Self>, errors::DatabaseError>;
 }
@@ -23,13 +25,27 @@ impl MandateDbExt for Mandate {
     async fn filter_by_constraints(
         conn: &PgPooledConn,
         merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: Option<&common_utils::id_type::CustomerId>,
         mandate_list_constraints: api_models::mandates::MandateListConstraints,
     ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
         let mut filter = <Self as HasTable>::table()
             .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
-            .order(dsl::created_at.desc())
             .into_boxed();
 
+        if let Some(customer_id) = customer_id {
+            filter = filter.filter(dsl::customer_id.eq(customer_id.to_owned()));
+        }
+
+        filter = match (
+            mandate_list_constraints.sort_by.unwrap_or_default(),
+            mandate_list_constraints.sort_order.unwrap_or_default(),
+        ) {
+            (MandateSortBy::CreatedAt, SortBy::Asc) => filter.order(dsl::created_at.asc()),
+            (MandateSortBy::CreatedAt, SortBy::Desc) => filter.order(dsl::created_at.desc()),
+            (MandateSortBy::ModifiedAt, SortBy::Asc) => filter.order(dsl::modified_at.asc()),
+            (MandateSortBy::ModifiedAt, SortBy::Desc) => filter.order(dsl::modified_at.desc()),
+        };
+
         if let Some(created_time) = mandate_list_constraints.created_time {
             filter = filter.filter(dsl::created_at.eq(created_time));
         }
@@ -45,6 +61,12 @@ impl MandateDbExt for Mandate {
         if let Some(created_time_gte) = mandate_list_constraints.created_time_gte {
             filter = filter.filter(dsl::created_at.ge(created_time_gte));
         }
+        if let Some(modified_time_lte) = mandate_list_constraints.modified_time_lte {
+            filter = filter.filter(dsl::modified_at.le(modified_time_lte));
+        }
+        if let Some(modified_time_gte) = mandate_list_constraints.modified_time_gte {
+            filter = filter.filter(dsl::modified_at.ge(modified_time_gte));
+        }
         if let Some(connector) = mandate_list_constraints.connector {
             filter = filter.filter(dsl::connector.eq(connector));
         }
@@ -1,4 +1,11 @@
+#[cfg(feature = "v1")]
+use std::collections::HashMap;
+
 use api_models::payments::AmountFilter;
+#[cfg(feature = "v1")]
+use api_models::refunds::{
+    RefundMismatchRule, RefundReconciliationRules, RefundStatusCountGroupBy,
+};
 use async_bb8_diesel::AsyncRunQueryDsl;
 use common_utils::errors::CustomResult;
 use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, QueryDsl};
@@ -17,6 +24,11 @@ use hyperswitch_domain_models::refunds;
 
 use crate::{connection::PgPooledConn, logger};
 
+/// The number of refunds of a status in a group, along with the connector and the currency of the
+/// group when the counts are grouped by them
+#[cfg(feature = "v1")]
+pub type RefundStatusCountRow = (Option<String>, Option<Currency>, RefundStatus, i64);
+
 #[async_trait::async_trait]
 pub trait RefundDbExt: Sized {
     #[cfg(feature = "v1")]
@@ -59,6 +71,24 @@ pub trait RefundDbExt: Sized {
         time_range: &common_utils::types::TimeRange,
     ) -> CustomResult<Vec<(RefundStatus, i64)>, errors::DatabaseError>;
 
+    #[cfg(feature = "v1")]
+    async fn get_refund_status_counts(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
+        time_range: &common_utils::types::TimeRange,
+        group_by: RefundStatusCountGroupBy,
+    ) -> CustomResult<Vec<RefundStatusCountRow>, errors::DatabaseError>;
+
+    #[cfg(feature = "v1")]
+    async fn find_inconsistent_refunds(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        older_than: time::PrimitiveDateTime,
+        rules: &RefundReconciliationRules,
+        limit: i64,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError>;
+
     #[cfg(feature = "v2")]
     async fn get_refunds_count(
         conn: &PgPooledConn,
@@ -79,10 +109,23 @@ impl RefundDbExt for Refund {
     ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
         let mut filter = <Self as HasTable>::table()
             .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
-            .order(dsl::modified_at.desc())
+            .order((dsl::created_at.desc(), dsl::refund_id.desc()))
             .into_boxed();
         let mut search_by_pay_or_ref_id = false;
 
+        // Keyset pagination continues after the cursor rather than skipping the offset
+        let offset = match &refund_list_details.starting_after {
+            Some(cursor) => {
+                filter = filter.filter(
+                    dsl::created_at.lt(cursor.created_at).or(dsl::created_at
+                        .eq(cursor.created_at)
+                        .and(dsl::refund_id.lt(cursor.refund_id.clone()))),
+                );
+                0
+            }
+            None => offset,
+        };
+
         if let (Some(pid), Some(ref_id)) = (
             &refund_list_details.payment_id,
             &refund_list_details.refund_id,
@@ -170,6 +213,24 @@ impl RefundDbExt for Refund {
             filter = filter.filter(dsl::refund_status.eq_any(filter_refund_status.clone()));
         }
 
+        if let Some(filter_origin) = &refund_list_details.origin {
+            filter = filter.filter(dsl::origin.eq_any(filter_origin.clone()));
+        }
+
+        if let Some(filter_unified_code) = &refund_list_details.unified_code {
+            filter = filter.filter(dsl::unified_code.eq_any(filter_unified_code.clone()));
+        }
+
+        if let Some(filter_issuer_error_code) = &refund_list_details.issuer_error_code {
+            filter = filter.filter(dsl::issuer_error_code.eq_any(filter_issuer_error_code.clone()));
+        }
+
+        filter = match refund_list_details.has_unified_error {
+            Some(true) => filter.filter(dsl::unified_code.is_not_null()),
+            Some(false) => filter.filter(dsl::unified_code.is_null()),
+            None => filter,
+        };
+
         logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
 
         db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
@@ -242,6 +303,10 @@ impl RefundDbExt for Refund {
             filter = filter.filter(dsl::refund_status.eq_any(filter_refund_status));
         }
 
+        if let Some(filter_origin) = refund_list_details.origin {
+            filter = filter.filter(dsl::origin.eq_any(filter_origin));
+        }
+
         filter = filter.limit(limit).offset(offset);
 
         logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
@@ -393,6 +458,24 @@ impl RefundDbExt for Refund {
             filter = filter.filter(dsl::refund_status.eq_any(filter_refund_status.clone()));
         }
 
+        if let Some(filter_origin) = &refund_list_details.origin {
+            filter = filter.filter(dsl::origin.eq_any(filter_origin.clone()));
+        }
+
+        if let Some(filter_unified_code) = &refund_list_details.unified_code {
+            filter = filter.filter(dsl::unified_code.eq_any(filter_unified_code.clone()));
+        }
+
+        if let Some(filter_issuer_error_code) = &refund_list_details.issuer_error_code {
+            filter = filter.filter(dsl::issuer_error_code.eq_any(filter_issuer_error_code.clone()));
+        }
+
+        filter = match refund_list_details.has_unified_error {
+            Some(true) => filter.filter(dsl::unified_code.is_not_null()),
+            Some(false) => filter.filter(dsl::unified_code.is_null()),
+            None => filter,
+        };
+
         logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
 
         filter
@@ -461,6 +544,10 @@ impl RefundDbExt for Refund {
             filter = filter.filter(dsl::refund_status.eq_any(filter_refund_status));
         }
 
+        if let Some(filter_origin) = refund_list_details.origin {
+            filter = filter.filter(dsl::origin.eq_any(filter_origin));
+        }
+
         logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
 
         filter
@@ -504,4 +591,132 @@ impl RefundDbExt for Refund {
         .change_context(errors::DatabaseError::NotFound)
         .attach_printable_lazy(|| "Error filtering status count of refunds")
     }
+
+    #[cfg(feature = "v1")]
+    async fn get_refund_status_counts(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
+        time_range: &common_utils::types::TimeRange,
+        group_by: RefundStatusCountGroupBy,
+    ) -> CustomResult<Vec<RefundStatusCountRow>, errors::DatabaseError> {
+        let mut query = <Self as HasTable>::table()
+            .group_by((dsl::connector, dsl::currency, dsl::refund_status))
+            .select((
+                dsl::connector,
+                dsl::currency,
+                dsl::refund_status,
+                diesel::dsl::count_star(),
+            ))
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .into_boxed();
+
+        if let Some(profile_id) = profile_id_list {
+            query = query.filter(dsl::profile_id.eq_any(profile_id));
+        }
+
+        query = query.filter(dsl::created_at.ge(time_range.start_time));
+
+        query = match time_range.end_time {
+            Some(ending_at) => query.filter(dsl::created_at.le(ending_at)),
+            None => query,
+        };
+
+        logger::debug!(filter = %diesel::debug_query::<diesel::pg::Pg,_>(&query).to_string());
+
+        let status_counts = db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_results_async::<(String, Currency, RefundStatus, i64)>(conn),
+            db_metrics::DatabaseOperation::Count,
+        )
+        .await
+        .change_context(errors::DatabaseError::NotFound)
+        .attach_printable_lazy(|| "Error filtering status counts of refunds")?;
+
+        Ok(group_refund_status_counts(status_counts, group_by))
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_inconsistent_refunds(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        older_than: time::PrimitiveDateTime,
+        rules: &RefundReconciliationRules,
+        limit: i64,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
+        let not_sent_to_gateway = dsl::refund_status
+            .eq_any(rules.statuses_requiring_gateway.clone())
+            .and(dsl::sent_to_gateway.eq(false));
+        let unresolved_with_connector_refund_id = dsl::refund_status
+            .eq_any(rules.unresolved_statuses.clone())
+            .and(dsl::connector_refund_id.is_not_null());
+
+        let query = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(dsl::created_at.le(older_than))
+            .filter(not_sent_to_gateway.or(unresolved_with_connector_refund_id))
+            .order(dsl::created_at.asc())
+            .limit(limit);
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::NotFound)
+        .attach_printable_lazy(|| "Error finding refunds inconsistent with their connector data")
+    }
+}
+
+/// The first reconciliation rule violated by the refund, if any. The age threshold of the rules is
+/// not checked here, it is left to the query selecting the refunds.
+#[cfg(feature = "v1")]
+pub fn get_refund_mismatch_rule(
+    refund: &Refund,
+    rules: &RefundReconciliationRules,
+) -> Option<RefundMismatchRule> {
+    if !refund.sent_to_gateway
+        && rules
+            .statuses_requiring_gateway
+            .contains(&refund.refund_status)
+    {
+        Some(RefundMismatchRule::NotSentToGateway)
+    } else if refund.connector_refund_id.is_some()
+        && rules.unresolved_statuses.contains(&refund.refund_status)
+    {
+        Some(RefundMismatchRule::UnresolvedWithConnectorRefundId)
+    } else {
+        None
+    }
+}
+
+/// Merges the refund status counts of each connector and currency into the groups of `group_by`,
+/// leaving out the dimensions that are not grouped by
+#[cfg(feature = "v1")]
+pub fn group_refund_status_counts(
+    status_counts: impl IntoIterator<Item = (String, Currency, RefundStatus, i64)>,
+    group_by: RefundStatusCountGroupBy,
+) -> Vec<RefundStatusCountRow> {
+    let mut grouped_counts: HashMap<(Option<String>, Option<Currency>, RefundStatus), i64> =
+        HashMap::new();
+
+    for (connector, currency, refund_status, count) in status_counts {
+        let (connector, currency) = match group_by {
+            RefundStatusCountGroupBy::Connector => (Some(connector), None),
+            RefundStatusCountGroupBy::Currency => (None, Some(currency)),
+            RefundStatusCountGroupBy::ConnectorAndCurrency => (Some(connector), Some(currency)),
+        };
+        let grouped_count = grouped_counts
+            .entry((connector, currency, refund_status))
+            .or_default();
+        *grouped_count = grouped_count.saturating_add(count);
+    }
+
+    grouped_counts
+        .into_iter()
+        .map(|((connector, currency, refund_status), count)| {
+            (connector, currency, refund_status, count)
+        })
+        .collect()
 }
@@ -0,0 +1,4 @@
+pub use diesel_models::refund_batch::{
+    RefundBatch, RefundBatchEntry, RefundBatchEntryNew, RefundBatchEntryUpdate, RefundBatchNew,
+    RefundBatchUpdate, RefundBatchWorkflow,
+};
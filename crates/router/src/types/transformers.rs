@@ -427,6 +427,7 @@ impl ForeignFrom<payments::MandateData> for hyperswitch_domain_models::mandates:
                 }
             }),
             update_mandate_id: d.update_mandate_id,
+            metadata: d.metadata,
         }
     }
 }
@@ -2055,6 +2056,7 @@ impl ForeignFrom<api_models::admin::WebhookDetails>
             payment_statuses_enabled: item.payment_statuses_enabled,
             refund_statuses_enabled: item.refund_statuses_enabled,
             payout_statuses_enabled: item.payout_statuses_enabled,
+            payload_version: item.payload_version,
         }
     }
 }
@@ -2074,6 +2076,7 @@ impl ForeignFrom<diesel_models::business_profile::WebhookDetails>
             payment_statuses_enabled: item.payment_statuses_enabled,
             refund_statuses_enabled: item.refund_statuses_enabled,
             payout_statuses_enabled: item.payout_statuses_enabled,
+            payload_version: item.payload_version,
         }
     }
 }
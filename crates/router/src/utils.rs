@@ -58,11 +58,16 @@ use crate::{
     types::{self, domain, transformers::ForeignInto},
 };
 #[cfg(feature = "v1")]
-use crate::{core::webhooks as webhooks_core, types::storage};
+use crate::{
+    core::{refunds as refunds_core, webhooks as webhooks_core},
+    types::{api::mandates::MandateResponseExt, storage},
+};
 
 pub mod error_parser {
     use std::fmt::Display;
 
+    #[cfg(feature = "v2")]
+    use actix_web::error::PathError;
     use actix_web::{
         error::{Error, JsonPayloadError},
         http::StatusCode,
@@ -108,6 +113,15 @@ pub mod error_parser {
     pub fn custom_json_error_handler(err: JsonPayloadError, _req: &HttpRequest) -> Error {
         Error::from(CustomJsonError { err })
     }
+
+    /// Surfaces path deserialization failures (for instance a global id of the wrong entity) as
+    /// invalid request errors instead of a generic not found response
+    #[cfg(feature = "v2")]
+    pub fn custom_path_error_handler(err: PathError, _req: &HttpRequest) -> Error {
+        Error::from(crate::core::errors::ApiErrorResponse::InvalidRequestData {
+            message: err.to_string(),
+        })
+    }
 }
 
 #[inline]
@@ -248,15 +262,17 @@ pub async fn find_payment_intent_from_refund_id_type(
             )
             .await
             .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?,
-        webhooks::RefundIdType::ConnectorRefundId(id) => db
-            .find_refund_by_merchant_id_connector_refund_id_connector(
+        webhooks::RefundIdType::ConnectorRefundId(id) => {
+            refunds_core::find_refund_by_connector_refund_id(
+                db,
                 merchant_context.get_merchant_account().get_id(),
                 &id,
                 connector_name,
                 merchant_context.get_merchant_account().storage_scheme,
             )
             .await
-            .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?,
+            .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?
+        }
     };
     let attempt = db
         .find_payment_attempt_by_attempt_id_merchant_id(
@@ -1268,6 +1284,7 @@ pub async fn trigger_refund_outgoing_webhook(
         let cloned_state = state.clone();
         let cloned_merchant_context = merchant_context.clone();
         let primary_object_created_at = refund_response.created_at;
+        let payload_version = business_profile.get_outgoing_webhook_payload_version();
         if let Some(outgoing_event_type) = event_type {
             tokio::spawn(
                 async move {
@@ -1279,7 +1296,12 @@ pub async fn trigger_refund_outgoing_webhook(
                         diesel_models::enums::EventClass::Refunds,
                         refund_id.to_string(),
                         diesel_models::enums::EventObjectType::RefundDetails,
-                        webhooks::OutgoingWebhookContent::RefundDetails(Box::new(refund_response)),
+                        webhooks::OutgoingWebhookContent::RefundDetails(Box::new(
+                            api_models::refunds::RefundWebhookResponse::new(
+                                refund_response,
+                                payload_version,
+                            ),
+                        )),
                         primary_object_created_at,
                     ))
                     .await
@@ -1293,6 +1315,54 @@ pub async fn trigger_refund_outgoing_webhook(
     Ok(())
 }
 
+#[cfg(feature = "v1")]
+pub async fn trigger_mandate_outgoing_webhook(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    business_profile: domain::Profile,
+    mandate: &storage::Mandate,
+) -> RouterResult<()> {
+    let Some(outgoing_event_type) = crate::core::mandate::get_mandate_webhook_event_type(mandate)
+    else {
+        logger::debug!(
+            mandate_id = %mandate.mandate_id,
+            mandate_status = %mandate.mandate_status,
+            "Outgoing webhook not sent as the mandate status has no event type"
+        );
+        return Ok(());
+    };
+
+    let mandate_response = api_models::mandates::MandateResponse::from_db_mandate(
+        state,
+        merchant_context.get_merchant_key_store().clone(),
+        mandate.clone(),
+        merchant_context.get_merchant_account(),
+    )
+    .await?;
+    let mandate_id = mandate.mandate_id.clone();
+    let primary_object_created_at = mandate.created_at;
+    let cloned_state = state.clone();
+    let cloned_merchant_context = merchant_context.clone();
+    tokio::spawn(
+        async move {
+            Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+                cloned_state,
+                cloned_merchant_context,
+                business_profile,
+                outgoing_event_type,
+                diesel_models::enums::EventClass::Mandates,
+                mandate_id,
+                diesel_models::enums::EventObjectType::MandateDetails,
+                webhooks::OutgoingWebhookContent::MandateDetails(Box::new(mandate_response)),
+                Some(primary_object_created_at),
+            ))
+            .await
+        }
+        .in_current_span(),
+    );
+    Ok(())
+}
+
 #[cfg(feature = "v2")]
 pub async fn trigger_refund_outgoing_webhook(
     state: &SessionState,
@@ -423,6 +423,7 @@ pub async fn generate_sample_data(
                 organization_id: org_id.clone(),
                 processor_refund_data: None,
                 processor_transaction_data,
+                origin: common_enums::RefundOrigin::Dashboard,
             })
         } else {
             None
@@ -169,6 +169,7 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
                     merchant_account.clone(),
                     key_store.clone(),
                     &tracking_data,
+                    business_profile.get_outgoing_webhook_payload_version(),
                 ))
                 .await?;
 
@@ -181,6 +182,8 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
                             event_type,
                             content: content.clone(),
                             timestamp: event.created_at,
+                            payload_version: business_profile
+                                .get_outgoing_webhook_payload_version(),
                         };
 
                         let request_content = webhooks_core::get_outgoing_webhook_request(
@@ -357,6 +360,7 @@ async fn get_outgoing_webhook_content_and_event_type(
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
     tracking_data: &OutgoingWebhookTrackingData,
+    payload_version: u8,
 ) -> Result<(OutgoingWebhookContent, Option<EventType>), errors::ProcessTrackerError> {
     use api_models::{
         mandates::MandateId,
@@ -467,7 +471,12 @@ async fn get_outgoing_webhook_content_and_event_type(
             let refund_response = RefundResponse::foreign_from(refund);
 
             Ok((
-                OutgoingWebhookContent::RefundDetails(Box::new(refund_response)),
+                OutgoingWebhookContent::RefundDetails(Box::new(
+                    api_models::refunds::RefundWebhookResponse::new(
+                        refund_response,
+                        payload_version,
+                    ),
+                )),
                 event_type,
             ))
         }
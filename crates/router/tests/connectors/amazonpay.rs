@@ -1,5 +1,16 @@
-use hyperswitch_domain_models::payment_method_data::{Card, PaymentMethodData};
-use masking::Secret;
+use base64::Engine;
+use common_utils::{consts, request::Request, types::MinorUnit};
+use hyperswitch_domain_models::{
+    configs::{ConnectorParams, Connectors},
+    payment_method_data::{Card, PaymentMethodData},
+};
+use masking::{ExposeInterface, Secret};
+use openssl::{
+    hash::MessageDigest,
+    pkey::{PKey, Private},
+    rsa::{Padding, Rsa},
+    sign::{RsaPssSaltlen, Verifier},
+};
 use router::types::{self, api, storage::enums};
 use test_utils::connector_auth;
 
@@ -418,4 +429,344 @@ async fn should_fail_for_refund_amount_higher_than_payment_amount() {
 
 // Connector dependent test cases goes here
 
+// Refund flows exercised against recorded Amazon Pay payloads. The requests are built and the
+// responses are handled in process, with a mock connectors config, so no network is involved.
+
+const FIXTURE_BASE_URL: &str = "https://pay-api.amazon.com/v2";
+const FIXTURE_PUBLIC_KEY_ID: &str = "SANDBOX-AFVX7ULWSGBZ5535PCUQOY7B";
+const FIXTURE_CHARGE_ID: &str = "S01-5105180-3221187-C056351";
+const FIXTURE_REFUND_ID: &str = "S01-5105180-3221187-R022311";
+const FIXTURE_SIGNED_HEADERS: &str =
+    "accept;content-type;x-amz-pay-date;x-amz-pay-host;x-amz-pay-region";
+
+const REFUND_EXECUTE_REQUEST: &str = r#"{"chargeId":"S01-5105180-3221187-C056351","refundAmount":{"amount":"10.00","currencyCode":"USD"}}"#;
+
+const REFUND_EXECUTE_CANONICAL_REQUEST: &str = "POST
+/v2/refunds
+
+accept:application/json
+content-type:application/json
+x-amz-pay-date:{date}
+x-amz-pay-host:pay-api.amazon.com
+x-amz-pay-region:na
+
+accept;content-type;x-amz-pay-date;x-amz-pay-host;x-amz-pay-region
+a09f1957f099504a0c59a2d51aff4e4a53bc03c2ca941a5e5dbb5fd3839496a5";
+
+const REFUND_SYNC_CANONICAL_REQUEST: &str = "GET
+/v2/refunds/S01-5105180-3221187-R022311
+
+accept:application/json
+content-type:application/json
+x-amz-pay-date:{date}
+x-amz-pay-host:pay-api.amazon.com
+x-amz-pay-region:na
+
+accept;content-type;x-amz-pay-date;x-amz-pay-host;x-amz-pay-region
+e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+
+const REFUND_INITIATED_RESPONSE: &str = r#"{
+    "refundId": "S01-5105180-3221187-R022311",
+    "chargeId": "S01-5105180-3221187-C056351",
+    "creationTimestamp": "20190714T155300Z",
+    "refundAmount": { "amount": "10.00", "currencyCode": "USD" },
+    "softDescriptor": "Descriptor",
+    "statusDetails": {
+        "state": "RefundInitiated",
+        "reasonCode": null,
+        "reasonDescription": null,
+        "lastUpdatedTimestamp": "20190714T155300Z"
+    },
+    "releaseEnvironment": "Sandbox"
+}"#;
+
+const REFUND_DECLINED_RESPONSE: &str = r#"{
+    "refundId": "S01-5105180-3221187-R022311",
+    "chargeId": "S01-5105180-3221187-C056351",
+    "creationTimestamp": "20190714T155300Z",
+    "refundAmount": { "amount": "10.00", "currencyCode": "USD" },
+    "softDescriptor": "Descriptor",
+    "statusDetails": {
+        "state": "Declined",
+        "reasonCode": "AmazonRejected",
+        "reasonDescription": "Amazon has rejected the refund",
+        "lastUpdatedTimestamp": "20190714T155300Z"
+    },
+    "releaseEnvironment": "Sandbox"
+}"#;
+
+const REFUND_COMPLETED_RESPONSE: &str = r#"{
+    "refundId": "S01-5105180-3221187-R022311",
+    "chargeId": "S01-5105180-3221187-C056351",
+    "creationTimestamp": "20190714T155300Z",
+    "refundAmount": { "amount": "10.00", "currencyCode": "USD" },
+    "softDescriptor": "Descriptor",
+    "statusDetails": {
+        "state": "Refunded",
+        "reasonCode": null,
+        "reasonDescription": null,
+        "lastUpdatedTimestamp": "20190715T093000Z"
+    },
+    "releaseEnvironment": "Sandbox"
+}"#;
+
+struct AmazonpayFixtureTest {
+    private_key: PKey<Private>,
+}
+
+impl AmazonpayFixtureTest {
+    fn new() -> Self {
+        let rsa = Rsa::generate(2048).expect("RSA key");
+        Self {
+            private_key: PKey::from_rsa(rsa).expect("private key"),
+        }
+    }
+
+    fn connectors() -> Connectors {
+        Connectors {
+            amazonpay: ConnectorParams {
+                base_url: FIXTURE_BASE_URL.to_string(),
+                secondary_base_url: None,
+            },
+            ..Default::default()
+        }
+    }
+
+    fn refund_data(minor_refund_amount: i64, currency: enums::Currency) -> types::RefundsData {
+        types::RefundsData {
+            refund_id: "ref_amazonpay_fixture".to_string(),
+            connector_transaction_id: FIXTURE_CHARGE_ID.to_string(),
+            connector_refund_id: Some(FIXTURE_REFUND_ID.to_string()),
+            currency,
+            refund_amount: minor_refund_amount,
+            minor_refund_amount: MinorUnit::new(minor_refund_amount),
+            ..utils::PaymentRefundType::default().0
+        }
+    }
+
+    fn refund_execute_data(
+        &self,
+        minor_refund_amount: i64,
+        currency: enums::Currency,
+    ) -> types::RefundExecuteRouterData {
+        self.generate_data(Self::refund_data(minor_refund_amount, currency), None)
+    }
+
+    fn refund_sync_data(&self) -> types::RefundSyncRouterData {
+        self.generate_data(Self::refund_data(1000, enums::Currency::USD), None)
+    }
+
+    /// Verifies the signature of the request against the expected canonical request
+    fn assert_signed_canonical_request(&self, request: &Request, canonical_request: &str) {
+        let date = header_value(request, "x-amz-pay-date").expect("x-amz-pay-date header");
+        assert_eq!(date.len(), "20190714T155300Z".len());
+        let canonical_request = canonical_request.replace("{date}", &date);
+
+        let authorization = header_value(request, "Authorization").expect("Authorization header");
+        let signature = authorization
+            .strip_prefix(&format!(
+                "AMZN-PAY-RSASSA-PSS-V2 PublicKeyId={FIXTURE_PUBLIC_KEY_ID}, SignedHeaders={FIXTURE_SIGNED_HEADERS}, Signature="
+            ))
+            .expect("Amazon Pay authorization header");
+        let signature = consts::BASE64_ENGINE
+            .decode(signature)
+            .expect("base64 signature");
+
+        let string_to_sign = format!(
+            "AMZN-PAY-RSASSA-PSS-V2\n{}",
+            hex::encode(openssl::sha::sha256(canonical_request.as_bytes()))
+        );
+        let mut verifier = Verifier::new(MessageDigest::sha256(), &self.private_key).unwrap();
+        verifier.set_rsa_padding(Padding::PKCS1_PSS).unwrap();
+        verifier
+            .set_rsa_pss_saltlen(RsaPssSaltlen::custom(20))
+            .unwrap();
+        verifier.update(string_to_sign.as_bytes()).unwrap();
+        assert!(
+            verifier.verify(&signature).unwrap(),
+            "signature does not match the canonical request"
+        );
+    }
+}
+
+impl ConnectorActions for AmazonpayFixtureTest {}
+impl utils::Connector for AmazonpayFixtureTest {
+    fn get_data(&self) -> api::ConnectorData {
+        utils::Connector::get_data(&AmazonpayTest)
+    }
+
+    fn get_auth_token(&self) -> types::ConnectorAuthType {
+        let private_key = self.private_key.private_key_to_pem_pkcs8().unwrap();
+        types::ConnectorAuthType::SignatureKey {
+            api_key: Secret::new(FIXTURE_PUBLIC_KEY_ID.to_string()),
+            key1: Secret::new("na".to_string()),
+            api_secret: Secret::new(String::from_utf8(private_key).unwrap()),
+        }
+    }
+
+    fn get_name(&self) -> String {
+        "amazonpay".to_string()
+    }
+}
+
+fn header_value(request: &Request, name: &str) -> Option<String> {
+    request
+        .headers
+        .iter()
+        .find(|(header, _)| header == name)
+        .map(|(_, value)| value.clone().into_inner())
+}
+
+fn fixture_response(body: &str) -> types::Response {
+    types::Response {
+        headers: None,
+        response: body.to_string().into(),
+        status_code: 200,
+    }
+}
+
+fn build_refund_execute_request(data: &types::RefundExecuteRouterData) -> Request {
+    let connector = router::connector::Amazonpay::new();
+    types::RefundExecuteType::build_request(connector, data, &AmazonpayFixtureTest::connectors())
+        .expect("refund execute request")
+        .expect("refund execute request")
+}
+
+#[test]
+fn should_build_signed_refund_execute_request() {
+    let fixture = AmazonpayFixtureTest::new();
+    let data = fixture.refund_execute_data(1000, enums::Currency::USD);
+    let request = build_refund_execute_request(&data);
+
+    assert_eq!(request.method, common_utils::request::Method::Post);
+    assert_eq!(request.url, format!("{FIXTURE_BASE_URL}/refunds"));
+    assert_eq!(
+        request
+            .body
+            .expect("request body")
+            .get_inner_value()
+            .expose(),
+        REFUND_EXECUTE_REQUEST
+    );
+    assert_eq!(
+        header_value(&request, "x-amz-pay-idempotency-key"),
+        Some(data.request.refund_id.clone())
+    );
+    assert_eq!(
+        header_value(&request, "x-amz-pay-host").as_deref(),
+        Some("pay-api.amazon.com")
+    );
+    fixture.assert_signed_canonical_request(&request, REFUND_EXECUTE_CANONICAL_REQUEST);
+}
+
+#[test]
+fn should_serialize_refund_amount_in_major_units() {
+    let fixture = AmazonpayFixtureTest::new();
+    for (minor_refund_amount, currency, expected_amount) in [
+        (1000, enums::Currency::USD, "10.00"),
+        (1999, enums::Currency::EUR, "19.99"),
+        (1500, enums::Currency::JPY, "1500"),
+    ] {
+        let data = fixture.refund_execute_data(minor_refund_amount, currency);
+        let body = build_refund_execute_request(&data)
+            .body
+            .expect("request body")
+            .get_inner_value()
+            .expose();
+        let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+
+        assert_eq!(body["refundAmount"]["amount"], expected_amount);
+        assert_eq!(body["refundAmount"]["currencyCode"], currency.to_string());
+    }
+}
+
+#[test]
+fn should_handle_refund_initiated_response() {
+    let fixture = AmazonpayFixtureTest::new();
+    let data = fixture.refund_execute_data(1000, enums::Currency::USD);
+    let response = types::RefundExecuteType::handle_response(
+        router::connector::Amazonpay::new(),
+        &data,
+        None,
+        fixture_response(REFUND_INITIATED_RESPONSE),
+    )
+    .expect("refund execute response")
+    .response
+    .expect("refund response");
+
+    assert_eq!(response.connector_refund_id, FIXTURE_REFUND_ID);
+    assert_eq!(response.refund_status, enums::RefundStatus::Pending);
+}
+
+#[test]
+fn should_handle_refund_declined_response() {
+    let fixture = AmazonpayFixtureTest::new();
+    let data = fixture.refund_execute_data(1000, enums::Currency::USD);
+    let error = types::RefundExecuteType::handle_response(
+        router::connector::Amazonpay::new(),
+        &data,
+        None,
+        fixture_response(REFUND_DECLINED_RESPONSE),
+    )
+    .expect("refund execute response")
+    .response
+    .unwrap_err();
+
+    assert_eq!(error.code, "AmazonRejected");
+    assert_eq!(error.message, "Payment was rejected by Amazon Pay");
+    assert_eq!(
+        error.reason.as_deref(),
+        Some("Amazon has rejected the refund")
+    );
+    assert_eq!(
+        error.connector_transaction_id.as_deref(),
+        Some(FIXTURE_REFUND_ID)
+    );
+}
+
+#[test]
+fn should_build_signed_refund_sync_request() {
+    let fixture = AmazonpayFixtureTest::new();
+    let data = fixture.refund_sync_data();
+    let request = types::RefundSyncType::build_request(
+        router::connector::Amazonpay::new(),
+        &data,
+        &AmazonpayFixtureTest::connectors(),
+    )
+    .expect("refund sync request")
+    .expect("refund sync request");
+
+    assert_eq!(request.method, common_utils::request::Method::Get);
+    assert_eq!(
+        request.url,
+        format!("{FIXTURE_BASE_URL}/refunds/{FIXTURE_REFUND_ID}")
+    );
+    assert!(request.body.is_none());
+    assert!(header_value(&request, "x-amz-pay-idempotency-key").is_none());
+    fixture.assert_signed_canonical_request(&request, REFUND_SYNC_CANONICAL_REQUEST);
+}
+
+#[test]
+fn should_handle_refund_sync_responses() {
+    let fixture = AmazonpayFixtureTest::new();
+    let data = fixture.refund_sync_data();
+    for (response, expected_status) in [
+        (REFUND_INITIATED_RESPONSE, enums::RefundStatus::Pending),
+        (REFUND_COMPLETED_RESPONSE, enums::RefundStatus::Success),
+    ] {
+        let response = types::RefundSyncType::handle_response(
+            router::connector::Amazonpay::new(),
+            &data,
+            None,
+            fixture_response(response),
+        )
+        .expect("refund sync response")
+        .response
+        .expect("refund response");
+
+        assert_eq!(response.connector_refund_id, FIXTURE_REFUND_ID);
+        assert_eq!(response.refund_status, expected_status);
+    }
+}
+
 // [#478]: add unit tests for non 3DS, wallets & webhooks in connector tests
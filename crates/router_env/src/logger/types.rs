@@ -104,6 +104,8 @@ pub enum Flow {
     CustomersDelete,
     /// Customers get mandates flow.
     CustomersGetMandates,
+    /// Customers revoke mandates flow.
+    CustomersRevokeMandates,
     /// Create an Ephemeral Key.
     EphemeralKeyCreate,
     /// Delete an Ephemeral Key.
@@ -112,8 +114,16 @@ pub enum Flow {
     MandatesRetrieve,
     /// Mandates revoke flow.
     MandatesRevoke,
+    /// Mandates update flow.
+    MandatesUpdate,
+    /// Mandates revocation eligibility flow.
+    MandatesRevocationEligibility,
     /// Mandates list flow.
     MandatesList,
+    /// Backfill the connector mandate id of mandates which do not have one
+    MandatesConnectorMandateIdBackfill,
+    /// Mark mandates pending on the asynchronous mandate reference past the timeout as inactive
+    MandatesPendingSweep,
     /// Payment methods create flow.
     PaymentMethodsCreate,
     /// Payment methods migrate flow.
@@ -223,10 +233,20 @@ pub enum Flow {
     RefundsUpdate,
     /// Refunds list flow.
     RefundsList,
+    /// Refunds list by modification time flow, for incremental syncs.
+    RefundsListModifiedSince,
+    /// Refunds export flow
+    RefundsExport,
+    /// Refunds reconciliation export flow
+    RefundsReconciliationExport,
     /// Refunds filters flow
     RefundsFilters,
     /// Refunds aggregates flow
     RefundsAggregate,
+    /// Refunds batch create flow
+    RefundsBatchCreate,
+    /// Refunds batch retrieve flow
+    RefundsBatchRetrieve,
     // Retrieve forex flow.
     RetrieveForexFlow,
     /// Toggles recon service for a merchant.
@@ -259,6 +279,8 @@ pub enum Flow {
     RoutingUpdateDefaultConfig,
     /// Routing delete config
     RoutingDeleteConfig,
+    /// Routing program import validation
+    RoutingValidateImport,
     /// Toggle dynamic routing
     ToggleDynamicRouting,
     /// Update dynamic routing config
@@ -533,10 +555,22 @@ pub enum Flow {
     ToggleExtendedCardInfo,
     /// Toggles the extended card info feature in profile level
     ToggleConnectorAgnosticMit,
+    /// Create or update the refund limits of a profile
+    RefundLimitsConfigUpsert,
+    /// Retrieve the refund limits of a profile
+    RefundLimitsConfigRetrieve,
+    /// Delete the refund limits of a profile
+    RefundLimitsConfigDelete,
     /// Get the extended card info associated to a payment_id
     GetExtendedCardInfo,
     /// Manually update the refund details like status, error code, error message etc.
     RefundsManualUpdate,
+    /// Backfill the merchant connector id of refunds which do not have one
+    RefundsMerchantConnectorIdBackfill,
+    /// Reset the refund circuit breaker of a merchant connector account
+    RefundsCircuitBreakerReset,
+    /// Sync the pending refunds of a merchant which have not been updated for a while
+    RefundsStaleSync,
     /// Manually update the payment details like status, error code, error message etc.
     PaymentsManualUpdate,
     /// Dynamic Tax Calcultion
@@ -1,4 +1,6 @@
 use common_utils::errors::CustomResult;
+#[cfg(feature = "v1")]
+use common_utils::types::ConnectorTransactionId;
 #[cfg(feature = "v2")]
 use common_utils::{id_type, types::keymanager::KeyManagerState};
 use diesel_models::enums as storage_enums;
@@ -79,12 +81,25 @@ impl PaymentAttemptInterface for MockDb {
     #[cfg(feature = "v1")]
     async fn find_payment_attempt_by_attempt_id_merchant_id(
         &self,
-        _attempt_id: &str,
-        _merchant_id: &common_utils::id_type::MerchantId,
+        attempt_id: &str,
+        merchant_id: &common_utils::id_type::MerchantId,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PaymentAttempt, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payment_attempts = self.payment_attempts.lock().await;
+
+        payment_attempts
+            .iter()
+            .find(|payment_attempt| {
+                payment_attempt.attempt_id == attempt_id
+                    && payment_attempt.merchant_id.eq(merchant_id)
+            })
+            .cloned()
+            .ok_or_else(|| {
+                StorageError::ValueNotFound(format!(
+                    "No payment attempt found for attempt_id = {attempt_id}"
+                ))
+                .into()
+            })
     }
 
     #[cfg(feature = "v2")]
@@ -99,6 +114,20 @@ impl PaymentAttemptInterface for MockDb {
         Err(StorageError::MockDbError)?
     }
 
+    #[cfg(feature = "v2")]
+    async fn attempt_belongs_to_payment(
+        &self,
+        attempt_id: &id_type::GlobalAttemptId,
+        payment_id: &id_type::GlobalPaymentId,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> error_stack::Result<bool, StorageError> {
+        let payment_attempts = self.payment_attempts.lock().await;
+
+        Ok(payment_attempts
+            .iter()
+            .any(|attempt| attempt.id == *attempt_id && attempt.payment_id == *payment_id))
+    }
+
     #[cfg(feature = "v2")]
     async fn find_payment_attempts_by_payment_intent_id(
         &self,
@@ -125,12 +154,30 @@ impl PaymentAttemptInterface for MockDb {
     #[cfg(feature = "v1")]
     async fn find_payment_attempt_by_merchant_id_connector_txn_id(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _connector_txn_id: &str,
+        merchant_id: &common_utils::id_type::MerchantId,
+        connector_txn_id: &str,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PaymentAttempt, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payment_attempts = self.payment_attempts.lock().await;
+
+        // The connector transaction id is stored hashed when it is too long, as in the database
+        payment_attempts
+            .iter()
+            .find(|payment_attempt| {
+                payment_attempt.merchant_id.eq(merchant_id)
+                    && payment_attempt
+                        .connector_transaction_id
+                        .clone()
+                        .map(ConnectorTransactionId::from)
+                        .is_some_and(|id| id.get_id() == connector_txn_id)
+            })
+            .cloned()
+            .ok_or_else(|| {
+                StorageError::ValueNotFound(format!(
+                    "No payment attempt found for connector_txn_id = {connector_txn_id}"
+                ))
+                .into()
+            })
     }
 
     #[cfg(feature = "v2")]
@@ -149,12 +196,20 @@ impl PaymentAttemptInterface for MockDb {
     #[cfg(feature = "v1")]
     async fn find_attempts_by_merchant_id_payment_id(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _payment_id: &common_utils::id_type::PaymentId,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<PaymentAttempt>, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payment_attempts = self.payment_attempts.lock().await;
+
+        Ok(payment_attempts
+            .iter()
+            .filter(|payment_attempt| {
+                payment_attempt.payment_id == *payment_id
+                    && payment_attempt.merchant_id == *merchant_id
+            })
+            .cloned()
+            .collect())
     }
 
     #[cfg(feature = "v1")]
@@ -189,7 +244,7 @@ impl PaymentAttemptInterface for MockDb {
             last_synced: payment_attempt.last_synced,
             cancellation_reason: payment_attempt.cancellation_reason,
             amount_to_capture: payment_attempt.amount_to_capture,
-            mandate_id: None,
+            mandate_id: payment_attempt.mandate_id,
             browser_info: None,
             payment_token: None,
             error_code: payment_attempt.error_code,
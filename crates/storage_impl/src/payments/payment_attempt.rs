@@ -1,11 +1,8 @@
-use common_utils::errors::CustomResult;
 #[cfg(feature = "v2")]
 use common_utils::types::keymanager::KeyManagerState;
 #[cfg(feature = "v1")]
-use common_utils::{
-    fallback_reverse_lookup_not_found,
-    types::{ConnectorTransactionId, ConnectorTransactionIdTrait, CreatedBy},
-};
+use common_utils::types::{ConnectorTransactionId, ConnectorTransactionIdTrait, CreatedBy};
+use common_utils::{errors::CustomResult, fallback_reverse_lookup_not_found};
 #[cfg(feature = "v1")]
 use diesel_models::payment_attempt::PaymentAttemptNew as DieselPaymentAttemptNew;
 use diesel_models::{
@@ -458,6 +455,25 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
             .change_context(errors::StorageError::DecryptionError)
     }
 
+    #[cfg(feature = "v2")]
+    #[instrument(skip_all)]
+    async fn attempt_belongs_to_payment(
+        &self,
+        attempt_id: &common_utils::id_type::GlobalAttemptId,
+        payment_id: &common_utils::id_type::GlobalPaymentId,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<bool, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+
+        DieselPaymentAttempt::get_count_by_id_payment_id(&conn, attempt_id, payment_id)
+            .await
+            .map_err(|er| {
+                let new_err = diesel_error_to_data_error(*er.current_context());
+                er.change_context(new_err)
+            })
+            .map(|count| count > 0)
+    }
+
     #[cfg(feature = "v2")]
     #[instrument(skip_all)]
     async fn find_payment_attempts_by_payment_intent_id(
@@ -1512,6 +1528,74 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
         .await
     }
 
+    #[cfg(feature = "v2")]
+    #[instrument(skip_all)]
+    async fn attempt_belongs_to_payment(
+        &self,
+        attempt_id: &common_utils::id_type::GlobalAttemptId,
+        payment_id: &common_utils::id_type::GlobalPaymentId,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<bool, errors::StorageError> {
+        let storage_scheme = Box::pin(decide_storage_scheme::<_, DieselPaymentAttempt>(
+            self,
+            storage_scheme,
+            Op::Find,
+        ))
+        .await;
+
+        match storage_scheme {
+            MerchantStorageScheme::PostgresOnly => {
+                self.router_store
+                    .attempt_belongs_to_payment(attempt_id, payment_id, storage_scheme)
+                    .await
+            }
+            // The attempt may not have been drained to the database yet, it is read from redis
+            // without being decrypted
+            MerchantStorageScheme::RedisKv => {
+                let lookup_id = label::get_global_id_label(attempt_id);
+                let lookup = fallback_reverse_lookup_not_found!(
+                    self.get_lookup_by_lookup_id(&lookup_id, storage_scheme)
+                        .await,
+                    self.router_store
+                        .attempt_belongs_to_payment(attempt_id, payment_id, storage_scheme)
+                        .await
+                );
+                let key = PartitionKey::CombinationKey {
+                    combination: &lookup.pk_id,
+                };
+                let database_call = || async {
+                    let conn = pg_connection_read(self).await?;
+                    DieselPaymentAttempt::find_by_id(&conn, attempt_id)
+                        .await
+                        .map_err(|er| {
+                            let new_err = diesel_error_to_data_error(*er.current_context());
+                            er.change_context(new_err)
+                        })
+                };
+
+                let payment_attempt = Box::pin(try_redis_get_else_try_database_get(
+                    async {
+                        Box::pin(kv_wrapper(
+                            self,
+                            KvOperation::<DieselPaymentAttempt>::HGet(&lookup.sk_id),
+                            key,
+                        ))
+                        .await?
+                        .try_into_hget()
+                    },
+                    database_call,
+                ))
+                .await;
+
+                match payment_attempt {
+                    Ok(payment_attempt) => Ok(payment_attempt.payment_id == *payment_id),
+                    Err(error) if error.current_context().is_db_not_found() => Ok(false),
+                    Err(error) => Err(error),
+                }
+            }
+        }
+    }
+
     #[cfg(feature = "v2")]
     #[instrument(skip_all)]
     async fn find_payment_attempts_by_payment_intent_id(
@@ -17,7 +17,7 @@ pub struct ConnectorAuthentication {
     #[cfg(feature = "payouts")]
     pub adyen_uk: Option<SignatureKey>,
     pub airwallex: Option<BodyKey>,
-    pub amazonpay: Option<HeaderKey>,
+    pub amazonpay: Option<SignatureKey>,
     pub archipel: Option<NoKey>,
     pub authorizedotnet: Option<BodyKey>,
     pub bambora: Option<BodyKey>,
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS refund_batch_entry;
+
+DROP INDEX IF EXISTS refund_batch_merchant_id_index;
+
+DROP TABLE IF EXISTS refund_batch;
@@ -0,0 +1,31 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS refund_batch (
+    batch_id VARCHAR(64) NOT NULL PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64),
+    organization_id VARCHAR(32) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    total_entries INTEGER NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS refund_batch_merchant_id_index ON refund_batch (merchant_id);
+
+CREATE TABLE IF NOT EXISTS refund_batch_entry (
+    batch_id VARCHAR(64) NOT NULL,
+    entry_index INTEGER NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(64) NOT NULL,
+    refund_id VARCHAR(64) NOT NULL,
+    amount BIGINT,
+    reason VARCHAR(255),
+    merchant_connector_id VARCHAR(32),
+    status VARCHAR(32) NOT NULL,
+    refund_status "RefundStatus",
+    error_code VARCHAR(64),
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (batch_id, entry_index)
+);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE mandate
+DROP COLUMN IF EXISTS frequency,
+DROP COLUMN IF EXISTS last_charged_at;
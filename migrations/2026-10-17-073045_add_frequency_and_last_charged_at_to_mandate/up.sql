@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE mandate
+ADD COLUMN IF NOT EXISTS frequency VARCHAR(32),
+ADD COLUMN IF NOT EXISTS last_charged_at TIMESTAMP;
@@ -0,0 +1,31 @@
+-- This file should undo anything in `up.sql`
+DELETE FROM gateway_status_map
+WHERE connector = 'amazonpay'
+    AND flow IN ('Authorize', 'PSync', 'Capture')
+    AND sub_flow = 'sub_flow'
+    AND code IN (
+        'SoftDeclined',
+        'HardDeclined',
+        'AmazonRejected',
+        'PaymentMethodNotAllowed',
+        'BuyerCanceled',
+        'CheckoutSessionCanceled',
+        'TransactionAmountExceeded',
+        'CurrencyMismatch',
+        'ExpiredUnused',
+        'MerchantCanceled',
+        'ChargePermissionCanceled',
+        'InvalidChargePermissionStatus',
+        'InvalidCheckoutSessionStatus',
+        'ProcessingFailure',
+        'TransactionTimedOut',
+        'AmazonCanceled',
+        'TooManyRequests',
+        'InternalServerError',
+        'ServiceUnavailable',
+        'InvalidParameterValue',
+        'MissingParameterValue',
+        'UnauthorizedAccess',
+        'InvalidRequestSignature',
+        'ResourceNotFound'
+    );
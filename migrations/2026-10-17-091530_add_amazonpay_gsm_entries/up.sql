@@ -0,0 +1,44 @@
+-- Your SQL goes here
+-- Unified error codes for the Amazon Pay reason codes mapped in the amazonpay connector
+INSERT INTO gateway_status_map (
+    connector,
+    flow,
+    sub_flow,
+    code,
+    message,
+    status,
+    decision,
+    step_up_possible,
+    unified_code,
+    unified_message
+)
+SELECT 'amazonpay', flows.flow, 'sub_flow', codes.code, codes.message, codes.status, 'do_default', FALSE, codes.unified_code, codes.message
+FROM (VALUES ('Authorize'), ('PSync'), ('Capture')) AS flows (flow)
+CROSS JOIN (
+    VALUES
+        ('SoftDeclined', 'Payment was declined, the customer may retry the payment', 'authorization_failed', 'UE_1000'),
+        ('HardDeclined', 'Payment was declined, the customer should use a different payment method', 'authorization_failed', 'UE_1000'),
+        ('AmazonRejected', 'Payment was rejected by Amazon Pay', 'failure', 'UE_1000'),
+        ('PaymentMethodNotAllowed', 'Payment method is not allowed for this payment', 'failure', 'UE_1000'),
+        ('BuyerCanceled', 'Payment was canceled by the customer', 'failure', 'UE_1000'),
+        ('CheckoutSessionCanceled', 'Checkout session was canceled by the customer', 'failure', 'UE_1000'),
+        ('TransactionAmountExceeded', 'Payment amount exceeds the amount authorized by the customer', 'failure', 'UE_2000'),
+        ('CurrencyMismatch', 'Payment currency does not match the currency of the checkout session', 'failure', 'UE_2000'),
+        ('ExpiredUnused', 'Authorization expired before it was captured', 'failure', 'UE_2000'),
+        ('MerchantCanceled', 'Payment was canceled by the merchant', 'voided', 'UE_2000'),
+        ('ChargePermissionCanceled', 'Charge permission of the payment has been canceled', 'failure', 'UE_2000'),
+        ('InvalidChargePermissionStatus', 'Charge permission is not in a state which allows this operation', 'failure', 'UE_2000'),
+        ('InvalidCheckoutSessionStatus', 'Checkout session is not in a state which allows this operation', 'failure', 'UE_2000'),
+        ('ProcessingFailure', 'Amazon Pay could not process the payment', 'failure', 'UE_3000'),
+        ('TransactionTimedOut', 'Payment timed out at Amazon Pay', 'failure', 'UE_3000'),
+        ('AmazonCanceled', 'Payment was canceled by Amazon Pay', 'failure', 'UE_3000'),
+        ('TooManyRequests', 'Amazon Pay is throttling requests', 'failure', 'UE_3000'),
+        ('InternalServerError', 'Amazon Pay encountered an internal error', 'failure', 'UE_3000'),
+        ('ServiceUnavailable', 'Amazon Pay is temporarily unavailable', 'failure', 'UE_3000'),
+        ('InvalidParameterValue', 'Request contains an invalid parameter value', 'failure', 'UE_4000'),
+        ('MissingParameterValue', 'Request is missing a required parameter', 'failure', 'UE_4000'),
+        ('UnauthorizedAccess', 'Amazon Pay credentials are not authorized for this request', 'failure', 'UE_4000'),
+        ('InvalidRequestSignature', 'Request signature could not be verified by Amazon Pay', 'failure', 'UE_4000'),
+        ('ResourceNotFound', 'Requested resource was not found at Amazon Pay', 'failure', 'UE_4000')
+) AS codes (code, message, status, unified_code)
+ON CONFLICT DO NOTHING;
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS mandate_merchant_id_modified_at_index;
+
+DROP INDEX IF EXISTS mandate_merchant_id_created_at_index;
+
+ALTER TABLE mandate DROP COLUMN IF EXISTS modified_at;
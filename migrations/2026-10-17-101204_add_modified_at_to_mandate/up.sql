@@ -0,0 +1,9 @@
+-- Your SQL goes here
+ALTER TABLE mandate
+ADD COLUMN IF NOT EXISTS modified_at TIMESTAMP NOT NULL DEFAULT now();
+
+UPDATE mandate SET modified_at = created_at;
+
+CREATE INDEX IF NOT EXISTS mandate_merchant_id_created_at_index ON mandate (merchant_id, created_at);
+
+CREATE INDEX IF NOT EXISTS mandate_merchant_id_modified_at_index ON mandate (merchant_id, modified_at);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS mandate_merchant_id_customer_id_mandate_status_index;
+
+DROP INDEX IF EXISTS mandate_merchant_id_mandate_status_created_at_index;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+CREATE INDEX IF NOT EXISTS mandate_merchant_id_mandate_status_created_at_index ON mandate (merchant_id, mandate_status, created_at);
+
+CREATE INDEX IF NOT EXISTS mandate_merchant_id_customer_id_mandate_status_index ON mandate (merchant_id, customer_id, mandate_status);
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+CREATE INDEX IF NOT EXISTS refund_merchant_id_modified_at_refund_id_index ON refund (merchant_id, modified_at, refund_id);
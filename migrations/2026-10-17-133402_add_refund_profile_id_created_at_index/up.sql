@@ -0,0 +1,2 @@
+-- Your SQL goes here
+CREATE INDEX IF NOT EXISTS refund_merchant_id_profile_id_created_at_index ON refund (merchant_id, profile_id, created_at);
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE mandate
+ADD COLUMN IF NOT EXISTS merchant_metadata JSONB DEFAULT NULL;
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE refund
+ADD COLUMN IF NOT EXISTS origin VARCHAR(32) NOT NULL DEFAULT 'legacy';
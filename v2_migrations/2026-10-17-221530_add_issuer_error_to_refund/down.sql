@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE refund
+DROP COLUMN IF EXISTS issuer_error_code,
+DROP COLUMN IF EXISTS issuer_error_message;